@@ -210,6 +210,10 @@ fn execution_loop<
     // TODO: Since we require all mutable objects to not show up more than
     // once across single tx, we should be able to run them in parallel.
     for (idx, single_tx) in transaction_kind.into_single_transactions().enumerate() {
+        // Programmable transactions attribute the gas of each of their commands
+        let is_programmable =
+            matches!(single_tx, SingleTransactionKind::ProgrammableTransaction(_));
+        let computation_units = gas_status.computation_gas_units();
         match single_tx {
             SingleTransactionKind::TransferObject(TransferObject {
                 recipient,
@@ -359,8 +363,7 @@ fn execution_loop<
                 protocol_config,
             )?,
             SingleTransactionKind::ProgrammableTransaction(pt) => {
-                // TODO use Mode
-                programmable_transactions::execution::execute(
+                programmable_transactions::execution::execute::<Mode, _, _>(
                     protocol_config,
                     move_vm,
                     temporary_store,
@@ -368,9 +371,17 @@ fn execution_loop<
                     gas_status,
                     gas_object_id,
                     pt,
+                    &mut results,
                 )?
             }
         };
+        if !is_programmable {
+            Mode::add_command_gas(
+                &mut results,
+                idx,
+                gas_status.computation_gas_units() - computation_units,
+            );
+        }
     }
     Ok(results)
 }
@@ -45,6 +45,9 @@ pub trait ExecutionMode {
         idx: TransactionIndex,
         result: Self::ExecutionResult,
     );
+
+    /// Records the computation gas units charged for the command at `idx`
+    fn add_command_gas(results: &mut Self::ExecutionResults, idx: TransactionIndex, units: u64);
 }
 
 #[derive(Copy, Clone)]
@@ -72,6 +75,8 @@ impl ExecutionMode for Normal {
     fn empty_results() -> Self::ExecutionResults {}
 
     fn add_result(_: &mut Self::ExecutionResults, _: TransactionIndex, _: Self::ExecutionResult) {}
+
+    fn add_command_gas(_: &mut Self::ExecutionResults, _: TransactionIndex, _: u64) {}
 }
 
 /// WARNING! Using this mode will bypass all normal checks around Move entry functions! This
@@ -84,9 +89,16 @@ pub type ExecutionResult = (
     /*  return_values */ Vec<(Vec<u8>, TypeTag)>,
 );
 
+#[derive(Default)]
+pub struct DevInspectExecutionResults {
+    pub results: Vec<(TransactionIndex, ExecutionResult)>,
+    /// Computation gas units charged for each command
+    pub command_gas: Vec<(TransactionIndex, u64)>,
+}
+
 impl ExecutionMode for DevInspect {
     type ExecutionResult = ExecutionResult;
-    type ExecutionResults = Vec<(TransactionIndex, ExecutionResult)>;
+    type ExecutionResults = DevInspectExecutionResults;
 
     fn allow_arbitrary_function_calls() -> bool {
         true
@@ -145,7 +157,7 @@ impl ExecutionMode for DevInspect {
     }
 
     fn empty_results() -> Self::ExecutionResults {
-        vec![]
+        DevInspectExecutionResults::default()
     }
 
     fn add_result(
@@ -153,7 +165,11 @@ impl ExecutionMode for DevInspect {
         idx: TransactionIndex,
         result: Self::ExecutionResult,
     ) {
-        results.push((idx, result))
+        results.results.push((idx, result))
+    }
+
+    fn add_command_gas(results: &mut Self::ExecutionResults, idx: TransactionIndex, units: u64) {
+        results.command_gas.push((idx, units))
     }
 }
 
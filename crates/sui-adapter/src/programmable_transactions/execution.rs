@@ -43,13 +43,15 @@ use sui_verifier::{
     INIT_FN_NAME,
 };
 
-use crate::adapter::{
-    convert_type_argument_error, generate_package_id, validate_primitive_arg_string,
+use crate::{
+    adapter::{convert_type_argument_error, generate_package_id, validate_primitive_arg_string},
+    execution_mode::ExecutionMode,
 };
 
 use super::{context::*, types::*};
 
-pub fn execute<E: fmt::Debug, S: StorageView<E>>(
+#[allow(clippy::too_many_arguments)]
+pub fn execute<Mode: ExecutionMode, E: fmt::Debug, S: StorageView<E>>(
     protocol_config: &ProtocolConfig,
     vm: &MoveVM,
     state_view: &mut S,
@@ -57,6 +59,7 @@ pub fn execute<E: fmt::Debug, S: StorageView<E>>(
     gas_status: &mut SuiGasStatus,
     gas_coin: ObjectID,
     pt: ProgrammableTransaction,
+    mode_results: &mut Mode::ExecutionResults,
 ) -> Result<(), ExecutionError> {
     let ProgrammableTransaction { inputs, commands } = pt;
     let mut context = ExecutionContext::new(
@@ -70,7 +73,13 @@ pub fn execute<E: fmt::Debug, S: StorageView<E>>(
     )?;
     // execute commands
     for (idx, command) in commands.into_iter().enumerate() {
+        let computation_units = context.gas_status.computation_gas_units();
         execute_command(&mut context, command).map_err(|e| e.with_command_index(idx))?;
+        Mode::add_command_gas(
+            mode_results,
+            idx,
+            context.gas_status.computation_gas_units() - computation_units,
+        );
     }
     // apply changes
     let ExecutionResults {
@@ -261,6 +270,9 @@ fn execute_command<E: fmt::Debug, S: StorageView<E>>(
         Command::Publish(modules) => {
             vec![execute_move_publish(context, modules)?]
         }
+        Command::Upgrade(..) => {
+            invariant_violation!("Package upgrades are rejected by the transaction input checks")
+        }
     };
     context.push_command_results(results)?;
     Ok(())
@@ -172,6 +172,12 @@ impl Cluster for LocalNewCluster {
         if let Some(rpc_port) = fullnode_port {
             cluster_builder = cluster_builder.set_fullnode_rpc_port(rpc_port);
         }
+        if let Some(num_validators) = options.num_validators {
+            cluster_builder = cluster_builder.with_num_validators(num_validators);
+        }
+        if let Some(epoch_duration_ms) = options.epoch_duration_ms {
+            cluster_builder = cluster_builder.with_epoch_duration_ms(epoch_duration_ms);
+        }
 
         let mut test_cluster = cluster_builder.build().await?;
 
@@ -22,6 +22,12 @@ pub struct ClusterTestOpt {
     pub faucet_address: Option<String>,
     #[clap(long)]
     pub fullnode_address: Option<String>,
+    /// Number of validators of a new local cluster
+    #[clap(long)]
+    pub num_validators: Option<usize>,
+    /// Epoch duration of a new local cluster, in milliseconds
+    #[clap(long)]
+    pub epoch_duration_ms: Option<u64>,
 }
 
 impl ClusterTestOpt {
@@ -30,6 +36,8 @@ impl ClusterTestOpt {
             env: Env::NewLocal,
             faucet_address: None,
             fullnode_address: None,
+            num_validators: None,
+            epoch_duration_ms: None,
         }
     }
 }
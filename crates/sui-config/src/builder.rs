@@ -461,6 +461,9 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
                     metrics: None,
                     supported_protocol_versions: Some(supported_protocol_versions),
                     db_checkpoint_config: self.db_checkpoint_config.clone(),
+                    json_rpc_rate_limit: None,
+                    archival_store_url: None,
+                    checkpoint_export_path: None,
                 }
             })
             .collect();
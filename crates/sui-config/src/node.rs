@@ -101,6 +101,22 @@ pub struct NodeConfig {
 
     #[serde(default)]
     pub db_checkpoint_config: DBCheckpointConfig,
+
+    /// Rate limits of the JSON-RPC server, requests are not limited if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_rpc_rate_limit: Option<RpcRateLimitConfig>,
+
+    /// Archive of the checkpoints and object versions pruned from the node, one of
+    /// `s3://<bucket>/<prefix>`, `gs://<bucket>/<prefix>` or `file://<directory>`. Reads of
+    /// pruned data fall back to the archive if set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archival_store_url: Option<String>,
+
+    /// Directory where the executed checkpoints are exported, in the binary format of
+    /// `sui_storage::checkpoint_export`, one file per epoch. Checkpoints are not exported if not
+    /// set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_export_path: Option<PathBuf>,
 }
 
 fn default_authority_store_pruning_config() -> AuthorityStorePruningConfig {
@@ -350,6 +366,67 @@ pub struct DBCheckpointConfig {
     pub checkpoint_path: Option<PathBuf>,
 }
 
+/// Rate limits of the JSON-RPC server. Every method has a weight, and the total weight of the
+/// requests of a client is limited by its quota. Requests over quota are rejected with a
+/// `429 Too Many Requests` response.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RpcRateLimitConfig {
+    /// Weight of the methods, e.g. to make expensive methods like `sui_dryRunTransaction` count
+    /// for more of the quota. Methods not listed weigh `default_method_weight`.
+    #[serde(default)]
+    pub method_weights: BTreeMap<String, u32>,
+    #[serde(default = "default_rpc_method_weight")]
+    pub default_method_weight: u32,
+    /// Quota of every client IP, IPs are not limited if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_ip_quota: Option<RpcQuota>,
+    /// Quotas of the API keys, requests with a known API key are limited by the quota of the
+    /// key instead of the quota of their IP. Requests with unknown keys are limited by IP.
+    #[serde(default)]
+    pub api_key_quotas: BTreeMap<String, RpcQuota>,
+    /// Header carrying the API key of a request.
+    #[serde(default = "default_rpc_api_key_header")]
+    pub api_key_header: String,
+    /// Header carrying the IP of the client, set by the load balancer in front of the fullnode.
+    /// The first IP of the header is used.
+    #[serde(default = "default_rpc_client_ip_header")]
+    pub client_ip_header: String,
+}
+
+impl Default for RpcRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            method_weights: BTreeMap::new(),
+            default_method_weight: default_rpc_method_weight(),
+            per_ip_quota: None,
+            api_key_quotas: BTreeMap::new(),
+            api_key_header: default_rpc_api_key_header(),
+            client_ip_header: default_rpc_client_ip_header(),
+        }
+    }
+}
+
+/// Quota of a client, as a token bucket refilled with `weight_per_second` up to `burst`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RpcQuota {
+    pub weight_per_second: u32,
+    pub burst: u32,
+}
+
+fn default_rpc_method_weight() -> u32 {
+    1
+}
+
+fn default_rpc_api_key_header() -> String {
+    "x-api-key".to_string()
+}
+
+fn default_rpc_client_ip_header() -> String {
+    "x-forwarded-for".to_string()
+}
+
 /// Publicly known information about a validator
 /// TODO read most of this from on-chain
 #[serde_as]
@@ -102,6 +102,15 @@ pub struct StateSyncConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint_content_download_concurrency: Option<usize>,
 
+    /// Set the upper bound on the number of checkpoints, above the highest synced checkpoint,
+    /// whose contents are being downloaded or are downloaded and waiting for the contents of an
+    /// earlier checkpoint. Contents are downloaded out of order but committed in order, so this
+    /// bounds how far a slow download can let the others run ahead.
+    ///
+    /// If unspecified, this will default to `1,000`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_content_buffer_size: Option<usize>,
+
     /// Set the timeout that should be used when sending most state-sync RPC requests.
     ///
     /// If unspecified, this will default to `10,000` milliseconds.
@@ -167,6 +176,13 @@ impl StateSyncConfig {
             .unwrap_or(CHECKPOINT_CONTENT_DOWNLOAD_CONCURRENCY)
     }
 
+    pub fn checkpoint_content_buffer_size(&self) -> usize {
+        const CHECKPOINT_CONTENT_BUFFER_SIZE: usize = 1_000;
+
+        self.checkpoint_content_buffer_size
+            .unwrap_or(CHECKPOINT_CONTENT_BUFFER_SIZE)
+    }
+
     pub fn timeout(&self) -> Duration {
         const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
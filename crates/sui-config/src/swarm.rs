@@ -279,6 +279,9 @@ impl<'a> FullnodeConfigBuilder<'a> {
             metrics: None,
             supported_protocol_versions: Some(supported_protocol_versions),
             db_checkpoint_config: self.db_checkpoint_config,
+            json_rpc_rate_limit: None,
+            archival_store_url: None,
+            checkpoint_export_path: None,
         })
     }
 }
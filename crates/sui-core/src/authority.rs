@@ -15,12 +15,14 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{ModuleId, StructTag};
 use move_core_types::parser::parse_struct_tag;
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
 use mysten_metrics::spawn_monitored_task;
 use parking_lot::Mutex;
 use prometheus::{
     register_histogram_with_registry, register_int_counter_vec_with_registry,
-    register_int_counter_with_registry, register_int_gauge_with_registry, Histogram, IntCounter,
-    IntCounterVec, IntGauge, Registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry,
+    register_int_gauge_with_registry, Histogram, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+    Registry,
 };
 use serde::de::DeserializeOwned;
 use std::path::{Path, PathBuf};
@@ -52,8 +54,9 @@ use narwhal_config::{
 use sui_adapter::{adapter, execution_mode};
 use sui_config::genesis::Genesis;
 use sui_json_rpc_types::{
-    type_and_fields_from_move_struct, DevInspectResults, DryRunTransactionResponse, SuiEvent,
-    SuiEventEnvelope, SuiMoveValue, SuiTransactionEvents,
+    type_and_fields_from_move_struct, DevInspectResults, DryRunTransactionResponse, SuiCommandGas,
+    SuiEvent, SuiEventEnvelope, SuiGasBreakdown, SuiGasSmash, SuiMoveValue, SuiObjectStorageGas,
+    SuiTransactionEvents,
 };
 use sui_macros::{fail_point, nondeterministic};
 use sui_protocol_config::{ProtocolConfig, SupportedProtocolVersions};
@@ -64,11 +67,12 @@ use sui_storage::{
     write_ahead_log::{DBTxGuard, TxGuard},
     IndexStore,
 };
+use sui_types::collection_types::Table;
 use sui_types::committee::{EpochId, ProtocolVersion};
 use sui_types::crypto::{sha3_hash, AuthorityKeyPair, NetworkKeyPair, Signer};
 use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldType};
 use sui_types::event::{Event, EventID};
-use sui_types::gas::{GasCostSummary, GasPrice, SuiCostTable, SuiGasStatus};
+use sui_types::gas::{get_gas_balance, GasCostSummary, GasPrice, SuiCostTable, SuiGasStatus};
 use sui_types::messages_checkpoint::{
     CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
     CheckpointSummary, CheckpointTimestamp, VerifiedCheckpoint,
@@ -97,6 +101,7 @@ use crate::authority::authority_per_epoch_store::{
 use crate::authority::authority_per_epoch_store_pruner::AuthorityPerEpochStorePruner;
 use crate::authority::authority_store::{ExecutionLockReadGuard, InputKey, ObjectLockStatus};
 use crate::authority::authority_store_pruner::AuthorityStorePruner;
+use crate::authority::table_entries::TableEntries;
 use crate::checkpoints::CheckpointStore;
 use crate::epoch::committee_store::CommitteeStore;
 use crate::epoch::epoch_metrics::EpochMetrics;
@@ -135,6 +140,7 @@ pub mod authority_per_epoch_store_pruner;
 pub mod authority_store_pruner;
 pub mod authority_store_tables;
 pub mod authority_store_types;
+pub mod table_entries;
 
 pub(crate) mod authority_notify_read;
 pub(crate) mod authority_store;
@@ -190,6 +196,7 @@ pub struct AuthorityMetrics {
     pub(crate) transaction_manager_num_ready: IntGauge,
 
     pub(crate) execution_driver_executed_transactions: IntCounter,
+    pub(crate) execution_driver_lane_queue_depth: IntGaugeVec,
 
     pub(crate) skipped_consensus_txns: IntCounter,
 
@@ -362,6 +369,13 @@ impl AuthorityMetrics {
                 registry,
             )
             .unwrap(),
+            execution_driver_lane_queue_depth: register_int_gauge_vec_with_registry!(
+                "execution_driver_lane_queue_depth",
+                "Number of ready certificates waiting for an execution slot, per execution lane",
+                &["lane"],
+                registry,
+            )
+            .unwrap(),
             skipped_consensus_txns: register_int_counter_with_registry!(
                 "skipped_consensus_txns",
                 "Total number of consensus transactions skipped",
@@ -1025,12 +1039,42 @@ impl AuthorityState {
                 &epoch_store.epoch_start_configuration().epoch_data(),
                 epoch_store.protocol_config(),
             );
+        let gas_smash = Self::dry_run_gas_smash(&gas, &inner_temp_store)?;
         Ok(DryRunTransactionResponse {
             effects: effects.try_into()?,
             events: SuiTransactionEvents::try_from(
                 inner_temp_store.events,
                 epoch_store.module_cache().as_ref(),
             )?,
+            gas_smash: Some(gas_smash),
+        })
+    }
+
+    /// Payment coins `gas` of a dry run after execution, all smashed into the first one.
+    fn dry_run_gas_smash(
+        gas: &[ObjectRef],
+        inner_temp_store: &InnerTemporaryStore,
+    ) -> Result<SuiGasSmash, anyhow::Error> {
+        let balance = |object: Option<&Object>, id: &ObjectID| {
+            let object = object.ok_or_else(|| anyhow!("Gas coin {id} is missing"))?;
+            Ok::<_, anyhow::Error>(get_gas_balance(object)?)
+        };
+        let mut input_balance = 0u64;
+        for (id, _, _) in gas {
+            input_balance += balance(inner_temp_store.objects.get(id), id)?;
+        }
+        let (gas_id, _, _) = gas
+            .first()
+            .ok_or_else(|| anyhow!("Transaction has no gas payment"))?;
+        let (gas_ref, gas_coin, _) = inner_temp_store
+            .written
+            .get(gas_id)
+            .ok_or_else(|| anyhow!("Gas coin {gas_id} is not written by the transaction"))?;
+        Ok(SuiGasSmash {
+            gas_coin: (*gas_ref).into(),
+            merged_coins: gas[1..].iter().map(|oref| (*oref).into()).collect(),
+            input_balance,
+            final_balance: balance(Some(gas_coin), gas_id)?,
         })
     }
 
@@ -1117,14 +1161,67 @@ impl AuthorityState {
                 &epoch_store.epoch_start_configuration().epoch_data(),
                 protocol_config,
             );
+        let (execution_result, gas_breakdown) = match execution_result {
+            Ok(execution_mode::DevInspectExecutionResults {
+                results,
+                command_gas,
+            }) => {
+                let commands = command_gas
+                    .into_iter()
+                    .map(|(command_index, computation_units)| SuiCommandGas {
+                        command_index,
+                        computation_units,
+                    })
+                    .collect();
+                (
+                    Ok(results),
+                    Some(SuiGasBreakdown {
+                        commands,
+                        objects: Self::dev_inspect_storage_gas(&inner_temp_store),
+                    }),
+                )
+            }
+            Err(e) => (Err(e), None),
+        };
         DevInspectResults::new(
             effects,
             inner_temp_store.events,
             execution_result,
+            gas_breakdown,
             epoch_store.module_cache().as_ref(),
         )
     }
 
+    /// Storage cost and rebate of the objects written or deleted by a dev inspect transaction.
+    /// The storage rebate of a written object is the storage cost charged for it.
+    fn dev_inspect_storage_gas(inner_temp_store: &InnerTemporaryStore) -> Vec<SuiObjectStorageGas> {
+        let input_rebate = |id: &ObjectID| {
+            inner_temp_store
+                .objects
+                .get(id)
+                .map_or(0, |object| object.storage_rebate)
+        };
+        let written = inner_temp_store
+            .written
+            .iter()
+            .filter(|(_, (_, object, _))| !object.is_immutable())
+            .map(|(id, (_, object, _))| SuiObjectStorageGas {
+                object_id: *id,
+                storage_cost: object.storage_rebate,
+                storage_rebate: input_rebate(id),
+            });
+        let deleted = inner_temp_store
+            .deleted
+            .keys()
+            .filter(|id| inner_temp_store.objects.contains_key(id))
+            .map(|id| SuiObjectStorageGas {
+                object_id: *id,
+                storage_cost: 0,
+                storage_rebate: input_rebate(id),
+            });
+        written.chain(deleted).collect()
+    }
+
     pub fn is_tx_already_executed(&self, digest: &TransactionDigest) -> SuiResult<bool> {
         self.database.is_tx_already_executed(digest)
     }
@@ -1207,9 +1304,9 @@ impl AuthorityState {
                         continue;
                     };
                 let Some(old_object) = self.database.get_object_by_key(id, *old_version)? else {
-                        error!("Error processing object owner index for tx [{:?}], cannot find object [{id}] at version [{old_version}].", effects.transaction_digest());
-                        continue;
-                    };
+                    error!("Error processing object owner index for tx [{:?}], cannot find object [{id}] at version [{old_version}].", effects.transaction_digest());
+                    continue;
+                };
                 if &old_object.owner != owner {
                     match old_object.owner {
                         Owner::AddressOwner(addr) => {
@@ -1304,7 +1401,8 @@ impl AuthorityState {
                     return Err(UserInputError::ObjectNotFound {
                         object_id,
                         version: Some(o.version()),
-                    }.into())
+                    }
+                    .into());
                 };
                 let version = object.version();
                 let digest = object.digest();
@@ -1939,6 +2037,17 @@ impl AuthorityState {
     }
 
     pub async fn get_object_read(&self, object_id: &ObjectID) -> Result<ObjectRead, SuiError> {
+        self.get_object_read_with_layout(object_id, true).await
+    }
+
+    /// Same as [`Self::get_object_read`], but only resolves the Move struct layout of the
+    /// object when `resolve_layout` is set, as layout resolution is expensive and only needed
+    /// to render the contents of the object.
+    pub async fn get_object_read_with_layout(
+        &self,
+        object_id: &ObjectID,
+        resolve_layout: bool,
+    ) -> Result<ObjectRead, SuiError> {
         match self.database.get_latest_parent_entry(*object_id)? {
             None => Ok(ObjectRead::NotExists(*object_id)),
             Some((obj_ref, _)) => {
@@ -1953,17 +2062,7 @@ impl AuthorityState {
                             .into())
                         }
                         Some(object) => {
-                            let layout = object.get_layout(
-                                ObjectFormatOptions::default(),
-                                // threading the epoch_store through this API does not
-                                // seem possible, so we just read it from the state (self) and fetch
-                                // the module cache out of it.
-                                // Notice that no matter what module cache we get things
-                                // should work
-                                self.load_epoch_store_one_call_per_task()
-                                    .module_cache()
-                                    .as_ref(),
-                            )?;
+                            let layout = self.get_object_layout(&object, resolve_layout)?;
                             Ok(ObjectRead::Exists(obj_ref, object, layout))
                         }
                     }
@@ -1974,6 +2073,27 @@ impl AuthorityState {
         }
     }
 
+    fn get_object_layout(
+        &self,
+        object: &Object,
+        resolve_layout: bool,
+    ) -> SuiResult<Option<MoveStructLayout>> {
+        if !resolve_layout {
+            return Ok(None);
+        }
+        object.get_layout(
+            ObjectFormatOptions::default(),
+            // threading the epoch_store through this API does not
+            // seem possible, so we just read it from the state (self) and fetch
+            // the module cache out of it.
+            // Notice that no matter what module cache we get things
+            // should work
+            self.load_epoch_store_one_call_per_task()
+                .module_cache()
+                .as_ref(),
+        )
+    }
+
     async fn get_move_object<T>(&self, object_id: &ObjectID) -> SuiResult<T>
     where
         T: DeserializeOwned,
@@ -2001,6 +2121,18 @@ impl AuthorityState {
         &self,
         object_id: &ObjectID,
         version: SequenceNumber,
+    ) -> Result<PastObjectRead, SuiError> {
+        self.get_past_object_read_with_layout(object_id, version, true)
+            .await
+    }
+
+    /// Same as [`Self::get_past_object_read`], but only resolves the Move struct layout of the
+    /// object when `resolve_layout` is set.
+    pub async fn get_past_object_read_with_layout(
+        &self,
+        object_id: &ObjectID,
+        version: SequenceNumber,
+        resolve_layout: bool,
     ) -> Result<PastObjectRead, SuiError> {
         // Firstly we see if the object ever exists by getting its latest data
         match self.database.get_latest_parent_entry(*object_id)? {
@@ -2018,15 +2150,7 @@ impl AuthorityState {
                     return Ok(match self.database.get_object_by_key(object_id, version)? {
                         None => PastObjectRead::VersionNotFound(*object_id, version),
                         Some(object) => {
-                            let layout = object.get_layout(
-                                ObjectFormatOptions::default(),
-                                // threading the epoch_store through this API does not
-                                // seem possible, so we just read it from the state (self) and fetch
-                                // the module cache out of it.
-                                // Notice that no matter what module cache we get things
-                                // should work
-                                self.epoch_store.load().module_cache().as_ref(),
-                            )?;
+                            let layout = self.get_object_layout(&object, resolve_layout)?;
                             let obj_ref = object.compute_object_reference();
                             PastObjectRead::VersionFound(obj_ref, object, layout)
                         }
@@ -2044,15 +2168,7 @@ impl AuthorityState {
                             .into())
                         }
                         Some(object) => {
-                            let layout = object.get_layout(
-                                ObjectFormatOptions::default(),
-                                // threading the epoch_store through this API does not
-                                // seem possible, so we just read it from the state (self) and fetch
-                                // the module cache out of it.
-                                // Notice that no matter what module cache we get things
-                                // should work
-                                self.epoch_store.load().module_cache().as_ref(),
-                            )?;
+                            let layout = self.get_object_layout(&object, resolve_layout)?;
                             Ok(PastObjectRead::VersionFound(obj_ref, object, layout))
                         }
                     }
@@ -2155,6 +2271,21 @@ impl AuthorityState {
         }
     }
 
+    /// Iterate lazily over the entries of `table`, in the order of the ids of their dynamic
+    /// fields, deserializing them with the layouts of the table key and value types. The entries
+    /// are found with the dynamic field index, which must be available.
+    pub fn table_entries(
+        &self,
+        table: &Table,
+        key_layout: MoveTypeLayout,
+        value_layout: MoveTypeLayout,
+    ) -> SuiResult<TableEntries<'_>> {
+        if self.indexes.is_none() {
+            return Err(SuiError::IndexStoreNotAvailable);
+        }
+        Ok(TableEntries::new(self, table, key_layout, value_layout))
+    }
+
     pub fn get_total_transaction_number(&self) -> Result<u64, anyhow::Error> {
         Ok(self.get_indexes()?.next_sequence_number())
     }
@@ -3055,7 +3186,9 @@ impl AuthorityState {
             //   state sync, and execute it. This will upgrade the framework packages, reconfigure,
             //   and most likely shut down in the new epoch (this validator likely doesn't support
             //   the new protocol version, or else it should have had the packages.)
-            return Err(anyhow!("missing system packages: cannot form ChangeEpochTx"));
+            return Err(anyhow!(
+                "missing system packages: cannot form ChangeEpochTx"
+            ));
         };
 
         let tx = VerifiedTransaction::new_change_epoch(
@@ -0,0 +1,85 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+
+use move_core_types::value::MoveTypeLayout;
+use sui_types::base_types::ObjectID;
+use sui_types::collection_types::Table;
+use sui_types::dynamic_field::DynamicFieldInfo;
+use sui_types::error::{SuiResult, UserInputError};
+use sui_types::table_viewer::{deserialize_table_entry, TableEntry};
+
+use super::AuthorityState;
+
+/// Number of dynamic fields read from the index at a time when iterating over a table.
+const TABLE_ENTRIES_PAGE_SIZE: usize = 50;
+
+/// Iterator over the entries of a `sui::table::Table`, see [AuthorityState::table_entries].
+pub struct TableEntries<'a> {
+    state: &'a AuthorityState,
+    table_id: ObjectID,
+    key_layout: MoveTypeLayout,
+    value_layout: MoveTypeLayout,
+    page: VecDeque<DynamicFieldInfo>,
+    // Cursor of the next page, None once the last page has been read.
+    cursor: Option<ObjectID>,
+}
+
+impl<'a> TableEntries<'a> {
+    pub(crate) fn new(
+        state: &'a AuthorityState,
+        table: &Table,
+        key_layout: MoveTypeLayout,
+        value_layout: MoveTypeLayout,
+    ) -> Self {
+        Self {
+            state,
+            table_id: table.id,
+            key_layout,
+            value_layout,
+            page: VecDeque::new(),
+            cursor: Some(ObjectID::ZERO),
+        }
+    }
+
+    fn next_page(&mut self, cursor: ObjectID) -> SuiResult {
+        let mut page = self.state.get_dynamic_fields(
+            self.table_id,
+            Some(cursor),
+            TABLE_ENTRIES_PAGE_SIZE + 1,
+        )?;
+        self.cursor = if page.len() > TABLE_ENTRIES_PAGE_SIZE {
+            page.pop().map(|field| field.object_id)
+        } else {
+            None
+        };
+        self.page = page.into();
+        Ok(())
+    }
+
+    fn entry(&self, field: &DynamicFieldInfo) -> SuiResult<TableEntry> {
+        let object = self.state.database.get_object(&field.object_id)?.ok_or(
+            UserInputError::ObjectNotFound {
+                object_id: field.object_id,
+                version: None,
+            },
+        )?;
+        deserialize_table_entry(&object, &self.key_layout, &self.value_layout)
+    }
+}
+
+impl<'a> Iterator for TableEntries<'a> {
+    type Item = SuiResult<TableEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.page.is_empty() {
+            let cursor = self.cursor.take()?;
+            if let Err(e) = self.next_page(cursor) {
+                return Some(Err(e));
+            }
+        }
+        let field = self.page.pop_front()?;
+        Some(self.entry(&field))
+    }
+}
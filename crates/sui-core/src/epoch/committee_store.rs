@@ -9,6 +9,8 @@ use sui_storage::default_db_options;
 use sui_types::base_types::ObjectID;
 use sui_types::committee::{Committee, EpochId};
 use sui_types::error::{SuiError, SuiResult};
+use sui_types::messages_checkpoint::{CertifiedCheckpointSummary, CheckpointSequenceNumber};
+use tokio::sync::watch;
 use typed_store::rocks::{DBMap, DBOptions, MetricConf};
 use typed_store::traits::{TableSummary, TypedStoreDebug};
 
@@ -20,6 +22,8 @@ use sui_macros::nondeterministic;
 pub struct CommitteeStore {
     tables: CommitteeStoreTables,
     cache: RwLock<HashMap<EpochId, Committee>>,
+    /// Committee of the latest epoch, sent to the subscribers of epoch changes.
+    latest_committee: watch::Sender<Committee>,
 }
 
 #[derive(DBMapUtils)]
@@ -27,6 +31,11 @@ pub struct CommitteeStoreTables {
     /// Map from each epoch ID to the committee information.
     #[default_options_override_fn = "committee_table_default_config"]
     committee_map: DBMap<EpochId, Committee>,
+
+    /// Map from each epoch ID to the sequence number of its end-of-epoch checkpoint, which
+    /// certifies the committee of the next epoch. Written in the same batch as that committee.
+    #[default_options_override_fn = "committee_table_default_config"]
+    epoch_last_checkpoint_map: DBMap<EpochId, CheckpointSequenceNumber>,
 }
 
 // These functions are used to initialize the DB tables
@@ -42,9 +51,11 @@ impl CommitteeStore {
             db_options,
             None,
         );
+        let (latest_committee, _) = watch::channel(genesis_committee.clone());
         let store = Self {
             tables,
             cache: RwLock::new(HashMap::new()),
+            latest_committee,
         };
         if store.database_is_empty() {
             store
@@ -52,6 +63,9 @@ impl CommitteeStore {
                 .expect("Init genesis committee data must not fail");
         }
         store
+            .latest_committee
+            .send_replace(store.get_latest_committee());
+        store
     }
 
     pub fn new_for_testing(genesis_committee: &Committee) -> Self {
@@ -78,10 +92,86 @@ impl CommitteeStore {
             self.cache
                 .write()
                 .insert(new_committee.epoch, new_committee.clone());
+            self.notify_new_committee(new_committee);
         }
         Ok(())
     }
 
+    /// Subscribe to epoch changes: the receiver holds the committee of the latest epoch, and is
+    /// notified every time the committee of a later epoch is inserted.
+    pub fn subscribe(&self) -> watch::Receiver<Committee> {
+        self.latest_committee.subscribe()
+    }
+
+    fn notify_new_committee(&self, committee: &Committee) {
+        self.latest_committee.send_if_modified(|latest| {
+            if committee.epoch > latest.epoch {
+                *latest = committee.clone();
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Extract the committee of the next epoch from the end-of-epoch `checkpoint`, after checking
+    /// that the checkpoint is certified by the stored committee of its epoch. The new committee
+    /// is persisted atomically with the sequence number of the checkpoint that ended the epoch,
+    /// so an epoch is never observed as ended without its successor committee being stored.
+    pub fn insert_committee_from_end_of_epoch_checkpoint(
+        &self,
+        checkpoint: &CertifiedCheckpointSummary,
+    ) -> SuiResult<Committee> {
+        let summary = checkpoint.data();
+        let epoch = summary.epoch;
+        let next_epoch_committee = summary.next_epoch_committee().ok_or_else(|| {
+            SuiError::from(
+                format!(
+                    "Checkpoint {} is not the last checkpoint of epoch {epoch}",
+                    summary.sequence_number
+                )
+                .as_str(),
+            )
+        })?;
+        let committee = self
+            .get_committee(&epoch)?
+            .ok_or(SuiError::MissingCommitteeAtEpoch(epoch))?;
+        checkpoint.verify_signature(&committee)?;
+        let next_committee =
+            Committee::new(epoch + 1, next_epoch_committee.iter().cloned().collect())?;
+
+        if let Some(old_committee) = self.get_committee(&next_committee.epoch)? {
+            // If somehow we already have this committee in the store, they must be the same.
+            assert_eq!(old_committee, next_committee);
+        }
+        self.tables
+            .committee_map
+            .batch()
+            .insert_batch(
+                &self.tables.committee_map,
+                [(next_committee.epoch, next_committee.clone())],
+            )?
+            .insert_batch(
+                &self.tables.epoch_last_checkpoint_map,
+                [(epoch, summary.sequence_number)],
+            )?
+            .write()?;
+        self.cache
+            .write()
+            .insert(next_committee.epoch, next_committee.clone());
+        self.notify_new_committee(&next_committee);
+        Ok(next_committee)
+    }
+
+    /// Return the sequence number of the end-of-epoch checkpoint of `epoch`, if the committee of
+    /// the following epoch was inserted from it.
+    pub fn get_epoch_last_checkpoint(
+        &self,
+        epoch: EpochId,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>> {
+        Ok(self.tables.epoch_last_checkpoint_map.get(&epoch)?)
+    }
+
     pub fn get_committee(&self, epoch_id: &EpochId) -> SuiResult<Option<Committee>> {
         if let Some(committee) = self.cache.read().get(epoch_id) {
             return Ok(Some(committee.clone())); // todo use Arc
@@ -93,6 +183,18 @@ impl CommitteeStore {
         Ok(committee)
     }
 
+    /// Return the committees of at most `limit` epochs, starting at epoch `from_epoch`.
+    pub fn get_committees(&self, from_epoch: EpochId, limit: usize) -> SuiResult<Vec<Committee>> {
+        Ok(self
+            .tables
+            .committee_map
+            .iter()
+            .skip_to(&from_epoch)?
+            .take(limit)
+            .map(|(_, committee)| committee)
+            .collect())
+    }
+
     // todo - make use of cache or remove this method
     pub fn get_latest_committee(&self) -> Committee {
         self.tables
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
+    collections::VecDeque,
     sync::{Arc, Weak},
     time::Duration,
 };
@@ -25,6 +26,32 @@ mod execution_driver_tests;
 const EXECUTION_MAX_ATTEMPTS: usize = 10;
 const EXECUTION_FAILURE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
 
+/// Ready certificates are executed in separate lanes, each with its own concurrency limit, so that
+/// a spike of transactions on shared objects cannot delay the execution of transactions that only
+/// touch owned objects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ExecutionLane {
+    Owned,
+    Shared,
+}
+
+impl ExecutionLane {
+    pub(crate) fn of(certificate: &VerifiedExecutableTransaction) -> Self {
+        if certificate.contains_shared_object() {
+            ExecutionLane::Shared
+        } else {
+            ExecutionLane::Owned
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionLane::Owned => "owned",
+            ExecutionLane::Shared => "shared",
+        }
+    }
+}
+
 /// When a notification that a new pending transaction is received we activate
 /// processing the transaction in a loop.
 pub async fn execution_process(
@@ -34,28 +61,45 @@ pub async fn execution_process(
 ) {
     info!("Starting pending certificates execution process.");
 
-    // Rate limit concurrent executions to # of cpus.
-    let limit = Arc::new(Semaphore::new(num_cpus::get()));
+    // Rate limit concurrent executions to # of cpus, separately for each lane.
+    let owned_limit = Arc::new(Semaphore::new(num_cpus::get()));
+    let shared_limit = Arc::new(Semaphore::new(num_cpus::get()));
+    let mut owned_queue = VecDeque::new();
+    let mut shared_queue = VecDeque::new();
 
-    // Loop whenever there is a signal that a new transactions is ready to process.
+    // Loop whenever there is a signal that a new transactions is ready to process, or a lane
+    // has both a queued transaction and a free execution slot.
     loop {
-        let certificate;
-        tokio::select! {
+        let (lane, certificate, permit) = tokio::select! {
             result = rx_ready_certificates.recv() => {
-                if let Some(cert) = result {
-                    certificate = cert;
-                } else {
+                let Some(certificate) = result else {
                     // Should only happen after the AuthorityState has shut down and tx_ready_certificate
                     // has been dropped by TransactionManager.
                     info!("No more certificate will be received. Exiting executor ...");
                     return;
                 };
+                let lane = ExecutionLane::of(&certificate);
+                match lane {
+                    ExecutionLane::Owned => owned_queue.push_back(certificate),
+                    ExecutionLane::Shared => shared_queue.push_back(certificate),
+                }
+                report_queue_depths(&authority_state, owned_queue.len(), shared_queue.len());
+                continue;
+            }
+            // hold semaphore permit until task completes. unwrap ok because we never close
+            // the semaphores in this context.
+            permit = owned_limit.clone().acquire_owned(), if !owned_queue.is_empty() => {
+                (ExecutionLane::Owned, owned_queue.pop_front().unwrap(), permit.unwrap())
+            }
+            permit = shared_limit.clone().acquire_owned(), if !shared_queue.is_empty() => {
+                (ExecutionLane::Shared, shared_queue.pop_front().unwrap(), permit.unwrap())
             }
             _ = &mut rx_execution_shutdown => {
                 info!("Shutdown signal received. Exiting executor ...");
                 return;
             }
         };
+        report_queue_depths(&authority_state, owned_queue.len(), shared_queue.len());
 
         let authority = if let Some(authority) = authority_state.upgrade() {
             authority
@@ -70,12 +114,11 @@ pub async fn execution_process(
         let epoch_store = authority.load_epoch_store_one_call_per_task();
 
         let digest = *certificate.digest();
-        debug!(?digest, "Pending certificate execution activated.");
-
-        let limit = limit.clone();
-        // hold semaphore permit until task completes. unwrap ok because we never close
-        // the semaphore in this context.
-        let permit = limit.acquire_owned().await.unwrap();
+        debug!(
+            ?digest,
+            lane = lane.as_str(),
+            "Pending certificate execution activated."
+        );
 
         // Certificate execution can take significant time, so run it in a separate task.
         spawn_monitored_task!(async move {
@@ -113,3 +156,23 @@ pub async fn execution_process(
         }.instrument(error_span!("execution_driver", tx_digest = ?digest)));
     }
 }
+
+fn report_queue_depths(
+    authority_state: &Weak<AuthorityState>,
+    owned_queue_len: usize,
+    shared_queue_len: usize,
+) {
+    let Some(authority) = authority_state.upgrade() else {
+        return;
+    };
+    for (lane, depth) in [
+        (ExecutionLane::Owned, owned_queue_len),
+        (ExecutionLane::Shared, shared_queue_len),
+    ] {
+        authority
+            .metrics
+            .execution_driver_lane_queue_depth
+            .with_label_values(&[lane.as_str()])
+            .set(depth as i64);
+    }
+}
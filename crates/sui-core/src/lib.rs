@@ -15,11 +15,13 @@ pub mod consensus_validator;
 pub mod epoch;
 pub mod event_handler;
 mod execution_driver;
+pub mod light_client;
 pub mod metrics;
 pub mod module_cache_metrics;
 pub mod narwhal_manager;
 mod notify_once;
 pub mod quorum_driver;
+pub mod replay;
 pub mod safe_client;
 mod stake_aggregator;
 pub mod state_accumulator;
@@ -0,0 +1,141 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use sui_types::base_types::ExecutionDigests;
+use sui_types::committee::Committee;
+use sui_types::error::{SuiError, SuiResult};
+use sui_types::message_envelope::Message;
+use sui_types::messages::{TransactionEffects, TransactionEffectsAPI};
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, VerifiedCheckpoint,
+};
+use tracing::info;
+
+use crate::epoch::committee_store::CommitteeStore;
+
+#[cfg(test)]
+#[path = "unit_tests/light_client_tests.rs"]
+mod light_client_tests;
+
+/// Proof that a transaction was executed with the given effects and included in a checkpoint:
+/// the certified summary of the checkpoint and its contents, which the summary commits to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionInclusionProof {
+    pub checkpoint: CertifiedCheckpointSummary,
+    pub contents: CheckpointContents,
+}
+
+/// A light client that verifies checkpoints without executing transactions.
+///
+/// Starting from a trusted genesis committee in its [CommitteeStore], it learns the committee of
+/// every following epoch from the certified end-of-epoch checkpoints, which carry the next
+/// committee and are signed by the current one. Checkpoints and transaction inclusion proofs are
+/// then verified against the committee of their epoch.
+pub struct LightClient {
+    committee_store: Arc<CommitteeStore>,
+}
+
+impl LightClient {
+    pub fn new(committee_store: Arc<CommitteeStore>) -> Self {
+        Self { committee_store }
+    }
+
+    /// The committee of the most recent epoch known to the light client.
+    pub fn latest_committee(&self) -> Committee {
+        self.committee_store.get_latest_committee()
+    }
+
+    /// Verify that `checkpoint` is certified by the committee of its epoch. Fails with
+    /// [SuiError::MissingCommitteeAtEpoch] if the light client has not followed the chain of
+    /// committees up to that epoch yet.
+    pub fn verify_checkpoint(
+        &self,
+        checkpoint: CertifiedCheckpointSummary,
+    ) -> SuiResult<VerifiedCheckpoint> {
+        let epoch = checkpoint.data().epoch;
+        let committee = self
+            .committee_store
+            .get_committee(&epoch)?
+            .ok_or(SuiError::MissingCommitteeAtEpoch(epoch))?;
+        checkpoint.verify(&committee)
+    }
+
+    /// Verify the last checkpoint of the latest known epoch and store the committee of the next
+    /// epoch it carries. Returns the new committee.
+    ///
+    /// End-of-epoch checkpoints must be supplied in order, as the committee of each epoch is only
+    /// known once the end-of-epoch checkpoint of the previous one has been verified.
+    pub fn advance_epoch(&self, checkpoint: CertifiedCheckpointSummary) -> SuiResult<Committee> {
+        let latest_epoch = self.latest_committee().epoch;
+        let epoch = checkpoint.data().epoch;
+        if epoch != latest_epoch {
+            return Err(SuiError::WrongEpoch {
+                expected_epoch: latest_epoch,
+                actual_epoch: epoch,
+            });
+        }
+
+        let next_committee = self
+            .committee_store
+            .insert_committee_from_end_of_epoch_checkpoint(&checkpoint)?;
+        info!(
+            "Light client advanced to epoch {} at checkpoint {}",
+            next_committee.epoch,
+            checkpoint.data().sequence_number
+        );
+        Ok(next_committee)
+    }
+
+    /// Follow a sequence of end-of-epoch checkpoints, ordered by epoch, from the latest known
+    /// epoch. Returns the latest committee.
+    pub fn advance_epochs(
+        &self,
+        checkpoints: impl IntoIterator<Item = CertifiedCheckpointSummary>,
+    ) -> SuiResult<Committee> {
+        for checkpoint in checkpoints {
+            self.advance_epoch(checkpoint)?;
+        }
+        Ok(self.latest_committee())
+    }
+
+    /// Verify that the transaction with `effects` is included in the checkpoint of `proof`, and
+    /// that the checkpoint is certified. Returns the verified checkpoint.
+    pub fn verify_transaction_inclusion(
+        &self,
+        proof: &TransactionInclusionProof,
+        effects: &TransactionEffects,
+    ) -> SuiResult<VerifiedCheckpoint> {
+        let checkpoint = self.verify_checkpoint(proof.checkpoint.clone())?;
+
+        let content_digest = proof.contents.digest();
+        if content_digest != checkpoint.data().content_digest {
+            return Err(SuiError::from(
+                format!(
+                    "Checkpoint {} contents digest mismatch: summary has {}, proof contents have {}",
+                    checkpoint.sequence_number,
+                    checkpoint.data().content_digest,
+                    content_digest
+                )
+                .as_str(),
+            ));
+        }
+
+        let execution_digests =
+            ExecutionDigests::new(*effects.transaction_digest(), effects.digest());
+        if !proof.contents.iter().any(|d| d == &execution_digests) {
+            return Err(SuiError::from(
+                format!(
+                    "Transaction {} with effects {} is not included in checkpoint {}",
+                    execution_digests.transaction,
+                    execution_digests.effects,
+                    checkpoint.sequence_number
+                )
+                .as_str(),
+            ));
+        }
+        Ok(checkpoint)
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Local re-execution of historical transactions.
+//!
+//! The replayer loads a transaction and its effects from an archival source, reconstructs the
+//! input objects at the versions the transaction originally observed, and re-executes it under
+//! the protocol config of the epoch it was executed in. The resulting effects are compared field
+//! by field against the recorded effects, which makes it possible to track down nondeterminism
+//! reports without writing a one-off script for every incident.
+
+use std::sync::Arc;
+
+use sui_adapter::adapter::new_move_vm;
+use sui_adapter::execution_engine;
+use sui_adapter::execution_mode;
+use sui_protocol_config::ProtocolConfig;
+use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, TransactionDigest};
+use sui_types::committee::{EpochId, ProtocolVersion};
+use sui_types::epoch_data::EpochData;
+use sui_types::error::{SuiError, SuiResult, UserInputError};
+use sui_types::gas::{self, SuiCostTable, SuiGasStatus};
+use sui_types::messages::{
+    InputObjectKind, InputObjects, TransactionDataAPI, TransactionEffects, TransactionEffectsAPI,
+    VerifiedTransaction,
+};
+use sui_types::messages_checkpoint::{CheckpointDigest, CheckpointTimestamp};
+use sui_types::object::{Object, Owner};
+use sui_types::storage::{BackingPackageStore, ChildObjectResolver, ParentSync};
+use sui_types::temporary_store::{InnerTemporaryStore, TemporaryStore};
+use sui_types::{MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS};
+use tracing::{info, instrument};
+
+/// Static information about an epoch needed to re-execute transactions from it.
+#[derive(Clone, Debug)]
+pub struct ReplayEpochInfo {
+    pub protocol_version: ProtocolVersion,
+    pub epoch_start_timestamp_ms: CheckpointTimestamp,
+    /// Digest of the last checkpoint of the previous epoch.
+    pub epoch_digest: CheckpointDigest,
+}
+
+/// An archival source of historical transactions, objects and epoch information.
+///
+/// Unlike the live authority store, every read is versioned so that the replayed transaction
+/// observes exactly the state it observed when it was originally executed.
+pub trait ReplayStore {
+    fn get_transaction_and_effects(
+        &self,
+        digest: &TransactionDigest,
+    ) -> SuiResult<Option<(VerifiedTransaction, TransactionEffects)>>;
+
+    fn get_object_by_key(
+        &self,
+        object_id: &ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult<Option<Object>>;
+
+    /// Returns the latest version of the object which is less than or equal to `version`.
+    fn find_object_lt_or_eq_version(
+        &self,
+        object_id: &ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult<Option<Object>>;
+
+    fn get_epoch_info(&self, epoch: EpochId) -> SuiResult<Option<ReplayEpochInfo>>;
+}
+
+/// A single field which differs between the recorded and the replayed effects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectsDiff {
+    pub field: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+pub struct ReplayResult {
+    pub expected_effects: TransactionEffects,
+    pub actual_effects: TransactionEffects,
+    pub inner_temporary_store: InnerTemporaryStore,
+    pub diffs: Vec<EffectsDiff>,
+}
+
+impl ReplayResult {
+    pub fn is_consistent(&self) -> bool {
+        self.diffs.is_empty()
+    }
+}
+
+pub struct TransactionReplayer<S> {
+    store: Arc<S>,
+}
+
+impl<S> TransactionReplayer<S>
+where
+    S: ReplayStore + Send + Sync + 'static,
+{
+    pub fn new(store: Arc<S>) -> Self {
+        Self { store }
+    }
+
+    /// Re-executes the transaction with the given digest and diffs the resulting effects against
+    /// the recorded ones.
+    #[instrument(level = "debug", skip(self))]
+    pub fn replay_transaction(&self, digest: &TransactionDigest) -> SuiResult<ReplayResult> {
+        let (transaction, expected_effects) = self
+            .store
+            .get_transaction_and_effects(digest)?
+            .ok_or(SuiError::TransactionNotFound { digest: *digest })?;
+
+        let epoch = expected_effects.executed_epoch();
+        let epoch_info = self.store.get_epoch_info(epoch)?.ok_or_else(|| {
+            SuiError::GenericStorageError(format!("Missing epoch info for epoch {epoch}"))
+        })?;
+        let protocol_config = ProtocolConfig::get_for_version(epoch_info.protocol_version);
+        let epoch_data = EpochData::new(
+            epoch,
+            epoch_info.epoch_start_timestamp_ms,
+            epoch_info.epoch_digest,
+        );
+
+        let transaction_data = transaction.data().transaction_data();
+        let input_objects = self.load_input_objects(transaction_data, &expected_effects)?;
+        let gas_status = Self::replay_gas_status(transaction_data, &protocol_config)?;
+
+        // Objects written by the transaction are all assigned the lamport version, so any object
+        // version execution could have observed is strictly below it.
+        let lamport_version = input_objects.lamport_timestamp();
+        let shared_object_refs = input_objects.filter_shared_objects();
+        let transaction_dependencies = input_objects.transaction_dependencies();
+        let temporary_store = TemporaryStore::new(
+            VersionedReplayStore {
+                store: self.store.clone(),
+                max_version: SequenceNumber::from_u64(lamport_version.value() - 1),
+            },
+            input_objects,
+            *digest,
+            &protocol_config,
+        );
+
+        let native_functions =
+            sui_framework::natives::all_natives(MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS);
+        let move_vm = Arc::new(
+            new_move_vm(native_functions, &protocol_config)
+                .expect("We defined natives to not fail here"),
+        );
+        let (kind, signer, gas) = transaction_data.execution_parts();
+        let (inner_temporary_store, actual_effects, _execution_error) =
+            execution_engine::execute_transaction_to_effects::<execution_mode::Normal, _>(
+                shared_object_refs,
+                temporary_store,
+                kind,
+                signer,
+                &gas,
+                *digest,
+                transaction_dependencies,
+                &move_vm,
+                gas_status,
+                &epoch_data,
+                &protocol_config,
+            );
+
+        let diffs = diff_effects(&expected_effects, &actual_effects);
+        info!(
+            tx_digest = ?digest,
+            num_diffs = diffs.len(),
+            "Replayed transaction"
+        );
+        Ok(ReplayResult {
+            expected_effects,
+            actual_effects,
+            inner_temporary_store,
+            diffs,
+        })
+    }
+
+    fn load_input_objects(
+        &self,
+        transaction_data: &impl TransactionDataAPI,
+        effects: &TransactionEffects,
+    ) -> SuiResult<InputObjects> {
+        let mut objects = vec![];
+        for kind in transaction_data.input_objects()? {
+            let object = match kind {
+                // Packages are immutable, so the latest version is the one the transaction used.
+                InputObjectKind::MovePackage(id) => self
+                    .store
+                    .find_object_lt_or_eq_version(&id, SequenceNumber::MAX)?,
+                InputObjectKind::ImmOrOwnedMoveObject((id, version, _)) => {
+                    self.store.get_object_by_key(&id, version)?
+                }
+                // The version of a shared object is only known once it is sequenced, so take the
+                // version recorded in the effects.
+                InputObjectKind::SharedMoveObject { id, .. } => {
+                    match effects
+                        .shared_objects()
+                        .iter()
+                        .find(|(oid, _, _)| *oid == id)
+                    {
+                        Some((_, version, _)) => self.store.get_object_by_key(&id, *version)?,
+                        None => None,
+                    }
+                }
+            };
+            let object = object.ok_or(UserInputError::ObjectNotFound {
+                object_id: kind.object_id(),
+                version: kind.version(),
+            })?;
+            objects.push((kind, object));
+        }
+        Ok(InputObjects::new(objects))
+    }
+
+    fn replay_gas_status(
+        transaction_data: &impl TransactionDataAPI,
+        protocol_config: &ProtocolConfig,
+    ) -> SuiResult<SuiGasStatus<'static>> {
+        if transaction_data.is_system_tx() {
+            return Ok(SuiGasStatus::new_unmetered());
+        }
+        // Balance and reference gas price checks already passed when the transaction was
+        // originally executed, only the metering itself needs to be reproduced.
+        Ok(gas::start_gas_metering(
+            transaction_data.gas_budget(),
+            transaction_data.gas_price(),
+            protocol_config.storage_gas_price(),
+            SuiCostTable::new(protocol_config),
+        )?)
+    }
+}
+
+/// Adapts a [`ReplayStore`] to the stores required by execution, hiding every object version
+/// written at or after the replayed transaction.
+struct VersionedReplayStore<S> {
+    store: Arc<S>,
+    max_version: SequenceNumber,
+}
+
+impl<S: ReplayStore> VersionedReplayStore<S> {
+    fn find_object_before_replay(&self, object_id: &ObjectID) -> SuiResult<Option<Object>> {
+        self.store
+            .find_object_lt_or_eq_version(object_id, self.max_version)
+    }
+}
+
+impl<S: ReplayStore> BackingPackageStore for VersionedReplayStore<S> {
+    fn get_package(&self, package_id: &ObjectID) -> SuiResult<Option<Object>> {
+        self.find_object_before_replay(package_id)
+    }
+}
+
+impl<S: ReplayStore> ChildObjectResolver for VersionedReplayStore<S> {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        let child_object = match self.find_object_before_replay(child)? {
+            None => return Ok(None),
+            Some(o) => o,
+        };
+        let parent = *parent;
+        if child_object.owner != Owner::ObjectOwner(parent.into()) {
+            return Err(SuiError::InvalidChildObjectAccess {
+                object: *child,
+                given_parent: parent,
+                actual_owner: child_object.owner,
+            });
+        }
+        Ok(Some(child_object))
+    }
+}
+
+impl<S: ReplayStore> ParentSync for VersionedReplayStore<S> {
+    fn get_latest_parent_entry_ref(&self, object_id: ObjectID) -> SuiResult<Option<ObjectRef>> {
+        Ok(self
+            .find_object_before_replay(&object_id)?
+            .map(|o| o.compute_object_reference()))
+    }
+}
+
+/// Compares two sets of effects field by field, returning every field that differs.
+pub fn diff_effects(
+    expected: &TransactionEffects,
+    actual: &TransactionEffects,
+) -> Vec<EffectsDiff> {
+    let mut diffs = vec![];
+    let mut check = |field: &'static str, expected: String, actual: String| {
+        if expected != actual {
+            diffs.push(EffectsDiff {
+                field,
+                expected,
+                actual,
+            });
+        }
+    };
+    check(
+        "status",
+        format!("{:?}", expected.status()),
+        format!("{:?}", actual.status()),
+    );
+    check(
+        "executed_epoch",
+        format!("{:?}", expected.executed_epoch()),
+        format!("{:?}", actual.executed_epoch()),
+    );
+    check(
+        "gas_used",
+        format!("{:?}", expected.gas_cost_summary()),
+        format!("{:?}", actual.gas_cost_summary()),
+    );
+    check(
+        "modified_at_versions",
+        format!("{:?}", sorted(expected.modified_at_versions())),
+        format!("{:?}", sorted(actual.modified_at_versions())),
+    );
+    check(
+        "shared_objects",
+        format!("{:?}", sorted(expected.shared_objects())),
+        format!("{:?}", sorted(actual.shared_objects())),
+    );
+    check(
+        "created",
+        format!("{:?}", sorted(expected.created())),
+        format!("{:?}", sorted(actual.created())),
+    );
+    check(
+        "mutated",
+        format!("{:?}", sorted(expected.mutated())),
+        format!("{:?}", sorted(actual.mutated())),
+    );
+    check(
+        "unwrapped",
+        format!("{:?}", sorted(expected.unwrapped())),
+        format!("{:?}", sorted(actual.unwrapped())),
+    );
+    check(
+        "deleted",
+        format!("{:?}", sorted(expected.deleted())),
+        format!("{:?}", sorted(actual.deleted())),
+    );
+    check(
+        "unwrapped_then_deleted",
+        format!("{:?}", sorted(expected.unwrapped_then_deleted())),
+        format!("{:?}", sorted(actual.unwrapped_then_deleted())),
+    );
+    check(
+        "wrapped",
+        format!("{:?}", sorted(expected.wrapped())),
+        format!("{:?}", sorted(actual.wrapped())),
+    );
+    check(
+        "gas_object",
+        format!("{:?}", expected.gas_object()),
+        format!("{:?}", actual.gas_object()),
+    );
+    check(
+        "events_digest",
+        format!("{:?}", expected.events_digest()),
+        format!("{:?}", actual.events_digest()),
+    );
+    check(
+        "dependencies",
+        format!("{:?}", sorted(expected.dependencies())),
+        format!("{:?}", sorted(actual.dependencies())),
+    );
+    diffs
+}
+
+// Effects produced by different versions of the execution engine may list objects in a
+// different order without being semantically different.
+fn sorted<T: Clone + Ord>(items: &[T]) -> Vec<T> {
+    let mut items = items.to_vec();
+    items.sort();
+    items
+}
+
+#[cfg(test)]
+#[path = "unit_tests/replay_tests.rs"]
+mod replay_tests;
@@ -12,7 +12,6 @@ use sui_types::messages::{TransactionEffects, TransactionEvents};
 use sui_types::messages_checkpoint::CheckpointContentsDigest;
 use sui_types::messages_checkpoint::CheckpointDigest;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
-use sui_types::messages_checkpoint::EndOfEpochData;
 use sui_types::messages_checkpoint::FullCheckpointContents;
 use sui_types::messages_checkpoint::VerifiedCheckpoint;
 use sui_types::messages_checkpoint::VerifiedCheckpointContents;
@@ -119,15 +118,12 @@ impl ReadStore for RocksDbStore {
 
 impl WriteStore for RocksDbStore {
     fn insert_checkpoint(&self, checkpoint: VerifiedCheckpoint) -> Result<(), Self::Error> {
-        if let Some(EndOfEpochData {
-            next_epoch_committee,
-            ..
-        }) = checkpoint.end_of_epoch_data.as_ref()
-        {
-            let next_committee = next_epoch_committee.iter().cloned().collect();
-            let committee = Committee::new(checkpoint.epoch().saturating_add(1), next_committee)
-                .expect("new committee from consensus should be constructable");
-            self.insert_committee(committee)?;
+        if checkpoint.end_of_epoch_data.is_some() {
+            self.committee_store
+                .insert_committee_from_end_of_epoch_checkpoint(checkpoint.inner())
+                .expect(
+                    "new committee from a verified end-of-epoch checkpoint should be insertable",
+                );
         }
 
         self.checkpoint_store.insert_verified_checkpoint(checkpoint)
@@ -15,11 +15,13 @@ use crate::quorum_driver::{QuorumDriverHandler, QuorumDriverHandlerBuilder, Quor
 use crate::safe_client::SafeClientMetricsBase;
 use mysten_metrics::histogram::{Histogram, HistogramTimerGuard, HistogramVec};
 use mysten_metrics::spawn_monitored_task;
+use parking_lot::Mutex;
 use prometheus::core::{AtomicI64, AtomicU64, GenericCounter, GenericGauge};
 use prometheus::{
     register_int_counter_vec_with_registry, register_int_counter_with_registry,
     register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Registry,
 };
+use std::collections::{HashMap, VecDeque};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -30,11 +32,12 @@ use sui_types::committee::Committee;
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse,
-    FinalizedEffects, QuorumDriverResponse, TransactionEffectsAPI,
+    FinalizedEffects, QuorumDriverResponse, Transaction, TransactionEffects, TransactionEffectsAPI,
     VerifiedCertifiedTransactionEffects, VerifiedExecutableTransaction,
 };
 use sui_types::quorum_driver_types::{
     QuorumDriverEffectsQueueResult, QuorumDriverError, QuorumDriverResult,
+    TransactionSubmissionStatus,
 };
 use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Receiver;
@@ -50,11 +53,16 @@ const LOCAL_EXECUTION_TIMEOUT: Duration = Duration::from_secs(5);
 
 const WAIT_FOR_FINALITY_TIMEOUT: Duration = Duration::from_secs(30);
 
+// How many results of finished transactions are kept for clients polling the status of
+// enqueued transactions.
+const MAX_RECENT_RESULTS: usize = 10_000;
+
 pub struct TransactiondOrchestrator<A> {
     quorum_driver_handler: Arc<QuorumDriverHandler<A>>,
     validator_state: Arc<AuthorityState>,
     _local_executor_handle: JoinHandle<()>,
     pending_tx_log: Arc<WritePathPendingTransactionLog>,
+    recent_results: Arc<Mutex<RecentResults>>,
     notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
     metrics: Arc<TransactionOrchestratorMetrics>,
 }
@@ -124,12 +132,15 @@ where
             parent_path.join("fullnode_pending_transactions"),
         ));
         let pending_tx_log_clone = pending_tx_log.clone();
+        let recent_results = Arc::new(Mutex::new(RecentResults::new(MAX_RECENT_RESULTS)));
+        let recent_results_clone = recent_results.clone();
         let _local_executor_handle = {
             spawn_monitored_task!(async move {
                 Self::loop_execute_finalized_tx_locally(
                     state_clone,
                     effects_receiver,
                     pending_tx_log_clone,
+                    recent_results_clone,
                     metrics_clone,
                 )
                 .await;
@@ -141,6 +152,7 @@ where
             validator_state,
             _local_executor_handle,
             pending_tx_log,
+            recent_results,
             notifier,
             metrics,
         }
@@ -247,6 +259,80 @@ where
         }
     }
 
+    /// Enqueues the transaction for execution and returns without waiting for finality. The
+    /// returned digest is the ticket to poll the transaction status with `submission_status`.
+    /// Retries and conflicting transactions are handled by Quorum Driver, and the transaction is
+    /// resubmitted from the pending transaction log if the node restarts before finality.
+    #[instrument(name = "tx_orchestrator_enqueue_transaction", level = "debug", skip_all,
+    fields(tx_digest = ?transaction.digest()),
+    err)]
+    pub async fn enqueue_transaction(
+        &self,
+        transaction: Transaction,
+    ) -> Result<TransactionDigest, QuorumDriverError> {
+        let transaction = transaction
+            .verify()
+            .map_err(QuorumDriverError::InvalidUserSignature)?;
+        let tx_digest = *transaction.digest();
+        self.enqueue(transaction).await.map_err(|e| {
+            warn!(?tx_digest, "QuorumDriverInternalError: {e:?}");
+            QuorumDriverError::QuorumDriverInternalError(e)
+        })?;
+        Ok(tx_digest)
+    }
+
+    async fn enqueue(&self, transaction: VerifiedTransaction) -> SuiResult {
+        let tx_digest = *transaction.digest();
+        if self.validator_state.is_tx_already_executed(&tx_digest)? {
+            debug!(?tx_digest, "Enqueued transaction is already executed.");
+            return Ok(());
+        }
+        // A transaction enqueued again after failing is submitted anew.
+        self.recent_results.lock().remove(&tx_digest);
+        if self
+            .pending_tx_log
+            .write_pending_transaction_maybe(&transaction)
+            .await?
+        {
+            debug!(?tx_digest, "no pending request in flight, enqueuing.");
+            self.quorum_driver()
+                .submit_transaction_no_ticket(transaction)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Status of a transaction enqueued with `enqueue_transaction`. Results are kept for the
+    /// most recent transactions only, older ones are `Unknown` unless executed by this node.
+    pub fn submission_status(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> SuiResult<TransactionSubmissionStatus> {
+        if let Some(effects) = self
+            .validator_state
+            .database
+            .get_executed_effects(tx_digest)?
+        {
+            return Ok(TransactionSubmissionStatus::Finalized {
+                effects,
+                executed_locally: true,
+            });
+        }
+        if let Some(result) = self.recent_results.lock().get(tx_digest) {
+            return Ok(match result {
+                Ok(effects) => TransactionSubmissionStatus::Finalized {
+                    effects: effects.clone(),
+                    executed_locally: false,
+                },
+                Err(err) => TransactionSubmissionStatus::Failed(err.clone()),
+            });
+        }
+        if self.pending_tx_log.is_pending(tx_digest)? {
+            return Ok(TransactionSubmissionStatus::Pending);
+        }
+        Ok(TransactionSubmissionStatus::Unknown)
+    }
+
     /// Submits the transaction for execution queue, returns a Future to be awaited
     async fn submit(
         &self,
@@ -340,6 +426,7 @@ where
         validator_state: Arc<AuthorityState>,
         mut effects_receiver: Receiver<QuorumDriverEffectsQueueResult>,
         pending_transaction_log: Arc<WritePathPendingTransactionLog>,
+        recent_results: Arc<Mutex<RecentResults>>,
         metrics: Arc<TransactionOrchestratorMetrics>,
     ) {
         loop {
@@ -350,6 +437,11 @@ where
                         effects_cert.executed_epoch(),
                     );
                     let tx_digest = executable_tx.digest();
+                    // Recorded before the transaction leaves the pending log, so that polling
+                    // clients never see it as unknown.
+                    recent_results
+                        .lock()
+                        .insert(*tx_digest, Ok(effects_cert.data().clone()));
                     if let Err(err) = pending_transaction_log.finish_transaction(tx_digest) {
                         error!(
                             ?tx_digest,
@@ -364,7 +456,8 @@ where
                     )
                     .await;
                 }
-                Ok(Err((tx_digest, _err))) => {
+                Ok(Err((tx_digest, err))) => {
+                    recent_results.lock().insert(tx_digest, Err(err));
                     if let Err(err) = pending_transaction_log.finish_transaction(&tx_digest) {
                         error!(
                             ?tx_digest,
@@ -449,6 +542,52 @@ where
     }
 }
 
+/// Results of recently finished transactions, the oldest results are evicted once `capacity`
+/// is reached.
+struct RecentResults {
+    results: HashMap<TransactionDigest, Result<TransactionEffects, QuorumDriverError>>,
+    order: VecDeque<TransactionDigest>,
+    capacity: usize,
+}
+
+impl RecentResults {
+    fn new(capacity: usize) -> Self {
+        Self {
+            results: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn insert(
+        &mut self,
+        tx_digest: TransactionDigest,
+        result: Result<TransactionEffects, QuorumDriverError>,
+    ) {
+        if self.results.insert(tx_digest, result).is_none() {
+            self.order.push_back(tx_digest);
+        }
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.results.remove(&evicted);
+            }
+        }
+    }
+
+    fn get(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Option<&Result<TransactionEffects, QuorumDriverError>> {
+        self.results.get(tx_digest)
+    }
+
+    fn remove(&mut self, tx_digest: &TransactionDigest) {
+        if self.results.remove(tx_digest).is_some() {
+            self.order.retain(|digest| digest != tx_digest);
+        }
+    }
+}
+
 /// Prometheus metrics which can be displayed in Grafana, queried and alerted on
 #[derive(Clone)]
 pub struct TransactionOrchestratorMetrics {
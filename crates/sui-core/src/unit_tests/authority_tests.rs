@@ -33,7 +33,8 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use sui_json_rpc_types::{
-    SuiExecutionResult, SuiExecutionStatus, SuiGasCostSummary, SuiTransactionEffectsAPI,
+    SuiExecutionResult, SuiExecutionStatus, SuiGasBreakdown, SuiGasCostSummary,
+    SuiTransactionEffectsAPI,
 };
 use sui_types::error::UserInputError;
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
@@ -256,6 +257,53 @@ async fn test_dry_run_transaction() {
     assert_eq!(shared_object_version, initial_shared_object_version);
 }
 
+#[tokio::test]
+async fn test_dry_run_gas_smash() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let (_validator, fullnode) = init_state_validator_with_fullnode().await;
+    let mut coins = vec![];
+    for _ in 0..3 {
+        let coin = Object::with_id_owner_gas_for_testing(ObjectID::random(), sender, 100_000);
+        coins.push(coin.compute_object_reference());
+        fullnode.insert_genesis_object(coin).await;
+    }
+    let transaction = TransactionData::new_pay_sui_with_dummy_gas_price(
+        sender,
+        coins[1..].to_vec(),
+        vec![recipient],
+        vec![1_000],
+        coins[0],
+        MAX_GAS,
+    )
+    .unwrap();
+
+    let response = fullnode
+        .dry_exec_transaction(transaction, TransactionDigest::random())
+        .await
+        .unwrap();
+    assert_eq!(*response.effects.status(), SuiExecutionStatus::Success);
+    let gas_smash = response.gas_smash.unwrap();
+    assert_eq!(gas_smash.gas_coin.object_id, coins[0].0);
+    assert_eq!(
+        gas_smash.gas_coin,
+        response.effects.gas_object().reference.clone()
+    );
+    assert_eq!(
+        gas_smash.merged_coins,
+        coins[1..]
+            .iter()
+            .map(|oref| (*oref).into())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(gas_smash.input_balance, 300_000);
+    let gas_used = response.effects.gas_used();
+    assert_eq!(
+        gas_smash.final_balance + 1_000 + gas_used.computation_cost + gas_used.storage_cost,
+        gas_smash.input_balance + gas_used.storage_rebate
+    );
+}
+
 #[tokio::test]
 async fn test_dev_inspect_object_by_bytes() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -265,7 +313,10 @@ async fn test_dev_inspect_object_by_bytes() {
 
     // test normal call
     let DevInspectResults {
-        effects, results, ..
+        effects,
+        results,
+        gas_breakdown,
+        ..
     } = call_dev_inspect(
         &fullnode,
         &sender,
@@ -295,6 +346,17 @@ async fn test_dev_inspect_object_by_bytes() {
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
     assert!(return_values.is_empty());
+    // the call is charged to its command, and the created object is charged its storage
+    let SuiGasBreakdown { commands, objects } = gas_breakdown.unwrap();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].command_index, 0);
+    assert!(commands[0].computation_units > 0);
+    let created = objects
+        .iter()
+        .find(|o| o.object_id == effects.created()[0].reference.object_id)
+        .unwrap();
+    assert!(created.storage_cost > 0);
+    assert_eq!(created.storage_rebate, 0);
     let dev_inspect_gas_summary = effects.gas_used().clone();
 
     // actually make the call to make an object
@@ -7,6 +7,7 @@ use crate::authority_aggregator::authority_aggregator_tests::{
     create_object_move_transaction, do_cert, do_transaction, extract_cert, get_latest_ref,
     transfer_object_move_transaction,
 };
+use crate::execution_driver::ExecutionLane;
 use crate::safe_client::SafeClient;
 use crate::test_authority_clients::LocalAuthorityClient;
 use crate::test_utils::init_local_authorities;
@@ -20,7 +21,8 @@ use sui_types::committee::Committee;
 use sui_types::crypto::{get_key_pair, AccountKeyPair};
 use sui_types::error::SuiResult;
 use sui_types::messages::{
-    TransactionEffects, TransactionEffectsAPI, VerifiedCertificate, VerifiedTransaction,
+    TransactionEffects, TransactionEffectsAPI, VerifiedCertificate, VerifiedExecutableTransaction,
+    VerifiedTransaction,
 };
 use sui_types::object::{Object, Owner};
 use test_utils::messages::{make_counter_create_transaction, make_counter_increment_transaction};
@@ -430,6 +432,25 @@ async fn test_transaction_manager() {
         .notify_read_executed_effects(digests)
         .await
         .unwrap();
+
+    // Owned and shared transactions were executed in their own lanes, which are now drained.
+    for (certs, lane) in [
+        (&executed_owned_certs, ExecutionLane::Owned),
+        (&executed_shared_certs, ExecutionLane::Shared),
+    ] {
+        for cert in certs {
+            let executable = VerifiedExecutableTransaction::new_from_certificate(cert.clone());
+            assert_eq!(ExecutionLane::of(&executable), lane);
+        }
+        assert_eq!(
+            authorities[3]
+                .metrics
+                .execution_driver_lane_queue_depth
+                .with_label_values(&[lane.as_str()])
+                .get(),
+            0
+        );
+    }
 }
 
 async fn try_sign_on_first_three_authorities(
@@ -0,0 +1,243 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use sui_protocol_config::ProtocolVersion;
+use sui_types::base_types::{AuthorityName, TransactionDigest};
+use sui_types::committee::EpochId;
+use sui_types::crypto::{get_key_pair, AuthorityKeyPair, KeypairTraits};
+use sui_types::messages_checkpoint::{
+    CheckpointSequenceNumber, CheckpointSummary, EndOfEpochData, SignedCheckpointSummary,
+};
+
+fn certify(
+    summary: CheckpointSummary,
+    committee: &Committee,
+    keys: &[AuthorityKeyPair],
+) -> CertifiedCheckpointSummary {
+    let signatures = keys
+        .iter()
+        .map(|key| {
+            SignedCheckpointSummary::sign(
+                summary.epoch,
+                &summary,
+                key,
+                AuthorityName::from(key.public()),
+            )
+        })
+        .collect();
+    CertifiedCheckpointSummary::new(summary, signatures, committee).unwrap()
+}
+
+fn summary(
+    epoch: EpochId,
+    sequence_number: CheckpointSequenceNumber,
+    contents: &CheckpointContents,
+    end_of_epoch_data: Option<EndOfEpochData>,
+) -> CheckpointSummary {
+    CheckpointSummary::new(
+        epoch,
+        sequence_number,
+        0,
+        contents,
+        None,
+        Default::default(),
+        end_of_epoch_data,
+        0,
+    )
+}
+
+fn committee_keys(epoch: EpochId) -> (Committee, Vec<AuthorityKeyPair>) {
+    let keys: Vec<AuthorityKeyPair> = (0..4)
+        .map(|_| get_key_pair::<AuthorityKeyPair>().1)
+        .collect();
+    let committee = Committee::new(
+        epoch,
+        keys.iter()
+            .map(|key| (AuthorityName::from(key.public()), 1))
+            .collect(),
+    )
+    .unwrap();
+    (committee, keys)
+}
+
+fn end_of_epoch_data(next_committee: &Committee) -> EndOfEpochData {
+    EndOfEpochData {
+        next_epoch_committee: next_committee.voting_rights.clone(),
+        next_epoch_protocol_version: ProtocolVersion::MIN,
+        epoch_commitments: vec![],
+    }
+}
+
+#[test]
+fn test_light_client_follows_committees() {
+    let (genesis_committee, genesis_keys) = committee_keys(0);
+    let (committee_1, keys_1) = committee_keys(1);
+    let (committee_2, _) = committee_keys(2);
+    let light_client = LightClient::new(Arc::new(CommitteeStore::new_for_testing(
+        &genesis_committee,
+    )));
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(vec![]);
+
+    // Checkpoints of epoch 1 can't be verified before the end of epoch 0 is known.
+    let checkpoint_1 = certify(summary(1, 11, &contents, None), &committee_1, &keys_1);
+    assert!(matches!(
+        light_client.verify_checkpoint(checkpoint_1.clone()),
+        Err(SuiError::MissingCommitteeAtEpoch(1))
+    ));
+
+    // A checkpoint that does not end the epoch does not advance it.
+    let checkpoint_0 = certify(
+        summary(0, 5, &contents, None),
+        &genesis_committee,
+        &genesis_keys,
+    );
+    assert!(light_client.advance_epoch(checkpoint_0).is_err());
+
+    // The end of epoch 1 can't be applied before the end of epoch 0.
+    let end_of_epoch_1 = certify(
+        summary(1, 20, &contents, Some(end_of_epoch_data(&committee_2))),
+        &committee_1,
+        &keys_1,
+    );
+    assert!(matches!(
+        light_client.advance_epoch(end_of_epoch_1.clone()),
+        Err(SuiError::WrongEpoch { .. })
+    ));
+
+    // End of epoch 0 signed by the wrong committee is rejected.
+    let end_of_epoch_0 = summary(0, 10, &contents, Some(end_of_epoch_data(&committee_1)));
+    let (other_committee, other_keys) = committee_keys(0);
+    let forged = certify(end_of_epoch_0.clone(), &other_committee, &other_keys);
+    assert!(light_client.advance_epoch(forged).is_err());
+    assert_eq!(light_client.latest_committee(), genesis_committee);
+
+    let end_of_epoch_0 = certify(end_of_epoch_0, &genesis_committee, &genesis_keys);
+    let latest = light_client
+        .advance_epochs(vec![end_of_epoch_0, end_of_epoch_1])
+        .unwrap();
+    assert_eq!(latest, committee_2);
+    light_client.verify_checkpoint(checkpoint_1).unwrap();
+}
+
+#[test]
+fn test_insert_committee_from_end_of_epoch_checkpoint() {
+    let (genesis_committee, genesis_keys) = committee_keys(0);
+    let (committee_1, _) = committee_keys(1);
+    let store = CommitteeStore::new_for_testing(&genesis_committee);
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(vec![]);
+
+    let checkpoint = certify(
+        summary(0, 5, &contents, None),
+        &genesis_committee,
+        &genesis_keys,
+    );
+    assert!(store
+        .insert_committee_from_end_of_epoch_checkpoint(&checkpoint)
+        .is_err());
+
+    let end_of_epoch_0 = summary(0, 10, &contents, Some(end_of_epoch_data(&committee_1)));
+    let (other_committee, other_keys) = committee_keys(0);
+    let forged = certify(end_of_epoch_0.clone(), &other_committee, &other_keys);
+    assert!(store
+        .insert_committee_from_end_of_epoch_checkpoint(&forged)
+        .is_err());
+    assert_eq!(store.get_committee(&1).unwrap(), None);
+    assert_eq!(store.get_epoch_last_checkpoint(0).unwrap(), None);
+
+    let end_of_epoch_0 = certify(end_of_epoch_0, &genesis_committee, &genesis_keys);
+    let committee = store
+        .insert_committee_from_end_of_epoch_checkpoint(&end_of_epoch_0)
+        .unwrap();
+    assert_eq!(committee, committee_1);
+    assert_eq!(store.get_committee(&1).unwrap(), Some(committee_1));
+    assert_eq!(store.get_epoch_last_checkpoint(0).unwrap(), Some(10));
+}
+
+#[test]
+fn test_subscribe_to_epoch_changes() {
+    let (genesis_committee, genesis_keys) = committee_keys(0);
+    let (committee_1, _) = committee_keys(1);
+    let (committee_2, _) = committee_keys(2);
+    let store = CommitteeStore::new_for_testing(&genesis_committee);
+    let mut receiver = store.subscribe();
+    assert_eq!(*receiver.borrow(), genesis_committee);
+    assert!(!receiver.has_changed().unwrap());
+
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(vec![]);
+    let end_of_epoch_0 = certify(
+        summary(0, 10, &contents, Some(end_of_epoch_data(&committee_1))),
+        &genesis_committee,
+        &genesis_keys,
+    );
+    store
+        .insert_committee_from_end_of_epoch_checkpoint(&end_of_epoch_0)
+        .unwrap();
+    assert!(receiver.has_changed().unwrap());
+    assert_eq!(*receiver.borrow_and_update(), committee_1);
+
+    store.insert_new_committee(&committee_2).unwrap();
+    assert!(receiver.has_changed().unwrap());
+    assert_eq!(*receiver.borrow_and_update(), committee_2);
+
+    // Committees inserted again or out of order are not epoch changes.
+    store.insert_new_committee(&committee_2).unwrap();
+    store.insert_new_committee(&committee_1).unwrap();
+    assert!(!receiver.has_changed().unwrap());
+    assert_eq!(store.subscribe().borrow().epoch, 2);
+}
+
+#[test]
+fn test_light_client_verifies_transaction_inclusion() {
+    let (committee, keys) = committee_keys(0);
+    let light_client = LightClient::new(Arc::new(CommitteeStore::new_for_testing(&committee)));
+
+    let effects = TransactionEffects::default();
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(vec![
+        ExecutionDigests::random(),
+        ExecutionDigests::new(*effects.transaction_digest(), effects.digest()),
+    ]);
+    let proof = TransactionInclusionProof {
+        checkpoint: certify(summary(0, 1, &contents, None), &committee, &keys),
+        contents: contents.clone(),
+    };
+    let checkpoint = light_client
+        .verify_transaction_inclusion(&proof, &effects)
+        .unwrap();
+    assert_eq!(checkpoint.sequence_number, 1);
+
+    // Contents that the checkpoint does not commit to are rejected.
+    let other_contents =
+        CheckpointContents::new_with_causally_ordered_transactions(vec![ExecutionDigests::new(
+            *effects.transaction_digest(),
+            effects.digest(),
+        )]);
+    let proof_with_other_contents = TransactionInclusionProof {
+        checkpoint: proof.checkpoint.clone(),
+        contents: other_contents,
+    };
+    assert!(light_client
+        .verify_transaction_inclusion(&proof_with_other_contents, &effects)
+        .is_err());
+
+    // Transactions missing from the checkpoint are rejected.
+    let mut other_effects = TransactionEffects::default();
+    *other_effects.transaction_digest_mut_for_testing() = TransactionDigest::random();
+    assert!(light_client
+        .verify_transaction_inclusion(&proof, &other_effects)
+        .is_err());
+
+    // Checkpoints not certified by the committee are rejected.
+    let (other_committee, other_keys) = committee_keys(0);
+    let forged_proof = TransactionInclusionProof {
+        checkpoint: certify(
+            summary(0, 1, &contents, None),
+            &other_committee,
+            &other_keys,
+        ),
+        contents,
+    };
+    assert!(light_client
+        .verify_transaction_inclusion(&forged_proof, &effects)
+        .is_err());
+}
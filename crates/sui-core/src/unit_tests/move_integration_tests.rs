@@ -401,6 +401,20 @@ async fn test_object_owning_another_object() {
     .await
     .unwrap();
     effects.status().unwrap();
+    // The transfer event of the child reports the sender as its previous owner.
+    let events = authority
+        .database
+        .get_events(&effects.events_digest().unwrap())
+        .unwrap()
+        .data;
+    let child_transfer = events
+        .iter()
+        .find(|e| e.event_type() == EventType::TransferObject && e.object_id() == Some(child.0))
+        .unwrap();
+    assert_eq!(
+        child_transfer.previous_owner(),
+        Some(&Owner::AddressOwner(sender))
+    );
     let child_effect = effects
         .mutated()
         .iter()
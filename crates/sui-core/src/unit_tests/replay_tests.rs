@@ -0,0 +1,49 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use sui_types::base_types::{random_object_ref, ObjectDigest};
+use sui_types::gas::GasCostSummary;
+use sui_types::messages::ExecutionStatus;
+
+#[test]
+fn test_diff_identical_effects() {
+    let effects = TransactionEffects::default();
+    assert!(diff_effects(&effects, &effects.clone()).is_empty());
+}
+
+#[test]
+fn test_diff_ignores_object_ordering() {
+    let first = random_object_ref();
+    let second = random_object_ref();
+
+    let mut expected = TransactionEffects::default();
+    *expected.modified_at_versions_mut_for_testing() =
+        vec![(first.0, first.1), (second.0, second.1)];
+    let mut actual = expected.clone();
+    *actual.modified_at_versions_mut_for_testing() = vec![(second.0, second.1), (first.0, first.1)];
+
+    assert!(diff_effects(&expected, &actual).is_empty());
+}
+
+#[test]
+fn test_diff_reports_changed_fields() {
+    let expected = TransactionEffects::default();
+    let mut actual = expected.clone();
+    *actual.status_mut_for_testing() = ExecutionStatus::new_failure(
+        sui_types::messages::ExecutionFailureStatus::InsufficientGas,
+        None,
+    );
+    *actual.gas_cost_summary_mut_for_testing() = GasCostSummary::new(1, 2, 3);
+    *actual.shared_objects_mut_for_testing() = vec![(
+        ObjectID::random(),
+        SequenceNumber::from_u64(1),
+        ObjectDigest::random(),
+    )];
+
+    let fields = diff_effects(&expected, &actual)
+        .into_iter()
+        .map(|diff| diff.field)
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec!["status", "gas_used", "shared_objects"]);
+}
@@ -758,6 +758,25 @@ pub fn legacy_bytecode_instruction_costs() -> Vec<(Bytecode, GasCost)> {
     ]
 }
 
+/// Costs of the instructions of [INITIAL_COST_SCHEDULE] by instruction name, e.g. `MoveLoc`, in
+/// the order of the table.
+pub fn initial_instruction_costs() -> Vec<(String, GasCost)> {
+    let mut instrs = legacy_bytecode_instruction_costs();
+    instrs.sort_by_key(|cost| instruction_key(&cost.0));
+    instrs
+        .into_iter()
+        .map(|(instr, cost)| {
+            // Drop the operands, formatted as `Instr(operand)` or `Instr[operand]`
+            let name = format!("{instr:?}");
+            let name = name
+                .split(|c| c == '(' || c == '[')
+                .next()
+                .unwrap_or_default();
+            (name.to_string(), cost)
+        })
+        .collect()
+}
+
 pub static INITIAL_COST_SCHEDULE: Lazy<CostTable> = Lazy::new(|| {
     let mut instrs = legacy_bytecode_instruction_costs();
     // Note that the DiemVM is expecting the table sorted by instruction order.
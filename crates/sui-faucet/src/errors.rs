@@ -31,6 +31,15 @@ pub enum FaucetError {
     #[error("Coin Transfer Failed `{0}`")]
     Transfer(String),
 
+    #[error("Too many requests, please retry after {0} seconds")]
+    TooManyRequests(u64),
+
+    #[error("Request queue is full, please try again later")]
+    BatchSendQueueFull,
+
+    #[error("Request `{0}` not found")]
+    TaskNotFound(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -22,6 +22,28 @@ pub struct CoinInfo {
     pub transfer_tx_digest: TransactionDigest,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchFaucetReceipt {
+    pub task: String,
+}
+
+/// Status of a request queued with [Faucet::batch_send].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "status", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BatchSendStatus {
+    /// Waiting in the queue, `position` is the number of requests ahead of it.
+    Queued {
+        position: usize,
+    },
+    InProgress,
+    Succeeded {
+        transferred_gas_objects: Vec<CoinInfo>,
+    },
+    Failed {
+        error: String,
+    },
+}
+
 #[async_trait]
 pub trait Faucet {
     /// Send `Coin<SUI>` of the specified amount to the recipient
@@ -31,4 +53,16 @@ pub trait Faucet {
         recipient: SuiAddress,
         amounts: &[u64],
     ) -> Result<FaucetReceipt, FaucetError>;
+
+    /// Queue a request to send `Coin<SUI>` of the specified amounts to the recipient, to be served
+    /// together with other queued requests in a single transaction.
+    async fn batch_send(
+        &self,
+        id: Uuid,
+        recipient: SuiAddress,
+        amounts: &[u64],
+    ) -> Result<BatchFaucetReceipt, FaucetError>;
+
+    /// Status of a request previously queued with `batch_send`
+    async fn get_batch_send_status(&self, task_id: Uuid) -> Result<BatchSendStatus, FaucetError>;
 }
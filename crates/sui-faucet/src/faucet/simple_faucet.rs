@@ -7,8 +7,7 @@ use async_trait::async_trait;
 use prometheus::Registry;
 use tap::tap::TapFallible;
 
-#[cfg(test)]
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::default::Default;
 use std::path::Path;
 
@@ -29,11 +28,11 @@ use tokio::sync::{
     mpsc::{self, Receiver, Sender},
     Mutex,
 };
-use tokio::time::{timeout, Duration};
+use tokio::time::{timeout, Duration, Instant, MissedTickBehavior};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
-use crate::{CoinInfo, Faucet, FaucetError, FaucetReceipt};
+use crate::{BatchFaucetReceipt, BatchSendStatus, CoinInfo, Faucet, FaucetError, FaucetReceipt};
 
 use super::write_ahead_log::WriteAheadLog;
 
@@ -44,6 +43,15 @@ pub struct SimpleFaucet {
     consumer: Mutex<Receiver<ObjectID>>,
     metrics: FaucetMetrics,
     wal: Mutex<WriteAheadLog>,
+    batch_queue: Mutex<VecDeque<BatchRequest>>,
+    batch_status: Mutex<HashMap<Uuid, (Instant, BatchSendStatus)>>,
+}
+
+/// A request waiting in the batch queue.
+struct BatchRequest {
+    id: Uuid,
+    recipient: SuiAddress,
+    amounts: Vec<u64>,
 }
 
 enum GasCoinResponse {
@@ -58,6 +66,9 @@ const DEFAULT_GAS_BUDGET: u64 = 1000;
 const PAY_SUI_GAS: u64 = 1000;
 const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
 const RECV_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_BATCH_QUEUE_SIZE: usize = 10_000;
+/// How long the status of a served batch request is kept around for clients to query.
+const BATCH_STATUS_RETENTION: Duration = Duration::from_secs(600);
 
 impl SimpleFaucet {
     pub async fn new(
@@ -116,6 +127,8 @@ impl SimpleFaucet {
             consumer: Mutex::new(consumer),
             metrics,
             wal: Mutex::new(wal),
+            batch_queue: Mutex::new(VecDeque::new()),
+            batch_status: Mutex::new(HashMap::new()),
         };
 
         // Retrying all the pending transactions from the WAL, before continuing.  Ignore return
@@ -156,14 +169,19 @@ impl SimpleFaucet {
 
     /// Pulls a coin from the queue and makes sure it is fit for use (belongs to the faucet, has
     /// sufficient balance).
-    async fn prepare_gas_coin(&self, total_amount: u64, uuid: Uuid) -> GasCoinResponse {
+    async fn prepare_gas_coin(
+        &self,
+        total_amount: u64,
+        gas_cost: u64,
+        uuid: Uuid,
+    ) -> GasCoinResponse {
         let Some(coin_id) = self.pop_gas_coin(uuid).await else {
             warn!("Failed getting gas coin, try later!");
             return GasCoinResponse::NoGasCoinAvailable;
         };
 
         match self.get_gas_coin_and_check_faucet_owner(coin_id).await {
-            Ok(Some(gas_coin)) if gas_coin.value() >= total_amount + PAY_SUI_GAS => {
+            Ok(Some(gas_coin)) if gas_coin.value() >= total_amount + gas_cost => {
                 info!(?uuid, ?coin_id, "balance: {}", gas_coin.value());
                 GasCoinResponse::ValidGasCoin(coin_id)
             }
@@ -291,25 +309,36 @@ impl SimpleFaucet {
         }
     }
 
+    /// Pay each recipient in `payments` the corresponding amounts, in a single PaySui
+    /// transaction. Recipients must be distinct. Returns the ids of the coins created for each
+    /// recipient, in the order of `payments`.
     async fn transfer_gases(
         &self,
-        amounts: &[u64],
-        recipient: SuiAddress,
+        payments: &[(SuiAddress, &[u64])],
         uuid: Uuid,
-    ) -> Result<(TransactionDigest, Vec<ObjectID>), FaucetError> {
-        let number_of_coins = amounts.len();
-        let total_amount: u64 = amounts.iter().sum();
-
-        let gas_coin_response = self.prepare_gas_coin(total_amount, uuid).await;
+    ) -> Result<(TransactionDigest, Vec<Vec<ObjectID>>), FaucetError> {
+        let total_amount: u64 = payments
+            .iter()
+            .flat_map(|(_, amounts)| amounts.iter())
+            .sum();
+        let number_of_payments = payments.len() as u64;
+        // Only used for logging and the WAL, a batch is logged against its first recipient.
+        let recipient = payments
+            .first()
+            .map(|(recipient, _)| *recipient)
+            .ok_or_else(|| FaucetError::internal("No recipient to transfer gases to"))?;
+
+        let gas_coin_response = self
+            .prepare_gas_coin(total_amount, PAY_SUI_GAS * number_of_payments, uuid)
+            .await;
         match gas_coin_response {
             GasCoinResponse::ValidGasCoin(coin_id) => {
                 let tx_data = self
                     .build_pay_sui_txn(
                         coin_id,
                         self.active_address,
-                        recipient,
-                        amounts,
-                        DEFAULT_GAS_BUDGET,
+                        payments,
+                        DEFAULT_GAS_BUDGET * number_of_payments,
                     )
                     .await
                     .map_err(FaucetError::internal)?;
@@ -326,7 +355,7 @@ impl SimpleFaucet {
                     .sign_and_execute_txn(uuid, recipient, coin_id, tx_data)
                     .await?;
 
-                self.check_and_map_transfer_gas_result(response, number_of_coins, recipient)
+                self.check_and_map_transfer_gas_result(response, payments)
                     .await
             }
 
@@ -434,16 +463,19 @@ impl SimpleFaucet {
         &self,
         coin_id: ObjectID,
         signer: SuiAddress,
-        recipient: SuiAddress,
-        amounts: &[u64],
+        payments: &[(SuiAddress, &[u64])],
         budget: u64,
     ) -> Result<TransactionData, anyhow::Error> {
-        let recipients: Vec<SuiAddress> =
-            std::iter::repeat(recipient).take(amounts.len()).collect();
+        let (recipients, amounts): (Vec<SuiAddress>, Vec<u64>) = payments
+            .iter()
+            .flat_map(|(recipient, amounts)| {
+                amounts.iter().map(move |amount| (*recipient, *amount))
+            })
+            .unzip();
         let client = self.wallet.get_client().await?;
         client
             .transaction_builder()
-            .pay_sui(signer, vec![coin_id], recipients, amounts.to_vec(), budget)
+            .pay_sui(signer, vec![coin_id], recipients, amounts, budget)
             .await
             .map_err(|e| {
                 anyhow::anyhow!(
@@ -457,9 +489,8 @@ impl SimpleFaucet {
     async fn check_and_map_transfer_gas_result(
         &self,
         res: SuiTransactionResponse,
-        number_of_coins: usize,
-        recipient: SuiAddress,
-    ) -> Result<(TransactionDigest, Vec<ObjectID>), FaucetError> {
+        payments: &[(SuiAddress, &[u64])],
+    ) -> Result<(TransactionDigest, Vec<Vec<ObjectID>>), FaucetError> {
         let txns = res
             .transaction
             .as_ref()
@@ -487,22 +518,189 @@ impl SimpleFaucet {
             })?
             .created()
             .to_vec();
+        let number_of_coins: usize = payments.iter().map(|(_, amounts)| amounts.len()).sum();
         if created.len() != number_of_coins {
             panic!(
                 "PaySui Transaction should create exact {:?} new coins, but got {:?}",
                 number_of_coins, created
             );
         }
-        assert!(created
+        let coin_ids = payments
             .iter()
-            .all(|created_coin_owner_ref| created_coin_owner_ref.owner == recipient));
-        let coin_ids: Vec<ObjectID> = created
-            .iter()
-            .map(|created_coin_owner_ref| created_coin_owner_ref.reference.object_id)
+            .map(|(recipient, amounts)| {
+                let coin_ids: Vec<ObjectID> = created
+                    .iter()
+                    .filter(|created_coin_owner_ref| created_coin_owner_ref.owner == *recipient)
+                    .map(|created_coin_owner_ref| created_coin_owner_ref.reference.object_id)
+                    .collect();
+                assert_eq!(
+                    coin_ids.len(),
+                    amounts.len(),
+                    "PaySui Transaction should create {} new coins for {}, but got {:?}",
+                    amounts.len(),
+                    recipient,
+                    created
+                );
+                coin_ids
+            })
             .collect();
         Ok((res.digest, coin_ids))
     }
 
+    async fn get_coin_infos(
+        &self,
+        uuid: Uuid,
+        recipient: SuiAddress,
+        digest: TransactionDigest,
+        coin_ids: Vec<ObjectID>,
+    ) -> Vec<CoinInfo> {
+        let mut sent = Vec::with_capacity(coin_ids.len());
+        let coin_results =
+            futures::future::join_all(coin_ids.iter().map(|coin_id| self.get_coin(*coin_id))).await;
+        for (coin_id, res) in coin_ids.into_iter().zip(coin_results) {
+            let amount = if let Ok(Some((_, coin))) = res {
+                coin.value()
+            } else {
+                info!(
+                    ?recipient,
+                    ?coin_id,
+                    ?uuid,
+                    "Could not find coin after successful transaction, error: {:?}",
+                    &res,
+                );
+                0
+            };
+            sent.push(CoinInfo {
+                transfer_tx_digest: digest,
+                amount,
+                id: coin_id,
+            });
+        }
+        sent
+    }
+
+    /// Serve the requests queued by `batch_send`, taking up to `max_batch_size` requests from the
+    /// queue every `interval` and paying them out in a single transaction. Never returns, so it
+    /// should be run in its own task.
+    pub async fn run_batch_send(&self, max_batch_size: usize, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            self.prune_batch_status().await;
+            self.serve_next_batch(max_batch_size).await;
+        }
+    }
+
+    async fn serve_next_batch(&self, max_batch_size: usize) {
+        let batch = self.take_batch(max_batch_size).await;
+        if batch.is_empty() {
+            return;
+        }
+
+        // ID of the batch transaction, for traceability
+        let uuid = Uuid::new_v4();
+        let payments: Vec<(SuiAddress, &[u64])> = batch
+            .iter()
+            .map(|request| (request.recipient, request.amounts.as_slice()))
+            .collect();
+        info!(
+            ?uuid,
+            requests = ?batch.iter().map(|request| request.id).collect::<Vec<_>>(),
+            "Serving batch of faucet requests"
+        );
+
+        match self.transfer_gases(&payments, uuid).await {
+            Ok((digest, coin_ids)) => {
+                info!(?uuid, ?digest, "Batch PaySui txn succeeded");
+                let sent = futures::future::join_all(batch.iter().zip(coin_ids).map(
+                    |(request, coin_ids)| {
+                        self.get_coin_infos(request.id, request.recipient, digest, coin_ids)
+                    },
+                ))
+                .await;
+                let mut status = self.batch_status.lock().await;
+                for (request, sent) in batch.iter().zip(sent) {
+                    let succeeded = BatchSendStatus::Succeeded {
+                        transferred_gas_objects: sent,
+                    };
+                    status.insert(request.id, (Instant::now(), succeeded));
+                }
+            }
+
+            // The gas coin was not fit for use, which says nothing about the requests themselves,
+            // so they are served again with the next batch.
+            Err(
+                e @ (FaucetError::NoGasCoinAvailable
+                | FaucetError::FullnodeReadingError
+                | FaucetError::InvalidGasCoin(_)
+                | FaucetError::GasCoinWithInsufficientBalance(_)),
+            ) => {
+                warn!(?uuid, "Failed to get gas coin for batch, requeueing: {e}");
+                self.requeue_batch(batch).await;
+            }
+
+            Err(e) => {
+                error!(?uuid, "Batch PaySui txn failed: {e}");
+                let mut status = self.batch_status.lock().await;
+                for request in &batch {
+                    let failed = BatchSendStatus::Failed {
+                        error: e.to_string(),
+                    };
+                    status.insert(request.id, (Instant::now(), failed));
+                }
+            }
+        }
+    }
+
+    /// Take up to `max_batch_size` requests from the front of the queue and mark them in
+    /// progress. A recipient is paid at most once per batch, further requests for it stay queued
+    /// for the next batch.
+    async fn take_batch(&self, max_batch_size: usize) -> Vec<BatchRequest> {
+        let mut queue = self.batch_queue.lock().await;
+        let mut recipients = HashSet::new();
+        let mut batch = vec![];
+        let mut deferred = VecDeque::new();
+        while batch.len() < max_batch_size {
+            let Some(request) = queue.pop_front() else {
+                break;
+            };
+            if recipients.insert(request.recipient) {
+                batch.push(request);
+            } else {
+                deferred.push_back(request);
+            }
+        }
+        deferred.append(&mut queue);
+        *queue = deferred;
+        self.metrics.current_queued_requests.set(queue.len() as i64);
+
+        let mut status = self.batch_status.lock().await;
+        for request in &batch {
+            status.insert(request.id, (Instant::now(), BatchSendStatus::InProgress));
+        }
+        batch
+    }
+
+    /// Put the requests of a batch that could not be served back at the front of the queue.
+    async fn requeue_batch(&self, batch: Vec<BatchRequest>) {
+        let mut queue = self.batch_queue.lock().await;
+        let mut status = self.batch_status.lock().await;
+        for request in batch.into_iter().rev() {
+            status.remove(&request.id);
+            queue.push_front(request);
+        }
+        self.metrics.current_queued_requests.set(queue.len() as i64);
+    }
+
+    async fn prune_batch_status(&self) {
+        let mut status = self.batch_status.lock().await;
+        status.retain(|_, (updated, status)| {
+            matches!(status, BatchSendStatus::InProgress)
+                || updated.elapsed() < BATCH_STATUS_RETENTION
+        });
+    }
+
     #[cfg(test)]
     async fn drain_gas_queue(&mut self, expected_gas_count: usize) -> HashSet<ObjectID> {
         use tokio::sync::mpsc::error::TryRecvError;
@@ -539,33 +737,51 @@ impl Faucet for SimpleFaucet {
     ) -> Result<FaucetReceipt, FaucetError> {
         info!(?recipient, uuid = ?id, "Getting faucet requests");
 
-        let (digest, coin_ids) = self.transfer_gases(amounts, recipient, id).await?;
+        let (digest, mut coin_ids) = self.transfer_gases(&[(recipient, amounts)], id).await?;
 
         info!(uuid = ?id, ?recipient, ?digest, "PaySui txn succeeded");
-        let mut sent = Vec::with_capacity(coin_ids.len());
-        let coin_results =
-            futures::future::join_all(coin_ids.iter().map(|coin_id| self.get_coin(*coin_id))).await;
-        for (coin_id, res) in coin_ids.into_iter().zip(coin_results) {
-            let amount = if let Ok(Some((_, coin))) = res {
-                coin.value()
-            } else {
-                info!(
-                    ?recipient,
-                    ?coin_id,
-                    uuid = ?id,
-                    "Could not find coin after successful transaction, error: {:?}",
-                    &res,
-                );
-                0
-            };
-            sent.push(CoinInfo {
-                transfer_tx_digest: digest,
-                amount,
-                id: coin_id,
-            });
-        }
+        let sent = self
+            .get_coin_infos(id, recipient, digest, coin_ids.swap_remove(0))
+            .await;
         Ok(FaucetReceipt { sent })
     }
+
+    async fn batch_send(
+        &self,
+        id: Uuid,
+        recipient: SuiAddress,
+        amounts: &[u64],
+    ) -> Result<BatchFaucetReceipt, FaucetError> {
+        let mut queue = self.batch_queue.lock().await;
+        if queue.len() >= MAX_BATCH_QUEUE_SIZE {
+            warn!(uuid = ?id, ?recipient, "Batch queue is full, rejecting request");
+            return Err(FaucetError::BatchSendQueueFull);
+        }
+        queue.push_back(BatchRequest {
+            id,
+            recipient,
+            amounts: amounts.to_vec(),
+        });
+        self.metrics.current_queued_requests.set(queue.len() as i64);
+        info!(uuid = ?id, ?recipient, position = queue.len() - 1, "Queued faucet request");
+        Ok(BatchFaucetReceipt {
+            task: id.to_string(),
+        })
+    }
+
+    async fn get_batch_send_status(&self, task_id: Uuid) -> Result<BatchSendStatus, FaucetError> {
+        // Lock order (queue, then status) must match `take_batch` and `requeue_batch`, so that a
+        // request moving between the two is always found in one of them.
+        let queue = self.batch_queue.lock().await;
+        if let Some(position) = queue.iter().position(|request| request.id == task_id) {
+            return Ok(BatchSendStatus::Queued { position });
+        }
+        let status = self.batch_status.lock().await;
+        status
+            .get(&task_id)
+            .map(|(_, status)| status.clone())
+            .ok_or_else(|| FaucetError::TaskNotFound(task_id.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -804,6 +1020,67 @@ mod tests {
         assert!(candidates.get(&tiny_coin_id).is_none());
     }
 
+    #[tokio::test]
+    async fn test_batch_send() {
+        telemetry_subscribers::init_for_testing();
+        let test_cluster = TestClusterBuilder::new().build().await.unwrap();
+
+        let tmp = tempfile::tempdir().unwrap();
+        let prom_registry = Registry::new();
+        let faucet = SimpleFaucet::new(
+            test_cluster.wallet,
+            &prom_registry,
+            &tmp.path().join("faucet.wal"),
+        )
+        .await
+        .unwrap();
+
+        let recipient_a = SuiAddress::random_for_testing_only();
+        let recipient_b = SuiAddress::random_for_testing_only();
+        let amounts = vec![1, 2];
+        let mut tasks = vec![];
+        for recipient in [recipient_a, recipient_b, recipient_a] {
+            let BatchFaucetReceipt { task } = faucet
+                .batch_send(Uuid::new_v4(), recipient, &amounts)
+                .await
+                .unwrap();
+            tasks.push(Uuid::parse_str(&task).unwrap());
+        }
+
+        for (i, task) in tasks.iter().enumerate() {
+            let status = faucet.get_batch_send_status(*task).await.unwrap();
+            assert!(
+                matches!(status, BatchSendStatus::Queued { position } if position == i),
+                "unexpected status {:?}",
+                status
+            );
+        }
+
+        // The second request for the same recipient is deferred to the next batch.
+        faucet.serve_next_batch(10).await;
+        for task in &tasks[..2] {
+            let status = faucet.get_batch_send_status(*task).await.unwrap();
+            let BatchSendStatus::Succeeded { transferred_gas_objects } = status else {
+                panic!("unexpected status {:?}", status);
+            };
+            let mut actual_amounts: Vec<u64> =
+                transferred_gas_objects.iter().map(|c| c.amount).collect();
+            actual_amounts.sort_unstable();
+            assert_eq!(actual_amounts, amounts);
+        }
+        let status = faucet.get_batch_send_status(tasks[2]).await.unwrap();
+        assert!(matches!(status, BatchSendStatus::Queued { position: 0 }));
+
+        faucet.serve_next_batch(10).await;
+        let status = faucet.get_batch_send_status(tasks[2]).await.unwrap();
+        assert!(matches!(status, BatchSendStatus::Succeeded { .. }));
+
+        assert!(matches!(
+            faucet.get_batch_send_status(Uuid::new_v4()).await,
+            Err(FaucetError::TaskNotFound(_))
+        ));
+    }
+
     async fn test_basic_interface(faucet: &impl Faucet) {
         let recipient = SuiAddress::random_for_testing_only();
         let amounts = vec![1, 2, 3];
@@ -3,6 +3,7 @@
 
 use axum::{
     error_handling::HandleErrorLayer,
+    extract::{ConnectInfo, Path},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
@@ -20,7 +21,11 @@ use std::{
 use std::{env, path::PathBuf};
 use sui::client_commands::WalletContext;
 use sui_config::{sui_config_dir, SUI_CLIENT_CONFIG};
-use sui_faucet::{Faucet, FaucetRequest, FaucetResponse, RequestMetricsLayer, SimpleFaucet};
+use sui_faucet::{
+    BatchFaucetResponse, BatchStatusFaucetResponse, Faucet, FaucetError, FaucetRequest,
+    FaucetResponse, RateLimiter, RequestMetricsLayer, SimpleFaucet,
+};
+use sui_types::base_types::SuiAddress;
 use tower::{limit::RateLimitLayer, ServiceBuilder};
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, warn};
@@ -58,12 +63,64 @@ struct FaucetConfig {
 
     #[clap(long)]
     write_ahead_log: PathBuf,
+
+    /// Maximum number of queued requests served together in one transaction.
+    #[clap(long, default_value_t = 50)]
+    batch_request_size: usize,
+
+    #[clap(long, default_value_t = 1000)]
+    batch_interval_ms: u64,
+
+    /// Maximum number of requests per IP address within `rate_limit_interval_secs`, unlimited if
+    /// not set.
+    #[clap(long)]
+    max_requests_per_ip: Option<u64>,
+
+    /// Maximum number of requests per recipient address within `rate_limit_interval_secs`,
+    /// unlimited if not set.
+    #[clap(long)]
+    max_requests_per_address: Option<u64>,
+
+    #[clap(long, default_value_t = 3600)]
+    rate_limit_interval_secs: u64,
 }
 
 struct AppState<F = SimpleFaucet> {
     faucet: F,
     config: FaucetConfig,
-    // TODO: add counter
+    ip_rate_limiter: Option<RateLimiter<IpAddr>>,
+    address_rate_limiter: Option<RateLimiter<SuiAddress>>,
+}
+
+impl<F> AppState<F> {
+    /// Take a token from the rate limits of the requesting IP and of the recipient.
+    fn check_rate_limits(&self, ip: IpAddr, recipient: SuiAddress) -> Result<(), FaucetError> {
+        let check_ip = self
+            .ip_rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.check(ip));
+        let check_address = self
+            .address_rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.check(recipient));
+        match (check_ip, check_address) {
+            (Some(Err(retry_after)), _) | (_, Some(Err(retry_after))) => {
+                // Round up, so that clients retrying after the given time succeed.
+                let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                Err(FaucetError::TooManyRequests(secs))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn prune_rate_limiters(&self) {
+        if let Some(limiter) = &self.ip_rate_limiter {
+            limiter.prune();
+        }
+        if let Some(limiter) = &self.address_rate_limiter {
+            limiter.prune();
+        }
+    }
 }
 
 const PROM_PORT_ADDR: &str = "0.0.0.0:9184";
@@ -89,6 +146,11 @@ async fn main() -> Result<(), anyhow::Error> {
         max_request_per_second,
         wallet_client_timeout_secs,
         ref write_ahead_log,
+        batch_request_size,
+        batch_interval_ms,
+        max_requests_per_ip,
+        max_requests_per_address,
+        rate_limit_interval_secs,
         ..
     } = config;
 
@@ -104,6 +166,29 @@ async fn main() -> Result<(), anyhow::Error> {
             .await
             .unwrap(),
         config,
+        ip_rate_limiter: max_requests_per_ip.map(|capacity| {
+            RateLimiter::new(capacity, Duration::from_secs(rate_limit_interval_secs))
+        }),
+        address_rate_limiter: max_requests_per_address.map(|capacity| {
+            RateLimiter::new(capacity, Duration::from_secs(rate_limit_interval_secs))
+        }),
+    });
+
+    let batch_state = app_state.clone();
+    spawn_monitored_task!(async move {
+        batch_state
+            .faucet
+            .run_batch_send(batch_request_size, Duration::from_millis(batch_interval_ms))
+            .await
+    });
+
+    let prune_state = app_state.clone();
+    spawn_monitored_task!(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(rate_limit_interval_secs));
+        loop {
+            interval.tick().await;
+            prune_state.prune_rate_limiters();
+        }
     });
 
     // TODO: restrict access if needed
@@ -115,6 +200,8 @@ async fn main() -> Result<(), anyhow::Error> {
     let app = Router::new()
         .route("/", get(health))
         .route("/gas", post(request_gas))
+        .route("/v1/gas", post(batch_request_gas))
+        .route("/v1/status/:task_id", get(request_status))
         .layer(
             ServiceBuilder::new()
                 .layer(HandleErrorLayer::new(handle_error))
@@ -134,7 +221,7 @@ async fn main() -> Result<(), anyhow::Error> {
     let addr = SocketAddr::new(IpAddr::V4(host_ip), port);
     info!("listening on {}", addr);
     axum::Server::bind(&addr)
-        .serve(app.into_make_service())
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await?;
     Ok(())
 }
@@ -147,6 +234,7 @@ async fn health() -> &'static str {
 /// handler for all the request_gas requests
 async fn request_gas(
     Extension(state): Extension<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     Json(payload): Json<FaucetRequest>,
 ) -> impl IntoResponse {
     // ID for traceability
@@ -154,6 +242,10 @@ async fn request_gas(
     info!(uuid = ?id, "Got new gas request.");
     let result = match payload {
         FaucetRequest::FixedAmountRequest(requests) => {
+            if let Err(e) = state.check_rate_limits(addr.ip(), requests.recipient) {
+                warn!(uuid = ?id, ip = ?addr.ip(), recipient = ?requests.recipient, "{e}");
+                return (StatusCode::TOO_MANY_REQUESTS, Json(FaucetResponse::from(e)));
+            }
             // We spawn a tokio task for this such that connection drop will not interrupt
             // it and impact the reclycing of coins
             spawn_monitored_task!(async move {
@@ -185,6 +277,67 @@ async fn request_gas(
     }
 }
 
+/// handler for requests queued to be served in batches
+async fn batch_request_gas(
+    Extension(state): Extension<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Json(payload): Json<FaucetRequest>,
+) -> impl IntoResponse {
+    // ID for traceability, and to query the status of the request
+    let id = Uuid::new_v4();
+    info!(uuid = ?id, "Got new batch gas request.");
+    let result = match payload {
+        FaucetRequest::FixedAmountRequest(requests) => {
+            if let Err(e) = state.check_rate_limits(addr.ip(), requests.recipient) {
+                warn!(uuid = ?id, ip = ?addr.ip(), recipient = ?requests.recipient, "{e}");
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    Json(BatchFaucetResponse::from(e)),
+                );
+            }
+            state
+                .faucet
+                .batch_send(
+                    id,
+                    requests.recipient,
+                    &vec![state.config.amount; state.config.num_coins],
+                )
+                .await
+        }
+    };
+    match result {
+        Ok(v) => {
+            info!(uuid =?id, "Request is successfully queued");
+            (StatusCode::ACCEPTED, Json(BatchFaucetResponse::from(v)))
+        }
+        Err(v) => {
+            warn!(uuid =?id, "Failed to queue gas request: {:?}", v);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(BatchFaucetResponse::from(v)),
+            )
+        }
+    }
+}
+
+/// handler for querying the status of requests queued by batch_request_gas
+async fn request_status(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(task_id): Path<String>,
+) -> impl IntoResponse {
+    let result = match Uuid::parse_str(&task_id) {
+        Ok(task_id) => state.faucet.get_batch_send_status(task_id).await,
+        Err(_) => Err(FaucetError::TaskNotFound(task_id)),
+    };
+    match result {
+        Ok(v) => (StatusCode::OK, Json(BatchStatusFaucetResponse::from(v))),
+        Err(v) => (
+            StatusCode::NOT_FOUND,
+            Json(BatchStatusFaucetResponse::from(v)),
+        ),
+    }
+}
+
 async fn create_wallet_context(timeout_secs: u64) -> Result<WalletContext, anyhow::Error> {
     let wallet_conf = sui_config_dir()?.join(SUI_CLIENT_CONFIG);
     info!("Initialize wallet from config path: {:?}", wallet_conf);
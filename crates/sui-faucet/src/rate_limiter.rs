@@ -0,0 +1,132 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket holding up to `capacity` tokens, refilled continuously at a rate of `capacity`
+/// tokens per `refill_interval`. Every request consumes one token.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u64, now: Instant) -> Self {
+        Self {
+            tokens: capacity as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, capacity: u64, refill_interval: Duration, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refilled = elapsed.as_secs_f64() / refill_interval.as_secs_f64() * capacity as f64;
+        self.tokens = (self.tokens + refilled).min(capacity as f64);
+        self.last_refill = now;
+    }
+
+    fn is_full(&self, capacity: u64) -> bool {
+        self.tokens >= capacity as f64
+    }
+}
+
+/// Per-key token bucket rate limiter, used by the faucet to limit requests per IP address and per
+/// recipient address. Buckets that have been refilled completely are dropped by `prune`, so memory
+/// use is bounded by the number of keys active within one refill interval.
+#[derive(Debug)]
+pub struct RateLimiter<K> {
+    capacity: u64,
+    refill_interval: Duration,
+    buckets: Mutex<HashMap<K, TokenBucket>>,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    pub fn new(capacity: u64, refill_interval: Duration) -> Self {
+        assert!(
+            !refill_interval.is_zero(),
+            "Rate limiter refill interval must be positive"
+        );
+        Self {
+            capacity,
+            refill_interval,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take one token from the bucket of `key`. Returns `Err(retry_after)` with the time until a
+    /// token becomes available if the bucket is empty.
+    pub fn check(&self, key: K) -> Result<(), Duration> {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(self.capacity, now));
+        bucket.refill(self.capacity, self.refill_interval, now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - bucket.tokens;
+            Err(Duration::from_secs_f64(
+                missing * self.refill_interval.as_secs_f64() / self.capacity.max(1) as f64,
+            ))
+        }
+    }
+
+    /// Drop the buckets that are full again, they behave the same as a bucket that was never used.
+    pub fn prune(&self) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.retain(|_, bucket| {
+            bucket.refill(self.capacity, self.refill_interval, now);
+            !bucket.is_full(self.capacity)
+        });
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.buckets.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limiter_exhausts_and_refills() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert!(limiter.check_at("a", start).is_ok());
+        assert!(limiter.check_at("a", start).is_ok());
+        let retry_after = limiter.check_at("a", start).unwrap_err();
+        assert_eq!(retry_after, Duration::from_secs(5));
+
+        // Other keys have their own bucket.
+        assert!(limiter.check_at("b", start).is_ok());
+
+        // Half of the interval refills one token.
+        let later = start + Duration::from_secs(5);
+        assert!(limiter.check_at("a", later).is_ok());
+        assert!(limiter.check_at("a", later).is_err());
+    }
+
+    #[test]
+    fn rate_limiter_prunes_full_buckets() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(1));
+        assert!(limiter.check("a").is_ok());
+        assert_eq!(limiter.len(), 1);
+
+        std::thread::sleep(Duration::from_millis(5));
+        limiter.prune();
+        assert_eq!(limiter.len(), 0);
+    }
+}
@@ -27,3 +27,51 @@ impl From<FaucetReceipt> for FaucetResponse {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchFaucetResponse {
+    pub task: Option<String>,
+    pub error: Option<String>,
+}
+
+impl From<FaucetError> for BatchFaucetResponse {
+    fn from(e: FaucetError) -> Self {
+        Self {
+            error: Some(e.to_string()),
+            task: None,
+        }
+    }
+}
+
+impl From<BatchFaucetReceipt> for BatchFaucetResponse {
+    fn from(v: BatchFaucetReceipt) -> Self {
+        Self {
+            task: Some(v.task),
+            error: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BatchStatusFaucetResponse {
+    pub status: Option<BatchSendStatus>,
+    pub error: Option<String>,
+}
+
+impl From<FaucetError> for BatchStatusFaucetResponse {
+    fn from(e: FaucetError) -> Self {
+        Self {
+            error: Some(e.to_string()),
+            status: None,
+        }
+    }
+}
+
+impl From<BatchSendStatus> for BatchStatusFaucetResponse {
+    fn from(v: BatchSendStatus) -> Self {
+        Self {
+            status: Some(v),
+            error: None,
+        }
+    }
+}
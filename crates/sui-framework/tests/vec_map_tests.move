@@ -3,6 +3,8 @@
 
 #[test_only]
 module sui::vec_map_tests {
+    use std::bcs;
+    use std::string;
     use std::vector;
     use sui::vec_map::{Self, VecMap};
 
@@ -132,4 +134,17 @@ module sui::vec_map_tests {
 
         assert!(vec_map::keys(&m) == vector[1, 5], 1);
     }
+
+    // Fixtures of the VecMap serialization in sui-types collection_types_tests.rs
+    #[test]
+    fun bcs_layout() {
+        let m = vec_map::empty();
+        vec_map::insert(&mut m, 5, true);
+        vec_map::insert(&mut m, 1, false);
+        assert!(bcs::to_bytes(&m) == x"02050000000000000001010000000000000000", 0);
+
+        let m = vec_map::empty();
+        vec_map::insert(&mut m, string::utf8(b"name"), string::utf8(b"Sui"));
+        assert!(bcs::to_bytes(&m) == x"01046e616d6503537569", 1);
+    }
 }
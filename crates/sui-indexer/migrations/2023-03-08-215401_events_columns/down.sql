@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS events_sender;
+DROP INDEX IF EXISTS events_move_event_type;
+DROP INDEX IF EXISTS events_transaction_module;
+DROP INDEX IF EXISTS events_package_id;
+
+ALTER TABLE events DROP COLUMN IF EXISTS sender;
+ALTER TABLE events DROP COLUMN IF EXISTS move_event_type;
+ALTER TABLE events DROP COLUMN IF EXISTS transaction_module;
+ALTER TABLE events DROP COLUMN IF EXISTS package_id;
+ALTER TABLE events DROP COLUMN IF EXISTS event_json;
@@ -0,0 +1,11 @@
+ALTER TABLE events ADD COLUMN event_json JSONB;
+ALTER TABLE events ADD COLUMN package_id VARCHAR(255);
+ALTER TABLE events ADD COLUMN transaction_module TEXT;
+-- Move struct tag of the event, only set for Move events
+ALTER TABLE events ADD COLUMN move_event_type TEXT;
+ALTER TABLE events ADD COLUMN sender VARCHAR(255);
+
+CREATE INDEX events_package_id ON events (package_id);
+CREATE INDEX events_transaction_module ON events (package_id, transaction_module);
+CREATE INDEX events_move_event_type ON events (move_event_type);
+CREATE INDEX events_sender ON events (sender);
@@ -0,0 +1,13 @@
+CREATE TABLE shared_object_usage (
+    id BIGSERIAL PRIMARY KEY,
+    object_id VARCHAR(255) NOT NULL,
+    checkpoint_sequence_number BIGINT NOT NULL,
+    epoch BIGINT NOT NULL,
+    timestamp_ms BIGINT NOT NULL,
+    transaction_count BIGINT NOT NULL,
+    consensus_latency_ms BIGINT NOT NULL
+);
+
+CREATE INDEX shared_object_usage_object_id ON shared_object_usage (object_id);
+CREATE INDEX shared_object_usage_checkpoint_sequence_number ON shared_object_usage (checkpoint_sequence_number);
+CREATE INDEX shared_object_usage_timestamp_ms ON shared_object_usage (timestamp_ms);
@@ -0,0 +1,16 @@
+CREATE TABLE dynamic_fields (
+    object_id VARCHAR(255) PRIMARY KEY,
+    epoch BIGINT NOT NULL,
+    checkpoint BIGINT NOT NULL,
+    version BIGINT NOT NULL,
+    object_digest VARCHAR(255) NOT NULL,
+    parent_object_id VARCHAR(255) NOT NULL,
+    name_type TEXT NOT NULL,
+    name_value JSONB NOT NULL,
+    field_type TEXT NOT NULL,
+    value_type TEXT NOT NULL,
+    value_object_id VARCHAR(255) NOT NULL
+);
+
+CREATE INDEX dynamic_fields_parent_object_id ON dynamic_fields (parent_object_id, object_id);
+CREATE INDEX dynamic_fields_name ON dynamic_fields (parent_object_id, name_type);
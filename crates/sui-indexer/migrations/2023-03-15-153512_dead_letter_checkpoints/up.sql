@@ -0,0 +1,8 @@
+CREATE TABLE dead_letter_checkpoints (
+    sequence_number BIGINT PRIMARY KEY,
+    error_type VARCHAR(63) NOT NULL,
+    error TEXT NOT NULL,
+    attempts BIGINT NOT NULL,
+    first_failed_at TIMESTAMP NOT NULL,
+    last_failed_at TIMESTAMP NOT NULL
+);
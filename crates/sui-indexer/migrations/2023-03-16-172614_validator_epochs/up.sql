@@ -0,0 +1,15 @@
+CREATE TABLE validator_epochs (
+    epoch BIGINT NOT NULL,
+    validator_address VARCHAR(255) NOT NULL,
+    reference_gas_survey_quote BIGINT NOT NULL,
+    stake BIGINT NOT NULL,
+    commission_rate BIGINT NOT NULL,
+    stake_rewards BIGINT NOT NULL,
+    pool_sui_amount BIGINT NOT NULL,
+    pool_token_amount BIGINT NOT NULL,
+    tallying_rule_reporters TEXT[] NOT NULL,
+    tallying_rule_global_score BIGINT NOT NULL,
+    PRIMARY KEY (epoch, validator_address)
+);
+
+CREATE INDEX validator_epochs_validator_address ON validator_epochs (validator_address);
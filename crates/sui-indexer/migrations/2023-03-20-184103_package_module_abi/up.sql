@@ -0,0 +1,2 @@
+-- Normalized interface of the package modules, as a JSON map from module name to module
+ALTER TABLE packages ADD COLUMN module_abi JSONB;
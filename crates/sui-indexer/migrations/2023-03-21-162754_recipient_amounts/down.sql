@@ -0,0 +1,2 @@
+ALTER TABLE recipients DROP COLUMN IF EXISTS amount;
+ALTER TABLE recipients DROP COLUMN IF EXISTS coin_type;
@@ -0,0 +1,4 @@
+-- Amount of the coin type received by the recipient in the transaction, derived from the balance
+-- changes of the transaction; NULL for recipients of objects other than coins.
+ALTER TABLE recipients ADD COLUMN coin_type TEXT;
+ALTER TABLE recipients ADD COLUMN amount BIGINT;
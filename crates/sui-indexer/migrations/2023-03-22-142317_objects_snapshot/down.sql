@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS objects_delta;
+DROP TABLE IF EXISTS objects_snapshot;
@@ -0,0 +1,32 @@
+-- Address-owned objects as of every Nth checkpoint, see `objects_snapshot_interval`.
+CREATE TABLE objects_snapshot
+(
+    checkpoint           BIGINT       NOT NULL,
+    object_id            address      NOT NULL,
+    version              BIGINT       NOT NULL,
+    object_digest        base58digest NOT NULL,
+    owner_address        address      NOT NULL,
+    previous_transaction base58digest NOT NULL,
+    object_type          VARCHAR      NOT NULL,
+    CONSTRAINT objects_snapshot_pk PRIMARY KEY (checkpoint, object_id)
+);
+CREATE INDEX objects_snapshot_owner_address ON objects_snapshot (owner_address, checkpoint);
+
+-- Last version of every object changed by a checkpoint, replayed on top of a snapshot to get
+-- the objects of an address at a later checkpoint.
+CREATE TABLE objects_delta
+(
+    checkpoint             BIGINT        NOT NULL,
+    object_id              address       NOT NULL,
+    version                BIGINT        NOT NULL,
+    object_digest          base58digest  NOT NULL,
+    owner_type             owner_type    NOT NULL,
+    owner_address          address,
+    initial_shared_version BIGINT,
+    previous_transaction   base58digest  NOT NULL,
+    object_type            VARCHAR       NOT NULL,
+    object_status          object_status NOT NULL,
+    CONSTRAINT objects_delta_pk PRIMARY KEY (checkpoint, object_id)
+);
+CREATE INDEX objects_delta_owner_address ON objects_delta (owner_address, checkpoint);
+CREATE INDEX objects_delta_object_id ON objects_delta (object_id, checkpoint);
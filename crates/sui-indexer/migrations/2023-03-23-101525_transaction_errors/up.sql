@@ -0,0 +1,21 @@
+-- Execution failures of transactions, one row per failed transaction.
+CREATE TABLE transaction_errors (
+    id BIGSERIAL PRIMARY KEY,
+    transaction_digest VARCHAR(255) NOT NULL,
+    checkpoint_sequence_number BIGINT NOT NULL,
+    epoch BIGINT NOT NULL,
+    timestamp_ms BIGINT NOT NULL,
+    sender VARCHAR(255) NOT NULL,
+    -- Variant of ExecutionFailureStatus, e.g. MoveAbort or InsufficientGas
+    error_kind VARCHAR(255) NOT NULL,
+    command_index BIGINT,
+    -- Move function executing when the failure occurred, NULL for failures outside of Move code
+    package VARCHAR(255),
+    module TEXT,
+    function TEXT,
+    abort_code BIGINT
+);
+
+CREATE INDEX transaction_errors_checkpoint_sequence_number ON transaction_errors (checkpoint_sequence_number);
+CREATE INDEX transaction_errors_timestamp_ms ON transaction_errors (timestamp_ms);
+CREATE INDEX transaction_errors_package_module_function ON transaction_errors (package, module, function);
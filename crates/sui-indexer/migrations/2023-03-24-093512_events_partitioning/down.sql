@@ -0,0 +1,39 @@
+ALTER TABLE events RENAME TO events_partitioned;
+ALTER SEQUENCE events_id_seq RENAME TO events_partitioned_id_seq;
+DROP INDEX IF EXISTS events_transaction_digest;
+DROP INDEX IF EXISTS events_event_time;
+DROP INDEX IF EXISTS events_package_id;
+DROP INDEX IF EXISTS events_transaction_module;
+DROP INDEX IF EXISTS events_move_event_type;
+DROP INDEX IF EXISTS events_sender;
+DROP INDEX IF EXISTS events_checkpoint;
+
+CREATE TABLE events (
+    id BIGSERIAL PRIMARY KEY,
+    transaction_digest VARCHAR(255) NOT NULL,
+    event_sequence BIGINT NOT NULL,
+    event_time TIMESTAMP,
+    event_type VARCHAR NOT NULL,
+    event_content VARCHAR NOT NULL,
+    event_json JSONB,
+    package_id VARCHAR(255),
+    transaction_module TEXT,
+    move_event_type TEXT,
+    sender VARCHAR(255)
+);
+
+CREATE INDEX events_transaction_digest ON events (transaction_digest);
+CREATE INDEX events_event_time ON events (event_time);
+CREATE INDEX events_package_id ON events (package_id);
+CREATE INDEX events_transaction_module ON events (package_id, transaction_module);
+CREATE INDEX events_move_event_type ON events (move_event_type);
+CREATE INDEX events_sender ON events (sender);
+
+INSERT INTO events (id, transaction_digest, event_sequence, event_time, event_type, event_content,
+                    event_json, package_id, transaction_module, move_event_type, sender)
+SELECT id, transaction_digest, event_sequence, event_time, event_type, event_content,
+       event_json, package_id, transaction_module, move_event_type, sender
+FROM events_partitioned;
+SELECT setval('events_id_seq', COALESCE((SELECT MAX(id) FROM events), 0) + 1, false);
+
+DROP TABLE events_partitioned;
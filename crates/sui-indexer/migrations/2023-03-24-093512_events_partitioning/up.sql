@@ -0,0 +1,60 @@
+-- Events are partitioned by ranges of 100000 checkpoints, the indexer creates the partitions of
+-- the new ranges as it reaches them.
+ALTER TABLE events RENAME TO events_unpartitioned;
+ALTER SEQUENCE events_id_seq RENAME TO events_unpartitioned_id_seq;
+DROP INDEX IF EXISTS events_transaction_digest;
+DROP INDEX IF EXISTS events_event_time;
+DROP INDEX IF EXISTS events_package_id;
+DROP INDEX IF EXISTS events_transaction_module;
+DROP INDEX IF EXISTS events_move_event_type;
+DROP INDEX IF EXISTS events_sender;
+
+CREATE TABLE events (
+    id BIGSERIAL NOT NULL,
+    transaction_digest VARCHAR(255) NOT NULL,
+    event_sequence BIGINT NOT NULL,
+    event_time TIMESTAMP,
+    event_type VARCHAR NOT NULL,
+    event_content VARCHAR NOT NULL,
+    event_json JSONB,
+    package_id VARCHAR(255),
+    transaction_module TEXT,
+    -- Move struct tag of the event, only set for Move events
+    move_event_type TEXT,
+    sender VARCHAR(255),
+    checkpoint BIGINT NOT NULL,
+    CONSTRAINT events_pk PRIMARY KEY (id, checkpoint)
+) PARTITION BY RANGE (checkpoint);
+
+CREATE INDEX events_transaction_digest ON events (transaction_digest);
+CREATE INDEX events_event_time ON events (event_time);
+CREATE INDEX events_package_id ON events (package_id);
+CREATE INDEX events_transaction_module ON events (package_id, transaction_module);
+CREATE INDEX events_move_event_type ON events (move_event_type);
+CREATE INDEX events_sender ON events (sender);
+CREATE INDEX events_checkpoint ON events (checkpoint);
+
+-- Partitions of the checkpoints indexed so far
+DO
+$body$
+DECLARE
+    last_checkpoint BIGINT;
+BEGIN
+    SELECT COALESCE(MAX(sequence_number), 0) INTO last_checkpoint FROM checkpoints;
+    FOR p IN 0..(last_checkpoint / 100000) LOOP
+        EXECUTE format('CREATE TABLE events_partition_%s PARTITION OF events FOR VALUES FROM (%s) TO (%s)',
+                       p, p::BIGINT * 100000, (p::BIGINT + 1) * 100000);
+    END LOOP;
+END
+$body$;
+
+INSERT INTO events (id, transaction_digest, event_sequence, event_time, event_type, event_content,
+                    event_json, package_id, transaction_module, move_event_type, sender, checkpoint)
+SELECT e.id, e.transaction_digest, e.event_sequence, e.event_time, e.event_type, e.event_content,
+       e.event_json, e.package_id, e.transaction_module, e.move_event_type, e.sender,
+       t.checkpoint_sequence_number
+FROM events_unpartitioned e
+         JOIN transactions t ON t.transaction_digest = e.transaction_digest;
+SELECT setval('events_id_seq', COALESCE((SELECT MAX(id) FROM events), 0) + 1, false);
+
+DROP TABLE events_unpartitioned;
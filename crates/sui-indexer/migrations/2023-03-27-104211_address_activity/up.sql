@@ -0,0 +1,11 @@
+-- Transactions sent by each address per epoch, maintained incrementally as checkpoints are indexed.
+CREATE TABLE address_activity (
+    address VARCHAR(255) NOT NULL,
+    epoch BIGINT NOT NULL,
+    tx_count BIGINT NOT NULL,
+    -- Net of storage rebates, so it can be negative
+    gas_spent BIGINT NOT NULL,
+    PRIMARY KEY (address, epoch)
+);
+
+CREATE INDEX address_activity_epoch ON address_activity (epoch);
@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS object_type_counts ON objects;
+DROP FUNCTION IF EXISTS object_type_counts_func();
+
+DROP INDEX IF EXISTS objects_object_type;
+DROP TABLE IF EXISTS object_type_counts;
@@ -0,0 +1,40 @@
+-- Number of live objects of each type, maintained from the objects table.
+CREATE TABLE object_type_counts
+(
+    object_type VARCHAR NOT NULL PRIMARY KEY,
+    live_count  BIGINT  NOT NULL
+);
+
+CREATE INDEX objects_object_type ON objects (object_type, object_id);
+
+INSERT INTO object_type_counts
+SELECT object_type, COUNT(*)
+FROM objects
+WHERE object_status IN ('created', 'mutated', 'unwrapped')
+GROUP BY object_type;
+
+-- Objects are only updated to newer versions, so that reprocessing a checkpoint does not count
+-- its objects twice.
+CREATE OR REPLACE FUNCTION object_type_counts_func() RETURNS TRIGGER AS
+$body$
+BEGIN
+    IF (TG_OP = 'UPDATE' OR TG_OP = 'DELETE') AND OLD.object_status IN ('created', 'mutated', 'unwrapped') THEN
+        UPDATE object_type_counts
+        SET live_count = live_count - 1
+        WHERE object_type = OLD.object_type;
+    END IF;
+    IF (TG_OP = 'UPDATE' OR TG_OP = 'INSERT') AND NEW.object_status IN ('created', 'mutated', 'unwrapped') THEN
+        INSERT INTO object_type_counts
+        VALUES (NEW.object_type, 1)
+        ON CONFLICT (object_type) DO UPDATE SET live_count = object_type_counts.live_count + 1;
+    END IF;
+    RETURN NULL;
+END;
+$body$
+    LANGUAGE plpgsql;
+
+CREATE TRIGGER object_type_counts
+    AFTER INSERT OR UPDATE OR DELETE
+    ON objects
+    FOR EACH ROW
+EXECUTE PROCEDURE object_type_counts_func();
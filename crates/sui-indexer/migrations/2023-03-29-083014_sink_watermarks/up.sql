@@ -0,0 +1,7 @@
+-- Last checkpoint published to each external sink, the checkpoints after it are published again
+-- when the sink handler restarts.
+CREATE TABLE sink_watermarks
+(
+    sink_name                  VARCHAR NOT NULL PRIMARY KEY,
+    checkpoint_sequence_number BIGINT  NOT NULL
+);
@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS events_position;
+DROP INDEX IF EXISTS recipients_position;
+DROP INDEX IF EXISTS move_calls_position;
+DROP INDEX IF EXISTS transactions_position;
+ALTER TABLE events DROP COLUMN IF EXISTS transaction_index;
+ALTER TABLE recipients DROP COLUMN IF EXISTS transaction_index;
+ALTER TABLE move_calls DROP COLUMN IF EXISTS transaction_index;
+ALTER TABLE transactions DROP COLUMN IF EXISTS transaction_index;
@@ -0,0 +1,34 @@
+-- Index of the transaction in its checkpoint, which along with the checkpoint sequence number is
+-- the position pages of transactions and events are read after, see StoreCursor. Unlike ids, the
+-- positions don't change when the tables are re-ingested.
+ALTER TABLE transactions ADD COLUMN transaction_index BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE move_calls ADD COLUMN transaction_index BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE recipients ADD COLUMN transaction_index BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE events ADD COLUMN transaction_index BIGINT NOT NULL DEFAULT 0;
+
+-- Transactions indexed so far were inserted in checkpoint order; in filtered mode, the indexes
+-- are the positions among the indexed transactions of the checkpoint only.
+UPDATE transactions t
+SET transaction_index = p.transaction_index
+FROM (SELECT id,
+             ROW_NUMBER() OVER (PARTITION BY checkpoint_sequence_number ORDER BY id) - 1
+                 AS transaction_index
+      FROM transactions) p
+WHERE t.id = p.id;
+UPDATE move_calls m
+SET transaction_index = t.transaction_index
+FROM transactions t
+WHERE t.transaction_digest = m.transaction_digest;
+UPDATE recipients r
+SET transaction_index = t.transaction_index
+FROM transactions t
+WHERE t.transaction_digest = r.transaction_digest;
+UPDATE events e
+SET transaction_index = t.transaction_index
+FROM transactions t
+WHERE t.transaction_digest = e.transaction_digest;
+
+CREATE INDEX transactions_position ON transactions (checkpoint_sequence_number, transaction_index);
+CREATE INDEX move_calls_position ON move_calls (checkpoint_sequence_number, transaction_index);
+CREATE INDEX recipients_position ON recipients (checkpoint_sequence_number, transaction_index);
+CREATE INDEX events_position ON events (checkpoint, transaction_index, event_sequence);
@@ -1,6 +1,10 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::http_client::HttpClient;
@@ -12,17 +16,44 @@ use sui_json_rpc_types::{Balance, CoinPage, SuiCoinMetadata};
 use sui_open_rpc::Module;
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::parse_sui_struct_tag;
+
+/// Coin metadata can be updated by the holder of the treasury cap, so cached entries expire.
+const COIN_METADATA_CACHE_TTL: Duration = Duration::from_secs(60);
 
 pub(crate) struct CoinReadApi {
     fullnode: HttpClient,
+    metadata_cache: Mutex<HashMap<String, (Instant, SuiCoinMetadata)>>,
 }
 
 impl CoinReadApi {
     pub fn new(fullnode_client: HttpClient) -> Self {
         Self {
             fullnode: fullnode_client,
+            metadata_cache: Mutex::new(HashMap::new()),
         }
     }
+
+    /// Cache key of a coin type, so that the different spellings of a type share an entry.
+    fn metadata_cache_key(coin_type: &str) -> String {
+        parse_sui_struct_tag(coin_type)
+            .map(|tag| tag.to_string())
+            .unwrap_or_else(|_| coin_type.to_string())
+    }
+
+    fn cached_metadata(&self, key: &str) -> Option<SuiCoinMetadata> {
+        let cache = self.metadata_cache.lock().unwrap();
+        cache
+            .get(key)
+            .filter(|(cached_at, _)| cached_at.elapsed() < COIN_METADATA_CACHE_TTL)
+            .map(|(_, metadata)| metadata.clone())
+    }
+
+    fn cache_metadata(&self, key: String, metadata: SuiCoinMetadata) {
+        let mut cache = self.metadata_cache.lock().unwrap();
+        cache.retain(|_, (cached_at, _)| cached_at.elapsed() < COIN_METADATA_CACHE_TTL);
+        cache.insert(key, (Instant::now(), metadata));
+    }
 }
 
 #[async_trait]
@@ -61,7 +92,45 @@ impl CoinReadApiServer for CoinReadApi {
     }
 
     async fn get_coin_metadata(&self, coin_type: String) -> RpcResult<SuiCoinMetadata> {
-        self.fullnode.get_coin_metadata(coin_type).await
+        let key = Self::metadata_cache_key(&coin_type);
+        if let Some(metadata) = self.cached_metadata(&key) {
+            return Ok(metadata);
+        }
+        let metadata = self.fullnode.get_coin_metadata(coin_type).await?;
+        self.cache_metadata(key, metadata.clone());
+        Ok(metadata)
+    }
+
+    async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> RpcResult<Vec<Option<SuiCoinMetadata>>> {
+        let keys: Vec<_> = coin_types
+            .iter()
+            .map(|coin_type| Self::metadata_cache_key(coin_type))
+            .collect();
+        let mut results: Vec<_> = keys.iter().map(|key| self.cached_metadata(key)).collect();
+
+        let missing: Vec<_> = results
+            .iter()
+            .zip(coin_types)
+            .enumerate()
+            .filter(|(_, (cached, _))| cached.is_none())
+            .map(|(i, (_, coin_type))| (i, coin_type))
+            .collect();
+        if missing.is_empty() {
+            return Ok(results);
+        }
+
+        let (indices, missing_types): (Vec<_>, Vec<_>) = missing.into_iter().unzip();
+        let fetched = self.fullnode.multi_get_coin_metadata(missing_types).await?;
+        for (i, metadata) in indices.into_iter().zip(fetched) {
+            if let Some(metadata) = &metadata {
+                self.cache_metadata(keys[i].clone(), metadata.clone());
+            }
+            results[i] = metadata;
+        }
+        Ok(results)
     }
 
     async fn get_total_supply(&self, coin_type: String) -> RpcResult<Supply> {
@@ -8,9 +8,11 @@ use jsonrpsee::types::{SubscriptionEmptyError, SubscriptionResult};
 use jsonrpsee::{RpcModule, SubscriptionSink};
 use sui_json_rpc::api::{EventReadApiClient, EventReadApiServer};
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_types::{EventPage, SuiEventFilter};
+use sui_json_rpc_types::{EventPage, SuiEventFilter, SuiPackagePublishFilter};
 use sui_open_rpc::Module;
+use sui_types::base_types::TransactionDigest;
 use sui_types::event::EventID;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::query::EventQuery;
 
 pub(crate) struct EventReadApi {
@@ -39,6 +41,28 @@ impl EventReadApiServer for EventReadApi {
             .await
     }
 
+    async fn get_events_by_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage> {
+        self.fullnode
+            .get_events_by_checkpoint(checkpoint, cursor, limit)
+            .await
+    }
+
+    async fn get_transaction_events(
+        &self,
+        digest: TransactionDigest,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage> {
+        self.fullnode
+            .get_transaction_events(digest, cursor, limit)
+            .await
+    }
+
     fn subscribe_event(
         &self,
         mut _sink: SubscriptionSink,
@@ -47,6 +71,14 @@ impl EventReadApiServer for EventReadApi {
         // subscription not supported by subscription yet
         Err(SubscriptionEmptyError)
     }
+
+    fn subscribe_package_publishes(
+        &self,
+        mut _sink: SubscriptionSink,
+        _filter: Option<SuiPackagePublishFilter>,
+    ) -> SubscriptionResult {
+        Err(SubscriptionEmptyError)
+    }
 }
 
 impl SuiRpcModule for EventReadApi {
@@ -0,0 +1,231 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::models::transaction_errors::failing_functions;
+use crate::store::IndexerStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::RpcModule;
+use std::str::FromStr;
+use sui_json_rpc::api::{cap_page_limit, ExtendedApiServer};
+use sui_json_rpc::SuiRpcModule;
+use sui_json_rpc_types::{
+    ActiveAddressStats, AddressEpochActivity, FailingFunction, HotSharedObject, IncomingPayment,
+    IncomingPaymentPage, ObjectHistoryEntry, ObjectHistoryPage, ObjectInfoPage, SuiObjectInfo,
+    TransactionErrorStats,
+};
+use sui_open_rpc::Module;
+use sui_types::base_types::{ObjectID, ObjectType, SequenceNumber, SuiAddress};
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+
+pub(crate) struct ExtendedApi<S> {
+    state: S,
+}
+
+impl<S: IndexerStore> ExtendedApi<S> {
+    pub fn new(state: S) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl<S> ExtendedApiServer for ExtendedApi<S>
+where
+    S: IndexerStore + Sync + Send + 'static,
+{
+    async fn get_hot_shared_objects(
+        &self,
+        window: u64,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<HotSharedObject>> {
+        let limit = cap_page_limit(limit);
+        let hot_shared_objects = self
+            .state
+            .get_hot_shared_objects(window as i64, limit)?
+            .into_iter()
+            .map(HotSharedObject::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(hot_shared_objects)
+    }
+
+    async fn get_failing_functions(
+        &self,
+        window: u64,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<FailingFunction>> {
+        let limit = cap_page_limit(limit);
+        let stats = self.state.get_failing_functions(window as i64, limit)?;
+        Ok(failing_functions(stats)?)
+    }
+
+    async fn get_transaction_error_stats(
+        &self,
+        window: u64,
+    ) -> RpcResult<Vec<TransactionErrorStats>> {
+        let stats = self
+            .state
+            .get_transaction_error_stats(window as i64)?
+            .into_iter()
+            .map(TransactionErrorStats::from)
+            .collect();
+        Ok(stats)
+    }
+
+    async fn get_active_addresses(
+        &self,
+        start_epoch: EpochId,
+        end_epoch: EpochId,
+    ) -> RpcResult<ActiveAddressStats> {
+        if start_epoch > end_epoch {
+            return Err(anyhow!(
+                "Start epoch {} is after end epoch {}",
+                start_epoch,
+                end_epoch
+            )
+            .into());
+        }
+        Ok(self
+            .state
+            .get_active_addresses(start_epoch as i64, end_epoch as i64)?
+            .into_stats(start_epoch, end_epoch))
+    }
+
+    async fn get_address_activity(
+        &self,
+        address: SuiAddress,
+        start_epoch: Option<EpochId>,
+        end_epoch: Option<EpochId>,
+    ) -> RpcResult<Vec<AddressEpochActivity>> {
+        let activity = self
+            .state
+            .get_address_activity(
+                &address.to_string(),
+                start_epoch.map_or(0, |epoch| epoch as i64),
+                end_epoch.map_or(i64::MAX, |epoch| epoch as i64),
+            )?
+            .into_iter()
+            .map(AddressEpochActivity::from)
+            .collect();
+        Ok(activity)
+    }
+
+    async fn get_object_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<SequenceNumber>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectHistoryPage> {
+        let limit = cap_page_limit(limit);
+        let start_version = cursor.map_or(0, |version| version.value() as i64);
+        let mut data = self
+            .state
+            .get_object_history(&object_id.to_string(), start_version, limit + 1)?
+            .into_iter()
+            .map(ObjectHistoryEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = data.get(limit).map(|entry| entry.version);
+        data.truncate(limit);
+        Ok(ObjectHistoryPage { data, next_cursor })
+    }
+
+    async fn get_incoming_payments(
+        &self,
+        recipient: SuiAddress,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+    ) -> RpcResult<IncomingPaymentPage> {
+        let limit = cap_page_limit(limit);
+        let mut payments = self.state.get_incoming_payments(
+            &recipient.to_string(),
+            cursor.map(|id| id as i64),
+            limit + 1,
+        )?;
+        let next_cursor = payments.get(limit).and_then(|p| p.id).map(|id| id as u64);
+        payments.truncate(limit);
+        let data = payments
+            .into_iter()
+            .map(IncomingPayment::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(IncomingPaymentPage { data, next_cursor })
+    }
+
+    async fn get_owned_objects_at_checkpoint(
+        &self,
+        address: SuiAddress,
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<SuiObjectInfo>> {
+        let latest_checkpoint = self.state.get_latest_checkpoint_sequence_number()?;
+        if checkpoint as i64 > latest_checkpoint {
+            return Err(anyhow!(
+                "Checkpoint {} is not indexed yet, the latest indexed checkpoint is {}",
+                checkpoint,
+                latest_checkpoint
+            )
+            .into());
+        }
+        let objects = self
+            .state
+            .get_owned_objects_at_checkpoint(&address.to_string(), checkpoint as i64)?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Checkpoint {} is older than the oldest objects snapshot",
+                    checkpoint
+                )
+            })?;
+        Ok(objects
+            .into_iter()
+            .map(SuiObjectInfo::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn get_objects_by_type(
+        &self,
+        object_type: String,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectInfoPage> {
+        let limit = cap_page_limit(limit);
+        // Pages start after their cursor, the last object of the previous page.
+        let mut data = self
+            .state
+            .get_objects_by_type_page(
+                &normalize_object_type(&object_type)?,
+                cursor.map(|id| id.to_string()),
+                limit + 1,
+            )?
+            .into_iter()
+            .map(SuiObjectInfo::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let has_next_page = data.len() > limit;
+        data.truncate(limit);
+        let next_cursor = has_next_page.then(|| data[limit - 1].object_id);
+        Ok(ObjectInfoPage { data, next_cursor })
+    }
+
+    async fn count_objects_by_type(&self, object_type: String) -> RpcResult<u64> {
+        let count = self
+            .state
+            .count_objects_by_type(&normalize_object_type(&object_type)?)?;
+        Ok(count as u64)
+    }
+}
+
+/// The type as recorded in the objects table, whatever the formatting of the addresses in `type_`.
+fn normalize_object_type(type_: &str) -> anyhow::Result<String> {
+    Ok(ObjectType::from_str(type_)?.to_string())
+}
+
+impl<S> SuiRpcModule for ExtendedApi<S>
+where
+    S: IndexerStore + Sync + Send + 'static,
+{
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        sui_json_rpc::api::ExtendedApiOpenRpc::module_doc()
+    }
+}
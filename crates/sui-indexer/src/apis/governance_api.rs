@@ -7,8 +7,9 @@ use jsonrpsee::http_client::HttpClient;
 use jsonrpsee::RpcModule;
 use sui_json_rpc::api::{GovernanceReadApiClient, GovernanceReadApiServer};
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_types::SuiCommittee;
+use sui_json_rpc_types::{CommitteePage, SuiCommittee, SuiGasCostTable, SuiValidatorReports};
 use sui_open_rpc::Module;
+use sui_protocol_config::ProtocolVersion;
 use sui_types::base_types::{EpochId, SuiAddress};
 use sui_types::governance::DelegatedStake;
 use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
@@ -35,6 +36,14 @@ impl GovernanceReadApiServer for GovernanceReadApi {
         self.fullnode.get_committee_info(epoch).await
     }
 
+    async fn get_committees(
+        &self,
+        cursor: Option<EpochId>,
+        limit: Option<usize>,
+    ) -> RpcResult<CommitteePage> {
+        self.fullnode.get_committees(cursor, limit).await
+    }
+
     async fn get_latest_sui_system_state(&self) -> RpcResult<SuiSystemStateSummary> {
         self.fullnode.get_latest_sui_system_state().await
     }
@@ -42,6 +51,17 @@ impl GovernanceReadApiServer for GovernanceReadApi {
     async fn get_reference_gas_price(&self) -> RpcResult<u64> {
         self.fullnode.get_reference_gas_price().await
     }
+
+    async fn get_gas_cost_table(
+        &self,
+        protocol_version: Option<ProtocolVersion>,
+    ) -> RpcResult<SuiGasCostTable> {
+        self.fullnode.get_gas_cost_table(protocol_version).await
+    }
+
+    async fn get_validator_reports(&self) -> RpcResult<SuiValidatorReports> {
+        self.fullnode.get_validator_reports().await
+    }
 }
 
 impl SuiRpcModule for GovernanceReadApi {
@@ -4,6 +4,7 @@
 mod bls_api;
 mod coin_api;
 mod event_api;
+mod extended_api;
 mod governance_api;
 mod read_api;
 mod transaction_builder_api;
@@ -12,6 +13,7 @@ mod write_api;
 pub(crate) use bls_api::ThresholdBlsApi;
 pub(crate) use coin_api::CoinReadApi;
 pub(crate) use event_api::EventReadApi;
+pub(crate) use extended_api::ExtendedApi;
 pub(crate) use governance_api::GovernanceReadApi;
 pub(crate) use read_api::ReadApi;
 pub(crate) use transaction_builder_api::TransactionBuilderApi;
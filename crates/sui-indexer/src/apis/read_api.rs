@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::models::dynamic_fields::normalize_name_value;
 use crate::store::IndexerStore;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::http_client::HttpClient;
@@ -11,10 +13,11 @@ use std::collections::BTreeMap;
 use sui_json_rpc::api::{cap_page_limit, ReadApiClient, ReadApiServer};
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, MoveFunctionArgType, Page,
+    Checkpoint, CheckpointAttestation, CheckpointId, DynamicFieldPage, MoveFunctionArgType, Page,
     SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
-    SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse, SuiPastObjectResponse,
-    SuiTransactionResponse, SuiTransactionResponseOptions, TransactionsPage,
+    SuiMoveStructLayout, SuiNodeInfo, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
+    SuiPastObjectResponse, SuiPruningConfig, SuiTransactionResponse, SuiTransactionResponseOptions,
+    TransactionsPage, TransactionsPageItem,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TxSequenceNumber};
@@ -39,11 +42,107 @@ impl<S: IndexerStore> ReadApi<S> {
         }
     }
 
+    /// Normalized modules of the package from the indexed package ABI, None if the ABI is not
+    /// indexed and has to be read from the fullnode.
+    fn get_package_abi(
+        &self,
+        package: ObjectID,
+    ) -> RpcResult<Option<BTreeMap<String, SuiMoveNormalizedModule>>> {
+        Ok(self.state.get_package_abi(&package.to_string())?)
+    }
+
+    fn get_module_abi(
+        &self,
+        package: ObjectID,
+        module_name: &str,
+    ) -> RpcResult<Option<SuiMoveNormalizedModule>> {
+        let Some(mut modules) = self.get_package_abi(package)? else {
+            return Ok(None);
+        };
+        Ok(Some(modules.remove(module_name).ok_or_else(|| {
+            anyhow!("No module found with module name {}", module_name)
+        })?))
+    }
+
     async fn get_total_transaction_number(&self) -> RpcResult<u64> {
         let total_tx_number = self.state.get_total_transaction_number()?;
         Ok(total_tx_number as u64)
     }
 
+    async fn get_dynamic_fields(
+        &self,
+        parent_object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<DynamicFieldPage> {
+        let limit = cap_page_limit(limit);
+        let mut data = self
+            .state
+            .get_dynamic_fields(
+                parent_object_id.to_string(),
+                cursor.map(|c| c.to_string()),
+                limit + 1,
+            )?
+            .into_iter()
+            .map(|(field, child)| {
+                let object_id = field.object_id.parse::<ObjectID>().map_err(|e| {
+                    IndexerError::JsonSerdeError(format!(
+                        "Failed to parse object id {} with err: {:?}",
+                        field.object_id, e
+                    ))
+                })?;
+                Ok((object_id, field.try_into_info(child)?))
+            })
+            .collect::<Result<Vec<_>, IndexerError>>()?;
+        // Cursors are Field object ids, which differ from the info object id of dynamic object
+        // fields.
+        let next_cursor = data.get(limit).map(|(object_id, _)| *object_id);
+        data.truncate(limit);
+        Ok(DynamicFieldPage {
+            data: data.into_iter().map(|(_, info)| info).collect(),
+            next_cursor,
+        })
+    }
+
+    async fn try_multi_get_object_refs(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<Vec<SuiObjectResponse>> {
+        self.fullnode
+            .try_multi_get_object_refs(object_ids, options)
+            .await
+    }
+
+    async fn get_dynamic_field_object(
+        &self,
+        parent_object_id: ObjectID,
+        name: DynamicFieldName,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<SuiObjectResponse> {
+        let name_value = normalize_name_value(&name.type_, name.value.clone());
+        let field = self
+            .state
+            .get_dynamic_field(
+                parent_object_id.to_string(),
+                name.type_.to_string(),
+                name_value,
+            )?
+            .ok_or_else(|| {
+                anyhow!("Cannot find dynamic field [{name:?}] for object [{parent_object_id}].")
+            })?;
+        let object_id = field.value_object_id.parse::<ObjectID>().map_err(|e| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to parse object id {} with err: {:?}",
+                field.value_object_id, e
+            ))
+        })?;
+        let options = options.unwrap_or_else(SuiObjectDataOptions::full_content);
+        self.fullnode
+            .get_object_with_options(object_id, Some(options))
+            .await
+    }
+
     async fn get_transaction_with_options(
         &self,
         digest: &TransactionDigest,
@@ -63,70 +162,58 @@ impl<S: IndexerStore> ReadApi<S> {
         cursor: Option<TransactionDigest>,
         limit: Option<usize>,
         descending_order: Option<bool>,
+        show_summaries: Option<bool>,
     ) -> RpcResult<TransactionsPage> {
         let limit = cap_page_limit(limit);
         let is_descending = descending_order.unwrap_or_default();
-        let cursor_str = cursor.map(|digest| digest.to_string());
+        // Pages are read from the position of the transaction of the cursor, which is the same
+        // in every table indexing transactions.
+        let cursor = cursor
+            .map(|digest| self.state.get_transaction_cursor(&digest.to_string()))
+            .transpose()?;
 
         let digests_from_db = match query {
             TransactionQuery::All => {
-                let indexer_seq_number = self
-                    .state
-                    .get_transaction_sequence_by_digest(cursor_str, is_descending)?;
                 self.state
-                    .get_all_transaction_digest_page(indexer_seq_number, limit, is_descending)
+                    .get_all_transaction_digest_page(cursor, limit, is_descending)
             }
             TransactionQuery::MoveFunction {
                 package,
                 module,
                 function,
-            } => {
-                let move_call_seq_number = self
-                    .state
-                    .get_move_call_sequence_by_digest(cursor_str, is_descending)?;
-                self.state.get_transaction_digest_page_by_move_call(
-                    package.to_string(),
-                    module,
-                    function,
-                    move_call_seq_number,
-                    limit,
-                    is_descending,
-                )
-            }
+            } => self.state.get_transaction_digest_page_by_move_call(
+                package.to_string(),
+                module,
+                function,
+                cursor,
+                limit,
+                is_descending,
+            ),
             // TODO(gegaowp): input objects are tricky to retrive from
             // SuiTransactionResponse, instead we should store the BCS
             // serialized transaction and retrive from there.
             // This is now blocked by the endpoint on FN side.
             TransactionQuery::InputObject(_input_obj_id) => Ok(vec![]),
             TransactionQuery::MutatedObject(mutated_obj_id) => {
-                let indexer_seq_number = self
-                    .state
-                    .get_transaction_sequence_by_digest(cursor_str, is_descending)?;
                 self.state.get_transaction_digest_page_by_mutated_object(
                     mutated_obj_id.to_string(),
-                    indexer_seq_number,
+                    cursor,
                     limit + 1,
                     is_descending,
                 )
             }
             TransactionQuery::FromAddress(sender_address) => {
-                let indexer_seq_number = self
-                    .state
-                    .get_transaction_sequence_by_digest(cursor_str, is_descending)?;
                 self.state.get_transaction_digest_page_by_sender_address(
                     sender_address.to_string(),
-                    indexer_seq_number,
+                    cursor,
                     limit + 1,
                     is_descending,
                 )
             }
             TransactionQuery::ToAddress(recipient_address) => {
-                let recipient_seq_number = self
-                    .state
-                    .get_recipient_sequence_by_digest(cursor_str, is_descending)?;
                 self.state.get_transaction_digest_page_by_recipient_address(
                     recipient_address.to_string(),
-                    recipient_seq_number,
+                    cursor,
                     limit + 1,
                     is_descending,
                 )
@@ -149,11 +236,24 @@ impl<S: IndexerStore> ReadApi<S> {
 
         let next_cursor = txn_digests.get(limit).cloned();
         txn_digests.truncate(limit);
+        let data = if show_summaries.unwrap_or_default() {
+            let mut summaries = Vec::with_capacity(txn_digests.len());
+            for digest in txn_digests {
+                let response: SuiTransactionResponse = self
+                    .state
+                    .get_transaction_by_digest(&digest.base58_encode())?
+                    .try_into()?;
+                summaries.push(TransactionsPageItem::Summary(response.try_into()?));
+            }
+            summaries
+        } else {
+            txn_digests
+                .into_iter()
+                .map(TransactionsPageItem::from)
+                .collect()
+        };
 
-        Ok(Page {
-            data: txn_digests,
-            next_cursor,
-        })
+        Ok(Page { data, next_cursor })
     }
 
     async fn get_latest_checkpoint_sequence_number(&self) -> Result<i64, IndexerError> {
@@ -184,8 +284,16 @@ where
         cursor: Option<ObjectID>,
         limit: Option<usize>,
     ) -> RpcResult<DynamicFieldPage> {
-        self.fullnode
-            .get_dynamic_fields(parent_object_id, cursor, limit)
+        if self
+            .method_to_be_forwarded
+            .contains(&"get_dynamic_fields".to_string())
+        {
+            return self
+                .fullnode
+                .get_dynamic_fields(parent_object_id, cursor, limit)
+                .await;
+        }
+        self.get_dynamic_fields(parent_object_id, cursor, limit)
             .await
     }
 
@@ -203,9 +311,18 @@ where
         &self,
         parent_object_id: ObjectID,
         name: DynamicFieldName,
+        options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse> {
-        self.fullnode
-            .get_dynamic_field_object(parent_object_id, name)
+        if self
+            .method_to_be_forwarded
+            .contains(&"get_dynamic_field_object".to_string())
+        {
+            return self
+                .fullnode
+                .get_dynamic_field_object(parent_object_id, name, options)
+                .await;
+        }
+        self.get_dynamic_field_object(parent_object_id, name, options)
             .await
     }
 
@@ -225,6 +342,7 @@ where
         cursor: Option<TransactionDigest>,
         limit: Option<usize>,
         descending_order: Option<bool>,
+        show_summaries: Option<bool>,
     ) -> RpcResult<TransactionsPage> {
         if self
             .method_to_be_forwarded
@@ -232,10 +350,10 @@ where
         {
             return self
                 .fullnode
-                .get_transactions(query, cursor, limit, descending_order)
+                .get_transactions(query, cursor, limit, descending_order, show_summaries)
                 .await;
         }
-        self.get_transactions(query, cursor, limit, descending_order)
+        self.get_transactions(query, cursor, limit, descending_order, show_summaries)
             .await
     }
 
@@ -252,9 +370,13 @@ where
         digest: TransactionDigest,
         options: Option<SuiTransactionResponseOptions>,
     ) -> RpcResult<SuiTransactionResponse> {
+        // Input objects are read from the history of objects of the full node.
         if self
             .method_to_be_forwarded
             .contains(&"get_transaction".to_string())
+            || options
+                .as_ref()
+                .map_or(false, |options| options.show_input_objects)
         {
             return self
                 .fullnode
@@ -269,9 +391,13 @@ where
         digests: Vec<TransactionDigest>,
         options: Option<SuiTransactionResponseOptions>,
     ) -> RpcResult<Vec<SuiTransactionResponse>> {
+        // Input objects are read from the history of objects of the full node.
         if self
             .method_to_be_forwarded
             .contains(&"muti_get_transactions".to_string())
+            || options
+                .as_ref()
+                .map_or(false, |options| options.show_input_objects)
         {
             return self
                 .fullnode
@@ -286,6 +412,9 @@ where
         &self,
         package: ObjectID,
     ) -> RpcResult<BTreeMap<String, SuiMoveNormalizedModule>> {
+        if let Some(modules) = self.get_package_abi(package)? {
+            return Ok(modules);
+        }
         self.fullnode
             .get_normalized_move_modules_by_package(package)
             .await
@@ -296,6 +425,9 @@ where
         package: ObjectID,
         module_name: String,
     ) -> RpcResult<SuiMoveNormalizedModule> {
+        if let Some(module) = self.get_module_abi(package, &module_name)? {
+            return Ok(module);
+        }
         self.fullnode
             .get_normalized_move_module(package, module_name)
             .await
@@ -307,6 +439,12 @@ where
         module_name: String,
         struct_name: String,
     ) -> RpcResult<SuiMoveNormalizedStruct> {
+        if let Some(mut module) = self.get_module_abi(package, &module_name)? {
+            return Ok(module
+                .structs
+                .remove(&struct_name)
+                .ok_or_else(|| anyhow!("No struct was found with struct name {}", struct_name))?);
+        }
         self.fullnode
             .get_normalized_move_struct(package, module_name, struct_name)
             .await
@@ -318,11 +456,23 @@ where
         module_name: String,
         function_name: String,
     ) -> RpcResult<SuiMoveNormalizedFunction> {
+        if let Some(mut module) = self.get_module_abi(package, &module_name)? {
+            return Ok(module
+                .exposed_functions
+                .remove(&function_name)
+                .ok_or_else(|| {
+                    anyhow!("No function was found with function name {}", function_name)
+                })?);
+        }
         self.fullnode
             .get_normalized_move_function(package, module_name, function_name)
             .await
     }
 
+    async fn get_move_struct_layout(&self, struct_type: String) -> RpcResult<SuiMoveStructLayout> {
+        self.fullnode.get_move_struct_layout(struct_type).await
+    }
+
     async fn get_move_function_arg_types(
         &self,
         package: ObjectID,
@@ -364,6 +514,30 @@ where
         }
         Ok(self.get_checkpoint(id).await?)
     }
+
+    async fn get_checkpoint_attestation(
+        &self,
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointAttestation> {
+        // The indexer does not store the signatures of the validators.
+        self.fullnode.get_checkpoint_attestation(id).await
+    }
+
+    async fn get_node_info(&self) -> RpcResult<SuiNodeInfo> {
+        let mut info = self.fullnode.get_node_info().await?;
+        // Checkpoints and transactions are served from the indexer, which never prunes them.
+        let latest_checkpoint = self.get_latest_checkpoint_sequence_number().await? as u64;
+        let checkpoint = self.get_checkpoint(latest_checkpoint.into()).await?;
+        info.indexer_lag = Some(info.latest_checkpoint.saturating_sub(latest_checkpoint));
+        info.latest_checkpoint = latest_checkpoint;
+        info.latest_checkpoint_timestamp_ms = checkpoint.timestamp_ms;
+        info.lowest_available_checkpoint = 0;
+        info.pruning = SuiPruningConfig {
+            num_epochs_to_retain: None,
+            archive_fallback: false,
+        };
+        Ok(info)
+    }
 }
 
 impl<S> SuiRpcModule for ReadApi<S>
@@ -10,10 +10,11 @@ use sui_json::SuiJsonValue;
 use sui_json_rpc::api::{TransactionBuilderClient, TransactionBuilderServer};
 use sui_json_rpc::SuiRpcModule;
 use sui_json_rpc_types::{
-    BigInt, RPCTransactionRequestParams, SuiTransactionBuilderMode, SuiTypeTag, TransactionBytes,
+    BatchTransferParams, BigInt, RPCTransactionRequestParams, SuiTransactionBuilderMode,
+    SuiTypeTag, TransactionBytes,
 };
 use sui_open_rpc::Module;
-use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::base_types::{EpochId, ObjectID, SuiAddress};
 
 pub(crate) struct TransactionBuilderApi {
     fullnode: HttpClient,
@@ -94,6 +95,19 @@ impl TransactionBuilderServer for TransactionBuilderApi {
             .await
     }
 
+    async fn auto_pay_sui(
+        &self,
+        signer: SuiAddress,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<BigInt>,
+        max_input_coins: Option<usize>,
+        gas_budget: u64,
+    ) -> RpcResult<Vec<TransactionBytes>> {
+        self.fullnode
+            .auto_pay_sui(signer, recipients, amounts, max_input_coins, gas_budget)
+            .await
+    }
+
     async fn publish(
         &self,
         sender: SuiAddress,
@@ -106,6 +120,29 @@ impl TransactionBuilderServer for TransactionBuilderApi {
             .await
     }
 
+    async fn upgrade_package(
+        &self,
+        sender: SuiAddress,
+        package_id: ObjectID,
+        compiled_modules: Vec<Base64>,
+        dependencies: Vec<ObjectID>,
+        upgrade_capability: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes> {
+        self.fullnode
+            .upgrade_package(
+                sender,
+                package_id,
+                compiled_modules,
+                dependencies,
+                upgrade_capability,
+                gas,
+                gas_budget,
+            )
+            .await
+    }
+
     async fn split_coin(
         &self,
         signer: SuiAddress,
@@ -185,6 +222,18 @@ impl TransactionBuilderServer for TransactionBuilderApi {
             .await
     }
 
+    async fn batch_transfer(
+        &self,
+        signer: SuiAddress,
+        transfers: Vec<BatchTransferParams>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes> {
+        self.fullnode
+            .batch_transfer(signer, transfers, gas, gas_budget)
+            .await
+    }
+
     async fn request_add_delegation(
         &self,
         signer: SuiAddress,
@@ -211,6 +260,16 @@ impl TransactionBuilderServer for TransactionBuilderApi {
             .request_withdraw_delegation(signer, delegation, staked_sui, gas, gas_budget)
             .await
     }
+
+    async fn set_transaction_expiration(
+        &self,
+        tx_bytes: Base64,
+        epoch: EpochId,
+    ) -> RpcResult<TransactionBytes> {
+        self.fullnode
+            .set_transaction_expiration(tx_bytes, epoch)
+            .await
+    }
 }
 
 impl SuiRpcModule for TransactionBuilderApi {
@@ -8,9 +8,12 @@ use jsonrpsee::http_client::HttpClient;
 use jsonrpsee::RpcModule;
 use sui_json_rpc::api::{WriteApiClient, WriteApiServer};
 use sui_json_rpc::SuiRpcModule;
-use sui_json_rpc_types::{DevInspectResults, DryRunTransactionResponse, SuiTransactionResponse};
+use sui_json_rpc_types::{
+    DevInspectResults, DryRunTransactionResponse, SuiTransactionResponse,
+    SuiTransactionSubmissionStatus,
+};
 use sui_open_rpc::Module;
-use sui_types::base_types::{EpochId, SuiAddress};
+use sui_types::base_types::{EpochId, SuiAddress, TransactionDigest};
 use sui_types::messages::ExecuteTransactionRequestType;
 
 pub(crate) struct WriteApi {
@@ -58,6 +61,25 @@ impl WriteApiServer for WriteApi {
             .await
     }
 
+    async fn enqueue_transaction(
+        &self,
+        tx_bytes: Base64,
+        signatures: Vec<Base64>,
+    ) -> RpcResult<TransactionDigest> {
+        self.fullnode
+            .enqueue_transaction(tx_bytes, signatures)
+            .await
+    }
+
+    async fn get_transaction_submission_status(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionSubmissionStatus> {
+        self.fullnode
+            .get_transaction_submission_status(digest)
+            .await
+    }
+
     async fn dev_inspect_transaction(
         &self,
         sender_address: SuiAddress,
@@ -0,0 +1,50 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use prometheus::Registry;
+use sui_indexer::errors::IndexerError;
+use sui_indexer::migrations::setup_database;
+use sui_indexer::store::PgIndexerStore;
+use sui_indexer::{get_pg_pool_connection, new_pg_connection_pool, Indexer, InterestList};
+use sui_types::base_types::{ObjectID, SuiAddress};
+
+/// Reindex the checkpoints that failed repeatedly and were moved to the dead letters by the
+/// indexer, typically after deploying the fix for the failure. Checkpoints that fail again are
+/// kept in the dead letters.
+#[tokio::main]
+async fn main() -> Result<(), IndexerError> {
+    let _guard = telemetry_subscribers::TelemetryConfig::new()
+        .with_env()
+        .init();
+
+    let config = ReprocessConfig::parse();
+    let registry = Registry::default();
+    let pg_connection_pool = new_pg_connection_pool(&config.db_url).await?;
+    // Migrations are left to the indexer, the DB must be at its schema already.
+    setup_database(&mut get_pg_pool_connection(&pg_connection_pool)?, false)?;
+    let store = PgIndexerStore::new(pg_connection_pool, &registry);
+
+    let interest_list = InterestList::new(config.index_packages, config.index_addresses);
+    Indexer::reprocess_dead_letters(&config.rpc_client_url, &registry, store, interest_list).await
+}
+
+#[derive(Parser)]
+#[clap(
+    name = "Reprocess dead letters",
+    about = "Reindex the checkpoints that the Sui indexer failed to process"
+)]
+pub struct ReprocessConfig {
+    #[clap(long)]
+    pub db_url: String,
+    #[clap(long)]
+    pub rpc_client_url: String,
+    /// Package of the interest list of the indexer when it runs in filtered mode, can be
+    /// repeated.
+    #[clap(long = "index-package")]
+    pub index_packages: Vec<ObjectID>,
+    /// Address of the interest list of the indexer when it runs in filtered mode, can be
+    /// repeated.
+    #[clap(long = "index-address")]
+    pub index_addresses: Vec<SuiAddress>,
+}
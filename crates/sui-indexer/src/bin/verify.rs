@@ -0,0 +1,84 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use prometheus::Registry;
+use sui_indexer::errors::IndexerError;
+use sui_indexer::migrations::setup_database;
+use sui_indexer::store::{IndexerStore, PgIndexerStore};
+use sui_indexer::{get_pg_pool_connection, new_pg_connection_pool, Indexer, InterestList};
+use sui_types::base_types::{ObjectID, SuiAddress};
+use tracing::info;
+
+/// Cross-check the indexed checkpoints against a full node, e.g. after a crash or a migration:
+/// checkpoints without gaps, with the digests and transactions of the full node, and
+/// transactions with the effects of the full node. Inconsistent checkpoints are reindexed with
+/// `--repair`.
+#[tokio::main]
+async fn main() -> Result<(), IndexerError> {
+    let _guard = telemetry_subscribers::TelemetryConfig::new()
+        .with_env()
+        .init();
+
+    let config = VerifyConfig::parse();
+    let registry = Registry::default();
+    let pg_connection_pool = new_pg_connection_pool(&config.db_url).await?;
+    // Migrations are left to the indexer, the DB must be at its schema already.
+    setup_database(&mut get_pg_pool_connection(&pg_connection_pool)?, false)?;
+    let store = PgIndexerStore::new(pg_connection_pool, &registry);
+
+    let start = config.start_checkpoint.unwrap_or(0);
+    let end = match config.end_checkpoint {
+        Some(end) => end,
+        None => store.get_latest_checkpoint_sequence_number()?.max(0) as u64,
+    };
+    let inconsistent = Indexer::verify(
+        &config.rpc_client_url,
+        &registry,
+        store,
+        start,
+        end,
+        config.repair,
+        InterestList::new(config.index_packages, config.index_addresses),
+    )
+    .await?;
+    if inconsistent.is_empty() {
+        info!("Checkpoints {start} to {end} are consistent with the full node.");
+    } else if config.repair {
+        info!("Reindexed inconsistent checkpoints {:?}.", inconsistent);
+    } else {
+        // Run again with --repair to reindex them.
+        return Err(IndexerError::InconsistentCheckpoints(inconsistent));
+    }
+    Ok(())
+}
+
+#[derive(Parser)]
+#[clap(
+    name = "Verify indexer",
+    about = "Verify the checkpoints indexed by the Sui indexer against a full node",
+    rename_all = "kebab-case"
+)]
+pub struct VerifyConfig {
+    #[clap(long)]
+    pub db_url: String,
+    #[clap(long)]
+    pub rpc_client_url: String,
+    /// First checkpoint to verify, the genesis checkpoint by default.
+    #[clap(long)]
+    pub start_checkpoint: Option<u64>,
+    /// Last checkpoint to verify, the latest indexed checkpoint by default.
+    #[clap(long)]
+    pub end_checkpoint: Option<u64>,
+    /// Reindex the inconsistent checkpoints from the full node.
+    #[clap(long)]
+    pub repair: bool,
+    /// Package of the interest list of the indexer when it runs in filtered mode, can be
+    /// repeated.
+    #[clap(long = "index-package")]
+    pub index_packages: Vec<ObjectID>,
+    /// Address of the interest list of the indexer when it runs in filtered mode, can be
+    /// repeated.
+    #[clap(long = "index-address")]
+    pub index_addresses: Vec<SuiAddress>,
+}
@@ -1,8 +1,10 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use jsonrpsee::core::Error as RpcError;
 use jsonrpsee::types::error::CallError;
+use sui_sdk::error::Error as SuiSdkError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -31,25 +33,116 @@ pub enum IndexerError {
     #[error("Indexer failed to get a pool connection from PG connection pool with error: `{0}`")]
     PgPoolConnectionError(String),
 
-    #[error("Indexer failed to read PostgresDB with error: `{0}`")]
-    PostgresReadError(String),
+    /// A failure of PostgresDB that may not happen again, e.g. a closed connection or a
+    /// serialization conflict between concurrent transactions.
+    #[error("Indexer failed {context} with transient PostgresDB error: `{source}`")]
+    TransientDbError {
+        context: String,
+        source: DieselError,
+    },
 
-    #[error("Indexer failed to commit changes to PostgresDB with error: `{0}`")]
-    PostgresWriteError(String),
+    /// A write rejected by a constraint of the schema, e.g. a duplicate key.
+    #[error("Indexer failed {context} violating a PostgresDB constraint: `{source}`")]
+    ConstraintViolation {
+        context: String,
+        source: DieselError,
+    },
+
+    /// Any other failure of PostgresDB, e.g. a missing row or a query invalid for the schema.
+    #[error("Indexer failed {context} with PostgresDB error: `{source}`")]
+    DbError {
+        context: String,
+        source: DieselError,
+    },
+
+    #[error("Indexer failed {context} from the full node: `{source}`")]
+    RpcFetchError {
+        context: String,
+        source: SuiSdkError,
+    },
+
+    /// Data contradicting other data of the full node or of the DB, which retrying won't fix.
+    #[error("Indexer found inconsistent data: {0}")]
+    DataInconsistency(String),
 
     #[error("Indexer failed to initialize fullnode RPC client with error: `{0}`")]
     RpcClientInitError(String),
 
     #[error("Indexer failed to serialize/deserialize JSON with error: `{0}`")]
     JsonSerdeError(String),
+
+    #[error("Indexer failed to migrate the DB schema with error: `{0}`")]
+    DatabaseMigrationError(String),
+
+    #[error("Indexer failed to export data to object storage with error: `{0}`")]
+    ExportError(String),
+
+    #[error("Indexer failed to publish data to the external sink with error: `{0}`")]
+    SinkError(String),
+
+    #[error("Indexer found checkpoints inconsistent with the full node: `{0:?}`")]
+    InconsistentCheckpoints(Vec<u64>),
+
+    #[error("Indexer failed to start the GraphQL server with error: `{0}`")]
+    GraphQLServerError(String),
+
+    #[error("Indexer failed to decode cursor `{0}`")]
+    InvalidCursor(String),
 }
 
 impl IndexerError {
+    /// Classifies a failed query of PostgresDB, `context` describing the query, e.g.
+    /// "reading checkpoint 10".
+    pub fn db(context: impl Into<String>, source: DieselError) -> Self {
+        let context = context.into();
+        match &source {
+            DieselError::DatabaseError(
+                DatabaseErrorKind::SerializationFailure
+                | DatabaseErrorKind::UnableToSendCommand
+                | DatabaseErrorKind::ClosedConnection,
+                _,
+            ) => IndexerError::TransientDbError { context, source },
+            DieselError::DatabaseError(
+                DatabaseErrorKind::UniqueViolation
+                | DatabaseErrorKind::ForeignKeyViolation
+                | DatabaseErrorKind::NotNullViolation
+                | DatabaseErrorKind::CheckViolation,
+                _,
+            ) => IndexerError::ConstraintViolation { context, source },
+            _ => IndexerError::DbError { context, source },
+        }
+    }
+
+    pub fn rpc_fetch(context: impl Into<String>, source: SuiSdkError) -> Self {
+        IndexerError::RpcFetchError {
+            context: context.into(),
+            source,
+        }
+    }
+
+    /// Whether the operation that failed may succeed if retried. Other errors, e.g. data
+    /// inconsistencies, need to be fixed first.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            IndexerError::TransientDbError { .. }
+            | IndexerError::PgPoolConnectionError(_)
+            | IndexerError::FullNodeReadingError(_) => true,
+            // Failed calls of the full node, as opposed to invalid responses.
+            IndexerError::RpcFetchError { source, .. } => {
+                matches!(source, SuiSdkError::RpcError(_))
+            }
+            _ => false,
+        }
+    }
+
     pub fn name(&self) -> String {
         match self {
             IndexerError::FullNodeReadingError(_) => "FullNodeReadingError".into(),
-            IndexerError::PostgresReadError(_) => "PostgresReadError".into(),
-            IndexerError::PostgresWriteError(_) => "PostgresWriteError".into(),
+            IndexerError::TransientDbError { .. } => "TransientDbError".into(),
+            IndexerError::ConstraintViolation { .. } => "ConstraintViolation".into(),
+            IndexerError::DbError { .. } => "DbError".into(),
+            IndexerError::RpcFetchError { .. } => "RpcFetchError".into(),
+            IndexerError::DataInconsistency(_) => "DataInconsistency".into(),
             IndexerError::InsertableParsingError(_) => "InsertableParsingError".into(),
             IndexerError::DateTimeParsingError(_) => "DateTimeParsingError".into(),
             IndexerError::ObjectMutationNotAvailable => "ObjectMutationNotAvailable".into(),
@@ -59,6 +152,12 @@ impl IndexerError {
             IndexerError::PgPoolConnectionError(_) => "PgPoolConnectionError".into(),
             IndexerError::JsonRpcServerError(_) => "JsonRpcServerError".into(),
             IndexerError::JsonSerdeError(_) => "JsonSerdeError".into(),
+            IndexerError::DatabaseMigrationError(_) => "DatabaseMigrationError".into(),
+            IndexerError::ExportError(_) => "ExportError".into(),
+            IndexerError::SinkError(_) => "SinkError".into(),
+            IndexerError::InconsistentCheckpoints(_) => "InconsistentCheckpoints".into(),
+            IndexerError::GraphQLServerError(_) => "GraphQLServerError".into(),
+            IndexerError::InvalidCursor(_) => "InvalidCursor".into(),
         }
     }
 }
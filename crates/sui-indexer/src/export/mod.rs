@@ -0,0 +1,150 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export of indexed checkpoints, transactions and events to object storage as Parquet files,
+//! for ingestion into data warehouses.
+//!
+//! The checkpoints are exported in batches that do not span epochs. Each batch is written as one
+//! file per table at `<table>/epoch=<epoch>/<first>_<last>.parquet`, followed by the manifest of
+//! the batch at `_manifests/<first>_<last>.json`, which lists the files of the batch. A batch is
+//! only complete once its manifest is written: files are named after the checkpoints they hold,
+//! so retrying a batch after a failure overwrites the files of the failed attempt, and consumers
+//! that only load the files listed in manifests see each checkpoint exactly once.
+
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use object_store::aws::AmazonS3Builder;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::local::LocalFileSystem;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::IndexerError;
+
+pub mod tables;
+
+const MANIFESTS_DIR: &str = "_manifests";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExportManifest {
+    pub epoch: i64,
+    pub first_checkpoint: i64,
+    pub last_checkpoint: i64,
+    pub files: Vec<ExportedFile>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExportedFile {
+    pub table: String,
+    /// Location of the file relative to the root of the export.
+    pub path: String,
+    pub rows: usize,
+}
+
+/// Object store and root path of the export at `url`, one of `s3://<bucket>/<prefix>`,
+/// `gs://<bucket>/<prefix>` or `file://<directory>`. Credentials of S3 and GCS are read from
+/// the environment.
+pub fn object_store_from_url(url: &str) -> Result<(Arc<dyn ObjectStore>, Path), IndexerError> {
+    let invalid_url =
+        |e: String| IndexerError::ExportError(format!("Invalid export destination {url}: {e}"));
+    let (scheme, location) = url
+        .split_once("://")
+        .ok_or_else(|| invalid_url("missing scheme".to_string()))?;
+    if scheme == "file" {
+        let store =
+            LocalFileSystem::new_with_prefix(location).map_err(|e| invalid_url(e.to_string()))?;
+        return Ok((Arc::new(store), Path::default()));
+    }
+    let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+    let store: Arc<dyn ObjectStore> = match scheme {
+        "s3" => Arc::new(
+            AmazonS3Builder::from_env()
+                .with_bucket_name(bucket)
+                .build()
+                .map_err(|e| invalid_url(e.to_string()))?,
+        ),
+        "gs" => Arc::new(
+            GoogleCloudStorageBuilder::from_env()
+                .with_bucket_name(bucket)
+                .build()
+                .map_err(|e| invalid_url(e.to_string()))?,
+        ),
+        _ => return Err(invalid_url(format!("unsupported scheme {scheme}"))),
+    };
+    let prefix = Path::parse(prefix).map_err(|e| invalid_url(e.to_string()))?;
+    Ok((store, prefix))
+}
+
+/// Name of the objects of a batch, zero padded so that they sort by checkpoint.
+fn batch_name(first_checkpoint: i64, last_checkpoint: i64) -> String {
+    format!("{first_checkpoint:020}_{last_checkpoint:020}")
+}
+
+pub fn data_file_path(
+    table: &str,
+    epoch: i64,
+    first_checkpoint: i64,
+    last_checkpoint: i64,
+) -> String {
+    format!(
+        "{table}/epoch={epoch}/{}.parquet",
+        batch_name(first_checkpoint, last_checkpoint)
+    )
+}
+
+fn manifest_path(first_checkpoint: i64, last_checkpoint: i64) -> String {
+    format!(
+        "{MANIFESTS_DIR}/{}.json",
+        batch_name(first_checkpoint, last_checkpoint)
+    )
+}
+
+/// Child of the root of the export at the relative `path`.
+pub fn export_path(root: &Path, path: &str) -> Path {
+    path.split('/')
+        .fold(root.clone(), |path, part| path.child(part))
+}
+
+/// Writes the manifest of a batch, completing it. Must only be called once all the files of the
+/// batch are written.
+pub async fn write_manifest(
+    store: &dyn ObjectStore,
+    root: &Path,
+    manifest: &ExportManifest,
+) -> Result<(), IndexerError> {
+    let bytes = serde_json::to_vec_pretty(manifest)
+        .map_err(|e| IndexerError::JsonSerdeError(e.to_string()))?;
+    let path = export_path(
+        root,
+        &manifest_path(manifest.first_checkpoint, manifest.last_checkpoint),
+    );
+    store
+        .put(&path, bytes.into())
+        .await
+        .map_err(|e| IndexerError::ExportError(format!("Failed writing manifest {path}: {e}")))
+}
+
+/// Last checkpoint of the completed batches of the export, `None` if no batch was completed yet.
+pub async fn last_exported_checkpoint(
+    store: &dyn ObjectStore,
+    root: &Path,
+) -> Result<Option<i64>, IndexerError> {
+    let manifests = export_path(root, MANIFESTS_DIR);
+    let objects = store
+        .list(Some(&manifests))
+        .await
+        .map_err(|e| IndexerError::ExportError(format!("Failed listing manifests: {e}")))?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(|e| IndexerError::ExportError(format!("Failed listing manifests: {e}")))?;
+    Ok(objects
+        .iter()
+        .filter_map(|object| {
+            let name = object.location.filename()?.strip_suffix(".json")?;
+            let (_, last_checkpoint) = name.split_once('_')?;
+            last_checkpoint.parse::<i64>().ok()
+        })
+        .max())
+}
@@ -0,0 +1,271 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{
+    ArrayRef, Float32Array, Int64Array, ListArray, RecordBatch, StringArray,
+    TimestampMillisecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::NaiveDateTime;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use crate::errors::IndexerError;
+use crate::models::checkpoints::Checkpoint;
+use crate::models::events::Event;
+use crate::models::transactions::Transaction;
+
+pub const CHECKPOINTS_TABLE: &str = "checkpoints";
+pub const TRANSACTIONS_TABLE: &str = "transactions";
+pub const EVENTS_TABLE: &str = "events";
+
+pub fn checkpoints_batch(checkpoints: &[Checkpoint]) -> Result<RecordBatch, IndexerError> {
+    let schema = Schema::new(vec![
+        Field::new("sequence_number", DataType::Int64, false),
+        Field::new("checkpoint_digest", DataType::Utf8, false),
+        Field::new("epoch", DataType::Int64, false),
+        Field::new("previous_checkpoint_digest", DataType::Utf8, true),
+        Field::new("transactions", string_list_type(), false),
+        Field::new("total_gas_cost", DataType::Int64, false),
+        Field::new("total_computation_cost", DataType::Int64, false),
+        Field::new("total_storage_cost", DataType::Int64, false),
+        Field::new("total_storage_rebate", DataType::Int64, false),
+        Field::new("total_transactions", DataType::Int64, false),
+        Field::new("total_transactions_current_epoch", DataType::Int64, false),
+        Field::new("total_transactions_from_genesis", DataType::Int64, false),
+        Field::new("timestamp", timestamp_type(), false),
+        Field::new("checkpoint_tps", DataType::Float32, false),
+        Field::new("next_epoch_committee", DataType::Utf8, true),
+        Field::new("next_epoch_protocol_version", DataType::Int64, true),
+        Field::new("end_of_epoch_data", DataType::Utf8, true),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        int64_column(checkpoints.iter().map(|c| c.sequence_number)),
+        string_column(
+            checkpoints
+                .iter()
+                .map(|c| Some(c.checkpoint_digest.as_str())),
+        ),
+        int64_column(checkpoints.iter().map(|c| c.epoch)),
+        string_column(
+            checkpoints
+                .iter()
+                .map(|c| c.previous_checkpoint_digest.as_deref()),
+        ),
+        string_list_column(checkpoints.iter().map(|c| &c.transactions)),
+        int64_column(checkpoints.iter().map(|c| c.total_gas_cost)),
+        int64_column(checkpoints.iter().map(|c| c.total_computation_cost)),
+        int64_column(checkpoints.iter().map(|c| c.total_storage_cost)),
+        int64_column(checkpoints.iter().map(|c| c.total_storage_rebate)),
+        int64_column(checkpoints.iter().map(|c| c.total_transactions)),
+        int64_column(
+            checkpoints
+                .iter()
+                .map(|c| c.total_transactions_current_epoch),
+        ),
+        int64_column(
+            checkpoints
+                .iter()
+                .map(|c| c.total_transactions_from_genesis),
+        ),
+        Arc::new(TimestampMillisecondArray::from(
+            checkpoints
+                .iter()
+                .map(|c| c.timestamp_ms)
+                .collect::<Vec<_>>(),
+        )),
+        Arc::new(Float32Array::from(
+            checkpoints
+                .iter()
+                .map(|c| c.checkpoint_tps)
+                .collect::<Vec<_>>(),
+        )),
+        string_column(
+            checkpoints
+                .iter()
+                .map(|c| c.next_epoch_committee.as_deref()),
+        ),
+        Arc::new(Int64Array::from(
+            checkpoints
+                .iter()
+                .map(|c| c.next_epoch_protocol_version)
+                .collect::<Vec<_>>(),
+        )),
+        string_column(checkpoints.iter().map(|c| c.end_of_epoch_data.as_deref())),
+    ];
+    record_batch(CHECKPOINTS_TABLE, schema, columns)
+}
+
+pub fn transactions_batch(transactions: &[Transaction]) -> Result<RecordBatch, IndexerError> {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("transaction_digest", DataType::Utf8, false),
+        Field::new("checkpoint_sequence_number", DataType::Int64, false),
+        Field::new("transaction_time", timestamp_type(), true),
+        Field::new("sender", DataType::Utf8, false),
+        Field::new("recipients", string_list_type(), false),
+        Field::new("transaction_kinds", string_list_type(), false),
+        Field::new("created", string_list_type(), false),
+        Field::new("mutated", string_list_type(), false),
+        Field::new("deleted", string_list_type(), false),
+        Field::new("unwrapped", string_list_type(), false),
+        Field::new("wrapped", string_list_type(), false),
+        Field::new("move_calls", string_list_type(), false),
+        Field::new("gas_object_id", DataType::Utf8, false),
+        Field::new("gas_object_sequence", DataType::Int64, false),
+        Field::new("gas_object_digest", DataType::Utf8, false),
+        Field::new("gas_budget", DataType::Int64, false),
+        Field::new("gas_price", DataType::Int64, false),
+        Field::new("total_gas_cost", DataType::Int64, false),
+        Field::new("computation_cost", DataType::Int64, false),
+        Field::new("storage_cost", DataType::Int64, false),
+        Field::new("storage_rebate", DataType::Int64, false),
+        Field::new("transaction_content", DataType::Utf8, false),
+        Field::new("transaction_effects_content", DataType::Utf8, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        // id is always set on rows read back from the store
+        int64_column(transactions.iter().map(|t| t.id.unwrap_or_default())),
+        string_column(
+            transactions
+                .iter()
+                .map(|t| Some(t.transaction_digest.as_str())),
+        ),
+        int64_column(transactions.iter().map(|t| t.checkpoint_sequence_number)),
+        timestamp_column(transactions.iter().map(|t| t.transaction_time)),
+        string_column(transactions.iter().map(|t| Some(t.sender.as_str()))),
+        string_list_column(transactions.iter().map(|t| &t.recipients)),
+        string_list_column(transactions.iter().map(|t| &t.transaction_kinds)),
+        string_list_column(transactions.iter().map(|t| &t.created)),
+        string_list_column(transactions.iter().map(|t| &t.mutated)),
+        string_list_column(transactions.iter().map(|t| &t.deleted)),
+        string_list_column(transactions.iter().map(|t| &t.unwrapped)),
+        string_list_column(transactions.iter().map(|t| &t.wrapped)),
+        string_list_column(transactions.iter().map(|t| &t.move_calls)),
+        string_column(transactions.iter().map(|t| Some(t.gas_object_id.as_str()))),
+        int64_column(transactions.iter().map(|t| t.gas_object_sequence)),
+        string_column(
+            transactions
+                .iter()
+                .map(|t| Some(t.gas_object_digest.as_str())),
+        ),
+        int64_column(transactions.iter().map(|t| t.gas_budget)),
+        int64_column(transactions.iter().map(|t| t.gas_price)),
+        int64_column(transactions.iter().map(|t| t.total_gas_cost)),
+        int64_column(transactions.iter().map(|t| t.computation_cost)),
+        int64_column(transactions.iter().map(|t| t.storage_cost)),
+        int64_column(transactions.iter().map(|t| t.storage_rebate)),
+        string_column(
+            transactions
+                .iter()
+                .map(|t| Some(t.transaction_content.as_str())),
+        ),
+        string_column(
+            transactions
+                .iter()
+                .map(|t| Some(t.transaction_effects_content.as_str())),
+        ),
+    ];
+    record_batch(TRANSACTIONS_TABLE, schema, columns)
+}
+
+pub fn events_batch(events: &[Event]) -> Result<RecordBatch, IndexerError> {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("transaction_digest", DataType::Utf8, false),
+        Field::new("checkpoint_sequence_number", DataType::Int64, false),
+        Field::new("event_sequence", DataType::Int64, false),
+        Field::new("event_time", timestamp_type(), true),
+        Field::new("event_type", DataType::Utf8, false),
+        Field::new("event_content", DataType::Utf8, false),
+        Field::new("event_json", DataType::Utf8, true),
+        Field::new("package_id", DataType::Utf8, true),
+        Field::new("transaction_module", DataType::Utf8, true),
+        Field::new("move_event_type", DataType::Utf8, true),
+        Field::new("sender", DataType::Utf8, true),
+    ]);
+    let event_json = events
+        .iter()
+        .map(|e| e.event_json.as_ref().map(|json| json.to_string()))
+        .collect::<Vec<_>>();
+    let columns: Vec<ArrayRef> = vec![
+        int64_column(events.iter().map(|e| e.id.unwrap_or_default())),
+        string_column(events.iter().map(|e| Some(e.transaction_digest.as_str()))),
+        int64_column(events.iter().map(|e| e.checkpoint)),
+        int64_column(events.iter().map(|e| e.event_sequence)),
+        timestamp_column(events.iter().map(|e| e.event_time)),
+        string_column(events.iter().map(|e| Some(e.event_type.as_str()))),
+        string_column(events.iter().map(|e| Some(e.event_content.as_str()))),
+        string_column(event_json.iter().map(|json| json.as_deref())),
+        string_column(events.iter().map(|e| e.package_id.as_deref())),
+        string_column(events.iter().map(|e| e.transaction_module.as_deref())),
+        string_column(events.iter().map(|e| e.move_event_type.as_deref())),
+        string_column(events.iter().map(|e| e.sender.as_deref())),
+    ];
+    record_batch(EVENTS_TABLE, schema, columns)
+}
+
+/// Encodes a record batch as a single Parquet file.
+pub fn to_parquet(batch: &RecordBatch) -> Result<Vec<u8>, IndexerError> {
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props))
+        .map_err(|e| IndexerError::ExportError(format!("Failed creating Parquet writer: {e}")))?;
+    writer
+        .write(batch)
+        .and_then(|_| writer.close())
+        .map_err(|e| IndexerError::ExportError(format!("Failed writing Parquet file: {e}")))?;
+    Ok(buffer)
+}
+
+fn record_batch(
+    table: &str,
+    schema: Schema,
+    columns: Vec<ArrayRef>,
+) -> Result<RecordBatch, IndexerError> {
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(|e| {
+        IndexerError::ExportError(format!("Failed building {table} record batch: {e}"))
+    })
+}
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Millisecond, None)
+}
+
+fn string_list_type() -> DataType {
+    DataType::List(Box::new(Field::new("item", DataType::Utf8, true)))
+}
+
+fn int64_column(values: impl Iterator<Item = i64>) -> ArrayRef {
+    Arc::new(Int64Array::from(values.collect::<Vec<_>>()))
+}
+
+fn string_column<'a>(values: impl Iterator<Item = Option<&'a str>>) -> ArrayRef {
+    Arc::new(StringArray::from(values.collect::<Vec<_>>()))
+}
+
+fn timestamp_column(values: impl Iterator<Item = Option<NaiveDateTime>>) -> ArrayRef {
+    Arc::new(TimestampMillisecondArray::from(
+        values
+            .map(|t| t.map(|t| t.timestamp_millis()))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn string_list_column<'a>(values: impl Iterator<Item = &'a Vec<Option<String>>>) -> ArrayRef {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for list in values {
+        for value in list {
+            builder.values().append_option(value.as_deref());
+        }
+        builder.append(true);
+    }
+    let array: ListArray = builder.finish();
+    Arc::new(array)
+}
@@ -0,0 +1,103 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! GraphQL API over the indexed transactions, objects, events, checkpoints and addresses, so that
+//! explorers can read related data in one request instead of chaining JSON-RPC calls.
+//!
+//! Lists are relay-style connections, paginated with `first` and `after` or with `last` and
+//! `before`. The columns read for transactions depend on the requested fields: pages of digests
+//! only read the transactions index, and the content and effects of transactions are only read
+//! when requested. All the reads of a request are made from one snapshot of the store.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::extract::State;
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::{Router, Server};
+use mysten_metrics::spawn_monitored_task;
+use tracing::{error, info, warn};
+
+use crate::errors::IndexerError;
+use crate::store::IndexerStore;
+
+pub use types::Query;
+
+mod pagination;
+mod types;
+
+pub type IndexerSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+type GraphQLStore = Arc<dyn IndexerStore + Send + Sync>;
+
+const GRAPHQL_PATH: &str = "/graphql";
+// Bounds of the queries, as connections nested in connections read pages of pages.
+const MAX_QUERY_DEPTH: usize = 10;
+const MAX_QUERY_COMPLEXITY: usize = 50_000;
+
+pub fn build_schema<S: IndexerStore + Send + Sync + 'static>(store: S) -> IndexerSchema {
+    schema_with_store(Arc::new(store))
+}
+
+fn schema_with_store(store: GraphQLStore) -> IndexerSchema {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(store)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+}
+
+#[derive(Clone)]
+struct ServerState {
+    schema: IndexerSchema,
+    store: GraphQLStore,
+}
+
+/// Serve the GraphQL API on `address` at [GRAPHQL_PATH], along with the GraphiQL IDE on GET
+/// requests.
+pub fn spawn_graphql_server<S: IndexerStore + Send + Sync + 'static>(
+    address: SocketAddr,
+    store: S,
+) -> Result<(), IndexerError> {
+    let store: GraphQLStore = Arc::new(store);
+    let state = ServerState {
+        schema: schema_with_store(store.clone()),
+        store,
+    };
+    let app = Router::new()
+        .route(GRAPHQL_PATH, get(graphiql).post(execute))
+        .with_state(state);
+    let server = Server::try_bind(&address)
+        .map_err(|e| IndexerError::GraphQLServerError(e.to_string()))?
+        .serve(app.into_make_service());
+    spawn_monitored_task!(async move {
+        if let Err(e) = server.await {
+            error!("GraphQL server failed with error: {:?}", e);
+        }
+    });
+    info!("Sui indexer GraphQL server started at {address}{GRAPHQL_PATH}...");
+    Ok(())
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint(GRAPHQL_PATH).finish())
+}
+
+async fn execute(State(state): State<ServerState>, request: GraphQLRequest) -> GraphQLResponse {
+    let request = request.into_inner();
+    match state.store.begin_read_snapshot() {
+        Ok(snapshot) => snapshot.run(state.schema.execute(request)).await,
+        Err(e) => {
+            warn!(
+                "Failed beginning read snapshot, reading without snapshot: {:?}",
+                e
+            );
+            state.schema.execute(request).await
+        }
+    }
+    .into()
+}
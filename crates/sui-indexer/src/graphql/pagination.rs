@@ -0,0 +1,113 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_graphql::connection::{Connection, Edge};
+use async_graphql::{Error, OutputType, Result};
+
+use crate::store::StoreCursor;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Page selected by the relay-style arguments of a connection field: the `first` items after the
+/// cursor `after`, or the `last` items before the cursor `before`.
+pub(crate) struct Page {
+    pub cursor: Option<String>,
+    pub limit: usize,
+    pub is_backward: bool,
+}
+
+impl Page {
+    pub fn new(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Self> {
+        if (first.is_some() || after.is_some()) && (last.is_some() || before.is_some()) {
+            return Err(Error::new(
+                "`first` and `after` cannot be combined with `last` and `before`",
+            ));
+        }
+        let limit = match first.or(last) {
+            None => DEFAULT_PAGE_SIZE,
+            Some(size) if size >= 1 && size as usize <= MAX_PAGE_SIZE => size as usize,
+            Some(size) => {
+                return Err(Error::new(format!(
+                    "Page size {size} is not between 1 and {MAX_PAGE_SIZE}"
+                )))
+            }
+        };
+        Ok(Self {
+            is_backward: last.is_some() || before.is_some(),
+            cursor: after.or(before),
+            limit,
+        })
+    }
+
+    /// A page read forward only, for connections ordered by a key that is not indexed in both
+    /// directions.
+    pub fn forward(first: Option<i32>, after: Option<String>) -> Result<Self> {
+        Self::new(first, after, None, None)
+    }
+
+    /// The cursor, for connections whose cursors are sequence numbers.
+    pub fn sequence_cursor(&self) -> Result<Option<i64>> {
+        self.cursor
+            .as_deref()
+            .map(|cursor| {
+                cursor
+                    .parse::<i64>()
+                    .map_err(|_| Error::new(format!("Invalid cursor {cursor}")))
+            })
+            .transpose()
+    }
+
+    /// The cursor, for connections whose cursors are positions of transactions or events.
+    pub fn store_cursor(&self) -> Result<Option<StoreCursor>> {
+        Ok(self.cursor.as_deref().map(str::parse).transpose()?)
+    }
+
+    /// Splits the `limit + 1` items read for the page into the items of the page, and whether
+    /// there are more items past the page.
+    pub fn split<T>(&self, mut items: Vec<T>) -> (Vec<T>, bool) {
+        let has_more = items.len() > self.limit;
+        items.truncate(self.limit);
+        (items, has_more)
+    }
+
+    /// Connection of the items of the page, given with their cursor in reading order, i.e. in
+    /// reverse order for backward pages.
+    pub fn connection<T: OutputType>(
+        &self,
+        mut items: Vec<(String, T)>,
+        has_more: bool,
+    ) -> Connection<String, T> {
+        // Items past the cursor are not counted, the cursor itself at least is one of them.
+        let (has_previous_page, has_next_page) = if self.is_backward {
+            items.reverse();
+            (has_more, self.cursor.is_some())
+        } else {
+            (self.cursor.is_some(), has_more)
+        };
+        let mut connection = Connection::new(has_previous_page, has_next_page);
+        connection.edges.extend(
+            items
+                .into_iter()
+                .map(|(cursor, node)| Edge::new(cursor, node)),
+        );
+        connection
+    }
+}
+
+/// Complexity of a connection field, the fields of its nodes count once per node of the page.
+pub(crate) fn connection_complexity(
+    first: Option<i32>,
+    last: Option<i32>,
+    child_complexity: usize,
+) -> usize {
+    let size = first.or(last).map_or(DEFAULT_PAGE_SIZE, |size| {
+        size.clamp(1, MAX_PAGE_SIZE as i32) as usize
+    });
+    size * child_complexity
+}
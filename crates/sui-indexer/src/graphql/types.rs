@@ -0,0 +1,834 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use async_graphql::connection::Connection;
+use async_graphql::{
+    Context, Enum, Error, InputObject, Json, Lookahead, Object, Result, SimpleObject,
+};
+use serde_json::Value;
+use sui_json_rpc_types::CheckpointId;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::digests::{CheckpointDigest, TransactionDigest};
+
+use crate::graphql::pagination::{connection_complexity, Page};
+use crate::graphql::GraphQLStore;
+use crate::models::checkpoints::Checkpoint as CheckpointRow;
+use crate::models::events::Event as EventRow;
+use crate::models::objects::{Object as ObjectRow, ObjectStatus as ObjectStatusRow};
+use crate::models::owners::OwnerType;
+use crate::models::transactions::{Transaction as TransactionRow, TransactionSummary};
+use crate::store::{StoreCursor, TransactionPageFilter};
+
+// Fields of transactions read from the content and effects columns, and from the other columns.
+const CONTENT_FIELDS: &[&str] = &["transaction", "effects"];
+const SUMMARY_FIELDS: &[&str] = &[
+    "sender",
+    "recipients",
+    "checkpoint",
+    "checkpointSequenceNumber",
+    "timestampMs",
+    "kinds",
+    "moveCalls",
+    "gasObject",
+    "gasBudget",
+    "gasPrice",
+    "gasCostSummary",
+];
+
+fn store<'a>(ctx: &Context<'a>) -> &'a GraphQLStore {
+    ctx.data_unchecked::<GraphQLStore>()
+}
+
+fn parse_address(address: &str) -> Result<String> {
+    Ok(SuiAddress::from_str(address)
+        .map_err(|e| Error::new(format!("Invalid address {address}: {e}")))?
+        .to_string())
+}
+
+fn parse_object_id(object_id: &str) -> Result<String> {
+    Ok(ObjectID::from_str(object_id)
+        .map_err(|e| Error::new(format!("Invalid object id {object_id}: {e}")))?
+        .to_string())
+}
+
+fn parse_json(json: &str) -> Result<Json<Value>> {
+    Ok(Json(serde_json::from_str(json)?))
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// Checkpoint by sequence number or by digest, the latest indexed checkpoint if neither is
+    /// given.
+    async fn checkpoint(
+        &self,
+        ctx: &Context<'_>,
+        sequence_number: Option<i64>,
+        digest: Option<String>,
+    ) -> Result<Option<Checkpoint>> {
+        let store = store(ctx);
+        match (sequence_number, digest) {
+            (Some(_), Some(_)) => Err(Error::new(
+                "Only one of `sequenceNumber` and `digest` can be set",
+            )),
+            (Some(sequence_number), None) => Checkpoint::by_sequence_number(store, sequence_number),
+            (None, Some(digest)) => {
+                let digest = CheckpointDigest::from_str(&digest)?;
+                Ok(Some(Checkpoint(
+                    store.get_checkpoint(CheckpointId::Digest(digest))?,
+                )))
+            }
+            (None, None) => Ok(store
+                .get_checkpoint_page(None, 1, true)?
+                .pop()
+                .map(Checkpoint)),
+        }
+    }
+
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn checkpoints(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Checkpoint>> {
+        let page = Page::new(first, after, last, before)?;
+        let checkpoints = store(ctx).get_checkpoint_page(
+            page.sequence_cursor()?,
+            page.limit + 1,
+            page.is_backward,
+        )?;
+        let (checkpoints, has_more) = page.split(checkpoints);
+        Ok(page.connection(
+            checkpoints
+                .into_iter()
+                .map(|checkpoint| {
+                    (
+                        checkpoint.sequence_number.to_string(),
+                        Checkpoint(checkpoint),
+                    )
+                })
+                .collect(),
+            has_more,
+        ))
+    }
+
+    async fn transaction(&self, ctx: &Context<'_>, digest: String) -> Result<Option<Transaction>> {
+        Transaction::by_digest(ctx, &digest)
+    }
+
+    /// Transactions matching all the fields of `filter`, ordered by sequence number.
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TransactionFilter>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Transaction>> {
+        let filter = filter.unwrap_or_default();
+        let filter = TransactionPageFilter {
+            sender: filter.sender.as_deref().map(parse_address).transpose()?,
+            recipient: filter.recipient.as_deref().map(parse_address).transpose()?,
+            checkpoint: filter.checkpoint,
+            ..Default::default()
+        };
+        Transaction::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+
+    /// Latest indexed version of the object.
+    async fn object(&self, ctx: &Context<'_>, object_id: String) -> Result<Option<SuiObject>> {
+        Ok(store(ctx)
+            .get_object(&parse_object_id(&object_id)?)?
+            .map(SuiObject))
+    }
+
+    /// Events matching `filter`, ordered by emission.
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        filter: EventFilter,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Event>> {
+        Event::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+
+    async fn address(&self, address: String) -> Result<Address> {
+        Ok(Address(parse_address(&address)?))
+    }
+}
+
+#[derive(InputObject, Default)]
+pub struct TransactionFilter {
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub checkpoint: Option<i64>,
+}
+
+/// Exactly one of `sender`, `package` and `eventType` must be set, `module` narrows `package`
+/// down to one of its modules.
+#[derive(InputObject, Default)]
+pub struct EventFilter {
+    pub sender: Option<String>,
+    pub package: Option<String>,
+    pub module: Option<String>,
+    pub event_type: Option<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct GasCostSummary {
+    pub computation_cost: i64,
+    pub storage_cost: i64,
+    pub storage_rebate: i64,
+    pub total_gas_cost: i64,
+}
+
+#[derive(SimpleObject)]
+pub struct ObjectRef {
+    pub object_id: String,
+    pub version: i64,
+    pub digest: String,
+}
+
+pub struct Checkpoint(CheckpointRow);
+
+impl Checkpoint {
+    fn by_sequence_number(store: &GraphQLStore, sequence_number: i64) -> Result<Option<Self>> {
+        Ok(store
+            .get_checkpoint_page(Some(sequence_number - 1), 1, false)?
+            .into_iter()
+            .find(|checkpoint| checkpoint.sequence_number == sequence_number)
+            .map(Checkpoint))
+    }
+}
+
+#[Object]
+impl Checkpoint {
+    async fn sequence_number(&self) -> i64 {
+        self.0.sequence_number
+    }
+
+    async fn digest(&self) -> &str {
+        &self.0.checkpoint_digest
+    }
+
+    async fn epoch(&self) -> i64 {
+        self.0.epoch
+    }
+
+    async fn previous_checkpoint_digest(&self) -> Option<&str> {
+        self.0.previous_checkpoint_digest.as_deref()
+    }
+
+    async fn timestamp_ms(&self) -> i64 {
+        self.0.timestamp_ms
+    }
+
+    /// Whether the checkpoint is the last one of its epoch.
+    async fn end_of_epoch(&self) -> bool {
+        self.0.end_of_epoch_data.is_some()
+    }
+
+    async fn transaction_count(&self) -> i64 {
+        self.0.transactions.len() as i64
+    }
+
+    /// Number of transactions from genesis up to this checkpoint included.
+    async fn network_total_transactions(&self) -> i64 {
+        self.0.total_transactions_from_genesis
+    }
+
+    async fn gas_cost_summary(&self) -> GasCostSummary {
+        GasCostSummary {
+            computation_cost: self.0.total_computation_cost,
+            storage_cost: self.0.total_storage_cost,
+            storage_rebate: self.0.total_storage_rebate,
+            total_gas_cost: self.0.total_gas_cost,
+        }
+    }
+
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Transaction>> {
+        let filter = TransactionPageFilter {
+            checkpoint: Some(self.0.sequence_number),
+            ..Default::default()
+        };
+        Transaction::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+}
+
+/// Columns of the transactions table read for the fields requested on transactions.
+#[derive(PartialEq, Eq)]
+enum TransactionColumns {
+    Digest,
+    Summary,
+    Full,
+}
+
+impl TransactionColumns {
+    fn requested(transaction: &Lookahead<'_>) -> Self {
+        if CONTENT_FIELDS
+            .iter()
+            .any(|field| transaction.field(field).exists())
+        {
+            Self::Full
+        } else if SUMMARY_FIELDS
+            .iter()
+            .any(|field| transaction.field(field).exists())
+        {
+            Self::Summary
+        } else {
+            Self::Digest
+        }
+    }
+}
+
+/// A transaction along with the columns read for the requested fields. The other columns are read
+/// on demand, e.g. for fields that the look-ahead of the query does not see.
+pub struct Transaction {
+    cursor: StoreCursor,
+    sequence_number: i64,
+    digest: String,
+    summary: Option<TransactionSummary>,
+    full: Option<TransactionRow>,
+}
+
+impl Transaction {
+    /// Transactions of the page of positions, sequence numbers and digests `rows`, in the same
+    /// order, read with the columns needed by the fields requested on `transaction`.
+    fn load(
+        store: &GraphQLStore,
+        rows: Vec<(StoreCursor, i64, String)>,
+        transaction: &Lookahead<'_>,
+    ) -> Result<Vec<Self>> {
+        let sequence_numbers = rows.iter().map(|(_, seq, _)| *seq).collect::<Vec<_>>();
+        let mut transactions = rows
+            .into_iter()
+            .map(|(cursor, sequence_number, digest)| {
+                let transaction = Self {
+                    cursor,
+                    sequence_number,
+                    digest,
+                    summary: None,
+                    full: None,
+                };
+                (sequence_number, transaction)
+            })
+            .collect::<BTreeMap<_, _>>();
+        match TransactionColumns::requested(transaction) {
+            TransactionColumns::Digest => (),
+            TransactionColumns::Summary => {
+                for summary in store.multi_get_transaction_summaries(&sequence_numbers)? {
+                    if let Some(transaction) = transactions.get_mut(&summary.id) {
+                        transaction.summary = Some(summary);
+                    }
+                }
+            }
+            TransactionColumns::Full => {
+                for full in store.multi_get_transactions(&sequence_numbers)? {
+                    if let Some(transaction) = transactions.get_mut(&full.id.unwrap_or_default()) {
+                        transaction.full = Some(full);
+                    }
+                }
+            }
+        }
+        Ok(sequence_numbers
+            .iter()
+            .filter_map(|seq| transactions.remove(seq))
+            .collect())
+    }
+
+    fn connection(
+        ctx: &Context<'_>,
+        filter: TransactionPageFilter,
+        page: Page,
+    ) -> Result<Connection<String, Transaction>> {
+        let store = store(ctx);
+        let rows = store.get_transaction_sequence_page(
+            &filter,
+            page.store_cursor()?,
+            page.limit + 1,
+            page.is_backward,
+        )?;
+        let (rows, has_more) = page.split(rows);
+        let transactions = Self::load(store, rows, &ctx.look_ahead().field("edges").field("node"))?;
+        Ok(page.connection(
+            transactions
+                .into_iter()
+                .map(|transaction| (transaction.cursor.to_string(), transaction))
+                .collect(),
+            has_more,
+        ))
+    }
+
+    /// The transaction with digest `digest`, read with the columns needed by the fields
+    /// requested on the current field.
+    fn by_digest(ctx: &Context<'_>, digest: &str) -> Result<Option<Self>> {
+        let store = store(ctx);
+        let filter = TransactionPageFilter {
+            digest: Some(TransactionDigest::from_str(digest)?.base58_encode()),
+            ..Default::default()
+        };
+        let rows = store.get_transaction_sequence_page(&filter, None, 1, false)?;
+        Ok(Self::load(store, rows, &ctx.look_ahead())?.pop())
+    }
+
+    fn summary(&self, ctx: &Context<'_>) -> Result<Cow<'_, TransactionSummary>> {
+        if let Some(summary) = &self.summary {
+            return Ok(Cow::Borrowed(summary));
+        }
+        if let Some(full) = &self.full {
+            return Ok(Cow::Owned(full.into()));
+        }
+        let summary = store(ctx)
+            .multi_get_transaction_summaries(&[self.sequence_number])?
+            .pop()
+            .ok_or_else(|| Error::new(format!("Transaction {} not found", self.digest)))?;
+        Ok(Cow::Owned(summary))
+    }
+
+    fn full(&self, ctx: &Context<'_>) -> Result<Cow<'_, TransactionRow>> {
+        if let Some(full) = &self.full {
+            return Ok(Cow::Borrowed(full));
+        }
+        let full = store(ctx)
+            .multi_get_transactions(&[self.sequence_number])?
+            .pop()
+            .ok_or_else(|| Error::new(format!("Transaction {} not found", self.digest)))?;
+        Ok(Cow::Owned(full))
+    }
+}
+
+#[Object]
+impl Transaction {
+    /// Position of the transaction in the order of indexing.
+    async fn sequence_number(&self) -> i64 {
+        self.sequence_number
+    }
+
+    async fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    async fn sender(&self, ctx: &Context<'_>) -> Result<Address> {
+        Ok(Address(self.summary(ctx)?.sender.clone()))
+    }
+
+    async fn recipients(&self, ctx: &Context<'_>) -> Result<Vec<Address>> {
+        Ok(self
+            .summary(ctx)?
+            .recipients
+            .iter()
+            .flatten()
+            .map(|recipient| Address(recipient.clone()))
+            .collect())
+    }
+
+    async fn checkpoint_sequence_number(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.summary(ctx)?.checkpoint_sequence_number)
+    }
+
+    async fn checkpoint(&self, ctx: &Context<'_>) -> Result<Option<Checkpoint>> {
+        let sequence_number = self.summary(ctx)?.checkpoint_sequence_number;
+        Checkpoint::by_sequence_number(store(ctx), sequence_number)
+    }
+
+    async fn timestamp_ms(&self, ctx: &Context<'_>) -> Result<Option<i64>> {
+        Ok(self
+            .summary(ctx)?
+            .transaction_time
+            .map(|time| time.timestamp_millis()))
+    }
+
+    async fn kinds(&self, ctx: &Context<'_>) -> Result<Vec<String>> {
+        Ok(self
+            .summary(ctx)?
+            .transaction_kinds
+            .iter()
+            .flatten()
+            .cloned()
+            .collect())
+    }
+
+    /// Move functions called by the transaction, as `package::module::function`.
+    async fn move_calls(&self, ctx: &Context<'_>) -> Result<Vec<String>> {
+        Ok(self
+            .summary(ctx)?
+            .move_calls
+            .iter()
+            .flatten()
+            .cloned()
+            .collect())
+    }
+
+    async fn gas_object(&self, ctx: &Context<'_>) -> Result<ObjectRef> {
+        let summary = self.summary(ctx)?;
+        Ok(ObjectRef {
+            object_id: summary.gas_object_id.clone(),
+            version: summary.gas_object_sequence,
+            digest: summary.gas_object_digest.clone(),
+        })
+    }
+
+    async fn gas_budget(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.summary(ctx)?.gas_budget)
+    }
+
+    async fn gas_price(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.summary(ctx)?.gas_price)
+    }
+
+    async fn gas_cost_summary(&self, ctx: &Context<'_>) -> Result<GasCostSummary> {
+        let summary = self.summary(ctx)?;
+        Ok(GasCostSummary {
+            computation_cost: summary.computation_cost,
+            storage_cost: summary.storage_cost,
+            storage_rebate: summary.storage_rebate,
+            total_gas_cost: summary.total_gas_cost,
+        })
+    }
+
+    /// The transaction data, in the JSON form of the JSON-RPC API.
+    async fn transaction(&self, ctx: &Context<'_>) -> Result<Json<Value>> {
+        parse_json(&self.full(ctx)?.transaction_content)
+    }
+
+    /// The transaction effects, in the JSON form of the JSON-RPC API.
+    async fn effects(&self, ctx: &Context<'_>) -> Result<Json<Value>> {
+        parse_json(&self.full(ctx)?.transaction_effects_content)
+    }
+
+    async fn events(&self, ctx: &Context<'_>) -> Result<Vec<Event>> {
+        Ok(store(ctx)
+            .get_events_by_transaction(&self.digest)?
+            .into_iter()
+            .map(Event)
+            .collect())
+    }
+}
+
+pub struct Event(EventRow);
+
+impl Event {
+    fn connection(
+        ctx: &Context<'_>,
+        filter: EventFilter,
+        page: Page,
+    ) -> Result<Connection<String, Event>> {
+        let store = store(ctx);
+        // The store reads events from a position included, the cursor is excluded.
+        let start = page.store_cursor()?.map(|cursor| {
+            if page.is_backward {
+                cursor.predecessor()
+            } else {
+                cursor.successor()
+            }
+        });
+        let (limit, is_descending) = (page.limit + 1, page.is_backward);
+        let events = match filter {
+            EventFilter {
+                sender: Some(sender),
+                package: None,
+                module: None,
+                event_type: None,
+            } => store.get_events_by_sender(parse_address(&sender)?, start, limit, is_descending),
+            EventFilter {
+                sender: None,
+                package: Some(package),
+                module: None,
+                event_type: None,
+            } => store.get_events_by_package(
+                parse_object_id(&package)?,
+                start,
+                limit,
+                is_descending,
+            ),
+            EventFilter {
+                sender: None,
+                package: Some(package),
+                module: Some(module),
+                event_type: None,
+            } => store.get_events_by_module(
+                parse_object_id(&package)?,
+                module,
+                start,
+                limit,
+                is_descending,
+            ),
+            EventFilter {
+                sender: None,
+                package: None,
+                module: None,
+                event_type: Some(event_type),
+            } => store.get_events_by_move_event_type(event_type, start, limit, is_descending),
+            _ => {
+                return Err(Error::new(
+                    "Exactly one of `sender`, `package` and `eventType` must be set, and `module` only along with `package`",
+                ))
+            }
+        }?;
+        let (events, has_more) = page.split(events);
+        Ok(page.connection(
+            events
+                .into_iter()
+                .map(|event| {
+                    let cursor = StoreCursor {
+                        checkpoint: event.checkpoint,
+                        transaction_index: event.transaction_index,
+                        sub_index: event.event_sequence,
+                    };
+                    (cursor.to_string(), Event(event))
+                })
+                .collect(),
+            has_more,
+        ))
+    }
+}
+
+#[Object]
+impl Event {
+    async fn transaction_digest(&self) -> &str {
+        &self.0.transaction_digest
+    }
+
+    /// Position of the event among the events of its transaction.
+    async fn event_sequence(&self) -> i64 {
+        self.0.event_sequence
+    }
+
+    async fn timestamp_ms(&self) -> Option<i64> {
+        self.0.event_time.map(|time| time.timestamp_millis())
+    }
+
+    async fn kind(&self) -> &str {
+        &self.0.event_type
+    }
+
+    /// Move type of the events emitted by Move code.
+    async fn move_type(&self) -> Option<&str> {
+        self.0.move_event_type.as_deref()
+    }
+
+    async fn package_id(&self) -> Option<&str> {
+        self.0.package_id.as_deref()
+    }
+
+    async fn module(&self) -> Option<&str> {
+        self.0.transaction_module.as_deref()
+    }
+
+    async fn sender(&self) -> Option<Address> {
+        self.0.sender.clone().map(Address)
+    }
+
+    async fn json(&self) -> Option<Json<Value>> {
+        self.0.event_json.clone().map(Json)
+    }
+
+    async fn transaction(&self, ctx: &Context<'_>) -> Result<Option<Transaction>> {
+        Transaction::by_digest(ctx, &self.0.transaction_digest)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ObjectStatus {
+    Created,
+    Mutated,
+    Deleted,
+    Wrapped,
+    Unwrapped,
+    UnwrappedThenDeleted,
+}
+
+impl From<ObjectStatusRow> for ObjectStatus {
+    fn from(status: ObjectStatusRow) -> Self {
+        match status {
+            ObjectStatusRow::Created => Self::Created,
+            ObjectStatusRow::Mutated => Self::Mutated,
+            ObjectStatusRow::Deleted => Self::Deleted,
+            ObjectStatusRow::Wrapped => Self::Wrapped,
+            ObjectStatusRow::Unwrapped => Self::Unwrapped,
+            ObjectStatusRow::UnwrappedThenDeleted => Self::UnwrappedThenDeleted,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum OwnerKind {
+    AddressOwner,
+    ObjectOwner,
+    Shared,
+    Immutable,
+}
+
+impl From<&OwnerType> for OwnerKind {
+    fn from(owner_type: &OwnerType) -> Self {
+        match owner_type {
+            OwnerType::AddressOwner => Self::AddressOwner,
+            OwnerType::ObjectOwner => Self::ObjectOwner,
+            OwnerType::Shared => Self::Shared,
+            OwnerType::Immutable => Self::Immutable,
+        }
+    }
+}
+
+pub struct SuiObject(ObjectRow);
+
+#[Object(name = "Object")]
+impl SuiObject {
+    async fn object_id(&self) -> &str {
+        &self.0.object_id
+    }
+
+    async fn version(&self) -> i64 {
+        self.0.version
+    }
+
+    async fn digest(&self) -> &str {
+        &self.0.object_digest
+    }
+
+    #[graphql(name = "type")]
+    async fn object_type(&self) -> &str {
+        &self.0.object_type
+    }
+
+    async fn status(&self) -> ObjectStatus {
+        self.0.object_status.into()
+    }
+
+    async fn owner_kind(&self) -> OwnerKind {
+        (&self.0.owner_type).into()
+    }
+
+    /// The owner address of address-owned objects, the parent object id of object-owned
+    /// objects.
+    async fn owner_address(&self) -> Option<&str> {
+        self.0.owner_address.as_deref()
+    }
+
+    async fn initial_shared_version(&self) -> Option<i64> {
+        self.0.initial_shared_version
+    }
+
+    /// Checkpoint of the last change of the object.
+    async fn checkpoint_sequence_number(&self) -> i64 {
+        self.0.checkpoint
+    }
+
+    async fn previous_transaction(&self, ctx: &Context<'_>) -> Result<Option<Transaction>> {
+        Transaction::by_digest(ctx, &self.0.previous_transaction)
+    }
+}
+
+pub struct Address(String);
+
+#[Object]
+impl Address {
+    async fn address(&self) -> &str {
+        &self.0
+    }
+
+    /// First transaction sent by the address.
+    async fn first_transaction(&self, ctx: &Context<'_>) -> Result<Option<Transaction>> {
+        match store(ctx).get_address(&self.0)? {
+            Some(address) => Transaction::by_digest(ctx, &address.first_appearance_tx),
+            None => Ok(None),
+        }
+    }
+
+    /// Transactions sent by the address.
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Transaction>> {
+        let filter = TransactionPageFilter {
+            sender: Some(self.0.clone()),
+            ..Default::default()
+        };
+        Transaction::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+
+    /// Transactions sending objects or coins to the address.
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn received_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Transaction>> {
+        let filter = TransactionPageFilter {
+            recipient: Some(self.0.clone()),
+            ..Default::default()
+        };
+        Transaction::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+
+    /// Objects owned by the address, ordered by object id.
+    #[graphql(complexity = "connection_complexity(first, None, child_complexity)")]
+    async fn objects(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<Connection<String, SuiObject>> {
+        let page = Page::forward(first, after)?;
+        let objects = store(ctx).get_owned_objects_page(
+            &self.0,
+            page.cursor.as_deref().map(parse_object_id).transpose()?,
+            page.limit + 1,
+        )?;
+        let (objects, has_more) = page.split(objects);
+        Ok(page.connection(
+            objects
+                .into_iter()
+                .map(|object| (object.object_id.clone(), SuiObject(object)))
+                .collect(),
+            has_more,
+        ))
+    }
+
+    /// Events emitted by the transactions sent by the address.
+    #[graphql(complexity = "connection_complexity(first, last, child_complexity)")]
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<String, Event>> {
+        let filter = EventFilter {
+            sender: Some(self.0.clone()),
+            ..Default::default()
+        };
+        Event::connection(ctx, filter, Page::new(first, after, last, before)?)
+    }
+}
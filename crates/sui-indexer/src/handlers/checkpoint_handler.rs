@@ -2,43 +2,65 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::handlers::interest_list::InterestList;
 use crate::metrics::IndexerCheckpointHandlerMetrics;
+use crate::models::address_activity::AddressActivity;
 use crate::models::checkpoints::Checkpoint;
+use crate::models::dead_letter_checkpoints::DeadLetterCheckpoint;
+use crate::models::dynamic_fields::DynamicField;
 use crate::models::events::Event;
 use crate::models::move_calls::MoveCall;
 use crate::models::objects::{DeletedObject, Object, ObjectStatus};
 use crate::models::packages::Package;
 use crate::models::recipients::Recipient;
+use crate::models::shared_object_usage::SharedObjectUsage;
+use crate::models::transaction_errors::TransactionError;
 use crate::models::transactions::Transaction;
+use crate::models::validator_epochs::ValidatorEpoch;
 use crate::store::{
     CheckpointData, IndexerStore, TemporaryCheckpointStore, TemporaryEpochStore,
     TransactionObjectChanges,
 };
-use chrono::NaiveDateTime;
 use futures::future::join_all;
 use futures::FutureExt;
 use mysten_metrics::spawn_monitored_task;
 use prometheus::Registry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 use sui_json_rpc_types::{
-    OwnedObjectRef, SuiObjectData, SuiObjectDataOptions, SuiRawData, SuiTransactionDataAPI,
-    SuiTransactionEffectsAPI, SuiTransactionKind, SuiTransactionResponse,
-    SuiTransactionResponseOptions,
+    Checkpoint as RpcCheckpoint, SuiEvent, SuiObjectData, SuiObjectDataOptions, SuiRawData,
+    SuiTransactionDataAPI, SuiTransactionEffects, SuiTransactionEffectsAPI, SuiTransactionKind,
+    SuiTransactionResponse, SuiTransactionResponseOptions,
 };
 use sui_sdk::error::Error;
 use sui_sdk::SuiClient;
-use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::base_types::{ObjectType, SuiAddress};
+use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::object::Owner;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::task::JoinHandle;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 const HANDLER_RETRY_INTERVAL_IN_SECS: u64 = 10;
+const CHECKPOINT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// Bounds of the number of checkpoints downloaded concurrently.
+const MIN_DOWNLOAD_BATCH_SIZE: usize = 1;
+const MAX_DOWNLOAD_BATCH_SIZE: usize = 100;
+// Number of transactions the batches of downloaded checkpoints aim at.
+const TARGET_BATCH_TX_COUNT: usize = 1_000;
+// Number of downloaded checkpoints waiting to be indexed before the downloader waits.
+const DOWNLOADED_CHECKPOINT_QUEUE_SIZE: usize = 200;
+const MAX_CHECKPOINT_PROCESSING_ATTEMPTS: u32 = 5;
+const CHECKPOINT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
 const MULTI_GET_CHUNK_SIZE: usize = 500;
+const VERIFY_CHUNK_SIZE: u64 = 100;
 
 pub struct CheckpointHandler<S> {
     state: S,
     rpc_client: SuiClient,
     metrics: IndexerCheckpointHandlerMetrics,
+    interest_list: InterestList,
 }
 
 impl<S> CheckpointHandler<S>
@@ -50,9 +72,16 @@ where
             state,
             rpc_client,
             metrics: IndexerCheckpointHandlerMetrics::new(prometheus_registry),
+            interest_list: InterestList::default(),
         }
     }
 
+    /// Only index the transactions touching `interest_list`, and the data derived from them.
+    pub fn with_interest_list(mut self, interest_list: InterestList) -> Self {
+        self.interest_list = interest_list;
+        self
+    }
+
     pub fn spawn(self) -> JoinHandle<()> {
         spawn_monitored_task!(async move {
             let mut checkpoint_handler_exec_res = self.start().await;
@@ -78,118 +107,339 @@ where
         }
         next_cursor_sequence_number += 1;
 
-        loop {
-            self.metrics.total_checkpoint_requested.inc();
-            let request_guard = self.metrics.full_node_read_request_latency.start_timer();
+        // Checkpoints are downloaded ahead by the downloader, and indexed and committed in order
+        // here. The queue between them is bounded so that the downloader slows down when
+        // indexing can't keep up.
+        let (sender, mut receiver) = mpsc::channel(DOWNLOADED_CHECKPOINT_QUEUE_SIZE);
+        spawn_monitored_task!(download_checkpoints(
+            self.rpc_client.clone(),
+            self.metrics.clone(),
+            next_cursor_sequence_number as u64,
+            sender,
+        ));
 
-            let mut checkpoint = self
-                .download_checkpoint_data(next_cursor_sequence_number as u64)
-                .await;
-            // this happens very often b/c checkpoint indexing is faster than checkpoint
-            // generation. Ideally we will want to differentiate between a real error and
-            // a checkpoint not generated yet.
-            while checkpoint.is_err() {
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                checkpoint = self
-                    .download_checkpoint_data(next_cursor_sequence_number as u64)
-                    .await
+        while let Some(DownloadedCheckpoint { checkpoint, data }) = receiver.recv().await {
+            self.metrics.downloaded_checkpoint_queue_size.dec();
+
+            // Retry transient failures of a checkpoint that exists, and set the checkpoint aside
+            // after MAX_CHECKPOINT_PROCESSING_ATTEMPTS, or right away if the failure is
+            // permanent, so that it does not block the ones after it. Retries download the
+            // checkpoint data again.
+            let mut data = Some(data);
+            let mut attempts = 0;
+            loop {
+                attempts += 1;
+                let result = match data.take() {
+                    Some(data) => data.and_then(|data| self.commit_checkpoint(data)),
+                    None => self.process_checkpoint(checkpoint.clone()).await,
+                };
+                match result {
+                    Ok(()) => break,
+                    Err(e) if e.is_retryable() && attempts < MAX_CHECKPOINT_PROCESSING_ATTEMPTS => {
+                        self.metrics.total_checkpoint_handler_error.inc();
+                        let delay = CHECKPOINT_RETRY_BASE_DELAY * 2u32.pow(attempts - 1);
+                        warn!(
+                            "Failed to process checkpoint {} on attempt {attempts} with error: {:?}, retrying after {:?}...",
+                            checkpoint.sequence_number, e, delay
+                        );
+                        tokio::time::sleep(delay).await;
+                    }
+                    Err(e) => {
+                        self.metrics.total_checkpoint_handler_error.inc();
+                        self.dead_letter_checkpoint(&checkpoint, e, attempts)?;
+                        break;
+                    }
+                }
             }
-            // unwrap here is safe because we checked for error above
-            let checkpoint = checkpoint.unwrap();
-            request_guard.stop_and_record();
-            self.metrics.total_checkpoint_received.inc();
-
-            // Index checkpoint data
-            // TODO: Metrics
-            let (indexed_checkpoint, indexed_epoch) = self.index_checkpoint(checkpoint)?;
-
-            // Write to DB
-            let db_guard = self.metrics.db_write_request_latency.start_timer();
-            let tx_count = indexed_checkpoint.transactions.len();
-            let object_count = indexed_checkpoint.objects_changes.len();
-
-            self.state.persist_checkpoint(&indexed_checkpoint)?;
-            info!(
-                "Checkpoint {} committed with {tx_count} transactions and {object_count} objects.",
-                next_cursor_sequence_number
-            );
-            self.metrics.total_checkpoint_processed.inc();
-            db_guard.stop_and_record();
-
-            if let Some(indexed_epoch) = indexed_epoch {
-                self.state.persist_epoch(&indexed_epoch)?;
+            self.update_checkpoint_lag(checkpoint.sequence_number as i64);
+        }
+        Err(IndexerError::FullNodeReadingError(
+            "Checkpoint downloader stopped".to_string(),
+        ))
+    }
+
+    /// Download, index and commit one checkpoint.
+    async fn process_checkpoint(&self, checkpoint: RpcCheckpoint) -> Result<(), IndexerError> {
+        let data = download_checkpoint(&self.rpc_client, &self.metrics, checkpoint).await?;
+        self.commit_checkpoint(data)
+    }
+
+    /// Index and commit the downloaded data of one checkpoint.
+    fn commit_checkpoint(&self, checkpoint: CheckpointData) -> Result<(), IndexerError> {
+        let sequence_number = checkpoint.checkpoint.sequence_number;
+
+        // Index checkpoint data
+        // TODO: Metrics
+        let (indexed_checkpoint, indexed_epoch) = self.index_checkpoint(checkpoint)?;
+
+        // Write to DB
+        let db_guard = self.metrics.db_write_request_latency.start_timer();
+        let tx_count = indexed_checkpoint.transactions.len();
+        let object_count = indexed_checkpoint.objects_changes.len();
+
+        self.state.persist_checkpoint(&indexed_checkpoint)?;
+        info!(
+            "Checkpoint {} committed with {tx_count} transactions and {object_count} objects.",
+            sequence_number
+        );
+        self.metrics.total_checkpoint_processed.inc();
+        db_guard.stop_and_record();
+
+        if let Some(indexed_epoch) = indexed_epoch {
+            self.state.persist_epoch(&indexed_epoch)?;
+        }
+        Ok(())
+    }
+
+    /// Commit only the checkpoint row of a checkpoint that failed processing, and record it in
+    /// the dead letters to be reprocessed with [CheckpointHandler::reprocess_dead_letters].
+    fn dead_letter_checkpoint(
+        &self,
+        checkpoint: &RpcCheckpoint,
+        error: IndexerError,
+        attempts: u32,
+    ) -> Result<(), IndexerError> {
+        error!(
+            "Failed to process checkpoint {} after {attempts} attempts with error: {:?}, moving it to dead letters.",
+            checkpoint.sequence_number, error
+        );
+        let previous_cp = if checkpoint.sequence_number == 0 {
+            Checkpoint::default()
+        } else {
+            self.state
+                .get_checkpoint((checkpoint.sequence_number - 1).into())?
+        };
+        self.state.persist_dead_letter_checkpoint(
+            &Checkpoint::from(checkpoint, &previous_cp)?,
+            &DeadLetterCheckpoint::new(checkpoint.sequence_number as i64, &error, attempts as i64),
+        )?;
+        // Epoch changes must not be skipped, the epoch store does not depend on the checkpoint
+        // content yet.
+        if checkpoint.end_of_epoch_data.is_some() {
+            self.state.persist_epoch(&TemporaryEpochStore {
+                new_epoch: checkpoint.epoch + 1,
+                owner_index: vec![],
+                validator_epochs: vec![],
+            })?;
+        }
+        self.metrics.total_checkpoint_dead_lettered.inc();
+        Ok(())
+    }
+
+    /// Process all dead-lettered checkpoints again, in order, e.g. after the bug that made them
+    /// fail has been fixed. Checkpoints that fail again stay in the dead letters.
+    pub async fn reprocess_dead_letters(&self) -> Result<(), IndexerError> {
+        let dead_letters = self.state.get_dead_letter_checkpoints()?;
+        info!(
+            "Reprocessing {} dead letter checkpoints...",
+            dead_letters.len()
+        );
+        for dead_letter in dead_letters {
+            let sequence_number = dead_letter.sequence_number as u64;
+            let checkpoint = self
+                .rpc_client
+                .read_api()
+                .get_checkpoint(sequence_number.into())
+                .await
+                .map_err(|e| {
+                    IndexerError::rpc_fetch(format!("getting checkpoint {sequence_number}"), e)
+                })?;
+            // Epoch changes were committed when the checkpoint was dead-lettered, only the
+            // validator epochs read from its transactions are missing.
+            let result = match download_checkpoint_data(&self.rpc_client, checkpoint).await {
+                Ok(data) => {
+                    self.index_checkpoint(data)
+                        .and_then(|(indexed_checkpoint, indexed_epoch)| {
+                            if let Some(indexed_epoch) = indexed_epoch {
+                                self.state
+                                    .persist_validator_epochs(&indexed_epoch.validator_epochs)?;
+                            }
+                            self.state.persist_checkpoint(&indexed_checkpoint)
+                        })
+                }
+                Err(e) => Err(IndexerError::rpc_fetch(
+                    format!("downloading data of checkpoint {sequence_number}"),
+                    e,
+                )),
+            };
+            match result {
+                Ok(_) => info!("Dead letter checkpoint {sequence_number} reprocessed."),
+                Err(e) => {
+                    warn!(
+                        "Failed to reprocess dead letter checkpoint {sequence_number} with error: {:?}",
+                        e
+                    );
+                    let checkpoint = self.state.get_checkpoint(sequence_number.into())?;
+                    self.state.persist_dead_letter_checkpoint(
+                        &checkpoint,
+                        &DeadLetterCheckpoint::new(dead_letter.sequence_number, &e, 1),
+                    )?;
+                }
             }
-            next_cursor_sequence_number += 1;
         }
+        Ok(())
     }
 
-    /// Download all the data we need for one checkpoint.
-    async fn download_checkpoint_data(
+    /// Cross-check the indexed checkpoints `start..=end` against the full node: every checkpoint
+    /// is indexed with the digest and transactions of the full node, and every transaction is
+    /// indexed with the effects of the full node. Returns the inconsistent checkpoints, and
+    /// reindexes them first if `repair` is set.
+    pub async fn verify_checkpoints(
         &self,
-        seq: CheckpointSequenceNumber,
-    ) -> Result<CheckpointData, Error> {
-        let checkpoint = self
-            .rpc_client
-            .read_api()
-            .get_checkpoint(seq.into())
-            .await?;
+        start: u64,
+        end: u64,
+        repair: bool,
+    ) -> Result<Vec<u64>, IndexerError> {
+        let mut inconsistent = vec![];
+        let mut chunk_start = start;
+        while chunk_start <= end {
+            let chunk_end = end.min(chunk_start + VERIFY_CHUNK_SIZE - 1);
+            info!("Verifying checkpoints {chunk_start} to {chunk_end}...");
+            let indexed = self
+                .state
+                .get_checkpoint_range_data(chunk_start as i64, chunk_end as i64)?;
+            let indexed_checkpoints = indexed
+                .checkpoints
+                .into_iter()
+                .map(|cp| (cp.sequence_number as u64, cp))
+                .collect::<BTreeMap<_, _>>();
+            let indexed_transactions = indexed
+                .transactions
+                .into_iter()
+                .map(|tx| (tx.transaction_digest.clone(), tx))
+                .collect::<BTreeMap<_, _>>();
 
-        let transactions = join_all(checkpoint.transactions.chunks(MULTI_GET_CHUNK_SIZE).map(
-            |digests| {
-                self.rpc_client
+            for sequence_number in chunk_start..=chunk_end {
+                let checkpoint = self
+                    .rpc_client
                     .read_api()
-                    .multi_get_transactions_with_options(
-                        digests.to_vec(),
-                        SuiTransactionResponseOptions::full_content(),
+                    .get_checkpoint(sequence_number.into())
+                    .await
+                    .map_err(|e| {
+                        IndexerError::rpc_fetch(format!("getting checkpoint {sequence_number}"), e)
+                    })?;
+                let issues = self
+                    .checkpoint_issues(
+                        &checkpoint,
+                        indexed_checkpoints.get(&sequence_number),
+                        &indexed_transactions,
                     )
-            },
-        ))
-        .await
-        .into_iter()
-        .try_fold(vec![], |mut acc, chunk| {
-            acc.extend(chunk?);
-            Ok::<_, Error>(acc)
-        })?;
+                    .await?;
+                if issues.is_empty() {
+                    continue;
+                }
+                warn!(
+                    "Checkpoint {sequence_number} is inconsistent with the full node: {}",
+                    issues.join(", ")
+                );
+                inconsistent.push(sequence_number);
+                if repair {
+                    self.state.delete_checkpoint_data(sequence_number as i64)?;
+                    let data =
+                        download_checkpoint(&self.rpc_client, &self.metrics, checkpoint).await?;
+                    // Epoch data is not rewritten, it is committed even for dead-lettered
+                    // checkpoints.
+                    let (indexed_checkpoint, _) = self.index_checkpoint(data)?;
+                    self.state.persist_checkpoint(&indexed_checkpoint)?;
+                    info!("Checkpoint {sequence_number} reindexed.");
+                }
+            }
+            chunk_start = chunk_end + 1;
+        }
+        Ok(inconsistent)
+    }
 
-        let all_mutated = transactions
+    /// Differences between a checkpoint of the full node and its indexed data.
+    async fn checkpoint_issues(
+        &self,
+        checkpoint: &RpcCheckpoint,
+        indexed_checkpoint: Option<&Checkpoint>,
+        indexed_transactions: &BTreeMap<String, Transaction>,
+    ) -> Result<Vec<String>, IndexerError> {
+        let Some(indexed_checkpoint) = indexed_checkpoint else {
+            return Ok(vec!["checkpoint is missing".to_string()]);
+        };
+        let mut issues = vec![];
+        if indexed_checkpoint.checkpoint_digest != checkpoint.digest.base58_encode() {
+            issues.push(format!(
+                "checkpoint digest {} instead of {}",
+                indexed_checkpoint.checkpoint_digest, checkpoint.digest
+            ));
+        }
+        let digests = checkpoint
+            .transactions
             .iter()
-            .flat_map(|tx| {
-                let effects = tx.effects.as_ref().expect("effects should not be empty");
-                let created = effects.created().iter();
-                let created = created.map(|o: &OwnedObjectRef| (o, ObjectStatus::Created));
-                let mutated = effects.mutated().iter();
-                let mutated = mutated.map(|o: &OwnedObjectRef| (o, ObjectStatus::Mutated));
-                let unwrapped = effects.unwrapped().iter();
-                let unwrapped = unwrapped.map(|o: &OwnedObjectRef| (o, ObjectStatus::Unwrapped));
-                created.chain(mutated).chain(unwrapped)
-            })
-            .fold(
-                vec![],
-                |mut acc, (o, status): (&OwnedObjectRef, ObjectStatus)| {
-                    acc.push((o.reference.object_id, o.reference.version, status));
-                    acc
-                },
-            );
-
-        // TODO: Use multi get objects
-        let rpc = self.rpc_client.clone();
-        let all_mutated_objects = join_all(all_mutated.into_iter().map(|(id, version, status)| {
-            rpc.read_api()
-                .try_get_parsed_past_object(id, version, SuiObjectDataOptions::bcs_lossless())
-                .map(move |resp| (resp, status))
-        }))
-        .await
-        .into_iter()
-        .try_fold(vec![], |mut acc, (response, status)| {
-            acc.push((status, response?.into_object()?));
-            Ok::<_, Error>(acc)
-        })?;
+            .map(|digest| Some(digest.base58_encode()))
+            .collect::<Vec<_>>();
+        if indexed_checkpoint.transactions != digests {
+            issues.push("checkpoint transactions differ".to_string());
+        }
 
-        Ok(CheckpointData {
-            checkpoint,
-            transactions,
-            all_mutated_objects,
-        })
+        // In filtered mode, the transactions and events are needed to tell the transactions left
+        // out from the missing ones.
+        let options = if self.interest_list.is_empty() {
+            SuiTransactionResponseOptions::new().with_effects()
+        } else {
+            SuiTransactionResponseOptions::new()
+                .with_input()
+                .with_effects()
+                .with_events()
+        };
+        for digests in checkpoint.transactions.chunks(MULTI_GET_CHUNK_SIZE) {
+            let transactions = self
+                .rpc_client
+                .read_api()
+                .multi_get_transactions_with_options(digests.to_vec(), options.clone())
+                .await
+                .map_err(|e| {
+                    IndexerError::rpc_fetch(
+                        format!(
+                            "getting transactions of checkpoint {}",
+                            checkpoint.sequence_number
+                        ),
+                        e,
+                    )
+                })?;
+            for tx in transactions {
+                let digest = tx.digest.base58_encode();
+                let Some(indexed_tx) = indexed_transactions.get(&digest) else {
+                    if !self.interest_list.matches(&tx, []) {
+                        continue;
+                    }
+                    issues.push(format!("transaction {digest} is missing"));
+                    continue;
+                };
+                if indexed_tx.checkpoint_sequence_number != checkpoint.sequence_number as i64 {
+                    issues.push(format!(
+                        "transaction {digest} is indexed in checkpoint {}",
+                        indexed_tx.checkpoint_sequence_number
+                    ));
+                }
+                let indexed_effects = serde_json::from_str::<SuiTransactionEffects>(
+                    &indexed_tx.transaction_effects_content,
+                )
+                .ok();
+                if indexed_effects != tx.effects {
+                    issues.push(format!("effects of transaction {digest} differ"));
+                }
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Update the lag metrics after committing `indexed_sequence_number`, against the latest
+    /// checkpoint of the full node last seen by the downloader.
+    fn update_checkpoint_lag(&self, indexed_sequence_number: i64) {
+        self.metrics
+            .latest_indexer_checkpoint_sequence_number
+            .set(indexed_sequence_number);
+        let fullnode_sequence_number = self
+            .metrics
+            .latest_fullnode_checkpoint_sequence_number
+            .get();
+        self.metrics
+            .checkpoint_lag
+            .set((fullnode_sequence_number - indexed_sequence_number).max(0));
     }
 
     fn index_checkpoint(
@@ -200,6 +450,7 @@ where
             checkpoint,
             transactions,
             all_mutated_objects,
+            dynamic_field_objects,
         } = data;
 
         let previous_cp = if checkpoint.sequence_number == 0 {
@@ -209,16 +460,68 @@ where
                 .get_checkpoint((checkpoint.sequence_number - 1).into())?
         };
 
+        // Index objects
+        let tx_objects = all_mutated_objects
+            .iter()
+            // Unwrap safe here as we requested previous tx data in the request.
+            .fold(BTreeMap::<_, Vec<_>>::new(), |mut acc, (status, o)| {
+                if let Some(digest) = &o.previous_transaction {
+                    acc.entry(*digest).or_default().push((status, o));
+                }
+                acc
+            });
+
+        // In filtered mode, only the transactions touching the interest list are indexed, the
+        // checkpoint itself is always indexed.
+        let (transactions, all_transactions) = if self.interest_list.is_empty() {
+            (transactions, None)
+        } else {
+            let interesting = transactions
+                .iter()
+                .filter(|tx| {
+                    let objects = tx_objects.get(&tx.digest).into_iter().flatten();
+                    self.interest_list.matches(tx, objects.map(|(_, o)| *o))
+                })
+                .cloned()
+                .collect();
+            (interesting, Some(transactions))
+        };
+
+        // Positions of the transactions in the checkpoint, the same in filtered mode.
+        let positions = checkpoint
+            .transactions
+            .iter()
+            .enumerate()
+            .map(|(index, digest)| (*digest, index as i64))
+            .collect::<BTreeMap<_, _>>();
+        let transaction_indexes = transactions
+            .iter()
+            .map(|tx| {
+                positions.get(&tx.digest).copied().ok_or_else(|| {
+                    IndexerError::DataInconsistency(format!(
+                        "Transaction {} is not in checkpoint {}",
+                        tx.digest, checkpoint.sequence_number
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         // Index transaction
         let db_transactions = transactions
             .iter()
-            .map(|tx| tx.clone().try_into())
-            .collect::<Result<Vec<_>, _>>()?;
+            .zip(&transaction_indexes)
+            .map(|(tx, transaction_index)| {
+                let mut db_transaction: Transaction = tx.clone().try_into()?;
+                db_transaction.transaction_index = *transaction_index;
+                Ok(db_transaction)
+            })
+            .collect::<Result<Vec<_>, IndexerError>>()?;
 
         // Index events
         let events = transactions
             .iter()
-            .flat_map(|tx| {
+            .zip(transaction_indexes.iter().copied())
+            .flat_map(|(tx, transaction_index)| {
                 let mut event_sequence = 0;
                 tx.events
                     .as_ref()
@@ -226,40 +529,20 @@ where
                     .data
                     .iter()
                     .map(move |event| {
-                        // TODO: we should rethink how we store the raw event in DB
-                        let event_content = serde_json::to_string(event).map_err(|err| {
-                            IndexerError::InsertableParsingError(format!(
-                                "Failed converting event to JSON with error: {:?}",
-                                err
-                            ))
-                        })?;
-                        let event = Event {
-                            id: None,
-                            transaction_digest: tx.digest.to_string(),
+                        let event = Event::try_from(
+                            &tx.digest,
+                            checkpoint.sequence_number as i64,
+                            transaction_index,
                             event_sequence,
-                            event_time: tx
-                                .timestamp_ms
-                                .and_then(|t| NaiveDateTime::from_timestamp_millis(t as i64)),
-                            event_type: event.get_event_type(),
-                            event_content,
-                        };
+                            tx.timestamp_ms,
+                            event,
+                        );
                         event_sequence += 1;
-                        Ok::<_, IndexerError>(event)
+                        event
                     })
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Index objects
-        let tx_objects = all_mutated_objects
-            .iter()
-            // Unwrap safe here as we requested previous tx data in the request.
-            .fold(BTreeMap::<_, Vec<_>>::new(), |mut acc, (status, o)| {
-                if let Some(digest) = &o.previous_transaction {
-                    acc.entry(*digest).or_default().push((status, o));
-                }
-                acc
-            });
-
         let objects_changes = transactions
             .iter()
             .map(|tx| {
@@ -272,23 +555,16 @@ where
                     })
                     .collect::<Vec<_>>();
                 let effects = tx.effects.as_ref().expect("effects should not be empty");
-                let deleted = effects.deleted().iter();
-                let deleted = deleted.map(|o| (ObjectStatus::Deleted, o));
-                let wrapped = effects.wrapped().iter();
-                let wrapped = wrapped.map(|o| (ObjectStatus::Wrapped, o));
-                let unwrapped_then_deleted = effects.unwrapped_then_deleted().iter();
-                let unwrapped_then_deleted =
-                    unwrapped_then_deleted.map(|o| (ObjectStatus::UnwrappedThenDeleted, o));
-                let all_deleted_objects = deleted
-                    .chain(wrapped)
-                    .chain(unwrapped_then_deleted)
-                    .map(|(status, oref)| {
+                let all_deleted_objects = effects
+                    .all_deleted_objects()
+                    .into_iter()
+                    .map(|(oref, kind)| {
                         DeletedObject::from(
                             &checkpoint.epoch,
                             &checkpoint.sequence_number,
-                            oref,
+                            &oref,
                             &tx.digest,
-                            status,
+                            ObjectStatus::from(kind),
                         )
                     })
                     .collect();
@@ -310,7 +586,8 @@ where
 
         let move_calls: Vec<MoveCall> = transactions
             .iter()
-            .flat_map(|t| {
+            .zip(transaction_indexes.iter().copied())
+            .flat_map(|(t, transaction_index)| {
                 t.transaction
                     .as_ref()
                     .expect("transaction should not be empty")
@@ -322,6 +599,7 @@ where
                             tx.clone(),
                             t.digest,
                             checkpoint.sequence_number,
+                            transaction_index,
                             checkpoint.epoch,
                             t.transaction
                                 .as_ref()
@@ -332,54 +610,185 @@ where
                     })
             })
             .filter_map(
-                |(tx_kind, txn_digest, checkpoint_seq, epoch, sender)| match tx_kind {
-                    SuiTransactionKind::Call(sui_move_call) => Some(MoveCall {
-                        id: None,
-                        transaction_digest: txn_digest.to_string(),
-                        checkpoint_sequence_number: checkpoint_seq as i64,
-                        epoch: epoch as i64,
-                        sender: sender.to_string(),
-                        move_package: sui_move_call.package.to_string(),
-                        move_module: sui_move_call.module,
-                        move_function: sui_move_call.function,
-                    }),
-                    _ => None,
+                |(tx_kind, txn_digest, checkpoint_seq, transaction_index, epoch, sender)| {
+                    match tx_kind {
+                        SuiTransactionKind::Call(sui_move_call) => Some(MoveCall {
+                            id: None,
+                            transaction_digest: txn_digest.to_string(),
+                            checkpoint_sequence_number: checkpoint_seq as i64,
+                            epoch: epoch as i64,
+                            sender: sender.to_string(),
+                            move_package: sui_move_call.package.to_string(),
+                            move_module: sui_move_call.module,
+                            move_function: sui_move_call.function,
+                            transaction_index,
+                        }),
+                        _ => None,
+                    }
                 },
             )
             .collect();
 
         let recipients: Vec<Recipient> = transactions
+            .iter()
+            .zip(transaction_indexes.iter().copied())
+            .flat_map(|(tx, transaction_index)| {
+                let effects = tx.effects.as_ref().expect("Effects should not be empty");
+                // Amounts received by each address by coin type, from the balance changes
+                let mut received: BTreeMap<SuiAddress, BTreeMap<String, i128>> = BTreeMap::new();
+                for event in tx.events.iter().flat_map(|events| events.data.iter()) {
+                    if let SuiEvent::CoinBalanceChange {
+                        owner: Owner::AddressOwner(owner),
+                        coin_type,
+                        amount,
+                        ..
+                    } = event
+                    {
+                        if *amount > 0 {
+                            *received
+                                .entry(*owner)
+                                .or_default()
+                                .entry(coin_type.clone())
+                                .or_default() += amount;
+                        }
+                    }
+                }
+                let addresses: BTreeSet<SuiAddress> = effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .filter_map(|(obj_ref, _)| match obj_ref.owner {
+                        Owner::AddressOwner(address) => Some(address),
+                        _ => None,
+                    })
+                    .collect();
+                addresses
+                    .into_iter()
+                    .flat_map(|address| match received.remove(&address) {
+                        Some(amounts) => amounts
+                            .into_iter()
+                            .map(|(coin_type, amount)| {
+                                (address, Some(coin_type), Some(amount as i64))
+                            })
+                            .collect(),
+                        None => vec![(address, None, None)],
+                    })
+                    .map(|(address, coin_type, amount)| Recipient {
+                        id: None,
+                        transaction_digest: effects.transaction_digest().to_string(),
+                        checkpoint_sequence_number: checkpoint.sequence_number as i64,
+                        epoch: checkpoint.epoch as i64,
+                        recipient: address.to_string(),
+                        coin_type,
+                        amount,
+                        transaction_index,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Index execution failures
+        let transaction_errors = transactions
+            .iter()
+            .filter_map(|tx| {
+                TransactionError::from_response(
+                    tx,
+                    checkpoint.epoch,
+                    checkpoint.sequence_number,
+                    checkpoint.timestamp_ms,
+                )
+            })
+            .collect();
+
+        // Index the activity of the senders, added to the activity of previous checkpoints
+        let address_activity =
+            AddressActivity::from_transactions(&db_transactions, checkpoint.epoch as i64);
+
+        // Index shared object usage
+        let consensus_latency_ms = if checkpoint.sequence_number == 0 {
+            0
+        } else {
+            (checkpoint.timestamp_ms as i64 - previous_cp.timestamp_ms).max(0)
+        };
+        let shared_object_usage = transactions
             .iter()
             .flat_map(|tx| {
                 let effects = tx.effects.as_ref().expect("Effects should not be empty");
                 effects
-                    .created()
+                    .shared_objects()
                     .iter()
-                    .cloned()
-                    .chain(effects.mutated().iter().cloned())
-                    .chain(effects.unwrapped().iter().cloned())
-                    .filter_map(|obj_ref| match obj_ref.owner {
-                        Owner::AddressOwner(address) => Some(Recipient {
-                            id: None,
-                            transaction_digest: effects.transaction_digest().to_string(),
-                            checkpoint_sequence_number: checkpoint.sequence_number as i64,
-                            epoch: checkpoint.epoch as i64,
-                            recipient: address.to_string(),
-                        }),
-                        _ => None,
-                    })
+                    .map(|obj_ref| obj_ref.object_id)
+            })
+            .fold(BTreeMap::<_, i64>::new(), |mut acc, object_id| {
+                *acc.entry(object_id).or_default() += 1;
+                acc
+            })
+            .into_iter()
+            .map(|(object_id, transaction_count)| SharedObjectUsage {
+                id: None,
+                object_id: object_id.to_string(),
+                checkpoint_sequence_number: checkpoint.sequence_number as i64,
+                epoch: checkpoint.epoch as i64,
+                timestamp_ms: checkpoint.timestamp_ms as i64,
+                transaction_count,
+                consensus_latency_ms,
             })
             .collect();
 
+        // Index dynamic fields, replaying the object changes in transaction order so that a field
+        // removed and added again within the checkpoint ends up in the right state.
+        let dynamic_field_objects = dynamic_field_objects
+            .iter()
+            .map(|o| ((o.object_id, o.version), o))
+            .collect::<BTreeMap<_, _>>();
+        let mut dynamic_field_changes = BTreeMap::new();
+        for tx in &transactions {
+            let effects = tx.effects.as_ref().expect("Effects should not be empty");
+            for (o, _) in effects.all_changed_objects() {
+                let key = (o.reference.object_id, o.reference.version);
+                if let Some(object) = dynamic_field_objects.get(&key) {
+                    let dynamic_field = DynamicField::try_from(
+                        &checkpoint.epoch,
+                        &checkpoint.sequence_number,
+                        object,
+                    )?;
+                    dynamic_field_changes.insert(o.reference.object_id, dynamic_field);
+                }
+            }
+            for (o, _) in effects.all_deleted_objects() {
+                dynamic_field_changes.insert(o.object_id, None);
+            }
+        }
+        let (dynamic_fields, deleted_dynamic_fields) = dynamic_field_changes.into_iter().fold(
+            (vec![], vec![]),
+            |(mut dynamic_fields, mut deleted), (object_id, dynamic_field)| {
+                match dynamic_field {
+                    Some(dynamic_field) => dynamic_fields.push(dynamic_field),
+                    None => deleted.push(object_id.to_string()),
+                }
+                (dynamic_fields, deleted)
+            },
+        );
+
         // Index epoch
         // TODO: Aggregate all object owner changes into owner index at epoch change.
-        let epoch_index =
-            checkpoint
-                .end_of_epoch_data
+        let epoch_index = if checkpoint.end_of_epoch_data.is_some() {
+            // The epoch change transaction emits the metrics of every validator for the epoch,
+            // it is read even when filtered out.
+            let validator_epochs = all_transactions
                 .as_ref()
-                .map(|_epoch_change| TemporaryEpochStore {
-                    owner_index: vec![],
-                });
+                .unwrap_or(&transactions)
+                .iter()
+                .flat_map(|tx| tx.events.iter().flat_map(|events| events.data.iter()))
+                .filter_map(|event| ValidatorEpoch::try_from_event(event).transpose())
+                .collect::<Result<Vec<_>, _>>()?;
+            Some(TemporaryEpochStore {
+                new_epoch: checkpoint.epoch + 1,
+                owner_index: vec![],
+                validator_epochs,
+            })
+        } else {
+            None
+        };
 
         Ok((
             TemporaryCheckpointStore {
@@ -391,6 +800,11 @@ where
                 packages,
                 move_calls,
                 recipients,
+                shared_object_usage,
+                transaction_errors,
+                address_activity,
+                dynamic_fields,
+                deleted_dynamic_fields,
             },
             epoch_index,
         ))
@@ -440,3 +854,242 @@ where
             .collect()
     }
 }
+
+/// Checkpoint downloaded ahead of its indexing, along with its data or the error downloading it.
+struct DownloadedCheckpoint {
+    checkpoint: RpcCheckpoint,
+    data: Result<CheckpointData, IndexerError>,
+}
+
+/// Download the checkpoints from `next_sequence_number` on and send them in order to the
+/// indexing pipeline, until it stops. Checkpoints are downloaded in batches, by one task per
+/// checkpoint of the batch. Batches aim at [TARGET_BATCH_TX_COUNT] transactions given the number
+/// of transactions of the checkpoints of the previous batch, and are halved when the indexing
+/// pipeline can't keep up and the queue of downloaded checkpoints is full.
+async fn download_checkpoints(
+    rpc_client: SuiClient,
+    metrics: IndexerCheckpointHandlerMetrics,
+    mut next_sequence_number: u64,
+    sender: mpsc::Sender<DownloadedCheckpoint>,
+) {
+    let mut batch_size = MIN_DOWNLOAD_BATCH_SIZE;
+    loop {
+        let latest_sequence_number = match rpc_client
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+        {
+            Ok(sequence_number) => sequence_number,
+            Err(e) => {
+                warn!(
+                    "Failed to read latest checkpoint sequence number from fullnode with error: {:?}",
+                    e
+                );
+                tokio::time::sleep(CHECKPOINT_POLL_INTERVAL).await;
+                continue;
+            }
+        };
+        metrics
+            .latest_fullnode_checkpoint_sequence_number
+            .set(latest_sequence_number as i64);
+        if latest_sequence_number < next_sequence_number {
+            tokio::time::sleep(CHECKPOINT_POLL_INTERVAL).await;
+            continue;
+        }
+
+        let batch_end = latest_sequence_number.min(next_sequence_number + batch_size as u64 - 1);
+        metrics
+            .checkpoint_download_batch_size
+            .set(batch_size as i64);
+        let downloads = (next_sequence_number..=batch_end)
+            .map(|sequence_number| {
+                let rpc_client = rpc_client.clone();
+                let metrics = metrics.clone();
+                spawn_monitored_task!(async move {
+                    let checkpoint = get_checkpoint(&rpc_client, &metrics, sequence_number).await;
+                    let data = download_checkpoint(&rpc_client, &metrics, checkpoint.clone()).await;
+                    DownloadedCheckpoint { checkpoint, data }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let checkpoint_count = downloads.len();
+        let mut tx_count = 0;
+        let mut backpressured = false;
+        for download in downloads {
+            let downloaded = match download.await {
+                Ok(downloaded) => downloaded,
+                Err(e) => {
+                    error!(
+                        "Failed to download checkpoint {next_sequence_number} with error: {:?}, stopping the downloader.",
+                        e
+                    );
+                    return;
+                }
+            };
+            tx_count += downloaded.checkpoint.transactions.len();
+            next_sequence_number += 1;
+            metrics.downloaded_checkpoint_queue_size.inc();
+            let downloaded = match sender.try_send(downloaded) {
+                Ok(()) => continue,
+                Err(TrySendError::Full(downloaded)) => downloaded,
+                Err(TrySendError::Closed(_)) => return,
+            };
+            backpressured = true;
+            metrics.total_checkpoint_download_backpressure.inc();
+            if sender.send(downloaded).await.is_err() {
+                return;
+            }
+        }
+        batch_size = next_batch_size(batch_size, checkpoint_count, tx_count, backpressured);
+    }
+}
+
+/// Size of the batch following a batch of `checkpoint_count` checkpoints and `tx_count`
+/// transactions, downloaded with a batch size of `batch_size`.
+fn next_batch_size(
+    batch_size: usize,
+    checkpoint_count: usize,
+    tx_count: usize,
+    backpressured: bool,
+) -> usize {
+    let next = if backpressured {
+        batch_size / 2
+    } else {
+        let tx_per_checkpoint = (tx_count / checkpoint_count.max(1)).max(1);
+        // Grow progressively, the next checkpoints may be much larger.
+        (TARGET_BATCH_TX_COUNT / tx_per_checkpoint).min(batch_size * 2)
+    };
+    next.clamp(MIN_DOWNLOAD_BATCH_SIZE, MAX_DOWNLOAD_BATCH_SIZE)
+}
+
+/// Get checkpoint `sequence_number`, known to exist, retrying until the full node returns it.
+async fn get_checkpoint(
+    rpc_client: &SuiClient,
+    metrics: &IndexerCheckpointHandlerMetrics,
+    sequence_number: u64,
+) -> RpcCheckpoint {
+    metrics.total_checkpoint_requested.inc();
+    loop {
+        match rpc_client
+            .read_api()
+            .get_checkpoint(sequence_number.into())
+            .await
+        {
+            Ok(checkpoint) => return checkpoint,
+            Err(e) => {
+                warn!(
+                    "Failed to get checkpoint {sequence_number} with error: {:?}, retrying...",
+                    e
+                );
+                tokio::time::sleep(CHECKPOINT_POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+/// Download the data of `checkpoint`, recording the download metrics.
+async fn download_checkpoint(
+    rpc_client: &SuiClient,
+    metrics: &IndexerCheckpointHandlerMetrics,
+    checkpoint: RpcCheckpoint,
+) -> Result<CheckpointData, IndexerError> {
+    let sequence_number = checkpoint.sequence_number;
+    let request_guard = metrics.full_node_read_request_latency.start_timer();
+    let data = download_checkpoint_data(rpc_client, checkpoint)
+        .await
+        .map_err(|e| match e {
+            // The full node is missing changed objects of the checkpoint.
+            Error::UserInputError(e) => IndexerError::DataInconsistency(format!(
+                "objects changed by checkpoint {sequence_number} missing from the full node: {e}"
+            )),
+            e => IndexerError::rpc_fetch(
+                format!("downloading data of checkpoint {sequence_number}"),
+                e,
+            ),
+        })?;
+    request_guard.stop_and_record();
+    metrics.total_checkpoint_received.inc();
+    Ok(data)
+}
+
+/// Download all the data we need for one checkpoint.
+async fn download_checkpoint_data(
+    rpc_client: &SuiClient,
+    checkpoint: RpcCheckpoint,
+) -> Result<CheckpointData, Error> {
+    let transactions = join_all(checkpoint.transactions.chunks(MULTI_GET_CHUNK_SIZE).map(
+        |digests| {
+            rpc_client.read_api().multi_get_transactions_with_options(
+                digests.to_vec(),
+                SuiTransactionResponseOptions::full_content(),
+            )
+        },
+    ))
+    .await
+    .into_iter()
+    .try_fold(vec![], |mut acc, chunk| {
+        acc.extend(chunk?);
+        Ok::<_, Error>(acc)
+    })?;
+
+    let all_mutated = transactions
+        .iter()
+        .flat_map(|tx| {
+            let effects = tx.effects.as_ref().expect("effects should not be empty");
+            effects.all_changed_objects()
+        })
+        .map(|(o, kind)| {
+            (
+                o.reference.object_id,
+                o.reference.version,
+                ObjectStatus::from(kind),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // TODO: Use multi get objects
+    let all_mutated_objects = join_all(all_mutated.into_iter().map(|(id, version, status)| {
+        rpc_client
+            .read_api()
+            .try_get_parsed_past_object(id, version, SuiObjectDataOptions::bcs_lossless())
+            .map(move |resp| (resp, status))
+    }))
+    .await
+    .into_iter()
+    .try_fold(vec![], |mut acc, (response, status)| {
+        acc.push((status, response?.into_object()?));
+        Ok::<_, Error>(acc)
+    })?;
+
+    // Dynamic field names can only be decoded with the Move type layout, fetch the parsed
+    // content of dynamic field objects from the full node.
+    let dynamic_field_objects = join_all(
+        all_mutated_objects
+            .iter()
+            .filter(|(_, o)| match &o.type_ {
+                Some(ObjectType::Struct(tag)) => DynamicFieldInfo::is_dynamic_field(tag),
+                _ => false,
+            })
+            .map(|(_, o)| {
+                rpc_client.read_api().try_get_parsed_past_object(
+                    o.object_id,
+                    o.version,
+                    SuiObjectDataOptions::bcs_lossless().with_content(),
+                )
+            }),
+    )
+    .await
+    .into_iter()
+    .try_fold(vec![], |mut acc, response| {
+        acc.push(response?.into_object()?);
+        Ok::<_, Error>(acc)
+    })?;
+
+    Ok(CheckpointData {
+        checkpoint,
+        transactions,
+        all_mutated_objects,
+        dynamic_field_objects,
+    })
+}
@@ -0,0 +1,207 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use mysten_metrics::spawn_monitored_task;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use prometheus::Registry;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::errors::IndexerError;
+use crate::export::tables::{
+    checkpoints_batch, events_batch, to_parquet, transactions_batch, CHECKPOINTS_TABLE,
+    EVENTS_TABLE, TRANSACTIONS_TABLE,
+};
+use crate::export::{
+    data_file_path, export_path, last_exported_checkpoint, write_manifest, ExportManifest,
+    ExportedFile,
+};
+use crate::metrics::IndexerExportHandlerMetrics;
+use crate::store::{CheckpointRangeData, IndexerStore};
+
+const HANDLER_RETRY_INTERVAL_IN_SECS: u64 = 10;
+const EXPORT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Continuously exports the indexed checkpoints to object storage, see [crate::export].
+pub struct ExportHandler<S> {
+    state: S,
+    object_store: Arc<dyn ObjectStore>,
+    root: Path,
+    batch_size: i64,
+    metrics: IndexerExportHandlerMetrics,
+}
+
+impl<S> ExportHandler<S>
+where
+    S: IndexerStore + Sync + Send + 'static,
+{
+    pub fn new(
+        state: S,
+        object_store: Arc<dyn ObjectStore>,
+        root: Path,
+        batch_size: usize,
+        prometheus_registry: &Registry,
+    ) -> Self {
+        Self {
+            state,
+            object_store,
+            root,
+            batch_size: batch_size.max(1) as i64,
+            metrics: IndexerExportHandlerMetrics::new(prometheus_registry),
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        spawn_monitored_task!(async move {
+            let mut export_handler_exec_res = self.start().await;
+            while let Err(e) = &export_handler_exec_res {
+                self.metrics.total_export_handler_error.inc();
+                warn!(
+                    "Indexer export handler failed with error: {:?}, retrying after {:?} secs...",
+                    e, HANDLER_RETRY_INTERVAL_IN_SECS
+                );
+                tokio::time::sleep(Duration::from_secs(HANDLER_RETRY_INTERVAL_IN_SECS)).await;
+                export_handler_exec_res = self.start().await;
+            }
+        })
+    }
+
+    async fn start(&self) -> Result<(), IndexerError> {
+        info!("Indexer export handler started...");
+        // Batches are only complete once their manifest is written, so resuming after the last
+        // manifest re-exports the batch that was in flight, if any.
+        let mut next_checkpoint =
+            match last_exported_checkpoint(self.object_store.as_ref(), &self.root).await? {
+                Some(last) => {
+                    info!("Resuming export from checkpoint {}", last + 1);
+                    last + 1
+                }
+                None => 0,
+            };
+
+        loop {
+            let latest_checkpoint = self.state.get_latest_checkpoint_sequence_number()?;
+            if latest_checkpoint < next_checkpoint {
+                tokio::time::sleep(EXPORT_POLL_INTERVAL).await;
+                continue;
+            }
+            let last_checkpoint = latest_checkpoint.min(next_checkpoint + self.batch_size - 1);
+            let data = self
+                .state
+                .get_checkpoint_range_data(next_checkpoint, last_checkpoint)?;
+            let Some(data) = next_batch(data, next_checkpoint) else {
+                // The next checkpoint is not indexed yet while later ones are, which happens
+                // while it is in dead letters, wait for it to be reprocessed.
+                warn!("Checkpoint {next_checkpoint} is not indexed yet, waiting to export it");
+                tokio::time::sleep(EXPORT_POLL_INTERVAL).await;
+                continue;
+            };
+
+            let _timer = self.metrics.export_batch_latency.start_timer();
+            let last_exported = self.export_batch(&data).await?;
+            self.metrics
+                .total_checkpoint_exported
+                .inc_by(data.checkpoints.len() as u64);
+            self.metrics
+                .latest_exported_checkpoint_sequence_number
+                .set(last_exported);
+            next_checkpoint = last_exported + 1;
+        }
+    }
+
+    /// Writes the files of the batch then its manifest, and returns the last checkpoint of the
+    /// batch.
+    async fn export_batch(&self, data: &CheckpointRangeData) -> Result<i64, IndexerError> {
+        // next_batch never returns an empty batch
+        let first = &data.checkpoints[0];
+        let last = &data.checkpoints[data.checkpoints.len() - 1];
+        let (epoch, first_checkpoint, last_checkpoint) =
+            (first.epoch, first.sequence_number, last.sequence_number);
+
+        let batches = [
+            (CHECKPOINTS_TABLE, checkpoints_batch(&data.checkpoints)?),
+            (TRANSACTIONS_TABLE, transactions_batch(&data.transactions)?),
+            (EVENTS_TABLE, events_batch(&data.events)?),
+        ];
+        let mut files = vec![];
+        for (table, batch) in batches {
+            files.push(
+                self.write_file(table, epoch, first_checkpoint, last_checkpoint, &batch)
+                    .await?,
+            );
+        }
+        write_manifest(
+            self.object_store.as_ref(),
+            &self.root,
+            &ExportManifest {
+                epoch,
+                first_checkpoint,
+                last_checkpoint,
+                files,
+            },
+        )
+        .await?;
+        info!("Exported checkpoints {first_checkpoint} to {last_checkpoint} of epoch {epoch}");
+        Ok(last_checkpoint)
+    }
+
+    async fn write_file(
+        &self,
+        table: &str,
+        epoch: i64,
+        first_checkpoint: i64,
+        last_checkpoint: i64,
+        batch: &RecordBatch,
+    ) -> Result<ExportedFile, IndexerError> {
+        let path = data_file_path(table, epoch, first_checkpoint, last_checkpoint);
+        let bytes = to_parquet(batch)?;
+        self.object_store
+            .put(&export_path(&self.root, &path), bytes.into())
+            .await
+            .map_err(|e| IndexerError::ExportError(format!("Failed writing {path}: {e}")))?;
+        Ok(ExportedFile {
+            table: table.to_string(),
+            path,
+            rows: batch.num_rows(),
+        })
+    }
+}
+
+/// Restricts the data read from the store to the checkpoints that can be exported as the next
+/// batch: the consecutive checkpoints from `next_checkpoint` on that belong to the same epoch, so
+/// that each file of the batch belongs to a single epoch partition. Returns `None` if
+/// `next_checkpoint` is not indexed.
+pub(crate) fn next_batch(
+    data: CheckpointRangeData,
+    next_checkpoint: i64,
+) -> Option<CheckpointRangeData> {
+    let epoch = data.checkpoints.first()?.epoch;
+    let checkpoints = data
+        .checkpoints
+        .into_iter()
+        .enumerate()
+        .take_while(|(i, c)| c.sequence_number == next_checkpoint + *i as i64 && c.epoch == epoch)
+        .map(|(_, c)| c)
+        .collect::<Vec<_>>();
+    let last_checkpoint = checkpoints.last()?.sequence_number;
+    let transactions = data
+        .transactions
+        .into_iter()
+        .filter(|t| t.checkpoint_sequence_number <= last_checkpoint)
+        .collect::<Vec<_>>();
+    let events = data
+        .events
+        .into_iter()
+        .filter(|e| e.checkpoint <= last_checkpoint)
+        .collect();
+    Some(CheckpointRangeData {
+        checkpoints,
+        transactions,
+        events,
+    })
+}
@@ -0,0 +1,97 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use sui_json_rpc_types::{
+    SuiEvent, SuiObjectData, SuiTransactionDataAPI, SuiTransactionEffectsAPI, SuiTransactionKind,
+    SuiTransactionResponse,
+};
+use sui_types::base_types::{ObjectID, ObjectType, SuiAddress};
+use sui_types::object::Owner;
+
+/// Packages and addresses an indexer running in filtered mode is interested in. Only the
+/// transactions touching them are indexed, along with their events, objects and the other data
+/// derived from them, while every checkpoint is still indexed to track the progress of the
+/// indexer. An empty interest list indexes everything.
+#[derive(Clone, Debug, Default)]
+pub struct InterestList {
+    packages: BTreeSet<ObjectID>,
+    addresses: BTreeSet<SuiAddress>,
+}
+
+impl InterestList {
+    pub fn new(
+        packages: impl IntoIterator<Item = ObjectID>,
+        addresses: impl IntoIterator<Item = SuiAddress>,
+    ) -> Self {
+        Self {
+            packages: packages.into_iter().collect(),
+            addresses: addresses.into_iter().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty() && self.addresses.is_empty()
+    }
+
+    /// Whether `tx` touches the interest list: it is sent by an address of the list, changes
+    /// objects owned by one, calls or emits events from a package of the list, or changes
+    /// `objects` of a type defined in one.
+    pub fn matches<'a>(
+        &self,
+        tx: &SuiTransactionResponse,
+        objects: impl IntoIterator<Item = &'a SuiObjectData>,
+    ) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        if let Some(transaction) = &tx.transaction {
+            let data = &transaction.data;
+            if self.addresses.contains(data.sender()) {
+                return true;
+            }
+            let calls_package = data.transactions().iter().any(|kind| match kind {
+                SuiTransactionKind::Call(call) => self.packages.contains(&call.package),
+                _ => false,
+            });
+            if calls_package {
+                return true;
+            }
+        }
+        if let Some(effects) = &tx.effects {
+            for (obj_ref, _) in effects.all_changed_objects() {
+                if let Owner::AddressOwner(address) = obj_ref.owner {
+                    if self.addresses.contains(&address) {
+                        return true;
+                    }
+                }
+            }
+        }
+        let emits_package_event = tx
+            .events
+            .iter()
+            .flat_map(|events| events.data.iter())
+            .any(|event| event_package(event).map_or(false, |p| self.packages.contains(p)));
+        if emits_package_event {
+            return true;
+        }
+        objects.into_iter().any(|o| match &o.type_ {
+            Some(ObjectType::Struct(tag)) => self.packages.contains(&ObjectID::from(tag.address)),
+            _ => false,
+        })
+    }
+}
+
+fn event_package(event: &SuiEvent) -> Option<&ObjectID> {
+    match event {
+        SuiEvent::MoveEvent { package_id, .. }
+        | SuiEvent::Publish { package_id, .. }
+        | SuiEvent::CoinBalanceChange { package_id, .. }
+        | SuiEvent::TransferObject { package_id, .. }
+        | SuiEvent::MutateObject { package_id, .. }
+        | SuiEvent::DeleteObject { package_id, .. }
+        | SuiEvent::NewObject { package_id, .. } => Some(package_id),
+        SuiEvent::EpochChange(_) | SuiEvent::Checkpoint(_) => None,
+    }
+}
@@ -2,3 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod checkpoint_handler;
+pub mod export_handler;
+pub mod interest_list;
+pub mod sink_handler;
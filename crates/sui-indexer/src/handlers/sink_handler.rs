@@ -0,0 +1,111 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use mysten_metrics::spawn_monitored_task;
+use prometheus::Registry;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::errors::IndexerError;
+use crate::handlers::export_handler::next_batch;
+use crate::metrics::IndexerSinkHandlerMetrics;
+use crate::sink::{checkpoint_records, DataSink};
+use crate::store::IndexerStore;
+
+const HANDLER_RETRY_INTERVAL_IN_SECS: u64 = 10;
+const SINK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Continuously publishes the indexed checkpoints to an external sink, see [crate::sink].
+pub struct SinkHandler<S> {
+    state: S,
+    sink: Box<dyn DataSink>,
+    batch_size: i64,
+    metrics: IndexerSinkHandlerMetrics,
+}
+
+impl<S> SinkHandler<S>
+where
+    S: IndexerStore + Sync + Send + 'static,
+{
+    pub fn new(
+        state: S,
+        sink: Box<dyn DataSink>,
+        batch_size: usize,
+        prometheus_registry: &Registry,
+    ) -> Self {
+        Self {
+            state,
+            sink,
+            batch_size: batch_size.max(1) as i64,
+            metrics: IndexerSinkHandlerMetrics::new(prometheus_registry),
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        spawn_monitored_task!(async move {
+            let mut sink_handler_exec_res = self.start().await;
+            while let Err(e) = &sink_handler_exec_res {
+                self.metrics.total_sink_handler_error.inc();
+                warn!(
+                    "Indexer sink handler failed with error: {:?}, retrying after {:?} secs...",
+                    e, HANDLER_RETRY_INTERVAL_IN_SECS
+                );
+                tokio::time::sleep(Duration::from_secs(HANDLER_RETRY_INTERVAL_IN_SECS)).await;
+                sink_handler_exec_res = self.start().await;
+            }
+        })
+    }
+
+    async fn start(&self) -> Result<(), IndexerError> {
+        let sink_name = self.sink.name();
+        info!("Indexer sink handler of {sink_name} started...");
+        let mut next_checkpoint = match self.state.get_sink_watermark(sink_name)? {
+            Some(last) => {
+                info!(
+                    "Resuming publication to {sink_name} from checkpoint {}",
+                    last + 1
+                );
+                last + 1
+            }
+            None => 0,
+        };
+
+        loop {
+            let latest_checkpoint = self.state.get_latest_checkpoint_sequence_number()?;
+            if latest_checkpoint < next_checkpoint {
+                tokio::time::sleep(SINK_POLL_INTERVAL).await;
+                continue;
+            }
+            let last_checkpoint = latest_checkpoint.min(next_checkpoint + self.batch_size - 1);
+            let data = self
+                .state
+                .get_checkpoint_range_data(next_checkpoint, last_checkpoint)?;
+            let Some(data) = next_batch(data, next_checkpoint) else {
+                warn!("Checkpoint {next_checkpoint} is not indexed yet, waiting to publish it");
+                tokio::time::sleep(SINK_POLL_INTERVAL).await;
+                continue;
+            };
+
+            let _timer = self.metrics.sink_batch_latency.start_timer();
+            let records = checkpoint_records(&data)?;
+            self.sink.publish(&records).await?;
+            // next_batch never returns an empty batch
+            let last_published = data.checkpoints[data.checkpoints.len() - 1].sequence_number;
+            // Only moved once the whole batch is acknowledged, so that a failed batch is
+            // published again.
+            self.state.set_sink_watermark(sink_name, last_published)?;
+            self.metrics
+                .total_checkpoint_published
+                .inc_by(data.checkpoints.len() as u64);
+            self.metrics
+                .total_record_published
+                .inc_by(records.len() as u64);
+            self.metrics
+                .latest_published_checkpoint_sequence_number
+                .set(last_published);
+            next_checkpoint = last_published + 1;
+        }
+    }
+}
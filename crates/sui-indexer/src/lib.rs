@@ -10,28 +10,41 @@ use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
 use jsonrpsee::http_client::{HeaderMap, HeaderValue, HttpClientBuilder};
 use prometheus::Registry;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle, CLIENT_SDK_TYPE_HEADER};
+use std::sync::Arc;
+use sui_json_rpc::{
+    JsonRpcServerBuilder, RequestFuture, RequestScope, ServerHandle, CHECKPOINT_WATERMARK_HEADER,
+    CLIENT_SDK_TYPE_HEADER,
+};
 use sui_sdk::{SuiClient, SuiClientBuilder};
 use tracing::{info, warn};
 
 pub mod apis;
 pub mod errors;
+pub mod export;
+#[cfg(feature = "graphql")]
+pub mod graphql;
 mod handlers;
 pub mod metrics;
+pub mod migrations;
 pub mod models;
 pub mod processors;
 pub mod schema;
+pub mod sink;
 pub mod store;
 pub mod utils;
 
+pub use handlers::interest_list::InterestList;
+
 pub type PgConnectionPool = Pool<ConnectionManager<PgConnection>>;
 pub type PgPoolConnection = PooledConnection<ConnectionManager<PgConnection>>;
 
 use crate::apis::{
-    CoinReadApi, EventReadApi, GovernanceReadApi, ReadApi, ThresholdBlsApi, TransactionBuilderApi,
-    WriteApi,
+    CoinReadApi, EventReadApi, ExtendedApi, GovernanceReadApi, ReadApi, ThresholdBlsApi,
+    TransactionBuilderApi, WriteApi,
 };
 use crate::handlers::checkpoint_handler::CheckpointHandler;
+use crate::handlers::export_handler::ExportHandler;
+use crate::handlers::sink_handler::SinkHandler;
 use crate::store::IndexerStore;
 use errors::IndexerError;
 use mysten_metrics::spawn_monitored_task;
@@ -42,10 +55,13 @@ pub const FAKE_PKG_VERSION: &str = "0.0.0";
 pub struct Indexer;
 
 impl Indexer {
+    /// Index the checkpoints of the full node at `fullnode_url` and serve the JSON-RPC API, only
+    /// indexing the transactions touching `interest_list` if it is not empty.
     pub async fn start<S: IndexerStore + Sync + Send + Clone + 'static>(
         fullnode_url: &str,
         registry: &Registry,
         store: S,
+        interest_list: InterestList,
     ) -> Result<(), IndexerError> {
         let handle = build_json_rpc_server(registry, store.clone(), fullnode_url)
             .await
@@ -58,7 +74,8 @@ impl Indexer {
             let rpc_client = new_rpc_client(fullnode_url).await?;
             // NOTE: Each handler is responsible for one type of data from nodes,like transactions and events;
             // Handler orchestrator runs these handlers in parallel and manage them upon errors etc.
-            let cp = CheckpointHandler::new(store.clone(), rpc_client.clone(), registry);
+            let cp = CheckpointHandler::new(store.clone(), rpc_client.clone(), registry)
+                .with_interest_list(interest_list.clone());
             cp.spawn()
                 .await
                 .expect("Indexer main should not run into errors.");
@@ -66,6 +83,76 @@ impl Indexer {
         })
         .await
     }
+
+    /// Continuously export the indexed checkpoints, transactions and events as Parquet files to
+    /// the object storage at `export_url`, see [export].
+    pub fn spawn_export<S: IndexerStore + Sync + Send + 'static>(
+        export_url: &str,
+        batch_size: usize,
+        registry: &Registry,
+        store: S,
+    ) -> Result<(), IndexerError> {
+        let (object_store, root) = export::object_store_from_url(export_url)?;
+        ExportHandler::new(store, object_store, root, batch_size, registry).spawn();
+        info!("Sui indexer export to {export_url} started...");
+        Ok(())
+    }
+
+    /// Continuously publish the indexed checkpoints, transactions and events to the Kafka or NATS
+    /// sink at `sink_url`, see [sink].
+    pub async fn spawn_sink<S: IndexerStore + Sync + Send + 'static>(
+        sink_url: &str,
+        batch_size: usize,
+        registry: &Registry,
+        store: S,
+    ) -> Result<(), IndexerError> {
+        let sink = sink::sink_from_url(sink_url).await?;
+        info!("Sui indexer publication to {} started...", sink.name());
+        SinkHandler::new(store, sink, batch_size, registry).spawn();
+        Ok(())
+    }
+
+    /// Serve the GraphQL API over the store on `address`, see [graphql].
+    #[cfg(feature = "graphql")]
+    pub fn spawn_graphql_server<S: IndexerStore + Sync + Send + 'static>(
+        address: SocketAddr,
+        store: S,
+    ) -> Result<(), IndexerError> {
+        graphql::spawn_graphql_server(address, store)
+    }
+
+    /// Reprocess the checkpoints that the checkpoint handler moved to dead letters after they
+    /// repeatedly failed to be indexed.
+    pub async fn reprocess_dead_letters<S: IndexerStore + Sync + Send + 'static>(
+        fullnode_url: &str,
+        registry: &Registry,
+        store: S,
+        interest_list: InterestList,
+    ) -> Result<(), IndexerError> {
+        let rpc_client = new_rpc_client(fullnode_url).await?;
+        CheckpointHandler::new(store, rpc_client, registry)
+            .with_interest_list(interest_list)
+            .reprocess_dead_letters()
+            .await
+    }
+
+    /// Verify the indexed checkpoints `start..=end` against the full node, and reindex the
+    /// inconsistent ones if `repair` is set. Returns the inconsistent checkpoints.
+    pub async fn verify<S: IndexerStore + Sync + Send + 'static>(
+        fullnode_url: &str,
+        registry: &Registry,
+        store: S,
+        start: u64,
+        end: u64,
+        repair: bool,
+        interest_list: InterestList,
+    ) -> Result<Vec<u64>, IndexerError> {
+        let rpc_client = new_rpc_client(fullnode_url).await?;
+        CheckpointHandler::new(store, rpc_client, registry)
+            .with_interest_list(interest_list)
+            .verify_checkpoints(start, end, repair)
+            .await
+    }
 }
 
 pub async fn new_rpc_client(http_url: &str) -> Result<SuiClient, IndexerError> {
@@ -110,7 +197,7 @@ pub fn get_pg_pool_connection(pool: &PgConnectionPool) -> Result<PgPoolConnectio
     })
 }
 
-pub async fn build_json_rpc_server<S: IndexerStore + Sync + Send + 'static>(
+pub async fn build_json_rpc_server<S: IndexerStore + Sync + Send + Clone + 'static>(
     prometheus_registry: &Registry,
     state: S,
     fullnode_url: &str,
@@ -127,14 +214,58 @@ pub async fn build_json_rpc_server<S: IndexerStore + Sync + Send + 'static>(
         .build(fullnode_url)
         .map_err(|e| IndexerError::RpcClientInitError(e.to_string()))?;
 
-    builder.register_module(ReadApi::new(state, http_client.clone()))?;
+    builder.register_module(ReadApi::new(state.clone(), http_client.clone()))?;
     builder.register_module(CoinReadApi::new(http_client.clone()))?;
     builder.register_module(ThresholdBlsApi::new(http_client.clone()))?;
     builder.register_module(TransactionBuilderApi::new(http_client.clone()))?;
     builder.register_module(GovernanceReadApi::new(http_client.clone()))?;
     builder.register_module(EventReadApi::new(http_client.clone()))?;
     builder.register_module(WriteApi::new(http_client))?;
+    builder.register_module(ExtendedApi::new(state.clone()))?;
+    builder.set_request_scope(Arc::new(ReadSnapshotScope { state }));
     // TODO: placeholder, read from env or config file.
     let default_socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 3030);
     Ok(builder.start(default_socket_addr).await?)
 }
+
+/// Pins the reads of every request to one snapshot of the store, and returns the checkpoint of
+/// the snapshot in the [CHECKPOINT_WATERMARK_HEADER] of the response. The requests setting a
+/// [sui_json_rpc::MIN_CHECKPOINT_HEADER] are held until the checkpoint is indexed.
+struct ReadSnapshotScope<S> {
+    state: S,
+}
+
+impl<S: IndexerStore + Sync + Send + Clone + 'static> RequestScope for ReadSnapshotScope<S> {
+    fn run(&self, request: RequestFuture) -> RequestFuture {
+        let state = self.state.clone();
+        Box::pin(async move {
+            let snapshot = match state.begin_read_snapshot() {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    warn!(
+                        "Failed beginning read snapshot, reading without snapshot: {:?}",
+                        e
+                    );
+                    return request.await;
+                }
+            };
+            let mut response = snapshot.run(request).await?;
+            response.headers_mut().insert(
+                CHECKPOINT_WATERMARK_HEADER,
+                HeaderValue::from(snapshot.checkpoint()),
+            );
+            Ok(response)
+        })
+    }
+
+    fn latest_checkpoint(&self) -> Option<u64> {
+        // -1 if there is no checkpoint yet
+        match self.state.get_latest_checkpoint_sequence_number() {
+            Ok(checkpoint) => u64::try_from(checkpoint).ok(),
+            Err(e) => {
+                warn!("Failed reading latest checkpoint: {:?}", e);
+                None
+            }
+        }
+    }
+}
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use sui_indexer::errors::IndexerError;
-use sui_indexer::{new_pg_connection_pool, Indexer};
+use sui_indexer::migrations::setup_database;
+use sui_indexer::{get_pg_pool_connection, new_pg_connection_pool, Indexer, InterestList};
 use sui_node::metrics::start_prometheus_server;
 
 use clap::Parser;
 
 use sui_indexer::store::PgIndexerStore;
+use sui_types::base_types::{ObjectID, SuiAddress};
 
 #[tokio::main]
 async fn main() -> Result<(), IndexerError> {
@@ -29,9 +31,56 @@ async fn main() -> Result<(), IndexerError> {
 
     let registry = registry_service.default_registry();
     let pg_connection_pool = new_pg_connection_pool(&indexer_config.db_url).await?;
-    let store = PgIndexerStore::new(pg_connection_pool);
+    setup_database(
+        &mut get_pg_pool_connection(&pg_connection_pool)?,
+        indexer_config.auto_migrate,
+    )?;
+    let mut store = PgIndexerStore::new(pg_connection_pool, &registry)
+        .with_objects_snapshot(
+            indexer_config.objects_snapshot_interval,
+            indexer_config.objects_snapshot_retention,
+        )
+        .with_read_cache(indexer_config.read_cache_size_mb << 20);
+    if let Some(db_read_replica_url) = &indexer_config.db_read_replica_url {
+        store = store.with_read_replica(
+            new_pg_connection_pool(db_read_replica_url).await?,
+            indexer_config.max_replica_checkpoint_lag,
+        );
+    }
+    if let Some(export_url) = &indexer_config.export_url {
+        Indexer::spawn_export(
+            export_url,
+            indexer_config.export_batch_size,
+            &registry,
+            store.clone(),
+        )?;
+    }
+    if let Some(sink_url) = &indexer_config.sink_url {
+        Indexer::spawn_sink(
+            sink_url,
+            indexer_config.sink_batch_size,
+            &registry,
+            store.clone(),
+        )
+        .await?;
+    }
+
+    #[cfg(feature = "graphql")]
+    if let Some(graphql_address) = indexer_config.graphql_address {
+        Indexer::spawn_graphql_server(graphql_address, store.clone())?;
+    }
 
-    Indexer::start(&indexer_config.rpc_client_url, &registry, store).await
+    let interest_list = InterestList::new(
+        indexer_config.index_packages,
+        indexer_config.index_addresses,
+    );
+    Indexer::start(
+        &indexer_config.rpc_client_url,
+        &registry,
+        store,
+        interest_list,
+    )
+    .await
 }
 
 #[derive(Parser)]
@@ -43,10 +92,60 @@ async fn main() -> Result<(), IndexerError> {
 pub struct IndexerConfig {
     #[clap(long)]
     pub db_url: String,
+    /// Serve the reads of RPC requests from this read replica of the database at `db_url`.
+    #[clap(long)]
+    pub db_read_replica_url: Option<String>,
+    /// Read from the database at `db_url` while the read replica is more than this number of
+    /// checkpoints behind it.
+    #[clap(long, default_value = "10")]
+    pub max_replica_checkpoint_lag: u64,
     #[clap(long)]
     pub rpc_client_url: String,
     #[clap(long, default_value = "0.0.0.0", global = true)]
     pub client_metric_host: String,
     #[clap(long, default_value = "9184", global = true)]
     pub client_metric_port: u16,
+    /// Snapshot the address-owned objects every this number of checkpoints, so that the objects
+    /// owned by an address can be queried at a past checkpoint. 0 disables the snapshots.
+    #[clap(long, default_value = "1000")]
+    pub objects_snapshot_interval: u64,
+    /// Number of objects snapshots kept, the oldest of them is the oldest checkpoint at which
+    /// owned objects can be queried.
+    #[clap(long, default_value = "24")]
+    pub objects_snapshot_retention: u64,
+    /// Size in MiB of the in-memory cache of the transactions and checkpoints read by the RPC
+    /// API. 0 disables the cache.
+    #[clap(long, default_value = "256")]
+    pub read_cache_size_mb: u64,
+    /// Run the pending DB migrations at startup instead of refusing to start.
+    #[clap(long)]
+    pub auto_migrate: bool,
+    /// Also export checkpoints, transactions and events as Parquet files to object storage, at
+    /// `s3://<bucket>/<prefix>`, `gs://<bucket>/<prefix>` or `file://<directory>`.
+    #[clap(long)]
+    pub export_url: Option<String>,
+    /// Maximum number of checkpoints per exported file.
+    #[clap(long, default_value = "1000")]
+    pub export_batch_size: usize,
+    /// Also publish checkpoints, transactions and events to Kafka or NATS JetStream, at
+    /// `kafka://<broker>[,<broker>...]/<topic-prefix>` or `nats://<host>:<port>/<subject-prefix>`.
+    /// Requires the indexer to be built with the `kafka` or `nats` feature.
+    #[clap(long)]
+    pub sink_url: Option<String>,
+    /// Maximum number of checkpoints published per batch, records are published again from the
+    /// start of the batch that was in flight when the indexer stopped.
+    #[clap(long, default_value = "100")]
+    pub sink_batch_size: usize,
+    /// Only index the transactions calling, emitting events from or changing objects of this
+    /// package, can be repeated. Every checkpoint is still indexed.
+    #[clap(long = "index-package")]
+    pub index_packages: Vec<ObjectID>,
+    /// Only index the transactions sent by or changing objects owned by this address, can be
+    /// repeated. Every checkpoint is still indexed.
+    #[clap(long = "index-address")]
+    pub index_addresses: Vec<SuiAddress>,
+    /// Also serve the GraphQL API at `http://<graphql-address>/graphql`.
+    #[cfg(feature = "graphql")]
+    #[clap(long)]
+    pub graphql_address: Option<std::net::SocketAddr>,
 }
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_histogram_with_registry, register_int_counter_with_registry, Histogram, IntCounter,
+    register_histogram_vec_with_registry, register_histogram_with_registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, Histogram, HistogramVec, IntCounter, IntCounterVec, IntGauge,
     Registry,
 };
 
@@ -247,13 +249,22 @@ impl IndexerMoveEventHandlerMetrics {
     }
 }
 
+#[derive(Clone, Debug)]
 pub struct IndexerCheckpointHandlerMetrics {
     pub total_checkpoint_requested: IntCounter,
     pub total_checkpoint_received: IntCounter,
     pub total_checkpoint_processed: IntCounter,
+    pub total_checkpoint_dead_lettered: IntCounter,
     pub total_checkpoint_handler_error: IntCounter,
+    pub total_checkpoint_download_backpressure: IntCounter,
+    pub checkpoint_download_batch_size: IntGauge,
+    pub downloaded_checkpoint_queue_size: IntGauge,
     pub db_write_request_latency: Histogram,
     pub full_node_read_request_latency: Histogram,
+
+    pub latest_fullnode_checkpoint_sequence_number: IntGauge,
+    pub latest_indexer_checkpoint_sequence_number: IntGauge,
+    pub checkpoint_lag: IntGauge,
 }
 
 impl IndexerCheckpointHandlerMetrics {
@@ -271,12 +282,36 @@ impl IndexerCheckpointHandlerMetrics {
                 registry,
             )
             .unwrap(),
+            total_checkpoint_dead_lettered: register_int_counter_with_registry!(
+                "total_checkpoint_dead_lettered",
+                "Total number of checkpoint that failed processing after all retries",
+                registry,
+            )
+            .unwrap(),
             total_checkpoint_handler_error: register_int_counter_with_registry!(
                 "total_checkpoint_handler_error",
                 "Total number of checkpoint handler error",
                 registry,
             )
             .unwrap(),
+            total_checkpoint_download_backpressure: register_int_counter_with_registry!(
+                "total_checkpoint_download_backpressure",
+                "Total number of checkpoint downloads waiting for the indexing to catch up",
+                registry,
+            )
+            .unwrap(),
+            checkpoint_download_batch_size: register_int_gauge_with_registry!(
+                "checkpoint_download_batch_size",
+                "Number of checkpoints downloaded concurrently",
+                registry,
+            )
+            .unwrap(),
+            downloaded_checkpoint_queue_size: register_int_gauge_with_registry!(
+                "downloaded_checkpoint_queue_size",
+                "Number of downloaded checkpoints waiting to be indexed",
+                registry,
+            )
+            .unwrap(),
             total_checkpoint_requested: register_int_counter_with_registry!(
                 "total_checkpoint_requested",
                 "Total number of checkpoint requested",
@@ -297,6 +332,195 @@ impl IndexerCheckpointHandlerMetrics {
                 registry,
             )
             .unwrap(),
+            latest_fullnode_checkpoint_sequence_number: register_int_gauge_with_registry!(
+                "latest_fullnode_checkpoint_sequence_number",
+                "Latest checkpoint sequence number of the Full Node",
+                registry,
+            )
+            .unwrap(),
+            latest_indexer_checkpoint_sequence_number: register_int_gauge_with_registry!(
+                "latest_indexer_checkpoint_sequence_number",
+                "Latest checkpoint sequence number committed by the indexer",
+                registry,
+            )
+            .unwrap(),
+            checkpoint_lag: register_int_gauge_with_registry!(
+                "checkpoint_lag",
+                "Number of checkpoints the indexer is behind the Full Node",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// export related metrics
+#[derive(Clone, Debug)]
+pub struct IndexerExportHandlerMetrics {
+    pub total_checkpoint_exported: IntCounter,
+    pub total_export_handler_error: IntCounter,
+    pub export_batch_latency: Histogram,
+    pub latest_exported_checkpoint_sequence_number: IntGauge,
+}
+
+impl IndexerExportHandlerMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            total_checkpoint_exported: register_int_counter_with_registry!(
+                "total_checkpoint_exported",
+                "Total number of checkpoint exported to object storage",
+                registry,
+            )
+            .unwrap(),
+            total_export_handler_error: register_int_counter_with_registry!(
+                "total_export_handler_error",
+                "Total number of export handler error",
+                registry,
+            )
+            .unwrap(),
+            export_batch_latency: register_histogram_with_registry!(
+                "export_batch_latency",
+                "Time spent writing the files and manifest of a batch of checkpoints",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            latest_exported_checkpoint_sequence_number: register_int_gauge_with_registry!(
+                "latest_exported_checkpoint_sequence_number",
+                "Latest checkpoint sequence number exported to object storage",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// sink related metrics
+#[derive(Clone, Debug)]
+pub struct IndexerSinkHandlerMetrics {
+    pub total_checkpoint_published: IntCounter,
+    pub total_record_published: IntCounter,
+    pub total_sink_handler_error: IntCounter,
+    pub sink_batch_latency: Histogram,
+    pub latest_published_checkpoint_sequence_number: IntGauge,
+}
+
+impl IndexerSinkHandlerMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            total_checkpoint_published: register_int_counter_with_registry!(
+                "total_checkpoint_published",
+                "Total number of checkpoint published to the external sink",
+                registry,
+            )
+            .unwrap(),
+            total_record_published: register_int_counter_with_registry!(
+                "total_record_published",
+                "Total number of records published to the external sink",
+                registry,
+            )
+            .unwrap(),
+            total_sink_handler_error: register_int_counter_with_registry!(
+                "total_sink_handler_error",
+                "Total number of sink handler error",
+                registry,
+            )
+            .unwrap(),
+            sink_batch_latency: register_histogram_with_registry!(
+                "sink_batch_latency",
+                "Time spent publishing a batch of checkpoints until acknowledged by the sink",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            latest_published_checkpoint_sequence_number: register_int_gauge_with_registry!(
+                "latest_published_checkpoint_sequence_number",
+                "Latest checkpoint sequence number published to the external sink",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// store related metrics
+#[derive(Clone, Debug)]
+pub struct IndexerStoreMetrics {
+    pub persist_table_latency: HistogramVec,
+    pub total_partitions_created: IntCounterVec,
+    pub total_error_logs: IntCounterVec,
+
+    pub db_pool_max_size: IntGauge,
+    pub db_pool_connections: IntGauge,
+    pub db_pool_idle_connections: IntGauge,
+    pub db_replica_checkpoint_lag: IntGauge,
+    pub read_cache_hits: IntCounterVec,
+    pub read_cache_misses: IntCounterVec,
+}
+
+impl IndexerStoreMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            persist_table_latency: register_histogram_vec_with_registry!(
+                "persist_table_latency",
+                "Time spent inserting the rows of one checkpoint into a table",
+                &["table"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            total_partitions_created: register_int_counter_vec_with_registry!(
+                "total_partitions_created",
+                "Total number of table partitions created",
+                &["table"],
+                registry,
+            )
+            .unwrap(),
+            total_error_logs: register_int_counter_vec_with_registry!(
+                "total_error_logs",
+                "Total number of errors logged to the error_logs table",
+                &["error_type"],
+                registry,
+            )
+            .unwrap(),
+            db_pool_max_size: register_int_gauge_with_registry!(
+                "db_pool_max_size",
+                "Maximum number of connections of the PG connection pool",
+                registry,
+            )
+            .unwrap(),
+            db_pool_connections: register_int_gauge_with_registry!(
+                "db_pool_connections",
+                "Number of connections currently managed by the PG connection pool",
+                registry,
+            )
+            .unwrap(),
+            db_pool_idle_connections: register_int_gauge_with_registry!(
+                "db_pool_idle_connections",
+                "Number of idle connections in the PG connection pool",
+                registry,
+            )
+            .unwrap(),
+            db_replica_checkpoint_lag: register_int_gauge_with_registry!(
+                "db_replica_checkpoint_lag",
+                "Number of checkpoints the PG read replica is behind the primary",
+                registry,
+            )
+            .unwrap(),
+            read_cache_hits: register_int_counter_vec_with_registry!(
+                "read_cache_hits",
+                "Number of reads served from the read cache",
+                &["table"],
+                registry,
+            )
+            .unwrap(),
+            read_cache_misses: register_int_counter_vec_with_registry!(
+                "read_cache_misses",
+                "Number of reads of the read cache that went to the DB",
+                &["table"],
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
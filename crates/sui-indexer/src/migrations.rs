@@ -0,0 +1,69 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::migration::MigrationSource;
+use diesel::pg::Pg;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::collections::BTreeSet;
+use tracing::info;
+
+use crate::errors::IndexerError;
+use crate::PgPoolConnection;
+
+/// Migrations under `migrations/`, embedded in the binary at build time.
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// Check that the DB schema matches the migrations embedded in the binary before the indexer
+/// touches any table, so that a schema mismatch fails at startup instead of on the first query.
+///
+/// Refuses to run if the DB has migrations this binary does not know of, e.g. after a rollback
+/// of the binary. Pending migrations are run if `auto_migrate` is set, otherwise they are an
+/// error too.
+pub fn setup_database(conn: &mut PgPoolConnection, auto_migrate: bool) -> Result<(), IndexerError> {
+    let known_versions = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| migration_error("Failed to read embedded migrations", e))?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect::<BTreeSet<_>>();
+    let applied_versions = conn
+        .applied_migrations()
+        .map_err(|e| migration_error("Failed to read applied migrations", e))?
+        .iter()
+        .map(|version| version.to_string())
+        .collect::<BTreeSet<_>>();
+
+    let unknown_versions = applied_versions
+        .difference(&known_versions)
+        .cloned()
+        .collect::<Vec<_>>();
+    if !unknown_versions.is_empty() {
+        return Err(IndexerError::DatabaseMigrationError(format!(
+            "DB schema is ahead of the indexer binary, unknown applied migrations: {:?}",
+            unknown_versions
+        )));
+    }
+
+    let pending_versions = known_versions
+        .difference(&applied_versions)
+        .cloned()
+        .collect::<Vec<_>>();
+    if pending_versions.is_empty() {
+        info!("DB schema is up to date.");
+        return Ok(());
+    }
+    if !auto_migrate {
+        return Err(IndexerError::DatabaseMigrationError(format!(
+            "DB schema is behind the indexer binary, pending migrations: {:?}. Run the indexer with --auto-migrate to apply them.",
+            pending_versions
+        )));
+    }
+    info!("Running pending migrations: {:?}", pending_versions);
+    conn.run_pending_migrations(MIGRATIONS)
+        .map_err(|e| migration_error("Failed to run pending migrations", e))?;
+    info!("DB schema migrated.");
+    Ok(())
+}
+
+fn migration_error(message: &str, e: Box<dyn std::error::Error + Send + Sync>) -> IndexerError {
+    IndexerError::DatabaseMigrationError(format!("{message} with error: {:?}", e))
+}
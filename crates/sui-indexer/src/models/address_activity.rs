@@ -0,0 +1,75 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::models::transactions::Transaction;
+use crate::schema::address_activity;
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use sui_json_rpc_types::{ActiveAddressStats, AddressEpochActivity};
+
+/// Transactions sent by an address in an epoch.
+#[derive(Queryable, Insertable, Debug, Clone, PartialEq, Eq)]
+#[diesel(table_name = address_activity)]
+pub struct AddressActivity {
+    pub address: String,
+    pub epoch: i64,
+    pub tx_count: i64,
+    // Net of storage rebates
+    pub gas_spent: i64,
+}
+
+impl AddressActivity {
+    /// Activity of the senders of `transactions`, all of epoch `epoch`, ordered by address.
+    pub fn from_transactions(transactions: &[Transaction], epoch: i64) -> Vec<Self> {
+        let mut activity: BTreeMap<&str, (i64, i64)> = BTreeMap::new();
+        for tx in transactions {
+            let (tx_count, gas_spent) = activity.entry(&tx.sender).or_default();
+            *tx_count += 1;
+            *gas_spent += tx.total_gas_cost;
+        }
+        activity
+            .into_iter()
+            .map(|(address, (tx_count, gas_spent))| Self {
+                address: address.to_string(),
+                epoch,
+                tx_count,
+                gas_spent,
+            })
+            .collect()
+    }
+}
+
+impl From<AddressActivity> for AddressEpochActivity {
+    fn from(activity: AddressActivity) -> Self {
+        Self {
+            epoch: activity.epoch as u64,
+            tx_count: activity.tx_count as u64,
+            gas_spent: activity.gas_spent,
+        }
+    }
+}
+
+/// Activity of all the addresses over a range of epochs.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct ActiveAddressCount {
+    #[diesel(sql_type = BigInt)]
+    pub active_addresses: i64,
+    #[diesel(sql_type = BigInt)]
+    pub tx_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub gas_spent: i64,
+}
+
+impl ActiveAddressCount {
+    pub fn into_stats(self, start_epoch: u64, end_epoch: u64) -> ActiveAddressStats {
+        ActiveAddressStats {
+            start_epoch,
+            end_epoch,
+            active_addresses: self.active_addresses as u64,
+            tx_count: self.tx_count as u64,
+            gas_spent: self.gas_spent,
+        }
+    }
+}
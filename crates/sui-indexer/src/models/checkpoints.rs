@@ -102,6 +102,7 @@ impl TryFrom<Checkpoint> for RpcCheckpoint {
             timestamp_ms: checkpoint.timestamp_ms as u64,
             transactions: parsed_txn_digests,
             checkpoint_commitments: vec![],
+            from_archive: None,
         })
     }
 }
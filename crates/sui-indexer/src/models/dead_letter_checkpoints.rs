@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::schema::dead_letter_checkpoints;
+
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+
+/// A checkpoint that could not be indexed after all retries. Its checkpoint row is committed
+/// without transactions, events or object changes so that indexing can move on, and the rest can
+/// be indexed later by reprocessing it.
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = dead_letter_checkpoints)]
+pub struct DeadLetterCheckpoint {
+    pub sequence_number: i64,
+    pub error_type: String,
+    pub error: String,
+    pub attempts: i64,
+    pub first_failed_at: NaiveDateTime,
+    pub last_failed_at: NaiveDateTime,
+}
+
+impl DeadLetterCheckpoint {
+    pub fn new(sequence_number: i64, error: &IndexerError, attempts: i64) -> Self {
+        let now = Utc::now().naive_utc();
+        DeadLetterCheckpoint {
+            sequence_number,
+            error_type: error.name(),
+            error: error.to_string(),
+            attempts,
+            first_failed_at: now,
+            last_failed_at: now,
+        }
+    }
+}
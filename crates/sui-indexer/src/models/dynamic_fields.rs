@@ -0,0 +1,222 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::models::objects::Object;
+use crate::schema::dynamic_fields;
+use diesel::prelude::*;
+use move_core_types::language_storage::TypeTag;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use sui_json_rpc_types::{SuiMoveStruct, SuiMoveValue, SuiObjectData, SuiParsedData};
+use sui_types::base_types::{EpochId, ObjectID, ObjectType, SequenceNumber, SuiAddress};
+use sui_types::digests::ObjectDigest;
+use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldName, DynamicFieldType};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::Owner;
+use sui_types::{parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS};
+
+const DYNAMIC_FIELD: &str = "DynamicField";
+const DYNAMIC_OBJECT: &str = "DynamicObject";
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = dynamic_fields)]
+pub struct DynamicField {
+    // id of the sui::dynamic_field::Field object.
+    pub object_id: String,
+    pub epoch: i64,
+    pub checkpoint: i64,
+    pub version: i64,
+    pub object_digest: String,
+    pub parent_object_id: String,
+    pub name_type: String,
+    pub name_value: Value,
+    pub field_type: String,
+    pub value_type: String,
+    // id of the child object for dynamic object fields, same as object_id otherwise.
+    pub value_object_id: String,
+}
+
+impl DynamicField {
+    /// Extract the dynamic field from a Field object, the object must be fetched with its parsed
+    /// content. Returns None if the object is not a dynamic field.
+    pub fn try_from(
+        epoch: &EpochId,
+        checkpoint: &CheckpointSequenceNumber,
+        o: &SuiObjectData,
+    ) -> Result<Option<Self>, IndexerError> {
+        let Some(ObjectType::Struct(tag)) = &o.type_ else {
+            return Ok(None);
+        };
+        if !DynamicFieldInfo::is_dynamic_field(tag) {
+            return Ok(None);
+        }
+        let Some(Owner::ObjectOwner(parent)) = o.owner else {
+            return Err(IndexerError::InsertableParsingError(format!(
+                "Dynamic field {} is not owned by an object",
+                o.object_id
+            )));
+        };
+        let Some(SuiParsedData::MoveObject(move_object)) = &o.content else {
+            return Err(IndexerError::InsertableParsingError(format!(
+                "Parsed content of dynamic field {} is missing",
+                o.object_id
+            )));
+        };
+
+        let field_type = if DynamicFieldInfo::is_dynamic_object_field(tag) {
+            DynamicFieldType::DynamicObject
+        } else {
+            DynamicFieldType::DynamicField
+        };
+        let name_type = DynamicFieldInfo::try_extract_field_name(tag, &field_type)
+            .map_err(|e| IndexerError::InsertableParsingError(e.to_string()))?;
+
+        let name = get_field(&move_object.fields, "name", &o.object_id)?;
+        let value = get_field(&move_object.fields, "value", &o.object_id)?;
+        let (name_value, field_type, value_object_id) = match field_type {
+            DynamicFieldType::DynamicObject => {
+                // Name of a dynamic object field is wrapped in sui::dynamic_object_field::Wrapper
+                let name = match name {
+                    SuiMoveValue::Struct(wrapper) => get_field(wrapper, "name", &o.object_id)?,
+                    _ => {
+                        return Err(IndexerError::InsertableParsingError(format!(
+                            "Cannot extract name from dynamic object field {}",
+                            o.object_id
+                        )))
+                    }
+                };
+                let value_object_id = match value {
+                    SuiMoveValue::Address(address) => ObjectID::from(*address),
+                    _ => {
+                        return Err(IndexerError::InsertableParsingError(format!(
+                            "Cannot extract object id from dynamic object field {}",
+                            o.object_id
+                        )))
+                    }
+                };
+                (name.clone(), DYNAMIC_OBJECT, value_object_id)
+            }
+            DynamicFieldType::DynamicField => (name.clone(), DYNAMIC_FIELD, o.object_id),
+        };
+
+        Ok(Some(DynamicField {
+            object_id: o.object_id.to_string(),
+            epoch: *epoch as i64,
+            checkpoint: *checkpoint as i64,
+            version: o.version.value() as i64,
+            object_digest: o.digest.base58_encode(),
+            parent_object_id: parent.to_string(),
+            name_type: name_type.to_string(),
+            name_value: name_value.to_json_value(),
+            field_type: field_type.to_string(),
+            value_type: tag
+                .type_params
+                .get(1)
+                .map(|t| t.to_string())
+                .unwrap_or_default(),
+            value_object_id: value_object_id.to_string(),
+        }))
+    }
+
+    /// Convert to [DynamicFieldInfo], dynamic object fields take the object id, version, digest
+    /// and type from their child object.
+    pub fn try_into_info(self, child: Option<Object>) -> Result<DynamicFieldInfo, IndexerError> {
+        let name = DynamicFieldName {
+            type_: parse_sui_type_tag(&self.name_type).map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse dynamic field name type {} with err: {:?}",
+                    self.name_type, e
+                ))
+            })?,
+            value: self.name_value,
+        };
+        let (type_, object_type, object_id, version, digest) = match self.field_type.as_str() {
+            DYNAMIC_OBJECT => {
+                let child = child.ok_or_else(|| {
+                    IndexerError::DataInconsistency(format!(
+                        "Child object {} of dynamic object field {} not found",
+                        self.value_object_id, self.object_id
+                    ))
+                })?;
+                (
+                    DynamicFieldType::DynamicObject,
+                    child.object_type,
+                    child.object_id,
+                    child.version,
+                    child.object_digest,
+                )
+            }
+            _ => (
+                DynamicFieldType::DynamicField,
+                self.value_type,
+                self.object_id,
+                self.version,
+                self.object_digest,
+            ),
+        };
+        Ok(DynamicFieldInfo {
+            name,
+            type_,
+            object_type,
+            object_id: object_id.parse().map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse object id {} with err: {:?}",
+                    object_id, e
+                ))
+            })?,
+            version: SequenceNumber::from_u64(version as u64),
+            digest: digest.parse::<ObjectDigest>().map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse object digest {} with err: {:?}",
+                    digest, e
+                ))
+            })?,
+        })
+    }
+}
+
+/// Encode a dynamic field name value the same way names are stored, so that names given by
+/// clients match e.g. `u64` keys of a `Table` or `LinkedTable` given as JSON numbers, or
+/// addresses given in their short form.
+pub fn normalize_name_value(type_: &TypeTag, value: Value) -> Value {
+    match (type_, value) {
+        (TypeTag::U64 | TypeTag::U128 | TypeTag::U256, Value::Number(n)) => {
+            Value::String(n.to_string())
+        }
+        (TypeTag::Address, Value::String(s)) => normalize_address(s),
+        (TypeTag::Struct(tag), Value::String(s))
+            if tag.address == SUI_FRAMEWORK_ADDRESS
+                && tag.module.as_str() == "object"
+                && tag.name.as_str() == "ID" =>
+        {
+            normalize_address(s)
+        }
+        (_, value) => value,
+    }
+}
+
+fn normalize_address(s: String) -> Value {
+    match ObjectID::from_str(&s) {
+        Ok(id) => json!(SuiAddress::from(id)),
+        Err(_) => Value::String(s),
+    }
+}
+
+fn get_field<'a>(
+    move_struct: &'a SuiMoveStruct,
+    field_name: &str,
+    object_id: &ObjectID,
+) -> Result<&'a SuiMoveValue, IndexerError> {
+    match move_struct {
+        SuiMoveStruct::WithTypes { fields, .. } | SuiMoveStruct::WithFields(fields) => {
+            fields.get(field_name)
+        }
+        SuiMoveStruct::Runtime(_) => None,
+    }
+    .ok_or_else(|| {
+        IndexerError::InsertableParsingError(format!(
+            "Cannot extract [{field_name}] field from dynamic field {object_id}"
+        ))
+    })
+}
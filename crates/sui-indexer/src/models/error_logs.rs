@@ -41,10 +41,5 @@ pub fn commit_error_logs(
                 .values(&new_error_logs)
                 .execute(conn)
         })
-        .map_err(|e| {
-            IndexerError::PostgresWriteError(format!(
-                "Failed writing error logs to PostgresDB with error logs  {:?} and error: {:?}",
-                new_error_logs, e
-            ))
-        })
+        .map_err(|e| IndexerError::db(format!("writing {} error logs", new_error_logs.len()), e))
 }
@@ -1,11 +1,14 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::errors::IndexerError;
 use crate::schema::events;
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
+use sui_json_rpc_types::SuiEvent;
+use sui_types::digests::TransactionDigest;
 
-#[derive(Queryable, Insertable, Debug)]
+#[derive(Queryable, Insertable, Debug, Clone)]
 #[diesel(table_name = events)]
 pub struct Event {
     #[diesel(deserialize_as = i64)]
@@ -15,4 +18,112 @@ pub struct Event {
     pub event_time: Option<NaiveDateTime>,
     pub event_type: String,
     pub event_content: String,
+    pub event_json: Option<serde_json::Value>,
+    pub package_id: Option<String>,
+    pub transaction_module: Option<String>,
+    // Move struct tag of the event, only set for Move events
+    pub move_event_type: Option<String>,
+    pub sender: Option<String>,
+    pub checkpoint: i64,
+    pub transaction_index: i64,
+}
+
+impl Event {
+    pub fn try_from(
+        tx_digest: &TransactionDigest,
+        checkpoint: i64,
+        transaction_index: i64,
+        event_sequence: i64,
+        timestamp_ms: Option<u64>,
+        event: &SuiEvent,
+    ) -> Result<Self, IndexerError> {
+        // TODO: we should rethink how we store the raw event in DB
+        let event_content = serde_json::to_string(event).map_err(|err| {
+            IndexerError::InsertableParsingError(format!(
+                "Failed converting event to JSON with error: {:?}",
+                err
+            ))
+        })?;
+        let event_json = serde_json::to_value(event).map_err(|err| {
+            IndexerError::InsertableParsingError(format!(
+                "Failed converting event to JSON value with error: {:?}",
+                err
+            ))
+        })?;
+
+        let (package_id, transaction_module, move_event_type, sender) = match event {
+            SuiEvent::MoveEvent {
+                package_id,
+                transaction_module,
+                sender,
+                type_,
+                ..
+            } => (
+                Some(package_id.to_string()),
+                Some(transaction_module.clone()),
+                Some(type_.clone()),
+                Some(sender.to_string()),
+            ),
+            SuiEvent::Publish {
+                sender, package_id, ..
+            } => (
+                Some(package_id.to_string()),
+                None,
+                None,
+                Some(sender.to_string()),
+            ),
+            SuiEvent::CoinBalanceChange {
+                package_id,
+                transaction_module,
+                sender,
+                ..
+            }
+            | SuiEvent::TransferObject {
+                package_id,
+                transaction_module,
+                sender,
+                ..
+            }
+            | SuiEvent::MutateObject {
+                package_id,
+                transaction_module,
+                sender,
+                ..
+            }
+            | SuiEvent::DeleteObject {
+                package_id,
+                transaction_module,
+                sender,
+                ..
+            }
+            | SuiEvent::NewObject {
+                package_id,
+                transaction_module,
+                sender,
+                ..
+            } => (
+                Some(package_id.to_string()),
+                Some(transaction_module.clone()),
+                None,
+                Some(sender.to_string()),
+            ),
+            SuiEvent::EpochChange(_) | SuiEvent::Checkpoint(_) => (None, None, None, None),
+        };
+
+        Ok(Self {
+            id: None,
+            transaction_digest: tx_digest.to_string(),
+            event_sequence,
+            event_time: timestamp_ms.and_then(|t| NaiveDateTime::from_timestamp_millis(t as i64)),
+            event_type: event.get_event_type(),
+            event_content,
+            event_json: Some(event_json),
+            package_id,
+            transaction_module,
+            move_event_type,
+            sender,
+            checkpoint,
+            transaction_index,
+        })
+    }
 }
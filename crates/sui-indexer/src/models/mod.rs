@@ -1,8 +1,11 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod address_activity;
 pub mod addresses;
 pub mod checkpoints;
+pub mod dead_letter_checkpoints;
+pub mod dynamic_fields;
 pub mod error_logs;
 pub mod events;
 pub mod move_calls;
@@ -10,4 +13,7 @@ pub mod objects;
 pub mod owners;
 pub mod packages;
 pub mod recipients;
+pub mod shared_object_usage;
+pub mod transaction_errors;
 pub mod transactions;
+pub mod validator_epochs;
@@ -15,4 +15,5 @@ pub struct MoveCall {
     pub move_package: String,
     pub move_module: String,
     pub move_function: String,
+    pub transaction_index: i64,
 }
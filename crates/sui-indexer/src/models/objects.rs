@@ -1,21 +1,26 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::errors::IndexerError;
 use crate::models::owners::OwnerType;
-use crate::schema::objects;
 use crate::schema::sql_types::BcsBytes;
+use crate::schema::{objects, objects_delta};
 use diesel::deserialize::FromSql;
 use diesel::pg::{Pg, PgValue};
 use diesel::prelude::*;
 use diesel::serialize::{Output, ToSql, WriteTuple};
-use diesel::sql_types::{Bytea, Nullable, Record, VarChar};
+use diesel::sql_types::{BigInt, Bytea, Nullable, Record, VarChar};
 use diesel::SqlType;
 use diesel_derive_enum::DbEnum;
-use sui_json_rpc_types::{SuiObjectData, SuiObjectRef, SuiRawData};
-use sui_types::base_types::EpochId;
+use std::str::FromStr;
+use sui_json_rpc_types::{
+    ObjectChangeStatus, ObjectHistoryEntry, SuiObjectData, SuiObjectInfo, SuiObjectRef, SuiRawData,
+};
+use sui_types::base_types::{EpochId, ObjectDigest, ObjectID, SequenceNumber, SuiAddress};
 use sui_types::digests::TransactionDigest;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::object::Owner;
+use sui_types::storage::{DeleteKind, WriteKind};
 const OBJECT: &str = "object";
 
 #[derive(Queryable, Insertable, Debug, Identifiable, Clone)]
@@ -69,6 +74,57 @@ pub struct DeletedObject {
     pub object_status: ObjectStatus,
 }
 
+/// The last version of an object changed by a checkpoint, in the delta log replayed on top of
+/// the objects snapshots.
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = objects_delta)]
+pub struct ObjectDelta {
+    pub checkpoint: i64,
+    pub object_id: String,
+    pub version: i64,
+    pub object_digest: String,
+    pub owner_type: OwnerType,
+    pub owner_address: Option<String>,
+    pub initial_shared_version: Option<i64>,
+    pub previous_transaction: String,
+    pub object_type: String,
+    pub object_status: ObjectStatus,
+}
+
+impl From<&Object> for ObjectDelta {
+    fn from(o: &Object) -> Self {
+        Self {
+            checkpoint: o.checkpoint,
+            object_id: o.object_id.clone(),
+            version: o.version,
+            object_digest: o.object_digest.clone(),
+            owner_type: o.owner_type.clone(),
+            owner_address: o.owner_address.clone(),
+            initial_shared_version: o.initial_shared_version,
+            previous_transaction: o.previous_transaction.clone(),
+            object_type: o.object_type.clone(),
+            object_status: o.object_status,
+        }
+    }
+}
+
+impl From<&DeletedObject> for ObjectDelta {
+    fn from(o: &DeletedObject) -> Self {
+        Self {
+            checkpoint: o.checkpoint,
+            object_id: o.object_id.clone(),
+            version: o.version,
+            object_digest: o.object_digest.clone(),
+            owner_type: o.owner_type.clone(),
+            owner_address: None,
+            initial_shared_version: None,
+            previous_transaction: o.previous_transaction.clone(),
+            object_type: o.object_type.clone(),
+            object_status: o.object_status,
+        }
+    }
+}
+
 #[derive(DbEnum, Debug, Clone, Copy)]
 #[ExistingTypePath = "crate::schema::sql_types::ObjectStatus"]
 pub enum ObjectStatus {
@@ -80,6 +136,191 @@ pub enum ObjectStatus {
     UnwrappedThenDeleted,
 }
 
+impl From<ObjectStatus> for ObjectChangeStatus {
+    fn from(status: ObjectStatus) -> Self {
+        match status {
+            ObjectStatus::Created => ObjectChangeStatus::Created,
+            ObjectStatus::Mutated => ObjectChangeStatus::Mutated,
+            ObjectStatus::Deleted => ObjectChangeStatus::Deleted,
+            ObjectStatus::Wrapped => ObjectChangeStatus::Wrapped,
+            ObjectStatus::Unwrapped => ObjectChangeStatus::Unwrapped,
+            ObjectStatus::UnwrappedThenDeleted => ObjectChangeStatus::UnwrappedThenDeleted,
+        }
+    }
+}
+
+impl From<WriteKind> for ObjectStatus {
+    fn from(kind: WriteKind) -> Self {
+        match kind {
+            WriteKind::Mutate => ObjectStatus::Mutated,
+            WriteKind::Create => ObjectStatus::Created,
+            WriteKind::Unwrap => ObjectStatus::Unwrapped,
+        }
+    }
+}
+
+impl From<DeleteKind> for ObjectStatus {
+    fn from(kind: DeleteKind) -> Self {
+        match kind {
+            DeleteKind::Normal => ObjectStatus::Deleted,
+            DeleteKind::UnwrapThenDelete => ObjectStatus::UnwrappedThenDeleted,
+            DeleteKind::Wrap => ObjectStatus::Wrapped,
+        }
+    }
+}
+
+/// A version of an object from the objects history, along with the owner of its previous
+/// version.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct ObjectHistory {
+    #[diesel(sql_type = BigInt)]
+    pub checkpoint: i64,
+    #[diesel(sql_type = BigInt)]
+    pub version: i64,
+    #[diesel(sql_type = VarChar)]
+    pub object_digest: String,
+    #[diesel(sql_type = crate::schema::sql_types::OwnerType)]
+    pub owner_type: OwnerType,
+    #[diesel(sql_type = Nullable<VarChar>)]
+    pub owner_address: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub initial_shared_version: Option<i64>,
+    #[diesel(sql_type = VarChar)]
+    pub previous_transaction: String,
+    #[diesel(sql_type = crate::schema::sql_types::ObjectStatus)]
+    pub object_status: ObjectStatus,
+    #[diesel(sql_type = Nullable<crate::schema::sql_types::OwnerType>)]
+    pub previous_owner_type: Option<OwnerType>,
+    #[diesel(sql_type = Nullable<VarChar>)]
+    pub previous_owner_address: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub previous_initial_shared_version: Option<i64>,
+    #[diesel(sql_type = Nullable<crate::schema::sql_types::ObjectStatus>)]
+    pub previous_object_status: Option<ObjectStatus>,
+}
+
+impl TryFrom<ObjectHistory> for ObjectHistoryEntry {
+    type Error = IndexerError;
+
+    fn try_from(history: ObjectHistory) -> Result<Self, Self::Error> {
+        let status = ObjectChangeStatus::from(history.object_status);
+        let (digest, owner) = if status.is_live() {
+            let digest = ObjectDigest::from_str(&history.object_digest).map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse object digest {} with err: {:?}",
+                    history.object_digest, e
+                ))
+            })?;
+            let owner = owner_info_to_owner(
+                &history.owner_type,
+                history.owner_address.as_deref(),
+                history.initial_shared_version,
+            )?;
+            (Some(digest), Some(owner))
+        } else {
+            (None, None)
+        };
+        let previous_owner = match (history.previous_owner_type, history.previous_object_status) {
+            (Some(owner_type), Some(previous_status))
+                if ObjectChangeStatus::from(previous_status).is_live() =>
+            {
+                Some(owner_info_to_owner(
+                    &owner_type,
+                    history.previous_owner_address.as_deref(),
+                    history.previous_initial_shared_version,
+                )?)
+            }
+            _ => None,
+        };
+        let transaction_digest = TransactionDigest::from_str(&history.previous_transaction)
+            .map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse transaction digest {} with err: {:?}",
+                    history.previous_transaction, e
+                ))
+            })?;
+        Ok(ObjectHistoryEntry {
+            version: SequenceNumber::from_u64(history.version as u64),
+            digest,
+            status,
+            transaction_digest,
+            checkpoint: history.checkpoint as u64,
+            previous_owner,
+            owner,
+        })
+    }
+}
+
+/// An object owned by an address at a given checkpoint, merged from the objects snapshot and
+/// delta log.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct OwnedObject {
+    #[diesel(sql_type = VarChar)]
+    pub object_id: String,
+    #[diesel(sql_type = BigInt)]
+    pub version: i64,
+    #[diesel(sql_type = VarChar)]
+    pub object_digest: String,
+    #[diesel(sql_type = VarChar)]
+    pub owner_address: String,
+    #[diesel(sql_type = VarChar)]
+    pub previous_transaction: String,
+    #[diesel(sql_type = VarChar)]
+    pub object_type: String,
+}
+
+impl TryFrom<OwnedObject> for SuiObjectInfo {
+    type Error = IndexerError;
+
+    fn try_from(o: OwnedObject) -> Result<Self, Self::Error> {
+        let parse_error = |field: &str, value: &str, e: &dyn std::fmt::Debug| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to parse {} {} with err: {:?}",
+                field, value, e
+            ))
+        };
+        Ok(SuiObjectInfo {
+            object_id: ObjectID::from_str(&o.object_id)
+                .map_err(|e| parse_error("object id", &o.object_id, &e))?,
+            version: SequenceNumber::from_u64(o.version as u64),
+            digest: ObjectDigest::from_str(&o.object_digest)
+                .map_err(|e| parse_error("object digest", &o.object_digest, &e))?,
+            type_: o.object_type,
+            owner: owner_info_to_owner(&OwnerType::AddressOwner, Some(&o.owner_address), None)?,
+            previous_transaction: TransactionDigest::from_str(&o.previous_transaction)
+                .map_err(|e| parse_error("transaction digest", &o.previous_transaction, &e))?,
+        })
+    }
+}
+
+impl TryFrom<Object> for SuiObjectInfo {
+    type Error = IndexerError;
+
+    fn try_from(o: Object) -> Result<Self, Self::Error> {
+        let parse_error = |field: &str, value: &str, e: &dyn std::fmt::Debug| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to parse {} {} with err: {:?}",
+                field, value, e
+            ))
+        };
+        Ok(SuiObjectInfo {
+            object_id: ObjectID::from_str(&o.object_id)
+                .map_err(|e| parse_error("object id", &o.object_id, &e))?,
+            version: SequenceNumber::from_u64(o.version as u64),
+            digest: ObjectDigest::from_str(&o.object_digest)
+                .map_err(|e| parse_error("object digest", &o.object_digest, &e))?,
+            type_: o.object_type,
+            owner: owner_info_to_owner(
+                &o.owner_type,
+                o.owner_address.as_deref(),
+                o.initial_shared_version,
+            )?,
+            previous_transaction: TransactionDigest::from_str(&o.previous_transaction)
+                .map_err(|e| parse_error("transaction digest", &o.previous_transaction, &e))?,
+        })
+    }
+}
+
 impl Object {
     pub fn from(
         epoch: &EpochId,
@@ -162,3 +403,32 @@ pub fn owner_to_owner_info(owner: &Owner) -> (OwnerType, Option<String>, Option<
         Owner::Immutable => (OwnerType::Immutable, None, None),
     }
 }
+
+/// Inverse of [owner_to_owner_info].
+pub fn owner_info_to_owner(
+    owner_type: &OwnerType,
+    owner_address: Option<&str>,
+    initial_shared_version: Option<i64>,
+) -> Result<Owner, IndexerError> {
+    let parse_address = || {
+        let address = owner_address.ok_or_else(|| {
+            IndexerError::JsonSerdeError(format!("Missing address of {:?} owner", owner_type))
+        })?;
+        SuiAddress::from_str(address).map_err(|e| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to parse owner address {} with err: {:?}",
+                address, e
+            ))
+        })
+    };
+    Ok(match owner_type {
+        OwnerType::AddressOwner => Owner::AddressOwner(parse_address()?),
+        OwnerType::ObjectOwner => Owner::ObjectOwner(parse_address()?),
+        OwnerType::Shared => Owner::Shared {
+            initial_shared_version: SequenceNumber::from_u64(
+                initial_shared_version.unwrap_or_default() as u64,
+            ),
+        },
+        OwnerType::Immutable => Owner::Immutable,
+    })
+}
@@ -5,10 +5,12 @@ use crate::errors::IndexerError;
 use crate::schema::packages;
 
 use diesel::prelude::*;
+use std::collections::BTreeMap;
 
 use crate::models::objects::NamedBcsBytes;
-use sui_json_rpc_types::SuiRawMovePackage;
+use sui_json_rpc_types::{SuiMoveNormalizedModule, SuiRawMovePackage};
 use sui_types::base_types::SuiAddress;
+use sui_types::move_package::normalize_modules;
 
 #[derive(Queryable, Insertable, Debug, Identifiable)]
 #[diesel(table_name = packages, primary_key(package_id, version))]
@@ -17,10 +19,28 @@ pub struct Package {
     pub version: i64,
     pub author: String,
     pub data: Vec<NamedBcsBytes>,
+    // Normalized interface of the modules, map from module name to SuiMoveNormalizedModule
+    pub module_abi: Option<serde_json::Value>,
 }
 
 impl Package {
     pub fn try_from(sender: SuiAddress, package: &SuiRawMovePackage) -> Result<Self, IndexerError> {
+        let module_abi = normalize_modules(package.module_map.values())
+            .map_err(|e| {
+                IndexerError::InsertableParsingError(format!(
+                    "Failed normalizing modules of package {} with error: {:?}",
+                    package.id, e
+                ))
+            })?
+            .into_iter()
+            .map(|(name, module)| (name, SuiMoveNormalizedModule::from(module)))
+            .collect::<BTreeMap<_, _>>();
+        let module_abi = serde_json::to_value(module_abi).map_err(|e| {
+            IndexerError::InsertableParsingError(format!(
+                "Failed converting ABI of package {} to JSON with error: {:?}",
+                package.id, e
+            ))
+        })?;
         Ok(Self {
             package_id: package.id.to_string(),
             version: package.version.value() as i64,
@@ -31,6 +51,24 @@ impl Package {
                 .into_iter()
                 .map(|(k, v)| NamedBcsBytes(k, v))
                 .collect(),
+            module_abi: Some(module_abi),
         })
     }
+
+    /// Normalized modules of the package by module name, None if the package was indexed before
+    /// module ABIs were stored.
+    pub fn module_abi(
+        &self,
+    ) -> Result<Option<BTreeMap<String, SuiMoveNormalizedModule>>, IndexerError> {
+        self.module_abi
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed deserializing ABI of package {} with error: {:?}",
+                    self.package_id, e
+                ))
+            })
+    }
 }
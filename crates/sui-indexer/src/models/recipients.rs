@@ -1,8 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::str::FromStr;
+
+use crate::errors::IndexerError;
 use crate::schema::recipients;
 use diesel::prelude::*;
+use sui_json_rpc_types::IncomingPayment;
+use sui_types::base_types::TransactionDigest;
 
 #[derive(Queryable, Insertable, Debug, Clone, Default)]
 pub struct Recipient {
@@ -11,4 +16,34 @@ pub struct Recipient {
     pub checkpoint_sequence_number: i64,
     pub epoch: i64,
     pub recipient: String,
+    // Coin type and amount received in the transaction, None if no coin was received
+    pub coin_type: Option<String>,
+    pub amount: Option<i64>,
+    pub transaction_index: i64,
+}
+
+impl TryFrom<Recipient> for IncomingPayment {
+    type Error = IndexerError;
+
+    fn try_from(recipient: Recipient) -> Result<Self, Self::Error> {
+        let (Some(coin_type), Some(amount)) = (recipient.coin_type, recipient.amount) else {
+            return Err(IndexerError::DataInconsistency(format!(
+                "Recipient of transaction {} did not receive any coin",
+                recipient.transaction_digest
+            )));
+        };
+        let transaction_digest = TransactionDigest::from_str(&recipient.transaction_digest)
+            .map_err(|e| {
+                IndexerError::JsonSerdeError(format!(
+                    "Failed to parse transaction digest {} with err: {:?}",
+                    recipient.transaction_digest, e
+                ))
+            })?;
+        Ok(Self {
+            transaction_digest,
+            checkpoint: recipient.checkpoint_sequence_number as u64,
+            coin_type,
+            amount: amount as u64,
+        })
+    }
 }
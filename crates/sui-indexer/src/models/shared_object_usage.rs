@@ -0,0 +1,57 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::schema::shared_object_usage;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, VarChar};
+use sui_json_rpc_types::HotSharedObject;
+use sui_types::base_types::ObjectID;
+
+/// Number of transactions in a checkpoint that used a shared object.
+#[derive(Queryable, Insertable, Debug, Clone, Default)]
+#[diesel(table_name = shared_object_usage)]
+pub struct SharedObjectUsage {
+    #[diesel(deserialize_as = i64)]
+    pub id: Option<i64>,
+    pub object_id: String,
+    pub checkpoint_sequence_number: i64,
+    pub epoch: i64,
+    pub timestamp_ms: i64,
+    pub transaction_count: i64,
+    // Time between the previous checkpoint and this one. Checkpoints are cut from consensus
+    // commits, so this approximates how long the transactions waited for consensus.
+    pub consensus_latency_ms: i64,
+}
+
+/// Usage of a shared object aggregated over a time window.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct SharedObjectStats {
+    #[diesel(sql_type = VarChar)]
+    pub object_id: String,
+    #[diesel(sql_type = BigInt)]
+    pub transaction_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub avg_consensus_latency_ms: i64,
+    #[diesel(sql_type = BigInt)]
+    pub max_consensus_latency_ms: i64,
+}
+
+impl TryFrom<SharedObjectStats> for HotSharedObject {
+    type Error = IndexerError;
+
+    fn try_from(stats: SharedObjectStats) -> Result<Self, Self::Error> {
+        let object_id = stats.object_id.parse::<ObjectID>().map_err(|e| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to decode object id: {:?} with err: {:?}",
+                stats.object_id, e
+            ))
+        })?;
+        Ok(HotSharedObject {
+            object_id,
+            transaction_count: stats.transaction_count as u64,
+            avg_consensus_latency_ms: stats.avg_consensus_latency_ms as u64,
+            max_consensus_latency_ms: stats.max_consensus_latency_ms as u64,
+        })
+    }
+}
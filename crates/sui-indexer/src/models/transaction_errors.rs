@@ -0,0 +1,141 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::schema::transaction_errors;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Nullable, Text, VarChar};
+use sui_json_rpc_types::{
+    FailingFunction, SuiExecutionStatus, SuiTransactionResponse, TransactionErrorStats,
+};
+use sui_types::base_types::ObjectID;
+
+/// Execution failure of a transaction.
+#[derive(Queryable, Insertable, Debug, Clone, Default)]
+#[diesel(table_name = transaction_errors)]
+pub struct TransactionError {
+    #[diesel(deserialize_as = i64)]
+    pub id: Option<i64>,
+    pub transaction_digest: String,
+    pub checkpoint_sequence_number: i64,
+    pub epoch: i64,
+    pub timestamp_ms: i64,
+    pub sender: String,
+    pub error_kind: String,
+    pub command_index: Option<i64>,
+    // Move function executing when the failure occurred, None for failures outside of Move code
+    pub package: Option<String>,
+    pub module: Option<String>,
+    pub function: Option<String>,
+    // Abort codes are u64, stored with the same bits so that they round-trip
+    pub abort_code: Option<i64>,
+}
+
+impl TransactionError {
+    /// The execution failure of `tx`, None if it succeeded or the fullnode did not report the
+    /// details of the failure.
+    pub fn from_response(
+        tx: &SuiTransactionResponse,
+        epoch: u64,
+        checkpoint: u64,
+        timestamp_ms: u64,
+    ) -> Option<Self> {
+        let effects = tx.effects.as_ref().expect("Effects should not be empty");
+        let SuiExecutionStatus::Failure { details: Some(details), .. } = effects.status() else {
+            return None;
+        };
+        let sender = tx
+            .transaction
+            .as_ref()
+            .expect("Transaction should not be empty")
+            .data
+            .sender();
+        let location = details.location.as_ref();
+        Some(Self {
+            id: None,
+            transaction_digest: tx.digest.to_string(),
+            checkpoint_sequence_number: checkpoint as i64,
+            epoch: epoch as i64,
+            timestamp_ms: timestamp_ms as i64,
+            sender: sender.to_string(),
+            error_kind: details.kind.clone(),
+            command_index: details.command.map(|idx| idx as i64),
+            package: location.map(|l| l.package.to_string()),
+            module: location.map(|l| l.module.clone()),
+            function: location.and_then(|l| l.function.clone()),
+            abort_code: details.abort_code.map(|code| code as i64),
+        })
+    }
+}
+
+/// Failures of a Move function with an abort code, aggregated over a time window.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct FailingFunctionStats {
+    #[diesel(sql_type = VarChar)]
+    pub package: String,
+    #[diesel(sql_type = Text)]
+    pub module: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub function: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub abort_code: Option<i64>,
+    #[diesel(sql_type = BigInt)]
+    pub failure_count: i64,
+}
+
+/// Failures of the functions of `stats`, which is ordered by function.
+pub fn failing_functions(
+    stats: Vec<FailingFunctionStats>,
+) -> Result<Vec<FailingFunction>, IndexerError> {
+    let mut functions: Vec<FailingFunction> = vec![];
+    for stat in stats {
+        let package = stat.package.parse::<ObjectID>().map_err(|e| {
+            IndexerError::JsonSerdeError(format!(
+                "Failed to decode package id: {:?} with err: {:?}",
+                stat.package, e
+            ))
+        })?;
+        let function = match functions.last_mut() {
+            Some(f)
+                if f.package == package
+                    && f.module == stat.module
+                    && f.function == stat.function =>
+            {
+                f
+            }
+            _ => {
+                functions.push(FailingFunction {
+                    package,
+                    module: stat.module,
+                    function: stat.function,
+                    failure_count: 0,
+                    abort_codes: Default::default(),
+                });
+                functions.last_mut().unwrap()
+            }
+        };
+        function.failure_count += stat.failure_count as u64;
+        if let Some(code) = stat.abort_code {
+            *function.abort_codes.entry(code as u64).or_default() += stat.failure_count as u64;
+        }
+    }
+    Ok(functions)
+}
+
+/// Number of failures with an error kind over a time window.
+#[derive(QueryableByName, Debug, Clone)]
+pub struct ErrorKindStats {
+    #[diesel(sql_type = VarChar)]
+    pub error_kind: String,
+    #[diesel(sql_type = BigInt)]
+    pub failure_count: i64,
+}
+
+impl From<ErrorKindStats> for TransactionErrorStats {
+    fn from(stats: ErrorKindStats) -> Self {
+        Self {
+            kind: stats.error_kind,
+            failure_count: stats.failure_count as u64,
+        }
+    }
+}
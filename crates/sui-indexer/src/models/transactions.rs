@@ -45,6 +45,55 @@ pub struct Transaction {
     pub transaction_content: String,
     pub transaction_effects_content: String,
     pub confirmed_local_execution: Option<bool>,
+    /// Index of the transaction in its checkpoint.
+    pub transaction_index: i64,
+}
+
+/// The columns of a [Transaction] without its content and effects, which are read when no field
+/// of the content or the effects is requested.
+#[derive(Clone, Debug, Queryable)]
+pub struct TransactionSummary {
+    pub id: i64,
+    pub transaction_digest: String,
+    pub sender: String,
+    pub recipients: Vec<Option<String>>,
+    pub checkpoint_sequence_number: i64,
+    pub transaction_time: Option<NaiveDateTime>,
+    pub transaction_kinds: Vec<Option<String>>,
+    pub move_calls: Vec<Option<String>>,
+    pub gas_object_id: String,
+    pub gas_object_sequence: i64,
+    pub gas_object_digest: String,
+    pub gas_budget: i64,
+    pub total_gas_cost: i64,
+    pub computation_cost: i64,
+    pub storage_cost: i64,
+    pub storage_rebate: i64,
+    pub gas_price: i64,
+}
+
+impl From<&Transaction> for TransactionSummary {
+    fn from(tx: &Transaction) -> Self {
+        Self {
+            id: tx.id.unwrap_or_default(),
+            transaction_digest: tx.transaction_digest.clone(),
+            sender: tx.sender.clone(),
+            recipients: tx.recipients.clone(),
+            checkpoint_sequence_number: tx.checkpoint_sequence_number,
+            transaction_time: tx.transaction_time,
+            transaction_kinds: tx.transaction_kinds.clone(),
+            move_calls: tx.move_calls.clone(),
+            gas_object_id: tx.gas_object_id.clone(),
+            gas_object_sequence: tx.gas_object_sequence,
+            gas_object_digest: tx.gas_object_digest.clone(),
+            gas_budget: tx.gas_budget,
+            total_gas_cost: tx.total_gas_cost,
+            computation_cost: tx.computation_cost,
+            storage_cost: tx.storage_cost,
+            storage_rebate: tx.storage_rebate,
+            gas_price: tx.gas_price,
+        }
+    }
 }
 
 pub fn commit_transactions(
@@ -70,12 +119,8 @@ pub fn commit_transactions(
             .execute(conn)
     });
 
-    txn_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing transactions to PostgresDB with transactions {:?} and error: {:?}",
-            new_txns, e
-        ))
-    })
+    txn_commit_result
+        .map_err(|e| IndexerError::db(format!("writing {} transactions", new_txns.len()), e))
 }
 
 impl TryFrom<SuiTransactionResponse> for Transaction {
@@ -214,6 +259,9 @@ impl TryFrom<SuiTransactionResponse> for Transaction {
             transaction_content: txn_json,
             transaction_effects_content: txn_effect_json,
             confirmed_local_execution: tx_resp.confirmed_local_execution,
+            // The response doesn't tell the position of the transaction in its checkpoint, which
+            // is set by the checkpoint handler.
+            transaction_index: 0,
         })
     }
 }
@@ -250,8 +298,12 @@ impl TryInto<SuiTransactionResponse> for Transaction {
                 .transaction_time
                 .map(|time| time.timestamp_millis() as u64),
             checkpoint: Some(self.checkpoint_sequence_number as u64),
+            from_archive: None,
             // TODO: Indexer need to persist event properly.
             events: Default::default(),
+            raw_effects: vec![],
+            gas_owner_balance_change: None,
+            input_objects: None,
             errors: vec![],
         })
     }
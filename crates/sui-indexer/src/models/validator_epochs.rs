@@ -0,0 +1,129 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::schema::validator_epochs;
+use diesel::prelude::*;
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
+use serde::Deserialize;
+use sui_json_rpc_types::SuiEvent;
+use sui_types::base_types::SuiAddress;
+use sui_types::{parse_sui_struct_tag, SUI_FRAMEWORK_ADDRESS};
+
+const VALIDATOR_SET_MODULE_NAME: &IdentStr = ident_str!("validator_set");
+const VALIDATOR_EPOCH_INFO_STRUCT_NAME: &IdentStr = ident_str!("ValidatorEpochInfo");
+
+/// Epochs last a day, pool rewards are compounded over this many epochs in a year.
+const EPOCHS_PER_YEAR: f64 = 365.0;
+
+/// Metrics of a validator for an epoch, recorded from the `ValidatorEpochInfo` event emitted for
+/// every active validator by the epoch change transaction.
+// TODO: record checkpoint signing participation once certified checkpoints are served by the
+// fullnode, the checkpoints read from the RPC do not carry their signatures.
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = validator_epochs)]
+pub struct ValidatorEpoch {
+    pub epoch: i64,
+    pub validator_address: String,
+    pub reference_gas_survey_quote: i64,
+    pub stake: i64,
+    pub commission_rate: i64,
+    pub stake_rewards: i64,
+    // Exchange rate of the staking pool at the end of the epoch.
+    pub pool_sui_amount: i64,
+    pub pool_token_amount: i64,
+    // Validators that reported this validator with the tallying rule.
+    pub tallying_rule_reporters: Vec<Option<String>>,
+    pub tallying_rule_global_score: i64,
+}
+
+/// Estimated APY of a validator, see [estimate_apy].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatorApy {
+    pub validator_address: String,
+    pub apy: f64,
+}
+
+/// Rust version of the Move sui::validator_set::ValidatorEpochInfo event.
+#[derive(Deserialize)]
+struct ValidatorEpochInfo {
+    epoch: u64,
+    validator_address: SuiAddress,
+    reference_gas_survey_quote: u64,
+    stake: u64,
+    commission_rate: u64,
+    stake_rewards: u64,
+    pool_token_exchange_rate: PoolTokenExchangeRate,
+    tallying_rule_reporters: Vec<SuiAddress>,
+    tallying_rule_global_score: u64,
+}
+
+#[derive(Deserialize)]
+struct PoolTokenExchangeRate {
+    sui_amount: u64,
+    pool_token_amount: u64,
+}
+
+impl ValidatorEpoch {
+    /// Parse the validator metrics from `event`, returns None if it is not a
+    /// `ValidatorEpochInfo` event.
+    pub fn try_from_event(event: &SuiEvent) -> Result<Option<Self>, IndexerError> {
+        let SuiEvent::MoveEvent { type_, bcs, .. } = event else {
+            return Ok(None);
+        };
+        let is_validator_epoch_info = parse_sui_struct_tag(type_).map_or(false, |tag| {
+            tag.address == SUI_FRAMEWORK_ADDRESS
+                && tag.module.as_ident_str() == VALIDATOR_SET_MODULE_NAME
+                && tag.name.as_ident_str() == VALIDATOR_EPOCH_INFO_STRUCT_NAME
+        });
+        if !is_validator_epoch_info {
+            return Ok(None);
+        }
+        let info: ValidatorEpochInfo = bcs::from_bytes(bcs).map_err(|e| {
+            IndexerError::InsertableParsingError(format!(
+                "Failed to parse validator epoch info event {:?} with error: {:?}",
+                event, e
+            ))
+        })?;
+        Ok(Some(ValidatorEpoch {
+            epoch: info.epoch as i64,
+            validator_address: info.validator_address.to_string(),
+            reference_gas_survey_quote: info.reference_gas_survey_quote as i64,
+            stake: info.stake as i64,
+            commission_rate: info.commission_rate as i64,
+            stake_rewards: info.stake_rewards as i64,
+            pool_sui_amount: info.pool_token_exchange_rate.sui_amount as i64,
+            pool_token_amount: info.pool_token_exchange_rate.pool_token_amount as i64,
+            tallying_rule_reporters: info
+                .tallying_rule_reporters
+                .iter()
+                .map(|reporter| Some(reporter.to_string()))
+                .collect(),
+            tallying_rule_global_score: info.tallying_rule_global_score as i64,
+        }))
+    }
+
+    /// SUI per pool token at the end of the epoch, pools start at a 1:1 exchange rate.
+    pub fn exchange_rate(&self) -> f64 {
+        if self.pool_token_amount == 0 {
+            1.0
+        } else {
+            self.pool_sui_amount as f64 / self.pool_token_amount as f64
+        }
+    }
+}
+
+/// Estimate the APY of a validator from its records, by annualizing the growth of its pool's
+/// exchange rate between the oldest and the newest record. Returns None with less than two
+/// epochs of records.
+pub fn estimate_apy(validator_epochs: &[ValidatorEpoch]) -> Option<f64> {
+    let oldest = validator_epochs.iter().min_by_key(|v| v.epoch)?;
+    let newest = validator_epochs.iter().max_by_key(|v| v.epoch)?;
+    if newest.epoch == oldest.epoch {
+        return None;
+    }
+    let growth = newest.exchange_rate() / oldest.exchange_rate();
+    let epochs = (newest.epoch - oldest.epoch) as f64;
+    Some(growth.powf(EPOCHS_PER_YEAR / epochs) - 1.0)
+}
@@ -16,6 +16,15 @@ pub mod sql_types {
     pub struct OwnerType;
 }
 
+diesel::table! {
+    address_activity (address, epoch) {
+        address -> Varchar,
+        epoch -> Int8,
+        tx_count -> Int8,
+        gas_spent -> Int8,
+    }
+}
+
 diesel::table! {
     addresses (account_address) {
         account_address -> Varchar,
@@ -47,6 +56,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    dead_letter_checkpoints (sequence_number) {
+        sequence_number -> Int8,
+        error_type -> Varchar,
+        error -> Text,
+        attempts -> Int8,
+        first_failed_at -> Timestamp,
+        last_failed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    dynamic_fields (object_id) {
+        object_id -> Varchar,
+        epoch -> Int8,
+        checkpoint -> Int8,
+        version -> Int8,
+        object_digest -> Varchar,
+        parent_object_id -> Varchar,
+        name_type -> Text,
+        name_value -> Jsonb,
+        field_type -> Text,
+        value_type -> Text,
+        value_object_id -> Varchar,
+    }
+}
+
 diesel::table! {
     error_logs (id) {
         id -> Int8,
@@ -57,13 +93,20 @@ diesel::table! {
 }
 
 diesel::table! {
-    events (id) {
+    events (id, checkpoint) {
         id -> Int8,
         transaction_digest -> Varchar,
         event_sequence -> Int8,
         event_time -> Nullable<Timestamp>,
         event_type -> Varchar,
         event_content -> Varchar,
+        event_json -> Nullable<Jsonb>,
+        package_id -> Nullable<Varchar>,
+        transaction_module -> Nullable<Text>,
+        move_event_type -> Nullable<Text>,
+        sender -> Nullable<Varchar>,
+        checkpoint -> Int8,
+        transaction_index -> Int8,
     }
 }
 
@@ -77,6 +120,14 @@ diesel::table! {
         move_package -> Text,
         move_module -> Text,
         move_function -> Text,
+        transaction_index -> Int8,
+    }
+}
+
+diesel::table! {
+    object_type_counts (object_type) {
+        object_type -> Varchar,
+        live_count -> Int8,
     }
 }
 
@@ -102,6 +153,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::OwnerType;
+    use super::sql_types::ObjectStatus;
+
+    objects_delta (checkpoint, object_id) {
+        checkpoint -> Int8,
+        object_id -> Varchar,
+        version -> Int8,
+        object_digest -> Varchar,
+        owner_type -> OwnerType,
+        owner_address -> Nullable<Varchar>,
+        initial_shared_version -> Nullable<Int8>,
+        previous_transaction -> Varchar,
+        object_type -> Varchar,
+        object_status -> ObjectStatus,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::OwnerType;
@@ -124,6 +194,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    objects_snapshot (checkpoint, object_id) {
+        checkpoint -> Int8,
+        object_id -> Varchar,
+        version -> Int8,
+        object_digest -> Varchar,
+        owner_address -> Varchar,
+        previous_transaction -> Varchar,
+        object_type -> Varchar,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::OwnerType;
@@ -169,6 +251,7 @@ diesel::table! {
         version -> Int8,
         author -> Varchar,
         data -> Array<Nullable<BcsBytes>>,
+        module_abi -> Nullable<Jsonb>,
     }
 }
 
@@ -179,6 +262,45 @@ diesel::table! {
         checkpoint_sequence_number -> Int8,
         epoch -> Int8,
         recipient -> Varchar,
+        coin_type -> Nullable<Text>,
+        amount -> Nullable<Int8>,
+        transaction_index -> Int8,
+    }
+}
+
+diesel::table! {
+    shared_object_usage (id) {
+        id -> Int8,
+        object_id -> Varchar,
+        checkpoint_sequence_number -> Int8,
+        epoch -> Int8,
+        timestamp_ms -> Int8,
+        transaction_count -> Int8,
+        consensus_latency_ms -> Int8,
+    }
+}
+
+diesel::table! {
+    sink_watermarks (sink_name) {
+        sink_name -> Varchar,
+        checkpoint_sequence_number -> Int8,
+    }
+}
+
+diesel::table! {
+    transaction_errors (id) {
+        id -> Int8,
+        transaction_digest -> Varchar,
+        checkpoint_sequence_number -> Int8,
+        epoch -> Int8,
+        timestamp_ms -> Int8,
+        sender -> Varchar,
+        error_kind -> Varchar,
+        command_index -> Nullable<Int8>,
+        package -> Nullable<Varchar>,
+        module -> Nullable<Text>,
+        function -> Nullable<Text>,
+        abort_code -> Nullable<Int8>,
     }
 }
 
@@ -209,20 +331,46 @@ diesel::table! {
         transaction_content -> Text,
         transaction_effects_content -> Text,
         confirmed_local_execution -> Nullable<Bool>,
+        transaction_index -> Int8,
+    }
+}
+
+diesel::table! {
+    validator_epochs (epoch, validator_address) {
+        epoch -> Int8,
+        validator_address -> Varchar,
+        reference_gas_survey_quote -> Int8,
+        stake -> Int8,
+        commission_rate -> Int8,
+        stake_rewards -> Int8,
+        pool_sui_amount -> Int8,
+        pool_token_amount -> Int8,
+        tallying_rule_reporters -> Array<Nullable<Text>>,
+        tallying_rule_global_score -> Int8,
     }
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    address_activity,
     addresses,
     checkpoints,
+    dead_letter_checkpoints,
+    dynamic_fields,
     error_logs,
     events,
     move_calls,
+    object_type_counts,
     objects,
+    objects_delta,
     objects_history,
+    objects_snapshot,
     owner,
     owner_history,
     packages,
     recipients,
+    shared_object_usage,
+    sink_watermarks,
+    transaction_errors,
     transactions,
+    validator_epochs,
 );
@@ -0,0 +1,69 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::ClientConfig;
+
+use crate::errors::IndexerError;
+use crate::sink::{DataSink, SinkRecord};
+
+const QUEUE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Publishes to the Kafka topics `<prefix>.<topic>`, with idempotent delivery so that retried
+/// sends keep the order of the records within their partitions.
+pub struct KafkaSink {
+    name: String,
+    prefix: String,
+    producer: FutureProducer,
+}
+
+impl KafkaSink {
+    pub fn new(brokers: &str, prefix: &str) -> Result<Self, IndexerError> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", brokers)
+            .set("enable.idempotence", "true")
+            .set("acks", "all")
+            .create()
+            .map_err(|e| IndexerError::SinkError(format!("Failed creating Kafka producer: {e}")))?;
+        Ok(Self {
+            name: format!("kafka:{prefix}"),
+            prefix: prefix.to_string(),
+            producer,
+        })
+    }
+}
+
+#[async_trait]
+impl DataSink for KafkaSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn publish(&self, records: &[SinkRecord]) -> Result<(), IndexerError> {
+        let topics = records
+            .iter()
+            .map(|record| format!("{}.{}", self.prefix, record.topic))
+            .collect::<Vec<_>>();
+        // The sends are first polled in order, which enqueues the records in order, and are then
+        // acknowledged concurrently.
+        try_join_all(records.iter().zip(&topics).map(|(record, topic)| {
+            let record = FutureRecord::to(topic)
+                .key(&record.key)
+                .payload(&record.payload);
+            async move {
+                self.producer
+                    .send(record, QUEUE_TIMEOUT)
+                    .await
+                    .map_err(|(e, _)| {
+                        IndexerError::SinkError(format!("Failed publishing to {topic}: {e}"))
+                    })
+            }
+        }))
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Publication of indexed checkpoints, transactions and events to message brokers, so that
+//! downstream services can consume chain data without polling the DB.
+//!
+//! Each checkpoint is published as one record to `<prefix>.checkpoints`, each of its transactions
+//! to `<prefix>.transactions` and each of its events to `<prefix>.events`, with the JSON of the
+//! checkpoint, transaction or event of the RPC API as payload. Records are keyed by checkpoint
+//! sequence number, transaction digest and `<transaction digest>:<event sequence>` respectively.
+//!
+//! Delivery is at least once: the last checkpoint published to a sink is recorded in the DB as its
+//! watermark once the broker acknowledged all the records up to it, and the sink handler resumes
+//! after the watermark when restarted. The records of the batch that was in flight when the
+//! handler stopped are published again, consumers deduplicate them by key.
+
+use async_trait::async_trait;
+use serde_json::json;
+use sui_json_rpc_types::{Checkpoint as RpcCheckpoint, SuiTransactionResponse};
+
+use crate::errors::IndexerError;
+use crate::store::CheckpointRangeData;
+
+#[cfg(feature = "kafka")]
+mod kafka;
+#[cfg(feature = "nats")]
+mod nats;
+
+pub const CHECKPOINTS_TOPIC: &str = "checkpoints";
+pub const TRANSACTIONS_TOPIC: &str = "transactions";
+pub const EVENTS_TOPIC: &str = "events";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SinkRecord {
+    /// One of [CHECKPOINTS_TOPIC], [TRANSACTIONS_TOPIC] or [EVENTS_TOPIC], appended to the prefix
+    /// of the sink.
+    pub topic: &'static str,
+    pub key: String,
+    pub payload: Vec<u8>,
+}
+
+#[async_trait]
+pub trait DataSink: Send + Sync {
+    /// Identifies the sink in the watermarks table, stable across restarts.
+    fn name(&self) -> &str;
+
+    /// Publish `records` in order, returning once the broker acknowledged all of them.
+    async fn publish(&self, records: &[SinkRecord]) -> Result<(), IndexerError>;
+}
+
+/// Connect to the sink at `url`, one of `kafka://<broker>[,<broker>...]/<prefix>` or
+/// `nats://<host>:<port>/<prefix>`. Kafka topics and NATS JetStream streams are not created by
+/// the indexer. The sink is named `<scheme>:<prefix>`.
+pub async fn sink_from_url(url: &str) -> Result<Box<dyn DataSink>, IndexerError> {
+    let invalid_url = |e: &str| IndexerError::SinkError(format!("Invalid sink {url}: {e}"));
+    let (scheme, location) = url
+        .split_once("://")
+        .ok_or_else(|| invalid_url("missing scheme"))?;
+    let (servers, prefix) = location
+        .split_once('/')
+        .filter(|(servers, prefix)| !servers.is_empty() && !prefix.is_empty())
+        .ok_or_else(|| invalid_url("expected <servers>/<prefix>"))?;
+    match scheme {
+        #[cfg(feature = "kafka")]
+        "kafka" => Ok(Box::new(kafka::KafkaSink::new(servers, prefix)?)),
+        #[cfg(feature = "nats")]
+        "nats" => Ok(Box::new(nats::NatsSink::connect(servers, prefix).await?)),
+        _ => {
+            // The servers and prefix are unused when the indexer is built without any sink.
+            let _ = (servers, prefix);
+            Err(invalid_url(&format!(
+                "unsupported scheme {scheme}, or the indexer was built without the {scheme} feature"
+            )))
+        }
+    }
+}
+
+/// The records of the checkpoints of `data`, ordered by checkpoint, the checkpoint record coming
+/// after the records of its transactions and events so that consumers seeing a checkpoint have
+/// seen all of its content.
+pub fn checkpoint_records(data: &CheckpointRangeData) -> Result<Vec<SinkRecord>, IndexerError> {
+    let mut records = vec![];
+    let mut transactions = data.transactions.iter().peekable();
+    let mut events = data.events.iter().peekable();
+    for checkpoint in &data.checkpoints {
+        let sequence_number = checkpoint.sequence_number;
+        while let Some(transaction) =
+            transactions.next_if(|t| t.checkpoint_sequence_number == sequence_number)
+        {
+            let response: SuiTransactionResponse = transaction.clone().try_into()?;
+            records.push(SinkRecord {
+                topic: TRANSACTIONS_TOPIC,
+                key: transaction.transaction_digest.clone(),
+                payload: to_json(&response)?,
+            });
+        }
+        while let Some(event) = events.next_if(|e| e.checkpoint == sequence_number) {
+            let content = match &event.event_json {
+                Some(json) => json.clone(),
+                None => serde_json::from_str(&event.event_content).map_err(|e| {
+                    IndexerError::JsonSerdeError(format!(
+                        "Failed parsing event {} of transaction {}: {e}",
+                        event.event_sequence, event.transaction_digest
+                    ))
+                })?,
+            };
+            records.push(SinkRecord {
+                topic: EVENTS_TOPIC,
+                key: format!("{}:{}", event.transaction_digest, event.event_sequence),
+                payload: to_json(&json!({
+                    "transactionDigest": event.transaction_digest,
+                    "eventSequence": event.event_sequence,
+                    "checkpoint": event.checkpoint,
+                    "timestampMs": event.event_time.map(|t| t.timestamp_millis()),
+                    "event": content,
+                }))?,
+            });
+        }
+        let rpc_checkpoint: RpcCheckpoint = checkpoint.clone().try_into()?;
+        records.push(SinkRecord {
+            topic: CHECKPOINTS_TOPIC,
+            key: sequence_number.to_string(),
+            payload: to_json(&rpc_checkpoint)?,
+        });
+    }
+    Ok(records)
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, IndexerError> {
+    serde_json::to_vec(value).map_err(|e| IndexerError::JsonSerdeError(e.to_string()))
+}
@@ -0,0 +1,62 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_nats::jetstream;
+use async_nats::HeaderMap;
+use async_trait::async_trait;
+
+use crate::errors::IndexerError;
+use crate::sink::{DataSink, SinkRecord};
+
+/// Publishes to the NATS JetStream subjects `<prefix>.<topic>`, which must be captured by a
+/// stream. The key of each record is its message ID, so that the stream drops the records
+/// published again within its duplicate window.
+pub struct NatsSink {
+    name: String,
+    prefix: String,
+    context: jetstream::Context,
+}
+
+impl NatsSink {
+    pub async fn connect(server: &str, prefix: &str) -> Result<Self, IndexerError> {
+        let client = async_nats::connect(server).await.map_err(|e| {
+            IndexerError::SinkError(format!("Failed connecting to NATS at {server}: {e}"))
+        })?;
+        Ok(Self {
+            name: format!("nats:{prefix}"),
+            prefix: prefix.to_string(),
+            context: jetstream::new(client),
+        })
+    }
+}
+
+#[async_trait]
+impl DataSink for NatsSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn publish(&self, records: &[SinkRecord]) -> Result<(), IndexerError> {
+        let publish_error = |subject: &str, e: String| {
+            IndexerError::SinkError(format!("Failed publishing to {subject}: {e}"))
+        };
+        // Publish all the records before waiting for their acknowledgements.
+        let mut acks = vec![];
+        for record in records {
+            let subject = format!("{}.{}", self.prefix, record.topic);
+            let mut headers = HeaderMap::new();
+            headers.insert("Nats-Msg-Id", record.key.as_str());
+            let ack = self
+                .context
+                .publish_with_headers(subject.clone(), headers, record.payload.clone().into())
+                .await
+                .map_err(|e| publish_error(&subject, e.to_string()))?;
+            acks.push((subject, ack));
+        }
+        for (subject, ack) in acks {
+            ack.await
+                .map_err(|e| publish_error(&subject, e.to_string()))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::str::FromStr;
+
+use diesel::dsl::sql;
+use diesel::expression::SqlLiteral;
+use diesel::sql_types::Bool;
+use fastcrypto::encoding::{Base64, Encoding};
+
+use crate::errors::IndexerError;
+
+/// Position of a row in the chain: the checkpoint of its transaction, the index of the transaction
+/// in the checkpoint and the index of the row in the transaction, e.g. the sequence number of an
+/// event. Unlike the `id` of the rows, the position doesn't change when the tables are re-ingested
+/// or partitioned differently, so pages are read after positions rather than after ids.
+///
+/// Cursors are handed out to clients encoded as opaque strings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StoreCursor {
+    pub checkpoint: i64,
+    pub transaction_index: i64,
+    pub sub_index: i64,
+}
+
+impl StoreCursor {
+    /// Position of a transaction, or of a row standing for the whole transaction.
+    pub fn transaction(checkpoint: i64, transaction_index: i64) -> Self {
+        Self {
+            checkpoint,
+            transaction_index,
+            sub_index: 0,
+        }
+    }
+
+    /// The position right after `self`, to read the rows after a cursor excluded from the
+    /// methods of the store reading from a cursor included.
+    pub fn successor(self) -> Self {
+        Self {
+            sub_index: self.sub_index + 1,
+            ..self
+        }
+    }
+
+    /// The position right before `self`, see [Self::successor].
+    pub fn predecessor(self) -> Self {
+        Self {
+            sub_index: self.sub_index - 1,
+            ..self
+        }
+    }
+
+    /// SQL condition selecting the rows at the cursor or after it (before it if `is_descending`),
+    /// given the columns of their checkpoint, transaction index and, for rows of which
+    /// transactions have many, index in the transaction. The comparison of row values lets
+    /// PostgresDB use the indexes of the position columns.
+    pub(crate) fn filter(
+        &self,
+        checkpoint: &str,
+        transaction_index: &str,
+        sub_index: Option<&str>,
+        is_descending: bool,
+    ) -> SqlLiteral<Bool> {
+        sql(&self.condition(checkpoint, transaction_index, sub_index, is_descending))
+    }
+
+    /// The condition of [Self::filter], for queries written in SQL.
+    pub(crate) fn condition(
+        &self,
+        checkpoint: &str,
+        transaction_index: &str,
+        sub_index: Option<&str>,
+        is_descending: bool,
+    ) -> String {
+        let operator = if is_descending { "<=" } else { ">=" };
+        match sub_index {
+            Some(sub_index) => format!(
+                "({checkpoint}, {transaction_index}, {sub_index}) {operator} ({}, {}, {})",
+                self.checkpoint, self.transaction_index, self.sub_index
+            ),
+            None => format!(
+                "({checkpoint}, {transaction_index}) {operator} ({}, {})",
+                self.checkpoint, self.transaction_index
+            ),
+        }
+    }
+}
+
+impl fmt::Display for StoreCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = [self.checkpoint, self.transaction_index, self.sub_index]
+            .iter()
+            .flat_map(|n| n.to_be_bytes())
+            .collect::<Vec<_>>();
+        write!(f, "{}", Base64::encode(bytes))
+    }
+}
+
+impl FromStr for StoreCursor {
+    type Err = IndexerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IndexerError::InvalidCursor(s.to_string());
+        let bytes = Base64::decode(s).map_err(|_| invalid())?;
+        let bytes: [u8; 24] = bytes.try_into().map_err(|_| invalid())?;
+        let field = |i: usize| i64::from_be_bytes(bytes[8 * i..8 * (i + 1)].try_into().unwrap());
+        Ok(Self {
+            checkpoint: field(0),
+            transaction_index: field(1),
+            sub_index: field(2),
+        })
+    }
+}
@@ -2,33 +2,55 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::models::address_activity::{ActiveAddressCount, AddressActivity};
 use crate::models::addresses::Address;
 use crate::models::checkpoints::Checkpoint;
+use crate::models::dead_letter_checkpoints::DeadLetterCheckpoint;
+use crate::models::dynamic_fields::DynamicField;
 use crate::models::events::Event;
 use crate::models::move_calls::MoveCall;
-use crate::models::objects::{DeletedObject, Object, ObjectStatus};
+use crate::models::objects::{DeletedObject, Object, ObjectHistory, ObjectStatus, OwnedObject};
 use crate::models::owners::ObjectOwner;
 use crate::models::packages::Package;
 use crate::models::recipients::Recipient;
-use crate::models::transactions::Transaction;
+use crate::models::shared_object_usage::{SharedObjectStats, SharedObjectUsage};
+use crate::models::transaction_errors::{ErrorKindStats, FailingFunctionStats, TransactionError};
+use crate::models::transactions::{Transaction, TransactionSummary};
+use crate::models::validator_epochs::{ValidatorApy, ValidatorEpoch};
+use crate::store::{ReadSnapshot, StoreCursor};
 use async_trait::async_trait;
+use std::collections::BTreeMap;
 use sui_json_rpc_types::{
-    Checkpoint as RpcCheckpoint, CheckpointId, SuiObjectData, SuiTransactionResponse,
+    Checkpoint as RpcCheckpoint, CheckpointId, SuiMoveNormalizedModule, SuiObjectData,
+    SuiTransactionResponse,
 };
+use sui_types::committee::EpochId;
 
 #[async_trait]
 pub trait IndexerStore {
     fn get_latest_checkpoint_sequence_number(&self) -> Result<i64, IndexerError>;
+    /// Begin a snapshot of the store that the reads of a request can be pinned to, see
+    /// [ReadSnapshot].
+    fn begin_read_snapshot(&self) -> Result<ReadSnapshot, IndexerError>;
     fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError>;
+    /// Checkpoints with sequence numbers in `[start, end]` along with their transactions and
+    /// events, all read from the same snapshot of the store.
+    fn get_checkpoint_range_data(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> Result<CheckpointRangeData, IndexerError>;
 
     fn get_total_transaction_number(&self) -> Result<i64, IndexerError>;
 
     // TODO: combine all get_transaction* methods
     fn get_transaction_by_digest(&self, txn_digest: &str) -> Result<Transaction, IndexerError>;
 
+    /// Digests of the transactions ordered by position, from `cursor` included if any (down from
+    /// it if `is_descending`), see [StoreCursor].
     fn get_all_transaction_digest_page(
         &self,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError>;
@@ -36,7 +58,7 @@ pub trait IndexerStore {
     fn get_transaction_digest_page_by_mutated_object(
         &self,
         object_id: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError>;
@@ -44,7 +66,7 @@ pub trait IndexerStore {
     fn get_transaction_digest_page_by_sender_address(
         &self,
         sender_address: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError>;
@@ -52,7 +74,7 @@ pub trait IndexerStore {
     fn get_transaction_digest_page_by_recipient_address(
         &self,
         recipient_address: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError>;
@@ -62,28 +84,192 @@ pub trait IndexerStore {
         package: String,
         module: Option<String>,
         function: Option<String>,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError>;
 
-    fn get_transaction_sequence_by_digest(
+    /// Position of the transaction, to read the pages of transactions from it.
+    fn get_transaction_cursor(&self, txn_digest: &str) -> Result<StoreCursor, IndexerError>;
+
+    /// Events ordered by position, the index of the event in its transaction being the sub-index
+    /// of the position, from `cursor` included if any (down from it if `is_descending`).
+    fn get_events_by_sender(
         &self,
-        txn_digest: Option<String>,
+        sender: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
         is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError>;
+    ) -> Result<Vec<Event>, IndexerError>;
 
-    fn get_move_call_sequence_by_digest(
+    fn get_events_by_package(
         &self,
-        txn_digest: Option<String>,
+        package: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
         is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError>;
+    ) -> Result<Vec<Event>, IndexerError>;
 
-    fn get_recipient_sequence_by_digest(
+    fn get_events_by_module(
         &self,
-        txn_digest: Option<String>,
+        package: String,
+        module: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
         is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError>;
+    ) -> Result<Vec<Event>, IndexerError>;
+
+    fn get_events_by_move_event_type(
+        &self,
+        move_event_type: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError>;
+
+    /// Dynamic fields of the parent object ordered by field object id, starting from `cursor`.
+    /// Dynamic object fields are returned along with their child object.
+    fn get_dynamic_fields(
+        &self,
+        parent_object_id: String,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<(DynamicField, Option<Object>)>, IndexerError>;
+
+    fn get_dynamic_field(
+        &self,
+        parent_object_id: String,
+        name_type: String,
+        name_value: serde_json::Value,
+    ) -> Result<Option<DynamicField>, IndexerError>;
+
+    /// Versions of an object from version `start_version`, in ascending order.
+    fn get_object_history(
+        &self,
+        object_id: &str,
+        start_version: i64,
+        limit: usize,
+    ) -> Result<Vec<ObjectHistory>, IndexerError>;
+
+    /// Objects owned by `owner` at `checkpoint` ordered by object id, merged from the latest
+    /// objects snapshot at or before `checkpoint` and the delta log since then. None if there is
+    /// no such snapshot, e.g. because `checkpoint` is older than the retained snapshots.
+    fn get_owned_objects_at_checkpoint(
+        &self,
+        owner: &str,
+        checkpoint: i64,
+    ) -> Result<Option<Vec<OwnedObject>>, IndexerError>;
+
+    /// Coins received by `recipient`, newest first, from the row with id `start_id` if any.
+    fn get_incoming_payments(
+        &self,
+        recipient: &str,
+        start_id: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<Recipient>, IndexerError>;
+
+    /// Normalized modules of the latest indexed version of the package by module name, None if
+    /// the package or its ABI is not indexed.
+    fn get_package_abi(
+        &self,
+        package_id: &str,
+    ) -> Result<Option<BTreeMap<String, SuiMoveNormalizedModule>>, IndexerError>;
+
+    /// Shared objects used by the most transactions within `window_ms` of the latest indexed checkpoint.
+    fn get_hot_shared_objects(
+        &self,
+        window_ms: i64,
+        limit: usize,
+    ) -> Result<Vec<SharedObjectStats>, IndexerError>;
+
+    /// Move functions that failed in the most transactions within `window_ms` of the latest
+    /// indexed checkpoint, with their number of failures by abort code, ordered by function.
+    fn get_failing_functions(
+        &self,
+        window_ms: i64,
+        limit: usize,
+    ) -> Result<Vec<FailingFunctionStats>, IndexerError>;
+
+    /// Number of failed transactions by error kind within `window_ms` of the latest indexed
+    /// checkpoint, most frequent first.
+    fn get_transaction_error_stats(
+        &self,
+        window_ms: i64,
+    ) -> Result<Vec<ErrorKindStats>, IndexerError>;
+
+    /// Number of distinct addresses that sent transactions in epochs `start_epoch..=end_epoch`,
+    /// along with the number of transactions they sent and the gas they spent.
+    fn get_active_addresses(
+        &self,
+        start_epoch: i64,
+        end_epoch: i64,
+    ) -> Result<ActiveAddressCount, IndexerError>;
+
+    /// Activity of `address` in epochs `start_epoch..=end_epoch`, ordered by epoch.
+    fn get_address_activity(
+        &self,
+        address: &str,
+        start_epoch: i64,
+        end_epoch: i64,
+    ) -> Result<Vec<AddressActivity>, IndexerError>;
+
+    /// Positions, sequence numbers and digests of the transactions matching `filter`, ordered by
+    /// position, after `cursor` if any (before it if `is_descending`).
+    fn get_transaction_sequence_page(
+        &self,
+        filter: &TransactionPageFilter,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<(StoreCursor, i64, String)>, IndexerError>;
+
+    /// Transactions with the given sequence numbers, without their content and effects.
+    fn multi_get_transaction_summaries(
+        &self,
+        sequence_numbers: &[i64],
+    ) -> Result<Vec<TransactionSummary>, IndexerError>;
+
+    /// Transactions with the given sequence numbers.
+    fn multi_get_transactions(
+        &self,
+        sequence_numbers: &[i64],
+    ) -> Result<Vec<Transaction>, IndexerError>;
+
+    /// Checkpoints ordered by sequence number, after `cursor` if any (before it if
+    /// `is_descending`).
+    fn get_checkpoint_page(
+        &self,
+        cursor: Option<i64>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Checkpoint>, IndexerError>;
+
+    /// Events emitted by the transaction, in emission order.
+    fn get_events_by_transaction(&self, txn_digest: &str) -> Result<Vec<Event>, IndexerError>;
+
+    /// Latest indexed version of the object, including deleted and wrapped objects.
+    fn get_object(&self, object_id: &str) -> Result<Option<Object>, IndexerError>;
+
+    /// Live objects owned by the address `owner` ordered by object id, after `cursor` if any.
+    fn get_owned_objects_page(
+        &self,
+        owner: &str,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<Object>, IndexerError>;
+
+    /// Live objects of type `object_type` ordered by object id, after `cursor` if any.
+    fn get_objects_by_type_page(
+        &self,
+        object_type: &str,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<Object>, IndexerError>;
+
+    /// Number of live objects of type `object_type`.
+    fn count_objects_by_type(&self, object_type: &str) -> Result<i64, IndexerError>;
+
+    fn get_address(&self, address: &str) -> Result<Option<Address>, IndexerError>;
 
     fn read_transactions(
         &self,
@@ -91,8 +277,42 @@ pub trait IndexerStore {
         limit: usize,
     ) -> Result<Vec<Transaction>, IndexerError>;
 
+    /// Commit an indexed checkpoint. Re-committing a dead-lettered checkpoint is allowed, it
+    /// removes the checkpoint from the dead letters and leaves newer object versions untouched.
     fn persist_checkpoint(&self, data: &TemporaryCheckpointStore) -> Result<usize, IndexerError>;
+    /// Commit the checkpoint row of a checkpoint that could not be indexed and record it in the
+    /// dead letters, adding to the attempts of a checkpoint already dead-lettered.
+    fn persist_dead_letter_checkpoint(
+        &self,
+        checkpoint: &Checkpoint,
+        dead_letter: &DeadLetterCheckpoint,
+    ) -> Result<usize, IndexerError>;
+    fn get_dead_letter_checkpoints(&self) -> Result<Vec<DeadLetterCheckpoint>, IndexerError>;
+    /// Delete the rows of a checkpoint, its transactions and the rows indexed from them, so that
+    /// the checkpoint can be committed again with [IndexerStore::persist_checkpoint]. Objects,
+    /// dynamic fields, packages and addresses are kept, committing the checkpoint again only
+    /// writes their newer versions.
+    fn delete_checkpoint_data(&self, sequence_number: i64) -> Result<usize, IndexerError>;
     fn persist_epoch(&self, data: &TemporaryEpochStore) -> Result<usize, IndexerError>;
+    /// Commit validator epoch records, records already committed are left untouched.
+    fn persist_validator_epochs(
+        &self,
+        validator_epochs: &[ValidatorEpoch],
+    ) -> Result<usize, IndexerError>;
+
+    /// Records of a validator for its `limit` latest epochs, latest first.
+    fn get_validator_epochs(
+        &self,
+        validator_address: &str,
+        limit: usize,
+    ) -> Result<Vec<ValidatorEpoch>, IndexerError>;
+    /// Estimated APY of every validator over the last `epochs` recorded epochs.
+    fn get_validators_apy(&self, epochs: usize) -> Result<Vec<ValidatorApy>, IndexerError>;
+
+    /// Last checkpoint published to the external sink `sink_name`, `None` if it has not published
+    /// any checkpoint yet.
+    fn get_sink_watermark(&self, sink_name: &str) -> Result<Option<i64>, IndexerError>;
+    fn set_sink_watermark(&self, sink_name: &str, checkpoint: i64) -> Result<(), IndexerError>;
 
     fn log_errors(&self, errors: Vec<IndexerError>) -> Result<(), IndexerError>;
 }
@@ -101,6 +321,7 @@ pub struct CheckpointData {
     pub checkpoint: RpcCheckpoint,
     pub transactions: Vec<SuiTransactionResponse>,
     pub all_mutated_objects: Vec<(ObjectStatus, SuiObjectData)>,
+    pub dynamic_field_objects: Vec<SuiObjectData>,
 }
 
 // Per checkpoint indexing
@@ -113,6 +334,29 @@ pub struct TemporaryCheckpointStore {
     pub packages: Vec<Package>,
     pub move_calls: Vec<MoveCall>,
     pub recipients: Vec<Recipient>,
+    pub shared_object_usage: Vec<SharedObjectUsage>,
+    pub transaction_errors: Vec<TransactionError>,
+    pub address_activity: Vec<AddressActivity>,
+    pub dynamic_fields: Vec<DynamicField>,
+    pub deleted_dynamic_fields: Vec<String>,
+}
+
+// Indexed data of a range of checkpoints, ordered by checkpoint, transaction index and event
+// sequence
+pub struct CheckpointRangeData {
+    pub checkpoints: Vec<Checkpoint>,
+    pub transactions: Vec<Transaction>,
+    pub events: Vec<Event>,
+}
+
+/// Filter of [IndexerStore::get_transaction_sequence_page], the transactions matching all the set
+/// fields are returned.
+#[derive(Debug, Default, Clone)]
+pub struct TransactionPageFilter {
+    pub digest: Option<String>,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub checkpoint: Option<i64>,
 }
 
 pub struct TransactionObjectChanges {
@@ -122,5 +366,8 @@ pub struct TransactionObjectChanges {
 
 // Per epoch indexing
 pub struct TemporaryEpochStore {
+    // Epoch starting after the end-of-epoch checkpoint
+    pub new_epoch: EpochId,
     pub owner_index: Vec<ObjectOwner>,
+    pub validator_epochs: Vec<ValidatorEpoch>,
 }
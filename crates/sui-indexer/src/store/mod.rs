@@ -1,8 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub use cursor::StoreCursor;
 pub use indexer_store::*;
 pub use pg_indexer_store::PgIndexerStore;
+pub use read_snapshot::ReadSnapshot;
 
+mod cursor;
 mod indexer_store;
 mod pg_indexer_store;
+mod read_cache;
+mod read_snapshot;
@@ -2,30 +2,56 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::metrics::IndexerStoreMetrics;
+use crate::models::address_activity::{ActiveAddressCount, AddressActivity};
+use crate::models::addresses::Address;
 use crate::models::checkpoints::Checkpoint;
+use crate::models::dead_letter_checkpoints::DeadLetterCheckpoint;
+use crate::models::dynamic_fields::DynamicField;
 use crate::models::error_logs::commit_error_logs;
-use crate::models::transactions::Transaction;
+use crate::models::events::Event;
+use crate::models::objects::{Object, ObjectDelta, ObjectHistory, ObjectStatus, OwnedObject};
+use crate::models::owners::OwnerType;
+use crate::models::packages::Package;
+use crate::models::recipients::Recipient;
+use crate::models::shared_object_usage::SharedObjectStats;
+use crate::models::transaction_errors::{ErrorKindStats, FailingFunctionStats};
+use crate::models::transactions::{Transaction, TransactionSummary};
+use crate::models::validator_epochs::{estimate_apy, ValidatorApy, ValidatorEpoch};
 use crate::schema::addresses::account_address;
 use crate::schema::checkpoints::dsl::checkpoints as checkpoints_table;
 use crate::schema::checkpoints::{checkpoint_digest, sequence_number};
 use crate::schema::move_calls::dsl as move_calls_dsl;
 use crate::schema::recipients::dsl as recipients_dsl;
 use crate::schema::transactions::{dsl, transaction_digest};
-use crate::schema::{addresses, events, move_calls, objects, packages, recipients, transactions};
-use crate::store::indexer_store::TemporaryCheckpointStore;
-use crate::store::{IndexerStore, TemporaryEpochStore};
-use crate::{get_pg_pool_connection, PgConnectionPool};
+use crate::schema::{
+    address_activity, addresses, checkpoints, dead_letter_checkpoints, dynamic_fields, events,
+    move_calls, object_type_counts, objects, objects_delta, objects_snapshot, packages, recipients,
+    shared_object_usage, sink_watermarks, transaction_errors, transactions, validator_epochs,
+};
+use crate::store::indexer_store::{TemporaryCheckpointStore, TransactionObjectChanges};
+use crate::store::read_cache::ReadCache;
+use crate::store::read_snapshot::pinned_snapshot;
+use crate::store::{
+    CheckpointRangeData, IndexerStore, ReadSnapshot, StoreCursor, TemporaryEpochStore,
+    TransactionPageFilter,
+};
+use crate::{get_pg_pool_connection, PgConnectionPool, PgPoolConnection};
 use async_trait::async_trait;
 use diesel::dsl::{count, max};
-use diesel::sql_types::VarChar;
+use diesel::pg::PgConnection;
+use diesel::sql_types::{Array, BigInt, Jsonb, Nullable, Text, Timestamp, VarChar};
 use diesel::upsert::excluded;
 use diesel::QueryableByName;
-use diesel::{ExpressionMethods, PgArrayExpressionMethods};
-use diesel::{QueryDsl, RunQueryDsl};
+use diesel::{Connection, ExpressionMethods, PgArrayExpressionMethods};
+use diesel::{OptionalExtension, QueryDsl, RunQueryDsl};
+use prometheus::Registry;
 use std::collections::BTreeMap;
-use sui_json_rpc_types::CheckpointId;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use sui_json_rpc_types::{CheckpointId, SuiMoveNormalizedModule};
 use sui_types::committee::EpochId;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 const GET_PARTITION_SQL: &str = r#"
 SELECT parent.relname                           AS table_name,
@@ -39,231 +65,676 @@ WHERE parent.relkind = 'p'
 GROUP BY table_name;
 "#;
 
+// Highest partition number of table $1, for tables whose partitions are numbered.
+const GET_LAST_PARTITION_SQL: &str = r#"
+SELECT MAX(SUBSTRING(child.relname FROM '\d+$')::BIGINT) AS last_partition
+FROM pg_inherits
+         JOIN pg_class parent ON pg_inherits.inhparent = parent.oid
+         JOIN pg_class child ON pg_inherits.inhrelid = child.oid
+WHERE parent.relname = $1;
+"#;
+
+// Events of a checkpoint, bound as one array per column so that the statement does not grow with
+// the number of events.
+const INSERT_EVENTS_SQL: &str = r#"
+INSERT INTO events (transaction_digest, event_sequence, event_time, event_type, event_content, event_json,
+                    package_id, transaction_module, move_event_type, sender, checkpoint)
+SELECT *
+FROM UNNEST($1::VARCHAR[], $2::BIGINT[], $3::TIMESTAMP[], $4::VARCHAR[], $5::VARCHAR[], $6::JSONB[],
+            $7::VARCHAR[], $8::TEXT[], $9::TEXT[], $10::VARCHAR[], $11::BIGINT[]);
+"#;
+
+// Tables partitioned by ranges of checkpoints rather than by epoch, with the number of
+// checkpoints of each partition.
+const CHECKPOINT_PARTITIONED_TABLES: &[(&str, i64)] = &[("events", 100_000)];
+
+// Transaction count and transaction weighted consensus latency of shared objects,
+// over the window ending at the latest indexed checkpoint.
+const GET_HOT_SHARED_OBJECTS_SQL: &str = r#"
+SELECT object_id,
+       SUM(transaction_count)::BIGINT                                                     AS transaction_count,
+       (SUM(transaction_count * consensus_latency_ms) / SUM(transaction_count))::BIGINT AS avg_consensus_latency_ms,
+       MAX(consensus_latency_ms)                                                          AS max_consensus_latency_ms
+FROM shared_object_usage
+WHERE timestamp_ms >= (SELECT COALESCE(MAX(timestamp_ms), 0) FROM checkpoints) - $1
+GROUP BY object_id
+ORDER BY transaction_count DESC
+LIMIT $2;
+"#;
+
+// Failures of the Move functions that failed the most over the window ending at the latest
+// indexed checkpoint, by abort code.
+const GET_FAILING_FUNCTIONS_SQL: &str = r#"
+WITH failures AS (SELECT package, module, function, abort_code, COUNT(*) AS failure_count
+                  FROM transaction_errors
+                  WHERE package IS NOT NULL
+                    AND timestamp_ms >= (SELECT COALESCE(MAX(timestamp_ms), 0) FROM checkpoints) - $1
+                  GROUP BY package, module, function, abort_code),
+     top_functions AS (SELECT package, module, function, SUM(failure_count) AS failure_count
+                       FROM failures
+                       GROUP BY package, module, function
+                       ORDER BY failure_count DESC
+                       LIMIT $2)
+SELECT f.package, f.module, f.function, f.abort_code, f.failure_count
+FROM failures f
+         JOIN top_functions t
+              ON f.package = t.package AND f.module = t.module AND f.function IS NOT DISTINCT FROM t.function
+ORDER BY t.failure_count DESC, f.package, f.module, f.function, f.abort_code;
+"#;
+
+// Failed transactions by error kind over the window ending at the latest indexed checkpoint.
+const GET_TRANSACTION_ERROR_STATS_SQL: &str = r#"
+SELECT error_kind, COUNT(*) AS failure_count
+FROM transaction_errors
+WHERE timestamp_ms >= (SELECT COALESCE(MAX(timestamp_ms), 0) FROM checkpoints) - $1
+GROUP BY error_kind
+ORDER BY failure_count DESC;
+"#;
+
+// Distinct addresses that sent transactions in a range of epochs, with their totals.
+const GET_ACTIVE_ADDRESSES_SQL: &str = r#"
+SELECT COUNT(DISTINCT address)              AS active_addresses,
+       COALESCE(SUM(tx_count), 0)::BIGINT   AS tx_count,
+       COALESCE(SUM(gas_spent), 0)::BIGINT  AS gas_spent
+FROM address_activity
+WHERE epoch BETWEEN $1 AND $2;
+"#;
+
+// Takes the transactions of a checkpoint out of the activity of their senders, before the
+// checkpoint is deleted to be reindexed.
+const REVERT_ADDRESS_ACTIVITY_SQL: &str = r#"
+UPDATE address_activity a
+SET tx_count  = a.tx_count - t.tx_count,
+    gas_spent = a.gas_spent - t.gas_spent
+FROM (SELECT tx.sender, c.epoch, COUNT(*) AS tx_count, SUM(tx.total_gas_cost) AS gas_spent
+      FROM transactions tx
+               JOIN checkpoints c ON c.sequence_number = tx.checkpoint_sequence_number
+      WHERE tx.checkpoint_sequence_number = $1
+      GROUP BY tx.sender, c.epoch) t
+WHERE a.address = t.sender
+  AND a.epoch = t.epoch;
+"#;
+
+// Versions of an object along with the owner of their previous version, which is looked up
+// before paging so that the first version of a page has its previous owner.
+const GET_OBJECT_HISTORY_SQL: &str = r#"
+SELECT *
+FROM (SELECT checkpoint,
+             version,
+             object_digest,
+             owner_type,
+             owner_address,
+             initial_shared_version,
+             previous_transaction,
+             object_status,
+             LAG(owner_type) OVER (ORDER BY version)             AS previous_owner_type,
+             LAG(owner_address) OVER (ORDER BY version)          AS previous_owner_address,
+             LAG(initial_shared_version) OVER (ORDER BY version) AS previous_initial_shared_version,
+             LAG(object_status) OVER (ORDER BY version)          AS previous_object_status
+      FROM objects_history
+      WHERE object_id = $1) AS history
+WHERE version >= $2
+ORDER BY version
+LIMIT $3;
+"#;
+
+// Address-owned live objects as of checkpoint $1, which must be the latest committed checkpoint.
+const OBJECTS_SNAPSHOT_SQL: &str = r#"
+INSERT INTO objects_snapshot
+SELECT $1, object_id, version, object_digest, owner_address, previous_transaction, object_type
+FROM objects
+WHERE owner_type = 'address_owner'
+  AND object_status IN ('created', 'mutated', 'unwrapped')
+ON CONFLICT DO NOTHING;
+"#;
+
+// Objects owned by address $1 at checkpoint $3, from the snapshot at checkpoint $2 overridden by
+// the last change of each object in the delta log after the snapshot.
+const GET_OWNED_OBJECTS_AT_CHECKPOINT_SQL: &str = r#"
+WITH changes AS (SELECT DISTINCT ON (object_id) *
+                 FROM objects_delta
+                 WHERE checkpoint > $2
+                   AND checkpoint <= $3
+                   AND object_id IN (SELECT object_id
+                                     FROM objects_snapshot
+                                     WHERE checkpoint = $2 AND owner_address = $1
+                                     UNION
+                                     SELECT object_id
+                                     FROM objects_delta
+                                     WHERE checkpoint > $2 AND checkpoint <= $3 AND owner_address = $1)
+                 ORDER BY object_id, checkpoint DESC)
+SELECT object_id, version, object_digest, owner_address, previous_transaction, object_type
+FROM changes
+WHERE owner_type = 'address_owner'
+  AND owner_address = $1
+  AND object_status IN ('created', 'mutated', 'unwrapped')
+UNION ALL
+SELECT object_id, version, object_digest, owner_address, previous_transaction, object_type
+FROM objects_snapshot s
+WHERE checkpoint = $2
+  AND owner_address = $1
+  AND NOT EXISTS (SELECT 1 FROM changes c WHERE c.object_id = s.object_id)
+ORDER BY object_id;
+"#;
+
+const DEFAULT_OBJECTS_SNAPSHOT_INTERVAL: u64 = 1000;
+const DEFAULT_OBJECTS_SNAPSHOT_RETENTION: u64 = 24;
+
+// How often the replication lag of the read replica is checked.
+const REPLICA_LAG_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Clone)]
 pub struct PgIndexerStore {
     cp: PgConnectionPool,
+    read_replica: Option<ReadReplica>,
+    // Checkpoints between two objects snapshots, 0 if snapshots are disabled
+    objects_snapshot_interval: i64,
+    // Number of objects snapshots kept, along with the delta log since the oldest of them
+    objects_snapshot_retention: i64,
     partition_manager: PartitionManager,
+    read_cache: Option<ReadCache>,
+    metrics: IndexerStoreMetrics,
+}
+
+/// Pool of connections to a read replica of the primary database, used by the read snapshots
+/// of the RPC requests as long as the replica is at most `max_checkpoint_lag` checkpoints behind.
+#[derive(Clone)]
+struct ReadReplica {
+    cp: PgConnectionPool,
+    max_checkpoint_lag: i64,
+    // Time of the last lag check and whether the replica was fresh enough then
+    last_check: Arc<Mutex<Option<(Instant, bool)>>>,
 }
 
 impl PgIndexerStore {
-    pub fn new(cp: PgConnectionPool) -> Self {
+    pub fn new(cp: PgConnectionPool, registry: &Registry) -> Self {
+        let metrics = IndexerStoreMetrics::new(registry);
+        metrics.db_pool_max_size.set(cp.max_size() as i64);
         PgIndexerStore {
             cp: cp.clone(),
-            partition_manager: PartitionManager::new(cp).unwrap(),
+            read_replica: None,
+            objects_snapshot_interval: DEFAULT_OBJECTS_SNAPSHOT_INTERVAL as i64,
+            objects_snapshot_retention: DEFAULT_OBJECTS_SNAPSHOT_RETENTION as i64,
+            partition_manager: PartitionManager::new(cp, metrics.clone()).unwrap(),
+            read_cache: None,
+            metrics,
         }
     }
-}
 
-#[async_trait]
-impl IndexerStore for PgIndexerStore {
-    fn get_latest_checkpoint_sequence_number(&self) -> Result<i64, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
-                checkpoints_table
-                    .select(max(sequence_number))
-                    .first::<Option<i64>>(conn)
-                    // -1 to differentiate between no checkpoints and the first checkpoint
-                    .map(|o| o.unwrap_or(-1))
-            })
-            .map_err(|e| {
-                IndexerError::PostgresReadError(format!(
-                    "Failed reading latest checkpoint sequence number in PostgresDB with error {:?}",
-                    e
-                ))
-            })
+    /// Serve the reads of RPC requests from `read_cp`, a pool of connections to a read replica,
+    /// so that they don't compete with checkpoint ingestion for the connections of the primary.
+    /// Reads fall back to the primary while the replica is more than `max_checkpoint_lag`
+    /// checkpoints behind. The handlers always read from the primary.
+    pub fn with_read_replica(mut self, read_cp: PgConnectionPool, max_checkpoint_lag: u64) -> Self {
+        self.read_replica = Some(ReadReplica {
+            cp: read_cp,
+            max_checkpoint_lag: max_checkpoint_lag as i64,
+            last_check: Arc::new(Mutex::new(None)),
+        });
+        self
     }
 
-    fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| match id {
-                CheckpointId::SequenceNumber(seq) => checkpoints_table
-                    .filter(sequence_number.eq(seq as i64))
-                    .limit(1)
-                    .first::<Checkpoint>(conn),
-                CheckpointId::Digest(digest) => checkpoints_table
-                    .filter(checkpoint_digest.eq(digest.base58_encode()))
-                    .limit(1)
-                    .first::<Checkpoint>(conn),
-            })
-            .map_err(|e| {
-                IndexerError::PostgresReadError(format!(
-                    "Failed reading previous checkpoint in PostgresDB with error {:?}",
-                    e
-                ))
-            })
+    /// Snapshot the address-owned objects every `interval` checkpoints and keep the last
+    /// `retention` snapshots, so that the objects owned by an address can be read as of any
+    /// checkpoint since the oldest snapshot. An `interval` of 0 disables the snapshots and the
+    /// delta log.
+    pub fn with_objects_snapshot(mut self, interval: u64, retention: u64) -> Self {
+        self.objects_snapshot_interval = interval as i64;
+        self.objects_snapshot_retention = retention.max(1) as i64;
+        self
     }
 
-    fn get_total_transaction_number(&self) -> Result<i64, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| dsl::transactions.select(count(dsl::id)).first::<i64>(conn))
-            .map_err(|e| {
-                IndexerError::PostgresReadError(format!(
-                    "Failed reading total transaction number with err: {:?}",
-                    e
-                ))
-            })
+    /// Cache up to about `max_size` bytes of the transactions read by digest and the checkpoints
+    /// read by sequence number in memory, as these rows never change once committed. A
+    /// `max_size` of 0 disables the cache.
+    pub fn with_read_cache(mut self, max_size: u64) -> Self {
+        self.read_cache = (max_size > 0).then(|| ReadCache::new(max_size, self.metrics.clone()));
+        self
     }
 
-    fn get_transaction_by_digest(&self, txn_digest: &str) -> Result<Transaction, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
+    /// Connection for a read snapshot, from the read replica if it is fresh enough.
+    fn get_read_pool_connection(&self) -> Result<PgPoolConnection, IndexerError> {
+        let Some(replica) = &self.read_replica else {
+            return self.get_pg_pool_connection();
+        };
+        let mut last_check = replica.last_check.lock().unwrap_or_else(|e| e.into_inner());
+        let fresh = match *last_check {
+            Some((checked_at, fresh)) if checked_at.elapsed() < REPLICA_LAG_CHECK_INTERVAL => fresh,
+            _ => {
+                let fresh = match self.get_replica_checkpoint_lag(replica) {
+                    Ok(lag) => {
+                        self.metrics.db_replica_checkpoint_lag.set(lag);
+                        lag <= replica.max_checkpoint_lag
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed checking read replica lag, reading from primary: {:?}",
+                            e
+                        );
+                        false
+                    }
+                };
+                *last_check = Some((Instant::now(), fresh));
+                fresh
+            }
+        };
+        drop(last_check);
+        if fresh {
+            get_pg_pool_connection(&replica.cp)
+        } else {
+            self.get_pg_pool_connection()
+        }
+    }
+
+    fn get_replica_checkpoint_lag(&self, replica: &ReadReplica) -> Result<i64, IndexerError> {
+        let latest_checkpoint = |conn: &mut PgConnection| {
+            checkpoints_table
+                .select(max(sequence_number))
+                .first::<Option<i64>>(conn)
+                .map(|o| o.unwrap_or(-1))
+                .map_err(|e| IndexerError::db("reading latest checkpoint sequence number", e))
+        };
+        let primary = latest_checkpoint(&mut self.get_pg_pool_connection()?)?;
+        let replica = latest_checkpoint(&mut get_pg_pool_connection(&replica.cp)?)?;
+        Ok(primary - replica)
+    }
+
+    /// Inserts `events` in a single statement. A multi-row `INSERT` takes one bind parameter per
+    /// value and runs into the limit of 65535 parameters for event-heavy checkpoints.
+    fn insert_events(
+        &self,
+        conn: &mut PgConnection,
+        events: &[Event],
+    ) -> Result<usize, diesel::result::Error> {
+        if events.is_empty() {
+            return Ok(0);
+        }
+        diesel::sql_query(INSERT_EVENTS_SQL)
+            .bind::<Array<VarChar>, _>(
+                events
+                    .iter()
+                    .map(|e| e.transaction_digest.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<BigInt>, _>(events.iter().map(|e| e.event_sequence).collect::<Vec<_>>())
+            .bind::<Array<Nullable<Timestamp>>, _>(
+                events.iter().map(|e| e.event_time).collect::<Vec<_>>(),
+            )
+            .bind::<Array<VarChar>, _>(
+                events
+                    .iter()
+                    .map(|e| e.event_type.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<VarChar>, _>(
+                events
+                    .iter()
+                    .map(|e| e.event_content.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Nullable<Jsonb>>, _>(
+                events
+                    .iter()
+                    .map(|e| e.event_json.as_ref())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Nullable<VarChar>>, _>(
+                events
+                    .iter()
+                    .map(|e| e.package_id.as_deref())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Nullable<Text>>, _>(
+                events
+                    .iter()
+                    .map(|e| e.transaction_module.as_deref())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Nullable<Text>>, _>(
+                events
+                    .iter()
+                    .map(|e| e.move_event_type.as_deref())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<Nullable<VarChar>>, _>(
+                events
+                    .iter()
+                    .map(|e| e.sender.as_deref())
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<Array<BigInt>, _>(events.iter().map(|e| e.checkpoint).collect::<Vec<_>>())
+            .execute(conn)
+    }
+
+    /// Appends the last version of the objects changed by checkpoint `sequence_number` to the
+    /// delta log, and snapshots the owned objects every `objects_snapshot_interval` checkpoints.
+    /// Must run in the transaction committing the checkpoint, after its objects are written.
+    fn persist_objects_delta(
+        &self,
+        conn: &mut PgConnection,
+        sequence_number: i64,
+        objects_changes: &[TransactionObjectChanges],
+        dead_lettered: bool,
+    ) -> Result<(), diesel::result::Error> {
+        let mut deltas: BTreeMap<&str, ObjectDelta> = BTreeMap::new();
+        for changes in objects_changes {
+            let mutated = changes
+                .mutated_objects
+                .iter()
+                .map(|o| (o.object_id.as_str(), ObjectDelta::from(o)));
+            let deleted = changes
+                .deleted_objects
+                .iter()
+                .map(|o| (o.object_id.as_str(), ObjectDelta::from(o)));
+            for (object_id, delta) in mutated.chain(deleted) {
+                if deltas
+                    .get(object_id)
+                    .map_or(true, |d| d.version < delta.version)
+                {
+                    deltas.insert(object_id, delta);
+                }
+            }
+        }
+        diesel::insert_into(objects_delta::table)
+            .values(deltas.into_values().collect::<Vec<_>>())
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+
+        // The snapshots taken while this checkpoint was dead-lettered miss its changes, reads
+        // fall back to an older snapshot and replay the changes from the delta log instead.
+        if dead_lettered {
+            diesel::delete(
+                objects_snapshot::table.filter(objects_snapshot::checkpoint.ge(sequence_number)),
+            )
+            .execute(conn)?;
+            return Ok(());
+        }
+
+        if sequence_number % self.objects_snapshot_interval == 0 {
+            diesel::sql_query(OBJECTS_SNAPSHOT_SQL)
+                .bind::<BigInt, _>(sequence_number)
+                .execute(conn)?;
+            let oldest_snapshot = sequence_number
+                - self.objects_snapshot_interval * (self.objects_snapshot_retention - 1);
+            diesel::delete(
+                objects_snapshot::table.filter(objects_snapshot::checkpoint.lt(oldest_snapshot)),
+            )
+            .execute(conn)?;
+            diesel::delete(
+                objects_delta::table.filter(objects_delta::checkpoint.le(oldest_snapshot)),
+            )
+            .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    fn get_pg_pool_connection(&self) -> Result<PgPoolConnection, IndexerError> {
+        let state = self.cp.state();
+        self.metrics
+            .db_pool_connections
+            .set(state.connections as i64);
+        self.metrics
+            .db_pool_idle_connections
+            .set(state.idle_connections as i64);
+        get_pg_pool_connection(&self.cp)
+    }
+
+    /// Runs the read-only `query` on the snapshot pinned by the current request if any, otherwise
+    /// in a repeatable read transaction of its own.
+    fn read_only_transaction<T, E, F>(&self, query: F) -> Result<Result<T, E>, IndexerError>
+    where
+        F: FnOnce(&mut PgConnection) -> Result<T, E>,
+        E: From<diesel::result::Error>,
+    {
+        if let Some(conn) = pinned_snapshot() {
+            // Nested in the transaction of the snapshot as a savepoint, so that a failed query
+            // does not abort the snapshot.
+            let mut conn = conn.lock().unwrap_or_else(|e| e.into_inner());
+            return Ok(conn.transaction(query));
+        }
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
+        Ok(pg_pool_conn
             .build_transaction()
             .read_only()
-            .run(|conn| {
-                dsl::transactions
-                    .filter(transaction_digest.eq(txn_digest))
-                    .first::<Transaction>(conn)
-            })
-            .map_err(|e| {
-                IndexerError::PostgresReadError(format!(
-                    "Failed reading transaction with digest {} and err: {:?}",
-                    txn_digest, e
-                ))
-            })
+            .repeatable_read()
+            .run(query))
     }
 
-    fn get_transaction_sequence_by_digest(
-        &self,
-        txn_digest: Option<String>,
-        is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
-        txn_digest
-            .map(|digest| {
-                let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-                pg_pool_conn
-                    .build_transaction()
-                    .read_only()
-                    .run(|conn| {
-                        let mut boxed_query = dsl::transactions
-                            .filter(transaction_digest.eq(digest.clone()))
-                            .select(dsl::id)
-                            .into_boxed();
-                        if is_descending {
-                            boxed_query = boxed_query.order(dsl::id.desc());
-                        } else {
-                            boxed_query = boxed_query.order(dsl::id.asc());
-                        }
-                        boxed_query.first::<i64>(conn)
-                    })
-                    .map_err(|e| {
-                        IndexerError::PostgresReadError(format!(
-                            "Failed reading transaction sequence with digest {} and err: {:?}",
-                            digest, e
-                        ))
-                    })
-            })
-            .transpose()
+    fn read_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError> {
+        self.read_only_transaction(|conn| match id {
+            CheckpointId::SequenceNumber(seq) => checkpoints_table
+                .filter(sequence_number.eq(seq as i64))
+                .limit(1)
+                .first::<Checkpoint>(conn),
+            CheckpointId::Digest(digest) => checkpoints_table
+                .filter(checkpoint_digest.eq(digest.base58_encode()))
+                .limit(1)
+                .first::<Checkpoint>(conn),
+        })?
+        .map_err(|e| IndexerError::db("reading previous checkpoint", e))
+    }
+
+    fn read_transaction_by_digest(&self, txn_digest: &str) -> Result<Transaction, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dsl::transactions
+                .filter(transaction_digest.eq(txn_digest))
+                .first::<Transaction>(conn)
+        })?
+        .map_err(|e| IndexerError::db(format!("reading transaction with digest {}", txn_digest), e))
     }
 
-    fn get_move_call_sequence_by_digest(
+    fn get_event_page(
         &self,
-        txn_digest: Option<String>,
+        filter: EventColumnFilter,
+        cursor: Option<StoreCursor>,
+        limit: usize,
         is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
-        txn_digest
-            .map(|digest| {
-                let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-                pg_pool_conn
-                    .build_transaction()
-                    .read_only()
-                    .run(|conn| {
-                        let mut boxed_query = move_calls_dsl::move_calls
-                            .filter(move_calls_dsl::transaction_digest.eq(digest.clone()))
-                            .into_boxed();
-                        if is_descending {
-                            boxed_query = boxed_query.order(move_calls_dsl::id.desc());
-                        } else {
-                            boxed_query = boxed_query.order(move_calls_dsl::id.asc());
-                        }
-                        boxed_query.select(move_calls_dsl::id).first::<i64>(conn)
-                    })
-                    .map_err(|e| {
-                        IndexerError::PostgresReadError(format!(
-                            "Failed reading move call sequence with digest {} and err: {:?}",
-                            digest, e
-                        ))
-                    })
-            })
-            .transpose()
+    ) -> Result<Vec<Event>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = events::table.into_boxed();
+            boxed_query = match &filter {
+                EventColumnFilter::Sender(sender) => {
+                    boxed_query.filter(events::sender.eq(sender.clone()))
+                }
+                EventColumnFilter::Package(package) => {
+                    boxed_query.filter(events::package_id.eq(package.clone()))
+                }
+                EventColumnFilter::Module(package, module) => boxed_query
+                    .filter(events::package_id.eq(package.clone()))
+                    .filter(events::transaction_module.eq(module.clone())),
+                EventColumnFilter::MoveEventType(move_event_type) => {
+                    boxed_query.filter(events::move_event_type.eq(move_event_type.clone()))
+                }
+            };
+            if let Some(cursor) = cursor {
+                boxed_query = boxed_query.filter(cursor.filter(
+                    "checkpoint",
+                    "transaction_index",
+                    Some("event_sequence"),
+                    is_descending,
+                ));
+            }
+
+            if is_descending {
+                boxed_query
+                    .order((
+                        events::checkpoint.desc(),
+                        events::transaction_index.desc(),
+                        events::event_sequence.desc(),
+                    ))
+                    .limit(limit as i64)
+                    .load::<Event>(conn)
+            } else {
+                boxed_query
+                    .order((
+                        events::checkpoint.asc(),
+                        events::transaction_index.asc(),
+                        events::event_sequence.asc(),
+                    ))
+                    .limit(limit as i64)
+                    .load::<Event>(conn)
+            }
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading events by {:?} with cursor {:?} and limit {}",
+                    filter, cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+}
+
+#[derive(Debug)]
+enum EventColumnFilter {
+    Sender(String),
+    Package(String),
+    Module(String, String),
+    MoveEventType(String),
+}
+
+#[async_trait]
+impl IndexerStore for PgIndexerStore {
+    fn begin_read_snapshot(&self) -> Result<ReadSnapshot, IndexerError> {
+        ReadSnapshot::begin(self.get_read_pool_connection()?)
+    }
+
+    fn get_latest_checkpoint_sequence_number(&self) -> Result<i64, IndexerError> {
+        self.read_only_transaction(|conn| {
+            checkpoints_table
+                .select(max(sequence_number))
+                .first::<Option<i64>>(conn)
+                // -1 to differentiate between no checkpoints and the first checkpoint
+                .map(|o| o.unwrap_or(-1))
+        })?
+        .map_err(|e| IndexerError::db("reading latest checkpoint sequence number", e))
     }
 
-    fn get_recipient_sequence_by_digest(
+    fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError> {
+        match (&self.read_cache, id) {
+            (Some(cache), CheckpointId::SequenceNumber(seq)) => cache
+                .get_checkpoint(seq as i64, || {
+                    self.read_checkpoint(CheckpointId::SequenceNumber(seq))
+                }),
+            (_, id) => self.read_checkpoint(id),
+        }
+    }
+
+    fn get_checkpoint_range_data(
         &self,
-        txn_digest: Option<String>,
-        is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
-        txn_digest
-            .map(|txn_digest| {
-                let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-                pg_pool_conn
-                    .build_transaction()
-                    .read_only()
-                    .run(|conn| {
-                        let mut boxed_query = recipients_dsl::recipients
-                            .filter(recipients_dsl::transaction_digest.eq(&txn_digest))
-                            .into_boxed();
-                        if is_descending {
-                            boxed_query = boxed_query.order(recipients_dsl::id.desc());
-                        } else {
-                            boxed_query = boxed_query.order(recipients_dsl::id.asc());
-                        }
-                        boxed_query.select(recipients_dsl::id).first::<i64>(conn)
-                    })
-                    .map_err(|e| {
-                        IndexerError::PostgresReadError(format!(
-                            "Failed reading recipients sequence with digest {} and err: {:?}",
-                            txn_digest, e
-                        ))
-                    })
+        start: i64,
+        end: i64,
+    ) -> Result<CheckpointRangeData, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let checkpoints = checkpoints_table
+                .filter(sequence_number.between(start, end))
+                .order(sequence_number.asc())
+                .load::<Checkpoint>(conn)?;
+            let transactions = dsl::transactions
+                .filter(dsl::checkpoint_sequence_number.between(start, end))
+                .order((
+                    dsl::checkpoint_sequence_number.asc(),
+                    dsl::transaction_index.asc(),
+                ))
+                .load::<Transaction>(conn)?;
+            let events = events::table
+                .filter(events::checkpoint.between(start, end))
+                .order((
+                    events::checkpoint.asc(),
+                    events::transaction_index.asc(),
+                    events::event_sequence.asc(),
+                ))
+                .load::<Event>(conn)?;
+            Ok::<_, diesel::result::Error>(CheckpointRangeData {
+                checkpoints,
+                transactions,
+                events,
             })
-            .transpose()
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!("reading data of checkpoints {} to {}", start, end),
+                e,
+            )
+        })
+    }
+
+    fn get_total_transaction_number(&self) -> Result<i64, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dsl::transactions.select(count(dsl::id)).first::<i64>(conn)
+        })?
+        .map_err(|e| IndexerError::db("reading total transaction number", e))
+    }
+
+    fn get_transaction_by_digest(&self, txn_digest: &str) -> Result<Transaction, IndexerError> {
+        match &self.read_cache {
+            Some(cache) => {
+                cache.get_transaction(txn_digest, || self.read_transaction_by_digest(txn_digest))
+            }
+            None => self.read_transaction_by_digest(txn_digest),
+        }
+    }
+
+    fn get_transaction_cursor(&self, txn_digest: &str) -> Result<StoreCursor, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dsl::transactions
+                .filter(transaction_digest.eq(txn_digest))
+                .select((dsl::checkpoint_sequence_number, dsl::transaction_index))
+                .first::<(i64, i64)>(conn)
+        })?
+        .map(|(checkpoint, transaction_index)| {
+            StoreCursor::transaction(checkpoint, transaction_index)
+        })
+        .map_err(|e| IndexerError::db(format!("reading position of transaction {}", txn_digest), e))
     }
 
     fn get_all_transaction_digest_page(
         &self,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
-                let mut boxed_query = dsl::transactions.into_boxed();
-                if is_descending {
-                    boxed_query = boxed_query.order(dsl::id.desc());
-                } else {
-                    boxed_query = boxed_query.order(dsl::id.asc());
-                }
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = dsl::transactions.into_boxed();
+            if let Some(cursor) = cursor {
+                boxed_query = boxed_query.filter(cursor.filter(
+                    "checkpoint_sequence_number",
+                    "transaction_index",
+                    None,
+                    is_descending,
+                ));
+            }
 
-                if is_descending {
-                    boxed_query
-                        .order(dsl::id.desc())
-                        .limit((limit + 1) as i64)
-                        .select(transaction_digest)
-                        .load::<String>(conn)
-                } else {
-                    boxed_query
-                        .order(dsl::id.asc())
-                        .limit((limit + 1) as i64)
-                        .select(transaction_digest)
-                        .load::<String>(conn)
-                }
-            }).map_err(|e| {
-            IndexerError::PostgresReadError(format!(
-                "Failed reading all transaction digests with start_sequence {:?} and limit {} and err: {:?}",
-                start_sequence, limit, e
-            ))
+            if is_descending {
+                boxed_query
+                    .order((
+                        dsl::checkpoint_sequence_number.desc(),
+                        dsl::transaction_index.desc(),
+                    ))
+                    .limit((limit + 1) as i64)
+                    .select(transaction_digest)
+                    .load::<String>(conn)
+            } else {
+                boxed_query
+                    .order((
+                        dsl::checkpoint_sequence_number.asc(),
+                        dsl::transaction_index.asc(),
+                    ))
+                    .limit((limit + 1) as i64)
+                    .select(transaction_digest)
+                    .load::<String>(conn)
+            }
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading all transaction digests with cursor {:?} and limit {}",
+                    cursor, limit
+                ),
+                e,
+            )
         })
     }
 
@@ -272,18 +743,15 @@ impl IndexerStore for PgIndexerStore {
         package_name: String,
         module_name: Option<String>,
         function_name: Option<String>,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
+        self.read_only_transaction(|conn| {
+                // Transactions making several matching calls are returned once.
                 let mut builder = move_calls_dsl::move_calls.filter(move_calls_dsl::move_package.eq(package_name.clone()))
-                    .group_by(move_calls_dsl::transaction_digest)
-                    .select((move_calls_dsl::transaction_digest, max(move_calls_dsl::id)))
+                    .select((move_calls_dsl::transaction_digest, move_calls_dsl::checkpoint_sequence_number, move_calls_dsl::transaction_index))
+                    .distinct()
                     .into_boxed();
                 if let Some(module_name) = module_name.clone() {
                     builder = builder.filter(move_calls_dsl::move_module.eq(module_name));
@@ -291,127 +759,96 @@ impl IndexerStore for PgIndexerStore {
                 if let Some(function_name) = function_name.clone() {
                     builder = builder.filter(move_calls_dsl::move_function.eq(function_name));
                 }
-                if let Some(start_sequence) = start_sequence {
-                    if is_descending {
-                        builder = builder.filter(move_calls_dsl::id.le(start_sequence));
-                    } else {
-                        builder = builder.filter(move_calls_dsl::id.ge(start_sequence));
-                    }
+                if let Some(cursor) = cursor {
+                    builder = builder.filter(cursor.filter("checkpoint_sequence_number", "transaction_index", None, is_descending));
                 }
 
                 if is_descending {
-                    builder.order(move_calls_dsl::id.desc())
+                    builder.order((move_calls_dsl::checkpoint_sequence_number.desc(), move_calls_dsl::transaction_index.desc()))
                         .limit(limit as i64)
-                        .load::<(String, Option<i64>)>(conn)
+                        .load::<(String, i64, i64)>(conn)
                 } else {
-                    builder.order(move_calls_dsl::id.asc())
+                    builder.order((move_calls_dsl::checkpoint_sequence_number.asc(), move_calls_dsl::transaction_index.asc()))
                         .limit(limit as i64)
-                        .load::<(String, Option<i64>)>(conn)
+                        .load::<(String, i64, i64)>(conn)
                 }
-            }).map(|v| v.into_iter().map(|(digest, _)| digest).collect()).map_err(|e| {
-            IndexerError::PostgresReadError(format!(
-                "Failed reading transaction digests with package_name {} module_name {:?} and function_name {:?} and start_sequence {:?} and limit {} and err: {:?}",
-                package_name, module_name, function_name, start_sequence, limit, e
-            ))
+            })?.map(|v| v.into_iter().map(|(digest, _, _)| digest).collect()).map_err(|e| {
+            IndexerError::db(format!("reading transaction digests with package_name {} module_name {:?} and function_name {:?} and cursor {:?} and limit {}", package_name, module_name, function_name, cursor, limit), e)
         })
     }
 
     fn get_transaction_digest_page_by_mutated_object(
         &self,
         object_id: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
+        self.read_only_transaction(|conn| {
                 let mut boxed_query = dsl::transactions
                     .filter(dsl::mutated.contains(vec![Some(object_id.clone())]))
                     .into_boxed();
-                if let Some(start_sequence) = start_sequence {
-                    if is_descending {
-                        boxed_query = boxed_query
-                            .filter(dsl::id.le(start_sequence));
-                    } else {
-                        boxed_query = boxed_query
-                            .filter(dsl::id.ge(start_sequence));
-                    }
+                if let Some(cursor) = cursor {
+                    boxed_query = boxed_query
+                        .filter(cursor.filter("checkpoint_sequence_number", "transaction_index", None, is_descending));
                 }
 
                 if is_descending {
                     boxed_query
-                        .order(dsl::id.desc())
+                        .order((dsl::checkpoint_sequence_number.desc(), dsl::transaction_index.desc()))
                         .limit(limit as i64)
                         .select(transaction_digest)
                         .load::<String>(conn)
                 } else {
                     boxed_query
-                        .order(dsl::id.asc())
+                        .order((dsl::checkpoint_sequence_number.asc(), dsl::transaction_index.asc()))
                         .limit(limit as i64)
                         .select(transaction_digest)
                         .load::<String>(conn)
                 }
-            }).map_err(|e| {
-            IndexerError::PostgresReadError(format!(
-                "Failed reading transaction digests by mutated object id {} with start_sequence {:?} and limit {} and err: {:?}",
-                object_id, start_sequence, limit, e
-            ))
+            })?.map_err(|e| {
+            IndexerError::db(format!("reading transaction digests by mutated object id {} with cursor {:?} and limit {}", object_id, cursor, limit), e)
         })
     }
 
     fn get_transaction_digest_page_by_sender_address(
         &self,
         sender_address: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
+        self.read_only_transaction(|conn| {
                     let mut boxed_query = dsl::transactions
                         .filter(dsl::sender.eq(sender_address.clone()))
                         .into_boxed();
-                    if let Some(start_sequence) = start_sequence {
-                        if is_descending {
-                            boxed_query = boxed_query
-                                .filter(dsl::id.le(start_sequence));
-                        } else {
-                            boxed_query = boxed_query
-                                .filter(dsl::id.ge(start_sequence));
-                        }
+                    if let Some(cursor) = cursor {
+                        boxed_query = boxed_query
+                            .filter(cursor.filter("checkpoint_sequence_number", "transaction_index", None, is_descending));
                     }
 
                     if is_descending {
                         boxed_query
-                            .order(dsl::id.desc())
+                            .order((dsl::checkpoint_sequence_number.desc(), dsl::transaction_index.desc()))
                             .limit(limit as i64)
                             .select(transaction_digest)
                             .load::<String>(conn)
                     } else {
                         boxed_query
-                            .order(dsl::id.asc())
+                            .order((dsl::checkpoint_sequence_number.asc(), dsl::transaction_index.asc()))
                             .limit(limit as i64)
                             .select(transaction_digest)
                             .load::<String>(conn)
                     }
-            }).map_err(|e| {
-            IndexerError::PostgresReadError(format!(
-                "Failed reading transaction digests by sender address {} with start_sequence {:?} and limit {} and err: {:?}",
-                sender_address, start_sequence, limit, e
-            ))
+            })?.map_err(|e| {
+            IndexerError::db(format!("reading transaction digests by sender address {} with cursor {:?} and limit {}", sender_address, cursor, limit), e)
         })
     }
 
     fn get_transaction_digest_page_by_recipient_address(
         &self,
         recipient_address: String,
-        start_sequence: Option<i64>,
+        cursor: Option<StoreCursor>,
         limit: usize,
         is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
@@ -421,63 +858,664 @@ impl IndexerStore for PgIndexerStore {
             digest_name: String,
         }
 
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        Ok(pg_pool_conn.build_transaction()
-            .read_only()
-            .run(|conn| {
+        Ok(self.read_only_transaction(|conn| {
+                let order = if is_descending { "DESC" } else { "ASC" };
                 let sql_query = format!(
-                    "SELECT transaction_digest as digest_name FROM (
-                        SELECT transaction_digest, max(id) AS max_id 
-                        FROM recipients WHERE recipient = '{}' {} GROUP BY transaction_digest ORDER BY max_id {} LIMIT {}
-                    ) AS t",
+                    "SELECT DISTINCT transaction_digest as digest_name, checkpoint_sequence_number, transaction_index
+                        FROM recipients WHERE recipient = '{}' {}
+                        ORDER BY checkpoint_sequence_number {order}, transaction_index {order} LIMIT {}",
                     recipient_address.clone(),
-                    if let Some(start_sequence) = start_sequence {
-                        if is_descending {
-                            format!("AND id <= {}", start_sequence)
-                        } else {
-                            format!("AND id >= {}", start_sequence)
-                        }
+                    if let Some(cursor) = cursor {
+                        format!("AND {}", cursor.condition("checkpoint_sequence_number", "transaction_index", None, is_descending))
                     } else {
                         "".to_string()
                     },
-                    if is_descending {
-                        "DESC"
-                    } else {
-                        "ASC"
-                    },
                     limit
                 );
                 diesel::sql_query(sql_query).load(conn)
-            })
+            })?
             .map_err(|e| {
-            IndexerError::PostgresReadError(format!(
-                "Failed reading transaction digests by recipient address {} with start_sequence {:?} and limit {} and err: {:?}",
-                recipient_address, start_sequence, limit, e
-            ))
+            IndexerError::db(format!("reading transaction digests by recipient address {} with cursor {:?} and limit {}", recipient_address, cursor, limit), e)
         })?.into_iter().map(|table: TempDigestTable| table.digest_name ).collect())
     }
 
+    fn get_events_by_sender(
+        &self,
+        sender: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        self.get_event_page(
+            EventColumnFilter::Sender(sender),
+            cursor,
+            limit,
+            is_descending,
+        )
+    }
+
+    fn get_events_by_package(
+        &self,
+        package: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        self.get_event_page(
+            EventColumnFilter::Package(package),
+            cursor,
+            limit,
+            is_descending,
+        )
+    }
+
+    fn get_events_by_module(
+        &self,
+        package: String,
+        module: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        self.get_event_page(
+            EventColumnFilter::Module(package, module),
+            cursor,
+            limit,
+            is_descending,
+        )
+    }
+
+    fn get_events_by_move_event_type(
+        &self,
+        move_event_type: String,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        self.get_event_page(
+            EventColumnFilter::MoveEventType(move_event_type),
+            cursor,
+            limit,
+            is_descending,
+        )
+    }
+
+    fn get_dynamic_fields(
+        &self,
+        parent_object_id: String,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<(DynamicField, Option<Object>)>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = dynamic_fields::table
+                .filter(dynamic_fields::parent_object_id.eq(parent_object_id.clone()))
+                .into_boxed();
+            if let Some(cursor) = &cursor {
+                boxed_query = boxed_query.filter(dynamic_fields::object_id.ge(cursor.clone()));
+            }
+            let fields = boxed_query
+                .order(dynamic_fields::object_id.asc())
+                .limit(limit as i64)
+                .load::<DynamicField>(conn)?;
+
+            // Dynamic object fields report the child object instead of the Field wrapper
+            let child_ids = fields
+                .iter()
+                .filter(|field| field.value_object_id != field.object_id)
+                .map(|field| field.value_object_id.clone())
+                .collect::<Vec<_>>();
+            let mut children = objects::table
+                .filter(objects::object_id.eq_any(child_ids))
+                .load::<Object>(conn)?
+                .into_iter()
+                .map(|o| (o.object_id.clone(), o))
+                .collect::<BTreeMap<_, _>>();
+
+            Ok::<_, diesel::result::Error>(
+                fields
+                    .into_iter()
+                    .map(|field| {
+                        let child = children.remove(&field.value_object_id);
+                        (field, child)
+                    })
+                    .collect(),
+            )
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading dynamic fields of {} with cursor {:?} and limit {}",
+                    parent_object_id, cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_dynamic_field(
+        &self,
+        parent_object_id: String,
+        name_type: String,
+        name_value: serde_json::Value,
+    ) -> Result<Option<DynamicField>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dynamic_fields::table
+                .filter(dynamic_fields::parent_object_id.eq(parent_object_id.clone()))
+                .filter(dynamic_fields::name_type.eq(name_type.clone()))
+                .filter(dynamic_fields::name_value.eq(name_value.clone()))
+                .first::<DynamicField>(conn)
+                .optional()
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading dynamic field of {} with name {}: {}",
+                    parent_object_id, name_type, name_value
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_object_history(
+        &self,
+        object_id: &str,
+        start_version: i64,
+        limit: usize,
+    ) -> Result<Vec<ObjectHistory>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            diesel::sql_query(GET_OBJECT_HISTORY_SQL)
+                .bind::<VarChar, _>(object_id)
+                .bind::<BigInt, _>(start_version)
+                .bind::<BigInt, _>(limit as i64)
+                .load(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading history of object {} from version {} with limit {}",
+                    object_id, start_version, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_owned_objects_at_checkpoint(
+        &self,
+        owner: &str,
+        checkpoint: i64,
+    ) -> Result<Option<Vec<OwnedObject>>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let snapshot = objects_snapshot::table
+                .select(max(objects_snapshot::checkpoint))
+                .filter(objects_snapshot::checkpoint.le(checkpoint))
+                .first::<Option<i64>>(conn)?;
+            snapshot
+                .map(|snapshot| {
+                    diesel::sql_query(GET_OWNED_OBJECTS_AT_CHECKPOINT_SQL)
+                        .bind::<VarChar, _>(owner)
+                        .bind::<BigInt, _>(snapshot)
+                        .bind::<BigInt, _>(checkpoint)
+                        .load(conn)
+                })
+                .transpose()
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading objects owned by {} at checkpoint {}",
+                    owner, checkpoint
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_incoming_payments(
+        &self,
+        recipient: &str,
+        start_id: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<Recipient>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = recipients_dsl::recipients
+                .filter(recipients_dsl::recipient.eq(recipient))
+                .filter(recipients_dsl::amount.is_not_null())
+                .into_boxed();
+            if let Some(start_id) = start_id {
+                boxed_query = boxed_query.filter(recipients_dsl::id.le(start_id));
+            }
+            boxed_query
+                .order(recipients_dsl::id.desc())
+                .limit(limit as i64)
+                .load::<Recipient>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading incoming payments of {} from id {:?} with limit {}",
+                    recipient, start_id, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_package_abi(
+        &self,
+        package_id: &str,
+    ) -> Result<Option<BTreeMap<String, SuiMoveNormalizedModule>>, IndexerError> {
+        let package = self
+            .read_only_transaction(|conn| {
+                packages::table
+                    .filter(packages::package_id.eq(package_id))
+                    .order(packages::version.desc())
+                    .first::<Package>(conn)
+                    .optional()
+            })?
+            .map_err(|e| IndexerError::db(format!("reading package {}", package_id), e))?;
+        Ok(package.map(|p| p.module_abi()).transpose()?.flatten())
+    }
+
+    fn get_hot_shared_objects(
+        &self,
+        window_ms: i64,
+        limit: usize,
+    ) -> Result<Vec<SharedObjectStats>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            diesel::sql_query(GET_HOT_SHARED_OBJECTS_SQL)
+                .bind::<BigInt, _>(window_ms)
+                .bind::<BigInt, _>(limit as i64)
+                .load(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading hot shared objects with window {} ms and limit {}",
+                    window_ms, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_failing_functions(
+        &self,
+        window_ms: i64,
+        limit: usize,
+    ) -> Result<Vec<FailingFunctionStats>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            diesel::sql_query(GET_FAILING_FUNCTIONS_SQL)
+                .bind::<BigInt, _>(window_ms)
+                .bind::<BigInt, _>(limit as i64)
+                .load(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading failing functions with window {} ms and limit {}",
+                    window_ms, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_transaction_error_stats(
+        &self,
+        window_ms: i64,
+    ) -> Result<Vec<ErrorKindStats>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            diesel::sql_query(GET_TRANSACTION_ERROR_STATS_SQL)
+                .bind::<BigInt, _>(window_ms)
+                .load(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading transaction error stats with window {} ms",
+                    window_ms
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_transaction_sequence_page(
+        &self,
+        filter: &TransactionPageFilter,
+        cursor: Option<StoreCursor>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<(StoreCursor, i64, String)>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = dsl::transactions
+                .select((
+                    dsl::checkpoint_sequence_number,
+                    dsl::transaction_index,
+                    dsl::id,
+                    transaction_digest,
+                ))
+                .into_boxed();
+            if let Some(digest) = &filter.digest {
+                boxed_query = boxed_query.filter(transaction_digest.eq(digest.clone()));
+            }
+            if let Some(sender) = &filter.sender {
+                boxed_query = boxed_query.filter(dsl::sender.eq(sender.clone()));
+            }
+            if let Some(recipient) = &filter.recipient {
+                boxed_query =
+                    boxed_query.filter(dsl::recipients.contains(vec![Some(recipient.clone())]));
+            }
+            if let Some(checkpoint) = filter.checkpoint {
+                boxed_query = boxed_query.filter(dsl::checkpoint_sequence_number.eq(checkpoint));
+            }
+            // The rows of transactions have no sub-index, the position right after the cursor is
+            // the first one of the next transaction.
+            if let Some(cursor) = cursor {
+                let start = if is_descending {
+                    StoreCursor::transaction(cursor.checkpoint, cursor.transaction_index - 1)
+                } else {
+                    StoreCursor::transaction(cursor.checkpoint, cursor.transaction_index + 1)
+                };
+                boxed_query = boxed_query.filter(start.filter(
+                    "checkpoint_sequence_number",
+                    "transaction_index",
+                    None,
+                    is_descending,
+                ));
+            }
+            if is_descending {
+                boxed_query = boxed_query.order((
+                    dsl::checkpoint_sequence_number.desc(),
+                    dsl::transaction_index.desc(),
+                ));
+            } else {
+                boxed_query = boxed_query.order((
+                    dsl::checkpoint_sequence_number.asc(),
+                    dsl::transaction_index.asc(),
+                ));
+            }
+            boxed_query
+                .limit(limit as i64)
+                .load::<(i64, i64, i64, String)>(conn)
+        })?
+        .map(|rows| {
+            rows.into_iter()
+                .map(|(checkpoint, transaction_index, id, digest)| {
+                    (
+                        StoreCursor::transaction(checkpoint, transaction_index),
+                        id,
+                        digest,
+                    )
+                })
+                .collect()
+        })
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading transaction page by {:?} with cursor {:?} and limit {}",
+                    filter, cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn multi_get_transaction_summaries(
+        &self,
+        sequence_numbers: &[i64],
+    ) -> Result<Vec<TransactionSummary>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dsl::transactions
+                .select((
+                    dsl::id,
+                    transaction_digest,
+                    dsl::sender,
+                    dsl::recipients,
+                    dsl::checkpoint_sequence_number,
+                    dsl::transaction_time,
+                    dsl::transaction_kinds,
+                    dsl::move_calls,
+                    dsl::gas_object_id,
+                    dsl::gas_object_sequence,
+                    dsl::gas_object_digest,
+                    dsl::gas_budget,
+                    dsl::total_gas_cost,
+                    dsl::computation_cost,
+                    dsl::storage_cost,
+                    dsl::storage_rebate,
+                    dsl::gas_price,
+                ))
+                .filter(dsl::id.eq_any(sequence_numbers))
+                .load::<TransactionSummary>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!("reading transaction summaries {:?}", sequence_numbers),
+                e,
+            )
+        })
+    }
+
+    fn multi_get_transactions(
+        &self,
+        sequence_numbers: &[i64],
+    ) -> Result<Vec<Transaction>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dsl::transactions
+                .filter(dsl::id.eq_any(sequence_numbers))
+                .load::<Transaction>(conn)
+        })?
+        .map_err(|e| IndexerError::db(format!("reading transactions {:?}", sequence_numbers), e))
+    }
+
+    fn get_checkpoint_page(
+        &self,
+        cursor: Option<i64>,
+        limit: usize,
+        is_descending: bool,
+    ) -> Result<Vec<Checkpoint>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = checkpoints_table.into_boxed();
+            if is_descending {
+                if let Some(cursor) = cursor {
+                    boxed_query = boxed_query.filter(sequence_number.lt(cursor));
+                }
+                boxed_query = boxed_query.order(sequence_number.desc());
+            } else {
+                if let Some(cursor) = cursor {
+                    boxed_query = boxed_query.filter(sequence_number.gt(cursor));
+                }
+                boxed_query = boxed_query.order(sequence_number.asc());
+            }
+            boxed_query.limit(limit as i64).load::<Checkpoint>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading checkpoint page with cursor {:?} and limit {}",
+                    cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_events_by_transaction(&self, txn_digest: &str) -> Result<Vec<Event>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            events::table
+                .filter(events::transaction_digest.eq(txn_digest))
+                .order(events::event_sequence.asc())
+                .load::<Event>(conn)
+        })?
+        .map_err(|e| IndexerError::db(format!("reading events of transaction {}", txn_digest), e))
+    }
+
+    fn get_object(&self, object_id: &str) -> Result<Option<Object>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            objects::table
+                .filter(objects::object_id.eq(object_id))
+                .first::<Object>(conn)
+                .optional()
+        })?
+        .map_err(|e| IndexerError::db(format!("reading object {}", object_id), e))
+    }
+
+    fn get_owned_objects_page(
+        &self,
+        owner: &str,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<Object>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = objects::table
+                .filter(objects::owner_type.eq(OwnerType::AddressOwner))
+                .filter(objects::owner_address.eq(owner))
+                .filter(objects::object_status.ne(ObjectStatus::Deleted))
+                .filter(objects::object_status.ne(ObjectStatus::Wrapped))
+                .filter(objects::object_status.ne(ObjectStatus::UnwrappedThenDeleted))
+                .into_boxed();
+            if let Some(cursor) = &cursor {
+                boxed_query = boxed_query.filter(objects::object_id.gt(cursor.clone()));
+            }
+            boxed_query
+                .order(objects::object_id.asc())
+                .limit(limit as i64)
+                .load::<Object>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading objects owned by {} with cursor {:?} and limit {}",
+                    owner, cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_objects_by_type_page(
+        &self,
+        object_type: &str,
+        cursor: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<Object>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            let mut boxed_query = objects::table
+                .filter(objects::object_type.eq(object_type))
+                .filter(objects::object_status.ne(ObjectStatus::Deleted))
+                .filter(objects::object_status.ne(ObjectStatus::Wrapped))
+                .filter(objects::object_status.ne(ObjectStatus::UnwrappedThenDeleted))
+                .into_boxed();
+            if let Some(cursor) = &cursor {
+                boxed_query = boxed_query.filter(objects::object_id.gt(cursor.clone()));
+            }
+            boxed_query
+                .order(objects::object_id.asc())
+                .limit(limit as i64)
+                .load::<Object>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading objects of type {} with cursor {:?} and limit {}",
+                    object_type, cursor, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn count_objects_by_type(&self, object_type: &str) -> Result<i64, IndexerError> {
+        self.read_only_transaction(|conn| {
+            object_type_counts::table
+                .select(object_type_counts::live_count)
+                .filter(object_type_counts::object_type.eq(object_type))
+                .first::<i64>(conn)
+                .optional()
+        })?
+        .map(|count| count.unwrap_or_default())
+        .map_err(|e| IndexerError::db(format!("counting objects of type {}", object_type), e))
+    }
+
+    fn get_address(&self, address: &str) -> Result<Option<Address>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            addresses::table
+                .filter(account_address.eq(address))
+                .first::<Address>(conn)
+                .optional()
+        })?
+        .map_err(|e| IndexerError::db(format!("reading address {}", address), e))
+    }
+
+    fn get_active_addresses(
+        &self,
+        start_epoch: i64,
+        end_epoch: i64,
+    ) -> Result<ActiveAddressCount, IndexerError> {
+        self.read_only_transaction(|conn| {
+            diesel::sql_query(GET_ACTIVE_ADDRESSES_SQL)
+                .bind::<BigInt, _>(start_epoch)
+                .bind::<BigInt, _>(end_epoch)
+                .get_result(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading active addresses of epochs {} to {}",
+                    start_epoch, end_epoch
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_address_activity(
+        &self,
+        address: &str,
+        start_epoch: i64,
+        end_epoch: i64,
+    ) -> Result<Vec<AddressActivity>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            address_activity::table
+                .filter(address_activity::address.eq(address))
+                .filter(address_activity::epoch.between(start_epoch, end_epoch))
+                .order(address_activity::epoch.asc())
+                .load::<AddressActivity>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading activity of address {} in epochs {} to {}",
+                    address, start_epoch, end_epoch
+                ),
+                e,
+            )
+        })
+    }
+
     fn read_transactions(
         &self,
         last_processed_id: i64,
         limit: usize,
     ) -> Result<Vec<Transaction>, IndexerError> {
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_only()
-            .run(|conn| {
-                dsl::transactions
-                    .filter(dsl::id.gt(last_processed_id))
-                    .limit(limit as i64)
-                    .load::<Transaction>(conn)
-            })
-            .map_err(|e| {
-                IndexerError::PostgresReadError(format!(
-                    "Failed reading transactions with last_processed_id {} and err: {:?}",
-                    last_processed_id, e
-                ))
-            })
+        self.read_only_transaction(|conn| {
+            dsl::transactions
+                .filter(dsl::id.gt(last_processed_id))
+                .limit(limit as i64)
+                .load::<Transaction>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading transactions with last_processed_id {}",
+                    last_processed_id
+                ),
+                e,
+            )
+        })
     }
 
     fn persist_checkpoint(&self, data: &TemporaryCheckpointStore) -> Result<usize, IndexerError> {
@@ -490,30 +1528,61 @@ impl IndexerStore for PgIndexerStore {
             packages,
             move_calls,
             recipients, // TODO: store raw object
+            shared_object_usage,
+            transaction_errors,
+            address_activity,
+            dynamic_fields,
+            deleted_dynamic_fields,
         } = data;
 
-        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
+        // Partitions are created ahead of the checkpoint transaction, which then only takes row
+        // locks on the partitioned tables.
+        self.partition_manager
+            .advance_checkpoint(checkpoint.sequence_number)?;
+
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
 
         // Commit indexed checkpoint in one transaction
-        pg_pool_conn
+        let persisted = pg_pool_conn
             .build_transaction()
             .serializable()
             .read_write()
             .run(|conn| {
+                let table_timer = |table: &str| {
+                    self.metrics
+                        .persist_table_latency
+                        .with_label_values(&[table])
+                        .start_timer()
+                };
+
+                // The checkpoint row of a dead-lettered checkpoint is already committed.
+                let guard = table_timer("checkpoints");
                 diesel::insert_into(checkpoints_table)
                     .values(checkpoint)
+                    .on_conflict(checkpoints::sequence_number)
+                    .do_nothing()
                     .execute(conn)?;
+                let dead_lettered = diesel::delete(dead_letter_checkpoints::table.filter(
+                    dead_letter_checkpoints::sequence_number.eq(checkpoint.sequence_number),
+                ))
+                .execute(conn)?;
+                guard.stop_and_record();
 
+                let guard = table_timer("transactions");
                 diesel::insert_into(transactions::table)
                     .values(transactions)
                     .execute(conn)?;
+                guard.stop_and_record();
 
-                diesel::insert_into(events::table)
-                    .values(events)
-                    .execute(conn)?;
+                let guard = table_timer("events");
+                self.insert_events(conn, events)?;
+                guard.stop_and_record();
 
                 // Object need to bulk insert by transaction to prevent same object mutated twice in the same sql call,
-                // which will result in "ON CONFLICT DO UPDATE command cannot affect row a second time" error
+                // which will result in "ON CONFLICT DO UPDATE command cannot affect row a second time" error.
+                // Only newer versions are written, so that reprocessing a dead-lettered checkpoint
+                // does not roll objects back.
+                let guard = table_timer("objects");
                 for changes in objects_changes {
                     diesel::insert_into(objects::table)
                         .values(&changes.mutated_objects)
@@ -524,10 +1593,15 @@ impl IndexerStore for PgIndexerStore {
                             objects::checkpoint.eq(excluded(objects::checkpoint)),
                             objects::version.eq(excluded(objects::version)),
                             objects::object_digest.eq(excluded(objects::object_digest)),
+                            objects::owner_type.eq(excluded(objects::owner_type)),
                             objects::owner_address.eq(excluded(objects::owner_address)),
-                            objects::previous_transaction.eq(excluded(objects::previous_transaction)),
+                            objects::initial_shared_version
+                                .eq(excluded(objects::initial_shared_version)),
+                            objects::previous_transaction
+                                .eq(excluded(objects::previous_transaction)),
                             objects::object_status.eq(excluded(objects::object_status)),
                         ))
+                        .filter(objects::version.lt(excluded(objects::version)))
                         .execute(conn)?;
 
                     diesel::insert_into(objects::table)
@@ -538,50 +1612,360 @@ impl IndexerStore for PgIndexerStore {
                             objects::epoch.eq(excluded(objects::epoch)),
                             objects::checkpoint.eq(excluded(objects::checkpoint)),
                             objects::version.eq(excluded(objects::version)),
-                            objects::previous_transaction.eq(excluded(objects::previous_transaction)),
+                            objects::previous_transaction
+                                .eq(excluded(objects::previous_transaction)),
                             objects::object_status.eq(excluded(objects::object_status)),
                         ))
+                        .filter(objects::version.lt(excluded(objects::version)))
                         .execute(conn)?;
                 }
+                guard.stop_and_record();
+
+                if self.objects_snapshot_interval > 0 {
+                    let guard = table_timer("objects_delta");
+                    self.persist_objects_delta(
+                        conn,
+                        checkpoint.sequence_number,
+                        objects_changes,
+                        dead_lettered > 0,
+                    )?;
+                    guard.stop_and_record();
+                }
 
                 // Only insert once for address, skip if conflict
+                let guard = table_timer("addresses");
                 diesel::insert_into(addresses::table)
                     .values(addresses)
                     .on_conflict(account_address)
                     .do_nothing()
                     .execute(conn)?;
+                guard.stop_and_record();
 
+                let guard = table_timer("packages");
                 diesel::insert_into(packages::table)
                     .values(packages)
                     // We need to keep multiple version of the object in the database because of package upgrade.
                     // Package with the same version number will not change, ignoring conflicts.
                     .on_conflict_do_nothing()
                     .execute(conn)?;
+                guard.stop_and_record();
 
+                let guard = table_timer("move_calls");
                 diesel::insert_into(move_calls::table)
                     .values(move_calls)
                     .execute(conn)?;
+                guard.stop_and_record();
 
+                let guard = table_timer("recipients");
                 diesel::insert_into(recipients::table)
                     .values(recipients)
+                    .execute(conn)?;
+                guard.stop_and_record();
+
+                let guard = table_timer("shared_object_usage");
+                diesel::insert_into(shared_object_usage::table)
+                    .values(shared_object_usage)
+                    .execute(conn)?;
+                guard.stop_and_record();
+
+                let guard = table_timer("transaction_errors");
+                diesel::insert_into(transaction_errors::table)
+                    .values(transaction_errors)
+                    .execute(conn)?;
+                guard.stop_and_record();
+
+                // Added to the activity of the previous checkpoints of the epoch
+                let guard = table_timer("address_activity");
+                diesel::insert_into(address_activity::table)
+                    .values(address_activity)
+                    .on_conflict((address_activity::address, address_activity::epoch))
+                    .do_update()
+                    .set(
+                        (
+                            address_activity::tx_count
+                                .eq(address_activity::tx_count
+                                    + excluded(address_activity::tx_count)),
+                            address_activity::gas_spent
+                                .eq(address_activity::gas_spent
+                                    + excluded(address_activity::gas_spent)),
+                        ),
+                    )
+                    .execute(conn)?;
+                guard.stop_and_record();
+
+                let guard = table_timer("dynamic_fields");
+                diesel::insert_into(dynamic_fields::table)
+                    .values(dynamic_fields)
+                    .on_conflict(dynamic_fields::object_id)
+                    .do_update()
+                    .set((
+                        dynamic_fields::epoch.eq(excluded(dynamic_fields::epoch)),
+                        dynamic_fields::checkpoint.eq(excluded(dynamic_fields::checkpoint)),
+                        dynamic_fields::version.eq(excluded(dynamic_fields::version)),
+                        dynamic_fields::object_digest.eq(excluded(dynamic_fields::object_digest)),
+                        dynamic_fields::value_type.eq(excluded(dynamic_fields::value_type)),
+                        dynamic_fields::value_object_id
+                            .eq(excluded(dynamic_fields::value_object_id)),
+                    ))
+                    .filter(dynamic_fields::version.lt(excluded(dynamic_fields::version)))
+                    .execute(conn)?;
+                let result = diesel::delete(
+                    dynamic_fields::table
+                        .filter(dynamic_fields::object_id.eq_any(deleted_dynamic_fields))
+                        .filter(dynamic_fields::checkpoint.le(checkpoint.sequence_number)),
+                )
+                .execute(conn);
+                guard.stop_and_record();
+                result
+            })
+            .map_err(|e| {
+                IndexerError::db(
+                    format!("writing checkpoint {}", checkpoint.sequence_number),
+                    e,
+                )
+            });
+        // Drop the rows cached before the checkpoint was reindexed, e.g. when it is repaired
+        if let Some(cache) = &self.read_cache {
+            cache.invalidate_checkpoint(checkpoint, transactions);
+        }
+        persisted
+    }
+
+    fn persist_dead_letter_checkpoint(
+        &self,
+        checkpoint: &Checkpoint,
+        dead_letter: &DeadLetterCheckpoint,
+    ) -> Result<usize, IndexerError> {
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
+        pg_pool_conn
+            .build_transaction()
+            .serializable()
+            .read_write()
+            .run(|conn| {
+                diesel::insert_into(checkpoints_table)
+                    .values(checkpoint)
+                    .on_conflict(checkpoints::sequence_number)
+                    .do_nothing()
+                    .execute(conn)?;
+                diesel::insert_into(dead_letter_checkpoints::table)
+                    .values(dead_letter)
+                    .on_conflict(dead_letter_checkpoints::sequence_number)
+                    .do_update()
+                    .set((
+                        dead_letter_checkpoints::error_type
+                            .eq(excluded(dead_letter_checkpoints::error_type)),
+                        dead_letter_checkpoints::error.eq(excluded(dead_letter_checkpoints::error)),
+                        dead_letter_checkpoints::attempts.eq(dead_letter_checkpoints::attempts
+                            + excluded(dead_letter_checkpoints::attempts)),
+                        dead_letter_checkpoints::last_failed_at
+                            .eq(excluded(dead_letter_checkpoints::last_failed_at)),
+                    ))
                     .execute(conn)
             })
             .map_err(|e| {
-                IndexerError::PostgresWriteError(format!(
-                    "Failed writing checkpoint to PostgresDB with transactions {:?} and error: {:?}",
-                    transactions, e
-                ))
+                IndexerError::db(
+                    format!(
+                        "writing dead letter checkpoint {}",
+                        dead_letter.sequence_number
+                    ),
+                    e,
+                )
+            })
+    }
+
+    fn delete_checkpoint_data(&self, checkpoint: i64) -> Result<usize, IndexerError> {
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
+        pg_pool_conn
+            .build_transaction()
+            .serializable()
+            .read_write()
+            .run(|conn| {
+                let mut deleted =
+                    diesel::delete(events::table.filter(events::checkpoint.eq(checkpoint)))
+                        .execute(conn)?;
+                deleted += diesel::delete(
+                    move_calls::table.filter(move_calls::checkpoint_sequence_number.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    recipients::table.filter(recipients::checkpoint_sequence_number.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    shared_object_usage::table
+                        .filter(shared_object_usage::checkpoint_sequence_number.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    transaction_errors::table
+                        .filter(transaction_errors::checkpoint_sequence_number.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    objects_delta::table.filter(objects_delta::checkpoint.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                // The activity is aggregated over the epoch, only the checkpoint's share is removed.
+                diesel::sql_query(REVERT_ADDRESS_ACTIVITY_SQL)
+                    .bind::<BigInt, _>(checkpoint)
+                    .execute(conn)?;
+                deleted += diesel::delete(
+                    address_activity::table
+                        .filter(
+                            address_activity::epoch.eq_any(
+                                checkpoints_table
+                                    .select(checkpoints::epoch)
+                                    .filter(sequence_number.eq(checkpoint)),
+                            ),
+                        )
+                        .filter(address_activity::tx_count.eq(0)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    dsl::transactions.filter(dsl::checkpoint_sequence_number.eq(checkpoint)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(checkpoints_table.filter(sequence_number.eq(checkpoint)))
+                    .execute(conn)?;
+                Ok::<_, diesel::result::Error>(deleted)
             })
+            .map_err(|e| IndexerError::db(format!("deleting data of checkpoint {}", checkpoint), e))
+    }
+
+    fn get_dead_letter_checkpoints(&self) -> Result<Vec<DeadLetterCheckpoint>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            dead_letter_checkpoints::table
+                .order(dead_letter_checkpoints::sequence_number.asc())
+                .load::<DeadLetterCheckpoint>(conn)
+        })?
+        .map_err(|e| IndexerError::db("reading dead letter checkpoints", e))
     }
 
-    fn persist_epoch(&self, _data: &TemporaryEpochStore) -> Result<usize, IndexerError> {
-        // TODO: create new partition on epoch change
-        self.partition_manager.advance_epoch(1)
+    fn persist_epoch(&self, data: &TemporaryEpochStore) -> Result<usize, IndexerError> {
+        self.persist_validator_epochs(&data.validator_epochs)?;
+        self.partition_manager.advance_epoch(data.new_epoch)
+    }
+
+    fn persist_validator_epochs(
+        &self,
+        validator_epochs: &[ValidatorEpoch],
+    ) -> Result<usize, IndexerError> {
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
+        pg_pool_conn
+            .build_transaction()
+            .serializable()
+            .read_write()
+            .run(|conn| {
+                diesel::insert_into(validator_epochs::table)
+                    .values(validator_epochs)
+                    .on_conflict((validator_epochs::epoch, validator_epochs::validator_address))
+                    .do_nothing()
+                    .execute(conn)
+            })
+            .map_err(|e| IndexerError::db("writing validator epochs", e))
+    }
+
+    fn get_validator_epochs(
+        &self,
+        validator_address: &str,
+        limit: usize,
+    ) -> Result<Vec<ValidatorEpoch>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            validator_epochs::table
+                .filter(validator_epochs::validator_address.eq(validator_address))
+                .order(validator_epochs::epoch.desc())
+                .limit(limit as i64)
+                .load::<ValidatorEpoch>(conn)
+        })?
+        .map_err(|e| {
+            IndexerError::db(
+                format!(
+                    "reading epochs of validator {} with limit {}",
+                    validator_address, limit
+                ),
+                e,
+            )
+        })
+    }
+
+    fn get_validators_apy(&self, epochs: usize) -> Result<Vec<ValidatorApy>, IndexerError> {
+        let records = self
+            .read_only_transaction(|conn| {
+                let latest_epoch = validator_epochs::table
+                    .select(max(validator_epochs::epoch))
+                    .first::<Option<i64>>(conn)?;
+                let Some(latest_epoch) = latest_epoch else {
+                    return Ok(vec![]);
+                };
+                validator_epochs::table
+                    .filter(validator_epochs::epoch.ge(latest_epoch - epochs as i64))
+                    .load::<ValidatorEpoch>(conn)
+            })?
+            .map_err(|e| {
+                IndexerError::db(
+                    format!("reading validator epochs of the last {} epochs", epochs),
+                    e,
+                )
+            })?;
+
+        let mut records_by_validator: BTreeMap<String, Vec<ValidatorEpoch>> = BTreeMap::new();
+        for record in records {
+            records_by_validator
+                .entry(record.validator_address.clone())
+                .or_default()
+                .push(record);
+        }
+        Ok(records_by_validator
+            .into_iter()
+            .filter_map(|(validator_address, records)| {
+                estimate_apy(&records).map(|apy| ValidatorApy {
+                    validator_address,
+                    apy,
+                })
+            })
+            .collect())
+    }
+
+    fn get_sink_watermark(&self, sink_name: &str) -> Result<Option<i64>, IndexerError> {
+        self.read_only_transaction(|conn| {
+            sink_watermarks::table
+                .select(sink_watermarks::checkpoint_sequence_number)
+                .filter(sink_watermarks::sink_name.eq(sink_name))
+                .first::<i64>(conn)
+                .optional()
+        })?
+        .map_err(|e| IndexerError::db(format!("reading watermark of sink {}", sink_name), e))
+    }
+
+    fn set_sink_watermark(&self, sink_name: &str, checkpoint: i64) -> Result<(), IndexerError> {
+        let mut pg_pool_conn = self.get_pg_pool_connection()?;
+        diesel::insert_into(sink_watermarks::table)
+            .values((
+                sink_watermarks::sink_name.eq(sink_name),
+                sink_watermarks::checkpoint_sequence_number.eq(checkpoint),
+            ))
+            .on_conflict(sink_watermarks::sink_name)
+            .do_update()
+            .set(sink_watermarks::checkpoint_sequence_number.eq(checkpoint))
+            .execute(&mut pg_pool_conn)
+            .map_err(|e| {
+                IndexerError::db(
+                    format!("writing watermark {} of sink {}", checkpoint, sink_name),
+                    e,
+                )
+            })?;
+        Ok(())
     }
 
     fn log_errors(&self, errors: Vec<IndexerError>) -> Result<(), IndexerError> {
         if !errors.is_empty() {
-            let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
+            for e in &errors {
+                self.metrics
+                    .total_error_logs
+                    .with_label_values(&[&e.name()])
+                    .inc();
+            }
+            let mut pg_pool_conn = self.get_pg_pool_connection()?;
             let new_error_logs = errors.into_iter().map(|e| e.into()).collect();
             if let Err(e) = commit_error_logs(&mut pg_pool_conn, new_error_logs) {
                 error!("Failed writing error logs with error {:?}", e);
@@ -594,13 +1978,25 @@ impl IndexerStore for PgIndexerStore {
 #[derive(Clone)]
 struct PartitionManager {
     cp: PgConnectionPool,
+    // Tables partitioned by epoch
     tables: Vec<String>,
+    // Last partition of each table of CHECKPOINT_PARTITIONED_TABLES
+    checkpoint_partitions: Arc<Mutex<BTreeMap<String, i64>>>,
+    // Last partition of each table partitioned by epoch known to exist
+    epoch_partitions: Arc<Mutex<BTreeMap<String, i64>>>,
+    metrics: IndexerStoreMetrics,
 }
 
 impl PartitionManager {
-    fn new(cp: PgConnectionPool) -> Result<Self, IndexerError> {
+    fn new(cp: PgConnectionPool, metrics: IndexerStoreMetrics) -> Result<Self, IndexerError> {
         // Find all tables with partition
-        let mut manager = Self { cp, tables: vec![] };
+        let mut manager = Self {
+            cp,
+            tables: vec![],
+            checkpoint_partitions: Default::default(),
+            epoch_partitions: Default::default(),
+            metrics,
+        };
         let tables = manager.get_table_partitions()?;
         info!(
             "Found {} tables with partitions : [{:?}]",
@@ -608,23 +2004,132 @@ impl PartitionManager {
             tables
         );
         for (table, _) in tables {
-            manager.tables.push(table)
+            if CHECKPOINT_PARTITIONED_TABLES
+                .iter()
+                .all(|(checkpoint_table, _)| *checkpoint_table != table)
+            {
+                manager.tables.push(table)
+            }
+        }
+        let mut checkpoint_partitions = BTreeMap::new();
+        for (table, _) in CHECKPOINT_PARTITIONED_TABLES {
+            if let Some(last_partition) = manager.get_last_partition(table)? {
+                checkpoint_partitions.insert(table.to_string(), last_partition);
+            }
         }
+        manager.checkpoint_partitions = Arc::new(Mutex::new(checkpoint_partitions));
+        // Partitions may be missing if the indexer stopped during an epoch change, or if the
+        // database was migrated after it was created.
+        let latest_epoch = manager.get_latest_epoch()?.unwrap_or_default();
+        manager.advance_epoch(latest_epoch as EpochId)?;
         Ok(manager)
     }
-    fn advance_epoch(&self, next_epoch_id: EpochId) -> Result<usize, IndexerError> {
+
+    /// Creates the missing partitions of the tables partitioned by checkpoint, up to the one
+    /// following the partition of `checkpoint` so that it is ready when the indexer reaches it.
+    fn advance_checkpoint(&self, checkpoint: i64) -> Result<usize, IndexerError> {
+        let mut checkpoint_partitions = self
+            .checkpoint_partitions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let mut created = 0;
+        for (table, size) in CHECKPOINT_PARTITIONED_TABLES {
+            let next_partition = checkpoint / size + 1;
+            let first_partition = match checkpoint_partitions.get(*table) {
+                Some(last_partition) if *last_partition >= next_partition => continue,
+                Some(last_partition) => last_partition + 1,
+                None => next_partition - 1,
+            };
+            let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
+            for partition in first_partition..=next_partition {
+                let sql = format!(
+                    "CREATE TABLE IF NOT EXISTS {table}_partition_{partition} PARTITION OF {table} FOR VALUES FROM ({}) TO ({});",
+                    partition * size,
+                    (partition + 1) * size
+                );
+                diesel::sql_query(sql)
+                    .execute(&mut pg_pool_conn)
+                    .map_err(|e| {
+                        IndexerError::db(
+                            format!("creating partition {partition} of table {table}"),
+                            e,
+                        )
+                    })?;
+                self.metrics
+                    .total_partitions_created
+                    .with_label_values(&[table])
+                    .inc();
+                created += 1;
+            }
+            info!("Created partitions of table {table} up to {next_partition}");
+            checkpoint_partitions.insert(table.to_string(), next_partition);
+        }
+        Ok(created)
+    }
+
+    /// Creates the missing partitions of the tables partitioned by epoch, up to the one of the
+    /// epoch following `epoch` so that it is ready when the epoch rolls over.
+    fn advance_epoch(&self, epoch: EpochId) -> Result<usize, IndexerError> {
+        let mut epoch_partitions = self
+            .epoch_partitions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let next_partition = epoch as i64 + 1;
+        let mut created = 0;
+        for table in &self.tables {
+            let first_partition = match epoch_partitions.get(table) {
+                Some(last_partition) if *last_partition >= next_partition => continue,
+                Some(last_partition) => last_partition + 1,
+                None => next_partition - 1,
+            };
+            let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
+            for partition in first_partition..=next_partition {
+                let sql = format!(
+                    "CREATE TABLE IF NOT EXISTS {table}_partition_{partition} PARTITION OF {table} FOR VALUES FROM ({partition}) TO ({});",
+                    partition + 1
+                );
+                diesel::sql_query(sql)
+                    .execute(&mut pg_pool_conn)
+                    .map_err(|e| {
+                        IndexerError::db(
+                            format!("creating partition {partition} of table {table}"),
+                            e,
+                        )
+                    })?;
+                self.metrics
+                    .total_partitions_created
+                    .with_label_values(&[table])
+                    .inc();
+                created += 1;
+            }
+            info!("Created partitions of table {table} up to epoch {next_partition}");
+            epoch_partitions.insert(table.clone(), next_partition);
+        }
+        Ok(created)
+    }
+
+    fn get_latest_epoch(&self) -> Result<Option<i64>, IndexerError> {
         let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
-        pg_pool_conn
-            .build_transaction()
-            .read_write().serializable()
-            .run(|conn| {
-                for table in &self.tables {
-                    let sql = format!("CREATE TABLE {table}_partition_{next_epoch_id} PARTITION OF {table} FOR VALUES FROM ({next_epoch_id}) TO ({});", next_epoch_id+1);
-                    diesel::sql_query(sql).execute(conn)?;
-                }
-                Ok::<_, diesel::result::Error>(self.tables.len())
-            })
-            .map_err(|e| IndexerError::PostgresReadError(e.to_string()))
+        checkpoints::table
+            .select(max(checkpoints::epoch))
+            .first::<Option<i64>>(&mut pg_pool_conn)
+            .map_err(|e| IndexerError::db("reading latest indexed epoch", e))
+    }
+
+    fn get_last_partition(&self, table: &str) -> Result<Option<i64>, IndexerError> {
+        let mut pg_pool_conn = get_pg_pool_connection(&self.cp)?;
+
+        #[derive(QueryableByName, Debug, Clone)]
+        struct LastPartition {
+            #[diesel(sql_type = Nullable<BigInt>)]
+            last_partition: Option<i64>,
+        }
+
+        Ok(diesel::sql_query(GET_LAST_PARTITION_SQL)
+            .bind::<VarChar, _>(table)
+            .get_result::<LastPartition>(&mut pg_pool_conn)
+            .map_err(|e| IndexerError::db(format!("reading last partition of table {table}"), e))?
+            .last_partition)
     }
 
     fn get_table_partitions(&self) -> Result<BTreeMap<String, String>, IndexerError> {
@@ -642,7 +2147,7 @@ impl PartitionManager {
             .build_transaction()
             .read_only()
             .run(|conn| diesel::sql_query(GET_PARTITION_SQL).load(conn))
-            .map_err(|e| IndexerError::PostgresReadError(e.to_string()))?
+            .map_err(|e| IndexerError::db("reading table partitions", e))?
             .into_iter()
             .map(|table: PartitionedTable| (table.table_name, table.last_partition))
             .collect())
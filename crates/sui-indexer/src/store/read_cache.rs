@@ -0,0 +1,159 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::mem::size_of;
+
+use moka::sync::Cache;
+
+use crate::metrics::IndexerStoreMetrics;
+use crate::models::checkpoints::Checkpoint;
+use crate::models::transactions::Transaction;
+use crate::store::read_snapshot::pinned_snapshot_checkpoint;
+
+/// In-memory read-through cache of the rows of the store that never change once committed:
+/// transactions by digest and checkpoints by sequence number. Entries are evicted by size only.
+///
+/// A cached row is only served to a request pinned to a read snapshot if the snapshot includes
+/// its checkpoint, so that the reads of a request stay consistent.
+#[derive(Clone)]
+pub(crate) struct ReadCache {
+    transactions: Cache<String, Transaction>,
+    checkpoints: Cache<i64, Checkpoint>,
+    metrics: IndexerStoreMetrics,
+}
+
+impl ReadCache {
+    /// A cache holding up to about `max_size` bytes of rows, split evenly between transactions and
+    /// checkpoints.
+    pub fn new(max_size: u64, metrics: IndexerStoreMetrics) -> Self {
+        Self {
+            transactions: Cache::builder()
+                .max_capacity(max_size / 2)
+                .weigher(|digest: &String, tx: &Transaction| {
+                    weight(digest.len() + transaction_size(tx))
+                })
+                .build(),
+            checkpoints: Cache::builder()
+                .max_capacity(max_size / 2)
+                .weigher(|_, checkpoint: &Checkpoint| weight(checkpoint_size(checkpoint)))
+                .build(),
+            metrics,
+        }
+    }
+
+    /// The transaction with digest `digest`, read from `read` on a miss.
+    pub fn get_transaction<E>(
+        &self,
+        digest: &str,
+        read: impl FnOnce() -> Result<Transaction, E>,
+    ) -> Result<Transaction, E> {
+        let cached = self
+            .transactions
+            .get(digest)
+            .filter(|tx| is_visible(tx.checkpoint_sequence_number));
+        self.record("transactions", cached.is_some());
+        if let Some(tx) = cached {
+            return Ok(tx);
+        }
+        let tx = read()?;
+        self.transactions.insert(digest.to_string(), tx.clone());
+        Ok(tx)
+    }
+
+    /// The checkpoint with sequence number `sequence_number`, read from `read` on a miss.
+    pub fn get_checkpoint<E>(
+        &self,
+        sequence_number: i64,
+        read: impl FnOnce() -> Result<Checkpoint, E>,
+    ) -> Result<Checkpoint, E> {
+        let cached = self
+            .checkpoints
+            .get(&sequence_number)
+            .filter(|checkpoint| is_visible(checkpoint.sequence_number));
+        self.record("checkpoints", cached.is_some());
+        if let Some(checkpoint) = cached {
+            return Ok(checkpoint);
+        }
+        let checkpoint = read()?;
+        self.checkpoints.insert(sequence_number, checkpoint.clone());
+        Ok(checkpoint)
+    }
+
+    /// Drops the cached rows of a checkpoint being (re)written.
+    pub fn invalidate_checkpoint(&self, checkpoint: &Checkpoint, transactions: &[Transaction]) {
+        self.checkpoints.invalidate(&checkpoint.sequence_number);
+        for tx in transactions {
+            self.transactions.invalidate(&tx.transaction_digest);
+        }
+    }
+
+    fn record(&self, table: &str, hit: bool) {
+        let counter = if hit {
+            &self.metrics.read_cache_hits
+        } else {
+            &self.metrics.read_cache_misses
+        };
+        counter.with_label_values(&[table]).inc();
+    }
+}
+
+/// Whether the rows of checkpoint `checkpoint` are visible to the snapshot pinned by the current
+/// request, if any.
+fn is_visible(checkpoint: i64) -> bool {
+    pinned_snapshot_checkpoint().map_or(true, |pinned| checkpoint <= pinned)
+}
+
+fn weight(size: usize) -> u32 {
+    size.try_into().unwrap_or(u32::MAX)
+}
+
+fn transaction_size(tx: &Transaction) -> usize {
+    let strings = [
+        &tx.transaction_digest,
+        &tx.sender,
+        &tx.gas_object_id,
+        &tx.gas_object_digest,
+        &tx.transaction_content,
+        &tx.transaction_effects_content,
+    ]
+    .into_iter()
+    .map(String::len)
+    .sum::<usize>();
+    let vecs = [
+        &tx.recipients,
+        &tx.transaction_kinds,
+        &tx.created,
+        &tx.mutated,
+        &tx.deleted,
+        &tx.unwrapped,
+        &tx.wrapped,
+        &tx.move_calls,
+    ]
+    .into_iter()
+    .map(|strings| optional_strings_size(strings))
+    .sum::<usize>();
+    size_of::<Transaction>() + strings + vecs
+}
+
+fn checkpoint_size(checkpoint: &Checkpoint) -> usize {
+    let optional = [
+        &checkpoint.previous_checkpoint_digest,
+        &checkpoint.next_epoch_committee,
+        &checkpoint.end_of_epoch_data,
+    ]
+    .into_iter()
+    .flatten()
+    .map(String::len)
+    .sum::<usize>();
+    size_of::<Checkpoint>()
+        + checkpoint.checkpoint_digest.len()
+        + optional
+        + optional_strings_size(&checkpoint.transactions)
+}
+
+fn optional_strings_size(strings: &[Option<String>]) -> usize {
+    strings
+        .iter()
+        .map(|s| size_of::<Option<String>>() + s.as_ref().map_or(0, String::len))
+        .sum()
+}
@@ -0,0 +1,80 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use diesel::connection::{AnsiTransactionManager, TransactionManager};
+use diesel::dsl::max;
+use diesel::prelude::*;
+use tracing::warn;
+
+use crate::errors::IndexerError;
+use crate::schema::checkpoints::dsl::{checkpoints as checkpoints_table, sequence_number};
+use crate::PgPoolConnection;
+
+tokio::task_local! {
+    /// Connection and checkpoint of the snapshot the reads of the current request are pinned to.
+    static PINNED_SNAPSHOT: (Arc<Mutex<PgPoolConnection>>, i64);
+}
+
+/// A repeatable read transaction held open for the duration of a request, so that all the reads
+/// of the request see the store as of the same checkpoint. The transaction is rolled back when
+/// the snapshot is dropped.
+pub struct ReadSnapshot {
+    conn: Arc<Mutex<PgPoolConnection>>,
+    checkpoint: i64,
+}
+
+impl ReadSnapshot {
+    pub(crate) fn begin(mut conn: PgPoolConnection) -> Result<Self, IndexerError> {
+        AnsiTransactionManager::begin_transaction_sql(
+            &mut *conn,
+            "BEGIN TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY",
+        )
+        .map_err(|e| IndexerError::db("beginning read snapshot", e))?;
+        // Dropping the snapshot rolls back the transaction if reading the checkpoint fails.
+        let mut snapshot = Self {
+            conn: Arc::new(Mutex::new(conn)),
+            checkpoint: -1,
+        };
+        snapshot.checkpoint = checkpoints_table
+            .select(max(sequence_number))
+            .first::<Option<i64>>(&mut **snapshot.conn.lock().unwrap())
+            // -1 to differentiate between no checkpoints and the first checkpoint
+            .map(|o| o.unwrap_or(-1))
+            .map_err(|e| IndexerError::db("reading checkpoint of read snapshot", e))?;
+        Ok(snapshot)
+    }
+
+    /// Latest checkpoint visible in the snapshot.
+    pub fn checkpoint(&self) -> i64 {
+        self.checkpoint
+    }
+
+    /// Runs `f` with all reads of the store pinned to this snapshot.
+    pub async fn run<F: Future>(&self, f: F) -> F::Output {
+        PINNED_SNAPSHOT
+            .scope((self.conn.clone(), self.checkpoint), f)
+            .await
+    }
+}
+
+impl Drop for ReadSnapshot {
+    fn drop(&mut self) {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = AnsiTransactionManager::rollback_transaction(&mut **conn) {
+            warn!("Failed rolling back read snapshot with error {:?}", e);
+        }
+    }
+}
+
+/// Connection of the snapshot pinned by the current request, if any.
+pub(crate) fn pinned_snapshot() -> Option<Arc<Mutex<PgPoolConnection>>> {
+    PINNED_SNAPSHOT.try_with(|(conn, _)| conn.clone()).ok()
+}
+
+/// Latest checkpoint visible in the snapshot pinned by the current request, if any.
+pub(crate) fn pinned_snapshot_checkpoint() -> Option<i64> {
+    PINNED_SNAPSHOT.try_with(|(_, checkpoint)| *checkpoint).ok()
+}
@@ -1,18 +1,83 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use prometheus::Registry;
+use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use sui_indexer::errors::IndexerError;
+use sui_indexer::models::address_activity::{ActiveAddressCount, AddressActivity};
+use sui_indexer::models::addresses::Address;
 use sui_indexer::models::checkpoints::Checkpoint;
-use sui_indexer::models::objects::Object;
-use sui_indexer::models::transactions::Transaction;
-use sui_indexer::store::{IndexerStore, TemporaryCheckpointStore, TemporaryEpochStore};
-use sui_indexer::Indexer;
-use sui_json_rpc_types::CheckpointId;
+use sui_indexer::models::dead_letter_checkpoints::DeadLetterCheckpoint;
+use sui_indexer::models::dynamic_fields::DynamicField;
+use sui_indexer::models::events::Event;
+use sui_indexer::models::objects::{Object, ObjectHistory, OwnedObject};
+use sui_indexer::models::recipients::Recipient;
+use sui_indexer::models::shared_object_usage::SharedObjectStats;
+use sui_indexer::models::transaction_errors::{ErrorKindStats, FailingFunctionStats};
+use sui_indexer::models::transactions::{Transaction, TransactionSummary};
+use sui_indexer::models::validator_epochs::{ValidatorApy, ValidatorEpoch};
+use sui_indexer::store::{
+    CheckpointRangeData, IndexerStore, ReadSnapshot, StoreCursor, TemporaryCheckpointStore,
+    TemporaryEpochStore, TransactionPageFilter,
+};
+use sui_indexer::{Indexer, InterestList};
+use sui_json_rpc_types::{CheckpointId, SuiMoveNormalizedModule};
+use sui_types::base_types::SuiAddress;
 use test_utils::network::TestClusterBuilder;
 
+#[test]
+fn test_error_classification() {
+    let db_error = |kind| DieselError::DatabaseError(kind, Box::new("error".to_string()));
+    let error = IndexerError::db(
+        "writing checkpoint 1",
+        db_error(DatabaseErrorKind::SerializationFailure),
+    );
+    assert!(matches!(error, IndexerError::TransientDbError { .. }));
+    assert!(error.is_retryable());
+    assert_eq!(
+        error.to_string(),
+        "Indexer failed writing checkpoint 1 with transient PostgresDB error: `error`"
+    );
+
+    let error = IndexerError::db(
+        "writing checkpoint 1",
+        db_error(DatabaseErrorKind::UniqueViolation),
+    );
+    assert!(matches!(error, IndexerError::ConstraintViolation { .. }));
+    assert!(!error.is_retryable());
+
+    let error = IndexerError::db("reading checkpoint 1", DieselError::NotFound);
+    assert!(matches!(error, IndexerError::DbError { .. }));
+    assert!(!error.is_retryable());
+
+    assert!(!IndexerError::DataInconsistency("missing object".to_string()).is_retryable());
+}
+
+#[test]
+fn test_store_cursor() {
+    let cursor = StoreCursor {
+        checkpoint: 1_000_000,
+        transaction_index: 12,
+        sub_index: 3,
+    };
+    assert_eq!(cursor, cursor.to_string().parse().unwrap());
+    // Cursors are opaque, ids and malformed strings are rejected.
+    assert!(matches!(
+        "12".parse::<StoreCursor>(),
+        Err(IndexerError::InvalidCursor(_))
+    ));
+    assert!("not a cursor!".parse::<StoreCursor>().is_err());
+
+    // Positions are ordered by checkpoint, then transaction, then row in the transaction.
+    let cursor = StoreCursor::transaction(10, 2);
+    assert!(cursor.predecessor() < cursor && cursor < cursor.successor());
+    assert!(cursor.successor() < StoreCursor::transaction(10, 3));
+    assert!(StoreCursor::transaction(9, 100) < cursor.predecessor());
+}
+
 #[tokio::test]
 async fn test_genesis() {
     let test_cluster = TestClusterBuilder::new().build().await.unwrap();
@@ -20,7 +85,13 @@ async fn test_genesis() {
 
     let s = store.clone();
     let _handle = tokio::task::spawn(async move {
-        Indexer::start(test_cluster.rpc_url(), &Registry::default(), s).await
+        Indexer::start(
+            test_cluster.rpc_url(),
+            &Registry::default(),
+            s,
+            InterestList::default(),
+        )
+        .await
     });
 
     // Allow indexer to process the data
@@ -34,6 +105,34 @@ async fn test_genesis() {
     assert!(!store.tables.read().unwrap().objects.is_empty());
 }
 
+#[tokio::test]
+async fn test_filtered_indexing() {
+    let test_cluster = TestClusterBuilder::new().build().await.unwrap();
+    let store = InMemoryIndexerStore::new();
+
+    // No transaction touches a random address, only the checkpoints are indexed.
+    let interest_list = InterestList::new([], [SuiAddress::random_for_testing_only()]);
+    let s = store.clone();
+    let _handle = tokio::task::spawn(async move {
+        Indexer::start(
+            test_cluster.rpc_url(),
+            &Registry::default(),
+            s,
+            interest_list,
+        )
+        .await
+    });
+
+    for _ in 1..3 {
+        tokio::task::yield_now().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let tables = store.tables.read().unwrap();
+    assert!(!tables.checkpoints.is_empty());
+    assert!(tables.objects.is_empty());
+}
+
 #[derive(Clone)]
 struct InMemoryIndexerStore {
     tables: Arc<RwLock<Tables>>,
@@ -58,6 +157,10 @@ impl IndexerStore for InMemoryIndexerStore {
         Ok(self.tables.read().unwrap().checkpoints.len() as i64 - 1)
     }
 
+    fn begin_read_snapshot(&self) -> Result<ReadSnapshot, IndexerError> {
+        todo!()
+    }
+
     fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, IndexerError> {
         Ok(match id {
             CheckpointId::SequenceNumber(seq) => {
@@ -75,41 +178,25 @@ impl IndexerStore for InMemoryIndexerStore {
         })
     }
 
-    fn get_total_transaction_number(&self) -> Result<i64, IndexerError> {
-        todo!()
-    }
-
-    fn get_transaction_by_digest(&self, _txn_digest: &str) -> Result<Transaction, IndexerError> {
-        todo!()
-    }
-
-    fn get_transaction_sequence_by_digest(
+    fn get_checkpoint_range_data(
         &self,
-        _txn_digest: Option<String>,
-        _is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
+        _start: i64,
+        _end: i64,
+    ) -> Result<CheckpointRangeData, IndexerError> {
         todo!()
     }
 
-    fn get_recipient_sequence_by_digest(
-        &self,
-        _txn_digest: Option<String>,
-        _is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
+    fn get_total_transaction_number(&self) -> Result<i64, IndexerError> {
         todo!()
     }
 
-    fn get_move_call_sequence_by_digest(
-        &self,
-        _txn_digest: Option<String>,
-        _is_descending: bool,
-    ) -> Result<Option<i64>, IndexerError> {
+    fn get_transaction_by_digest(&self, _txn_digest: &str) -> Result<Transaction, IndexerError> {
         todo!()
     }
 
     fn get_all_transaction_digest_page(
         &self,
-        _start_sequence: Option<i64>,
+        _cursor: Option<StoreCursor>,
         _limit: usize,
         _is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
@@ -119,7 +206,7 @@ impl IndexerStore for InMemoryIndexerStore {
     fn get_transaction_digest_page_by_mutated_object(
         &self,
         _object_id: String,
-        _start_sequence: Option<i64>,
+        _cursor: Option<StoreCursor>,
         _limit: usize,
         _is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
@@ -129,7 +216,7 @@ impl IndexerStore for InMemoryIndexerStore {
     fn get_transaction_digest_page_by_sender_address(
         &self,
         _sender_address: String,
-        _start_sequence: Option<i64>,
+        _cursor: Option<StoreCursor>,
         _limit: usize,
         _is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
@@ -141,7 +228,7 @@ impl IndexerStore for InMemoryIndexerStore {
         _package: String,
         _module: Option<String>,
         _function: Option<String>,
-        _start_sequence: Option<i64>,
+        _cursor: Option<StoreCursor>,
         _limit: usize,
         _is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
@@ -151,13 +238,216 @@ impl IndexerStore for InMemoryIndexerStore {
     fn get_transaction_digest_page_by_recipient_address(
         &self,
         _recipient_address: String,
-        _start_sequence: Option<i64>,
+        _cursor: Option<StoreCursor>,
         _limit: usize,
         _is_descending: bool,
     ) -> Result<Vec<String>, IndexerError> {
         todo!()
     }
 
+    fn get_transaction_cursor(&self, _txn_digest: &str) -> Result<StoreCursor, IndexerError> {
+        todo!()
+    }
+
+    fn get_events_by_sender(
+        &self,
+        _sender: String,
+        _cursor: Option<StoreCursor>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        todo!()
+    }
+
+    fn get_events_by_package(
+        &self,
+        _package: String,
+        _cursor: Option<StoreCursor>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        todo!()
+    }
+
+    fn get_events_by_module(
+        &self,
+        _package: String,
+        _module: String,
+        _cursor: Option<StoreCursor>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        todo!()
+    }
+
+    fn get_events_by_move_event_type(
+        &self,
+        _move_event_type: String,
+        _cursor: Option<StoreCursor>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<Event>, IndexerError> {
+        todo!()
+    }
+
+    fn get_dynamic_fields(
+        &self,
+        _parent_object_id: String,
+        _cursor: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<(DynamicField, Option<Object>)>, IndexerError> {
+        todo!()
+    }
+
+    fn get_dynamic_field(
+        &self,
+        _parent_object_id: String,
+        _name_type: String,
+        _name_value: serde_json::Value,
+    ) -> Result<Option<DynamicField>, IndexerError> {
+        todo!()
+    }
+
+    fn get_object_history(
+        &self,
+        _object_id: &str,
+        _start_version: i64,
+        _limit: usize,
+    ) -> Result<Vec<ObjectHistory>, IndexerError> {
+        todo!()
+    }
+
+    fn get_incoming_payments(
+        &self,
+        _recipient: &str,
+        _start_id: Option<i64>,
+        _limit: usize,
+    ) -> Result<Vec<Recipient>, IndexerError> {
+        todo!()
+    }
+
+    fn get_package_abi(
+        &self,
+        _package_id: &str,
+    ) -> Result<Option<BTreeMap<String, SuiMoveNormalizedModule>>, IndexerError> {
+        todo!()
+    }
+
+    fn get_owned_objects_at_checkpoint(
+        &self,
+        _owner: &str,
+        _checkpoint: i64,
+    ) -> Result<Option<Vec<OwnedObject>>, IndexerError> {
+        todo!()
+    }
+
+    fn get_hot_shared_objects(
+        &self,
+        _window_ms: i64,
+        _limit: usize,
+    ) -> Result<Vec<SharedObjectStats>, IndexerError> {
+        todo!()
+    }
+
+    fn get_failing_functions(
+        &self,
+        _window_ms: i64,
+        _limit: usize,
+    ) -> Result<Vec<FailingFunctionStats>, IndexerError> {
+        todo!()
+    }
+
+    fn get_transaction_error_stats(
+        &self,
+        _window_ms: i64,
+    ) -> Result<Vec<ErrorKindStats>, IndexerError> {
+        todo!()
+    }
+
+    fn get_active_addresses(
+        &self,
+        _start_epoch: i64,
+        _end_epoch: i64,
+    ) -> Result<ActiveAddressCount, IndexerError> {
+        todo!()
+    }
+
+    fn get_address_activity(
+        &self,
+        _address: &str,
+        _start_epoch: i64,
+        _end_epoch: i64,
+    ) -> Result<Vec<AddressActivity>, IndexerError> {
+        todo!()
+    }
+
+    fn get_transaction_sequence_page(
+        &self,
+        _filter: &TransactionPageFilter,
+        _cursor: Option<StoreCursor>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<(StoreCursor, i64, String)>, IndexerError> {
+        todo!()
+    }
+
+    fn multi_get_transaction_summaries(
+        &self,
+        _sequence_numbers: &[i64],
+    ) -> Result<Vec<TransactionSummary>, IndexerError> {
+        todo!()
+    }
+
+    fn multi_get_transactions(
+        &self,
+        _sequence_numbers: &[i64],
+    ) -> Result<Vec<Transaction>, IndexerError> {
+        todo!()
+    }
+
+    fn get_checkpoint_page(
+        &self,
+        _cursor: Option<i64>,
+        _limit: usize,
+        _is_descending: bool,
+    ) -> Result<Vec<Checkpoint>, IndexerError> {
+        todo!()
+    }
+
+    fn get_events_by_transaction(&self, _txn_digest: &str) -> Result<Vec<Event>, IndexerError> {
+        todo!()
+    }
+
+    fn get_object(&self, _object_id: &str) -> Result<Option<Object>, IndexerError> {
+        todo!()
+    }
+
+    fn get_owned_objects_page(
+        &self,
+        _owner: &str,
+        _cursor: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Object>, IndexerError> {
+        todo!()
+    }
+
+    fn get_objects_by_type_page(
+        &self,
+        _object_type: &str,
+        _cursor: Option<String>,
+        _limit: usize,
+    ) -> Result<Vec<Object>, IndexerError> {
+        todo!()
+    }
+
+    fn count_objects_by_type(&self, _object_type: &str) -> Result<i64, IndexerError> {
+        todo!()
+    }
+
+    fn get_address(&self, _address: &str) -> Result<Option<Address>, IndexerError> {
+        todo!()
+    }
+
     fn read_transactions(
         &self,
         _last_processed_id: i64,
@@ -181,10 +471,53 @@ impl IndexerStore for InMemoryIndexerStore {
         Ok(0)
     }
 
+    fn persist_dead_letter_checkpoint(
+        &self,
+        _checkpoint: &Checkpoint,
+        _dead_letter: &DeadLetterCheckpoint,
+    ) -> Result<usize, IndexerError> {
+        todo!()
+    }
+
+    fn get_dead_letter_checkpoints(&self) -> Result<Vec<DeadLetterCheckpoint>, IndexerError> {
+        todo!()
+    }
+
+    fn delete_checkpoint_data(&self, _sequence_number: i64) -> Result<usize, IndexerError> {
+        todo!()
+    }
+
     fn persist_epoch(&self, _data: &TemporaryEpochStore) -> Result<usize, IndexerError> {
         todo!()
     }
 
+    fn persist_validator_epochs(
+        &self,
+        _validator_epochs: &[ValidatorEpoch],
+    ) -> Result<usize, IndexerError> {
+        todo!()
+    }
+
+    fn get_validator_epochs(
+        &self,
+        _validator_address: &str,
+        _limit: usize,
+    ) -> Result<Vec<ValidatorEpoch>, IndexerError> {
+        todo!()
+    }
+
+    fn get_validators_apy(&self, _epochs: usize) -> Result<Vec<ValidatorApy>, IndexerError> {
+        todo!()
+    }
+
+    fn get_sink_watermark(&self, _sink_name: &str) -> Result<Option<i64>, IndexerError> {
+        todo!()
+    }
+
+    fn set_sink_watermark(&self, _sink_name: &str, _checkpoint: i64) -> Result<(), IndexerError> {
+        todo!()
+    }
+
     fn log_errors(&self, _errors: Vec<IndexerError>) -> Result<(), IndexerError> {
         todo!()
     }
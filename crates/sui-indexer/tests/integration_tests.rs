@@ -5,21 +5,26 @@
 #[cfg(feature = "pg_integration")]
 mod pg_integration {
     use diesel::migration::MigrationSource;
-    use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+    use diesel_migrations::MigrationHarness;
     use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
     use prometheus::Registry;
     use std::env;
     use std::str::FromStr;
     use sui_indexer::errors::IndexerError;
-    use sui_indexer::store::{IndexerStore, PgIndexerStore};
+    use sui_indexer::migrations::MIGRATIONS;
+    use sui_indexer::sink::{
+        checkpoint_records, CHECKPOINTS_TOPIC, EVENTS_TOPIC, TRANSACTIONS_TOPIC,
+    };
+    use sui_indexer::store::{IndexerStore, PgIndexerStore, StoreCursor};
     use sui_indexer::PgPoolConnection;
-    use sui_indexer::{new_pg_connection_pool, Indexer};
+    use sui_indexer::{new_pg_connection_pool, Indexer, InterestList};
+    use sui_json_rpc::api::ReadApiClient;
+    use sui_json_rpc_types::SuiTransactionResponseOptions;
+    use sui_types::base_types::ObjectType;
     use sui_types::digests::TransactionDigest;
+    use sui_types::gas_coin::GasCoin;
     use test_utils::network::{TestCluster, TestClusterBuilder};
     use tokio::task::JoinHandle;
-    const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
-    use sui_json_rpc::api::ReadApiClient;
-    use sui_json_rpc_types::SuiTransactionResponseOptions;
 
     #[tokio::test]
     async fn test_genesis_sync() {
@@ -52,6 +57,235 @@ mod pg_integration {
         drop(handle);
     }
 
+    #[tokio::test]
+    async fn test_read_cache() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+        let cached_store = store.clone().with_read_cache(1 << 20);
+
+        // Reads miss, then hit the cache
+        for _ in 0..2 {
+            let checkpoint = cached_store.get_checkpoint(0.into()).unwrap();
+            assert_eq!(
+                checkpoint.checkpoint_digest,
+                store.get_checkpoint(0.into()).unwrap().checkpoint_digest
+            );
+            for tx in checkpoint.transactions {
+                let tx = tx.unwrap();
+                assert_eq!(
+                    cached_store
+                        .get_transaction_by_digest(&tx)
+                        .unwrap()
+                        .transaction_content,
+                    store
+                        .get_transaction_by_digest(&tx)
+                        .unwrap()
+                        .transaction_content
+                );
+            }
+        }
+
+        // Cached checkpoints are not visible to the snapshots that do not include them
+        let snapshot = cached_store.begin_read_snapshot().unwrap();
+        let next_checkpoint = (snapshot.checkpoint() + 1) as u64;
+        wait_until_checkpoint(&store, next_checkpoint as i64).await;
+        assert!(cached_store.get_checkpoint(next_checkpoint.into()).is_ok());
+        let in_snapshot = snapshot
+            .run(async { cached_store.get_checkpoint(next_checkpoint.into()) })
+            .await;
+        assert!(in_snapshot.is_err());
+        drop(handle);
+    }
+
+    #[tokio::test]
+    async fn test_address_activity() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+
+        // The activity aggregates the transactions of every indexed checkpoint of the epoch
+        let snapshot = store.begin_read_snapshot().unwrap();
+        let latest_checkpoint = snapshot.checkpoint();
+        snapshot
+            .run(async {
+                let mut tx_count = 0;
+                for seq in 0..=latest_checkpoint {
+                    let checkpoint = store.get_checkpoint((seq as u64).into()).unwrap();
+                    assert_eq!(checkpoint.epoch, 0);
+                    tx_count += checkpoint.transactions.len() as i64;
+                }
+                let stats = store.get_active_addresses(0, 0).unwrap();
+                assert_eq!(stats.tx_count, tx_count);
+                assert!(stats.active_addresses >= 1);
+                assert_eq!(store.get_active_addresses(1, 1).unwrap().tx_count, 0);
+
+                let checkpoint = store.get_checkpoint(0.into()).unwrap();
+                let genesis = checkpoint.transactions[0].as_ref().unwrap();
+                let sender = store.get_transaction_by_digest(genesis).unwrap().sender;
+                let activity = store.get_address_activity(&sender, 0, i64::MAX).unwrap();
+                assert_eq!(activity.len(), 1);
+                assert_eq!(activity[0].epoch, 0);
+                assert!(activity[0].tx_count >= 1);
+            })
+            .await;
+        drop(handle);
+    }
+
+    #[tokio::test]
+    async fn test_objects_by_type() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+
+        let snapshot = store.begin_read_snapshot().unwrap();
+        snapshot
+            .run(async {
+                let coin_type = ObjectType::Struct(GasCoin::type_()).to_string();
+                let count = store.count_objects_by_type(&coin_type).unwrap();
+                assert!(count > 0);
+
+                // Paging through the objects of the type finds all of them
+                let mut cursor = None;
+                let mut objects = vec![];
+                loop {
+                    let page = store
+                        .get_objects_by_type_page(&coin_type, cursor, 10)
+                        .unwrap();
+                    cursor = page.last().map(|o| o.object_id.clone());
+                    let last_page = page.len() < 10;
+                    objects.extend(page);
+                    if last_page {
+                        break;
+                    }
+                }
+                assert_eq!(objects.len() as i64, count);
+                assert!(objects.iter().all(|o| o.object_type == coin_type));
+                assert_eq!(
+                    store.count_objects_by_type("0x2::unknown::Type").unwrap(),
+                    0
+                );
+            })
+            .await;
+        drop(handle);
+    }
+
+    #[tokio::test]
+    async fn test_sink_records() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+
+        let data = store.get_checkpoint_range_data(0, 1).unwrap();
+        let records = checkpoint_records(&data).unwrap();
+        let count = |topic| records.iter().filter(|r| r.topic == topic).count();
+        assert_eq!(count(CHECKPOINTS_TOPIC), data.checkpoints.len());
+        assert_eq!(count(TRANSACTIONS_TOPIC), data.transactions.len());
+        assert_eq!(count(EVENTS_TOPIC), data.events.len());
+
+        // Each checkpoint comes after its transactions and events, the last record closing the
+        // last checkpoint.
+        let last = records.last().unwrap();
+        assert_eq!(last.topic, CHECKPOINTS_TOPIC);
+        assert_eq!(last.key, "1");
+        let checkpoint: serde_json::Value = serde_json::from_slice(&last.payload).unwrap();
+        assert_eq!(checkpoint["sequenceNumber"], 1);
+        let genesis = data.transactions[0].transaction_digest.clone();
+        assert_eq!(records[0].topic, TRANSACTIONS_TOPIC);
+        assert_eq!(records[0].key, genesis);
+
+        // Watermarks are per sink, setting one overwrites the previous one.
+        assert_eq!(store.get_sink_watermark("kafka:sui").unwrap(), None);
+        store.set_sink_watermark("kafka:sui", 0).unwrap();
+        store.set_sink_watermark("kafka:sui", 1).unwrap();
+        assert_eq!(store.get_sink_watermark("kafka:sui").unwrap(), Some(1));
+        assert_eq!(store.get_sink_watermark("nats:sui").unwrap(), None);
+        drop(handle);
+    }
+
+    #[tokio::test]
+    async fn test_store_cursors() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+
+        // The genesis transaction is the first transaction of the first checkpoint, pages are
+        // read from the cursor included.
+        let genesis = store
+            .get_all_transaction_digest_page(None, 1, false)
+            .unwrap()[0]
+            .clone();
+        let cursor = store.get_transaction_cursor(&genesis).unwrap();
+        assert_eq!(cursor, StoreCursor::transaction(0, 0));
+        let page = store
+            .get_all_transaction_digest_page(Some(cursor), 1, true)
+            .unwrap();
+        assert_eq!(page, vec![genesis.clone()]);
+
+        // Events are positioned by their sequence number in the transaction.
+        let events = store.get_events_by_transaction(&genesis).unwrap();
+        let package = events.iter().find_map(|e| e.package_id.clone()).unwrap();
+        let page = store
+            .get_events_by_package(package.clone(), None, 3, false)
+            .unwrap();
+        assert!(page.len() > 1);
+        let cursor = StoreCursor {
+            checkpoint: page[1].checkpoint,
+            transaction_index: page[1].transaction_index,
+            sub_index: page[1].event_sequence,
+        };
+        let next = store
+            .get_events_by_package(package, Some(cursor), 2, false)
+            .unwrap();
+        assert_eq!(next[0].event_sequence, page[1].event_sequence);
+        assert!(next[0].event_sequence < next[1].event_sequence);
+        drop(handle);
+    }
+
+    #[cfg(feature = "graphql")]
+    #[tokio::test]
+    async fn test_graphql_checkpoints() {
+        let (_test_cluster, _indexer_rpc_client, store, handle) = start_test_cluster().await;
+        wait_until_checkpoint(&store, 1).await;
+        let schema = sui_indexer::graphql::build_schema(store.clone());
+
+        let response = schema
+            .execute(
+                "{ checkpoints(first: 1) { edges { cursor node { sequenceNumber } } pageInfo { hasNextPage } } }",
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let page = &data["checkpoints"];
+        assert_eq!(page["edges"][0]["node"]["sequenceNumber"], 0);
+        assert_eq!(page["pageInfo"]["hasNextPage"], true);
+
+        // The next page starts after the cursor.
+        let cursor = page["edges"][0]["cursor"].as_str().unwrap();
+        let response = schema
+            .execute(format!(
+                r#"{{ checkpoints(first: 1, after: "{cursor}") {{ edges {{ node {{ sequenceNumber }} }} }} }}"#
+            ))
+            .await;
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["checkpoints"]["edges"][0]["node"]["sequenceNumber"], 1);
+
+        // Transactions of the genesis checkpoint, with their effects.
+        let checkpoint = store.get_checkpoint(0.into()).unwrap();
+        let response = schema
+            .execute(
+                "{ checkpoint(sequenceNumber: 0) { digest transactions { edges { node { digest effects } } } } }",
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["checkpoint"]["digest"], checkpoint.checkpoint_digest);
+        let edges = data["checkpoint"]["transactions"]["edges"]
+            .as_array()
+            .unwrap();
+        assert_eq!(edges.len(), checkpoint.transactions.len());
+        for (edge, digest) in edges.iter().zip(checkpoint.transactions) {
+            assert_eq!(edge["node"]["digest"], digest.unwrap());
+            assert!(edge["node"]["effects"].is_object());
+        }
+        drop(handle);
+    }
+
     async fn start_test_cluster() -> (
         TestCluster,
         HttpClient,
@@ -66,14 +300,15 @@ mod pg_integration {
         reset_database(&mut pg_connection_pool.get().unwrap());
 
         let test_cluster = TestClusterBuilder::new().build().await.unwrap();
-        let store = PgIndexerStore::new(pg_connection_pool);
+        let registry = Registry::default();
+        let store = PgIndexerStore::new(pg_connection_pool, &registry);
 
         let store_clone = store.clone();
-        let registry = Registry::default();
 
         let rpc_url = test_cluster.rpc_url().to_string();
-        let handle =
-            tokio::spawn(async move { Indexer::start(&rpc_url, &registry, store_clone).await });
+        let handle = tokio::spawn(async move {
+            Indexer::start(&rpc_url, &registry, store_clone, InterestList::default()).await
+        });
 
         // TODO: make indexer port configurable
         let http_client = HttpClientBuilder::default()
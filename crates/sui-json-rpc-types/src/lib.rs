@@ -9,25 +9,35 @@ use sui_types::dynamic_field::DynamicFieldInfo;
 
 pub use sui_event::*;
 pub use sui_object::*;
+pub use sui_package_cache::*;
+pub use sui_rpc_error::*;
 pub use sui_transaction::*;
 
 pub use sui_bls::*;
+pub use sui_canonical::*;
 pub use sui_checkpoint::*;
 pub use sui_coin::*;
+pub use sui_extended::*;
 pub use sui_governance::*;
 pub use sui_move::*;
+pub use sui_node::*;
 
 #[cfg(test)]
 #[path = "unit_tests/rpc_types_tests.rs"]
 mod rpc_types_tests;
 
 mod sui_bls;
+mod sui_canonical;
 mod sui_checkpoint;
 mod sui_coin;
 mod sui_event;
+mod sui_extended;
 mod sui_governance;
 mod sui_move;
+mod sui_node;
 mod sui_object;
+mod sui_package_cache;
+mod sui_rpc_error;
 mod sui_transaction;
 
 pub type DynamicFieldPage = Page<DynamicFieldInfo, ObjectID>;
@@ -0,0 +1,92 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use crate::{
+    SuiEvent, SuiEventEnvelope, SuiTransactionEffects, SuiTransactionEvents, SuiTransactionResponse,
+};
+
+/// Deterministic JSON encoding of RPC types, so that responses can be hashed, attested and
+/// compared byte for byte across nodes.
+///
+/// The encoding has no insignificant whitespace, object keys are sorted by their UTF-8 bytes,
+/// and numbers are written in a single form: integers without fraction or exponent, other
+/// numbers in their shortest round-trip decimal form.
+pub trait CanonicalJson: Serialize {
+    fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        to_canonical_json(self)
+    }
+}
+
+impl CanonicalJson for SuiTransactionResponse {}
+impl CanonicalJson for SuiTransactionEffects {}
+impl CanonicalJson for SuiTransactionEvents {}
+impl CanonicalJson for SuiEventEnvelope {}
+impl CanonicalJson for SuiEvent {}
+
+/// Serializes `value` with the encoding described in [CanonicalJson].
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_canonical(&value, &mut out)?;
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut String) -> Result<(), serde_json::Error> {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => {
+            out.push_str(&serde_json::to_string(value)?)
+        }
+        Value::Number(n) => out.push_str(&canonical_number(n)),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            // The map keeps insertion order when serde_json's `preserve_order` is enabled.
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::to_string(key)?);
+                out.push(':');
+                write_canonical(value, out)?;
+            }
+            out.push('}');
+        }
+    }
+    Ok(())
+}
+
+fn canonical_number(n: &Number) -> String {
+    let repr = n.to_string();
+    // Integers are already written without fraction or exponent, and may exceed the f64 range
+    // when serde_json's `arbitrary_precision` is enabled.
+    if repr
+        .strip_prefix('-')
+        .unwrap_or(&repr)
+        .bytes()
+        .all(|b| b.is_ascii_digit())
+    {
+        return repr;
+    }
+    match n.as_f64() {
+        // Integral floats such as `1.0` are written as integers.
+        Some(f) if f.fract() == 0.0 && f >= i64::MIN as f64 && f <= i64::MAX as f64 => {
+            (f as i64).to_string()
+        }
+        Some(f) => f.to_string(),
+        None => repr,
+    }
+}
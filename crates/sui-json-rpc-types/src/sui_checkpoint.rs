@@ -1,18 +1,23 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use fastcrypto::encoding::Base64;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use sui_types::base_types::TransactionDigest;
 use sui_types::committee::EpochId;
+use sui_types::crypto::SuiAuthorityStrongQuorumSignInfo;
 use sui_types::digests::CheckpointDigest;
 use sui_types::gas::GasCostSummary;
 use sui_types::message_envelope::Message;
 use sui_types::messages_checkpoint::{
-    CheckpointCommitment, CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
+    self, CheckpointCommitment, CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
     CheckpointTimestamp, EndOfEpochData,
 };
 
+use crate::SuiCommittee;
+
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Checkpoint {
@@ -41,6 +46,9 @@ pub struct Checkpoint {
 
     /// Commitments to checkpoint state
     pub checkpoint_commitments: Vec<CheckpointCommitment>,
+    /// Whether the checkpoint was read from the archival store, as it was pruned from the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_archive: Option<bool>,
 }
 
 impl From<(CheckpointSummary, CheckpointContents)> for Checkpoint {
@@ -71,10 +79,49 @@ impl From<(CheckpointSummary, CheckpointContents)> for Checkpoint {
             // info (if they need it, they need to get signed BCS data anyway in order to trust
             // it).
             checkpoint_commitments: Default::default(),
+            from_archive: None,
         }
     }
 }
 
+/// A checkpoint along with the aggregated signature of the validators certifying it and the
+/// committee of its epoch, so that it can be archived and verified offline.
+#[serde_as]
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointAttestation {
+    pub checkpoint: Checkpoint,
+    /// Aggregated signature of the validators, with the bitmap of the signers indexed in the
+    /// committee.
+    pub auth_signature: SuiAuthorityStrongQuorumSignInfo,
+    pub committee: SuiCommittee,
+    /// BCS encoded [messages_checkpoint::CheckpointAttestation], the certified checkpoint summary
+    /// along with the committee, verifiable on its own.
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    pub attestation_bcs: Vec<u8>,
+}
+
+impl CheckpointAttestation {
+    pub fn new(
+        attestation: &messages_checkpoint::CheckpointAttestation,
+        contents: CheckpointContents,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            checkpoint: (attestation.summary.data().clone(), contents).into(),
+            auth_signature: attestation.summary.auth_sig().into(),
+            committee: attestation.committee.clone().into(),
+            attestation_bcs: bcs::to_bytes(attestation)?,
+        })
+    }
+
+    /// Decodes the BCS encoded attestation, to be checked with
+    /// [messages_checkpoint::CheckpointAttestation::verify].
+    pub fn attestation(&self) -> Result<messages_checkpoint::CheckpointAttestation, anyhow::Error> {
+        Ok(bcs::from_bytes(&self.attestation_bcs)?)
+    }
+}
+
 #[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CheckpointId {
@@ -5,8 +5,8 @@ use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
 
 use fastcrypto::encoding::Base64;
-use move_bytecode_utils::module_cache::GetModule;
 use move_core_types::identifier::Identifier;
+use move_core_types::value::MoveStruct;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -18,10 +18,10 @@ use sui_types::base_types::{
 use sui_types::event::{BalanceChangeType, Event, EventEnvelope, EventID, EventType};
 use sui_types::filter::EventFilter;
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
-use sui_types::object::Owner;
+use sui_types::object::{ObjectFormatOptions, Owner};
 use sui_types::parse_sui_struct_tag;
 
-use crate::{type_and_fields_from_move_struct, Page, SuiMoveStruct};
+use crate::{type_and_fields_from_move_struct, LayoutResolver, Page, SuiMoveStruct};
 
 pub type EventPage = Page<SuiEventEnvelope, EventID>;
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -31,6 +31,10 @@ pub struct SuiEventEnvelope {
     pub timestamp: u64,
     /// Transaction digest of associated transaction
     pub tx_digest: TransactionDigest,
+    /// The checkpoint number of the associated transaction, absent if the transaction is not
+    /// checkpointed yet, e.g. for events streamed as the transaction executes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<CheckpointSequenceNumber>,
     /// Sequential event ID, ie (transaction seq number, event seq number).
     /// 1) Serves as a unique event ID for each fullnode
     /// 2) Also serves to sequence events for the purposes of pagination and querying.
@@ -90,6 +94,10 @@ pub enum SuiEvent {
         package_id: ObjectID,
         transaction_module: String,
         sender: SuiAddress,
+        /// Owner of the previous version of the object, absent if that version was not an input of
+        /// the transaction, e.g. for an unwrapped object or a child object of an input
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        previous_owner: Option<Owner>,
         recipient: Owner,
         object_type: String,
         object_id: ObjectID,
@@ -160,6 +168,7 @@ impl TryFrom<SuiEvent> for Event {
                 package_id,
                 transaction_module,
                 sender,
+                previous_owner,
                 recipient,
                 object_type,
                 object_id,
@@ -168,6 +177,7 @@ impl TryFrom<SuiEvent> for Event {
                 package_id,
                 transaction_module: Identifier::from_str(&transaction_module)?,
                 sender,
+                previous_owner,
                 recipient,
                 object_type,
                 object_id,
@@ -246,7 +256,7 @@ impl TryFrom<SuiEvent> for Event {
 }
 
 impl SuiEvent {
-    pub fn try_from(event: Event, resolver: &impl GetModule) -> Result<Self, anyhow::Error> {
+    pub fn try_from(event: Event, resolver: &impl LayoutResolver) -> Result<Self, anyhow::Error> {
         Ok(match event {
             Event::MoveEvent {
                 package_id,
@@ -255,11 +265,11 @@ impl SuiEvent {
                 type_,
                 contents,
             } => {
-                let bcs = contents.to_vec();
-
-                let (type_, fields) = if let Ok(move_struct) =
-                    Event::move_event_to_move_struct(&type_, &contents, resolver)
-                {
+                let move_struct = resolver
+                    .struct_layout(&type_, ObjectFormatOptions::default())
+                    .ok()
+                    .and_then(|layout| MoveStruct::simple_deserialize(&contents, &layout).ok());
+                let (type_, fields) = if let Some(move_struct) = move_struct {
                     let (type_, field) = type_and_fields_from_move_struct(&type_, move_struct);
                     (type_, Some(field))
                 } else {
@@ -272,7 +282,7 @@ impl SuiEvent {
                     sender,
                     type_,
                     fields,
-                    bcs,
+                    bcs: contents,
                 }
             }
             Event::Publish {
@@ -290,6 +300,7 @@ impl SuiEvent {
                 package_id,
                 transaction_module,
                 sender,
+                previous_owner,
                 recipient,
                 object_type,
                 object_id,
@@ -298,6 +309,7 @@ impl SuiEvent {
                 package_id,
                 transaction_module: transaction_module.to_string(),
                 sender,
+                previous_owner,
                 recipient,
                 object_type,
                 object_id,
@@ -450,6 +462,7 @@ impl PartialEq<SuiEvent> for Event {
                 package_id: self_package_id,
                 transaction_module: self_transaction_module,
                 sender: self_sender,
+                previous_owner: self_previous_owner,
                 recipient: self_recipient,
                 object_type: self_object_type,
                 object_id: self_object_id,
@@ -459,6 +472,7 @@ impl PartialEq<SuiEvent> for Event {
                     package_id,
                     transaction_module,
                     sender,
+                    previous_owner,
                     recipient,
                     object_type,
                     object_id,
@@ -468,6 +482,7 @@ impl PartialEq<SuiEvent> for Event {
                     package_id == self_package_id
                         && &self_transaction_module.to_string() == transaction_module
                         && self_sender == sender
+                        && self_previous_owner == previous_owner
                         && self_recipient == recipient
                         && self_object_id == object_id
                         && self_version == version
@@ -664,3 +679,38 @@ impl TryInto<EventFilter> for SuiEventFilter {
         })
     }
 }
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "PackagePublish", rename_all = "camelCase")]
+pub struct SuiPackagePublish {
+    /// UTC timestamp in milliseconds since epoch (1/1/1970)
+    pub timestamp: u64,
+    /// Digest of the transaction that published the package
+    pub tx_digest: TransactionDigest,
+    pub package_id: ObjectID,
+    pub version: SequenceNumber,
+    pub publisher: SuiAddress,
+    /// Names of the modules contained in the package
+    pub modules: Vec<String>,
+    /// Packages the published modules link against, excluding the package itself
+    pub dependencies: Vec<ObjectID>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "PackagePublishFilter", rename_all = "camelCase")]
+pub struct SuiPackagePublishFilter {
+    /// Only emit packages published by this address
+    pub publisher: Option<SuiAddress>,
+    /// Only emit packages that depend on this package
+    pub dependency: Option<ObjectID>,
+}
+
+impl SuiPackagePublishFilter {
+    pub fn matches(&self, publish: &SuiPackagePublish) -> bool {
+        self.publisher
+            .map_or(true, |publisher| publisher == publish.publisher)
+            && self.dependency.map_or(true, |dependency| {
+                publish.dependencies.contains(&dependency)
+            })
+    }
+}
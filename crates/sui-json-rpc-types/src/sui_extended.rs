@@ -0,0 +1,122 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber, TransactionDigest};
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::Owner;
+
+use crate::{Page, SuiObjectInfo};
+
+pub type ObjectHistoryPage = Page<ObjectHistoryEntry, SequenceNumber>;
+pub type IncomingPaymentPage = Page<IncomingPayment, u64>;
+pub type ObjectInfoPage = Page<SuiObjectInfo, ObjectID>;
+
+/// Usage statistics of a shared object over a time window.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotSharedObject {
+    pub object_id: ObjectID,
+    /// Number of transactions that used the shared object in the window.
+    pub transaction_count: u64,
+    /// Average consensus latency of those transactions, in milliseconds.
+    pub avg_consensus_latency_ms: u64,
+    /// Highest consensus latency of those transactions, in milliseconds.
+    pub max_consensus_latency_ms: u64,
+}
+
+/// Failures of a Move function over a time window.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailingFunction {
+    pub package: ObjectID,
+    pub module: String,
+    /// Name of the function, None if it could not be resolved.
+    pub function: Option<String>,
+    /// Number of transactions that failed while executing the function in the window.
+    pub failure_count: u64,
+    /// Number of those failures that were aborts, by abort code.
+    pub abort_codes: BTreeMap<u64, u64>,
+}
+
+/// Number of transactions that failed with a kind of execution error over a time window.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionErrorStats {
+    /// Name of the `ExecutionFailureStatus` variant, e.g. `MoveAbort` or `InsufficientGas`.
+    pub kind: String,
+    pub failure_count: u64,
+}
+
+/// Activity of the addresses sending transactions over a range of epochs.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveAddressStats {
+    pub start_epoch: EpochId,
+    pub end_epoch: EpochId,
+    /// Number of distinct addresses that sent at least one transaction in the range.
+    pub active_addresses: u64,
+    /// Number of transactions sent in the range.
+    pub tx_count: u64,
+    /// Gas spent by those transactions, net of storage rebates.
+    pub gas_spent: i64,
+}
+
+/// Transactions sent by an address in an epoch.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressEpochActivity {
+    pub epoch: EpochId,
+    pub tx_count: u64,
+    /// Gas spent by the transactions, net of storage rebates.
+    pub gas_spent: i64,
+}
+
+/// A version of an object, written by the transaction that created, mutated, transferred, wrapped,
+/// unwrapped or deleted it.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectHistoryEntry {
+    pub version: SequenceNumber,
+    /// Digest of the object at this version, None if it was deleted or wrapped.
+    pub digest: Option<ObjectDigest>,
+    pub status: ObjectChangeStatus,
+    pub transaction_digest: TransactionDigest,
+    pub checkpoint: CheckpointSequenceNumber,
+    /// Owner of the previous version, None for the first version of the object, or if the
+    /// previous version was deleted or wrapped.
+    pub previous_owner: Option<Owner>,
+    /// Owner of this version, None if the object was deleted or wrapped.
+    pub owner: Option<Owner>,
+}
+
+/// Amount of a coin type received by an address in a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingPayment {
+    pub transaction_digest: TransactionDigest,
+    pub checkpoint: CheckpointSequenceNumber,
+    pub coin_type: String,
+    pub amount: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+pub enum ObjectChangeStatus {
+    Created,
+    Mutated,
+    Deleted,
+    Wrapped,
+    Unwrapped,
+    UnwrappedThenDeleted,
+}
+
+impl ObjectChangeStatus {
+    /// Whether the object is live after the change.
+    pub fn is_live(&self) -> bool {
+        matches!(self, Self::Created | Self::Mutated | Self::Unwrapped)
+    }
+}
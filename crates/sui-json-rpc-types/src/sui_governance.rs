@@ -1,11 +1,29 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
+use fastcrypto::encoding::{Base64, Encoding};
+use fastcrypto::traits::ToFromBytes;
+use multiaddr::Multiaddr;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use sui_types::base_types::{AuthorityName, EpochId};
+use sui_cost_tables::bytecode_tables::initial_instruction_costs;
+use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
+use sui_types::base_types::{AuthorityName, EpochId, SuiAddress};
 use sui_types::committee::{Committee, StakeUnit};
+use sui_types::sui_system_state::sui_system_state_summary::{
+    SuiSystemStateSummary, SuiValidatorSummary,
+};
+
+use crate::Page;
+
+pub type CommitteePage = Page<SuiCommittee, EpochId>;
+
+/// Voting power the reporters of a validator must reach for the tallying rule to slash its
+/// rewards, see `voting_power::quorum_threshold` in the framework.
+const QUORUM_THRESHOLD: u64 = 6_667;
 
 /// RPC representation of the [Committee] type.
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -13,6 +31,9 @@ use sui_types::committee::{Committee, StakeUnit};
 pub struct SuiCommittee {
     pub epoch: EpochId,
     pub validators: Vec<(AuthorityName, StakeUnit)>,
+    /// Names, keys and network metadata of the validators, from the on-chain validator set.
+    #[serde(rename = "validatorInfo", default)]
+    pub validator_info: Vec<SuiCommitteeValidator>,
 }
 
 impl From<Committee> for SuiCommittee {
@@ -20,6 +41,178 @@ impl From<Committee> for SuiCommittee {
         Self {
             epoch: committee.epoch,
             validators: committee.voting_rights,
+            validator_info: vec![],
+        }
+    }
+}
+
+impl SuiCommittee {
+    /// Add the metadata of the committee members found in `validators`, the active validators
+    /// of the latest epoch. Members no longer in the validator set are left out.
+    pub fn with_validator_info(mut self, validators: &[SuiValidatorSummary]) -> Self {
+        let validators: BTreeMap<_, _> = validators
+            .iter()
+            .filter_map(|v| Some((AuthorityName::from_bytes(&v.protocol_pubkey_bytes).ok()?, v)))
+            .collect();
+        self.validator_info = self
+            .validators
+            .iter()
+            .filter_map(|(name, stake)| {
+                let validator = validators.get(name)?;
+                Some(SuiCommitteeValidator {
+                    authority_name: *name,
+                    stake: *stake,
+                    sui_address: validator.sui_address,
+                    name: validator.name.clone(),
+                    network_pubkey: Base64::encode(&validator.network_pubkey_bytes),
+                    worker_pubkey: Base64::encode(&validator.worker_pubkey_bytes),
+                    net_address: multiaddr_to_string(&validator.net_address),
+                    p2p_address: multiaddr_to_string(&validator.p2p_address),
+                    primary_address: multiaddr_to_string(&validator.primary_address),
+                    worker_address: multiaddr_to_string(&validator.worker_address),
+                    gas_price: validator.gas_price,
+                    commission_rate: validator.commission_rate,
+                })
+            })
+            .collect();
+        self
+    }
+}
+
+/// A committee member along with its metadata in the on-chain validator set.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "CommitteeValidator")]
+pub struct SuiCommitteeValidator {
+    pub authority_name: AuthorityName,
+    pub stake: StakeUnit,
+    pub sui_address: SuiAddress,
+    pub name: String,
+    /// Base64 encoded network public key.
+    pub network_pubkey: String,
+    /// Base64 encoded worker public key.
+    pub worker_pubkey: String,
+    pub net_address: String,
+    pub p2p_address: String,
+    pub primary_address: String,
+    pub worker_address: String,
+    pub gas_price: u64,
+    /// Commission rate in basis points.
+    pub commission_rate: u64,
+}
+
+/// Network addresses are stored on-chain as encoded multiaddrs, they are validated when set.
+fn multiaddr_to_string(bytes: &[u8]) -> String {
+    Multiaddr::try_from(bytes.to_vec())
+        .map(|address| address.to_string())
+        .unwrap_or_default()
+}
+
+/// Gas cost parameters in effect at a protocol version, as charged by the validators.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "GasCostTable")]
+pub struct SuiGasCostTable {
+    pub protocol_version: ProtocolVersion,
+    /// Cost of each Move bytecode instruction, in gas units
+    pub instructions: Vec<SuiInstructionCost>,
+    /// Costs of the Move native functions, by parameter name
+    pub native_function_costs: BTreeMap<String, u64>,
+    /// Costs charged for transactions independently of the Move code they run, by parameter name
+    pub transaction_costs: BTreeMap<String, u64>,
+}
+
+impl From<&ProtocolConfig> for SuiGasCostTable {
+    fn from(config: &ProtocolConfig) -> Self {
+        let to_owned = |parameters: BTreeMap<&str, u64>| {
+            parameters
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect()
+        };
+        Self {
+            protocol_version: config.version,
+            instructions: initial_instruction_costs()
+                .into_iter()
+                .map(|(instruction, cost)| SuiInstructionCost {
+                    instruction,
+                    instruction_gas: cost.instruction_gas,
+                    memory_gas: cost.memory_gas,
+                })
+                .collect(),
+            native_function_costs: to_owned(config.native_cost_parameters()),
+            transaction_costs: to_owned(config.transaction_cost_parameters()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "InstructionCost")]
+pub struct SuiInstructionCost {
+    pub instruction: String,
+    pub instruction_gas: u64,
+    pub memory_gas: u64,
+}
+
+/// The reports of the validators against each other in the current epoch, along with the
+/// validators whose rewards the tallying rule slashes at the end of the epoch if the reports
+/// don't change.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "ValidatorReports")]
+pub struct SuiValidatorReports {
+    pub epoch: EpochId,
+    /// Voting power the reporters of a validator must reach for its rewards to be slashed.
+    pub quorum_threshold: u64,
+    /// Share of the rewards of a slashed validator that is slashed, in basis points.
+    pub reward_slashing_rate: u64,
+    pub reports: Vec<SuiValidatorReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", rename = "ValidatorReport")]
+pub struct SuiValidatorReport {
+    pub validator_address: SuiAddress,
+    pub validator_name: String,
+    pub reporters: Vec<SuiAddress>,
+    /// Sum of the voting power of the reporters.
+    pub reporters_voting_power: u64,
+    /// Whether the reporters reach the quorum threshold.
+    pub slashed: bool,
+}
+
+impl SuiValidatorReports {
+    pub fn new(system_state: &SuiSystemStateSummary, reward_slashing_rate: u64) -> Self {
+        let validators: BTreeMap<_, _> = system_state
+            .active_validators
+            .iter()
+            .map(|v| (v.sui_address, v))
+            .collect();
+        let reports = system_state
+            .validator_report_records
+            .iter()
+            .map(|(validator_address, reporters)| {
+                // Only active validators can report, their voting power is counted as of the
+                // current epoch.
+                let reporters_voting_power = reporters
+                    .iter()
+                    .filter_map(|reporter| validators.get(reporter))
+                    .map(|reporter| reporter.voting_power)
+                    .sum();
+                SuiValidatorReport {
+                    validator_address: *validator_address,
+                    validator_name: validators
+                        .get(validator_address)
+                        .map(|v| v.name.clone())
+                        .unwrap_or_default(),
+                    reporters: reporters.clone(),
+                    reporters_voting_power,
+                    slashed: reporters_voting_power >= QUORUM_THRESHOLD,
+                }
+            })
+            .collect();
+        Self {
+            epoch: system_state.epoch,
+            quorum_threshold: QUORUM_THRESHOLD,
+            reward_slashing_rate,
+            reports,
         }
     }
 }
@@ -7,6 +7,7 @@ use std::fmt::{Display, Formatter, Write};
 use sui_types::base_types::{ObjectID, SuiAddress};
 use tracing::warn;
 
+use anyhow::anyhow;
 use colored::Colorize;
 use itertools::Itertools;
 use schemars::JsonSchema;
@@ -20,7 +21,7 @@ use move_binary_format::normalized::{
 };
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
-use move_core_types::value::{MoveStruct, MoveValue};
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
 
 pub type SuiMoveTypeParameterIndex = u16;
 
@@ -274,6 +275,81 @@ pub enum MoveFunctionArgType {
     Object(ObjectValueKind),
 }
 
+/// Layout of the BCS encoding of a Move value, to decode the BCS bytes of objects without the
+/// Move modules declaring their types.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+pub enum SuiMoveTypeLayout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<SuiMoveTypeLayout>),
+    Struct(SuiMoveStructLayout),
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+pub struct SuiMoveStructLayout {
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The fields of the struct, in the order of their BCS encoding
+    pub fields: Vec<SuiMoveFieldLayout>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Eq, PartialEq)]
+pub struct SuiMoveFieldLayout {
+    pub name: String,
+    pub layout: SuiMoveTypeLayout,
+}
+
+impl TryFrom<MoveTypeLayout> for SuiMoveTypeLayout {
+    type Error = anyhow::Error;
+
+    fn try_from(layout: MoveTypeLayout) -> Result<Self, Self::Error> {
+        Ok(match layout {
+            MoveTypeLayout::Bool => Self::Bool,
+            MoveTypeLayout::U8 => Self::U8,
+            MoveTypeLayout::U16 => Self::U16,
+            MoveTypeLayout::U32 => Self::U32,
+            MoveTypeLayout::U64 => Self::U64,
+            MoveTypeLayout::U128 => Self::U128,
+            MoveTypeLayout::U256 => Self::U256,
+            MoveTypeLayout::Address => Self::Address,
+            MoveTypeLayout::Signer => Self::Signer,
+            MoveTypeLayout::Vector(inner) => Self::Vector(Box::new((*inner).try_into()?)),
+            MoveTypeLayout::Struct(layout) => Self::Struct(layout.try_into()?),
+        })
+    }
+}
+
+/// Only layouts with types, as built with [sui_types::object::ObjectFormatOptions::with_types],
+/// can be converted.
+impl TryFrom<MoveStructLayout> for SuiMoveStructLayout {
+    type Error = anyhow::Error;
+
+    fn try_from(layout: MoveStructLayout) -> Result<Self, Self::Error> {
+        match layout {
+            MoveStructLayout::WithTypes { type_, fields } => Ok(Self {
+                type_: type_.to_string(),
+                fields: fields
+                    .into_iter()
+                    .map(|field| {
+                        Ok(SuiMoveFieldLayout {
+                            name: field.name.to_string(),
+                            layout: field.layout.try_into()?,
+                        })
+                    })
+                    .collect::<Result<_, anyhow::Error>>()?,
+            }),
+            _ => Err(anyhow!("Expected a struct layout with types")),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
 #[serde(untagged, rename = "MoveValue")]
 pub enum SuiMoveValue {
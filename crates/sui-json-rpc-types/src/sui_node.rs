@@ -0,0 +1,38 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// State of the node serving the RPC API, for load balancers and clients to route requests away
+/// from nodes that are behind or that do not have the data they need.
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiNodeInfo {
+    /// First 4 bytes of the digest of the genesis checkpoint, hex encoded.
+    pub chain_identifier: String,
+    pub epoch: EpochId,
+    pub protocol_version: u64,
+    pub latest_checkpoint: CheckpointSequenceNumber,
+    /// Timestamp of the latest checkpoint, telling how far the node is behind the chain.
+    pub latest_checkpoint_timestamp_ms: u64,
+    /// Lowest checkpoint whose transactions and objects have not been pruned.
+    pub lowest_available_checkpoint: CheckpointSequenceNumber,
+    pub pruning: SuiPruningConfig,
+    /// Number of checkpoints the indexer serving the request is behind the full node it indexes,
+    /// None if the request is served by a full node.
+    pub indexer_lag: Option<u64>,
+    pub software_version: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, JsonSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiPruningConfig {
+    /// Number of past epochs whose transactions and object versions are retained, None if
+    /// nothing is pruned.
+    pub num_epochs_to_retain: Option<u64>,
+    /// Whether the data pruned from the node is read from an archive.
+    pub archive_fallback: bool,
+}
@@ -76,6 +76,10 @@ pub struct SuiObjectData {
     /// Move object content or package content in BCS, default to be None unless SuiObjectDataOptions.showBcs is set to true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bcs: Option<SuiRawData>,
+    /// Whether the object was read from the archival store, as this version of the object was
+    /// pruned from the node. Only returned by the reads of past versions of objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_archive: Option<bool>,
 }
 
 impl SuiObjectData {
@@ -257,6 +261,13 @@ impl SuiObjectDataOptions {
         self.show_bcs = true;
         self
     }
+
+    /// Whether the Move struct layout of the object is needed to serve these options.
+    /// Resolving the layout is expensive, callers that only need metadata such as the owner
+    /// and version of an object can skip it.
+    pub fn requires_layout(&self) -> bool {
+        self.show_content || self.show_bcs || self.show_display
+    }
 }
 
 impl TryFrom<(ObjectRead, SuiObjectDataOptions)> for SuiObjectResponse {
@@ -359,6 +370,7 @@ impl
             content,
             bcs,
             display: None,
+            from_archive: None,
         })
     }
 }
@@ -691,6 +703,8 @@ pub fn type_and_fields_from_move_struct(
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
 #[serde(rename = "RawMoveObject", rename_all = "camelCase")]
 pub struct SuiRawMoveObject {
+    /// The type of the object, the layout to decode `bcsBytes` with can be fetched once per type
+    /// with `sui_getMoveStructLayout`
     #[serde(rename = "type")]
     pub type_: String,
     pub has_public_transfer: bool,
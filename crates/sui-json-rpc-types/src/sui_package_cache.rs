@@ -0,0 +1,365 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use linked_hash_map::LinkedHashMap;
+use move_binary_format::normalized;
+use move_bytecode_utils::module_cache::GetModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::{IdentStr, Identifier};
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
+use parking_lot::Mutex;
+use serde_json::Value;
+
+use sui_json::move_function_pure_arg_layouts;
+use sui_types::base_types::{ObjectID, SequenceNumber};
+use sui_types::committee::EpochId;
+use sui_types::error::SuiResult;
+use sui_types::move_package::MovePackage;
+use sui_types::object::{Data, MoveObject, Object, ObjectFormatOptions};
+
+use crate::SuiRpcConversionError;
+
+pub const DEFAULT_PACKAGE_CACHE_CAPACITY: usize = 1_000;
+
+/// Resolution of the layouts needed to convert objects, events and transaction arguments into
+/// their RPC representation.
+pub trait LayoutResolver {
+    fn struct_layout(
+        &self,
+        struct_tag: &StructTag,
+        format: ObjectFormatOptions,
+    ) -> SuiResult<MoveStructLayout>;
+
+    /// Layouts of the parameters of `function` in `module_id`, `None` for the parameters that
+    /// are not pure values. Empty if the function can't be found.
+    fn pure_arg_layouts(
+        &self,
+        module_id: &ModuleId,
+        function: &IdentStr,
+        type_arguments: &[TypeTag],
+    ) -> Result<Vec<Option<MoveTypeLayout>>, SuiRpcConversionError>;
+
+    /// Layout of `object`, `None` for packages.
+    fn object_layout(&self, object: &Object) -> SuiResult<Option<MoveStructLayout>> {
+        match &object.data {
+            Data::Move(m) => Ok(Some(
+                self.struct_layout(&m.type_, ObjectFormatOptions::default())?,
+            )),
+            Data::Package(_) => Ok(None),
+        }
+    }
+}
+
+impl<T: GetModule> LayoutResolver for T {
+    fn struct_layout(
+        &self,
+        struct_tag: &StructTag,
+        format: ObjectFormatOptions,
+    ) -> SuiResult<MoveStructLayout> {
+        MoveObject::get_layout_from_struct_tag(struct_tag.clone(), format, self)
+    }
+
+    fn pure_arg_layouts(
+        &self,
+        module_id: &ModuleId,
+        function: &IdentStr,
+        type_arguments: &[TypeTag],
+    ) -> Result<Vec<Option<MoveTypeLayout>>, SuiRpcConversionError> {
+        match self.get_module_by_id(module_id) {
+            Ok(Some(module)) => {
+                Ok(
+                    move_function_pure_arg_layouts(module.borrow(), function, type_arguments)
+                        .unwrap_or_default(),
+                )
+            }
+            Ok(None) => Ok(vec![]),
+            Err(e) => Err(SuiRpcConversionError::ModuleResolution {
+                module: module_id.clone(),
+                error: format!("{e:?}"),
+            }),
+        }
+    }
+}
+
+type FunctionKey = (ModuleId, Identifier, Vec<TypeTag>);
+
+/// Bounded cache of the data derived from packages by the RPC read path: disassembled and
+/// normalized modules, struct layouts and the layouts of the pure arguments of functions.
+/// Deriving them is CPU bound and the same hot packages are requested over and over.
+///
+/// Packages are cached by ID along with their version, the entries of a package are dropped
+/// when a newer version of it is seen. Layouts are resolved with the modules of the epoch they
+/// are requested in, they are dropped when the epoch changes as system packages are upgraded at
+/// epoch boundaries.
+pub struct PackageCache {
+    capacity: usize,
+    inner: Mutex<PackageCacheInner>,
+}
+
+struct PackageCacheInner {
+    epoch: Option<EpochId>,
+    packages: LinkedHashMap<ObjectID, CachedPackage>,
+    struct_layouts: LinkedHashMap<(StructTag, ObjectFormatOptions), MoveStructLayout>,
+    pure_arg_layouts: LinkedHashMap<FunctionKey, Vec<Option<MoveTypeLayout>>>,
+}
+
+struct CachedPackage {
+    version: SequenceNumber,
+    disassembled: Option<Arc<BTreeMap<String, Value>>>,
+    normalized: Option<Arc<BTreeMap<String, normalized::Module>>>,
+}
+
+impl Default for PackageCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_PACKAGE_CACHE_CAPACITY)
+    }
+}
+
+impl PackageCache {
+    /// A cache holding at most `capacity` packages, and as many layouts of each kind.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(PackageCacheInner {
+                epoch: None,
+                packages: LinkedHashMap::new(),
+                struct_layouts: LinkedHashMap::new(),
+                pure_arg_layouts: LinkedHashMap::new(),
+            }),
+        }
+    }
+
+    pub fn disassembled(&self, package: &MovePackage) -> SuiResult<Arc<BTreeMap<String, Value>>> {
+        if let Some(disassembled) = self.with_package(package, |p| p.disassembled.clone()) {
+            return Ok(disassembled);
+        }
+        let disassembled = Arc::new(package.disassemble()?);
+        self.with_package(package, |p| p.disassembled = Some(disassembled.clone()));
+        Ok(disassembled)
+    }
+
+    pub fn normalized(
+        &self,
+        package: &MovePackage,
+    ) -> SuiResult<Arc<BTreeMap<String, normalized::Module>>> {
+        if let Some(normalized) = self.with_package(package, |p| p.normalized.clone()) {
+            return Ok(normalized);
+        }
+        let normalized = Arc::new(package.normalize()?);
+        self.with_package(package, |p| p.normalized = Some(normalized.clone()));
+        Ok(normalized)
+    }
+
+    /// Drop the cached entries of `package`, e.g. after it has been upgraded.
+    pub fn invalidate(&self, package: &ObjectID) {
+        self.inner.lock().invalidate(package);
+    }
+
+    /// A resolver of the layouts of the current `epoch`, caching the layouts it resolves with
+    /// `resolver`.
+    pub fn resolver<'a, R: GetModule>(
+        &'a self,
+        epoch: EpochId,
+        resolver: &'a R,
+    ) -> CachedLayoutResolver<'a, R> {
+        let mut inner = self.inner.lock();
+        if inner.epoch != Some(epoch) {
+            inner.struct_layouts.clear();
+            inner.pure_arg_layouts.clear();
+            inner.epoch = Some(epoch);
+        }
+        CachedLayoutResolver {
+            cache: self,
+            epoch,
+            resolver,
+        }
+    }
+
+    /// Run `f` on the entry of `package`, created or replaced if the cache holds no entry for its
+    /// version.
+    fn with_package<T>(&self, package: &MovePackage, f: impl FnOnce(&mut CachedPackage) -> T) -> T {
+        let mut inner = self.inner.lock();
+        let id = package.id();
+        let version = package.version();
+        match inner.packages.get_refresh(&id).map(|p| p.version) {
+            Some(cached) if cached == version => (),
+            // An older version of the package was read after a newer one, leave the cache as is.
+            Some(cached) if cached > version => {
+                return f(&mut CachedPackage {
+                    version,
+                    disassembled: None,
+                    normalized: None,
+                })
+            }
+            cached => {
+                if cached.is_some() {
+                    inner.invalidate(&id);
+                }
+                inner.packages.insert(
+                    id,
+                    CachedPackage {
+                        version,
+                        disassembled: None,
+                        normalized: None,
+                    },
+                );
+                evict(&mut inner.packages, self.capacity);
+            }
+        }
+        f(inner
+            .packages
+            .get_mut(&id)
+            .expect("Package entry was just inserted"))
+    }
+
+    fn get_layout<K: Hash + Eq, V: Clone>(
+        &self,
+        epoch: EpochId,
+        map: impl FnOnce(&mut PackageCacheInner) -> &mut LinkedHashMap<K, V>,
+        key: &K,
+    ) -> Option<V> {
+        let mut inner = self.inner.lock();
+        if inner.epoch != Some(epoch) {
+            return None;
+        }
+        map(&mut *inner).get_refresh(key).cloned()
+    }
+
+    fn insert_layout<K: Hash + Eq, V>(
+        &self,
+        epoch: EpochId,
+        map: impl FnOnce(&mut PackageCacheInner) -> &mut LinkedHashMap<K, V>,
+        key: K,
+        value: V,
+    ) {
+        let mut inner = self.inner.lock();
+        // Layouts resolved with the modules of another epoch than the cached one are not kept.
+        if inner.epoch != Some(epoch) {
+            return;
+        }
+        let map = map(&mut *inner);
+        map.insert(key, value);
+        evict(map, self.capacity);
+    }
+}
+
+impl PackageCacheInner {
+    fn invalidate(&mut self, package: &ObjectID) {
+        let address = AccountAddress::from(*package);
+        self.packages.remove(package);
+        let stale: Vec<_> = self
+            .struct_layouts
+            .keys()
+            .filter(|(tag, _)| struct_tag_references(tag, &address))
+            .cloned()
+            .collect();
+        for key in stale {
+            self.struct_layouts.remove(&key);
+        }
+        let stale: Vec<_> = self
+            .pure_arg_layouts
+            .keys()
+            .filter(|(module, _, type_arguments)| {
+                module.address() == &address
+                    || type_arguments
+                        .iter()
+                        .any(|tag| type_tag_references(tag, &address))
+            })
+            .cloned()
+            .collect();
+        for key in stale {
+            self.pure_arg_layouts.remove(&key);
+        }
+    }
+}
+
+fn evict<K: Hash + Eq, V>(map: &mut LinkedHashMap<K, V>, capacity: usize) {
+    while map.len() > capacity {
+        map.pop_front();
+    }
+}
+
+fn struct_tag_references(tag: &StructTag, address: &AccountAddress) -> bool {
+    &tag.address == address
+        || tag
+            .type_params
+            .iter()
+            .any(|tag| type_tag_references(tag, address))
+}
+
+fn type_tag_references(tag: &TypeTag, address: &AccountAddress) -> bool {
+    match tag {
+        TypeTag::Struct(tag) => struct_tag_references(tag, address),
+        TypeTag::Vector(tag) => type_tag_references(tag, address),
+        _ => false,
+    }
+}
+
+/// [LayoutResolver] reading and filling a [PackageCache], see [PackageCache::resolver].
+pub struct CachedLayoutResolver<'a, R> {
+    cache: &'a PackageCache,
+    epoch: EpochId,
+    resolver: &'a R,
+}
+
+impl<R: GetModule> LayoutResolver for CachedLayoutResolver<'_, R> {
+    fn struct_layout(
+        &self,
+        struct_tag: &StructTag,
+        format: ObjectFormatOptions,
+    ) -> SuiResult<MoveStructLayout> {
+        let key = (struct_tag.clone(), format);
+        if let Some(layout) =
+            self.cache
+                .get_layout(self.epoch, |inner| &mut inner.struct_layouts, &key)
+        {
+            return Ok(layout);
+        }
+        let layout = self.resolver.struct_layout(&key.0, key.1.clone())?;
+        self.cache.insert_layout(
+            self.epoch,
+            |inner| &mut inner.struct_layouts,
+            key,
+            layout.clone(),
+        );
+        Ok(layout)
+    }
+
+    fn pure_arg_layouts(
+        &self,
+        module_id: &ModuleId,
+        function: &IdentStr,
+        type_arguments: &[TypeTag],
+    ) -> Result<Vec<Option<MoveTypeLayout>>, SuiRpcConversionError> {
+        let key = (
+            module_id.clone(),
+            function.to_owned(),
+            type_arguments.to_vec(),
+        );
+        if let Some(layouts) =
+            self.cache
+                .get_layout(self.epoch, |inner| &mut inner.pure_arg_layouts, &key)
+        {
+            return Ok(layouts);
+        }
+        let layouts = self
+            .resolver
+            .pure_arg_layouts(module_id, function, type_arguments)?;
+        // Functions missing from the resolver may be published later, only found ones are kept.
+        if !layouts.is_empty() {
+            self.cache.insert_layout(
+                self.epoch,
+                |inner| &mut inner.pure_arg_layouts,
+                key,
+                layouts.clone(),
+            );
+        }
+        Ok(layouts)
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use sui_types::base_types::{ObjectID, SequenceNumber, TransactionDigest};
+use sui_types::error::{SuiError, UserInputError};
+use sui_types::quorum_driver_types::QuorumDriverError;
+
+/// JSON-RPC error codes of [SuiRpcErrorData], stable across releases.
+pub const INVALID_PARAMS_CODE: i32 = -32602;
+pub const INTERNAL_ERROR_CODE: i32 = -32603;
+pub const OBJECT_NOT_FOUND_CODE: i32 = -32001;
+pub const OBJECT_VERSION_TOO_HIGH_CODE: i32 = -32002;
+pub const INSUFFICIENT_GAS_CODE: i32 = -32003;
+pub const TRANSACTION_NOT_FOUND_CODE: i32 = -32004;
+pub const QUORUM_FAILURE_CODE: i32 = -32005;
+
+/// Machine-readable description of a failed call, sent as the `data` member of the JSON-RPC
+/// error object, whose `code` is given by [SuiRpcErrorData::code]. Clients can branch on the
+/// code and on the fields of the payload instead of parsing error messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Error)]
+#[serde(rename = "RpcErrorData", tag = "type")]
+pub enum SuiRpcErrorData {
+    #[error("Could not find object {object_id} at version {version:?}")]
+    #[serde(rename_all = "camelCase")]
+    ObjectNotFound {
+        object_id: ObjectID,
+        version: Option<SequenceNumber>,
+    },
+    #[error(
+        "Version {asked_version} of object {object_id} is higher than its latest version {latest_version}"
+    )]
+    #[serde(rename_all = "camelCase")]
+    ObjectVersionTooHigh {
+        object_id: ObjectID,
+        asked_version: SequenceNumber,
+        latest_version: SequenceNumber,
+    },
+    /// The gas payment does not cover the gas budget of the transaction.
+    #[error("Gas balance {provided} is lower than the required {required}")]
+    InsufficientGas { required: u128, provided: u128 },
+    #[error("Could not find transaction {digest}")]
+    TransactionNotFound { digest: TransactionDigest },
+    /// The transaction could not be certified or finalized by a quorum of validators, `errors`
+    /// lists the reasons reported for the failure.
+    #[error("Failed to reach a quorum of validators: {errors:?}")]
+    QuorumFailure { errors: Vec<String> },
+    #[error("Invalid params: {message}")]
+    InvalidParams { message: String },
+    #[error("{message}")]
+    Internal { message: String },
+}
+
+impl SuiRpcErrorData {
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::ObjectNotFound { .. } => OBJECT_NOT_FOUND_CODE,
+            Self::ObjectVersionTooHigh { .. } => OBJECT_VERSION_TOO_HIGH_CODE,
+            Self::InsufficientGas { .. } => INSUFFICIENT_GAS_CODE,
+            Self::TransactionNotFound { .. } => TRANSACTION_NOT_FOUND_CODE,
+            Self::QuorumFailure { .. } => QUORUM_FAILURE_CODE,
+            Self::InvalidParams { .. } => INVALID_PARAMS_CODE,
+            Self::Internal { .. } => INTERNAL_ERROR_CODE,
+        }
+    }
+}
+
+impl From<&SuiError> for SuiRpcErrorData {
+    fn from(error: &SuiError) -> Self {
+        match error {
+            SuiError::UserInputError { error } => error.into(),
+            SuiError::TransactionNotFound { digest } => {
+                Self::TransactionNotFound { digest: *digest }
+            }
+            SuiError::QuorumFailedToGetEffectsQuorumWhenProcessingTransaction { .. } => {
+                Self::QuorumFailure {
+                    errors: vec![error.to_string()],
+                }
+            }
+            _ => Self::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<&UserInputError> for SuiRpcErrorData {
+    fn from(error: &UserInputError) -> Self {
+        match error {
+            UserInputError::ObjectNotFound { object_id, version } => Self::ObjectNotFound {
+                object_id: *object_id,
+                version: *version,
+            },
+            UserInputError::ObjectSequenceNumberTooHigh {
+                object_id,
+                asked_version,
+                latest_version,
+            } => Self::ObjectVersionTooHigh {
+                object_id: *object_id,
+                asked_version: *asked_version,
+                latest_version: *latest_version,
+            },
+            UserInputError::GasBalanceTooLowToCoverGasBudget {
+                gas_balance,
+                gas_budget,
+            } => Self::InsufficientGas {
+                required: *gas_budget,
+                provided: *gas_balance,
+            },
+            _ => Self::InvalidParams {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<&QuorumDriverError> for SuiRpcErrorData {
+    fn from(error: &QuorumDriverError) -> Self {
+        match error {
+            QuorumDriverError::QuorumDriverInternalError(error) => error.into(),
+            QuorumDriverError::InvalidUserSignature(error) => Self::InvalidParams {
+                message: error.to_string(),
+            },
+            QuorumDriverError::NonRecoverableTransactionError { errors } => {
+                // Validators agreeing on an invalid input, e.g. an insufficient gas payment,
+                // report that input error rather than a quorum failure.
+                let input_errors = errors
+                    .iter()
+                    .map(|(error, _, _)| match error {
+                        SuiError::UserInputError { error } => Some(Self::from(error)),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+                match input_errors.as_deref() {
+                    Some([first, rest @ ..]) if rest.iter().all(|e| e == first) => first.clone(),
+                    _ => Self::QuorumFailure {
+                        errors: errors
+                            .iter()
+                            .map(|(error, _, _)| error.to_string())
+                            .collect(),
+                    },
+                }
+            }
+            QuorumDriverError::ObjectsDoubleUsed { .. }
+            | QuorumDriverError::TimeoutBeforeFinality
+            | QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts { .. } => {
+                Self::QuorumFailure {
+                    errors: vec![error.to_string()],
+                }
+            }
+        }
+    }
+}
@@ -2,36 +2,48 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::fmt::{self, Display, Formatter, Write};
+use std::str::FromStr;
 
 use enum_dispatch::enum_dispatch;
-use fastcrypto::encoding::{Base64, Encoding, Hex};
+use fastcrypto::encoding::{Base64, Encoding};
+use move_binary_format::CompiledModule;
 use move_bytecode_utils::module_cache::GetModule;
-use move_core_types::language_storage::TypeTag;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::value::MoveTypeLayout;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::de::{DeserializeOwned, Error as _, IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use serde_with::{serde_as, DisplayFromStr};
+use thiserror::Error;
 
 use sui_json::SuiJsonValue;
+use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
 use sui_types::base_types::{
-    EpochId, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
+    EpochId, ObjectDigest, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
 };
 use sui_types::digests::TransactionEventsDigest;
 use sui_types::error::ExecutionError;
 use sui_types::gas::GasCostSummary;
+use sui_types::gas_coin::GAS;
 use sui_types::messages::{
-    Argument, CallArg, Command, ExecutionStatus, GenesisObject, InputObjectKind, ObjectArg, Pay,
-    PayAllSui, PaySui, ProgrammableMoveCall, ProgrammableTransaction, SenderSignedData,
-    SingleTransactionKind, TransactionData, TransactionDataAPI, TransactionEffects,
-    TransactionEffectsAPI, TransactionEvents, TransactionKind, VersionedProtocolMessage,
+    Argument, CallArg, Command, CommandIndex, ExecutionFailureStatus, ExecutionStatus,
+    GenesisObject, InputObjectKind, MoveLocation, ObjectArg, Pay, PayAllSui, PaySui,
+    ProgrammableMoveCall, ProgrammableTransaction, SenderSignedData, SingleTransactionKind,
+    TransactionData, TransactionDataAPI, TransactionEffects, TransactionEffectsAPI,
+    TransactionEvents, TransactionExpiration, TransactionKind, VersionedProtocolMessage,
 };
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
-use sui_types::move_package::disassemble_modules;
+use sui_types::move_package::{compute_digest_for_modules_and_deps, disassemble_modules};
 use sui_types::object::Owner;
 use sui_types::parse_sui_type_tag;
+use sui_types::quorum_driver_types::TransactionSubmissionStatus;
 use sui_types::signature::GenericSignature;
+use sui_types::storage::{DeleteKind, WriteKind};
 
-use crate::{Page, SuiEvent, SuiMovePackage, SuiObjectRef};
+use crate::{LayoutResolver, Page, SuiEvent, SuiMovePackage, SuiObjectData, SuiObjectRef};
 
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq, Eq, Copy)]
@@ -60,7 +72,67 @@ impl Display for BigInt {
     }
 }
 
-pub type TransactionsPage = Page<TransactionDigest, TransactionDigest>;
+pub type TransactionsPage = Page<TransactionsPageItem, TransactionDigest>;
+pub type TransactionSummariesPage = Page<SuiTransactionSummary, TransactionDigest>;
+
+/// A transaction of a [TransactionsPage]: its digest, or its summary when requested.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq)]
+#[serde(untagged, rename = "TransactionsPageItem")]
+pub enum TransactionsPageItem {
+    Digest(TransactionDigest),
+    Summary(SuiTransactionSummary),
+}
+
+impl TransactionsPageItem {
+    pub fn digest(&self) -> &TransactionDigest {
+        match self {
+            TransactionsPageItem::Digest(digest) => digest,
+            TransactionsPageItem::Summary(summary) => &summary.digest,
+        }
+    }
+}
+
+impl From<TransactionDigest> for TransactionsPageItem {
+    fn from(digest: TransactionDigest) -> Self {
+        TransactionsPageItem::Digest(digest)
+    }
+}
+
+/// The fields of a transaction needed to list it, so that listing a page of transactions does
+/// not take a request per transaction.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", rename = "TransactionSummary")]
+pub struct SuiTransactionSummary {
+    pub digest: TransactionDigest,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_ms: Option<u64>,
+    /// The checkpoint number when this transaction was included and hence finalized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<CheckpointSequenceNumber>,
+    pub status: SuiExecutionStatus,
+    pub sender: SuiAddress,
+}
+
+/// Summarize a response read with at least the input and the effects of the transaction.
+impl TryFrom<SuiTransactionResponse> for SuiTransactionSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(response: SuiTransactionResponse) -> Result<Self, Self::Error> {
+        let (Some(transaction), Some(effects)) = (response.transaction, response.effects) else {
+            return Err(anyhow::anyhow!(
+                "Missing input or effects of transaction {}",
+                response.digest
+            ));
+        };
+        Ok(Self {
+            digest: response.digest,
+            timestamp_ms: response.timestamp_ms,
+            checkpoint: response.checkpoint,
+            status: effects.status().clone(),
+            sender: *transaction.data.sender(),
+        })
+    }
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
 #[serde(
@@ -75,6 +147,14 @@ pub struct SuiTransactionResponseOptions {
     pub show_effects: bool,
     /// Whether to show transaction events. Default to be False
     pub show_events: bool,
+    /// Whether to show bcs-encoded transaction effects. Default to be False
+    pub show_raw_effects: bool,
+    /// Whether to show the input objects of the transaction, with their type, version and
+    /// owner as of its execution. Default to be False
+    pub show_input_objects: bool,
+    /// Whether to show the content of the input objects, if `show_input_objects` is set.
+    /// Default to be False
+    pub show_input_objects_content: bool,
 }
 
 impl SuiTransactionResponseOptions {
@@ -87,6 +167,9 @@ impl SuiTransactionResponseOptions {
             show_effects: true,
             show_input: true,
             show_events: true,
+            show_raw_effects: false,
+            show_input_objects: false,
+            show_input_objects_content: false,
         }
     }
 
@@ -104,8 +187,25 @@ impl SuiTransactionResponseOptions {
         self.show_events = true;
         self
     }
+
+    pub fn with_raw_effects(mut self) -> Self {
+        self.show_raw_effects = true;
+        self
+    }
+
+    pub fn with_input_objects(mut self) -> Self {
+        self.show_input_objects = true;
+        self
+    }
+
+    pub fn with_input_objects_content(mut self) -> Self {
+        self.show_input_objects = true;
+        self.show_input_objects_content = true;
+        self
+    }
 }
 
+#[serde_as]
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Default)]
 #[serde(rename_all = "camelCase", rename = "TransactionResponse")]
 pub struct SuiTransactionResponse {
@@ -117,6 +217,12 @@ pub struct SuiTransactionResponse {
     pub effects: Option<SuiTransactionEffects>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub events: Option<SuiTransactionEvents>,
+    /// BCS encoded [TransactionEffects], can be hashed and checked against the effects digest
+    /// in checkpoint contents.
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub raw_effects: Vec<u8>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timestamp_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -125,6 +231,19 @@ pub struct SuiTransactionResponse {
     /// This is only returned in the read api, not in the transaction execution api.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint: Option<CheckpointSequenceNumber>,
+    /// Whether the transaction was read from the archival store, as it was pruned from the node.
+    /// This is only returned in the read api.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_archive: Option<bool>,
+    /// Net change of the SUI balance of the gas owner: the gas fees net of the storage rebate,
+    /// plus the SUI it received, minus the SUI it sent.
+    /// This is only returned in the transaction execution api.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_owner_balance_change: Option<i128>,
+    /// Input objects of the transaction, at the versions it read them.
+    /// This is only returned in the read api.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_objects: Option<Vec<SuiObjectData>>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub errors: Vec<String>,
 }
@@ -143,14 +262,17 @@ impl PartialEq for SuiTransactionResponse {
     fn eq(&self, other: &Self) -> bool {
         self.transaction == other.transaction
             && self.effects == other.effects
+            && self.raw_effects == other.raw_effects
             && self.timestamp_ms == other.timestamp_ms
             && self.confirmed_local_execution == other.confirmed_local_execution
             && self.checkpoint == other.checkpoint
     }
 }
 
+/// Kinds added by newer versions are deserialized as [SuiTransactionKind::Unknown], so that
+/// clients keep working with servers of newer versions.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename = "TransactionKind")]
+#[serde(remote = "Self", rename = "TransactionKind")]
 pub enum SuiTransactionKind {
     /// Initiate an object transfer between addresses
     TransferObject(SuiTransferObject),
@@ -179,6 +301,53 @@ pub enum SuiTransactionKind {
     /// commands
     ProgrammableTransaction(SuiProgrammableTransaction),
     // .. more transaction types go here
+    /// A kind of transaction unknown to this version
+    Unknown,
+}
+
+/// Tags of the kinds of [SuiTransactionKind], to tell the kinds unknown to this version apart
+/// from malformed known kinds.
+#[derive(Deserialize)]
+#[serde(field_identifier)]
+enum SuiTransactionKindTag {
+    TransferObject,
+    Pay,
+    PaySui,
+    PayAllSui,
+    Publish,
+    Call,
+    TransferSui,
+    ChangeEpoch,
+    Genesis,
+    ConsensusCommitPrologue,
+    ProgrammableTransaction,
+    #[serde(other)]
+    Unknown,
+}
+
+impl Serialize for SuiTransactionKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SuiTransactionKind::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SuiTransactionKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let tag = match &value {
+            Value::String(tag) => Some(tag),
+            Value::Object(kind) if kind.len() == 1 => kind.keys().next(),
+            _ => None,
+        };
+        if let Some(tag) = tag {
+            let tag: Result<_, serde::de::value::Error> =
+                SuiTransactionKindTag::deserialize(tag.as_str().into_deserializer());
+            if let Ok(SuiTransactionKindTag::Unknown) = tag {
+                return Ok(Self::Unknown);
+            }
+        }
+        SuiTransactionKind::deserialize(value).map_err(D::Error::custom)
+    }
 }
 
 impl Display for SuiTransactionKind {
@@ -278,15 +447,42 @@ impl Display for SuiTransactionKind {
                 writeln!(writer, "Transaction Kind : Programmable")?;
                 write!(writer, "{p}")?;
             }
+            Self::Unknown => {
+                write!(writer, "Transaction Kind : Unknown")?;
+            }
         }
         write!(f, "{}", writer)
     }
 }
 
+/// Failure to convert a transaction into its RPC representation.
+#[derive(Debug, Error)]
+pub enum SuiRpcConversionError {
+    #[error("Support for TransactionData version {message_version} not implemented")]
+    UnsupportedVersion { message_version: u64 },
+
+    #[error("Cannot decode argument {index}: {error}")]
+    ArgDecode { index: usize, error: String },
+
+    #[error("Cannot resolve module {module}: {error}")]
+    ModuleResolution { module: ModuleId, error: String },
+}
+
 impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
-    type Error = anyhow::Error;
+    type Error = SuiRpcConversionError;
 
     fn try_from(tx: SingleTransactionKind) -> Result<Self, Self::Error> {
+        Self::try_from(tx, &NoModuleResolver)
+    }
+}
+
+impl SuiTransactionKind {
+    /// Convert `tx`, decoding the pure arguments of Move calls with the signature of the called
+    /// function, looked up with `resolver`.
+    pub fn try_from(
+        tx: SingleTransactionKind,
+        resolver: &impl LayoutResolver,
+    ) -> Result<Self, SuiRpcConversionError> {
         Ok(match tx {
             SingleTransactionKind::TransferObject(t) => Self::TransferObject(SuiTransferObject {
                 recipient: t.recipient,
@@ -300,33 +496,32 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
             SingleTransactionKind::PaySui(p) => Self::PaySui(p.into()),
             SingleTransactionKind::PayAllSui(p) => Self::PayAllSui(p.into()),
             SingleTransactionKind::Publish(p) => Self::Publish(p.into()),
-            SingleTransactionKind::Call(c) => Self::Call(SuiMoveCall {
-                package: c.package,
-                module: c.module.to_string(),
-                function: c.function.to_string(),
-                type_arguments: c.type_arguments.iter().map(|ty| ty.to_string()).collect(),
-                arguments: c
-                    .arguments
-                    .into_iter()
-                    .map(|arg| match arg {
-                        CallArg::Pure(p) => SuiJsonValue::from_bcs_bytes(&p),
-                        CallArg::Object(ObjectArg::ImmOrOwnedObject((id, _, _)))
-                        | CallArg::Object(ObjectArg::SharedObject { id, .. }) => {
-                            SuiJsonValue::new(Value::String(Hex::encode(id)))
-                        }
-                        CallArg::ObjVec(vec) => SuiJsonValue::new(Value::Array(
-                            vec.iter()
-                                .map(|obj_arg| match obj_arg {
-                                    ObjectArg::ImmOrOwnedObject((id, _, _))
-                                    | ObjectArg::SharedObject { id, .. } => {
-                                        Value::String(Hex::encode(id))
-                                    }
-                                })
-                                .collect(),
-                        )),
-                    })
-                    .collect::<Result<Vec<_>, _>>()?,
-            }),
+            SingleTransactionKind::Call(c) => {
+                let layouts = pure_arg_layouts(
+                    resolver,
+                    &c.package,
+                    &c.module,
+                    &c.function,
+                    &c.type_arguments,
+                )?;
+                Self::Call(SuiMoveCall {
+                    package: c.package,
+                    module: c.module.to_string(),
+                    function: c.function.to_string(),
+                    type_arguments: c.type_arguments.iter().map(|ty| ty.to_string()).collect(),
+                    arguments: c
+                        .arguments
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, arg)| match arg {
+                            CallArg::Pure(p) => {
+                                pure_arg_to_json(i, &p, layouts.get(i).and_then(Option::as_ref))
+                            }
+                            arg => call_arg_to_json(i, arg),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                })
+            }
             SingleTransactionKind::ChangeEpoch(e) => Self::ChangeEpoch(SuiChangeEpoch {
                 epoch: e.epoch,
                 storage_charge: e.storage_charge,
@@ -345,7 +540,7 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                 })
             }
             SingleTransactionKind::ProgrammableTransaction(p) => {
-                Self::ProgrammableTransaction(p.try_into()?)
+                Self::ProgrammableTransaction(SuiProgrammableTransaction::try_from(p, resolver)?)
             }
         })
     }
@@ -403,6 +598,40 @@ pub trait SuiTransactionEffectsAPI {
 
     /// Return an iterator of mutated objects, but excluding the gas object.
     fn mutated_excluding_gas(&self) -> Vec<OwnedObjectRef>;
+
+    /// Return all objects that exist in the object state after this transaction, including
+    /// mutated, created and unwrapped objects, with the kind of write.
+    fn all_changed_objects(&self) -> Vec<(OwnedObjectRef, WriteKind)> {
+        self.mutated()
+            .iter()
+            .map(|o| (o.clone(), WriteKind::Mutate))
+            .chain(
+                self.created()
+                    .iter()
+                    .map(|o| (o.clone(), WriteKind::Create)),
+            )
+            .chain(
+                self.unwrapped()
+                    .iter()
+                    .map(|o| (o.clone(), WriteKind::Unwrap)),
+            )
+            .collect()
+    }
+
+    /// Return all objects that do not exist in the object state after this transaction,
+    /// including deleted, unwrapped_then_deleted and wrapped objects, with the kind of deletion.
+    fn all_deleted_objects(&self) -> Vec<(SuiObjectRef, DeleteKind)> {
+        self.deleted()
+            .iter()
+            .map(|r| (r.clone(), DeleteKind::Normal))
+            .chain(
+                self.unwrapped_then_deleted()
+                    .iter()
+                    .map(|r| (r.clone(), DeleteKind::UnwrapThenDelete)),
+            )
+            .chain(self.wrapped().iter().map(|r| (r.clone(), DeleteKind::Wrap)))
+            .collect()
+    }
 }
 
 /// The response from processing a transaction or a certified transaction
@@ -602,6 +831,67 @@ impl Display for SuiTransactionEffects {
 pub struct DryRunTransactionResponse {
     pub effects: SuiTransactionEffects,
     pub events: SuiTransactionEvents,
+    /// Gas coins of the sender after execution, absent from the responses of older nodes
+    #[serde(default, rename = "gasSmash", skip_serializing_if = "Option::is_none")]
+    pub gas_smash: Option<SuiGasSmash>,
+}
+
+/// Payment coins of a transaction after execution. They are smashed into the first one, which
+/// pays for the transaction, and the others are deleted.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "GasSmash", rename_all = "camelCase")]
+pub struct SuiGasSmash {
+    /// Reference of the coin paying for the transaction after execution
+    pub gas_coin: SuiObjectRef,
+    /// Payment coins merged into the gas coin and deleted, as of before execution
+    pub merged_coins: Vec<SuiObjectRef>,
+    /// Sum of the balances of the payment coins before execution
+    pub input_balance: u64,
+    /// Balance of the gas coin after execution, net of the gas charged and of the amounts the
+    /// transaction spent from it
+    pub final_balance: u64,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(
+    rename = "TransactionSubmissionStatus",
+    rename_all = "camelCase",
+    tag = "status"
+)]
+pub enum SuiTransactionSubmissionStatus {
+    /// The transaction was not enqueued on this node, or its result is no longer known.
+    Unknown,
+    /// The transaction is being submitted to validators.
+    Pending,
+    #[serde(rename_all = "camelCase")]
+    Finalized {
+        effects: SuiTransactionEffects,
+        confirmed_local_execution: bool,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+impl TryFrom<TransactionSubmissionStatus> for SuiTransactionSubmissionStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(status: TransactionSubmissionStatus) -> Result<Self, Self::Error> {
+        Ok(match status {
+            TransactionSubmissionStatus::Unknown => Self::Unknown,
+            TransactionSubmissionStatus::Pending => Self::Pending,
+            TransactionSubmissionStatus::Finalized {
+                effects,
+                executed_locally,
+            } => Self::Finalized {
+                effects: effects.try_into()?,
+                confirmed_local_execution: executed_locally,
+            },
+            TransactionSubmissionStatus::Failed(err) => Self::Failed {
+                error: err.to_string(),
+            },
+        })
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
@@ -611,9 +901,26 @@ pub struct SuiTransactionEvents {
 }
 
 impl SuiTransactionEvents {
+    /// Net change of the SUI balance of `owner` reported by the coin balance change events.
+    pub fn sui_balance_change(&self, owner: SuiAddress) -> i128 {
+        let sui = GAS::type_().to_string();
+        self.data
+            .iter()
+            .filter_map(|event| match event {
+                SuiEvent::CoinBalanceChange {
+                    owner: Owner::AddressOwner(address),
+                    coin_type,
+                    amount,
+                    ..
+                } if *address == owner && *coin_type == sui => Some(*amount),
+                _ => None,
+            })
+            .sum()
+    }
+
     pub fn try_from(
         events: TransactionEvents,
-        resolver: &impl GetModule,
+        resolver: &impl LayoutResolver,
     ) -> Result<Self, anyhow::Error> {
         Ok(Self {
             data: events
@@ -638,6 +945,40 @@ pub struct DevInspectResults {
     /// Execution results (including return values) from executing the transactions
     /// Currently contains only return values from Move calls
     pub results: Result<Vec<(usize, SuiExecutionResult)>, String>,
+    /// Attribution of the gas used by the transaction to its commands and to the objects it
+    /// writes or deletes, absent if the execution failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_breakdown: Option<SuiGasBreakdown>,
+}
+
+/// Breakdown of the gas used by a dev inspect transaction. The computation charged outside of
+/// the commands, such as the minimum transaction cost and the reads of the input objects, is not
+/// attributed to any command.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "GasBreakdown", rename_all = "camelCase")]
+pub struct SuiGasBreakdown {
+    /// Computation gas units charged by each command, in execution order
+    pub commands: Vec<SuiCommandGas>,
+    /// Storage cost and rebate of each written or deleted object. Immutable objects, such as
+    /// published packages, are not included.
+    pub objects: Vec<SuiObjectStorageGas>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "CommandGas", rename_all = "camelCase")]
+pub struct SuiCommandGas {
+    pub command_index: usize,
+    pub computation_units: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "ObjectStorageGas", rename_all = "camelCase")]
+pub struct SuiObjectStorageGas {
+    pub object_id: ObjectID,
+    /// Storage cost charged for the new version of the object, 0 if it was deleted
+    pub storage_cost: u64,
+    /// Storage rebate of the version of the object read by the transaction, 0 if it was created
+    pub storage_rebate: u64,
 }
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
@@ -662,7 +1003,8 @@ impl DevInspectResults {
         effects: TransactionEffects,
         events: TransactionEvents,
         return_values: Result<Vec<(usize, ExecutionResult)>, ExecutionError>,
-        resolver: &impl GetModule,
+        gas_breakdown: Option<SuiGasBreakdown>,
+        resolver: &impl LayoutResolver,
     ) -> Result<Self, anyhow::Error> {
         let results = match return_values {
             Err(e) => Err(format!("{}", e)),
@@ -690,6 +1032,7 @@ impl DevInspectResults {
             effects: effects.try_into()?,
             events: SuiTransactionEvents::try_from(events, resolver)?,
             results,
+            gas_breakdown,
         })
     }
 }
@@ -709,7 +1052,62 @@ pub enum SuiExecutionStatus {
     // Gas used in the success case.
     Success,
     // Gas used in the failed case, and the error.
-    Failure { error: String },
+    Failure {
+        error: String,
+        /// Structured description of the error, for clients that branch on the kind of failure.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        details: Option<SuiExecutionFailure>,
+    },
+}
+
+/// Kind and location of an execution failure.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "ExecutionFailure", rename_all = "camelCase")]
+pub struct SuiExecutionFailure {
+    /// Name of the `ExecutionFailureStatus` variant, e.g. `MoveAbort` or `InsufficientGas`.
+    pub kind: String,
+    /// Index of the command that failed, None if the failure is not specific to a command.
+    pub command: Option<usize>,
+    /// Move function executing when the failure occurred, for Move aborts and Move runtime errors.
+    pub location: Option<SuiMoveLocation>,
+    /// Abort code of a Move abort.
+    pub abort_code: Option<u64>,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "MoveLocation", rename_all = "camelCase")]
+pub struct SuiMoveLocation {
+    pub package: ObjectID,
+    pub module: String,
+    /// Name of the function, None if it could not be resolved.
+    pub function: Option<String>,
+}
+
+impl From<MoveLocation> for SuiMoveLocation {
+    fn from(location: MoveLocation) -> Self {
+        Self {
+            package: ObjectID::from(*location.module.address()),
+            module: location.module.name().to_string(),
+            function: location.function_name,
+        }
+    }
+}
+
+impl SuiExecutionFailure {
+    fn new(error: ExecutionFailureStatus, command: Option<CommandIndex>) -> Self {
+        let kind = <&'static str>::from(&error).to_string();
+        let (location, abort_code) = match error {
+            ExecutionFailureStatus::MoveAbort(location, code) => (Some(location), Some(code)),
+            ExecutionFailureStatus::MovePrimitiveRuntimeError(location) => (location, None),
+            _ => (None, None),
+        };
+        Self {
+            kind,
+            command,
+            location: location.map(SuiMoveLocation::from),
+            abort_code,
+        }
+    }
 }
 
 impl SuiExecutionStatus {
@@ -725,17 +1123,12 @@ impl From<ExecutionStatus> for SuiExecutionStatus {
     fn from(status: ExecutionStatus) -> Self {
         match status {
             ExecutionStatus::Success => Self::Success,
-            ExecutionStatus::Failure {
-                error,
-                command: None,
-            } => Self::Failure {
-                error: format!("{error:?}"),
-            },
-            ExecutionStatus::Failure {
-                error,
-                command: Some(idx),
-            } => Self::Failure {
-                error: format!("{error:?} in command {idx}"),
+            ExecutionStatus::Failure { error, command } => Self::Failure {
+                error: match command {
+                    None => format!("{error:?}"),
+                    Some(idx) => format!("{error:?} in command {idx}"),
+                },
+                details: Some(SuiExecutionFailure::new(error, command)),
             },
         }
     }
@@ -761,14 +1154,37 @@ pub struct SuiGasCostSummary {
     pub computation_cost: u64,
     pub storage_cost: u64,
     pub storage_rebate: u64,
+    /// The part of the storage rebate that is kept by the storage fund instead of being
+    /// refunded to the sender.
+    #[serde(default)]
+    pub non_refundable_storage_fee: u64,
+}
+
+impl SuiGasCostSummary {
+    /// Net gas usage ignoring the non refundable storage fee, positive number means used gas;
+    /// negative number means refund.
+    pub fn net_gas_usage(&self) -> i64 {
+        self.computation_cost as i64 + self.storage_cost as i64 - self.storage_rebate as i64
+    }
+
+    /// Net gas usage including the non refundable storage fee, i.e. the change of balance of
+    /// the gas coin. Positive number means used gas; negative number means refund.
+    pub fn net_gas_usage_improved(&self) -> i64 {
+        self.net_gas_usage() + self.non_refundable_storage_fee as i64
+    }
 }
 
 impl From<GasCostSummary> for SuiGasCostSummary {
     fn from(s: GasCostSummary) -> Self {
+        // The summary does not carry the protocol version it was charged with, the storage rebate
+        // rate is the same for all protocol versions so far.
+        let storage_rebate_rate =
+            ProtocolConfig::get_for_version(ProtocolVersion::MIN).storage_rebate_rate();
         Self {
             computation_cost: s.computation_cost,
             storage_cost: s.storage_cost,
             storage_rebate: s.storage_rebate,
+            non_refundable_storage_fee: s.storage_fund_rebate_inflow(storage_rebate_rate),
         }
     }
 }
@@ -880,6 +1296,9 @@ pub trait SuiTransactionDataAPI {
     fn transactions(&self) -> &[SuiTransactionKind];
     fn sender(&self) -> &SuiAddress;
     fn gas_data(&self) -> &SuiGasData;
+    /// Whether the gas of the transaction is paid by a sponsor instead of the sender.
+    fn is_sponsored(&self) -> bool;
+    fn expiration(&self) -> Option<EpochId>;
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
@@ -888,6 +1307,10 @@ pub struct SuiTransactionDataV1 {
     pub transactions: Vec<SuiTransactionKind>,
     pub sender: SuiAddress,
     pub gas_data: SuiGasData,
+    /// The last epoch validators sign the transaction in, so that a transaction of which signing
+    /// was abandoned can't be executed later. None if the transaction never expires.
+    #[serde(default)]
+    pub expiration: Option<EpochId>,
 }
 
 impl SuiTransactionDataAPI for SuiTransactionDataV1 {
@@ -900,6 +1323,12 @@ impl SuiTransactionDataAPI for SuiTransactionDataV1 {
     fn gas_data(&self) -> &SuiGasData {
         &self.gas_data
     }
+    fn is_sponsored(&self) -> bool {
+        self.gas_data.owner != self.sender
+    }
+    fn expiration(&self) -> Option<EpochId> {
+        self.expiration
+    }
 }
 
 impl SuiTransactionData {
@@ -947,16 +1376,25 @@ impl Display for SuiTransactionData {
 }
 
 impl TryFrom<TransactionData> for SuiTransactionData {
-    type Error = anyhow::Error;
+    type Error = SuiRpcConversionError;
 
     fn try_from(data: TransactionData) -> Result<Self, Self::Error> {
+        Self::try_from(data, &NoModuleResolver)
+    }
+}
+
+impl SuiTransactionData {
+    pub fn try_from(
+        data: TransactionData,
+        resolver: &impl LayoutResolver,
+    ) -> Result<Self, SuiRpcConversionError> {
         let transactions = match data.kind().clone() {
             TransactionKind::Single(tx) => {
-                vec![tx.try_into()?]
+                vec![SuiTransactionKind::try_from(tx, resolver)?]
             }
             TransactionKind::Batch(txs) => txs
                 .into_iter()
-                .map(SuiTransactionKind::try_from)
+                .map(|tx| SuiTransactionKind::try_from(tx, resolver))
                 .collect::<Result<Vec<_>, _>>()?,
         };
         let message_version = data
@@ -977,11 +1415,12 @@ impl TryFrom<TransactionData> for SuiTransactionData {
                     price: data.gas_price(),
                     budget: data.gas_budget(),
                 },
+                expiration: match data.expiration() {
+                    TransactionExpiration::None => None,
+                    TransactionExpiration::Epoch(epoch) => Some(*epoch),
+                },
             })),
-            _ => Err(anyhow::anyhow!(
-                "Support for TransactionData version {} not implemented",
-                message_version
-            )),
+            _ => Err(SuiRpcConversionError::UnsupportedVersion { message_version }),
         }
     }
 }
@@ -994,11 +1433,20 @@ pub struct SuiTransaction {
 }
 
 impl TryFrom<SenderSignedData> for SuiTransaction {
-    type Error = anyhow::Error;
+    type Error = SuiRpcConversionError;
 
     fn try_from(data: SenderSignedData) -> Result<Self, Self::Error> {
+        Self::try_from(data, &NoModuleResolver)
+    }
+}
+
+impl SuiTransaction {
+    pub fn try_from(
+        data: SenderSignedData,
+        resolver: &impl LayoutResolver,
+    ) -> Result<Self, SuiRpcConversionError> {
         Ok(Self {
-            data: data.intent_message.value.try_into()?,
+            data: SuiTransactionData::try_from(data.intent_message.value, resolver)?,
             tx_signatures: data.tx_signatures,
         })
     }
@@ -1060,7 +1508,7 @@ pub enum SuiInputObjectKind {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct SuiProgrammableTransaction {
     /// Input objects or primitive values
-    pub inputs: Vec<SuiJsonValue>,
+    pub inputs: Vec<SuiCallArg>,
     /// The commands to be executed sequentially. A failure in any command will
     /// result in the failure of the entire transaction.
     pub commands: Vec<SuiCommand>,
@@ -1079,20 +1527,222 @@ impl Display for SuiProgrammableTransaction {
 }
 
 impl TryFrom<ProgrammableTransaction> for SuiProgrammableTransaction {
-    type Error = anyhow::Error;
+    type Error = SuiRpcConversionError;
 
     fn try_from(value: ProgrammableTransaction) -> Result<Self, Self::Error> {
+        Self::try_from(value, &NoModuleResolver)
+    }
+}
+
+impl SuiProgrammableTransaction {
+    /// Convert `value`, decoding the pure inputs passed to Move calls with the signature of the
+    /// called function, looked up with `resolver`.
+    pub fn try_from(
+        value: ProgrammableTransaction,
+        resolver: &impl LayoutResolver,
+    ) -> Result<Self, SuiRpcConversionError> {
         let ProgrammableTransaction { inputs, commands } = value;
+        // An input may be used by several calls, the first call gives its type.
+        let mut input_layouts: Vec<Option<MoveTypeLayout>> = vec![None; inputs.len()];
+        for command in &commands {
+            if let Command::MoveCall(call) = command {
+                let layouts = pure_arg_layouts(
+                    resolver,
+                    &call.package,
+                    &call.module,
+                    &call.function,
+                    &call.type_arguments,
+                )?;
+                for (arg, layout) in call.arguments.iter().zip(layouts) {
+                    if let (Argument::Input(i), Some(layout)) = (arg, layout) {
+                        if let Some(input_layout) = input_layouts.get_mut(*i as usize) {
+                            input_layout.get_or_insert(layout);
+                        }
+                    }
+                }
+            }
+        }
         Ok(SuiProgrammableTransaction {
             inputs: inputs
                 .into_iter()
-                .map(|arg| arg.try_into())
-                .collect::<Result<_, _>>()?,
+                .zip(input_layouts)
+                .map(|(arg, layout)| SuiCallArg::new(arg, layout.as_ref()))
+                .collect(),
             commands: commands.into_iter().map(SuiCommand::from).collect(),
         })
     }
 }
 
+/// Layouts of the pure parameters of the called function, see [LayoutResolver::pure_arg_layouts].
+/// Empty if the function can't be found, the pure arguments are then decoded without their
+/// type.
+fn pure_arg_layouts(
+    resolver: &impl LayoutResolver,
+    package: &ObjectID,
+    module: &Identifier,
+    function: &Identifier,
+    type_arguments: &[TypeTag],
+) -> Result<Vec<Option<MoveTypeLayout>>, SuiRpcConversionError> {
+    let module_id = ModuleId::new(AccountAddress::from(*package), module.clone());
+    resolver.pure_arg_layouts(&module_id, function, type_arguments)
+}
+
+fn pure_arg_to_json(
+    index: usize,
+    bytes: &[u8],
+    layout: Option<&MoveTypeLayout>,
+) -> Result<SuiJsonValue, SuiRpcConversionError> {
+    match layout.and_then(|layout| SuiJsonValue::from_bcs_bytes_with_layout(bytes, layout).ok()) {
+        Some(value) => Ok(value),
+        None => SuiJsonValue::from_bcs_bytes(bytes).map_err(|e| SuiRpcConversionError::ArgDecode {
+            index,
+            error: e.to_string(),
+        }),
+    }
+}
+
+fn call_arg_to_json(index: usize, arg: CallArg) -> Result<SuiJsonValue, SuiRpcConversionError> {
+    SuiJsonValue::try_from(arg).map_err(|e| SuiRpcConversionError::ArgDecode {
+        index,
+        error: e.to_string(),
+    })
+}
+
+/// An input of a programmable transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase", rename = "CallArg")]
+pub enum SuiCallArg {
+    /// A pure value, BCS encoded
+    Pure(SuiPureValue),
+    /// A Move object
+    Object(SuiObjectArg),
+    /// A vector of Move objects
+    ObjVec { objects: Vec<SuiObjectArg> },
+}
+
+impl SuiCallArg {
+    /// Convert `arg`, decoding a pure value with `layout`, the layout of the parameter it is
+    /// passed to if known.
+    pub fn new(arg: CallArg, layout: Option<&MoveTypeLayout>) -> Self {
+        match arg {
+            CallArg::Pure(bytes) => Self::Pure(SuiPureValue::new(bytes, layout)),
+            CallArg::Object(object) => Self::Object(object.into()),
+            CallArg::ObjVec(objects) => Self::ObjVec {
+                objects: objects.into_iter().map(SuiObjectArg::from).collect(),
+            },
+        }
+    }
+
+    pub fn pure(&self) -> Option<&SuiPureValue> {
+        match self {
+            Self::Pure(value) => Some(value),
+            Self::Object(_) | Self::ObjVec { .. } => None,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename = "PureValue", rename_all = "camelCase")]
+pub struct SuiPureValue {
+    /// The Move type of the value, known if the value is passed to a Move call. Strings and IDs
+    /// are left untyped.
+    pub value_type: Option<String>,
+    /// The value decoded with its type, if known
+    pub value: Option<SuiJsonValue>,
+    /// The BCS encoding of the value
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    pub bytes: Vec<u8>,
+}
+
+impl SuiPureValue {
+    fn new(bytes: Vec<u8>, layout: Option<&MoveTypeLayout>) -> Self {
+        Self {
+            value_type: layout.and_then(layout_type_name),
+            value: layout
+                .and_then(|layout| SuiJsonValue::from_bcs_bytes_with_layout(&bytes, layout).ok()),
+            bytes,
+        }
+    }
+
+    /// The value decoded from its BCS bytes as a `T`, `None` if they don't encode a `T`.
+    pub fn to_value<T: DeserializeOwned>(&self) -> Option<T> {
+        bcs::from_bytes(&self.bytes).ok()
+    }
+}
+
+/// Name of the Move type of the values decoded with `layout`, `None` for the struct layouts of
+/// pure values, which don't carry their type.
+fn layout_type_name(layout: &MoveTypeLayout) -> Option<String> {
+    Some(match layout {
+        MoveTypeLayout::Bool => "bool".to_string(),
+        MoveTypeLayout::U8 => "u8".to_string(),
+        MoveTypeLayout::U16 => "u16".to_string(),
+        MoveTypeLayout::U32 => "u32".to_string(),
+        MoveTypeLayout::U64 => "u64".to_string(),
+        MoveTypeLayout::U128 => "u128".to_string(),
+        MoveTypeLayout::U256 => "u256".to_string(),
+        MoveTypeLayout::Address => "address".to_string(),
+        MoveTypeLayout::Signer => "signer".to_string(),
+        MoveTypeLayout::Vector(inner) => format!("vector<{}>", layout_type_name(inner)?),
+        MoveTypeLayout::Struct(_) => return None,
+    })
+}
+
+/// An object input of a programmable transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "objectType", rename_all = "camelCase", rename = "ObjectArg")]
+pub enum SuiObjectArg {
+    /// A Move object, either immutable, or owned mutable
+    #[serde(rename_all = "camelCase")]
+    ImmOrOwnedObject {
+        object_id: ObjectID,
+        version: SequenceNumber,
+        digest: ObjectDigest,
+    },
+    /// A Move object that's shared, used mutably or not
+    #[serde(rename_all = "camelCase")]
+    SharedObject {
+        object_id: ObjectID,
+        initial_shared_version: SequenceNumber,
+        mutable: bool,
+    },
+}
+
+impl From<ObjectArg> for SuiObjectArg {
+    fn from(arg: ObjectArg) -> Self {
+        match arg {
+            ObjectArg::ImmOrOwnedObject((object_id, version, digest)) => Self::ImmOrOwnedObject {
+                object_id,
+                version,
+                digest,
+            },
+            ObjectArg::SharedObject {
+                id,
+                initial_shared_version,
+                mutable,
+            } => Self::SharedObject {
+                object_id: id,
+                initial_shared_version,
+                mutable,
+            },
+        }
+    }
+}
+
+/// Resolver without any module, for conversions without a module cache at hand.
+struct NoModuleResolver;
+
+impl GetModule for NoModuleResolver {
+    type Error = anyhow::Error;
+    type Item = CompiledModule;
+
+    fn get_module_by_id(&self, _id: &ModuleId) -> Result<Option<CompiledModule>, anyhow::Error> {
+        Ok(None)
+    }
+}
+
 /// A single command in a programmable transaction.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub enum SuiCommand {
@@ -1115,6 +1765,8 @@ pub enum SuiCommand {
     /// Given n-values of the same type, it constructs a vector. For non objects or an empty vector,
     /// the type tag must be specified.
     MakeMoveVec(Option<String>, Vec<SuiArgument>),
+    /// Upgrades a Move package, as authorized by an `UpgradeTicket`
+    Upgrade(Box<SuiProgrammableUpgrade>),
 }
 
 impl Display for SuiCommand {
@@ -1146,6 +1798,7 @@ impl Display for SuiCommand {
                 write!(f, ")")
             }
             Self::Publish(_bytes) => write!(f, "Publish(_)"),
+            Self::Upgrade(u) => write!(f, "Upgrade({u})"),
         }
     }
 }
@@ -1170,6 +1823,18 @@ impl From<Command> for SuiCommand {
                 tag_opt.map(|tag| tag.to_string()),
                 args.into_iter().map(SuiArgument::from).collect(),
             ),
+            Command::Upgrade(modules, dependencies, package, ticket) => {
+                SuiCommand::Upgrade(Box::new(SuiProgrammableUpgrade {
+                    package,
+                    ticket: ticket.into(),
+                    modules_digest: compute_digest_for_modules_and_deps(&modules, &dependencies)
+                        .to_vec(),
+                    dependencies,
+                    modules: SuiMovePackage {
+                        disassembled: disassemble_modules(modules.iter()).unwrap_or_default(),
+                    },
+                }))
+            }
         }
     }
 }
@@ -1230,6 +1895,33 @@ pub struct SuiProgrammableMoveCall {
     pub arguments: Vec<SuiArgument>,
 }
 
+/// The command for upgrading a Move package to a new version.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiProgrammableUpgrade {
+    /// The package being upgraded.
+    pub package: ObjectID,
+    /// The `UpgradeTicket` authorizing the upgrade, issued by the `UpgradeCap` of the package.
+    pub ticket: SuiArgument,
+    /// The transitive dependencies of the new version of the package.
+    pub dependencies: Vec<ObjectID>,
+    /// The digest of the modules and dependencies of the new version of the package, that the
+    /// ticket must have been issued for.
+    #[serde_as(as = "Base64")]
+    #[schemars(with = "Base64")]
+    pub modules_digest: Vec<u8>,
+    /// The modules of the new version of the package.
+    pub modules: SuiMovePackage,
+}
+
+impl Display for SuiProgrammableUpgrade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},[", self.package, self.ticket)?;
+        write_sep(f, &self.dependencies, ",")?;
+        write!(f, "],{}", Base64::encode(&self.modules_digest))
+    }
+}
+
 fn write_sep<T: Display>(
     f: &mut Formatter<'_>,
     items: impl IntoIterator<Item = T>,
@@ -1307,20 +1999,106 @@ impl From<InputObjectKind> for SuiInputObjectKind {
     }
 }
 
-#[derive(Serialize, Deserialize, JsonSchema, Clone)]
-#[serde(rename = "TypeTag", rename_all = "camelCase")]
-pub struct SuiTypeTag(String);
+/// A Move type, parsed when constructed so that the same type compares equal however it was
+/// written, e.g. `0x2::sui::SUI`, `sui::sui::SUI` or with the full address. Serialized in the
+/// short form, see [TypeTagDisplay].
+#[derive(
+    Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename = "TypeTag", try_from = "String", into = "String")]
+pub struct SuiTypeTag(#[schemars(with = "String")] TypeTag);
+
+/// How the addresses of a [SuiTypeTag] are written.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TypeTagDisplay {
+    /// Leading zeros are dropped, e.g. `0x2::coin::Coin<0x2::sui::SUI>`.
+    #[default]
+    Short,
+    /// Addresses are written in full, so that a type has a single canonical string.
+    Canonical,
+}
+
+impl SuiTypeTag {
+    /// Parses a type tag, named addresses `std` and `sui` are resolved.
+    pub fn new(tag: &str) -> anyhow::Result<Self> {
+        parse_sui_type_tag(tag).map(Self)
+    }
+
+    pub fn type_tag(&self) -> &TypeTag {
+        &self.0
+    }
 
-impl TryInto<TypeTag> for SuiTypeTag {
+    pub fn to_string_with(&self, display: TypeTagDisplay) -> String {
+        let mut out = String::new();
+        write_type_tag(&self.0, display, &mut out);
+        out
+    }
+}
+
+fn write_type_tag(tag: &TypeTag, display: TypeTagDisplay, out: &mut String) {
+    match tag {
+        TypeTag::Vector(tag) => {
+            out.push_str("vector<");
+            write_type_tag(tag, display, out);
+            out.push('>');
+        }
+        TypeTag::Struct(tag) => {
+            match display {
+                TypeTagDisplay::Short => write!(out, "0x{}", tag.address.short_str_lossless()),
+                TypeTagDisplay::Canonical => write!(out, "0x{:x}", tag.address),
+            }
+            .unwrap();
+            write!(out, "::{}::{}", tag.module, tag.name).unwrap();
+            if !tag.type_params.is_empty() {
+                out.push('<');
+                for (i, param) in tag.type_params.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    write_type_tag(param, display, out);
+                }
+                out.push('>');
+            }
+        }
+        primitive => write!(out, "{primitive}").unwrap(),
+    }
+}
+
+impl Display for SuiTypeTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with(TypeTagDisplay::Short))
+    }
+}
+
+impl FromStr for SuiTypeTag {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for SuiTypeTag {
     type Error = anyhow::Error;
-    fn try_into(self) -> Result<TypeTag, Self::Error> {
-        parse_sui_type_tag(&self.0)
+    fn try_from(tag: String) -> Result<Self, Self::Error> {
+        Self::new(&tag)
+    }
+}
+
+impl From<SuiTypeTag> for String {
+    fn from(tag: SuiTypeTag) -> Self {
+        tag.to_string()
+    }
+}
+
+impl From<SuiTypeTag> for TypeTag {
+    fn from(tag: SuiTypeTag) -> Self {
+        tag.0
     }
 }
 
 impl From<TypeTag> for SuiTypeTag {
     fn from(tag: TypeTag) -> Self {
-        Self(format!("{}", tag))
+        Self(tag)
     }
 }
 
@@ -1338,6 +2116,23 @@ pub struct TransferObjectParams {
     pub object_id: ObjectID,
 }
 
+/// A single transfer of a batch transfer transaction, either an object or an amount of SUI
+/// split from the gas coin.
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BatchTransferParams {
+    #[serde(rename_all = "camelCase")]
+    Object {
+        object_id: ObjectID,
+        recipient: SuiAddress,
+    },
+    #[serde(rename_all = "camelCase")]
+    Sui {
+        amount: BigInt,
+        recipient: SuiAddress,
+    },
+}
+
 #[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MoveCallParams {
@@ -2,20 +2,39 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::str::FromStr;
+use std::sync::Arc;
 
 use anyhow::anyhow;
+use move_binary_format::file_format::empty_module;
+use move_binary_format::CompiledModule;
+use move_bytecode_utils::module_cache::GetModule;
 use move_core_types::ident_str;
 use move_core_types::identifier::Identifier;
-use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
 use move_core_types::value::{MoveStruct, MoveValue};
 
 use sui_types::base_types::SequenceNumber;
-use sui_types::base_types::{ObjectID, SuiAddress};
-use sui_types::gas_coin::GasCoin;
-use sui_types::object::MoveObject;
+use sui_types::base_types::{random_object_ref, ObjectID, SuiAddress};
+use sui_types::event::BalanceChangeType;
+use sui_types::gas::GasCostSummary;
+use sui_types::gas_coin::{GasCoin, GAS};
+use sui_types::messages::{
+    Argument, CallArg, Command, ExecutionFailureStatus, ExecutionStatus, MoveCall, MoveLocation,
+    ObjectArg, ProgrammableTransaction, SingleTransactionKind, TransactionEffects,
+    TransactionEffectsV1,
+};
+use sui_types::move_package::{compute_digest_for_modules_and_deps, MovePackage};
+use sui_types::object::{MoveObject, Owner};
+use sui_types::storage::{DeleteKind, WriteKind};
 use sui_types::{MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS};
 
-use crate::{SuiMoveStruct, SuiMoveValue};
+use crate::{
+    to_canonical_json, CanonicalJson, PackageCache, SuiCallArg, SuiCommand, SuiEvent,
+    SuiExecutionFailure, SuiExecutionStatus, SuiGasCostSummary, SuiMoveLocation, SuiMoveStruct,
+    SuiMoveValue, SuiObjectArg, SuiProgrammableTransaction, SuiRpcConversionError,
+    SuiTransactionEffects, SuiTransactionEffectsAPI, SuiTransactionEvents, SuiTransactionKind,
+    SuiTransferSui, SuiTypeTag, TypeTagDisplay,
+};
 
 #[test]
 fn test_move_value_to_sui_coin() {
@@ -143,3 +162,361 @@ fn test_serde() {
         )
     }
 }
+
+#[test]
+fn test_all_changed_and_deleted_objects() {
+    let owner = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+    let (created, mutated, unwrapped) = (
+        random_object_ref(),
+        random_object_ref(),
+        random_object_ref(),
+    );
+    let (deleted, unwrapped_then_deleted, wrapped) = (
+        random_object_ref(),
+        random_object_ref(),
+        random_object_ref(),
+    );
+    let effects = TransactionEffects::V1(TransactionEffectsV1 {
+        created: vec![(created, owner)],
+        mutated: vec![(mutated, owner)],
+        unwrapped: vec![(unwrapped, owner)],
+        deleted: vec![deleted],
+        unwrapped_then_deleted: vec![unwrapped_then_deleted],
+        wrapped: vec![wrapped],
+        ..Default::default()
+    });
+    let effects = SuiTransactionEffects::try_from(effects).unwrap();
+
+    let changed = effects
+        .all_changed_objects()
+        .into_iter()
+        .map(|(o, kind)| (o.reference.to_object_ref(), kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changed,
+        vec![
+            (mutated, WriteKind::Mutate),
+            (created, WriteKind::Create),
+            (unwrapped, WriteKind::Unwrap),
+        ]
+    );
+
+    let deleted_objects = effects
+        .all_deleted_objects()
+        .into_iter()
+        .map(|(o, kind)| (o.to_object_ref(), kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        deleted_objects,
+        vec![
+            (deleted, DeleteKind::Normal),
+            (unwrapped_then_deleted, DeleteKind::UnwrapThenDelete),
+            (wrapped, DeleteKind::Wrap),
+        ]
+    );
+}
+
+#[test]
+fn test_gas_cost_summary_net_gas_usage() {
+    // The storage rebate exceeds the costs, the sender gets a refund
+    let summary = SuiGasCostSummary::from(GasCostSummary::new(100, 200, 1000));
+    // 1% of the rebate is kept by the storage fund
+    assert_eq!(summary.non_refundable_storage_fee, 10);
+    assert_eq!(summary.net_gas_usage(), -700);
+    assert_eq!(summary.net_gas_usage_improved(), -690);
+
+    let summary = SuiGasCostSummary::from(GasCostSummary::new(100, 200, 0));
+    assert_eq!(summary.non_refundable_storage_fee, 0);
+    assert_eq!(summary.net_gas_usage(), 300);
+    assert_eq!(summary.net_gas_usage_improved(), 300);
+
+    // Summaries without the fee still deserialize
+    let summary: SuiGasCostSummary =
+        serde_json::from_str(r#"{"computationCost":1,"storageCost":2,"storageRebate":3}"#).unwrap();
+    assert_eq!(summary.non_refundable_storage_fee, 0);
+}
+
+#[test]
+fn test_transaction_kind_conversion_errors() {
+    struct FailingResolver;
+
+    impl GetModule for FailingResolver {
+        type Error = anyhow::Error;
+        type Item = CompiledModule;
+
+        fn get_module_by_id(&self, _id: &ModuleId) -> Result<Option<CompiledModule>, Self::Error> {
+            Err(anyhow!("store unavailable"))
+        }
+    }
+
+    let call = SingleTransactionKind::Call(MoveCall {
+        package: ObjectID::random(),
+        module: Identifier::new("m").unwrap(),
+        function: Identifier::new("f").unwrap(),
+        type_arguments: vec![],
+        arguments: vec![CallArg::Pure(bcs::to_bytes(&42u64).unwrap())],
+    });
+
+    // Without the module, the pure argument is decoded without its type.
+    assert!(SuiTransactionKind::try_from(call.clone()).is_ok());
+
+    let err = SuiTransactionKind::try_from(call, &FailingResolver).unwrap_err();
+    assert!(
+        matches!(err, SuiRpcConversionError::ModuleResolution { ref module, .. } if module.name().as_str() == "m"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_programmable_transaction_inputs() {
+    let shared_id = ObjectID::random();
+    let owned = random_object_ref();
+    let pt = ProgrammableTransaction {
+        inputs: vec![
+            CallArg::Pure(bcs::to_bytes(&42u64).unwrap()),
+            CallArg::Object(ObjectArg::SharedObject {
+                id: shared_id,
+                initial_shared_version: SequenceNumber::from_u64(3),
+                mutable: false,
+            }),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+        ],
+        commands: vec![],
+    };
+    let pt: SuiProgrammableTransaction = pt.try_into().unwrap();
+
+    // Without the called functions, the type of the pure value is unknown.
+    let SuiCallArg::Pure(pure) = &pt.inputs[0] else {
+        panic!("Expected a pure value");
+    };
+    assert_eq!(pure.value_type, None);
+    assert_eq!(pure.value, None);
+    assert_eq!(pure.to_value::<u64>(), Some(42));
+    assert_eq!(
+        pt.inputs[1],
+        SuiCallArg::Object(SuiObjectArg::SharedObject {
+            object_id: shared_id,
+            initial_shared_version: SequenceNumber::from_u64(3),
+            mutable: false,
+        })
+    );
+    assert_eq!(
+        pt.inputs[2],
+        SuiCallArg::Object(SuiObjectArg::ImmOrOwnedObject {
+            object_id: owned.0,
+            version: owned.1,
+            digest: owned.2,
+        })
+    );
+
+    let json = serde_json::to_value(&pt.inputs[1]).unwrap();
+    assert_eq!(json["type"], "object");
+    assert_eq!(json["objectType"], "sharedObject");
+    assert_eq!(json["mutable"], false);
+    let inputs: Vec<SuiCallArg> =
+        serde_json::from_value(serde_json::to_value(&pt.inputs).unwrap()).unwrap();
+    assert_eq!(inputs, pt.inputs);
+}
+
+#[test]
+fn test_upgrade_command_conversion() {
+    let mut module = vec![];
+    empty_module().serialize(&mut module).unwrap();
+    let modules = vec![module, vec![0xab; 4]];
+    let dependencies = vec![ObjectID::random(), ObjectID::random()];
+    let package = ObjectID::random();
+
+    // The digest does not depend on the order of the modules and dependencies.
+    let digest = compute_digest_for_modules_and_deps(&modules, &dependencies);
+    let reversed_modules: Vec<_> = modules.iter().rev().cloned().collect();
+    let reversed_dependencies: Vec<_> = dependencies.iter().rev().cloned().collect();
+    assert_eq!(
+        digest,
+        compute_digest_for_modules_and_deps(&reversed_modules, &reversed_dependencies)
+    );
+    assert_ne!(digest, compute_digest_for_modules_and_deps(&modules, &[]));
+
+    let command = Command::Upgrade(modules, dependencies.clone(), package, Argument::Result(0));
+    let SuiCommand::Upgrade(upgrade) = SuiCommand::from(command) else {
+        panic!("Expected an upgrade command");
+    };
+    assert_eq!(upgrade.package, package);
+    assert_eq!(upgrade.dependencies, dependencies);
+    assert_eq!(upgrade.modules_digest, digest.to_vec());
+}
+
+#[test]
+fn test_canonical_json() {
+    let a: serde_json::Value =
+        serde_json::from_str(r#"{"b": 1.0, "a": {"d": [2, -3.50], "c": "\u00e9"}, "A": null}"#)
+            .unwrap();
+    let b: serde_json::Value =
+        serde_json::from_str(r#"{"A": null, "a": {"c": "é", "d": [2.0, -3.5]}, "b": 1}"#).unwrap();
+    let expected = r#"{"A":null,"a":{"c":"é","d":[2,-3.5]},"b":1}"#;
+    assert_eq!(to_canonical_json(&a).unwrap(), expected);
+    assert_eq!(to_canonical_json(&b).unwrap(), expected);
+
+    // Integers that are not exactly representable as f64 are kept verbatim.
+    let big = serde_json::json!({ "v": u64::MAX });
+    assert_eq!(
+        to_canonical_json(&big).unwrap(),
+        format!(r#"{{"v":{}}}"#, u64::MAX)
+    );
+
+    let events = SuiTransactionEvents::default();
+    assert_eq!(events.to_canonical_json().unwrap(), "[]");
+}
+
+#[test]
+fn test_execution_failure_details() {
+    let module = ModuleId::new(SUI_FRAMEWORK_ADDRESS, ident_str!("coin").to_owned());
+    let status = ExecutionStatus::Failure {
+        error: ExecutionFailureStatus::MoveAbort(
+            MoveLocation {
+                module,
+                function: 2,
+                instruction: 7,
+                function_name: Some("split".to_string()),
+            },
+            5,
+        ),
+        command: Some(1),
+    };
+    let SuiExecutionStatus::Failure { error, details } = SuiExecutionStatus::from(status) else {
+        panic!("Expected a failure");
+    };
+    assert!(error.ends_with("in command 1"), "{error}");
+    assert_eq!(
+        details,
+        Some(SuiExecutionFailure {
+            kind: "MoveAbort".to_string(),
+            command: Some(1),
+            location: Some(SuiMoveLocation {
+                package: ObjectID::from(SUI_FRAMEWORK_ADDRESS),
+                module: "coin".to_string(),
+                function: Some("split".to_string()),
+            }),
+            abort_code: Some(5),
+        })
+    );
+
+    let status = ExecutionStatus::Failure {
+        error: ExecutionFailureStatus::InsufficientGas,
+        command: None,
+    };
+    let SuiExecutionStatus::Failure { details, .. } = SuiExecutionStatus::from(status) else {
+        panic!("Expected a failure");
+    };
+    let details = details.unwrap();
+    assert_eq!(details.kind, "InsufficientGas");
+    assert_eq!((details.location, details.abort_code), (None, None));
+}
+
+#[test]
+fn test_type_tag_forms() {
+    let full_address = format!("0x{:x}", SUI_FRAMEWORK_ADDRESS);
+    let canonical = format!("{full_address}::coin::Coin<{full_address}::sui::SUI>");
+    let short = SuiTypeTag::new("0x2::coin::Coin<0x2::sui::SUI>").unwrap();
+    let named = SuiTypeTag::new("sui::coin::Coin<sui::sui::SUI>").unwrap();
+    let full = SuiTypeTag::new(&canonical).unwrap();
+    assert_eq!(short, named);
+    assert_eq!(short, full);
+
+    assert_eq!(short.to_string(), "0x2::coin::Coin<0x2::sui::SUI>");
+    assert_eq!(full.to_string_with(TypeTagDisplay::Canonical), canonical);
+    assert_eq!(
+        SuiTypeTag::new("vector<u8>")
+            .unwrap()
+            .to_string_with(TypeTagDisplay::Canonical),
+        "vector<u8>"
+    );
+
+    let json = serde_json::to_string(&full).unwrap();
+    assert_eq!(json, r#""0x2::coin::Coin<0x2::sui::SUI>""#);
+    assert_eq!(serde_json::from_str::<SuiTypeTag>(&json).unwrap(), short);
+    assert!(serde_json::from_str::<SuiTypeTag>(r#""0x2::coin::""#).is_err());
+    assert!(SuiTypeTag::new("not a type").is_err());
+}
+
+#[test]
+fn test_package_cache_invalidation() {
+    let module = empty_module();
+    let package = |version| {
+        MovePackage::from_module_iter(SequenceNumber::from(version), [module.clone()], u64::MAX)
+            .unwrap()
+    };
+    let (v1, v2) = (package(1), package(2));
+    let cache = PackageCache::new(1);
+
+    let disassembled = cache.disassembled(&v1).unwrap();
+    assert!(Arc::ptr_eq(
+        &disassembled,
+        &cache.disassembled(&v1).unwrap()
+    ));
+
+    // A newer version of the package replaces the cached one, older versions don't.
+    let upgraded = cache.disassembled(&v2).unwrap();
+    assert!(!Arc::ptr_eq(&disassembled, &upgraded));
+    assert_eq!(disassembled, upgraded);
+    cache.disassembled(&v1).unwrap();
+    assert!(Arc::ptr_eq(&upgraded, &cache.disassembled(&v2).unwrap()));
+
+    cache.invalidate(&v2.id());
+    assert!(!Arc::ptr_eq(&upgraded, &cache.disassembled(&v2).unwrap()));
+}
+
+#[test]
+fn test_unknown_transaction_kind() {
+    let kind = SuiTransactionKind::TransferSui(SuiTransferSui {
+        recipient: SuiAddress::random_for_testing_only(),
+        amount: Some(100),
+    });
+    let json = serde_json::to_value(&kind).unwrap();
+    assert_eq!(
+        serde_json::from_value::<SuiTransactionKind>(json).unwrap(),
+        kind
+    );
+
+    // Kinds added by newer versions are read as unknown, malformed known kinds are errors.
+    let new_kind = serde_json::json!({ "NewKind": { "field": 1 } });
+    assert_eq!(
+        serde_json::from_value::<SuiTransactionKind>(new_kind).unwrap(),
+        SuiTransactionKind::Unknown
+    );
+    let malformed = serde_json::json!({ "TransferSui": { "amount": "all" } });
+    assert!(serde_json::from_value::<SuiTransactionKind>(malformed).is_err());
+}
+
+#[test]
+fn test_sui_balance_change() {
+    let owner = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let balance_change =
+        |owner, coin_type: String, change_type, amount| SuiEvent::CoinBalanceChange {
+            package_id: ObjectID::random(),
+            transaction_module: "pay".to_string(),
+            sender: owner,
+            change_type,
+            owner: Owner::AddressOwner(owner),
+            coin_type,
+            coin_object_id: ObjectID::random(),
+            version: SequenceNumber::new(),
+            amount,
+        };
+    let sui = GAS::type_().to_string();
+    let events = SuiTransactionEvents {
+        data: vec![
+            balance_change(owner, sui.clone(), BalanceChangeType::Gas, -1_000),
+            balance_change(owner, sui.clone(), BalanceChangeType::Pay, -50_000),
+            balance_change(recipient, sui.clone(), BalanceChangeType::Receive, 50_000),
+            balance_change(
+                owner,
+                "0x2::devnet_nft::NFT".to_string(),
+                BalanceChangeType::Receive,
+                1,
+            ),
+        ],
+    };
+    assert_eq!(events.sui_balance_change(owner), -51_000);
+    assert_eq!(events.sui_balance_change(recipient), 50_000);
+}
@@ -4,9 +4,11 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
 
-use sui_json_rpc_types::{EventPage, SuiEventFilter};
+use sui_json_rpc_types::{EventPage, SuiEventFilter, SuiPackagePublish, SuiPackagePublishFilter};
 use sui_open_rpc_macros::open_rpc;
+use sui_types::base_types::TransactionDigest;
 use sui_types::event::EventID;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 
 use sui_json_rpc_types::SuiEventEnvelope;
 use sui_types::query::EventQuery;
@@ -28,6 +30,31 @@ pub trait EventReadApi {
         descending_order: Option<bool>,
     ) -> RpcResult<EventPage>;
 
+    /// Return all events emitted by the transactions of a checkpoint, in execution order.
+    #[method(name = "getEventsByCheckpoint")]
+    async fn get_events_by_checkpoint(
+        &self,
+        /// the sequence number of the checkpoint.
+        checkpoint: CheckpointSequenceNumber,
+        /// optional paging cursor
+        cursor: Option<EventID>,
+        /// maximum number of items per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage>;
+
+    /// Return the events emitted by a transaction, in emission order. Transactions emitting many
+    /// events can be fetched page by page instead of in a single response.
+    #[method(name = "getTransactionEvents")]
+    async fn get_transaction_events(
+        &self,
+        /// the digest of the transaction.
+        digest: TransactionDigest,
+        /// optional paging cursor, must refer to an event of this transaction.
+        cursor: Option<EventID>,
+        /// maximum number of items per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage>;
+
     /// Subscribe to a stream of Sui event
     #[subscription(name = "subscribeEvent", item = SuiEventEnvelope)]
     fn subscribe_event(
@@ -35,4 +62,12 @@ pub trait EventReadApi {
         /// the filter criteria of the event stream, see the [Sui docs](https://docs.sui.io/build/pubsub#event-filters) for detailed examples.
         filter: SuiEventFilter,
     );
+
+    /// Subscribe to a stream of Move package publishes
+    #[subscription(name = "subscribePackagePublishes", item = SuiPackagePublish)]
+    fn subscribe_package_publishes(
+        &self,
+        /// optional publisher and dependency criteria, all packages are emitted if not specified.
+        filter: Option<SuiPackagePublishFilter>,
+    );
 }
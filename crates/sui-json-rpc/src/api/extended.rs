@@ -0,0 +1,134 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::core::RpcResult;
+use jsonrpsee_proc_macros::rpc;
+
+use sui_json_rpc_types::{
+    ActiveAddressStats, AddressEpochActivity, FailingFunction, HotSharedObject,
+    IncomingPaymentPage, ObjectHistoryPage, ObjectInfoPage, SuiObjectInfo, TransactionErrorStats,
+};
+use sui_open_rpc_macros::open_rpc;
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// Analytics APIs served by the indexer, these are not available on full nodes.
+#[open_rpc(namespace = "sui", tag = "Extended API")]
+#[rpc(server, client, namespace = "sui")]
+pub trait ExtendedApi {
+    /// Return the shared objects used by the most transactions within a time window, ordered by
+    /// transaction count. Useful for spotting contention on shared objects.
+    #[method(name = "getHotSharedObjects")]
+    async fn get_hot_shared_objects(
+        &self,
+        /// Length of the window in milliseconds, counting back from the latest indexed checkpoint.
+        window: u64,
+        /// Maximum number of objects returned, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<HotSharedObject>>;
+
+    /// Return the Move functions that failed in the most transactions within a time window,
+    /// ordered by number of failures, with the number of aborts by abort code. A failure is
+    /// attributed to the function executing when the transaction aborted or hit a runtime error.
+    #[method(name = "getFailingFunctions")]
+    async fn get_failing_functions(
+        &self,
+        /// Length of the window in milliseconds, counting back from the latest indexed checkpoint.
+        window: u64,
+        /// Maximum number of functions returned, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<FailingFunction>>;
+
+    /// Return the number of failed transactions by kind of execution error within a time window,
+    /// most frequent first.
+    #[method(name = "getTransactionErrorStats")]
+    async fn get_transaction_error_stats(
+        &self,
+        /// Length of the window in milliseconds, counting back from the latest indexed checkpoint.
+        window: u64,
+    ) -> RpcResult<Vec<TransactionErrorStats>>;
+
+    /// Return the number of distinct addresses that sent transactions in a range of epochs, e.g.
+    /// the monthly active addresses over the last 30 epochs, along with the number of
+    /// transactions they sent and the gas they spent.
+    #[method(name = "getActiveAddresses")]
+    async fn get_active_addresses(
+        &self,
+        /// the first epoch of the range
+        start_epoch: EpochId,
+        /// the last epoch of the range, inclusive
+        end_epoch: EpochId,
+    ) -> RpcResult<ActiveAddressStats>;
+
+    /// Return the number of transactions sent by an address and the gas it spent per epoch, in
+    /// ascending epoch order. Epochs without transactions are left out.
+    #[method(name = "getAddressActivity")]
+    async fn get_address_activity(
+        &self,
+        /// the address sending the transactions
+        address: SuiAddress,
+        /// Optional first epoch, default to the first epoch if not specified.
+        start_epoch: Option<EpochId>,
+        /// Optional last epoch, inclusive, default to the latest epoch if not specified.
+        end_epoch: Option<EpochId>,
+    ) -> RpcResult<Vec<AddressEpochActivity>>;
+
+    /// Return the versions of an object in ascending order, with the transaction that wrote each
+    /// of them and the owner changes, e.g. to trace the provenance of an NFT.
+    #[method(name = "getObjectHistory")]
+    async fn get_object_history(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// Optional paging cursor, the version to start from
+        cursor: Option<SequenceNumber>,
+        /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectHistoryPage>;
+
+    /// Return the coins received by an address, newest first, with the amount of each coin type
+    /// received per transaction.
+    #[method(name = "getIncomingPayments")]
+    async fn get_incoming_payments(
+        &self,
+        /// the address receiving the coins
+        recipient: SuiAddress,
+        /// Optional paging cursor
+        cursor: Option<u64>,
+        /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<IncomingPaymentPage>;
+
+    /// Return the objects owned by an address as of a past checkpoint, ordered by object ID.
+    /// Only checkpoints since the oldest objects snapshot retained by the indexer can be queried.
+    #[method(name = "getOwnedObjectsAtCheckpoint")]
+    async fn get_owned_objects_at_checkpoint(
+        &self,
+        /// the owner's Sui address
+        address: SuiAddress,
+        /// the checkpoint at which the objects are owned
+        checkpoint: CheckpointSequenceNumber,
+    ) -> RpcResult<Vec<SuiObjectInfo>>;
+
+    /// Return the live objects of a type ordered by object ID, e.g. to browse the holders of the
+    /// NFTs of a collection.
+    #[method(name = "getObjectsByType")]
+    async fn get_objects_by_type(
+        &self,
+        /// the type of the objects, including its type parameters, e.g. `0x2::coin::Coin<0x2::sui::SUI>`
+        object_type: String,
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectInfoPage>;
+
+    /// Return the number of live objects of a type, e.g. the supply of an NFT collection.
+    #[method(name = "countObjectsByType")]
+    async fn count_objects_by_type(
+        &self,
+        /// the type of the objects, including its type parameters
+        object_type: String,
+    ) -> RpcResult<u64>;
+}
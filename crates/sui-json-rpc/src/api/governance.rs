@@ -4,8 +4,9 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
 
-use sui_json_rpc_types::SuiCommittee;
+use sui_json_rpc_types::{CommitteePage, SuiCommittee, SuiGasCostTable, SuiValidatorReports};
 use sui_open_rpc_macros::open_rpc;
+use sui_protocol_config::ProtocolVersion;
 use sui_types::base_types::SuiAddress;
 
 use sui_types::committee::EpochId;
@@ -20,7 +21,8 @@ pub trait GovernanceReadApi {
     #[method(name = "getDelegatedStakes")]
     async fn get_delegated_stakes(&self, owner: SuiAddress) -> RpcResult<Vec<DelegatedStake>>;
 
-    /// Return the committee information for the asked `epoch`.
+    /// Return the committee information for the asked `epoch`, with the names, public keys,
+    /// network addresses and commission of its validators in the latest validator set.
     #[method(name = "getCommitteeInfo")]
     async fn get_committee_info(
         &self,
@@ -28,6 +30,17 @@ pub trait GovernanceReadApi {
         epoch: Option<EpochId>,
     ) -> RpcResult<SuiCommittee>;
 
+    /// Return the committees of a range of epochs, in ascending epoch order, with the validator
+    /// information of [Self::get_committee_info].
+    #[method(name = "getCommittees")]
+    async fn get_committees(
+        &self,
+        /// optional paging cursor, the first epoch of the page. If None, start at the genesis epoch
+        cursor: Option<EpochId>,
+        /// maximum number of items per page
+        limit: Option<usize>,
+    ) -> RpcResult<CommitteePage>;
+
     /// Return the latest SUI system state object on-chain.
     #[method(name = "getLatestSuiSystemState")]
     async fn get_latest_sui_system_state(&self) -> RpcResult<SuiSystemStateSummary>;
@@ -35,4 +48,18 @@ pub trait GovernanceReadApi {
     /// Return the reference gas price for the network
     #[method(name = "getReferenceGasPrice")]
     async fn get_reference_gas_price(&self) -> RpcResult<u64>;
+
+    /// Return the gas costs of the Move bytecode instructions, native functions and transactions
+    /// at a protocol version
+    #[method(name = "getGasCostTable")]
+    async fn get_gas_cost_table(
+        &self,
+        /// The protocol version of interest. If None, default to the version of the latest epoch
+        protocol_version: Option<ProtocolVersion>,
+    ) -> RpcResult<SuiGasCostTable>;
+
+    /// Return the reports of the validators against each other in the current epoch, and the
+    /// validators whose rewards the tallying rule slashes at the end of the epoch
+    #[method(name = "getValidatorReports")]
+    async fn get_validator_reports(&self) -> RpcResult<SuiValidatorReports>;
 }
@@ -4,6 +4,7 @@
 mod bls;
 mod coin;
 mod event;
+mod extended;
 mod governance;
 mod read;
 mod transaction_builder;
@@ -21,6 +22,10 @@ pub use event::EventReadApiClient;
 pub use event::EventReadApiOpenRpc;
 pub use event::EventReadApiServer;
 
+pub use extended::ExtendedApiClient;
+pub use extended::ExtendedApiOpenRpc;
+pub use extended::ExtendedApiServer;
+
 pub use write::WriteApiClient;
 pub use write::WriteApiOpenRpc;
 pub use write::WriteApiServer;
@@ -5,10 +5,10 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
 use std::collections::BTreeMap;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, MoveFunctionArgType, SuiMoveNormalizedFunction,
-    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectDataOptions, SuiObjectInfo,
-    SuiObjectResponse, SuiPastObjectResponse, SuiTransactionResponse,
-    SuiTransactionResponseOptions, TransactionsPage,
+    Checkpoint, CheckpointAttestation, CheckpointId, DynamicFieldPage, MoveFunctionArgType,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiMoveStructLayout, SuiNodeInfo, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
+    SuiPastObjectResponse, SuiTransactionResponse, SuiTransactionResponseOptions, TransactionsPage,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{
@@ -75,6 +75,20 @@ pub trait ReadApi {
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse>;
 
+    /// Return the latest reference and owner of each of the specified objects, in the order of
+    /// the input, e.g. to resolve the input objects of a transaction in one call. Only the
+    /// references and owners are returned unless requested otherwise with `options`.
+    /// The method will throw an error if the input contains any duplicate or
+    /// the input size exceeds QUERY_MAX_RESULT_LIMIT
+    #[method(name = "tryMultiGetObjectRefs")]
+    async fn try_multi_get_object_refs(
+        &self,
+        /// the IDs of the queried objects
+        object_ids: Vec<ObjectID>,
+        /// options for specifying the content to be returned, default to the owner only
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<Vec<SuiObjectResponse>>;
+
     /// Return the dynamic field object information for a specified object
     #[method(name = "getDynamicFieldObject")]
     async fn get_dynamic_field_object(
@@ -83,6 +97,8 @@ pub trait ReadApi {
         parent_object_id: ObjectID,
         /// The Name of the dynamic field
         name: DynamicFieldName,
+        /// options for specifying the content to be returned, default to full content
+        options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse>;
 
     /// Return the argument types of a Move function,
@@ -128,6 +144,15 @@ pub trait ReadApi {
         function_name: String,
     ) -> RpcResult<SuiMoveNormalizedFunction>;
 
+    /// Return the BCS layout of a Move struct type, to decode the BCS bytes of objects of that type.
+    /// Layouts only depend on the type, so clients can cache them by type.
+    #[method(name = "getMoveStructLayout")]
+    async fn get_move_struct_layout(
+        &self,
+        /// the struct type, e.g. `0x2::coin::Coin<0x2::sui::SUI>`
+        struct_type: String,
+    ) -> RpcResult<SuiMoveStructLayout>;
+
     /// Return list of transactions for a specified query criteria.
     #[method(name = "getTransactions")]
     async fn get_transactions(
@@ -140,6 +165,9 @@ pub trait ReadApi {
         limit: Option<usize>,
         /// query result ordering, default to false (ascending order), oldest record first.
         descending_order: Option<bool>,
+        /// Whether to return summaries of the transactions, with their timestamp, checkpoint,
+        /// status and sender, instead of their digests. Default to false.
+        show_summaries: Option<bool>,
     ) -> RpcResult<TransactionsPage>;
 
     /// Returns an ordered list of transaction responses
@@ -180,4 +208,19 @@ pub trait ReadApi {
         /// Checkpoint identifier, can use either checkpoint digest, or checkpoint sequence number as input.
         id: CheckpointId,
     ) -> RpcResult<Checkpoint>;
+
+    /// Return a checkpoint along with the aggregated signature of the validators certifying it
+    /// and the committee of its epoch, which auditors can archive and verify offline
+    #[method(name = "getCheckpointAttestation")]
+    async fn get_checkpoint_attestation(
+        &self,
+        /// Checkpoint identifier, can use either checkpoint digest, or checkpoint sequence number as input.
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointAttestation>;
+
+    /// Return the chain, epoch and protocol version of the node, the range of checkpoints it
+    /// serves and its software version, for load balancers and clients to route requests away
+    /// from stale nodes
+    #[method(name = "getNodeInfo")]
+    async fn get_node_info(&self) -> RpcResult<SuiNodeInfo>;
 }
@@ -7,11 +7,12 @@ use jsonrpsee_proc_macros::rpc;
 
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    BigInt, RPCTransactionRequestParams, SuiTransactionBuilderMode, SuiTypeTag, TransactionBytes,
+    BatchTransferParams, BigInt, RPCTransactionRequestParams, SuiTransactionBuilderMode,
+    SuiTypeTag, TransactionBytes,
 };
 
 use sui_open_rpc_macros::open_rpc;
-use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::base_types::{EpochId, ObjectID, SuiAddress};
 
 #[open_rpc(namespace = "sui", tag = "Transaction Builder API")]
 #[rpc(server, client, namespace = "sui")]
@@ -114,6 +115,25 @@ pub trait TransactionBuilder {
         gas_budget: u64,
     ) -> RpcResult<TransactionBytes>;
 
+    /// Create the unsigned PaySui transactions paying `amounts` to `recipients` with SUI coins
+    /// of the signer, selected largest first. Locked coins are never selected.
+    /// The payments are split, in order, across as many transactions as needed to use at most
+    /// `max_input_coins` coins per transaction, each paying its own gas.
+    #[method(name = "autoPaySui")]
+    async fn auto_pay_sui(
+        &self,
+        /// the transaction signer's Sui address
+        signer: SuiAddress,
+        /// the recipients' addresses, the length of this vector must be the same as amounts.
+        recipients: Vec<SuiAddress>,
+        /// the amounts to be transferred to recipients, following the same order
+        amounts: Vec<BigInt>,
+        /// the maximum number of coins used by a transaction, including its gas coin. Default to the maximum number of gas coins of a transaction
+        max_input_coins: Option<usize>,
+        /// the gas budget of each transaction, the transaction will fail if the gas cost exceed the budget
+        gas_budget: u64,
+    ) -> RpcResult<Vec<TransactionBytes>>;
+
     /// Create an unsigned transaction to execute a Move call on the network, by calling the specified function in the module of a given package.
     #[method(name = "moveCall")]
     async fn move_call(
@@ -152,6 +172,27 @@ pub trait TransactionBuilder {
         gas_budget: u64,
     ) -> RpcResult<TransactionBytes>;
 
+    /// Create an unsigned transaction to upgrade a Move package, as authorized by its upgrade
+    /// capability with the upgrade policy of the capability.
+    #[method(name = "upgradePackage")]
+    async fn upgrade_package(
+        &self,
+        /// the transaction signer's Sui address
+        sender: SuiAddress,
+        /// the package to upgrade
+        package_id: ObjectID,
+        /// the compiled bytes of the modules of the new version of the package
+        compiled_modules: Vec<Base64>,
+        /// the transitive dependencies of the new version of the package
+        dependencies: Vec<ObjectID>,
+        /// the upgrade capability of the package, owned by the signer
+        upgrade_capability: ObjectID,
+        /// gas object to be used in this transaction, node will pick one from the signer's possession if not provided
+        gas: Option<ObjectID>,
+        /// the gas budget, the transaction will fail if the gas cost exceed the budget
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes>;
+
     /// Create an unsigned transaction to split a coin object into multiple coins.
     #[method(name = "splitCoin")]
     async fn split_coin(
@@ -216,6 +257,21 @@ pub trait TransactionBuilder {
         txn_builder_mode: Option<SuiTransactionBuilderMode>,
     ) -> RpcResult<TransactionBytes>;
 
+    /// Create an unsigned programmable transaction performing many transfers at once. Each transfer
+    /// either sends an object of the signer, or an amount of SUI split from the gas coin, to a recipient.
+    #[method(name = "batchTransfer")]
+    async fn batch_transfer(
+        &self,
+        /// the transaction signer's Sui address
+        signer: SuiAddress,
+        /// list of transfers to be performed in this transaction
+        transfers: Vec<BatchTransferParams>,
+        /// gas object to be used in this transaction, also the source of the transferred SUI amounts, node will pick one from the signer's possession if not provided
+        gas: Option<ObjectID>,
+        /// the gas budget, the transaction will fail if the gas cost exceed the budget
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes>;
+
     /// Add delegated stake to a validator's staking pool using multiple coins and amount.
     #[method(name = "requestAddDelegation")]
     async fn request_add_delegation(
@@ -250,4 +306,15 @@ pub trait TransactionBuilder {
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
     ) -> RpcResult<TransactionBytes>;
+
+    /// Set the expiration of an unsigned transaction: validators refuse to sign it after epoch `epoch`,
+    /// so that the transaction can't be executed later if its signing is abandoned.
+    #[method(name = "setTransactionExpiration")]
+    async fn set_transaction_expiration(
+        &self,
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string
+        tx_bytes: Base64,
+        /// the last epoch the transaction can be signed in, not before the current epoch
+        epoch: EpochId,
+    ) -> RpcResult<TransactionBytes>;
 }
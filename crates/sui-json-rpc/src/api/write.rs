@@ -4,10 +4,13 @@
 use fastcrypto::encoding::Base64;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
-use sui_json_rpc_types::{DevInspectResults, DryRunTransactionResponse, SuiTransactionResponse};
+use sui_json_rpc_types::{
+    DevInspectResults, DryRunTransactionResponse, SuiTransactionResponse,
+    SuiTransactionSubmissionStatus,
+};
 
 use sui_open_rpc_macros::open_rpc;
-use sui_types::base_types::{EpochId, SuiAddress};
+use sui_types::base_types::{EpochId, SuiAddress, TransactionDigest};
 use sui_types::messages::ExecuteTransactionRequestType;
 
 #[open_rpc(namespace = "sui", tag = "Write API")]
@@ -57,6 +60,26 @@ pub trait WriteApi {
         request_type: ExecuteTransactionRequestType,
     ) -> RpcResult<SuiTransactionResponse>;
 
+    /// Enqueue the transaction for execution and return its digest without waiting for finality.
+    /// The node keeps submitting the transaction to validators, also across restarts, and the
+    /// client polls `sui_getTransactionSubmissionStatus` with the digest for the result.
+    #[method(name = "enqueueTransaction")]
+    async fn enqueue_transaction(
+        &self,
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+        tx_bytes: Base64,
+        /// A list of signatures (`flag || signature || pubkey` bytes, as base-64 encoded string). Signature is committed to the intent message of the transaction data, as base-64 encoded string.
+        signatures: Vec<Base64>,
+    ) -> RpcResult<TransactionDigest>;
+
+    /// Return the status of a transaction enqueued with `sui_enqueueTransaction`.
+    #[method(name = "getTransactionSubmissionStatus")]
+    async fn get_transaction_submission_status(
+        &self,
+        /// the digest returned by `sui_enqueueTransaction`
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionSubmissionStatus>;
+
     /// Runs the transaction in dev-inspect mode. Which allows for nearly any
     /// transaction (or Move call) with any arguments. Detailed results are
     /// provided, including both the transaction effects and any return values.
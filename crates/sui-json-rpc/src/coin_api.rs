@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use futures::future::join_all;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::RpcModule;
 use move_core_types::language_storage::{StructTag, TypeTag};
@@ -18,14 +19,14 @@ use sui_open_rpc::Module;
 use sui_types::balance::Supply;
 use sui_types::base_types::{ObjectID, ObjectType, SuiAddress};
 use sui_types::coin::{Coin, CoinMetadata, LockedCoin, TreasuryCap};
-use sui_types::error::SuiError;
+use sui_types::error::{SuiError, UserInputError};
 use sui_types::event::Event;
 use sui_types::gas_coin::GAS;
 use sui_types::messages::{TransactionEffectsAPI, TransactionEvents};
 use sui_types::object::Object;
 use sui_types::parse_sui_struct_tag;
 
-use crate::api::{cap_page_limit, CoinReadApiServer};
+use crate::api::{cap_page_limit, CoinReadApiServer, QUERY_MAX_RESULT_LIMIT};
 use crate::error::Error;
 use crate::SuiRpcModule;
 
@@ -284,6 +285,25 @@ impl CoinReadApiServer for CoinReadApi {
         })?)
     }
 
+    async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> RpcResult<Vec<Option<SuiCoinMetadata>>> {
+        if coin_types.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(UserInputError::SizeLimitExceeded {
+                limit: "multi get coin metadata input limit".to_string(),
+                value: QUERY_MAX_RESULT_LIMIT.to_string()
+            })
+            .into());
+        }
+        Ok(join_all(
+            coin_types
+                .into_iter()
+                .map(|coin_type| async move { self.get_coin_metadata(coin_type).await.ok() }),
+        )
+        .await)
+    }
+
     async fn get_total_supply(&self, coin_type: String) -> RpcResult<Supply> {
         let coin_struct = parse_sui_struct_tag(&coin_type)?;
 
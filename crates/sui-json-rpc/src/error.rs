@@ -3,8 +3,10 @@
 
 use hyper::header::InvalidHeaderValue;
 use jsonrpsee::core::Error as RpcError;
-use jsonrpsee::types::error::CallError;
-use sui_types::error::SuiError;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sui_json_rpc_types::{SuiRpcConversionError, SuiRpcErrorData};
+use sui_types::error::{SuiError, UserInputError};
+use sui_types::quorum_driver_types::QuorumDriverError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -26,10 +28,64 @@ pub enum Error {
 
     #[error(transparent)]
     InvalidHeaderValue(#[from] InvalidHeaderValue),
+
+    #[error(transparent)]
+    ConversionError(#[from] SuiRpcConversionError),
+
+    #[error(transparent)]
+    QuorumDriverError(#[from] QuorumDriverError),
+}
+
+impl Error {
+    /// The machine-readable description of this error, sent along with the error message.
+    pub fn data(&self) -> SuiRpcErrorData {
+        match self {
+            Error::SuiError(e) => e.into(),
+            Error::QuorumDriverError(e) => e.into(),
+            // The node APIs mostly return `anyhow` errors wrapping the underlying error.
+            Error::InternalError(e) => {
+                if let Some(e) = e.downcast_ref::<SuiError>() {
+                    e.into()
+                } else if let Some(e) = e.downcast_ref::<UserInputError>() {
+                    e.into()
+                } else if let Some(e) = e.downcast_ref::<QuorumDriverError>() {
+                    e.into()
+                } else {
+                    SuiRpcErrorData::Internal {
+                        message: e.to_string(),
+                    }
+                }
+            }
+            // The transaction given by the client cannot be represented, as opposed to a failure
+            // of the node to resolve the modules it calls.
+            Error::ConversionError(
+                e @ (SuiRpcConversionError::UnsupportedVersion { .. }
+                | SuiRpcConversionError::ArgDecode { .. }),
+            ) => SuiRpcErrorData::InvalidParams {
+                message: e.to_string(),
+            },
+            Error::BcsError(e) => SuiRpcErrorData::InvalidParams {
+                message: e.to_string(),
+            },
+            e => SuiRpcErrorData::Internal {
+                message: e.to_string(),
+            },
+        }
+    }
 }
 
 impl From<Error> for RpcError {
     fn from(e: Error) -> Self {
-        RpcError::Call(CallError::Failed(e.into()))
+        match e {
+            Error::RPCServerError(e) => e,
+            e => {
+                let data = e.data();
+                RpcError::Call(CallError::Custom(ErrorObject::owned(
+                    data.code(),
+                    e.to_string(),
+                    Some(data),
+                )))
+            }
+        }
     }
 }
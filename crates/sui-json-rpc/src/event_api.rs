@@ -1,9 +1,11 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use futures::{StreamExt, TryStream};
 
@@ -11,15 +13,25 @@ use jsonrpsee::core::error::SubscriptionClosed;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::types::SubscriptionResult;
 use jsonrpsee::{RpcModule, SubscriptionSink};
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::CompiledModule;
 use mysten_metrics::spawn_monitored_task;
 use serde::Serialize;
 use tracing::{debug, warn};
 
 use sui_core::authority::AuthorityState;
 use sui_core::event_handler::EventHandler;
-use sui_json_rpc_types::{EventPage, SuiEvent, SuiEventEnvelope, SuiEventFilter};
+use sui_json_rpc_types::{
+    EventPage, SuiEvent, SuiEventEnvelope, SuiEventFilter, SuiPackagePublish,
+    SuiPackagePublishFilter,
+};
 use sui_open_rpc::Module;
-use sui_types::event::{EventEnvelope, EventID};
+use sui_types::base_types::{ObjectID, TransactionDigest};
+use sui_types::event::{Event, EventEnvelope, EventID, EventType};
+use sui_types::filter::EventFilter;
+use sui_types::messages::TransactionEffectsAPI;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::{Data, ObjectRead};
 use sui_types::query::EventQuery;
 
 use crate::api::cap_page_limit;
@@ -58,6 +70,26 @@ impl EventReadApi {
             event_handler,
         }
     }
+
+    /// Set the checkpoint of the transaction of each of `events`, read once per transaction.
+    fn set_checkpoints(&self, events: &mut [SuiEventEnvelope]) -> Result<(), anyhow::Error> {
+        let mut checkpoints = BTreeMap::new();
+        for event in events {
+            let checkpoint = match checkpoints.get(&event.tx_digest) {
+                Some(checkpoint) => *checkpoint,
+                None => {
+                    let checkpoint = self
+                        .state
+                        .get_transaction_checkpoint_sequence(&event.tx_digest)?
+                        .map(|(_, checkpoint)| checkpoint);
+                    checkpoints.insert(event.tx_digest, checkpoint);
+                    checkpoint
+                }
+            };
+            event.checkpoint = checkpoint;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -85,7 +117,131 @@ impl EventReadApiServer for EventReadApi {
             .await?;
         let next_cursor = data.get(limit).map(|(id, _)| id.clone());
         data.truncate(limit);
-        let data = data.into_iter().map(|(_, event)| event).collect();
+        let mut data = data.into_iter().map(|(_, event)| event).collect::<Vec<_>>();
+        self.set_checkpoints(&mut data)?;
+        Ok(EventPage { data, next_cursor })
+    }
+
+    async fn get_events_by_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage> {
+        debug!(
+            ?checkpoint,
+            ?cursor,
+            ?limit,
+            "get_events_by_checkpoint query"
+        );
+        let limit = cap_page_limit(limit);
+        let summary = self
+            .state
+            .get_checkpoint_summary_by_sequence_number(checkpoint)?;
+        let contents = self.state.get_checkpoint_contents(summary.content_digest)?;
+        let digests = contents
+            .iter()
+            .map(|digests| digests.transaction)
+            .collect::<Vec<_>>();
+        let effects = self.state.multi_get_executed_effects(&digests).await?;
+
+        let mut tx_digests = vec![];
+        let mut events_digests = vec![];
+        for (digest, effects) in digests.iter().zip(effects) {
+            let effects = effects.ok_or_else(|| anyhow!("Effects not found for [{digest}]"))?;
+            if let Some(events_digest) = effects.events_digest() {
+                tx_digests.push(*digest);
+                events_digests.push(*events_digest);
+            }
+        }
+        let all_events = self.state.multi_get_events(&events_digests)?;
+
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let mut data = vec![];
+        for (tx_digest, events) in tx_digests.into_iter().zip(all_events) {
+            let events = events.ok_or_else(|| anyhow!("Events not found for [{tx_digest}]"))?;
+            for (event_seq, event) in events.data.into_iter().enumerate() {
+                data.push(SuiEventEnvelope {
+                    timestamp: summary.timestamp_ms,
+                    tx_digest,
+                    checkpoint: Some(checkpoint),
+                    id: EventID::from((tx_digest, event_seq as i64)),
+                    event: SuiEvent::try_from(event, epoch_store.module_cache().as_ref())?,
+                });
+            }
+        }
+
+        // The cursor is inclusive, as for `get_events`
+        if let Some(cursor) = cursor {
+            let start = data
+                .iter()
+                .position(|event| event.id == cursor)
+                .ok_or_else(|| {
+                    anyhow!("Cursor [{cursor:?}] not found in checkpoint {checkpoint}")
+                })?;
+            data.drain(..start);
+        }
+        let next_cursor = data.get(limit).map(|event| event.id.clone());
+        data.truncate(limit);
+        Ok(EventPage { data, next_cursor })
+    }
+
+    async fn get_transaction_events(
+        &self,
+        digest: TransactionDigest,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> RpcResult<EventPage> {
+        debug!(?digest, ?cursor, ?limit, "get_transaction_events query");
+        let limit = cap_page_limit(limit);
+        let start = match cursor {
+            Some(cursor) if cursor.tx_digest != digest => {
+                return Err(anyhow!(
+                    "Cursor [{cursor:?}] does not belong to transaction [{digest}]"
+                )
+                .into())
+            }
+            Some(cursor) => usize::try_from(cursor.event_seq)
+                .map_err(|_| anyhow!("Invalid cursor [{cursor:?}]"))?,
+            None => 0,
+        };
+
+        let effects = self.state.get_executed_effects(digest).await?;
+        let Some(events_digest) = effects.events_digest() else {
+            return Ok(EventPage {
+                data: vec![],
+                next_cursor: None,
+            });
+        };
+        let events = self.state.get_transaction_events(*events_digest).await?;
+        // Events are not timestamped individually, use the timestamp of the enclosing
+        // checkpoint, if the transaction has been checkpointed already.
+        let checkpoint = self.state.get_transaction_checkpoint(&digest)?;
+        let timestamp = checkpoint
+            .as_ref()
+            .map_or(0, |checkpoint| checkpoint.timestamp_ms);
+        let checkpoint = checkpoint.map(|checkpoint| checkpoint.sequence_number);
+
+        // Only the requested page is converted, plus one event to compute the next cursor.
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let mut data = events
+            .data
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(limit + 1)
+            .map(|(event_seq, event)| {
+                Ok(SuiEventEnvelope {
+                    timestamp,
+                    tx_digest: digest,
+                    checkpoint,
+                    id: EventID::from((digest, event_seq as i64)),
+                    event: SuiEvent::try_from(event, epoch_store.module_cache().as_ref())?,
+                })
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        let next_cursor = data.get(limit).map(|event| event.id.clone());
+        data.truncate(limit);
         Ok(EventPage { data, next_cursor })
     }
 
@@ -118,9 +274,11 @@ impl EventReadApiServer for EventReadApi {
                     .module_cache()
                     .as_ref(),
             );
+            // Events are streamed as transactions execute, before they are checkpointed.
             event.map(|event| SuiEventEnvelope {
                 timestamp: e.timestamp,
                 tx_digest: e.tx_digest,
+                checkpoint: None,
                 id: EventID::from((e.tx_digest, e.event_num as i64)),
                 event,
             })
@@ -128,6 +286,86 @@ impl EventReadApiServer for EventReadApi {
         spawn_subscription(sink, stream);
         Ok(())
     }
+
+    fn subscribe_package_publishes(
+        &self,
+        sink: SubscriptionSink,
+        filter: Option<SuiPackagePublishFilter>,
+    ) -> SubscriptionResult {
+        let filter = filter.unwrap_or_default();
+        let event_filter = EventFilter::EventType(EventType::Publish);
+        let event_filter = match filter.publisher {
+            Some(publisher) => {
+                EventFilter::MatchAll(vec![event_filter, EventFilter::SenderAddress(publisher)])
+            }
+            None => event_filter,
+        };
+
+        let state = self.state.clone();
+        let stream =
+            self.event_handler
+                .subscribe(event_filter)
+                .filter_map(move |e: EventEnvelope| {
+                    let state = state.clone();
+                    let filter = filter.clone();
+                    async move {
+                        let publish = match package_publish(&state, e).await {
+                            Ok(Some(publish)) => publish,
+                            Ok(None) => return None,
+                            Err(e) => return Some(Err(e)),
+                        };
+                        filter.matches(&publish).then_some(Ok(publish))
+                    }
+                });
+        spawn_subscription(sink, Box::pin(stream));
+        Ok(())
+    }
+}
+
+/// Resolves a `Publish` event into the published package's modules and dependencies.
+async fn package_publish(
+    state: &AuthorityState,
+    e: EventEnvelope,
+) -> Result<Option<SuiPackagePublish>, anyhow::Error> {
+    let Event::Publish {
+        sender,
+        package_id,
+        version,
+        ..
+    } = e.event
+    else {
+        return Ok(None);
+    };
+    let package = match state.get_object_read(&package_id).await? {
+        ObjectRead::Exists(_, object, _) => match object.data {
+            Data::Package(package) => package,
+            _ => return Err(anyhow!("Object {package_id} is not a package")),
+        },
+        _ => return Err(anyhow!("Published package {package_id} not found")),
+    };
+
+    let mut dependencies = BTreeSet::new();
+    for bytes in package.serialized_module_map().values() {
+        let module = CompiledModule::deserialize(bytes)
+            .map_err(|e| anyhow!("Failed to deserialize module of {package_id}: {e}"))?;
+        dependencies.extend(
+            module
+                .immediate_dependencies()
+                .iter()
+                .map(|id| ObjectID::from(*id.address())),
+        );
+    }
+    dependencies.remove(&package_id);
+
+    Ok(Some(SuiPackagePublish {
+        timestamp: e.timestamp,
+        tx_digest: e.tx_digest,
+        package_id,
+        version,
+        publisher: sender,
+        modules: package.serialized_module_map().keys().cloned().collect(),
+        dependencies: dependencies.into_iter().collect(),
+    }))
 }
 
 impl SuiRpcModule for EventReadApi {
@@ -4,18 +4,20 @@
 use jsonrpsee::core::RpcResult;
 use std::collections::HashMap;
 use std::sync::Arc;
-use sui_json_rpc_types::SuiCommittee;
+use sui_json_rpc_types::{CommitteePage, SuiCommittee, SuiGasCostTable, SuiValidatorReports};
 use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
 
-use crate::api::GovernanceReadApiServer;
+use crate::api::{cap_page_limit, GovernanceReadApiServer};
 use crate::error::Error;
 use crate::SuiRpcModule;
 use async_trait::async_trait;
 use jsonrpsee::RpcModule;
 use sui_core::authority::AuthorityState;
 use sui_open_rpc::Module;
+use sui_protocol_config::{ProtocolConfig, ProtocolVersion, SupportedProtocolVersions};
 use sui_types::base_types::SuiAddress;
 use sui_types::committee::EpochId;
+use sui_types::error::SuiError;
 use sui_types::governance::{DelegatedStake, Delegation, DelegationStatus, StakedSui};
 use sui_types::sui_system_state::SuiSystemStateTrait;
 
@@ -70,12 +72,48 @@ impl GovernanceReadApiServer for GovernanceReadApi {
     }
 
     async fn get_committee_info(&self, epoch: Option<EpochId>) -> RpcResult<SuiCommittee> {
-        Ok(self
+        let committee: SuiCommittee = self
             .state
             .committee_store()
             .get_or_latest_committee(epoch)
-            .map(|committee| committee.into())
-            .map_err(Error::from)?)
+            .map_err(Error::from)?
+            .into();
+        let system_state = self
+            .state
+            .database
+            .get_sui_system_state_object()
+            .map_err(Error::from)?
+            .into_sui_system_state_summary();
+        Ok(committee.with_validator_info(&system_state.active_validators))
+    }
+
+    async fn get_committees(
+        &self,
+        cursor: Option<EpochId>,
+        limit: Option<usize>,
+    ) -> RpcResult<CommitteePage> {
+        let limit = cap_page_limit(limit);
+        let mut committees = self
+            .state
+            .committee_store()
+            .get_committees(cursor.unwrap_or_default(), limit + 1)
+            .map_err(Error::from)?;
+        let next_cursor = committees.get(limit).map(|committee| committee.epoch);
+        committees.truncate(limit);
+
+        let system_state = self
+            .state
+            .database
+            .get_sui_system_state_object()
+            .map_err(Error::from)?
+            .into_sui_system_state_summary();
+        let data = committees
+            .into_iter()
+            .map(|committee| {
+                SuiCommittee::from(committee).with_validator_info(&system_state.active_validators)
+            })
+            .collect();
+        Ok(CommitteePage { data, next_cursor })
     }
 
     async fn get_latest_sui_system_state(&self) -> RpcResult<SuiSystemStateSummary> {
@@ -91,6 +129,50 @@ impl GovernanceReadApiServer for GovernanceReadApi {
         let epoch_store = self.state.load_epoch_store_one_call_per_task();
         Ok(epoch_store.reference_gas_price())
     }
+
+    async fn get_gas_cost_table(
+        &self,
+        protocol_version: Option<ProtocolVersion>,
+    ) -> RpcResult<SuiGasCostTable> {
+        let config = match protocol_version {
+            Some(version) => {
+                if !SupportedProtocolVersions::SYSTEM_DEFAULT.is_version_supported(version) {
+                    return Err(Error::SuiError(SuiError::UnsupportedFeatureError {
+                        error: format!(
+                            "Protocol version {} is not supported by this node",
+                            version.as_u64()
+                        ),
+                    })
+                    .into());
+                }
+                ProtocolConfig::get_for_version(version)
+            }
+            None => self
+                .state
+                .load_epoch_store_one_call_per_task()
+                .protocol_config()
+                .clone(),
+        };
+        Ok(SuiGasCostTable::from(&config))
+    }
+
+    async fn get_validator_reports(&self) -> RpcResult<SuiValidatorReports> {
+        let system_state = self
+            .state
+            .database
+            .get_sui_system_state_object()
+            .map_err(Error::from)?
+            .into_sui_system_state_summary();
+        let reward_slashing_rate = self
+            .state
+            .load_epoch_store_one_call_per_task()
+            .protocol_config()
+            .reward_slashing_rate();
+        Ok(SuiValidatorReports::new(
+            &system_state,
+            reward_slashing_rate,
+        ))
+    }
 }
 
 impl SuiRpcModule for GovernanceReadApi {
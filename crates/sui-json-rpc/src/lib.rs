@@ -4,6 +4,7 @@
 use std::env;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use hyper::header::HeaderName;
 use hyper::header::HeaderValue;
@@ -17,9 +18,14 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::{info, warn};
 
 use crate::error::Error;
+use sui_config::node::RpcRateLimitConfig;
 use sui_open_rpc::{Module, Project};
 
 use crate::metrics::MetricsLogger;
+use crate::rate_limit_layer::RateLimitLayer;
+use crate::request_id_layer::RequestIdLayer;
+use crate::request_scope_layer::RequestScopeLayer;
+pub use crate::request_scope_layer::{RequestFuture, RequestScope};
 use crate::routing_layer::RoutingLayer;
 
 pub mod api;
@@ -28,7 +34,10 @@ pub mod error;
 pub mod event_api;
 pub mod governance_api;
 mod metrics;
+mod rate_limit_layer;
 pub mod read_api;
+mod request_id_layer;
+mod request_scope_layer;
 mod routing_layer;
 pub mod threshold_bls_api;
 pub mod transaction_builder_api;
@@ -41,6 +50,16 @@ pub const CLIENT_SDK_VERSION_HEADER: &str = "client-sdk-version";
 /// API version.
 pub const CLIENT_TARGET_API_VERSION_HEADER: &str = "client-target-api-version";
 pub const APP_NAME_HEADER: &str = "app-name";
+/// The latest checkpoint in the data a response was read from, set by servers pinning the reads
+/// of a request to one snapshot.
+pub const CHECKPOINT_WATERMARK_HEADER: &str = "checkpoint-watermark";
+/// The ID of a request, to correlate the logs of the services handling it. Servers use the ID set
+/// by the client or generate one, and echo it in the response.
+pub const REQUEST_ID_HEADER: &str = "request-id";
+/// The checkpoint the data read by a request must include, e.g. the checkpoint of a transaction
+/// the client just executed, or the [CHECKPOINT_WATERMARK_HEADER] of a previous response. The
+/// request is held until the server reaches it, and rejected if it doesn't in time.
+pub const MIN_CHECKPOINT_HEADER: &str = "min-checkpoint";
 
 pub const MAX_REQUEST_SIZE: u32 = 2 << 30;
 
@@ -52,6 +71,8 @@ pub struct JsonRpcServerBuilder {
     module: RpcModule<()>,
     rpc_doc: Project,
     registry: Registry,
+    rate_limit: Option<RpcRateLimitConfig>,
+    request_scope: Option<Arc<dyn RequestScope>>,
 }
 
 pub fn sui_rpc_doc(version: &str) -> Project {
@@ -73,9 +94,21 @@ impl JsonRpcServerBuilder {
             module: RpcModule::new(()),
             rpc_doc: sui_rpc_doc(version),
             registry: prometheus_registry.clone(),
+            rate_limit: None,
+            request_scope: None,
         }
     }
 
+    /// Limit the rate of requests per client, see [RpcRateLimitConfig].
+    pub fn set_rate_limit(&mut self, config: RpcRateLimitConfig) {
+        self.rate_limit = Some(config);
+    }
+
+    /// Handle every request within `scope`, see [RequestScope].
+    pub fn set_request_scope(&mut self, scope: Arc<dyn RequestScope>) {
+        self.request_scope = Some(scope);
+    }
+
     pub fn register_module<T: SuiRpcModule>(&mut self, module: T) -> Result<(), Error> {
         self.rpc_doc.add_module(T::rpc_doc_module());
         Ok(self.module.merge(module.rpc())?)
@@ -106,6 +139,12 @@ impl JsonRpcServerBuilder {
                 HeaderName::from_static(CLIENT_SDK_VERSION_HEADER),
                 HeaderName::from_static(CLIENT_TARGET_API_VERSION_HEADER),
                 HeaderName::from_static(APP_NAME_HEADER),
+                HeaderName::from_static(REQUEST_ID_HEADER),
+                HeaderName::from_static(MIN_CHECKPOINT_HEADER),
+            ])
+            .expose_headers([
+                HeaderName::from_static(CHECKPOINT_WATERMARK_HEADER),
+                HeaderName::from_static(REQUEST_ID_HEADER),
             ]);
 
         let routing = self.rpc_doc.method_routing.clone();
@@ -140,9 +179,18 @@ impl JsonRpcServerBuilder {
         // We need to use the routing layer to block access to the old methods when routing is disabled.
         let routing_layer = RoutingLayer::new(routing, disable_routing);
 
+        let rate_limit_layer = RateLimitLayer::new(self.rate_limit.clone());
+
+        let request_scope_layer = RequestScopeLayer::new(self.request_scope.clone());
+
+        // Outside of the other layers, so that the responses they reject requests with carry the
+        // request ID too.
         let middleware = tower::ServiceBuilder::new()
             .layer(cors)
-            .layer(routing_layer);
+            .layer(RequestIdLayer)
+            .layer(rate_limit_layer)
+            .layer(routing_layer)
+            .layer(request_scope_layer);
 
         let server = ServerBuilder::default()
             .max_response_body_size(MAX_REQUEST_SIZE)
@@ -0,0 +1,292 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::routing_layer::{is_json, response};
+use crate::MAX_REQUEST_SIZE;
+use hyper::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use jsonrpsee::core::__reexports::serde_json;
+use jsonrpsee::core::error::GenericTransportError;
+use jsonrpsee::core::http_helpers::read_body;
+use jsonrpsee::types::error::ErrorObject;
+use jsonrpsee::types::{ErrorResponse, Id, Request as RpcRequest};
+use std::collections::HashMap;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use sui_config::node::{RpcQuota, RpcRateLimitConfig};
+use tower::{Layer, Service};
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "unit_tests/rate_limit_layer_tests.rs"]
+mod rate_limit_layer_tests;
+
+/// Buckets of clients are pruned once there are this many, full buckets are dropped as they are
+/// the same as new ones.
+const MAX_TRACKED_CLIENTS: usize = 100_000;
+
+/// JSON-RPC error code of requests rejected for being over quota.
+const RATE_LIMITED_CODE: i32 = -32050;
+
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RateLimitLayer {
+    /// Requests are not limited if `config` is None.
+    pub fn new(config: Option<RpcRateLimitConfig>) -> Self {
+        Self {
+            limiter: config.map(|config| Arc::new(RateLimiter::new(config))),
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Response: 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = Box<dyn Error + Send + Sync + 'static>;
+    type Future =
+        Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send + 'static>>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let limiter = self.limiter.clone();
+        // take the service that was ready
+        // https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let res_fut = async move {
+            let req = match limiter {
+                Some(limiter) if req.method() == Method::POST && is_json(&req) => {
+                    let (parts, body) = req.into_parts();
+                    // The body is read here to get the called methods, and passed on as is.
+                    let (body, is_single) = match read_body(&parts.headers, body, MAX_REQUEST_SIZE)
+                        .await
+                    {
+                        Ok(r) => r,
+                        Err(GenericTransportError::TooLarge) => {
+                            return Ok(response::too_large(MAX_REQUEST_SIZE))
+                        }
+                        Err(GenericTransportError::Malformed) => return Ok(response::malformed()),
+                        Err(GenericTransportError::Inner(e)) => {
+                            tracing::error!("Internal error reading request body: {}", e);
+                            return Ok(response::internal_error());
+                        }
+                    };
+                    let methods = called_methods(&body, is_single);
+                    let weight = limiter.weight(&methods);
+                    let client = limiter.client(&parts.headers);
+                    if let Err(retry_after) = limiter.check(&client, weight, Instant::now()) {
+                        debug!(?client, ?methods, ?retry_after, "Rate limited RPC request");
+                        return Ok(too_many_requests(retry_after));
+                    }
+                    Request::from_parts(parts, Body::from(body))
+                }
+                _ => req,
+            };
+            inner.call(req).await.map_err(|err| err.into())
+        };
+        Box::pin(res_fut)
+    }
+}
+
+/// The methods called by a single or batched request, requests that cannot be parsed are left
+/// for the server to reject.
+fn called_methods(body: &[u8], is_single: bool) -> Vec<String> {
+    let method = |request: &[u8]| {
+        serde_json::from_slice::<RpcRequest>(request)
+            .ok()
+            .map(|request| request.method.to_string())
+    };
+    if is_single {
+        method(body).into_iter().collect()
+    } else {
+        serde_json::from_slice::<Vec<&serde_json::value::RawValue>>(body)
+            .map(|requests| {
+                requests
+                    .into_iter()
+                    .filter_map(|request| method(request.get().as_bytes()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn too_many_requests(retry_after: Duration) -> Response<Body> {
+    let retry_after_secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    let error = ErrorObject::owned(
+        RATE_LIMITED_CODE,
+        format!("Too many requests, retry after {retry_after_secs}s"),
+        None::<()>,
+    );
+    let body = serde_json::to_string(&ErrorResponse::borrowed(error, Id::Null))
+        .expect("built from known-good data; qed");
+    Response::builder()
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .header(
+            "content-type",
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        )
+        .header(RETRY_AFTER, retry_after_secs)
+        .body(Body::from(body))
+        .expect("Unable to parse response body for type conversion")
+}
+
+/// Client a request is charged to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RpcClient {
+    ApiKey(String),
+    Ip(String),
+    /// Clients with an unknown API key and no IP are not limited.
+    Unknown,
+}
+
+pub struct RateLimiter {
+    config: RpcRateLimitConfig,
+    buckets: Mutex<HashMap<RpcClient, TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RpcRateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Total weight of the called methods.
+    pub fn weight(&self, methods: &[String]) -> u32 {
+        methods
+            .iter()
+            .map(|method| {
+                self.config
+                    .method_weights
+                    .get(method)
+                    .copied()
+                    .unwrap_or(self.config.default_method_weight)
+            })
+            .fold(0, u32::saturating_add)
+    }
+
+    pub fn client(&self, headers: &HeaderMap) -> RpcClient {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+        if let Some(api_key) = header(&self.config.api_key_header) {
+            if self.config.api_key_quotas.contains_key(api_key) {
+                return RpcClient::ApiKey(api_key.to_string());
+            }
+        }
+        match header(&self.config.client_ip_header)
+            .and_then(|ips| ips.split(',').next())
+            .map(str::trim)
+        {
+            Some(ip) if !ip.is_empty() => RpcClient::Ip(ip.to_string()),
+            _ => RpcClient::Unknown,
+        }
+    }
+
+    fn quota(&self, client: &RpcClient) -> Option<RpcQuota> {
+        match client {
+            RpcClient::ApiKey(api_key) => self.config.api_key_quotas.get(api_key).copied(),
+            RpcClient::Ip(_) => self.config.per_ip_quota,
+            RpcClient::Unknown => None,
+        }
+    }
+
+    /// Charge `weight` to the quota of `client`, returns how long to wait before retrying if the
+    /// client is over quota.
+    pub fn check(&self, client: &RpcClient, weight: u32, now: Instant) -> Result<(), Duration> {
+        let Some(quota) = self.quota(client) else {
+            return Ok(());
+        };
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(client) {
+            buckets.retain(|_, bucket| !bucket.is_full(&quota, now));
+        }
+        buckets
+            .entry(client.clone())
+            .or_insert_with(|| TokenBucket::new(&quota, now))
+            .take(&quota, weight, now)
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(quota: &RpcQuota, now: Instant) -> Self {
+        Self {
+            tokens: quota.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, quota: &RpcQuota, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * quota.weight_per_second as f64).min(quota.burst as f64);
+        self.last_refill = now;
+    }
+
+    fn is_full(&mut self, quota: &RpcQuota, now: Instant) -> bool {
+        self.refill(quota, now);
+        self.tokens >= quota.burst as f64
+    }
+
+    fn take(&mut self, quota: &RpcQuota, weight: u32, now: Instant) -> Result<(), Duration> {
+        self.refill(quota, now);
+        // Requests weighing more than the burst are let through with a full bucket, otherwise
+        // they could never be served.
+        let weight = weight.min(quota.burst) as f64;
+        if self.tokens >= weight {
+            self.tokens -= weight;
+            Ok(())
+        } else if quota.weight_per_second == 0 {
+            Err(Duration::MAX)
+        } else {
+            Err(Duration::from_secs_f64(
+                (weight - self.tokens) / quota.weight_per_second as f64,
+            ))
+        }
+    }
+}
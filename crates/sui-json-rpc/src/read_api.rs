@@ -12,44 +12,48 @@ use std::sync::Arc;
 use tap::TapFallible;
 use tracing::debug;
 
-use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::{Base64, Encoding, Hex};
 use move_binary_format::normalized::{Module as NormalizedModule, Type};
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use move_core_types::value::{MoveStruct, MoveStructLayout, MoveValue};
 
+use sui_config::node::AuthorityStorePruningConfig;
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_types::{
-    Checkpoint, CheckpointId, DynamicFieldPage, MoveFunctionArgType, ObjectValueKind, Page,
-    SuiEvent, SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
-    SuiMoveStruct, SuiMoveValue, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
-    SuiPastObjectResponse, SuiTransactionEvents, SuiTransactionResponse,
-    SuiTransactionResponseOptions, TransactionsPage,
+    Checkpoint, CheckpointAttestation, CheckpointId, DynamicFieldPage, LayoutResolver,
+    MoveFunctionArgType, ObjectValueKind, PackageCache, Page, SuiEvent, SuiMoveNormalizedFunction,
+    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiMovePackage, SuiMoveStruct,
+    SuiMoveStructLayout, SuiMoveValue, SuiNodeInfo, SuiObjectData, SuiObjectDataOptions,
+    SuiObjectInfo, SuiObjectResponse, SuiParsedData, SuiPastObjectResponse, SuiPruningConfig,
+    SuiTransaction, SuiTransactionEvents, SuiTransactionResponse, SuiTransactionResponseOptions,
+    SuiTransactionSummary, TransactionsPage, TransactionsPageItem,
 };
 use sui_open_rpc::Module;
+use sui_storage::archival_store::ArchivalStore;
 use sui_types::base_types::{
-    ObjectID, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
+    ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
 };
 use sui_types::collection_types::VecMap;
 use sui_types::crypto::sha3_hash;
 use sui_types::digests::TransactionEventsDigest;
 use sui_types::display::{DisplayCreatedEvent, DisplayObject};
 use sui_types::dynamic_field::DynamicFieldName;
-use sui_types::error::UserInputError;
+use sui_types::error::{SuiError, UserInputError};
 use sui_types::intent::{AppId, Intent, IntentMessage, IntentScope, IntentVersion};
 use sui_types::messages::{
-    TransactionData, TransactionEffects, TransactionEffectsAPI, TransactionEvents,
-    VerifiedTransaction,
+    InputObjectKind, TransactionData, TransactionDataAPI, TransactionEffects,
+    TransactionEffectsAPI, TransactionEvents, VerifiedTransaction,
 };
-use sui_types::messages_checkpoint::{CheckpointSequenceNumber, CheckpointTimestamp};
-use sui_types::move_package::normalize_modules;
-use sui_types::object::{Data, Object, ObjectRead, PastObjectRead};
+use sui_types::messages_checkpoint::{self, CheckpointSequenceNumber, CheckpointTimestamp};
+use sui_types::object::{Data, Object, ObjectFormatOptions, ObjectRead, PastObjectRead};
+use sui_types::parse_sui_struct_tag;
 use sui_types::query::{EventQuery, TransactionQuery};
 
 use crate::api::cap_page_limit;
 use crate::api::ReadApiServer;
 use crate::error::Error;
-use crate::SuiRpcModule;
+use crate::{RequestScope, SuiRpcModule};
 
 use crate::api::QUERY_MAX_RESULT_LIMIT;
 
@@ -59,6 +63,11 @@ const MAX_DISPLAY_NESTED_LEVEL: usize = 10;
 // Fullnodes.
 pub struct ReadApi {
     pub state: Arc<AuthorityState>,
+    package_cache: PackageCache,
+    /// Archive of the data pruned from the node, read when the node doesn't have the data.
+    archive: Option<Arc<dyn ArchivalStore>>,
+    software_version: String,
+    pruning_config: AuthorityStorePruningConfig,
 }
 
 // Internal data structure to make it easy to work with data returned from
@@ -72,6 +81,8 @@ struct IntermediateTransactionResponse {
     events: Option<SuiTransactionEvents>,
     checkpoint_seq: Option<CheckpointSequenceNumber>,
     timestamp: Option<CheckpointTimestamp>,
+    from_archive: Option<bool>,
+    input_objects: Option<Vec<SuiObjectData>>,
     errors: Vec<String>,
 }
 
@@ -86,7 +97,122 @@ impl IntermediateTransactionResponse {
 
 impl ReadApi {
     pub fn new(state: Arc<AuthorityState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            package_cache: PackageCache::default(),
+            archive: None,
+            software_version: "unknown".to_string(),
+            pruning_config: AuthorityStorePruningConfig::default(),
+        }
+    }
+
+    /// Version of the node and configuration of its pruning, reported by `sui_getNodeInfo`.
+    pub fn with_node_config(
+        mut self,
+        software_version: &str,
+        pruning_config: AuthorityStorePruningConfig,
+    ) -> Self {
+        self.software_version = software_version.to_string();
+        self.pruning_config = pruning_config;
+        self
+    }
+
+    /// Fall back to `archive` for the transactions, checkpoints and object versions pruned from
+    /// the node.
+    pub fn with_archive(mut self, archive: Arc<dyn ArchivalStore>) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    /// Layout of `object`, if needed to serve `options`.
+    fn object_layout(
+        &self,
+        object: &Object,
+        options: &SuiObjectDataOptions,
+    ) -> RpcResult<Option<MoveStructLayout>> {
+        if !options.requires_layout() {
+            return Ok(None);
+        }
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        Ok(self
+            .package_cache
+            .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref())
+            .object_layout(object)
+            .map_err(|e| anyhow!("{e}"))?)
+    }
+
+    fn object_data(
+        &self,
+        object_ref: ObjectRef,
+        object: Object,
+        layout: Option<MoveStructLayout>,
+        options: SuiObjectDataOptions,
+        display_fields: Option<BTreeMap<String, String>>,
+    ) -> RpcResult<SuiObjectData> {
+        // The content of packages is disassembled through the cache instead of by the conversion.
+        let disassembled = match &object.data {
+            Data::Package(p) if options.show_content => Some(
+                self.package_cache
+                    .disassembled(p)
+                    .map_err(|e| anyhow!("{e}"))?,
+            ),
+            _ => None,
+        };
+        let conversion_options = SuiObjectDataOptions {
+            show_content: options.show_content && disassembled.is_none(),
+            ..options
+        };
+        let mut data: SuiObjectData = (
+            object_ref,
+            object,
+            layout,
+            conversion_options,
+            display_fields,
+        )
+            .try_into()?;
+        if let Some(disassembled) = disassembled {
+            data.content = Some(SuiParsedData::Package(SuiMovePackage {
+                disassembled: disassembled.as_ref().clone(),
+            }));
+        }
+        Ok(data)
+    }
+
+    /// Latest version of `object_id`, with the content requested by `options`.
+    async fn object_response(
+        &self,
+        object_id: ObjectID,
+        options: SuiObjectDataOptions,
+    ) -> RpcResult<SuiObjectResponse> {
+        // The layout is resolved through the package cache, and only if needed by the options.
+        let object_read = self
+            .state
+            .get_object_read_with_layout(&object_id, false)
+            .await
+            .map_err(|e| {
+                debug!(?object_id, "Failed to get object: {:?}", e);
+                anyhow!("{e}")
+            })?;
+
+        match object_read {
+            ObjectRead::NotExists(id) => Ok(SuiObjectResponse::NotExists(id)),
+            ObjectRead::Exists(object_ref, o, _) => {
+                let layout = self.object_layout(&o, &options)?;
+                let display_fields = if options.show_display {
+                    get_display_fields(self, &o, &layout).await?
+                } else {
+                    None
+                };
+                Ok(SuiObjectResponse::Exists(self.object_data(
+                    object_ref,
+                    o,
+                    layout,
+                    options,
+                    display_fields,
+                )?))
+            }
+            ObjectRead::Deleted(oref) => Ok(SuiObjectResponse::Deleted(oref.into())),
+        }
     }
 
     fn get_checkpoint_internal(&self, id: CheckpointId) -> Result<Checkpoint, Error> {
@@ -103,6 +229,187 @@ impl ReadApi {
             }
         })
     }
+
+    fn get_node_info_internal(&self) -> Result<SuiNodeInfo, Error> {
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let genesis = self
+            .state
+            .get_checkpoint_by_sequence_number(0)?
+            .ok_or_else(|| anyhow!("Genesis checkpoint not found"))?;
+        let latest_checkpoint = self.state.get_latest_checkpoint_sequence_number()?;
+        let latest = self
+            .state
+            .get_checkpoint_summary_by_sequence_number(latest_checkpoint)?;
+        let lowest_available_checkpoint = self
+            .state
+            .get_checkpoint_store()
+            .get_highest_pruned_checkpoint_seq_number()
+            .map_err(SuiError::from)?
+            .map_or(0, |pruned| pruned + 1);
+        let num_epochs_to_retain = self.pruning_config.num_epochs_to_retain;
+        Ok(SuiNodeInfo {
+            chain_identifier: Hex::encode(&genesis.digest().inner()[..4]),
+            epoch: epoch_store.epoch(),
+            protocol_version: epoch_store.protocol_version().as_u64(),
+            latest_checkpoint,
+            latest_checkpoint_timestamp_ms: latest.timestamp_ms,
+            lowest_available_checkpoint,
+            pruning: SuiPruningConfig {
+                num_epochs_to_retain: (num_epochs_to_retain != u64::MAX)
+                    .then_some(num_epochs_to_retain),
+                archive_fallback: self.archive.is_some(),
+            },
+            indexer_lag: None,
+            software_version: self.software_version.clone(),
+        })
+    }
+
+    fn get_checkpoint_attestation_internal(
+        &self,
+        id: CheckpointId,
+    ) -> Result<CheckpointAttestation, Error> {
+        let checkpoint = match &id {
+            CheckpointId::SequenceNumber(seq) => {
+                self.state.get_checkpoint_by_sequence_number(*seq)?
+            }
+            CheckpointId::Digest(digest) => self
+                .state
+                .get_checkpoint_store()
+                .get_checkpoint_by_digest(digest)
+                .map_err(SuiError::from)?,
+        }
+        .ok_or_else(|| anyhow!("Verified checkpoint not found: {:?}", id))?;
+        let summary = checkpoint.into_inner();
+        let committee = self
+            .state
+            .committee_store()
+            .get_committee(&summary.epoch())?
+            .ok_or_else(|| anyhow!("Committee of epoch {} not found", summary.epoch()))?;
+        let contents = self.state.get_checkpoint_contents(summary.content_digest)?;
+        let attestation = messages_checkpoint::CheckpointAttestation { summary, committee };
+        Ok(CheckpointAttestation::new(&attestation, contents)?)
+    }
+
+    /// Checkpoint `id` read from the archive, `None` if there is no archive or the checkpoint is
+    /// not archived.
+    async fn get_archived_checkpoint(&self, id: CheckpointId) -> Result<Option<Checkpoint>, Error> {
+        let Some(archive) = &self.archive else {
+            return Ok(None);
+        };
+        let archived = match id {
+            CheckpointId::SequenceNumber(seq) => archive.get_checkpoint(seq).await?,
+            CheckpointId::Digest(digest) => archive.get_checkpoint_by_digest(&digest).await?,
+        };
+        Ok(archived.map(|archived| {
+            let mut checkpoint = Checkpoint::from((
+                archived.summary.into_data(),
+                archived.contents.into_checkpoint_contents(),
+            ));
+            checkpoint.from_archive = Some(true);
+            checkpoint
+        }))
+    }
+
+    /// Transaction `digest` read from the archive, `None` if there is no archive, the transaction
+    /// was executed by the node or it is not archived.
+    async fn get_archived_transaction(
+        &self,
+        digest: TransactionDigest,
+        opts: &SuiTransactionResponseOptions,
+    ) -> Result<Option<IntermediateTransactionResponse>, Error> {
+        let Some(archive) = &self.archive else {
+            return Ok(None);
+        };
+        if self.state.is_tx_already_executed(&digest)? {
+            return Ok(None);
+        }
+        let Some(archived) = archive.get_transaction(&digest).await? else {
+            return Ok(None);
+        };
+        let mut response = IntermediateTransactionResponse::new(digest);
+        response.transaction = Some(VerifiedTransaction::new_unchecked(archived.transaction));
+        response.effects = Some(archived.effects);
+        response.checkpoint_seq = Some(archived.checkpoint);
+        response.timestamp = Some(archived.timestamp_ms);
+        response.from_archive = Some(true);
+        if opts.show_events {
+            match to_sui_transaction_events(self, archived.events.unwrap_or_default()) {
+                Ok(e) => response.events = Some(e),
+                Err(e) => response.errors.push(e.to_string()),
+            }
+        }
+        Ok(Some(response))
+    }
+
+    /// Read the input objects of the transaction of `response` at the versions it read them, if
+    /// requested by `opts`. Requires the transaction and its effects.
+    async fn fill_input_objects(
+        &self,
+        response: &mut IntermediateTransactionResponse,
+        opts: &SuiTransactionResponseOptions,
+    ) -> RpcResult<()> {
+        if !opts.show_input_objects {
+            return Ok(());
+        }
+        let (Some(transaction), Some(effects)) = (&response.transaction, &response.effects) else {
+            return Ok(());
+        };
+        let input_objects = match transaction.data().transaction_data().input_objects() {
+            Ok(input_objects) => input_objects,
+            Err(e) => {
+                response.errors.push(e.to_string());
+                return Ok(());
+            }
+        };
+        // Shared objects are read at the versions assigned by consensus, and packages are
+        // immutable so their latest version is the one read.
+        let versions = input_objects
+            .into_iter()
+            .map(|input| match input {
+                InputObjectKind::MovePackage(id) => (id, None),
+                InputObjectKind::ImmOrOwnedMoveObject((id, version, _)) => (id, Some(version)),
+                InputObjectKind::SharedMoveObject { id, .. } => (
+                    id,
+                    effects
+                        .shared_objects()
+                        .iter()
+                        .find(|(shared_id, _, _)| *shared_id == id)
+                        .map(|(_, version, _)| *version),
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        let mut options = SuiObjectDataOptions::new().with_type().with_owner();
+        if opts.show_input_objects_content {
+            options = options.with_content();
+        }
+        let mut objects = Vec::with_capacity(versions.len());
+        for (id, version) in versions {
+            let data = match version {
+                Some(version) => {
+                    match self
+                        .try_get_past_object(id, version, Some(options.clone()))
+                        .await?
+                    {
+                        SuiPastObjectResponse::VersionFound(data) => Some(data),
+                        _ => None,
+                    }
+                }
+                None => match self.object_response(id, options.clone()).await? {
+                    SuiObjectResponse::Exists(data) => Some(data),
+                    _ => None,
+                },
+            };
+            match data {
+                Some(data) => objects.push(data),
+                None => response.errors.push(format!(
+                    "Failed to fetch input object {id} at version {version:?}"
+                )),
+            }
+        }
+        response.input_objects = Some(objects);
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -141,26 +448,31 @@ impl ReadApiServer for ReadApi {
         object_id: ObjectID,
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse> {
-        let object_read = self.state.get_object_read(&object_id).await.map_err(|e| {
-            debug!(?object_id, "Failed to get object: {:?}", e);
-            anyhow!("{e}")
-        })?;
-        let options = options.unwrap_or_default();
+        self.object_response(object_id, options.unwrap_or_default())
+            .await
+    }
 
-        match object_read {
-            ObjectRead::NotExists(id) => Ok(SuiObjectResponse::NotExists(id)),
-            ObjectRead::Exists(object_ref, o, layout) => {
-                let display_fields = if options.show_display {
-                    get_display_fields(self, &o, &layout).await?
-                } else {
-                    None
-                };
-                Ok(SuiObjectResponse::Exists(
-                    (object_ref, o, layout, options, display_fields).try_into()?,
-                ))
-            }
-            ObjectRead::Deleted(oref) => Ok(SuiObjectResponse::Deleted(oref.into())),
+    async fn try_multi_get_object_refs(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: Option<SuiObjectDataOptions>,
+    ) -> RpcResult<Vec<SuiObjectResponse>> {
+        if object_ids.len() > QUERY_MAX_RESULT_LIMIT {
+            return Err(anyhow!(UserInputError::SizeLimitExceeded {
+                limit: "multi get object refs input limit".to_string(),
+                value: QUERY_MAX_RESULT_LIMIT.to_string()
+            })
+            .into());
+        }
+        if object_ids.iter().unique().count() < object_ids.len() {
+            return Err(anyhow!("The list of object IDs in the input contain duplicates").into());
         }
+        let options = options.unwrap_or_else(|| SuiObjectDataOptions::new().with_owner());
+        let mut responses = Vec::with_capacity(object_ids.len());
+        for object_id in object_ids {
+            responses.push(self.object_response(object_id, options.clone()).await?);
+        }
+        Ok(responses)
     }
 
     async fn try_get_past_object(
@@ -169,29 +481,58 @@ impl ReadApiServer for ReadApi {
         version: SequenceNumber,
         options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiPastObjectResponse> {
+        let options = options.unwrap_or_default();
         let past_read = self
             .state
-            .get_past_object_read(&object_id, version)
+            .get_past_object_read_with_layout(&object_id, version, false)
             .await
             .map_err(|e| anyhow!("{e}"))?;
-        let options = options.unwrap_or_default();
         match past_read {
             PastObjectRead::ObjectNotExists(id) => Ok(SuiPastObjectResponse::ObjectNotExists(id)),
-            PastObjectRead::VersionFound(object_ref, o, layout) => {
+            PastObjectRead::VersionFound(object_ref, o, _) => {
+                let layout = self.object_layout(&o, &options)?;
                 let display_fields = if options.show_display {
                     get_display_fields(self, &o, &layout).await?
                 } else {
                     None
                 };
-                Ok(SuiPastObjectResponse::VersionFound(
-                    (object_ref, o, layout, options, display_fields).try_into()?,
-                ))
+                Ok(SuiPastObjectResponse::VersionFound(self.object_data(
+                    object_ref,
+                    o,
+                    layout,
+                    options,
+                    display_fields,
+                )?))
             }
             PastObjectRead::ObjectDeleted(oref) => {
                 Ok(SuiPastObjectResponse::ObjectDeleted(oref.into()))
             }
             PastObjectRead::VersionNotFound(id, seq_num) => {
-                Ok(SuiPastObjectResponse::VersionNotFound(id, seq_num))
+                let archived = match &self.archive {
+                    Some(archive) => archive
+                        .get_object(&id, seq_num)
+                        .await
+                        .map_err(Error::from)?,
+                    None => None,
+                };
+                let Some(o) = archived else {
+                    return Ok(SuiPastObjectResponse::VersionNotFound(id, seq_num));
+                };
+                let layout = self.object_layout(&o, &options)?;
+                let display_fields = if options.show_display {
+                    get_display_fields(self, &o, &layout).await?
+                } else {
+                    None
+                };
+                let mut data = self.object_data(
+                    o.compute_object_reference(),
+                    o,
+                    layout,
+                    options,
+                    display_fields,
+                )?;
+                data.from_archive = Some(true);
+                Ok(SuiPastObjectResponse::VersionFound(data))
             }
             PastObjectRead::VersionTooHigh {
                 object_id,
@@ -209,6 +550,7 @@ impl ReadApiServer for ReadApi {
         &self,
         parent_object_id: ObjectID,
         name: DynamicFieldName,
+        options: Option<SuiObjectDataOptions>,
     ) -> RpcResult<SuiObjectResponse> {
         let id = self
             .state
@@ -217,9 +559,8 @@ impl ReadApiServer for ReadApi {
             .ok_or_else(|| {
                 anyhow!("Cannot find dynamic field [{name:?}] for object [{parent_object_id}].")
             })?;
-        // TODO(chris): add options to `get_dynamic_field_object` API as well
-        self.get_object_with_options(id, Some(SuiObjectDataOptions::full_content()))
-            .await
+        let options = options.unwrap_or_else(SuiObjectDataOptions::full_content);
+        self.get_object_with_options(id, Some(options)).await
     }
 
     async fn get_total_transaction_number(&self) -> RpcResult<u64> {
@@ -245,21 +586,43 @@ impl ReadApiServer for ReadApi {
         opts: Option<SuiTransactionResponseOptions>,
     ) -> RpcResult<SuiTransactionResponse> {
         let opts = opts.unwrap_or_default();
+        // Transactions not executed by the node may have been pruned from it.
+        if let Some(mut archived) = self.get_archived_transaction(digest, &opts).await? {
+            self.fill_input_objects(&mut archived, &opts).await?;
+            let epoch_store = self.state.load_epoch_store_one_call_per_task();
+            return Ok(convert_to_response(
+                archived,
+                &opts,
+                &self
+                    .package_cache
+                    .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref()),
+            ));
+        }
+
         let mut temp_response = IntermediateTransactionResponse::new(digest);
 
-        if opts.show_input {
-            temp_response.transaction =
-                Some(self.state.get_executed_transaction(digest).await.tap_err(
-                    |err| debug!(tx_digest=?digest, "Failed to get transaction: {:?}", err),
-                )?);
+        if opts.show_input || opts.show_input_objects {
+            temp_response.transaction = Some(
+                self.state
+                    .get_executed_transaction(digest)
+                    .await
+                    .tap_err(
+                        |err| debug!(tx_digest=?digest, "Failed to get transaction: {:?}", err),
+                    )
+                    .map_err(Error::from)?,
+            );
         }
 
         // Fetch effects when `show_events` is true because events relies on effects
-        if opts.show_effects || opts.show_events {
-            temp_response.effects =
-                Some(self.state.get_executed_effects(digest).await.tap_err(
-                    |err| debug!(tx_digest=?digest, "Failed to get effects: {:?}", err),
-                )?);
+        if opts.show_effects || opts.show_events || opts.show_raw_effects || opts.show_input_objects
+        {
+            temp_response.effects = Some(
+                self.state
+                    .get_executed_effects(digest)
+                    .await
+                    .tap_err(|err| debug!(tx_digest=?digest, "Failed to get effects: {:?}", err))
+                    .map_err(Error::from)?,
+            );
         }
 
         if let Some((_, seq)) = self
@@ -299,7 +662,16 @@ impl ReadApiServer for ReadApi {
             }
         }
 
-        Ok(convert_to_response(temp_response, &opts))
+        self.fill_input_objects(&mut temp_response, &opts).await?;
+
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        Ok(convert_to_response(
+            temp_response,
+            &opts,
+            &self
+                .package_cache
+                .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref()),
+        ))
     }
 
     async fn multi_get_transactions_with_options(
@@ -328,7 +700,7 @@ impl ReadApiServer for ReadApi {
 
         let opts = opts.unwrap_or_default();
 
-        if opts.show_input {
+        if opts.show_input || opts.show_input_objects {
             let transactions = self
                 .state
                 .multi_get_executed_transactions(&digests)
@@ -345,7 +717,8 @@ impl ReadApiServer for ReadApi {
         }
 
         // Fetch effects when `show_events` is true because events relies on effects
-        if opts.show_effects || opts.show_events {
+        if opts.show_effects || opts.show_events || opts.show_raw_effects || opts.show_input_objects
+        {
             let effects_list = self
                 .state
                 .multi_get_executed_effects(&digests)
@@ -457,9 +830,26 @@ impl ReadApiServer for ReadApi {
             }
         }
 
+        // Transactions not executed by the node may have been pruned from it.
+        for (digest, cache_entry) in temp_response.iter_mut() {
+            if cache_entry.checkpoint_seq.is_none() {
+                if let Some(archived) = self.get_archived_transaction(**digest, &opts).await? {
+                    *cache_entry = archived;
+                }
+            }
+        }
+
+        for (_, cache_entry) in temp_response.iter_mut() {
+            self.fill_input_objects(cache_entry, &opts).await?;
+        }
+
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let resolver = self
+            .package_cache
+            .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref());
         Ok(temp_response
             .into_iter()
-            .map(|c| convert_to_response(c.1, &opts))
+            .map(|c| convert_to_response(c.1, &opts, &resolver))
             .collect::<Vec<_>>())
     }
 
@@ -469,8 +859,8 @@ impl ReadApiServer for ReadApi {
     ) -> RpcResult<BTreeMap<String, SuiMoveNormalizedModule>> {
         let modules = get_move_modules_by_package(self, package).await?;
         Ok(modules
-            .into_iter()
-            .map(|(name, module)| (name, module.into()))
+            .iter()
+            .map(|(name, module)| (name.clone(), module.clone().into()))
             .collect::<BTreeMap<String, SuiMoveNormalizedModule>>())
     }
 
@@ -519,6 +909,17 @@ impl ReadApiServer for ReadApi {
         }?)
     }
 
+    async fn get_move_struct_layout(&self, struct_type: String) -> RpcResult<SuiMoveStructLayout> {
+        let struct_tag = parse_sui_struct_tag(&struct_type)?;
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let layout = self
+            .package_cache
+            .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref())
+            .struct_layout(&struct_tag, ObjectFormatOptions::with_types())
+            .map_err(|e| anyhow!("{e}"))?;
+        Ok(layout.try_into()?)
+    }
+
     async fn get_move_function_arg_types(
         &self,
         package: ObjectID,
@@ -533,7 +934,9 @@ impl ReadApiServer for ReadApi {
 
         let normalized = match object_read {
             ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
-                Data::Package(p) => normalize_modules(p.serialized_module_map().values())
+                Data::Package(p) => self
+                    .package_cache
+                    .normalized(&p)
                     .map_err(|e| anyhow!("{e}")),
                 _ => Err(anyhow!("Object is not a package with ID {}", package)),
             },
@@ -576,18 +979,36 @@ impl ReadApiServer for ReadApi {
         cursor: Option<TransactionDigest>,
         limit: Option<usize>,
         descending_order: Option<bool>,
+        show_summaries: Option<bool>,
     ) -> RpcResult<TransactionsPage> {
         let limit = cap_page_limit(limit);
         let descending = descending_order.unwrap_or_default();
 
         // Retrieve 1 extra item for next cursor
-        let mut data = self
-            .state
-            .get_transactions(query, cursor, Some(limit + 1), descending)?;
+        let mut digests =
+            self.state
+                .get_transactions(query, cursor, Some(limit + 1), descending)?;
 
         // extract next cursor
-        let next_cursor = data.get(limit).cloned();
-        data.truncate(limit);
+        let next_cursor = digests.get(limit).cloned();
+        digests.truncate(limit);
+        let data = if show_summaries.unwrap_or_default() {
+            let options = SuiTransactionResponseOptions::new()
+                .with_input()
+                .with_effects();
+            self.multi_get_transactions_with_options(digests, Some(options))
+                .await?
+                .into_iter()
+                .map(|response| {
+                    SuiTransactionSummary::try_from(response).map(TransactionsPageItem::Summary)
+                })
+                .collect::<Result<_, _>>()?
+        } else {
+            digests
+                .into_iter()
+                .map(TransactionsPageItem::from)
+                .collect()
+        };
         Ok(Page { data, next_cursor })
     }
 
@@ -601,7 +1022,21 @@ impl ReadApiServer for ReadApi {
     }
 
     async fn get_checkpoint(&self, id: CheckpointId) -> RpcResult<Checkpoint> {
-        Ok(self.get_checkpoint_internal(id)?)
+        match self.get_checkpoint_internal(id.clone()) {
+            Ok(checkpoint) => Ok(checkpoint),
+            Err(e) => Ok(self.get_archived_checkpoint(id).await?.ok_or(e)?),
+        }
+    }
+
+    async fn get_checkpoint_attestation(
+        &self,
+        id: CheckpointId,
+    ) -> RpcResult<CheckpointAttestation> {
+        Ok(self.get_checkpoint_attestation_internal(id)?)
+    }
+
+    async fn get_node_info(&self) -> RpcResult<SuiNodeInfo> {
+        Ok(self.get_node_info_internal()?)
     }
 }
 
@@ -615,22 +1050,39 @@ impl SuiRpcModule for ReadApi {
     }
 }
 
+/// Holds the requests setting a [crate::MIN_CHECKPOINT_HEADER] until the node executed the
+/// checkpoint.
+pub struct ExecutedCheckpointScope {
+    state: Arc<AuthorityState>,
+}
+
+impl ExecutedCheckpointScope {
+    pub fn new(state: Arc<AuthorityState>) -> Self {
+        Self { state }
+    }
+}
+
+impl RequestScope for ExecutedCheckpointScope {
+    fn latest_checkpoint(&self) -> Option<u64> {
+        self.state.get_latest_checkpoint_sequence_number().ok()
+    }
+}
+
 fn to_sui_transaction_events(
     fullnode_api: &ReadApi,
     events: TransactionEvents,
 ) -> RpcResult<SuiTransactionEvents> {
+    // threading the epoch_store through this API does not
+    // seem possible, so we just read it from the state and fetch
+    // the module cache out of it.
+    // Notice that no matter what module cache we get things
+    // should work
+    let epoch_store = fullnode_api.state.load_epoch_store_one_call_per_task();
     Ok(SuiTransactionEvents::try_from(
         events,
-        // threading the epoch_store through this API does not
-        // seem possible, so we just read it from the state and fetch
-        // the module cache out of it.
-        // Notice that no matter what module cache we get things
-        // should work
-        fullnode_api
-            .state
-            .load_epoch_store_one_call_per_task()
-            .module_cache()
-            .as_ref(),
+        &fullnode_api
+            .package_cache
+            .resolver(epoch_store.epoch(), epoch_store.module_cache().as_ref()),
     )?)
 }
 
@@ -741,7 +1193,7 @@ pub async fn get_move_module(
 pub async fn get_move_modules_by_package(
     fullnode_api: &ReadApi,
     package: ObjectID,
-) -> RpcResult<BTreeMap<String, NormalizedModule>> {
+) -> RpcResult<Arc<BTreeMap<String, NormalizedModule>>> {
     let object_read = fullnode_api
         .state
         .get_object_read(&package)
@@ -750,9 +1202,10 @@ pub async fn get_move_modules_by_package(
 
     Ok(match object_read {
         ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
-            Data::Package(p) => {
-                normalize_modules(p.serialized_module_map().values()).map_err(|e| anyhow!("{e}"))
-            }
+            Data::Package(p) => fullnode_api
+                .package_cache
+                .normalized(&p)
+                .map_err(|e| anyhow!("{e}")),
             _ => Err(anyhow!("Object is not a package with ID {}", package)),
         },
         _ => Err(anyhow!("Package object does not exist with ID {}", package)),
@@ -883,12 +1336,13 @@ fn get_value_from_move_struct(move_struct: &SuiMoveStruct, var_name: &str) -> Rp
 fn convert_to_response(
     cache: IntermediateTransactionResponse,
     opts: &SuiTransactionResponseOptions,
+    resolver: &impl LayoutResolver,
 ) -> SuiTransactionResponse {
     let mut response = SuiTransactionResponse::new(cache.digest);
     response.errors = cache.errors;
 
     if opts.show_input && cache.transaction.is_some() {
-        match cache.transaction.unwrap().into_message().try_into() {
+        match SuiTransaction::try_from(cache.transaction.unwrap().into_message(), resolver) {
             Ok(t) => {
                 response.transaction = Some(t);
             }
@@ -898,6 +1352,19 @@ fn convert_to_response(
         }
     }
 
+    if opts.show_raw_effects {
+        if let Some(effects) = &cache.effects {
+            match bcs::to_bytes(effects) {
+                Ok(raw_effects) => {
+                    response.raw_effects = raw_effects;
+                }
+                Err(e) => {
+                    response.errors.push(e.to_string());
+                }
+            }
+        }
+    }
+
     if opts.show_effects && cache.effects.is_some() {
         match cache.effects.unwrap().try_into() {
             Ok(effects) => {
@@ -911,10 +1378,15 @@ fn convert_to_response(
 
     response.checkpoint = cache.checkpoint_seq;
     response.timestamp_ms = cache.timestamp;
+    response.from_archive = cache.from_archive;
 
     if opts.show_events {
         response.events = cache.events;
     }
 
+    if opts.show_input_objects {
+        response.input_objects = cache.input_objects;
+    }
+
     response
 }
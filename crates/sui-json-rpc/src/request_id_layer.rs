@@ -0,0 +1,91 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use hyper::header::{HeaderName, HeaderValue};
+use hyper::{Body, Request, Response};
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::{info_span, Instrument};
+
+use crate::request_scope_layer::{BoxError, RequestFuture};
+use crate::REQUEST_ID_HEADER;
+
+/// Request IDs set by clients longer than this are replaced, to bound the size of the logs.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Handles every request within a tracing span carrying the ID of the request, so that the logs
+/// of a request can be correlated across the services it goes through. The ID is read from the
+/// [REQUEST_ID_HEADER] of the request, or generated if the client did not set it, and is echoed
+/// in the same header of the response, including error responses.
+#[derive(Clone, Default)]
+pub struct RequestIdLayer;
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+}
+
+impl<S> Service<Request<Body>> for RequestIdService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = RequestFuture;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
+        let header = HeaderName::from_static(REQUEST_ID_HEADER);
+        let request_id = match req.headers().get(&header).filter(|id| is_valid(id)) {
+            Some(request_id) => request_id.clone(),
+            None => {
+                let request_id = new_request_id();
+                req.headers_mut().insert(&header, request_id.clone());
+                request_id
+            }
+        };
+        // Valid IDs are visible ASCII.
+        let span = info_span!(
+            "rpc_request",
+            request_id = request_id.to_str().unwrap_or_default()
+        );
+
+        let clone = self.inner.clone();
+        // take the service that was ready
+        // https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(
+            async move {
+                let mut response = inner.call(req).await.map_err(Into::into)?;
+                response.headers_mut().insert(header, request_id);
+                Ok(response)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+fn is_valid(request_id: &HeaderValue) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= MAX_REQUEST_ID_LENGTH
+        && request_id.as_bytes().iter().all(u8::is_ascii_graphic)
+}
+
+fn new_request_id() -> HeaderValue {
+    HeaderValue::from_str(&format!("{:032x}", rand::random::<u128>()))
+        .expect("Hex strings are valid header values")
+}
@@ -0,0 +1,135 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::future::BoxFuture;
+use hyper::header::HeaderMap;
+use hyper::{Body, Request, Response};
+use std::error::Error;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::Instant;
+use tower::{Layer, Service};
+
+use crate::routing_layer::response;
+use crate::MIN_CHECKPOINT_HEADER;
+
+pub type BoxError = Box<dyn Error + Send + Sync + 'static>;
+
+/// Future handling a request, the method calls of the request are executed when it is polled.
+pub type RequestFuture = BoxFuture<'static, Result<Response<Body>, BoxError>>;
+
+/// How long a request waits for the checkpoint set in its [MIN_CHECKPOINT_HEADER] before being
+/// rejected.
+const MIN_CHECKPOINT_TIMEOUT: Duration = Duration::from_secs(10);
+
+const MIN_CHECKPOINT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Scope the handling of every request runs within, e.g. to pin all the reads of a request to
+/// one snapshot of the data, and to add headers to the response.
+pub trait RequestScope: Send + Sync + 'static {
+    fn run(&self, request: RequestFuture) -> RequestFuture {
+        request
+    }
+
+    /// Latest checkpoint in the data read by the requests, None if there is no checkpoint yet.
+    /// The requests setting a later checkpoint in their [MIN_CHECKPOINT_HEADER] are held until
+    /// it is reached, before running in the scope.
+    fn latest_checkpoint(&self) -> Option<u64>;
+}
+
+#[derive(Clone)]
+pub struct RequestScopeLayer {
+    scope: Option<Arc<dyn RequestScope>>,
+}
+
+impl RequestScopeLayer {
+    /// Requests are handled as is if `scope` is None.
+    pub fn new(scope: Option<Arc<dyn RequestScope>>) -> Self {
+        Self { scope }
+    }
+}
+
+impl<S> Layer<S> for RequestScopeLayer {
+    type Service = RequestScopeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestScopeService {
+            inner,
+            scope: self.scope.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestScopeService<S> {
+    inner: S,
+    scope: Option<Arc<dyn RequestScope>>,
+}
+
+impl<S> Service<Request<Body>> for RequestScopeService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Response: 'static,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = RequestFuture;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        // take the service that was ready
+        // https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let min_checkpoint = min_checkpoint(req.headers());
+        let res_fut: RequestFuture =
+            Box::pin(async move { inner.call(req).await.map_err(|err| err.into()) });
+        match self.scope.clone() {
+            Some(scope) => Box::pin(async move {
+                if let Some(min_checkpoint) = min_checkpoint {
+                    if let Err(latest) = wait_for_checkpoint(scope.as_ref(), min_checkpoint).await {
+                        return Ok(response::checkpoint_not_reached(min_checkpoint, latest));
+                    }
+                }
+                scope.run(res_fut).await
+            }),
+            None => res_fut,
+        }
+    }
+}
+
+/// The checkpoint set in the [MIN_CHECKPOINT_HEADER] of a request, invalid values are ignored.
+fn min_checkpoint(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(MIN_CHECKPOINT_HEADER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Waits until the latest checkpoint of `scope` is at least `min_checkpoint`, returns the latest
+/// checkpoint if it isn't after [MIN_CHECKPOINT_TIMEOUT].
+async fn wait_for_checkpoint(
+    scope: &dyn RequestScope,
+    min_checkpoint: u64,
+) -> Result<(), Option<u64>> {
+    let deadline = Instant::now() + MIN_CHECKPOINT_TIMEOUT;
+    loop {
+        let latest = scope.latest_checkpoint();
+        if latest.map_or(false, |latest| latest >= min_checkpoint) {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err(latest);
+        }
+        tokio::time::sleep(MIN_CHECKPOINT_POLL_INTERVAL).await;
+    }
+}
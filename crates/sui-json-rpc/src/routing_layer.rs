@@ -200,12 +200,15 @@ fn process_single_request(
 }
 
 // error responses borrowed from jsonrpsee
-mod response {
+pub(crate) mod response {
     use jsonrpsee::core::__reexports::serde_json;
-    use jsonrpsee::types::error::{reject_too_big_request, ErrorCode};
+    use jsonrpsee::types::error::{reject_too_big_request, ErrorCode, ErrorObject};
     use jsonrpsee::types::{ErrorResponse, Id};
     const JSON: &str = "application/json; charset=utf-8";
 
+    /// JSON-RPC error code of requests rejected for requiring a checkpoint not reached in time.
+    const CHECKPOINT_NOT_REACHED_CODE: i32 = -32051;
+
     pub(crate) fn too_large(limit: u32) -> hyper::Response<hyper::Body> {
         let error = serde_json::to_string(&ErrorResponse::borrowed(
             reject_too_big_request(limit),
@@ -235,6 +238,24 @@ mod response {
         from_template(hyper::StatusCode::BAD_REQUEST, error, JSON)
     }
 
+    pub(crate) fn checkpoint_not_reached(
+        min_checkpoint: u64,
+        latest: Option<u64>,
+    ) -> hyper::Response<hyper::Body> {
+        let latest = latest.map_or_else(|| "none".to_string(), |latest| latest.to_string());
+        let error = serde_json::to_string(&ErrorResponse::borrowed(
+            ErrorObject::owned(
+                CHECKPOINT_NOT_REACHED_CODE,
+                format!("Checkpoint {min_checkpoint} not reached, latest checkpoint is {latest}"),
+                None::<()>,
+            ),
+            Id::Null,
+        ))
+        .expect("built from known-good data; qed");
+
+        from_template(hyper::StatusCode::SERVICE_UNAVAILABLE, error, JSON)
+    }
+
     fn from_template<S: Into<hyper::Body>>(
         status: hyper::StatusCode,
         body: S,
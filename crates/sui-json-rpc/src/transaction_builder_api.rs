@@ -3,19 +3,20 @@
 
 use crate::api::TransactionBuilderServer;
 use crate::SuiRpcModule;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use std::sync::Arc;
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_types::{
-    BigInt, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse, SuiTransactionBuilderMode,
-    SuiTypeTag, TransactionBytes,
+    BatchTransferParams, BigInt, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
+    SuiTransactionBuilderMode, SuiTypeTag, TransactionBytes,
 };
 use sui_open_rpc::Module;
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 use sui_types::{
-    base_types::{ObjectID, SuiAddress},
-    messages::TransactionData,
+    base_types::{EpochId, ObjectID, SuiAddress},
+    messages::{TransactionData, TransactionDataAPI, TransactionExpiration},
 };
 
 use fastcrypto::encoding::Base64;
@@ -26,14 +27,16 @@ use sui_json::SuiJsonValue;
 use sui_json_rpc_types::RPCTransactionRequestParams;
 
 pub struct TransactionBuilderApi {
+    state: Arc<AuthorityState>,
     builder: TransactionBuilder<Normal>,
     dev_inspect_builder: TransactionBuilder<DevInspect>,
 }
 
 impl TransactionBuilderApi {
     pub fn new(state: Arc<AuthorityState>) -> Self {
-        let reader = Arc::new(AuthorityStateDataReader::new(state));
+        let reader = Arc::new(AuthorityStateDataReader::new(state.clone()));
         Self {
+            state,
             builder: TransactionBuilder::new(reader.clone()),
             dev_inspect_builder: TransactionBuilder::new(reader),
         }
@@ -168,6 +171,30 @@ impl TransactionBuilderServer for TransactionBuilderApi {
         Ok(TransactionBytes::from_data(data)?)
     }
 
+    async fn auto_pay_sui(
+        &self,
+        signer: SuiAddress,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<BigInt>,
+        max_input_coins: Option<usize>,
+        gas_budget: u64,
+    ) -> RpcResult<Vec<TransactionBytes>> {
+        let data = self
+            .builder
+            .auto_pay_sui(
+                signer,
+                recipients,
+                amounts.into_iter().map(|a| a.into()).collect(),
+                max_input_coins,
+                gas_budget,
+            )
+            .await?;
+        Ok(data
+            .into_iter()
+            .map(TransactionBytes::from_data)
+            .collect::<anyhow::Result<Vec<_>>>()?)
+    }
+
     async fn publish(
         &self,
         sender: SuiAddress,
@@ -186,6 +213,35 @@ impl TransactionBuilderServer for TransactionBuilderApi {
         Ok(TransactionBytes::from_data(data)?)
     }
 
+    async fn upgrade_package(
+        &self,
+        sender: SuiAddress,
+        package_id: ObjectID,
+        compiled_modules: Vec<Base64>,
+        dependencies: Vec<ObjectID>,
+        upgrade_capability: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes> {
+        let compiled_modules = compiled_modules
+            .into_iter()
+            .map(|data| data.to_vec().map_err(|e| anyhow::anyhow!(e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let data = self
+            .builder
+            .upgrade(
+                sender,
+                package_id,
+                compiled_modules,
+                dependencies,
+                upgrade_capability,
+                gas,
+                gas_budget,
+            )
+            .await?;
+        Ok(TransactionBytes::from_data(data)?)
+    }
+
     async fn split_coin(
         &self,
         signer: SuiAddress,
@@ -301,6 +357,20 @@ impl TransactionBuilderServer for TransactionBuilderApi {
         Ok(TransactionBytes::from_data(data)?)
     }
 
+    async fn batch_transfer(
+        &self,
+        signer: SuiAddress,
+        transfers: Vec<BatchTransferParams>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> RpcResult<TransactionBytes> {
+        let data = self
+            .builder
+            .batch_transfer(signer, transfers, gas, gas_budget)
+            .await?;
+        Ok(TransactionBytes::from_data(data)?)
+    }
+
     async fn request_add_delegation(
         &self,
         signer: SuiAddress,
@@ -331,6 +401,25 @@ impl TransactionBuilderServer for TransactionBuilderApi {
                 .await?,
         )?)
     }
+
+    async fn set_transaction_expiration(
+        &self,
+        tx_bytes: Base64,
+        epoch: EpochId,
+    ) -> RpcResult<TransactionBytes> {
+        let mut data: TransactionData =
+            bcs::from_bytes(&tx_bytes.to_vec().map_err(|e| anyhow!(e))?).map_err(|e| anyhow!(e))?;
+        // Validators would refuse to sign the transaction right away.
+        let current_epoch = self.state.load_epoch_store_one_call_per_task().epoch();
+        if epoch < current_epoch {
+            return Err(anyhow!(
+                "Expiration epoch {epoch} is before the current epoch {current_epoch}"
+            )
+            .into());
+        }
+        *data.expiration_mut() = TransactionExpiration::Epoch(epoch);
+        Ok(TransactionBytes::from_data(data)?)
+    }
 }
 
 impl SuiRpcModule for TransactionBuilderApi {
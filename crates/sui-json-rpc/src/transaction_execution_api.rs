@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::api::WriteApiServer;
+use crate::error::Error;
 use crate::read_api::get_transaction_data_and_digest;
 use crate::SuiRpcModule;
 use anyhow::anyhow;
@@ -16,10 +17,11 @@ use sui_core::authority::AuthorityState;
 use sui_core::authority_client::NetworkAuthorityClient;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_json_rpc_types::{
-    DevInspectResults, DryRunTransactionResponse, SuiTransactionEvents, SuiTransactionResponse,
+    DevInspectResults, DryRunTransactionResponse, SuiTransaction, SuiTransactionDataAPI,
+    SuiTransactionEvents, SuiTransactionResponse, SuiTransactionSubmissionStatus,
 };
 use sui_open_rpc::Module;
-use sui_types::base_types::{EpochId, SuiAddress};
+use sui_types::base_types::{EpochId, SuiAddress, TransactionDigest};
 use sui_types::intent::Intent;
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, TransactionKind,
@@ -73,19 +75,15 @@ impl WriteApiServer for TransactionExecutionApi {
         signatures: Vec<Base64>,
         request_type: ExecuteTransactionRequestType,
     ) -> RpcResult<SuiTransactionResponse> {
-        let tx_data =
-            bcs::from_bytes(&tx_bytes.to_vec().map_err(|e| anyhow!(e))?).map_err(|e| anyhow!(e))?;
-
-        let mut sigs = Vec::new();
-        for sig in signatures {
-            sigs.push(
-                GenericSignature::from_bytes(&sig.to_vec().map_err(|e| anyhow!(e))?)
-                    .map_err(|e| anyhow!(e))?,
-            );
-        }
-
-        let txn = Transaction::from_generic_sig_data(tx_data, Intent::default(), sigs);
-        let tx = txn.data().clone().try_into()?;
+        let txn = to_transaction(tx_bytes, signatures)?;
+        let module_cache = self
+            .state
+            .load_epoch_store_one_call_per_task()
+            .module_cache()
+            .clone();
+        let tx = SuiTransaction::try_from(txn.data().clone(), module_cache.as_ref())
+            .map_err(Error::from)?;
+        let gas_owner = tx.data.gas_data().owner;
         let digest = *txn.digest();
 
         let transaction_orchestrator = self.transaction_orchestrator.clone();
@@ -97,33 +95,55 @@ impl WriteApiServer for TransactionExecutionApi {
         ))
         .await
         .map_err(|e| anyhow!(e))? // for JoinError
-        .map_err(|e| anyhow!(e))?; // For Sui transaction execution error (SuiResult<ExecuteTransactionResponse>)
+        .map_err(Error::from)?; // For Sui transaction execution error (SuiResult<ExecuteTransactionResponse>)
 
         match response {
             ExecuteTransactionResponse::EffectsCert(cert) => {
                 let (effects, events, is_executed_locally) = *cert;
-                let module_cache = self
-                    .state
-                    .load_epoch_store_one_call_per_task()
-                    .module_cache()
-                    .clone();
+                let events = SuiTransactionEvents::try_from(events, module_cache.as_ref())?;
                 Ok(SuiTransactionResponse {
                     digest,
                     transaction: Some(tx),
                     effects: Some(effects.effects.try_into()?),
-                    events: Some(SuiTransactionEvents::try_from(
-                        events,
-                        module_cache.as_ref(),
-                    )?),
+                    gas_owner_balance_change: Some(events.sui_balance_change(gas_owner)),
+                    events: Some(events),
+                    raw_effects: vec![],
                     timestamp_ms: None,
                     confirmed_local_execution: Some(is_executed_locally),
                     checkpoint: None,
+                    from_archive: None,
+                    input_objects: None,
                     errors: vec![],
                 })
             }
         }
     }
 
+    async fn enqueue_transaction(
+        &self,
+        tx_bytes: Base64,
+        signatures: Vec<Base64>,
+    ) -> RpcResult<TransactionDigest> {
+        let txn = to_transaction(tx_bytes, signatures)?;
+        let transaction_orchestrator = self.transaction_orchestrator.clone();
+        Ok(
+            spawn_monitored_task!(transaction_orchestrator.enqueue_transaction(txn))
+                .await
+                .map_err(|e| anyhow!(e))? // for JoinError
+                .map_err(Error::from)?,
+        )
+    }
+
+    async fn get_transaction_submission_status(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionSubmissionStatus> {
+        Ok(self
+            .transaction_orchestrator
+            .submission_status(&digest)?
+            .try_into()?)
+    }
+
     async fn dev_inspect_transaction(
         &self,
         sender_address: SuiAddress,
@@ -148,6 +168,25 @@ impl WriteApiServer for TransactionExecutionApi {
     }
 }
 
+fn to_transaction(tx_bytes: Base64, signatures: Vec<Base64>) -> anyhow::Result<Transaction> {
+    let tx_data =
+        bcs::from_bytes(&tx_bytes.to_vec().map_err(|e| anyhow!(e))?).map_err(|e| anyhow!(e))?;
+
+    let mut sigs = Vec::new();
+    for sig in signatures {
+        sigs.push(
+            GenericSignature::from_bytes(&sig.to_vec().map_err(|e| anyhow!(e))?)
+                .map_err(|e| anyhow!(e))?,
+        );
+    }
+
+    Ok(Transaction::from_generic_sig_data(
+        tx_data,
+        Intent::default(),
+        sigs,
+    ))
+}
+
 impl SuiRpcModule for TransactionExecutionApi {
     fn rpc(self) -> RpcModule<Self> {
         self.into_rpc()
@@ -0,0 +1,119 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{called_methods, RateLimiter, RpcClient};
+use hyper::header::{HeaderMap, HeaderValue};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use sui_config::node::{RpcQuota, RpcRateLimitConfig};
+
+fn config() -> RpcRateLimitConfig {
+    RpcRateLimitConfig {
+        method_weights: BTreeMap::from([("sui_dryRunTransaction".to_string(), 5)]),
+        per_ip_quota: Some(RpcQuota {
+            weight_per_second: 2,
+            burst: 10,
+        }),
+        api_key_quotas: BTreeMap::from([(
+            "partner".to_string(),
+            RpcQuota {
+                weight_per_second: 100,
+                burst: 100,
+            },
+        )]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_called_methods() {
+    let single = br#"{"jsonrpc":"2.0","id":1,"method":"sui_getObject","params":[]}"#;
+    assert_eq!(called_methods(single, true), vec!["sui_getObject"]);
+
+    let batch = br#"[
+        {"jsonrpc":"2.0","id":1,"method":"sui_getObject","params":[]},
+        {"jsonrpc":"2.0","id":2,"method":"sui_dryRunTransaction","params":[]}
+    ]"#;
+    assert_eq!(
+        called_methods(batch, false),
+        vec!["sui_getObject", "sui_dryRunTransaction"]
+    );
+
+    assert!(called_methods(b"not json", true).is_empty());
+}
+
+#[test]
+fn test_weight() {
+    let limiter = RateLimiter::new(config());
+    assert_eq!(limiter.weight(&["sui_getObject".to_string()]), 1);
+    assert_eq!(
+        limiter.weight(&[
+            "sui_getObject".to_string(),
+            "sui_dryRunTransaction".to_string()
+        ]),
+        6
+    );
+}
+
+#[test]
+fn test_client() {
+    let limiter = RateLimiter::new(config());
+    let mut headers = HeaderMap::new();
+    assert_eq!(limiter.client(&headers), RpcClient::Unknown);
+
+    headers.insert(
+        "x-forwarded-for",
+        HeaderValue::from_static("1.2.3.4, 10.0.0.1"),
+    );
+    assert_eq!(
+        limiter.client(&headers),
+        RpcClient::Ip("1.2.3.4".to_string())
+    );
+
+    // Unknown API keys are limited by IP.
+    headers.insert("x-api-key", HeaderValue::from_static("unknown"));
+    assert_eq!(
+        limiter.client(&headers),
+        RpcClient::Ip("1.2.3.4".to_string())
+    );
+
+    headers.insert("x-api-key", HeaderValue::from_static("partner"));
+    assert_eq!(
+        limiter.client(&headers),
+        RpcClient::ApiKey("partner".to_string())
+    );
+}
+
+#[test]
+fn test_check_quota() {
+    let limiter = RateLimiter::new(config());
+    let client = RpcClient::Ip("1.2.3.4".to_string());
+    let now = Instant::now();
+
+    // The burst is available at once.
+    assert!(limiter.check(&client, 5, now).is_ok());
+    assert!(limiter.check(&client, 5, now).is_ok());
+    assert_eq!(
+        limiter.check(&client, 1, now),
+        Err(Duration::from_millis(500))
+    );
+
+    // Other clients have their own quota.
+    let other = RpcClient::Ip("5.6.7.8".to_string());
+    assert!(limiter.check(&other, 10, now).is_ok());
+
+    // The quota is refilled over time, up to the burst.
+    let later = now + Duration::from_secs(1);
+    assert!(limiter.check(&client, 2, later).is_ok());
+    assert!(limiter.check(&client, 1, later).is_err());
+    let much_later = now + Duration::from_secs(60);
+    assert!(limiter.check(&client, 10, much_later).is_ok());
+    assert!(limiter.check(&client, 1, much_later).is_err());
+
+    // Requests weighing more than the burst pass with a full bucket.
+    let even_later = much_later + Duration::from_secs(60);
+    assert!(limiter.check(&client, 50, even_later).is_ok());
+
+    // Clients without a quota are not limited.
+    assert!(limiter.check(&RpcClient::Unknown, u32::MAX, now).is_ok());
+}
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::api::{
-    CoinReadApiClient, GovernanceReadApiClient, ReadApiClient, ThresholdBlsApiClient,
-    TransactionBuilderClient, WriteApiClient,
+    CoinReadApiClient, EventReadApiClient, GovernanceReadApiClient, ReadApiClient,
+    ThresholdBlsApiClient, TransactionBuilderClient, WriteApiClient,
 };
+use jsonrpsee::types::error::CallError;
 use std::path::Path;
 
 #[cfg(not(msim))]
@@ -14,22 +15,31 @@ use sui_framework_build::compiled_package::BuildConfig;
 use sui_json::SuiJsonValue;
 
 use sui_json_rpc_types::{
-    Balance, CoinPage, SuiCoinMetadata, SuiEvent, SuiExecutionStatus, SuiObjectResponse,
-    SuiTBlsSignObjectCommitmentType, SuiTransactionEffectsAPI, SuiTransactionResponse,
-    SuiTransactionResponseOptions, TransactionBytes,
+    Balance, BatchTransferParams, CheckpointId, CoinPage, SuiCoinMetadata, SuiEvent,
+    SuiExecutionStatus, SuiMoveAbility, SuiMoveNormalizedType, SuiMoveTypeLayout,
+    SuiMoveVisibility, SuiObjectResponse, SuiRpcErrorData, SuiTBlsSignObjectCommitmentType,
+    SuiTransactionDataAPI, SuiTransactionEffectsAPI, SuiTransactionResponse,
+    SuiTransactionResponseOptions, TransactionBytes, TRANSACTION_NOT_FOUND_CODE,
 };
 use sui_json_rpc_types::{SuiObjectDataOptions, SuiObjectInfo};
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_protocol_config::ProtocolVersion;
 use sui_types::balance::Supply;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::TransactionDigest;
 use sui_types::coin::{TreasuryCap, COIN_MODULE_NAME, LOCKED_COIN_MODULE_NAME};
+use sui_types::event::EventID;
 use sui_types::gas_coin::GAS;
-use sui_types::messages::ExecuteTransactionRequestType;
+use sui_types::messages::{
+    ExecuteTransactionRequestType, TransactionDataAPI, TransactionEffects, TransactionEffectsAPI,
+    TransactionExpiration,
+};
 use sui_types::object::Owner;
 use sui_types::query::{EventQuery, TransactionQuery};
 use sui_types::utils::to_sender_signed_transaction;
-use sui_types::{parse_sui_struct_tag, parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS};
+use sui_types::{
+    parse_sui_struct_tag, parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS, SUI_FRAMEWORK_OBJECT_ID,
+};
 use test_utils::network::TestClusterBuilder;
 
 use sui_macros::sim_test;
@@ -90,6 +100,119 @@ async fn test_public_transfer_object() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_batch_transfer() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let recipient = cluster.accounts.last().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+
+    let transaction_bytes: TransactionBytes = http_client
+        .batch_transfer(
+            *address,
+            vec![
+                BatchTransferParams::Object {
+                    object_id: objects[0].object_id,
+                    recipient: *recipient,
+                },
+                BatchTransferParams::Object {
+                    object_id: objects[1].object_id,
+                    recipient: *recipient,
+                },
+                BatchTransferParams::Sui {
+                    amount: 1000.into(),
+                    recipient: *recipient,
+                },
+            ],
+            Some(objects.last().unwrap().object_id),
+            1000,
+        )
+        .await?;
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(transaction_bytes.to_data()?, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+
+    let tx_response: SuiTransactionResponse = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+
+    let effects = tx_response.effects.unwrap();
+    assert_eq!(SuiExecutionStatus::Success, *effects.status());
+    // One new coin for the SUI amount.
+    assert_eq!(effects.created().len(), 1);
+
+    let recipient_objects = http_client.get_objects_owned_by_address(*recipient).await?;
+    assert!(recipient_objects
+        .iter()
+        .any(|o| o.object_id == objects[0].object_id));
+    assert!(recipient_objects
+        .iter()
+        .any(|o| o.object_id == objects[1].object_id));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_auto_pay_sui() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let recipient = cluster.accounts.last().unwrap();
+
+    // Two payments of half the largest coin cannot both come out of one coin.
+    let coins = http_client.get_coins(*address, None, None, None).await?;
+    let largest = coins.data.iter().map(|coin| coin.balance).max().unwrap();
+    let amount = largest / 2;
+    let transactions: Vec<TransactionBytes> = http_client
+        .auto_pay_sui(
+            *address,
+            vec![*recipient, *recipient],
+            vec![amount.into(), amount.into()],
+            Some(1),
+            1000,
+        )
+        .await?;
+    assert_eq!(transactions.len(), 2);
+
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    for transaction_bytes in transactions {
+        let data = transaction_bytes.to_data()?;
+        assert_eq!(data.gas().len(), 1);
+        let tx = to_sender_signed_transaction(data, keystore.get_key(address)?);
+        let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+        let tx_response: SuiTransactionResponse = http_client
+            .submit_transaction(
+                tx_bytes,
+                signatures,
+                ExecuteTransactionRequestType::WaitForLocalExecution,
+            )
+            .await?;
+        let effects = tx_response.effects.unwrap();
+        assert_eq!(SuiExecutionStatus::Success, *effects.status());
+    }
+
+    // Payments the coins cannot cover are rejected.
+    assert!(http_client
+        .auto_pay_sui(
+            *address,
+            vec![*recipient],
+            vec![(largest * 2).into()],
+            Some(1),
+            1000,
+        )
+        .await
+        .is_err());
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_tbls_sign_randomness_object() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
@@ -352,6 +475,131 @@ async fn test_get_object_info() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_try_multi_get_object_refs() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+
+    let mut object_ids: Vec<_> = objects.iter().map(|o| o.object_id).collect();
+    let missing = ObjectID::random();
+    object_ids.push(missing);
+    let responses = http_client
+        .try_multi_get_object_refs(object_ids.clone(), None)
+        .await?;
+    assert_eq!(object_ids.len(), responses.len());
+    for (oref, response) in objects.iter().zip(&responses) {
+        let SuiObjectResponse::Exists(object) = response else {
+            panic!("Object {} should exist", oref.object_id);
+        };
+        assert_eq!(oref.to_object_ref(), object.object_ref());
+        assert_eq!(Some(Owner::AddressOwner(*address)), object.owner);
+        // Only the refs and owners are returned by default.
+        assert!(object.type_.is_none() && object.content.is_none() && object.bcs.is_none());
+    }
+    assert!(matches!(responses.last(), Some(SuiObjectResponse::NotExists(id)) if *id == missing));
+
+    let responses = http_client
+        .try_multi_get_object_refs(
+            object_ids[..1].to_vec(),
+            Some(SuiObjectDataOptions::full_content()),
+        )
+        .await?;
+    assert!(
+        matches!(&responses[..], [SuiObjectResponse::Exists(object)] if object.content.is_some())
+    );
+
+    // Duplicates are rejected
+    let duplicates = vec![object_ids[0], object_ids[0]];
+    assert!(http_client
+        .try_multi_get_object_refs(duplicates, None)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_move_struct_layout() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let layout = http_client
+        .get_move_struct_layout("0x2::coin::Coin<0x2::sui::SUI>".to_string())
+        .await?;
+    assert_eq!(
+        parse_sui_struct_tag(&layout.type_)?,
+        parse_sui_struct_tag("0x2::coin::Coin<0x2::sui::SUI>")?
+    );
+    let fields = layout
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec!["id", "balance"]);
+    assert!(matches!(
+        &layout.fields[1].layout,
+        SuiMoveTypeLayout::Struct(balance) if balance.fields.len() == 1
+            && balance.fields[0].layout == SuiMoveTypeLayout::U64
+    ));
+
+    let result = http_client
+        .get_move_struct_layout("0x2::coin::NotAStruct".to_string())
+        .await;
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_normalized_move_signatures() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let function = http_client
+        .get_normalized_move_function(SUI_FRAMEWORK_OBJECT_ID, "pay".into(), "split".into())
+        .await?;
+    assert!(matches!(function.visibility, SuiMoveVisibility::Public));
+    assert!(function.is_entry);
+    assert_eq!(function.type_parameters.len(), 1);
+    assert!(function.type_parameters[0].abilities.is_empty());
+    assert_eq!(function.parameters.len(), 3);
+    assert!(matches!(
+        &function.parameters[0],
+        SuiMoveNormalizedType::MutableReference(coin) if matches!(
+            coin.as_ref(),
+            SuiMoveNormalizedType::Struct { module, name, .. } if module == "coin" && name == "Coin"
+        )
+    ));
+    assert!(matches!(function.parameters[1], SuiMoveNormalizedType::U64));
+    assert!(function.return_.is_empty());
+
+    let function = http_client
+        .get_normalized_move_function(SUI_FRAMEWORK_OBJECT_ID, "coin".into(), "value".into())
+        .await?;
+    assert!(!function.is_entry);
+    assert_eq!(function.return_.len(), 1);
+
+    let struct_ = http_client
+        .get_normalized_move_struct(SUI_FRAMEWORK_OBJECT_ID, "coin".into(), "Coin".into())
+        .await?;
+    let abilities = &struct_.abilities.abilities;
+    assert_eq!(abilities.len(), 2);
+    assert!(abilities.iter().any(|a| matches!(a, SuiMoveAbility::Key)));
+    assert!(abilities.iter().any(|a| matches!(a, SuiMoveAbility::Store)));
+    assert!(struct_.type_parameters[0].is_phantom);
+
+    let module = http_client
+        .get_normalized_move_module(SUI_FRAMEWORK_OBJECT_ID, "pay".into())
+        .await?;
+    assert!(module.exposed_functions.contains_key("split"));
+
+    let result = http_client
+        .get_normalized_move_function(SUI_FRAMEWORK_OBJECT_ID, "pay".into(), "nope".into())
+        .await;
+    assert!(result.is_err());
+    Ok(())
+}
+
 #[sim_test]
 async fn test_get_coins() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
@@ -462,6 +710,18 @@ async fn test_get_metadata() -> Result<(), anyhow::Error> {
     assert_eq!("Trusted Coin", result.name);
     assert_eq!(2, result.decimals);
 
+    let results = http_client
+        .multi_get_coin_metadata(vec![
+            format!("{package_id}::trusted_coin::TRUSTED_COIN"),
+            format!("{package_id}::trusted_coin::NOT_A_COIN"),
+            "0x2::sui::SUI".to_string(),
+        ])
+        .await?;
+    assert_eq!(results.len(), 3);
+    assert_eq!("TRUSTED", results[0].as_ref().unwrap().symbol);
+    assert!(results[1].is_none());
+    assert_eq!("SUI", results[2].as_ref().unwrap().symbol);
+
     Ok(())
 }
 
@@ -645,6 +905,75 @@ async fn test_get_transaction() -> Result<(), anyhow::Error> {
         ))
     }
 
+    // test get_transaction with raw effects
+    let digest = tx_responses[0].digest;
+    let response: SuiTransactionResponse = http_client
+        .get_transaction_with_options(
+            digest,
+            Some(SuiTransactionResponseOptions::new().with_raw_effects()),
+        )
+        .await?;
+    assert!(response.effects.is_none());
+    let effects: TransactionEffects = bcs::from_bytes(&response.raw_effects)?;
+    assert_eq!(effects.transaction_digest(), &digest);
+
+    // test get_transaction with input objects, read at the versions of the transaction
+    let response: SuiTransactionResponse = http_client
+        .get_transaction_with_options(
+            tx_responses[1].digest,
+            Some(SuiTransactionResponseOptions::new().with_input_objects()),
+        )
+        .await?;
+    let input_objects = response.input_objects.unwrap();
+    assert_eq!(2, input_objects.len());
+    let transferred = input_objects
+        .iter()
+        .find(|o| o.object_id == objects[1].object_id)
+        .unwrap();
+    assert_eq!(transferred.version, objects[1].version);
+    assert!(transferred.type_.is_some());
+    assert!(transferred.content.is_none());
+    let gas = input_objects
+        .iter()
+        .find(|o| o.object_id == gas_id)
+        .unwrap();
+    assert!(gas.version > objects.last().unwrap().version);
+    assert_eq!(gas.owner, Some(Owner::AddressOwner(*address)));
+
+    let response: SuiTransactionResponse = http_client
+        .get_transaction_with_options(
+            tx_responses[1].digest,
+            Some(SuiTransactionResponseOptions::new().with_input_objects_content()),
+        )
+        .await?;
+    assert!(response
+        .input_objects
+        .unwrap()
+        .iter()
+        .all(|o| o.content.is_some()));
+
+    Ok(())
+}
+
+#[sim_test]
+async fn test_rpc_error_data() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let digest = TransactionDigest::random();
+    let error = http_client
+        .get_transaction_with_options(
+            digest,
+            Some(SuiTransactionResponseOptions::new().with_input()),
+        )
+        .await
+        .unwrap_err();
+    let jsonrpsee::core::Error::Call(CallError::Custom(error)) = error else {
+        panic!("Unexpected error {error:?}")
+    };
+    assert_eq!(error.code(), TRANSACTION_NOT_FOUND_CODE);
+    let data: SuiRpcErrorData = serde_json::from_str(error.data().unwrap().get())?;
+    assert_eq!(data, SuiRpcErrorData::TransactionNotFound { digest });
     Ok(())
 }
 
@@ -727,7 +1056,7 @@ async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
         .unwrap();
     assert_eq!(10, latest.data.len());
 
-    assert_eq!(Some(all_txs_rev[10]), latest.next_cursor);
+    assert_eq!(Some(*all_txs_rev[10].digest()), latest.next_cursor);
     assert_eq!(all_txs_rev[0..10], latest.data);
 
     // test get from address txs in ascending order
@@ -770,10 +1099,10 @@ async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
     assert_eq!(20, tx.data.len());
 
     // test get_transaction
-    for tx_digest in tx.data {
+    for tx_digest in &tx.data {
         let response: SuiTransactionResponse = client
             .read_api()
-            .get_transaction_with_options(tx_digest, SuiTransactionResponseOptions::new())
+            .get_transaction_with_options(*tx_digest.digest(), SuiTransactionResponseOptions::new())
             .await
             .unwrap();
         assert!(tx_responses
@@ -781,6 +1110,31 @@ async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
             .any(|resp| resp.digest == response.digest))
     }
 
+    // test get transaction summaries, which list the same transactions
+    let summaries = client
+        .read_api()
+        .get_transaction_summaries(TransactionQuery::All, None, Some(20), true)
+        .await
+        .unwrap();
+    assert_eq!(tx.next_cursor, summaries.next_cursor);
+    for (summary, item) in summaries.data.iter().zip(&tx.data) {
+        assert_eq!(&summary.digest, item.digest());
+        let response = client
+            .read_api()
+            .get_transaction_with_options(
+                summary.digest,
+                SuiTransactionResponseOptions::new()
+                    .with_input()
+                    .with_effects(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(summary.checkpoint, response.checkpoint);
+        assert_eq!(summary.timestamp_ms, response.timestamp_ms);
+        assert_eq!(&summary.status, response.effects.unwrap().status());
+        assert_eq!(&summary.sender, response.transaction.unwrap().data.sender());
+    }
+
     Ok(())
 }
 
@@ -922,6 +1276,75 @@ async fn test_get_fullnode_events() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_get_events_by_checkpoint() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    // The genesis checkpoint contains the events of the genesis transaction.
+    let all_events = http_client.get_events_by_checkpoint(0, None, None).await?;
+    assert!(all_events.data.len() > 3);
+    assert_eq!(None, all_events.next_cursor);
+    assert!(all_events.data.iter().all(|e| e.checkpoint == Some(0)));
+
+    let mut events = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_events_by_checkpoint(0, cursor, Some(3))
+            .await?;
+        assert!(page.data.len() <= 3);
+        events.extend(page.data);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(all_events.data, events);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_transaction_events() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    // The genesis transaction emits more events than fit in one page.
+    let checkpoint_events = http_client.get_events_by_checkpoint(0, None, None).await?;
+    let digest = checkpoint_events.data[0].tx_digest;
+    let all_events = http_client
+        .get_transaction_events(digest, None, None)
+        .await?;
+    assert!(all_events.data.len() > 3);
+    assert!(all_events
+        .data
+        .iter()
+        .all(|e| e.tx_digest == digest && e.checkpoint == Some(0)));
+
+    let mut events = vec![];
+    let mut cursor = None;
+    loop {
+        let page = http_client
+            .get_transaction_events(digest, cursor, Some(3))
+            .await?;
+        assert!(page.data.len() <= 3);
+        events.extend(page.data);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(all_events.data, events);
+
+    // Cursors of other transactions are rejected.
+    let foreign_cursor = EventID::from((TransactionDigest::random(), 0));
+    assert!(http_client
+        .get_transaction_events(digest, Some(foreign_cursor), None)
+        .await
+        .is_err());
+    Ok(())
+}
+
 #[sim_test]
 async fn test_locked_sui() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
@@ -1209,3 +1632,207 @@ async fn test_delegation_with_locked_sui() -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[sim_test]
+async fn test_get_committee_info() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let committee = http_client.get_committee_info(None).await?;
+    let system_state = http_client.get_latest_sui_system_state().await?;
+    assert_eq!(committee.epoch, system_state.epoch);
+    // Every committee member is in the validator set of the current epoch.
+    assert_eq!(committee.validator_info.len(), committee.validators.len());
+    for (info, (name, stake)) in committee.validator_info.iter().zip(&committee.validators) {
+        assert_eq!(info.authority_name, *name);
+        assert_eq!(info.stake, *stake);
+        let validator = system_state
+            .active_validators
+            .iter()
+            .find(|v| v.sui_address == info.sui_address)
+            .unwrap();
+        assert_eq!(info.name, validator.name);
+        assert_eq!(info.commission_rate, validator.commission_rate);
+        assert!(!info.net_address.is_empty());
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_committees() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new()
+        .with_epoch_duration_ms(1000)
+        .build()
+        .await?;
+    let http_client = cluster.rpc_client();
+
+    // Wait for a few epochs to be committed.
+    for _ in 0..60 {
+        if http_client.get_committee_info(None).await?.epoch >= 2 {
+            break;
+        }
+        sleep(Duration::from_secs(1)).await;
+    }
+
+    let first_page = http_client.get_committees(None, Some(1)).await?;
+    assert_eq!(first_page.data.len(), 1);
+    assert_eq!(first_page.data[0].epoch, 0);
+    assert_eq!(first_page.next_cursor, Some(1));
+
+    let second_page = http_client
+        .get_committees(first_page.next_cursor, None)
+        .await?;
+    assert!(second_page.data.len() >= 2);
+    assert!(second_page.next_cursor.is_none());
+    for (epoch, committee) in (1..).zip(&second_page.data) {
+        assert_eq!(committee.epoch, epoch);
+        assert_eq!(committee.validator_info.len(), committee.validators.len());
+    }
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_checkpoint_attestation() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let attestation = http_client
+        .get_checkpoint_attestation(CheckpointId::SequenceNumber(0))
+        .await?;
+    let checkpoint = http_client
+        .get_checkpoint(CheckpointId::SequenceNumber(0))
+        .await?;
+    assert_eq!(attestation.checkpoint.digest, checkpoint.digest);
+    assert_eq!(attestation.committee.epoch, checkpoint.epoch);
+
+    // The archived bytes verify on their own.
+    let archived = attestation.attestation()?;
+    archived.verify()?;
+    assert_eq!(*archived.summary.digest(), checkpoint.digest);
+
+    // The attestation can be fetched by digest too.
+    let by_digest = http_client
+        .get_checkpoint_attestation(CheckpointId::Digest(checkpoint.digest))
+        .await?;
+    assert_eq!(by_digest.attestation_bcs, attestation.attestation_bcs);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_gas_cost_table() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let table = http_client.get_gas_cost_table(None).await?;
+    let system_state = http_client.get_latest_sui_system_state().await?;
+    assert_eq!(
+        table.protocol_version.as_u64(),
+        system_state.protocol_version
+    );
+    assert!(table
+        .instructions
+        .iter()
+        .any(|cost| cost.instruction == "MoveLoc"));
+    assert!(table
+        .native_function_costs
+        .contains_key("event_emit_cost_per_byte"));
+    assert!(table.transaction_costs.contains_key("base_tx_cost_fixed"));
+
+    // The table of the current version can be asked explicitly, unknown versions are rejected.
+    let explicit = http_client
+        .get_gas_cost_table(Some(table.protocol_version))
+        .await?;
+    assert_eq!(explicit, table);
+    assert!(http_client
+        .get_gas_cost_table(Some(ProtocolVersion::new(u64::MAX)))
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_validator_reports() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    // No validator reported another one since genesis.
+    let reports = http_client.get_validator_reports().await?;
+    let system_state = http_client.get_latest_sui_system_state().await?;
+    assert_eq!(reports.epoch, system_state.epoch);
+    assert!(reports.reports.is_empty());
+    assert!(reports.quorum_threshold > 0);
+    assert!(reports.reward_slashing_rate <= 10_000);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_get_node_info() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+
+    let info = http_client.get_node_info().await?;
+    let system_state = http_client.get_latest_sui_system_state().await?;
+    assert_eq!(info.epoch, system_state.epoch);
+    assert_eq!(info.protocol_version, system_state.protocol_version);
+    assert_eq!(8, info.chain_identifier.len());
+    assert!(info.lowest_available_checkpoint <= info.latest_checkpoint);
+    let latest = http_client.get_latest_checkpoint_sequence_number().await?;
+    assert!(info.latest_checkpoint <= latest);
+    // Served by the full node, not by an indexer.
+    assert_eq!(info.indexer_lag, None);
+    Ok(())
+}
+
+#[sim_test]
+async fn test_set_transaction_expiration() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let transaction_bytes: TransactionBytes = http_client
+        .transfer_object(
+            *address,
+            objects.first().unwrap().object_id,
+            Some(objects.last().unwrap().object_id),
+            1000,
+            *address,
+        )
+        .await?;
+    let epoch = http_client.get_latest_sui_system_state().await?.epoch;
+
+    // Expiring before the current epoch, the transaction could never be signed.
+    if epoch > 0 {
+        let result = http_client
+            .set_transaction_expiration(transaction_bytes.tx_bytes.clone(), epoch - 1)
+            .await;
+        assert!(result.is_err());
+    }
+
+    let transaction_bytes = http_client
+        .set_transaction_expiration(transaction_bytes.tx_bytes, epoch)
+        .await?;
+    let data = transaction_bytes.to_data()?;
+    assert_eq!(data.expiration(), &TransactionExpiration::Epoch(epoch));
+
+    // Validators sign the transaction within its expiration epoch.
+    let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+    let tx = to_sender_signed_transaction(data, keystore.get_key(address)?);
+    let (tx_bytes, signatures) = tx.to_tx_bytes_and_signatures();
+    let tx_response = http_client
+        .submit_transaction(
+            tx_bytes,
+            signatures,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+        .await?;
+    let response = http_client
+        .get_transaction_with_options(
+            tx_response.digest,
+            Some(SuiTransactionResponseOptions::new().with_input()),
+        )
+        .await?;
+    assert_eq!(response.transaction.unwrap().data.expiration(), Some(epoch));
+    Ok(())
+}
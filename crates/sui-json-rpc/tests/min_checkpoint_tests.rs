@@ -0,0 +1,81 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::core::__reexports::serde_json::json;
+use prometheus::Registry;
+use reqwest::{Client, StatusCode};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use sui_config::utils::get_available_port;
+use sui_json_rpc::{JsonRpcServerBuilder, RequestScope, MIN_CHECKPOINT_HEADER};
+
+struct TestScope {
+    latest: Arc<AtomicU64>,
+}
+
+impl RequestScope for TestScope {
+    fn latest_checkpoint(&self) -> Option<u64> {
+        Some(self.latest.load(Ordering::SeqCst))
+    }
+}
+
+#[tokio::test]
+async fn test_min_checkpoint() {
+    let latest = Arc::new(AtomicU64::new(3));
+    let mut builder = JsonRpcServerBuilder::new("1.0", &Registry::new());
+    builder.set_request_scope(Arc::new(TestScope {
+        latest: latest.clone(),
+    }));
+    let port = get_available_port("0.0.0.0");
+    let handle = builder
+        .start(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, port)))
+        .await
+        .unwrap();
+    let url = format!("http://127.0.0.1:{}/", port);
+    let client = Client::new();
+    let request = || {
+        client
+            .post(&url)
+            .json(&json!({"jsonrpc": "2.0", "id": 1, "method": "rpc.discover", "params": []}))
+    };
+
+    // Requests without a min checkpoint, or with one already reached, are not held.
+    let response = request().send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = request()
+        .header(MIN_CHECKPOINT_HEADER, "3")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A request is held until its min checkpoint is reached.
+    let advance = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        latest.store(5, Ordering::SeqCst);
+    });
+    let response = request()
+        .header(MIN_CHECKPOINT_HEADER, "5")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    advance.await.unwrap();
+
+    // And rejected if it isn't reached in time.
+    let response = request()
+        .header(MIN_CHECKPOINT_HEADER, "100")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response
+        .text()
+        .await
+        .unwrap()
+        .contains("latest checkpoint is 5"));
+
+    handle.stop().unwrap()
+}
@@ -0,0 +1,64 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::core::__reexports::serde_json::json;
+use prometheus::Registry;
+use reqwest::Client;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use sui_config::utils::get_available_port;
+use sui_json_rpc::{JsonRpcServerBuilder, REQUEST_ID_HEADER};
+
+#[tokio::test]
+async fn test_request_id_echoed() {
+    let builder = JsonRpcServerBuilder::new("1.0", &Registry::new());
+    let port = get_available_port("0.0.0.0");
+    let handle = builder
+        .start(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, port)))
+        .await
+        .unwrap();
+    let url = format!("http://127.0.0.1:{}/", port);
+    let client = Client::new();
+    let request = |method: &str| {
+        client
+            .post(&url)
+            .json(&json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": []}))
+    };
+
+    // The ID set by the client is echoed.
+    let response = request("rpc.discover")
+        .header(REQUEST_ID_HEADER, "client-request-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get(REQUEST_ID_HEADER).unwrap(),
+        "client-request-1"
+    );
+
+    // An ID is generated when the client does not set one, or sets an invalid one.
+    let response = request("rpc.discover").send().await.unwrap();
+    let generated = response.headers().get(REQUEST_ID_HEADER).unwrap().clone();
+    assert_eq!(generated.len(), 32);
+    let response = request("rpc.discover")
+        .header(REQUEST_ID_HEADER, "a".repeat(1000))
+        .send()
+        .await
+        .unwrap();
+    let replaced = response.headers().get(REQUEST_ID_HEADER).unwrap();
+    assert_eq!(replaced.len(), 32);
+    assert_ne!(replaced, generated);
+
+    // Error responses carry the ID too.
+    let response = request("test_unknownMethod")
+        .header(REQUEST_ID_HEADER, "client-request-2")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get(REQUEST_ID_HEADER).unwrap(),
+        "client-request-2"
+    );
+    assert!(response.text().await.unwrap().contains("error"));
+
+    handle.stop().unwrap()
+}
@@ -5,11 +5,12 @@ use anyhow::{anyhow, bail};
 use fastcrypto::encoding::{Encoding, Hex};
 use move_binary_format::{
     access::ModuleAccess, binary_views::BinaryIndexedView, file_format::SignatureToken,
+    CompiledModule,
 };
 use move_core_types::account_address::AccountAddress;
 use move_core_types::u256::U256;
 use move_core_types::{
-    identifier::Identifier,
+    identifier::{IdentStr, Identifier},
     language_storage::{StructTag, TypeTag},
     value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue},
 };
@@ -20,6 +21,9 @@ use std::collections::VecDeque;
 use std::fmt::{self, Debug, Formatter};
 use std::str::FromStr;
 use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::dynamic_field::{
+    derive_dynamic_field_id_from_bcs, DynamicFieldName, DynamicFieldType,
+};
 use sui_types::messages::{CallArg, ObjectArg};
 use sui_types::move_package::MovePackage;
 use sui_verifier::entry_points_verifier::{
@@ -122,6 +126,17 @@ impl SuiJsonValue {
         SuiJsonValue::new(try_from_bcs_bytes(bytes)?)
     }
 
+    /// Decode the BCS bytes of a pure argument with the layout of its type, as returned by
+    /// [move_function_pure_arg_layouts]. Integers up to u64 are decoded as numbers, larger ones as
+    /// decimal strings, addresses and IDs as hex strings and strings as strings.
+    pub fn from_bcs_bytes_with_layout(
+        bytes: &[u8],
+        layout: &MoveTypeLayout,
+    ) -> Result<Self, anyhow::Error> {
+        let move_value = MoveValue::simple_deserialize(bytes, layout)?;
+        SuiJsonValue::new(move_value_to_json(move_value)?)
+    }
+
     pub fn to_json_value(&self) -> JsonValue {
         self.0.clone()
     }
@@ -162,6 +177,10 @@ impl SuiJsonValue {
                              with one field of address or u8 vector type"
                 ),
             },
+            // IDs
+            MoveTypeLayout::Address => Ok(MoveValue::Struct(MoveStruct::Runtime(vec![
+                Self::to_move_value(val, &MoveTypeLayout::Address)?,
+            ]))),
             _ => bail!(
                 "Cannot convert string arg {s} to {ty} which is expected \
                  to be a struct with one field of a vector type"
@@ -711,6 +730,385 @@ pub fn resolve_move_function_args(
     resolve_call_args(&view, type_args, &combined_args_json, parameters)
 }
 
+/// Layouts to decode the BCS bytes of the arguments of `function` in `module`, `None` for the
+/// parameters that don't take pure arguments (objects and TxContext).
+pub fn move_function_pure_arg_layouts(
+    module: &CompiledModule,
+    function: &IdentStr,
+    type_args: &[TypeTag],
+) -> Result<Vec<Option<MoveTypeLayout>>, anyhow::Error> {
+    let parameters = function_parameters(module, function)?;
+    let view = BinaryIndexedView::Module(module);
+    Ok(parameters
+        .iter()
+        .map(|param| pure_arg_layout(&view, type_args, param))
+        .collect())
+}
+
+fn function_parameters<'a>(
+    module: &'a CompiledModule,
+    function: &IdentStr,
+) -> Result<&'a [SignatureToken], anyhow::Error> {
+    let fdef = module
+        .function_defs
+        .iter()
+        .find(|fdef| {
+            module.identifier_at(module.function_handle_at(fdef.function).name) == function
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not resolve function {} in module {}",
+                function,
+                module.self_id()
+            )
+        })?;
+    let function_signature = module.function_handle_at(fdef.function);
+    Ok(&module.signature_at(function_signature.parameters).0)
+}
+
+/// Coerce the string arguments of a CLI call of `function` in `module` into JSON values, guided by
+/// the parameter types of the function. Integers can be decimal (with `_` separators) or `0x` hex,
+/// addresses and object IDs must be `0x` hex, `vector<u8>` can be `0x` hex, a JSON array or a utf8
+/// string, and other vectors are JSON arrays. Errors name the argument and its expected Move type.
+pub fn coerce_move_function_args(
+    module: &CompiledModule,
+    function: &IdentStr,
+    type_args: &[TypeTag],
+    args: &[String],
+) -> Result<Vec<SuiJsonValue>, anyhow::Error> {
+    let parameters = function_parameters(module, function)?;
+    let view = BinaryIndexedView::Module(module);
+
+    // The TxContext is not passed by the caller
+    let parameters = match parameters.split_last() {
+        Some((last, rest)) if is_tx_context(&view, last) != TxContextKind::None => rest,
+        _ => parameters,
+    };
+    if args.len() != parameters.len() {
+        bail!(
+            "Expected {} args for {}::{}, found {}",
+            parameters.len(),
+            module.self_id(),
+            function,
+            args.len()
+        );
+    }
+
+    args.iter()
+        .zip(parameters)
+        .enumerate()
+        .map(|(idx, (arg, param))| {
+            coerce_arg(&view, type_args, idx, arg, param).map_err(|e| {
+                anyhow!(
+                    "Invalid argument {idx} `{arg}`, expected {}: {e}",
+                    signature_token_name(&view, type_args, param)
+                )
+            })
+        })
+        .collect()
+}
+
+fn coerce_arg(
+    view: &BinaryIndexedView,
+    type_args: &[TypeTag],
+    idx: usize,
+    arg: &str,
+    param: &SignatureToken,
+) -> Result<SuiJsonValue, anyhow::Error> {
+    let layout = match primitive_type(view, type_args, param) {
+        (_, Some(layout)) => layout,
+        (true, None) => pure_arg_layout(view, type_args, param)
+            .ok_or_else(|| anyhow!("Unsupported argument type"))?,
+        (false, None) => {
+            return match param {
+                SignatureToken::Vector(_) => {
+                    let ids = resolve_object_vec_arg(idx, &SuiJsonValue::from_str(arg)?)?;
+                    SuiJsonValue::new(JsonValue::Array(
+                        ids.into_iter()
+                            .map(|id| JsonValue::String(id.to_hex_literal()))
+                            .collect(),
+                    ))
+                }
+                _ => Ok(SuiJsonValue::from_object_id(resolve_object_arg(
+                    idx,
+                    &JsonValue::String(arg.to_string()),
+                )?)),
+            };
+        }
+    };
+    let value = SuiJsonValue::new(coerce_str(arg, &layout)?)?;
+    // Make sure the value can be used as the argument of the call
+    value.to_bcs_bytes(&layout)?;
+    Ok(value)
+}
+
+fn coerce_str(arg: &str, layout: &MoveTypeLayout) -> Result<JsonValue, anyhow::Error> {
+    let arg = arg.trim();
+    Ok(match layout {
+        MoveTypeLayout::Bool => JsonValue::Bool(arg.parse()?),
+        MoveTypeLayout::U8
+        | MoveTypeLayout::U16
+        | MoveTypeLayout::U32
+        | MoveTypeLayout::U64
+        | MoveTypeLayout::U128
+        | MoveTypeLayout::U256 => {
+            // Integers are passed as strings, which are accepted for all widths
+            JsonValue::String(convert_string_to_u256(&arg.replace('_', ""))?.to_string())
+        }
+        MoveTypeLayout::Address | MoveTypeLayout::Signer => {
+            let value = JsonValue::String(arg.to_string());
+            json_value_to_sui_address(&value)?;
+            value
+        }
+        MoveTypeLayout::Vector(inner) => match &**inner {
+            MoveTypeLayout::U8 if arg.starts_with(HEX_PREFIX) => {
+                Hex::decode(arg).map_err(|e| anyhow!(e))?;
+                JsonValue::String(arg.to_string())
+            }
+            MoveTypeLayout::U8 if !arg.starts_with('[') => JsonValue::String(arg.to_string()),
+            inner => match serde_json::from_str(arg)? {
+                JsonValue::Array(values) => JsonValue::Array(
+                    values
+                        .iter()
+                        .map(|value| match value {
+                            JsonValue::String(s) => coerce_str(s, inner),
+                            value => coerce_str(&value.to_string(), inner),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                value => bail!("Expected a JSON array, found {value}"),
+            },
+        },
+        // Strings and IDs, see [primitive_type]
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(fields)) => match fields.as_slice() {
+            [MoveTypeLayout::Vector(inner)] if matches!(**inner, MoveTypeLayout::Address) => {
+                coerce_str(arg, inner)?
+            }
+            _ => JsonValue::String(arg.to_string()),
+        },
+        MoveTypeLayout::Struct(_) => bail!("Unexpected struct layout {layout}"),
+    })
+}
+
+fn signature_token_name(
+    view: &BinaryIndexedView,
+    type_args: &[TypeTag],
+    param: &SignatureToken,
+) -> String {
+    match param {
+        SignatureToken::Bool => "bool".to_string(),
+        SignatureToken::U8 => "u8".to_string(),
+        SignatureToken::U16 => "u16".to_string(),
+        SignatureToken::U32 => "u32".to_string(),
+        SignatureToken::U64 => "u64".to_string(),
+        SignatureToken::U128 => "u128".to_string(),
+        SignatureToken::U256 => "u256".to_string(),
+        SignatureToken::Address => "address".to_string(),
+        SignatureToken::Signer => "signer".to_string(),
+        SignatureToken::Vector(inner) => {
+            format!("vector<{}>", signature_token_name(view, type_args, inner))
+        }
+        SignatureToken::Struct(idx) => {
+            let (address, module, name) = sui_verifier::resolve_struct(view, *idx);
+            format!("0x{}::{module}::{name}", address.short_str_lossless())
+        }
+        SignatureToken::StructInstantiation(idx, targs) => {
+            let (address, module, name) = sui_verifier::resolve_struct(view, *idx);
+            let targs = targs
+                .iter()
+                .map(|targ| signature_token_name(view, type_args, targ))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "0x{}::{module}::{name}<{targs}>",
+                address.short_str_lossless()
+            )
+        }
+        SignatureToken::Reference(inner) => {
+            format!("&{}", signature_token_name(view, type_args, inner))
+        }
+        SignatureToken::MutableReference(inner) => {
+            format!("&mut {}", signature_token_name(view, type_args, inner))
+        }
+        SignatureToken::TypeParameter(idx) => type_args
+            .get(*idx as usize)
+            .map(|tag| tag.to_string())
+            .unwrap_or_else(|| format!("T{idx}")),
+    }
+}
+
+/// Same as [primitive_type], but with the layouts of the BCS encoding of the values, which differ
+/// for IDs.
+fn pure_arg_layout(
+    view: &BinaryIndexedView,
+    type_args: &[TypeTag],
+    param: &SignatureToken,
+) -> Option<MoveTypeLayout> {
+    match param {
+        SignatureToken::Bool => Some(MoveTypeLayout::Bool),
+        SignatureToken::U8 => Some(MoveTypeLayout::U8),
+        SignatureToken::U16 => Some(MoveTypeLayout::U16),
+        SignatureToken::U32 => Some(MoveTypeLayout::U32),
+        SignatureToken::U64 => Some(MoveTypeLayout::U64),
+        SignatureToken::U128 => Some(MoveTypeLayout::U128),
+        SignatureToken::U256 => Some(MoveTypeLayout::U256),
+        SignatureToken::Address => Some(MoveTypeLayout::Address),
+        SignatureToken::Vector(inner) => pure_arg_layout(view, type_args, inner)
+            .map(|inner| MoveTypeLayout::Vector(Box::new(inner))),
+        SignatureToken::Struct(struct_handle_idx) => {
+            let resolved_struct = sui_verifier::resolve_struct(view, *struct_handle_idx);
+            if resolved_struct == RESOLVED_ASCII_STR || resolved_struct == RESOLVED_UTF8_STR {
+                Some(MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+                    MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+                ])))
+            } else if resolved_struct == RESOLVED_SUI_ID {
+                Some(MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+                    MoveTypeLayout::Address,
+                ])))
+            } else {
+                None
+            }
+        }
+        SignatureToken::StructInstantiation(idx, targs) => {
+            let resolved_struct = sui_verifier::resolve_struct(view, *idx);
+            // Options are encoded as vectors of zero or one element
+            if resolved_struct == RESOLVED_STD_OPTION && targs.len() == 1 {
+                pure_arg_layout(view, type_args, &targs[0])
+                    .map(|inner| MoveTypeLayout::Vector(Box::new(inner)))
+            } else {
+                None
+            }
+        }
+        SignatureToken::TypeParameter(idx) => {
+            type_args.get(*idx as usize).and_then(type_tag_layout)
+        }
+        SignatureToken::Signer
+        | SignatureToken::Reference(_)
+        | SignatureToken::MutableReference(_) => None,
+    }
+}
+
+fn type_tag_layout(tag: &TypeTag) -> Option<MoveTypeLayout> {
+    match tag {
+        TypeTag::Bool => Some(MoveTypeLayout::Bool),
+        TypeTag::U8 => Some(MoveTypeLayout::U8),
+        TypeTag::U16 => Some(MoveTypeLayout::U16),
+        TypeTag::U32 => Some(MoveTypeLayout::U32),
+        TypeTag::U64 => Some(MoveTypeLayout::U64),
+        TypeTag::U128 => Some(MoveTypeLayout::U128),
+        TypeTag::U256 => Some(MoveTypeLayout::U256),
+        TypeTag::Address => Some(MoveTypeLayout::Address),
+        TypeTag::Vector(inner) => {
+            type_tag_layout(inner).map(|inner| MoveTypeLayout::Vector(Box::new(inner)))
+        }
+        TypeTag::Struct(st) => {
+            let resolved_struct = (
+                &st.address,
+                st.module.as_ident_str(),
+                st.name.as_ident_str(),
+            );
+            if resolved_struct == RESOLVED_ASCII_STR || resolved_struct == RESOLVED_UTF8_STR {
+                Some(MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+                    MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+                ])))
+            } else if resolved_struct == RESOLVED_SUI_ID {
+                Some(MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+                    MoveTypeLayout::Address,
+                ])))
+            } else {
+                None
+            }
+        }
+        TypeTag::Signer => None,
+    }
+}
+
+/// Encode the value of `name` with BCS, as the key of the dynamic field the ID of the field is
+/// derived from. Names of primitive types, strings and IDs are supported.
+pub fn dynamic_field_name_to_bcs(name: &DynamicFieldName) -> Result<Vec<u8>, anyhow::Error> {
+    let layout = type_tag_layout(&name.type_)
+        .ok_or_else(|| anyhow!("Unsupported dynamic field name type {}", name.type_))?;
+    SuiJsonValue::new(name.value.clone())?.to_bcs_bytes(&layout)
+}
+
+/// Decode the BCS bytes of a dynamic field name of type `type_`, rendering the value the way the
+/// full node indexes it: integers larger than u32 as decimal strings, addresses and IDs as hex
+/// strings and strings as strings.
+pub fn dynamic_field_name_from_bcs(
+    type_: TypeTag,
+    bytes: &[u8],
+) -> Result<DynamicFieldName, anyhow::Error> {
+    let layout = type_tag_layout(&type_)
+        .ok_or_else(|| anyhow!("Unsupported dynamic field name type {type_}"))?;
+    let value = dynamic_field_name_value_to_json(MoveValue::simple_deserialize(bytes, &layout)?)?;
+    Ok(DynamicFieldName { type_, value })
+}
+
+/// The ID of the `sui::dynamic_field::Field` object holding the dynamic field of `parent` named
+/// `name`, derived the way the framework does, so that the field can be read without listing the
+/// dynamic fields of `parent`. For dynamic object fields this is the ID of the field wrapping the
+/// object, not of the object itself.
+pub fn derive_dynamic_field_id_from_name(
+    parent: ObjectID,
+    type_: &DynamicFieldType,
+    name: &DynamicFieldName,
+) -> Result<ObjectID, anyhow::Error> {
+    let key_bytes = dynamic_field_name_to_bcs(name)?;
+    derive_dynamic_field_id_from_bcs(parent, &type_.key_type_tag(&name.type_), &key_bytes)
+        .ok_or_else(|| anyhow!("Cannot derive the ID of dynamic field {name} of {parent}"))
+}
+
+fn dynamic_field_name_value_to_json(value: MoveValue) -> Result<JsonValue, anyhow::Error> {
+    Ok(match value {
+        MoveValue::U64(n) => JsonValue::String(n.to_string()),
+        MoveValue::Vector(values) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(dynamic_field_name_value_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        value => move_value_to_json(value)?,
+    })
+}
+
+fn move_value_to_json(value: MoveValue) -> Result<JsonValue, anyhow::Error> {
+    Ok(match value {
+        MoveValue::Bool(b) => JsonValue::Bool(b),
+        MoveValue::U8(n) => JsonValue::Number(Number::from(n)),
+        MoveValue::U16(n) => JsonValue::Number(Number::from(n)),
+        MoveValue::U32(n) => JsonValue::Number(Number::from(n)),
+        MoveValue::U64(n) => JsonValue::Number(Number::from(n)),
+        // Larger integers don't fit in JSON numbers
+        MoveValue::U128(n) => JsonValue::String(n.to_string()),
+        MoveValue::U256(n) => JsonValue::String(n.to_string()),
+        MoveValue::Address(a) | MoveValue::Signer(a) => JsonValue::String(format!("{a:#x}")),
+        MoveValue::Vector(values) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(move_value_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        // The only structs in pure arguments are strings and IDs
+        MoveValue::Struct(MoveStruct::Runtime(mut fields)) if fields.len() == 1 => {
+            match fields.pop().unwrap() {
+                MoveValue::Address(a) => JsonValue::String(format!("{a:#x}")),
+                MoveValue::Vector(bytes) => {
+                    let bytes = bytes
+                        .into_iter()
+                        .map(|b| match b {
+                            MoveValue::U8(b) => Ok(b),
+                            _ => Err(anyhow!("Expected string bytes, found {b:?}")),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    JsonValue::String(String::from_utf8(bytes)?)
+                }
+                field => bail!("Unexpected struct field {field:?} in pure argument"),
+            }
+        }
+        MoveValue::Struct(s) => bail!("Unexpected struct {s:?} in pure argument"),
+    })
+}
+
 fn convert_string_to_u256(s: &str) -> Result<U256, anyhow::Error> {
     // Try as normal number
     if let Ok(v) = s.parse::<U256>() {
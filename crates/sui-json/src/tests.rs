@@ -7,17 +7,27 @@ use std::str::FromStr;
 use fastcrypto::encoding::{Encoding, Hex};
 use move_core_types::u256::U256;
 use move_core_types::{
-    account_address::AccountAddress, identifier::Identifier, value::MoveTypeLayout,
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::TypeTag,
+    value::{MoveStructLayout, MoveTypeLayout},
 };
 use serde_json::{json, Value};
 use sui_framework_build::compiled_package::BuildConfig;
 use test_fuzz::runtime::num_traits::ToPrimitive;
 
 use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
+use sui_types::dynamic_field::{
+    derive_dynamic_field_id_from_bcs, DynamicFieldName, DynamicFieldType,
+};
 use sui_types::object::Object;
 
 use super::{check_valid_homogeneous, HEX_PREFIX};
-use super::{resolve_move_function_args, SuiJsonCallArg, SuiJsonValue};
+use super::{
+    coerce_move_function_args, derive_dynamic_field_id_from_name, dynamic_field_name_from_bcs,
+    dynamic_field_name_to_bcs, move_function_pure_arg_layouts, resolve_move_function_args,
+    SuiJsonCallArg, SuiJsonValue,
+};
 
 // Negative test cases
 #[test]
@@ -760,3 +770,236 @@ fn test_from_str() {
     assert!(test.0.is_string());
     assert_eq!("Some string", test.0.as_str().unwrap())
 }
+
+#[test]
+fn test_pure_args_from_bcs_bytes_with_layout() {
+    let path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../sui_programmability/examples/nfts");
+    let compiled_modules = BuildConfig::new_for_testing()
+        .build(path)
+        .unwrap()
+        .into_modules();
+    let example_package =
+        Object::new_package_for_testing(compiled_modules, TransactionDigest::genesis()).unwrap();
+    let example_package = example_package.data.try_as_package().unwrap();
+    let module = example_package
+        .deserialize_module(&Identifier::new("geniteam").unwrap())
+        .unwrap();
+    let function = Identifier::new("create_monster").unwrap();
+
+    let layouts = move_function_pure_arg_layouts(&module, &function, &[]).unwrap();
+    // Objects and TxContext don't take pure arguments
+    assert_eq!(
+        layouts,
+        vec![
+            None,
+            Some(MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))),
+            Some(MoveTypeLayout::U64),
+            Some(MoveTypeLayout::U8),
+            Some(MoveTypeLayout::U8),
+            Some(MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))),
+            Some(MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))),
+            None,
+        ]
+    );
+
+    let monster_img_index = SuiJsonValue::from_bcs_bytes_with_layout(
+        &bcs::to_bytes(&12345678u64).unwrap(),
+        layouts[2].as_ref().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(monster_img_index.to_json_value(), json!(12345678));
+
+    let monster_name = SuiJsonValue::from_bcs_bytes_with_layout(
+        &bcs::to_bytes(&b"Monster".to_vec()).unwrap(),
+        layouts[1].as_ref().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        monster_name.to_json_value(),
+        json!([77, 111, 110, 115, 116, 101, 114])
+    );
+
+    // Strings, IDs, options and large integers
+    let string_layout =
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![MoveTypeLayout::Vector(
+            Box::new(MoveTypeLayout::U8),
+        )]));
+    let value =
+        SuiJsonValue::from_bcs_bytes_with_layout(&bcs::to_bytes("Sui").unwrap(), &string_layout)
+            .unwrap();
+    assert_eq!(value.to_json_value(), json!("Sui"));
+
+    let id = ObjectID::random();
+    let id_layout =
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![MoveTypeLayout::Address]));
+    let value =
+        SuiJsonValue::from_bcs_bytes_with_layout(&bcs::to_bytes(&id).unwrap(), &id_layout).unwrap();
+    assert_eq!(
+        value.to_json_value(),
+        json!(format!("{:#x}", AccountAddress::from(id)))
+    );
+
+    let option_layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64));
+    let value = SuiJsonValue::from_bcs_bytes_with_layout(
+        &bcs::to_bytes(&Some(10u64)).unwrap(),
+        &option_layout,
+    )
+    .unwrap();
+    assert_eq!(value.to_json_value(), json!([10]));
+
+    let value = SuiJsonValue::from_bcs_bytes_with_layout(
+        &bcs::to_bytes(&u128::MAX).unwrap(),
+        &MoveTypeLayout::U128,
+    )
+    .unwrap();
+    assert_eq!(value.to_json_value(), json!(u128::MAX.to_string()));
+}
+
+#[test]
+fn test_coerce_move_function_args() {
+    let path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../sui_programmability/examples/nfts");
+    let compiled_modules = BuildConfig::new_for_testing()
+        .build(path)
+        .unwrap()
+        .into_modules();
+    let example_package =
+        Object::new_package_for_testing(compiled_modules, TransactionDigest::genesis()).unwrap();
+    let example_package = example_package.data.try_as_package().unwrap();
+    let module_name = Identifier::new("geniteam").unwrap();
+    let module = example_package.deserialize_module(&module_name).unwrap();
+    let function = Identifier::new("create_monster").unwrap();
+
+    let player_id = ObjectID::random();
+    let mut args = vec![
+        player_id.to_hex_literal(),
+        "MonsterName".to_string(),
+        "12_345_678".to_string(),
+        "0x59".to_string(),
+        "200".to_string(),
+        "0x4d6f6e73746572".to_string(),
+        "[68, 105]".to_string(),
+    ];
+    let values = coerce_move_function_args(&module, &function, &[], &args).unwrap();
+    assert_eq!(
+        values
+            .iter()
+            .map(|value| value.to_json_value())
+            .collect::<Vec<_>>(),
+        vec![
+            json!(player_id.to_hex_literal()),
+            json!("MonsterName"),
+            json!("12345678"),
+            json!("89"),
+            json!("200"),
+            json!("0x4d6f6e73746572"),
+            json!(["68", "105"]),
+        ]
+    );
+    // The coerced values resolve into call args
+    let json_args = resolve_move_function_args(
+        example_package,
+        module_name,
+        function.clone(),
+        &[],
+        values,
+        /* allow_arbitrary_function_call */ false,
+    )
+    .unwrap();
+    assert_eq!(json_args[0], SuiJsonCallArg::Object(player_id));
+    assert_eq!(
+        json_args[2],
+        SuiJsonCallArg::Pure(bcs::to_bytes(&12345678u64).unwrap())
+    );
+    assert_eq!(
+        json_args[6],
+        SuiJsonCallArg::Pure(bcs::to_bytes(&b"Di".to_vec()).unwrap())
+    );
+
+    // Errors name the argument and its expected type
+    args[3] = "256".to_string();
+    let err = coerce_move_function_args(&module, &function, &[], &args).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid argument 3 `256`, expected u8"));
+
+    args[3] = "89".to_string();
+    args[0] = "player".to_string();
+    let err = coerce_move_function_args(&module, &function, &[], &args).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid argument 0 `player`, expected &mut "));
+
+    args.pop();
+    let err = coerce_move_function_args(&module, &function, &[], &args).unwrap_err();
+    assert!(err.to_string().starts_with("Expected 7 args"));
+}
+
+#[test]
+fn test_dynamic_field_names() {
+    let parent = ObjectID::random();
+    let name = DynamicFieldName {
+        type_: TypeTag::U64,
+        value: json!("42"),
+    };
+    let bytes = dynamic_field_name_to_bcs(&name).unwrap();
+    assert_eq!(bytes, bcs::to_bytes(&42u64).unwrap());
+    assert_eq!(
+        dynamic_field_name_from_bcs(TypeTag::U64, &bytes)
+            .unwrap()
+            .value,
+        name.value
+    );
+
+    // The IDs of dynamic object fields are derived from the wrapped name
+    let field_id =
+        derive_dynamic_field_id_from_name(parent, &DynamicFieldType::DynamicField, &name).unwrap();
+    assert_eq!(
+        Some(field_id),
+        derive_dynamic_field_id_from_bcs(parent, &TypeTag::U64, &bytes)
+    );
+    let wrapper = TypeTag::from_str("0x2::dynamic_object_field::Wrapper<u64>").unwrap();
+    let object_field_id =
+        derive_dynamic_field_id_from_name(parent, &DynamicFieldType::DynamicObject, &name).unwrap();
+    assert_eq!(
+        Some(object_field_id),
+        derive_dynamic_field_id_from_bcs(parent, &wrapper, &bytes)
+    );
+    assert_ne!(field_id, object_field_id);
+
+    // Strings and IDs
+    let string_type = TypeTag::from_str("0x1::string::String").unwrap();
+    let name = DynamicFieldName {
+        type_: string_type.clone(),
+        value: json!("Sui"),
+    };
+    let bytes = dynamic_field_name_to_bcs(&name).unwrap();
+    assert_eq!(bytes, bcs::to_bytes("Sui").unwrap());
+    assert_eq!(
+        dynamic_field_name_from_bcs(string_type, &bytes)
+            .unwrap()
+            .value,
+        name.value
+    );
+
+    let id = ObjectID::random();
+    let id_type = TypeTag::from_str("0x2::object::ID").unwrap();
+    let name = DynamicFieldName {
+        type_: id_type.clone(),
+        value: json!(format!("{:#x}", AccountAddress::from(id))),
+    };
+    let bytes = dynamic_field_name_to_bcs(&name).unwrap();
+    assert_eq!(bytes, bcs::to_bytes(&id).unwrap());
+    assert_eq!(
+        dynamic_field_name_from_bcs(id_type, &bytes).unwrap().value,
+        name.value
+    );
+
+    // Names of other struct types are not supported
+    let name = DynamicFieldName {
+        type_: TypeTag::from_str("0x2::coin::Coin<0x2::sui::SUI>").unwrap(),
+        value: json!({}),
+    };
+    assert!(dynamic_field_name_to_bcs(&name).is_err());
+}
@@ -0,0 +1,130 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::anyhow;
+use argon2::{Algorithm, Argon2, Params, Version};
+use fastcrypto::encoding::{Base64, Encoding};
+use rand::{thread_rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable holding the passphrase of encrypted keystores, if not set the passphrase
+/// is prompted for.
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "SUI_KEYSTORE_PASSPHRASE";
+
+const ENCRYPTION_VERSION: u8 = 1;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+/// Data encrypted with AES-256-GCM, under a key derived from a passphrase with Argon2id.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedData {
+    pub version: u8,
+    pub kdf: Argon2Params,
+    /// Base64 encoded KDF salt.
+    pub salt: String,
+    /// Base64 encoded AES-GCM nonce.
+    pub nonce: String,
+    /// Base64 encoded ciphertext, including the authentication tag.
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Argon2Params {
+    /// Memory cost in KiB.
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self {
+            m_cost: 19 * 1024,
+            t_cost: 2,
+            p_cost: 1,
+        }
+    }
+}
+
+impl EncryptedData {
+    pub fn encrypt(passphrase: &str, plaintext: &[u8]) -> Result<Self, anyhow::Error> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        thread_rng().fill_bytes(&mut salt);
+        thread_rng().fill_bytes(&mut nonce);
+
+        let kdf = Argon2Params::default();
+        let cipher = cipher(passphrase, &salt, &kdf)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|e| anyhow!("Failed to encrypt data: {e}"))?;
+        Ok(Self {
+            version: ENCRYPTION_VERSION,
+            kdf,
+            salt: Base64::encode(salt),
+            nonce: Base64::encode(nonce),
+            ciphertext: Base64::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt the data, fails if the passphrase is wrong or the data was tampered with.
+    pub fn decrypt(&self, passphrase: &str) -> Result<Vec<u8>, anyhow::Error> {
+        if self.version != ENCRYPTION_VERSION {
+            return Err(anyhow!(
+                "Unsupported encryption version {}, expected {ENCRYPTION_VERSION}",
+                self.version
+            ));
+        }
+        let salt = Base64::decode(&self.salt).map_err(|e| anyhow!("Invalid salt: {e}"))?;
+        let nonce = Base64::decode(&self.nonce).map_err(|e| anyhow!("Invalid nonce: {e}"))?;
+        let ciphertext =
+            Base64::decode(&self.ciphertext).map_err(|e| anyhow!("Invalid ciphertext: {e}"))?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(anyhow!("Invalid nonce length {}", nonce.len()));
+        }
+        cipher(passphrase, &salt, &self.kdf)?
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| anyhow!("Failed to decrypt data, the passphrase is wrong"))
+    }
+}
+
+fn cipher(passphrase: &str, salt: &[u8], kdf: &Argon2Params) -> Result<Aes256Gcm, anyhow::Error> {
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(KEY_LENGTH))
+        .map_err(|e| anyhow!("Invalid KDF parameters: {e}"))?;
+    let mut key = [0u8; KEY_LENGTH];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Failed to derive key from passphrase: {e}"))?;
+    Aes256Gcm::new_from_slice(&key).map_err(|e| anyhow!("Invalid key: {e}"))
+}
+
+/// Read the keystore passphrase from [KEYSTORE_PASSPHRASE_ENV], or prompt for it.
+pub fn read_keystore_passphrase() -> Result<String, anyhow::Error> {
+    match std::env::var(KEYSTORE_PASSPHRASE_ENV) {
+        Ok(passphrase) => Ok(passphrase),
+        Err(_) => Ok(rpassword::prompt_password("Keystore passphrase: ")?),
+    }
+}
+
+/// Read a new keystore passphrase from [KEYSTORE_PASSPHRASE_ENV], or prompt for it twice.
+pub fn read_new_keystore_passphrase() -> Result<String, anyhow::Error> {
+    if let Ok(passphrase) = std::env::var(KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    prompt_new_passphrase()
+}
+
+/// Prompt for a new passphrase, twice to catch typos.
+pub fn prompt_new_passphrase() -> Result<String, anyhow::Error> {
+    let passphrase = rpassword::prompt_password("New keystore passphrase: ")?;
+    if passphrase.is_empty() {
+        return Err(anyhow!("The passphrase cannot be empty"));
+    }
+    if rpassword::prompt_password("Confirm keystore passphrase: ")? != passphrase {
+        return Err(anyhow!("The passphrases do not match"));
+    }
+    Ok(passphrase)
+}
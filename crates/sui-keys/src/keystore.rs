@@ -21,6 +21,7 @@ use sui_types::crypto::{
     SignatureScheme, Signer, SuiKeyPair,
 };
 
+use crate::encryption::{read_keystore_passphrase, EncryptedData};
 use crate::key_derive::{derive_key_pair_from_path, generate_new_key};
 
 #[derive(Serialize, Deserialize)]
@@ -28,6 +29,7 @@ use crate::key_derive::{derive_key_pair_from_path, generate_new_key};
 pub enum Keystore {
     File(FileBasedKeystore),
     InMem(InMemKeystore),
+    Encrypted(EncryptedFileBasedKeystore),
 }
 #[enum_dispatch]
 pub trait AccountKeystore: Send + Sync {
@@ -36,6 +38,17 @@ pub trait AccountKeystore: Send + Sync {
     fn add_key(&mut self, keypair: SuiKeyPair) -> Result<(), anyhow::Error>;
     fn keys(&self) -> Vec<PublicKey>;
     fn get_key(&self, address: &SuiAddress) -> Result<&SuiKeyPair, anyhow::Error>;
+    /// Remove the key of `address` and its alias from the keystore.
+    fn remove_key(&mut self, address: &SuiAddress) -> Result<SuiKeyPair, anyhow::Error>;
+
+    fn alias(&self, address: &SuiAddress) -> Option<&str>;
+    /// Set the alias of `address`, or remove it if `alias` is None. An alias names one address
+    /// only.
+    fn set_alias(
+        &mut self,
+        address: &SuiAddress,
+        alias: Option<String>,
+    ) -> Result<(), anyhow::Error>;
 
     fn sign_secure<T>(
         &self,
@@ -49,6 +62,40 @@ pub trait AccountKeystore: Send + Sync {
         self.keys().iter().map(|k| k.into()).collect()
     }
 
+    fn address_by_alias(&self, alias: &str) -> Result<SuiAddress, anyhow::Error> {
+        self.addresses()
+            .into_iter()
+            .find(|address| self.alias(address) == Some(alias))
+            .ok_or_else(|| anyhow!("Cannot find address with alias: [{alias}]"))
+    }
+
+    /// Generate a new key to replace the key of `address`, of the same scheme unless
+    /// `key_scheme` is given. The new key takes over the alias of the old one, which is kept in
+    /// the keystore unless `remove_old` is set, e.g. until its objects are transferred.
+    fn rotate_key(
+        &mut self,
+        address: &SuiAddress,
+        key_scheme: Option<SignatureScheme>,
+        derivation_path: Option<DerivationPath>,
+        remove_old: bool,
+    ) -> Result<(SuiAddress, String, SignatureScheme), anyhow::Error> {
+        let key_scheme = match key_scheme {
+            Some(key_scheme) => key_scheme,
+            None => self.get_key(address)?.public().scheme(),
+        };
+        let alias = self.alias(address).map(str::to_owned);
+        let (new_address, phrase, scheme) =
+            self.generate_and_add_new_key(key_scheme, derivation_path)?;
+        if alias.is_some() {
+            self.set_alias(address, None)?;
+            self.set_alias(&new_address, alias)?;
+        }
+        if remove_old {
+            self.remove_key(address)?;
+        }
+        Ok((new_address, phrase, scheme))
+    }
+
     fn generate_and_add_new_key(
         &mut self,
         key_scheme: SignatureScheme,
@@ -91,13 +138,21 @@ impl Display for Keystore {
                 writeln!(writer, "Keystore Type : InMem")?;
                 write!(f, "{}", writer)
             }
+            Keystore::Encrypted(file) => {
+                writeln!(writer, "Keystore Type : Encrypted")?;
+                write!(writer, "Keystore Path : {:?}", file.path)?;
+                write!(f, "{}", writer)
+            }
         }
     }
 }
 
+/// Keystore saving its keys in plaintext in a file, and their aliases in a sibling file with the
+/// `aliases` extension.
 #[derive(Default)]
 pub struct FileBasedKeystore {
     keys: BTreeMap<SuiAddress, SuiKeyPair>,
+    aliases: BTreeMap<SuiAddress, String>,
     path: Option<PathBuf>,
 }
 
@@ -173,6 +228,25 @@ impl AccountKeystore for FileBasedKeystore {
             None => Err(anyhow!("Cannot find key for address: [{address}]")),
         }
     }
+
+    fn remove_key(&mut self, address: &SuiAddress) -> Result<SuiKeyPair, anyhow::Error> {
+        let key = remove_key(&mut self.keys, &mut self.aliases, address)?;
+        self.save()?;
+        Ok(key)
+    }
+
+    fn alias(&self, address: &SuiAddress) -> Option<&str> {
+        self.aliases.get(address).map(String::as_str)
+    }
+
+    fn set_alias(
+        &mut self,
+        address: &SuiAddress,
+        alias: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        set_alias(&self.keys, &mut self.aliases, address, alias)?;
+        self.save()
+    }
 }
 
 impl FileBasedKeystore {
@@ -191,9 +265,18 @@ impl FileBasedKeystore {
         } else {
             BTreeMap::new()
         };
+        let aliases_path = aliases_path(path);
+        let aliases = if aliases_path.exists() {
+            let reader = BufReader::new(File::open(&aliases_path)?);
+            serde_json::from_reader(reader)
+                .map_err(|e| anyhow::anyhow!("Invalid aliases file {:#?} {:?}", e, aliases_path))?
+        } else {
+            BTreeMap::new()
+        };
 
         Ok(Self {
             keys,
+            aliases,
             path: Some(path.to_path_buf()),
         })
     }
@@ -212,7 +295,11 @@ impl FileBasedKeystore {
                     .collect::<Vec<_>>(),
             )
             .unwrap();
-            fs::write(path, store)?
+            fs::write(path, store)?;
+            let aliases_path = aliases_path(path);
+            if !self.aliases.is_empty() || aliases_path.exists() {
+                fs::write(aliases_path, serde_json::to_string_pretty(&self.aliases)?)?;
+            }
         }
         Ok(())
     }
@@ -220,11 +307,258 @@ impl FileBasedKeystore {
     pub fn key_pairs(&self) -> Vec<&SuiKeyPair> {
         self.keys.values().collect()
     }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Encrypt the keystore in place with `passphrase`, removing its plaintext keys and aliases
+    /// from disk.
+    pub fn encrypt(self, passphrase: String) -> Result<EncryptedFileBasedKeystore, anyhow::Error> {
+        let path = self
+            .path
+            .clone()
+            .ok_or_else(|| anyhow!("Cannot encrypt a keystore without a path"))?;
+        let keystore = EncryptedFileBasedKeystore::from_file_keystore(self, &path, passphrase)?;
+        let aliases_path = aliases_path(&path);
+        if aliases_path.exists() {
+            fs::remove_file(aliases_path)?;
+        }
+        Ok(keystore)
+    }
+}
+
+fn aliases_path(path: &Path) -> PathBuf {
+    path.with_extension("aliases")
+}
+
+/// Keystore saving its keys and their aliases in a file, encrypted with a passphrase. See
+/// [EncryptedData] for the encryption scheme.
+pub struct EncryptedFileBasedKeystore {
+    keys: BTreeMap<SuiAddress, SuiKeyPair>,
+    aliases: BTreeMap<SuiAddress, String>,
+    path: PathBuf,
+    passphrase: String,
+}
+
+/// Plaintext content of an encrypted keystore.
+#[derive(Serialize, Deserialize, Default)]
+struct EncryptedKeystoreContent {
+    keys: Vec<String>,
+    aliases: BTreeMap<SuiAddress, String>,
+}
+
+impl Serialize for EncryptedFileBasedKeystore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.path.to_str().unwrap_or(""))
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptedFileBasedKeystore {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let path = PathBuf::from(String::deserialize(deserializer)?);
+        let passphrase = read_keystore_passphrase().map_err(D::Error::custom)?;
+        EncryptedFileBasedKeystore::new(&path, passphrase).map_err(D::Error::custom)
+    }
+}
+
+impl AccountKeystore for EncryptedFileBasedKeystore {
+    #[warn(deprecated)]
+    fn sign(&self, address: &SuiAddress, msg: &[u8]) -> Result<Signature, signature::Error> {
+        Ok(self
+            .keys
+            .get(address)
+            .ok_or_else(|| {
+                signature::Error::from_source(format!("Cannot find key for address: [{address}]"))
+            })?
+            .sign(msg))
+    }
+
+    fn sign_secure<T>(
+        &self,
+        address: &SuiAddress,
+        msg: &T,
+        intent: Intent,
+    ) -> Result<Signature, signature::Error>
+    where
+        T: Serialize,
+    {
+        Ok(Signature::new_secure(
+            &IntentMessage::new(intent, msg),
+            self.keys.get(address).ok_or_else(|| {
+                signature::Error::from_source(format!("Cannot find key for address: [{address}]"))
+            })?,
+        ))
+    }
+
+    fn add_key(&mut self, keypair: SuiKeyPair) -> Result<(), anyhow::Error> {
+        let address: SuiAddress = (&keypair.public()).into();
+        self.keys.insert(address, keypair);
+        self.save()
+    }
+
+    fn keys(&self) -> Vec<PublicKey> {
+        self.keys.values().map(|key| key.public()).collect()
+    }
+
+    fn get_key(&self, address: &SuiAddress) -> Result<&SuiKeyPair, anyhow::Error> {
+        match self.keys.get(address) {
+            Some(key) => Ok(key),
+            None => Err(anyhow!("Cannot find key for address: [{address}]")),
+        }
+    }
+
+    fn remove_key(&mut self, address: &SuiAddress) -> Result<SuiKeyPair, anyhow::Error> {
+        let key = remove_key(&mut self.keys, &mut self.aliases, address)?;
+        self.save()?;
+        Ok(key)
+    }
+
+    fn alias(&self, address: &SuiAddress) -> Option<&str> {
+        self.aliases.get(address).map(String::as_str)
+    }
+
+    fn set_alias(
+        &mut self,
+        address: &SuiAddress,
+        alias: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        set_alias(&self.keys, &mut self.aliases, address, alias)?;
+        self.save()
+    }
+}
+
+impl EncryptedFileBasedKeystore {
+    /// Open the encrypted keystore at `path` with `passphrase`, the keystore is created on the
+    /// first save if the file does not exist.
+    pub fn new(path: &Path, passphrase: String) -> Result<Self, anyhow::Error> {
+        let content = if path.exists() {
+            let reader = BufReader::new(File::open(path)?);
+            let encrypted: EncryptedData = serde_json::from_reader(reader)
+                .map_err(|e| anyhow!("Invalid encrypted keystore file {:#?} {:?}", e, path))?;
+            serde_json::from_slice(&encrypted.decrypt(&passphrase)?)?
+        } else {
+            EncryptedKeystoreContent::default()
+        };
+        let keys = content
+            .keys
+            .iter()
+            .map(|kpstr| {
+                let key = SuiKeyPair::decode_base64(kpstr);
+                key.map(|k| (Into::<SuiAddress>::into(&k.public()), k))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map_err(|e| anyhow!("Invalid keypair in encrypted keystore {:#?} {:?}", e, path))?;
+
+        Ok(Self {
+            keys,
+            aliases: content.aliases,
+            path: path.to_path_buf(),
+            passphrase,
+        })
+    }
+
+    /// Encrypt the keys and aliases of a plaintext keystore into an encrypted keystore at `path`.
+    pub fn from_file_keystore(
+        keystore: FileBasedKeystore,
+        path: &Path,
+        passphrase: String,
+    ) -> Result<Self, anyhow::Error> {
+        let keystore = Self {
+            keys: keystore.keys,
+            aliases: keystore.aliases,
+            path: path.to_path_buf(),
+            passphrase,
+        };
+        keystore.save()?;
+        Ok(keystore)
+    }
+
+    /// Whether the file at `path` is an encrypted keystore.
+    pub fn is_encrypted(path: &Path) -> bool {
+        File::open(path)
+            .ok()
+            .and_then(|file| serde_json::from_reader::<_, EncryptedData>(BufReader::new(file)).ok())
+            .is_some()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encrypt the keystore with a new passphrase.
+    pub fn change_passphrase(&mut self, passphrase: String) -> Result<(), anyhow::Error> {
+        self.passphrase = passphrase;
+        self.save()
+    }
+
+    pub fn save(&self) -> Result<(), anyhow::Error> {
+        let content = EncryptedKeystoreContent {
+            keys: self
+                .keys
+                .values()
+                .map(EncodeDecodeBase64::encode_base64)
+                .collect(),
+            aliases: self.aliases.clone(),
+        };
+        let encrypted = EncryptedData::encrypt(&self.passphrase, &serde_json::to_vec(&content)?)?;
+        fs::write(&self.path, serde_json::to_string_pretty(&encrypted)?)?;
+        Ok(())
+    }
+}
+
+fn remove_key(
+    keys: &mut BTreeMap<SuiAddress, SuiKeyPair>,
+    aliases: &mut BTreeMap<SuiAddress, String>,
+    address: &SuiAddress,
+) -> Result<SuiKeyPair, anyhow::Error> {
+    let key = keys
+        .remove(address)
+        .ok_or_else(|| anyhow!("Cannot find key for address: [{address}]"))?;
+    aliases.remove(address);
+    Ok(key)
+}
+
+fn set_alias(
+    keys: &BTreeMap<SuiAddress, SuiKeyPair>,
+    aliases: &mut BTreeMap<SuiAddress, String>,
+    address: &SuiAddress,
+    alias: Option<String>,
+) -> Result<(), anyhow::Error> {
+    if !keys.contains_key(address) {
+        return Err(anyhow!("Cannot find key for address: [{address}]"));
+    }
+    match alias {
+        Some(alias) => {
+            if let Some((other, _)) = aliases
+                .iter()
+                .find(|(other, other_alias)| *other != address && **other_alias == alias)
+            {
+                return Err(anyhow!(
+                    "Alias [{alias}] is already used by address: [{other}]"
+                ));
+            }
+            aliases.insert(*address, alias);
+        }
+        None => {
+            aliases.remove(address);
+        }
+    }
+    Ok(())
 }
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct InMemKeystore {
     keys: BTreeMap<SuiAddress, SuiKeyPair>,
+    #[serde(default)]
+    aliases: BTreeMap<SuiAddress, String>,
 }
 
 impl AccountKeystore for InMemKeystore {
@@ -272,6 +606,22 @@ impl AccountKeystore for InMemKeystore {
             None => Err(anyhow!("Cannot find key for address: [{address}]")),
         }
     }
+
+    fn remove_key(&mut self, address: &SuiAddress) -> Result<SuiKeyPair, anyhow::Error> {
+        remove_key(&mut self.keys, &mut self.aliases, address)
+    }
+
+    fn alias(&self, address: &SuiAddress) -> Option<&str> {
+        self.aliases.get(address).map(String::as_str)
+    }
+
+    fn set_alias(
+        &mut self,
+        address: &SuiAddress,
+        alias: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        set_alias(&self.keys, &mut self.aliases, address, alias)
+    }
 }
 
 impl InMemKeystore {
@@ -282,6 +632,9 @@ impl InMemKeystore {
             .map(|(ad, k)| (ad, SuiKeyPair::Ed25519(k)))
             .collect::<BTreeMap<SuiAddress, SuiKeyPair>>();
 
-        Self { keys }
+        Self {
+            keys,
+            aliases: BTreeMap::new(),
+        }
     }
 }
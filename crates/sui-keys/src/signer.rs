@@ -0,0 +1,84 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{PublicKey, Signature, SuiKeyPair};
+use sui_types::intent::IntentMessage;
+use sui_types::messages::TransactionData;
+
+use crate::keystore::AccountKeystore;
+
+/// Abstraction over anything able to sign transactions on behalf of an address, so that keys
+/// don't have to be held in memory. Implement this to plug in a KMS, an HSM, a hardware wallet or
+/// a remote signing service.
+///
+/// The returned signature must be a [Signature] over the BCS bytes of `intent_message`, as
+/// produced by [Signature::new_secure], carrying the public key of [Signer::public_key].
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Public key of the signing key.
+    fn public_key(&self) -> PublicKey;
+
+    /// Address the transactions are signed for, derived from the public key by default.
+    fn address(&self) -> SuiAddress {
+        (&self.public_key()).into()
+    }
+
+    async fn sign(
+        &self,
+        intent_message: &IntentMessage<TransactionData>,
+    ) -> Result<Signature, anyhow::Error>;
+}
+
+#[async_trait]
+impl Signer for SuiKeyPair {
+    fn public_key(&self) -> PublicKey {
+        self.public()
+    }
+
+    async fn sign(
+        &self,
+        intent_message: &IntentMessage<TransactionData>,
+    ) -> Result<Signature, anyhow::Error> {
+        Ok(Signature::new_secure(intent_message, self))
+    }
+}
+
+/// [Signer] for one of the addresses of a keystore.
+pub struct KeystoreSigner<'a, K> {
+    keystore: &'a K,
+    address: SuiAddress,
+}
+
+impl<'a, K: AccountKeystore> KeystoreSigner<'a, K> {
+    pub fn new(keystore: &'a K, address: SuiAddress) -> Result<Self, anyhow::Error> {
+        // Fail early rather than when signing, if the keystore does not manage the address.
+        keystore.get_key(&address)?;
+        Ok(Self { keystore, address })
+    }
+}
+
+#[async_trait]
+impl<'a, K: AccountKeystore> Signer for KeystoreSigner<'a, K> {
+    fn public_key(&self) -> PublicKey {
+        // Ok to unwrap, the key was checked to exist when creating the signer, and keys can't be
+        // removed from a keystore we hold a shared reference to.
+        self.keystore.get_key(&self.address).unwrap().public()
+    }
+
+    fn address(&self) -> SuiAddress {
+        self.address
+    }
+
+    async fn sign(
+        &self,
+        intent_message: &IntentMessage<TransactionData>,
+    ) -> Result<Signature, anyhow::Error> {
+        Ok(self.keystore.sign_secure(
+            &self.address,
+            &intent_message.value,
+            intent_message.intent.clone(),
+        )?)
+    }
+}
@@ -40,8 +40,10 @@
 //!
 //! Once we've ratcheted up our highest_verified_checkpoint, and if it is higher than
 //! highest_synced_checkpoint, StateSync will then kick off a task to synchronize the contents of
-//! all of the checkpoints from highest_synced_checkpoint..=highest_verified_checkpoint. After the
-//! contents of each checkpoint is fully downloaded, StateSync will update our
+//! all of the checkpoints from highest_synced_checkpoint..=highest_verified_checkpoint. The
+//! contents of several checkpoints are downloaded concurrently, each from a randomly selected
+//! peer, and may complete out of order. Once the contents of a checkpoint, and of all the
+//! checkpoints before it, are fully downloaded, StateSync will update our
 //! highest_synced_checkpoint watermark and send out a notification on a broadcast channel
 //! indicating that a new checkpoint has been fully downloaded. Notifications on this broadcast
 //! channel will always be made in order. StateSync will also send out a notification to its peers
@@ -49,9 +51,9 @@
 
 use anemo::{types::PeerEvent, PeerId, Request, Response, Result};
 use anyhow::anyhow;
-use futures::{FutureExt, StreamExt};
+use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     sync::{Arc, RwLock},
     time::{Duration, SystemTime},
 };
@@ -569,6 +571,7 @@ where
                 self.checkpoint_event_sender.clone(),
                 self.metrics.clone(),
                 self.config.checkpoint_content_download_concurrency(),
+                self.config.checkpoint_content_buffer_size(),
                 self.config.checkpoint_content_timeout(),
                 highest_verified_checkpoint,
             );
@@ -966,6 +969,7 @@ async fn sync_checkpoint_contents<S>(
     checkpoint_event_sender: broadcast::Sender<VerifiedCheckpoint>,
     metrics: Metrics,
     checkpoint_content_download_concurrency: usize,
+    checkpoint_content_buffer_size: usize,
     timeout: Duration,
     target_checkpoint: VerifiedCheckpoint,
 ) where
@@ -976,52 +980,69 @@ async fn sync_checkpoint_contents<S>(
         .get_highest_synced_checkpoint()
         .expect("store operation should not fail");
 
-    let start = highest_synced.sequence_number().saturating_add(1);
-
-    let mut checkpoint_contents_stream = (start..=*target_checkpoint.sequence_number())
-        .map(|next| {
-            store
-                .get_checkpoint_by_sequence_number(next)
+    let mut next_to_download = highest_synced.sequence_number().saturating_add(1);
+    let mut checkpoint_contents_downloads = FuturesUnordered::new();
+    // Checkpoints whose contents are downloaded, waiting for the contents of an earlier checkpoint
+    let mut downloaded_checkpoints = BTreeMap::new();
+
+    loop {
+        // Keep up to `checkpoint_content_download_concurrency` downloads in flight, without
+        // running more than `checkpoint_content_buffer_size` checkpoints ahead of the highest
+        // synced checkpoint.
+        while next_to_download <= *target_checkpoint.sequence_number()
+            && checkpoint_contents_downloads.len() < checkpoint_content_download_concurrency
+            && next_to_download - highest_synced.sequence_number()
+                <= checkpoint_content_buffer_size as u64
+        {
+            let checkpoint = store
+                .get_checkpoint_by_sequence_number(next_to_download)
                 .expect("store operation should not fail")
                 .expect(
                     "BUG: store should have all checkpoints older than highest_verified_checkpoint",
-                )
-        })
-        .map(|checkpoint| {
-            sync_one_checkpoint_contents(
+                );
+            checkpoint_contents_downloads.push(sync_one_checkpoint_contents(
                 network.clone(),
                 &store,
                 peer_heights.clone(),
                 timeout,
                 checkpoint,
-            )
-        })
-        .pipe(futures::stream::iter)
-        .buffered(checkpoint_content_download_concurrency);
+            ));
+            next_to_download += 1;
+        }
 
-    while let Some(maybe_checkpoint) = checkpoint_contents_stream.next().await {
+        let Some(maybe_checkpoint) = checkpoint_contents_downloads.next().await else {
+            break;
+        };
         match maybe_checkpoint {
             Ok((checkpoint, num_txns)) => {
-                // if this fails, there is a bug in checkpoint construction (or the chain is
-                // corrupted)
-                assert_eq!(
-                    highest_synced.network_total_transactions + num_txns,
-                    checkpoint.network_total_transactions
-                );
-
-                store
-                    .update_highest_synced_checkpoint(&checkpoint)
-                    .expect("store operation should not fail");
-                metrics.set_highest_synced_checkpoint(*checkpoint.sequence_number());
-                // We don't care if no one is listening as this is a broadcast channel
-                let _ = checkpoint_event_sender.send(checkpoint.clone());
-                highest_synced = checkpoint;
+                downloaded_checkpoints
+                    .insert(*checkpoint.sequence_number(), (checkpoint, num_txns));
             }
             Err(err) => {
                 debug!("unable to sync contents of checkpoint: {err}");
                 break;
             }
         }
+
+        // Commit the downloaded checkpoints in order
+        while let Some((checkpoint, num_txns)) =
+            downloaded_checkpoints.remove(&highest_synced.sequence_number().saturating_add(1))
+        {
+            // if this fails, there is a bug in checkpoint construction (or the chain is
+            // corrupted)
+            assert_eq!(
+                highest_synced.network_total_transactions + num_txns,
+                checkpoint.network_total_transactions
+            );
+
+            store
+                .update_highest_synced_checkpoint(&checkpoint)
+                .expect("store operation should not fail");
+            metrics.set_highest_synced_checkpoint(*checkpoint.sequence_number());
+            // We don't care if no one is listening as this is a broadcast channel
+            let _ = checkpoint_event_sender.send(checkpoint.clone());
+            highest_synced = checkpoint;
+        }
     }
 
     // Notify event loop to notify our peers that we've synced to a new checkpoint height
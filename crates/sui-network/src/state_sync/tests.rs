@@ -11,6 +11,7 @@ use crate::{
 };
 use anemo::{PeerId, Request};
 use std::{collections::HashMap, time::Duration};
+use sui_config::p2p::StateSyncConfig;
 use sui_types::{
     messages_checkpoint::CheckpointDigest,
     storage::{ReadStore, SharedInMemoryStore, WriteStore},
@@ -253,6 +254,74 @@ async fn isolated_sync_job() {
     }
 }
 
+#[tokio::test]
+async fn sync_checkpoint_contents_in_order() {
+    let committee = CommitteeFixture::generate(rand::rngs::OsRng, 0, 4);
+    let (ordered_checkpoints, _, _) = committee.make_checkpoints(50, None);
+
+    // Download a few checkpoints at a time, with a buffer smaller than the range to sync
+    let config = StateSyncConfig {
+        checkpoint_content_download_concurrency: Some(4),
+        checkpoint_content_buffer_size: Some(8),
+        ..Default::default()
+    };
+    let (builder, server) = Builder::new()
+        .config(config)
+        .store(SharedInMemoryStore::default())
+        .build();
+    let network_1 = build_network(|router| router.add_rpc_service(server));
+    let (mut event_loop_1, handle_1) = builder.build(network_1.clone());
+    let (builder, server) = Builder::new().store(SharedInMemoryStore::default()).build();
+    let network_2 = build_network(|router| router.add_rpc_service(server));
+    let (_event_loop_2, _handle_2) = builder.build(network_2.clone());
+    network_1.connect(network_2.local_addr()).await.unwrap();
+
+    // Node 1 has verified all the checkpoints but only synced the genesis checkpoint
+    {
+        let mut store = event_loop_1.store.inner_mut();
+        store.insert_genesis_state(
+            ordered_checkpoints.first().cloned().unwrap(),
+            empty_contents(),
+            committee.committee().to_owned(),
+        );
+        for checkpoint in ordered_checkpoints.clone() {
+            store.insert_checkpoint(checkpoint);
+        }
+    }
+    event_loop_1.peer_heights.write().unwrap().peers.insert(
+        network_2.peer_id(),
+        PeerStateSyncInfo {
+            genesis_checkpoint_digest: *ordered_checkpoints[0].digest(),
+            on_same_chain_as_us: true,
+            height: *ordered_checkpoints.last().unwrap().sequence_number(),
+        },
+    );
+    event_loop_1
+        .peer_heights
+        .write()
+        .unwrap()
+        .insert_checkpoint(ordered_checkpoints.last().cloned().unwrap().into_inner());
+
+    let mut subscriber_1 = handle_1.subscribe_to_synced_checkpoints();
+    event_loop_1.maybe_start_checkpoint_contents_sync_task();
+    event_loop_1.tasks.join_next().await.unwrap().unwrap();
+
+    assert_eq!(
+        ordered_checkpoints.last().map(|x| x.digest()),
+        Some(
+            event_loop_1
+                .store
+                .get_highest_synced_checkpoint()
+                .unwrap()
+                .digest()
+        )
+    );
+    // Checkpoints are synced in order
+    for checkpoint in &ordered_checkpoints[1..] {
+        assert_eq!(subscriber_1.recv().await.unwrap().data(), checkpoint.data());
+    }
+}
+
 #[tokio::test]
 async fn sync_with_checkpoints_being_inserted() {
     telemetry_subscribers::init_for_testing();
@@ -0,0 +1,189 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use mysten_metrics::spawn_monitored_task;
+use sui_core::authority::AuthorityStore;
+use sui_core::checkpoints::CheckpointStore;
+use sui_storage::checkpoint_export::{CheckpointExportWriter, ExportedCheckpoint};
+use sui_types::messages::TransactionEffectsAPI;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+const EXPORT_INTERVAL: Duration = Duration::from_secs(1);
+const FILE_EXTENSION: &str = "chk";
+
+/// Appends the checkpoints executed by the node to export files in `dir`, see
+/// [sui_storage::checkpoint_export].
+///
+/// A file is named after the sequence number of its first checkpoint and holds the checkpoints
+/// up to the end of an epoch, so that there is one file per epoch. On restart, the export resumes
+/// after the last complete checkpoint of the last file.
+pub struct CheckpointExporter {
+    dir: PathBuf,
+    checkpoint_store: Arc<CheckpointStore>,
+    store: Arc<AuthorityStore>,
+    /// Writer of the current file, `None` at the end of an epoch.
+    writer: Option<CheckpointExportWriter<BufWriter<File>>>,
+    next_checkpoint: CheckpointSequenceNumber,
+}
+
+impl CheckpointExporter {
+    pub fn new(
+        dir: PathBuf,
+        checkpoint_store: Arc<CheckpointStore>,
+        store: Arc<AuthorityStore>,
+    ) -> Result<Self> {
+        fs::create_dir_all(&dir)?;
+        let mut exporter = Self {
+            dir,
+            checkpoint_store,
+            store,
+            writer: None,
+            next_checkpoint: 0,
+        };
+        match exporter.last_file()? {
+            Some(first_checkpoint) => {
+                let (writer, last_checkpoint) =
+                    CheckpointExportWriter::open(&exporter.file_path(first_checkpoint))?;
+                exporter.next_checkpoint = last_checkpoint.map_or(first_checkpoint, |c| c + 1);
+                // The next epoch starts a new file.
+                if !exporter.is_end_of_epoch(last_checkpoint)? {
+                    exporter.writer = Some(writer);
+                }
+            }
+            // Pruned checkpoints cannot be exported.
+            None => {
+                exporter.next_checkpoint = exporter
+                    .checkpoint_store
+                    .get_highest_pruned_checkpoint_seq_number()?
+                    .map_or(0, |c| c + 1)
+            }
+        }
+        info!(
+            dir = ?exporter.dir,
+            next_checkpoint = exporter.next_checkpoint,
+            "Exporting checkpoints"
+        );
+        Ok(exporter)
+    }
+
+    pub fn spawn(mut self) -> JoinHandle<()> {
+        spawn_monitored_task!(async move {
+            loop {
+                if let Err(e) = self.export_executed_checkpoints() {
+                    warn!(
+                        checkpoint = self.next_checkpoint,
+                        "Failed exporting checkpoint: {e}"
+                    );
+                }
+                tokio::time::sleep(EXPORT_INTERVAL).await;
+            }
+        })
+    }
+
+    fn export_executed_checkpoints(&mut self) -> Result<()> {
+        let Some(highest_executed) = self
+            .checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()?
+        else {
+            return Ok(());
+        };
+        while self.next_checkpoint <= highest_executed {
+            let checkpoint = self.read_checkpoint(self.next_checkpoint)?;
+            if self.writer.is_none() {
+                let (writer, _) =
+                    CheckpointExportWriter::open(&self.file_path(self.next_checkpoint))?;
+                self.writer = Some(writer);
+            }
+            let writer = self.writer.as_mut().expect("Export file should be open");
+            writer.write_checkpoint(&checkpoint)?;
+            self.next_checkpoint += 1;
+            if checkpoint.summary.end_of_epoch_data.is_some() {
+                writer.flush()?;
+                self.writer = None;
+            }
+        }
+        if let Some(writer) = &mut self.writer {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    fn read_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<ExportedCheckpoint> {
+        let summary = self
+            .checkpoint_store
+            .get_checkpoint_by_sequence_number(sequence_number)?
+            .ok_or_else(|| anyhow!("Missing checkpoint {sequence_number}"))?
+            .into_inner();
+        let contents = self
+            .checkpoint_store
+            .get_checkpoint_contents(&summary.content_digest)?
+            .ok_or_else(|| anyhow!("Missing contents of checkpoint {sequence_number}"))?;
+        let effects = self
+            .store
+            .multi_get_effects(contents.iter().map(|digests| &digests.effects))?
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| anyhow!("Missing effects of checkpoint {sequence_number}"))?;
+        let events_digests = effects
+            .iter()
+            .filter_map(|effects| effects.events_digest().copied())
+            .collect::<Vec<_>>();
+        let events = self
+            .store
+            .multi_get_events(&events_digests)?
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| anyhow!("Missing events of checkpoint {sequence_number}"))?;
+        Ok(ExportedCheckpoint {
+            summary,
+            contents,
+            effects,
+            events,
+        })
+    }
+
+    fn is_end_of_epoch(&self, checkpoint: Option<CheckpointSequenceNumber>) -> Result<bool> {
+        let Some(checkpoint) = checkpoint else {
+            return Ok(false);
+        };
+        Ok(self
+            .checkpoint_store
+            .get_checkpoint_by_sequence_number(checkpoint)?
+            .map_or(false, |summary| summary.end_of_epoch_data.is_some()))
+    }
+
+    fn file_path(&self, first_checkpoint: CheckpointSequenceNumber) -> PathBuf {
+        // Zero padded so that files are listed in order.
+        self.dir
+            .join(format!("{first_checkpoint:020}.{FILE_EXTENSION}"))
+    }
+
+    /// First checkpoint of the last export file of the directory.
+    fn last_file(&self) -> Result<Option<CheckpointSequenceNumber>> {
+        let mut last = None;
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |ext| ext != FILE_EXTENSION) {
+                continue;
+            }
+            let first_checkpoint = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse().ok());
+            last = last.max(first_checkpoint);
+        }
+        Ok(last)
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::checkpoint_export::CheckpointExporter;
 use crate::metrics::GrpcMetrics;
 use anemo::Network;
 use anemo_tower::callback::CallbackLayer;
@@ -35,7 +36,7 @@ use sui_core::{
     authority_client::NetworkAuthorityClient,
 };
 use sui_json_rpc::event_api::EventReadApi;
-use sui_json_rpc::read_api::ReadApi;
+use sui_json_rpc::read_api::{ExecutedCheckpointScope, ReadApi};
 use sui_json_rpc::transaction_builder_api::TransactionBuilderApi;
 use sui_json_rpc::transaction_execution_api::TransactionExecutionApi;
 use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle};
@@ -48,6 +49,7 @@ use tracing::{debug, warn};
 use sui_protocol_config::{ProtocolConfig, ProtocolVersion, SupportedProtocolVersions};
 
 use sui_storage::{
+    archival_store::ObjectStoreArchive,
     event_store::{EventStoreType, SqlEventStore},
     IndexStore,
 };
@@ -61,6 +63,7 @@ use tower::ServiceBuilder;
 use tracing::{error_span, info, Instrument};
 use typed_store::DBMetrics;
 pub mod admin;
+mod checkpoint_export;
 mod handle;
 pub mod metrics;
 pub use handle::SuiNodeHandle;
@@ -294,6 +297,10 @@ impl SuiNode {
                 .unwrap();
         }
 
+        if let Some(dir) = &config.checkpoint_export_path {
+            CheckpointExporter::new(dir.clone(), checkpoint_store.clone(), store.clone())?.spawn();
+        }
+
         let (end_of_epoch_channel, end_of_epoch_receiver) =
             broadcast::channel(config.end_of_epoch_broadcast_channel_capacity);
 
@@ -1008,8 +1015,19 @@ pub async fn build_server(
     }
 
     let mut server = JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), prometheus_registry);
-
-    server.register_module(ReadApi::new(state.clone()))?;
+    if let Some(rate_limit) = &config.json_rpc_rate_limit {
+        server.set_rate_limit(rate_limit.clone());
+    }
+    server.set_request_scope(Arc::new(ExecutedCheckpointScope::new(state.clone())));
+
+    let mut read_api = ReadApi::new(state.clone()).with_node_config(
+        env!("CARGO_PKG_VERSION"),
+        config.authority_store_pruning_config,
+    );
+    if let Some(url) = &config.archival_store_url {
+        read_api = read_api.with_archive(Arc::new(ObjectStoreArchive::from_url(url)?));
+    }
+    server.register_module(read_api)?;
     server.register_module(CoinReadApi::new(state.clone()))?;
     server.register_module(ThresholdBlsApi::new(state.clone()))?;
     server.register_module(TransactionBuilderApi::new(state.clone()))?;
@@ -0,0 +1,221 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Backward compatibility checks between two versions of the JSON schemas of the JSON-RPC types.
+//!
+//! Clients built against the `old` schemas keep working with responses following the `new`
+//! schemas as long as every type, field, enum value and variant they know about is still there
+//! with the same type. New types, optional fields and variants are compatible additions.
+
+use serde_json::{Map, Value};
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Changes from the `old` to the `new` named schemas breaking clients built against `old`:
+/// removed types, fields, enum values and variants, fields changing type or becoming optional.
+pub fn breaking_changes(old: &Map<String, Value>, new: &Map<String, Value>) -> Vec<String> {
+    let mut changes = vec![];
+    for (name, old_schema) in old {
+        match new.get(name) {
+            Some(new_schema) => compare(name, old_schema, new_schema, &mut changes),
+            None => changes.push(format!("{name}: type removed")),
+        }
+    }
+    changes
+}
+
+fn compare(path: &str, old: &Value, new: &Value, changes: &mut Vec<String>) {
+    if old.get("$ref").is_some() || new.get("$ref").is_some() {
+        if old.get("$ref") != new.get("$ref") {
+            changes.push(format!(
+                "{path}: type changed from {} to {}",
+                describe(old),
+                describe(new)
+            ));
+        }
+        return;
+    }
+    if let Some(old_type) = old.get("type") {
+        if new.get("type") != Some(old_type) {
+            changes.push(format!(
+                "{path}: type changed from {} to {}",
+                describe(old),
+                describe(new)
+            ));
+            return;
+        }
+    }
+
+    if let (Some(old_values), Some(new_values)) = (array(old, "enum"), array(new, "enum")) {
+        for value in old_values {
+            if !new_values.contains(value) {
+                changes.push(format!("{path}: value {value} removed"));
+            }
+        }
+    }
+
+    if let Some(old_fields) = old.get("properties").and_then(Value::as_object) {
+        let new_fields = new.get("properties").and_then(Value::as_object);
+        for (field, old_field) in old_fields {
+            let field_path = format!("{path}.{field}");
+            match new_fields.and_then(|fields| fields.get(field)) {
+                Some(new_field) => {
+                    compare(&field_path, old_field, new_field, changes);
+                    if is_required(old, field) && !is_required(new, field) {
+                        changes.push(format!("{field_path}: field became optional"));
+                    }
+                }
+                None => changes.push(format!("{field_path}: field removed")),
+            }
+        }
+    }
+
+    for (key, suffix) in [("items", "[]"), ("additionalProperties", "{}")] {
+        if let (Some(old_items), Some(new_items)) = (old.get(key), new.get(key)) {
+            if old_items.is_object() && new_items.is_object() {
+                compare(&format!("{path}{suffix}"), old_items, new_items, changes);
+            }
+        }
+    }
+
+    // Wrappers of a single schema, e.g. to document a reference.
+    if let (Some(old_all), Some(new_all)) = (array(old, "allOf"), array(new, "allOf")) {
+        for (i, (old_schema, new_schema)) in old_all.iter().zip(new_all).enumerate() {
+            compare(
+                &format!("{path}.allOf[{i}]"),
+                old_schema,
+                new_schema,
+                changes,
+            );
+        }
+    }
+
+    // Variants are matched by content rather than by position, new ones may be inserted
+    // anywhere.
+    for key in ["oneOf", "anyOf"] {
+        let Some(old_variants) = array(old, key) else {
+            continue;
+        };
+        let new_variants = array(new, key).map(Vec::as_slice).unwrap_or_default();
+        for variant in old_variants {
+            if !new_variants.iter().any(|new| is_compatible(variant, new)) {
+                changes.push(format!(
+                    "{path}: variant {} removed or changed",
+                    describe(variant)
+                ));
+            }
+        }
+    }
+}
+
+fn is_compatible(old: &Value, new: &Value) -> bool {
+    let mut changes = vec![];
+    compare("", old, new, &mut changes);
+    changes.is_empty()
+}
+
+fn array<'a>(schema: &'a Value, key: &str) -> Option<&'a Vec<Value>> {
+    schema.get(key).and_then(Value::as_array)
+}
+
+fn is_required(schema: &Value, field: &str) -> bool {
+    array(schema, "required").map_or(false, |required| {
+        required.iter().any(|name| name.as_str() == Some(field))
+    })
+}
+
+/// Short description of `schema` for error messages.
+fn describe(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference
+            .strip_prefix(SCHEMA_REF_PREFIX)
+            .unwrap_or(reference)
+            .to_string();
+    }
+    if let Some(values) = schema.get("enum") {
+        return values.to_string();
+    }
+    if let Some(required) = schema.get("required") {
+        return format!("object with {required}");
+    }
+    match schema.get("type") {
+        Some(type_) => type_.to_string(),
+        None => "schema".to_string(),
+    }
+}
+
+#[cfg(test)]
+fn schemas(value: Value) -> Map<String, Value> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn test_compatible_additions() {
+    let old = schemas(serde_json::json!({
+        "Coin": {
+            "type": "object",
+            "required": ["balance"],
+            "properties": { "balance": { "type": "integer" } }
+        },
+        "Kind": { "oneOf": [{ "type": "string", "enum": ["Transfer"] }] }
+    }));
+    let new = schemas(serde_json::json!({
+        "Coin": {
+            "type": "object",
+            "required": ["balance", "locked"],
+            "properties": {
+                "balance": { "type": "integer" },
+                "locked": { "type": "boolean" },
+                "owner": { "$ref": "#/components/schemas/Owner" }
+            }
+        },
+        "Kind": {
+            "oneOf": [
+                { "type": "string", "enum": ["Publish"] },
+                { "type": "string", "enum": ["Transfer"] }
+            ]
+        },
+        "Owner": { "type": "string" }
+    }));
+    assert!(breaking_changes(&old, &new).is_empty());
+}
+
+#[test]
+fn test_breaking_changes() {
+    let old = schemas(serde_json::json!({
+        "Coin": {
+            "type": "object",
+            "required": ["balance", "owner", "id"],
+            "properties": {
+                "balance": { "type": "integer" },
+                "owner": { "$ref": "#/components/schemas/Owner" },
+                "id": { "type": "string" }
+            }
+        },
+        "Kind": { "oneOf": [{ "type": "string", "enum": ["Transfer"] }] },
+        "Owner": { "type": "string" }
+    }));
+    let new = schemas(serde_json::json!({
+        "Coin": {
+            "type": "object",
+            "required": ["balance"],
+            "properties": {
+                "balance": { "type": "string" },
+                "owner": { "$ref": "#/components/schemas/Owner" }
+            }
+        },
+        "Kind": { "oneOf": [{ "type": "string", "enum": ["Publish"] }] }
+    }));
+    let mut changes = breaking_changes(&old, &new);
+    changes.sort();
+    assert_eq!(
+        changes,
+        vec![
+            "Coin.balance: type changed from \"integer\" to \"string\"",
+            "Coin.id: field removed",
+            "Coin.owner: field became optional",
+            "Kind: variant [\"Transfer\"] removed or changed",
+            "Owner: type removed",
+        ]
+    );
+}
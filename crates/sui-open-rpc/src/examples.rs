@@ -18,9 +18,10 @@ use sui_json_rpc_types::{
     Checkpoint, CheckpointId, EventPage, MoveCallParams, OwnedObjectRef,
     RPCTransactionRequestParams, SuiData, SuiEvent, SuiEventEnvelope, SuiExecutionStatus,
     SuiGasCostSummary, SuiObjectData, SuiObjectDataOptions, SuiObjectInfo, SuiObjectRef,
-    SuiObjectResponse, SuiParsedData, SuiPastObjectResponse, SuiTransaction, SuiTransactionData,
-    SuiTransactionEffects, SuiTransactionEffectsV1, SuiTransactionEvents, SuiTransactionResponse,
-    SuiTransactionResponseOptions, TransactionBytes, TransactionsPage, TransferObjectParams,
+    SuiObjectResponse, SuiParsedData, SuiPastObjectResponse, SuiTransaction, SuiTransactionEffects,
+    SuiTransactionEffectsV1, SuiTransactionEvents, SuiTransactionResponse,
+    SuiTransactionResponseOptions, TransactionBytes, TransactionsPage, TransactionsPageItem,
+    TransferObjectParams,
 };
 use sui_open_rpc::ExamplePairing;
 use sui_types::base_types::{
@@ -231,6 +232,7 @@ impl RpcExampleProvider {
             type_: Some(ObjectType::Struct(GasCoin::type_())),
             bcs: None,
             display: None,
+            from_archive: None,
         });
 
         Examples::new(
@@ -270,6 +272,7 @@ impl RpcExampleProvider {
             type_: Some(ObjectType::Struct(GasCoin::type_())),
             bcs: None,
             display: None,
+            from_archive: None,
         });
 
         Examples::new(
@@ -298,6 +301,7 @@ impl RpcExampleProvider {
             end_of_epoch_data: None,
             transactions: vec![TransactionDigest::new(self.rng.gen())],
             checkpoint_commitments: vec![],
+            from_archive: None,
         };
 
         Examples::new(
@@ -366,8 +370,12 @@ impl RpcExampleProvider {
     }
 
     fn get_transactions(&mut self) -> Examples {
-        let mut data = self.get_transaction_digests(5..9);
-        let next_cursor = data.pop();
+        let mut digests = self.get_transaction_digests(5..9);
+        let next_cursor = digests.pop();
+        let data = digests
+            .into_iter()
+            .map(TransactionsPageItem::from)
+            .collect();
 
         let result = TransactionsPage { data, next_cursor };
         Examples::new(
@@ -382,6 +390,7 @@ impl RpcExampleProvider {
                     ("cursor", json!(TransactionDigest::new(self.rng.gen()))),
                     ("limit", json!(100)),
                     ("descending_order", json!(false)),
+                    ("show_summaries", json!(false)),
                 ],
                 json!(result),
             )],
@@ -434,6 +443,7 @@ impl RpcExampleProvider {
             package_id: ObjectID::from_hex_literal("0x2").unwrap(),
             transaction_module: String::from("native"),
             sender: signer,
+            previous_owner: Some(Owner::AddressOwner(signer)),
             recipient: Owner::AddressOwner(recipient),
             object_type: "0x2::example::Object".to_string(),
             object_id: object_ref.0,
@@ -442,6 +452,7 @@ impl RpcExampleProvider {
         let events = vec![SuiEventEnvelope {
             timestamp: std::time::Instant::now().elapsed().as_secs(),
             tx_digest: *tx_digest,
+            checkpoint: Some(1),
             id: EventID::from((*tx_digest, 0)),
             event: sui_event.clone(),
         }];
@@ -454,6 +465,7 @@ impl RpcExampleProvider {
                     computation_cost: 100,
                     storage_cost: 100,
                     storage_rebate: 10,
+                    non_refundable_storage_fee: 0,
                 },
                 shared_objects: vec![],
                 transaction_digest: TransactionDigest::new(self.rng.gen()),
@@ -482,13 +494,17 @@ impl RpcExampleProvider {
             events: Some(SuiTransactionEvents {
                 data: vec![sui_event],
             }),
+            raw_effects: vec![],
             timestamp_ms: None,
             transaction: Some(SuiTransaction {
-                data: SuiTransactionData::try_from(data1).unwrap(),
+                data: data1.try_into().unwrap(),
                 tx_signatures: signatures.clone(),
             }),
             confirmed_local_execution: None,
             checkpoint: None,
+            from_archive: None,
+            gas_owner_balance_change: None,
+            input_objects: None,
             errors: vec![],
         };
 
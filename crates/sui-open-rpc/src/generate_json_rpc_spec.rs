@@ -7,7 +7,9 @@ use std::io::Write;
 use clap::ArgEnum;
 use clap::Parser;
 use pretty_assertions::assert_str_eq;
+use serde_json::{Map, Value};
 use sui_core::SUI_CORE_VERSION;
+use versions::Versioning;
 
 use sui_json_rpc::coin_api::CoinReadApi;
 use sui_json_rpc::event_api::EventReadApi;
@@ -18,10 +20,13 @@ use sui_json_rpc::threshold_bls_api::ThresholdBlsApi;
 use sui_json_rpc::transaction_builder_api::TransactionBuilderApi;
 use sui_json_rpc::transaction_execution_api::TransactionExecutionApi;
 use sui_json_rpc::SuiRpcModule;
+use sui_open_rpc::compatibility::breaking_changes;
 
 use crate::examples::RpcExampleProvider;
+use crate::rust_client::generate_rust_client;
 
 mod examples;
+mod rust_client;
 
 #[derive(Debug, Parser, Clone, Copy, ArgEnum)]
 enum Action {
@@ -41,6 +46,41 @@ struct Options {
 }
 
 const FILE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/spec/openrpc.json",);
+const CLIENT_FILE_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../sui-sdk/src/json_rpc_client.rs",
+);
+// JSON schemas of the JSON-RPC types, one file per version.
+const SCHEMAS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/spec/schemas");
+
+fn schemas_file_path(version: &str) -> String {
+    format!("{SCHEMAS_DIR}/{version}.json")
+}
+
+/// Check that the `schemas` of the current version don't break clients of the versions recorded
+/// before it.
+fn check_compatibility(schemas: &Map<String, Value>) {
+    let current = Versioning::new(SUI_CORE_VERSION).unwrap();
+    for entry in std::fs::read_dir(SCHEMAS_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(version) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if Versioning::new(version).map_or(true, |version| version >= current) {
+            continue;
+        }
+        let recorded: Map<String, Value> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let changes = breaking_changes(&recorded, schemas);
+        assert!(
+            changes.is_empty(),
+            "\n\
+Backward incompatible changes to the JSON-RPC types since version {version}:\n{}\n\
+Keep the old fields and variants, or add new ones instead of changing them.\n",
+            changes.join("\n")
+        );
+    }
+}
 
 #[tokio::main]
 async fn main() {
@@ -57,6 +97,12 @@ async fn main() {
 
     open_rpc.add_examples(RpcExampleProvider::new().examples());
 
+    let schemas = serde_json::to_value(&open_rpc).unwrap()["components"]["schemas"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+    let schemas_content = serde_json::to_string_pretty(&schemas).unwrap() + "\n";
+
     match options.action {
         Action::Print => {
             let content = serde_json::to_string_pretty(&open_rpc).unwrap();
@@ -66,11 +112,21 @@ async fn main() {
             let content = serde_json::to_string_pretty(&open_rpc).unwrap();
             let mut f = File::create(FILE_PATH).unwrap();
             writeln!(f, "{content}").unwrap();
+            let client = generate_rust_client(&serde_json::to_value(&open_rpc).unwrap());
+            std::fs::write(CLIENT_FILE_PATH, client).unwrap();
+            std::fs::create_dir_all(SCHEMAS_DIR).unwrap();
+            std::fs::write(schemas_file_path(SUI_CORE_VERSION), schemas_content).unwrap();
         }
         Action::Test => {
             let reference = std::fs::read_to_string(FILE_PATH).unwrap();
             let content = serde_json::to_string_pretty(&open_rpc).unwrap() + "\n";
             assert_str_eq!(&reference, &content);
+            let reference = std::fs::read_to_string(CLIENT_FILE_PATH).unwrap();
+            let client = generate_rust_client(&serde_json::to_value(&open_rpc).unwrap());
+            assert_str_eq!(&reference, &client);
+            let reference = std::fs::read_to_string(schemas_file_path(SUI_CORE_VERSION)).unwrap();
+            assert_str_eq!(&reference, &schemas_content);
+            check_compatibility(&schemas);
         }
     }
 }
@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use versions::Versioning;
 
+pub mod compatibility;
+
 /// OPEN-RPC documentation following the OpenRPC specification <https://spec.open-rpc.org>
 /// The implementation is partial, only required fields and subset of optional fields
 /// in the specification are implemented catered to Sui's need.
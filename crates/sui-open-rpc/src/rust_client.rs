@@ -0,0 +1,318 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generates a typed async Rust client from the OpenRPC document, so that a change of the RPC
+//! types that is not reflected in the document, or the other way around, fails to compile
+//! instead of failing to deserialize at runtime.
+
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+/// Path of the types used by the client, by type name.
+const RUST_TYPES: &[(&str, &str)] = &[
+    ("BTreeMap", "std::collections::BTreeMap"),
+    ("Balance", "sui_json_rpc_types::Balance"),
+    ("Base64", "fastcrypto::encoding::Base64"),
+    (
+        "BatchTransferParams",
+        "sui_json_rpc_types::BatchTransferParams",
+    ),
+    ("BigInt", "sui_json_rpc_types::BigInt"),
+    ("Checkpoint", "sui_json_rpc_types::Checkpoint"),
+    (
+        "CheckpointAttestation",
+        "sui_json_rpc_types::CheckpointAttestation",
+    ),
+    ("CheckpointId", "sui_json_rpc_types::CheckpointId"),
+    (
+        "CheckpointSequenceNumber",
+        "sui_types::messages_checkpoint::CheckpointSequenceNumber",
+    ),
+    ("CoinPage", "sui_json_rpc_types::CoinPage"),
+    ("CommitteePage", "sui_json_rpc_types::CommitteePage"),
+    ("DelegatedStake", "sui_types::governance::DelegatedStake"),
+    ("DevInspectResults", "sui_json_rpc_types::DevInspectResults"),
+    (
+        "DryRunTransactionResponse",
+        "sui_json_rpc_types::DryRunTransactionResponse",
+    ),
+    (
+        "DynamicFieldName",
+        "sui_types::dynamic_field::DynamicFieldName",
+    ),
+    ("DynamicFieldPage", "sui_json_rpc_types::DynamicFieldPage"),
+    ("EventID", "sui_types::event::EventID"),
+    ("EventPage", "sui_json_rpc_types::EventPage"),
+    ("EventQuery", "sui_types::query::EventQuery"),
+    (
+        "ExecuteTransactionRequestType",
+        "sui_types::messages::ExecuteTransactionRequestType",
+    ),
+    (
+        "MoveFunctionArgType",
+        "sui_json_rpc_types::MoveFunctionArgType",
+    ),
+    ("ObjectID", "sui_types::base_types::ObjectID"),
+    ("ProtocolVersion", "sui_protocol_config::ProtocolVersion"),
+    (
+        "RPCTransactionRequestParams",
+        "sui_json_rpc_types::RPCTransactionRequestParams",
+    ),
+    ("SequenceNumber", "sui_types::base_types::SequenceNumber"),
+    ("SuiAddress", "sui_types::base_types::SuiAddress"),
+    ("SuiCoinMetadata", "sui_json_rpc_types::SuiCoinMetadata"),
+    ("SuiCommittee", "sui_json_rpc_types::SuiCommittee"),
+    ("SuiGasCostTable", "sui_json_rpc_types::SuiGasCostTable"),
+    ("SuiJsonValue", "sui_json::SuiJsonValue"),
+    (
+        "SuiMoveNormalizedFunction",
+        "sui_json_rpc_types::SuiMoveNormalizedFunction",
+    ),
+    (
+        "SuiMoveNormalizedModule",
+        "sui_json_rpc_types::SuiMoveNormalizedModule",
+    ),
+    (
+        "SuiMoveNormalizedStruct",
+        "sui_json_rpc_types::SuiMoveNormalizedStruct",
+    ),
+    (
+        "SuiMoveStructLayout",
+        "sui_json_rpc_types::SuiMoveStructLayout",
+    ),
+    ("SuiNodeInfo", "sui_json_rpc_types::SuiNodeInfo"),
+    (
+        "SuiObjectDataOptions",
+        "sui_json_rpc_types::SuiObjectDataOptions",
+    ),
+    ("SuiObjectInfo", "sui_json_rpc_types::SuiObjectInfo"),
+    ("SuiObjectResponse", "sui_json_rpc_types::SuiObjectResponse"),
+    (
+        "SuiPastObjectResponse",
+        "sui_json_rpc_types::SuiPastObjectResponse",
+    ),
+    (
+        "SuiSystemStateSummary",
+        "sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary",
+    ),
+    (
+        "SuiTBlsSignObjectCommitmentType",
+        "sui_json_rpc_types::SuiTBlsSignObjectCommitmentType",
+    ),
+    (
+        "SuiTBlsSignRandomnessObjectResponse",
+        "sui_json_rpc_types::SuiTBlsSignRandomnessObjectResponse",
+    ),
+    (
+        "SuiTransactionBuilderMode",
+        "sui_json_rpc_types::SuiTransactionBuilderMode",
+    ),
+    (
+        "SuiTransactionResponse",
+        "sui_json_rpc_types::SuiTransactionResponse",
+    ),
+    (
+        "SuiTransactionResponseOptions",
+        "sui_json_rpc_types::SuiTransactionResponseOptions",
+    ),
+    (
+        "SuiTransactionSubmissionStatus",
+        "sui_json_rpc_types::SuiTransactionSubmissionStatus",
+    ),
+    ("SuiTypeTag", "sui_json_rpc_types::SuiTypeTag"),
+    (
+        "SuiValidatorReports",
+        "sui_json_rpc_types::SuiValidatorReports",
+    ),
+    ("Supply", "sui_types::balance::Supply"),
+    ("TransactionBytes", "sui_json_rpc_types::TransactionBytes"),
+    (
+        "TransactionDigest",
+        "sui_types::base_types::TransactionDigest",
+    ),
+    ("TransactionQuery", "sui_types::query::TransactionQuery"),
+    ("TransactionsPage", "sui_json_rpc_types::TransactionsPage"),
+];
+
+/// Type names of the schemas renamed by serde, by schema name.
+const SCHEMA_TYPES: &[(&str, &str)] = &[
+    ("ObjectDataOptions", "SuiObjectDataOptions"),
+    (
+        "TransactionResponseOptions",
+        "SuiTransactionResponseOptions",
+    ),
+    ("TypeTag", "SuiTypeTag"),
+];
+
+// Types in scope without an import.
+const PRELUDE_TYPES: &[&str] = &[
+    "Option", "String", "Vec", "bool", "u8", "u32", "u64", "usize",
+];
+
+const HEADER: &str = "// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed client of the Sui JSON-RPC API, generated from the OpenRPC document with
+//! `cargo -q run --example generate-json-rpc-spec -- record`, do not edit.
+";
+
+/// Generates the client of the methods of the OpenRPC document `spec`, as a trait implemented
+/// for every jsonrpsee client. Subscriptions are left out.
+pub fn generate_rust_client(spec: &Value) -> String {
+    let mut imports = BTreeSet::new();
+    let mut methods = String::new();
+    for method in spec["methods"]
+        .as_array()
+        .expect("methods should be an array")
+    {
+        let tags = method["tags"].as_array().cloned().unwrap_or_default();
+        if tags.iter().any(|tag| tag["name"] == "PubSub") {
+            continue;
+        }
+        let name = method["name"].as_str().expect("method should have a name");
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for param in method["params"]
+            .as_array()
+            .expect("params should be an array")
+        {
+            let param_name = rust_ident(param["name"].as_str().expect("param should have a name"));
+            let ty = optional(param, schema_type(&param["schema"]));
+            imports.extend(type_paths(&ty));
+            params.push(format!("{param_name}: {ty}"));
+            args.push(param_name);
+        }
+        let result = match method.get("result") {
+            Some(result) => optional(
+                result,
+                result["name"]
+                    .as_str()
+                    .expect("result should have a name")
+                    .to_string(),
+            ),
+            None => "()".to_string(),
+        };
+        imports.extend(type_paths(&result));
+
+        if let Some(description) = method["description"].as_str() {
+            for line in description.lines() {
+                methods.push_str(&format!("    /// {}\n", line.trim()));
+            }
+        }
+        if method["deprecated"].as_bool().unwrap_or_default() {
+            methods.push_str("    #[deprecated]\n");
+        }
+        let fn_name = rust_ident(&snake_case(name.trim_start_matches("sui_")));
+        methods.push_str(&format!(
+            "    async fn {fn_name}(&self, {}) -> Result<{result}, Error> {{\n        self.request(\"{name}\", rpc_params![{}]).await\n    }}\n\n",
+            params.join(", "),
+            args.join(", "),
+        ));
+    }
+
+    let mut code = HEADER.to_string();
+    code.push_str("\nuse async_trait::async_trait;\nuse jsonrpsee::core::client::ClientT;\nuse jsonrpsee::core::Error;\nuse jsonrpsee::rpc_params;\n");
+    for path in imports {
+        code.push_str(&format!("use {path};\n"));
+    }
+    code.push_str("\n/// Methods of the Sui JSON-RPC API, available on any JSON-RPC client.\n#[async_trait]\npub trait SuiJsonRpcClient: ClientT + Sync {\n");
+    code.push_str(methods.trim_end());
+    code.push_str("\n}\n\nimpl<T: ClientT + Sync> SuiJsonRpcClient for T {}\n");
+    rustfmt(&code)
+}
+
+/// Rust type of a JSON schema of the document.
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name = reference.trim_start_matches("#/components/schemas/");
+        return SCHEMA_TYPES
+            .iter()
+            .find(|(schema_name, _)| *schema_name == name)
+            .map_or(name, |(_, ty)| ty)
+            .to_string();
+    }
+    if let Some([ty, null]) = schema["anyOf"].as_array().map(Vec::as_slice) {
+        if null["type"] == "null" {
+            return format!("Option<{}>", schema_type(ty));
+        }
+    }
+    match (schema["type"].as_str(), schema["format"].as_str()) {
+        (Some("array"), _) => format!("Vec<{}>", schema_type(&schema["items"])),
+        (Some("object"), _) => format!(
+            "BTreeMap<String, {}>",
+            schema_type(&schema["additionalProperties"])
+        ),
+        (Some("string"), _) => "String".to_string(),
+        (Some("boolean"), _) => "bool".to_string(),
+        (Some("integer"), Some("uint")) => "usize".to_string(),
+        (Some("integer"), Some(format @ ("uint8" | "uint32" | "uint64"))) => {
+            format.replace("uint", "u")
+        }
+        _ => panic!("Unsupported schema {schema}"),
+    }
+}
+
+fn optional(descriptor: &Value, ty: String) -> String {
+    if descriptor["required"].as_bool().unwrap_or_default() {
+        ty
+    } else {
+        format!("Option<{ty}>")
+    }
+}
+
+/// Import paths of the types named in `ty`.
+fn type_paths(ty: &str) -> Vec<&'static str> {
+    ty.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|name| !name.is_empty() && !PRELUDE_TYPES.contains(name))
+        .map(|name| {
+            RUST_TYPES
+                .iter()
+                .find(|(type_name, _)| *type_name == name)
+                .map(|(_, path)| *path)
+                .unwrap_or_else(|| {
+                    panic!("Unknown type {name}, add its path to RUST_TYPES in rust_client.rs")
+                })
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn rust_ident(name: &str) -> String {
+    match name {
+        "type" | "move" | "ref" | "fn" | "mod" | "struct" | "match" => format!("r#{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn rustfmt(code: &str) -> String {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run rustfmt");
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.as_bytes())
+        .unwrap();
+    let output = rustfmt.wait_with_output().unwrap();
+    assert!(output.status.success(), "rustfmt failed on:\n{code}");
+    String::from_utf8(output.stdout).unwrap()
+}
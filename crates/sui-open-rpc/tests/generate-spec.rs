@@ -4,8 +4,11 @@
 #[test]
 #[cfg_attr(msim, ignore)]
 fn test_json_rpc_spec() {
-    // If this test breaks and you intended a json rpc schema change, you need to run to get the fresh schema:
+    // If this test breaks and you intended a json rpc schema change, you need to run to get the fresh schema
+    // and the matching Rust client in sui-sdk:
     // # cargo -q run --example generate-json-rpc-spec -- record
+    // It also fails on changes to the JSON-RPC types breaking the clients of the previous versions,
+    // whose schemas are recorded in spec/schemas.
     let status = std::process::Command::new("cargo")
         .current_dir("..")
         .args(["run", "--example", "generate-json-rpc-spec", "--"])
@@ -5,6 +5,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::{info, warn};
 
@@ -588,6 +589,54 @@ impl ProtocolConfig {
     // }
 }
 
+/// The constants among `$field`s of `$config` defined in its protocol version, by name.
+macro_rules! defined_constants {
+    ($config:expr, $($field:ident),* $(,)?) => {
+        [$((stringify!($field), $config.$field)),*]
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value?)))
+            .collect()
+    };
+}
+
+// gas cost introspection
+impl ProtocolConfig {
+    /// Gas and storage costs charged for transactions, independently of the Move code they run.
+    pub fn transaction_cost_parameters(&self) -> BTreeMap<&'static str, u64> {
+        defined_constants!(
+            self,
+            base_tx_cost_fixed,
+            package_publish_cost_fixed,
+            base_tx_cost_per_byte,
+            package_publish_cost_per_byte,
+            obj_access_cost_read_per_byte,
+            obj_access_cost_mutate_per_byte,
+            obj_access_cost_delete_per_byte,
+            obj_access_cost_verify_per_byte,
+            obj_data_cost_refundable,
+            obj_metadata_cost_non_refundable,
+            storage_gas_price,
+        )
+    }
+
+    /// Gas costs of the Move native functions.
+    pub fn native_cost_parameters(&self) -> BTreeMap<&'static str, u64> {
+        defined_constants!(
+            self,
+            copy_bytes_to_address_cost_per_byte,
+            address_to_vec_cost_per_byte,
+            address_vec_reverse_cost_per_byte,
+            copy_convert_to_u256_cost_per_byte,
+            u256_to_bytes_to_vec_cost_per_byte,
+            u256_bytes_vec_reverse_cost_per_byte,
+            copy_convert_to_address_cost_per_byte,
+            event_value_size_derivation_cost_per_byte,
+            event_tag_size_derivation_cost_per_byte,
+            event_emit_cost_per_byte,
+        )
+    }
+}
+
 #[cfg(not(msim))]
 static POISON_VERSION_METHODS: AtomicBool = AtomicBool::new(false);
 
@@ -132,7 +132,7 @@ pub async fn submit(
         )
         .await?;
 
-    if let SuiExecutionStatus::Failure { error } = response
+    if let SuiExecutionStatus::Failure { error, .. } = response
         .effects
         .expect("Execute transaction should return effects")
         .status()
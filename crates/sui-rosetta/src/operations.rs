@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::anyhow;
-use std::collections::HashMap;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::CompiledModule;
+use move_bytecode_utils::module_cache::GetModule;
+use move_core_types::language_storage::ModuleId;
+use std::collections::{BTreeMap, HashMap};
 use std::vec;
 use sui_json_rpc_types::SuiArgument;
+use sui_json_rpc_types::SuiCallArg;
 use sui_json_rpc_types::SuiCommand;
 use sui_json_rpc_types::SuiProgrammableMoveCall;
 use sui_json_rpc_types::SuiProgrammableTransaction;
-use sui_sdk::json::SuiJsonValue;
 
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 use sui_sdk::rpc_types::{
@@ -192,17 +197,8 @@ impl Operations {
         enum KnownValue {
             GasCoin(u64),
         }
-        macro_rules! bcs_json_to_value {
-            ($value:expr) => {
-                $value.to_json_value().as_array().and_then(|v| {
-                    // value is a byte array
-                    let bytes = v
-                        .iter()
-                        .flat_map(|v| v.as_u64().map(|n| n as u8))
-                        .collect::<Vec<_>>();
-                    bcs::from_bytes(&bytes).ok()
-                })
-            };
+        fn pure_input<T: DeserializeOwned>(inputs: &[SuiCallArg], i: u16) -> Option<T> {
+            inputs.get(i as usize)?.pure()?.to_value()
         }
         fn resolve_result(
             known_results: &[Vec<KnownValue>],
@@ -214,13 +210,13 @@ impl Operations {
                 .and_then(|inner| inner.get(j as usize))
         }
         fn split_coin(
-            inputs: &[SuiJsonValue],
+            inputs: &[SuiCallArg],
             _known_results: &[Vec<KnownValue>],
             _coin: SuiArgument,
             amount: SuiArgument,
         ) -> Option<Vec<KnownValue>> {
             let amount: u64 = match amount {
-                SuiArgument::Input(i) => bcs_json_to_value!(&inputs[i as usize])?,
+                SuiArgument::Input(i) => pure_input(inputs, i)?,
                 SuiArgument::GasCoin | SuiArgument::Result(_) | SuiArgument::NestedResult(_, _) => {
                     return None
                 }
@@ -229,13 +225,13 @@ impl Operations {
         }
         fn transfer_object(
             aggregated_recipients: &mut HashMap<SuiAddress, u64>,
-            inputs: &[SuiJsonValue],
+            inputs: &[SuiCallArg],
             known_results: &[Vec<KnownValue>],
             objs: &[SuiArgument],
             recipient: SuiArgument,
         ) -> Option<Vec<KnownValue>> {
             let addr = match recipient {
-                SuiArgument::Input(i) => inputs[i as usize].to_sui_address().ok()?,
+                SuiArgument::Input(i) => pure_input(inputs, i)?,
                 SuiArgument::GasCoin | SuiArgument::Result(_) | SuiArgument::NestedResult(_, _) => {
                     return None
                 }
@@ -258,7 +254,7 @@ impl Operations {
             Some(vec![])
         }
         fn delegation_call(
-            inputs: &[SuiJsonValue],
+            inputs: &[SuiCallArg],
             _known_results: &[Vec<KnownValue>],
             call: &SuiProgrammableMoveCall,
         ) -> Result<Option<(Option<u64>, SuiAddress)>, Error> {
@@ -266,7 +262,7 @@ impl Operations {
             let (amount, validator) = match &arguments[..] {
                 [_, _, amount, validator] => {
                     let amount = match amount {
-                        SuiArgument::Input(i) => match bcs_json_to_value!(&inputs[*i as usize]){
+                        SuiArgument::Input(i) => match pure_input::<Option<u64>>(inputs, *i) {
                             Some(amount) => amount,
                             None => return Ok(None),
                         },
@@ -275,7 +271,7 @@ impl Operations {
                         SuiArgument::NestedResult(_, _) => return Ok(None),
                     };
                     let validator = match validator {
-                        SuiArgument::Input(i) => inputs[*i as usize].to_sui_address(),
+                        SuiArgument::Input(i) => pure_input::<SuiAddress>(inputs, *i),
                         SuiArgument::GasCoin |
                         SuiArgument::Result(_) |
                         SuiArgument::NestedResult(_, _) => return Ok(None),
@@ -284,7 +280,7 @@ impl Operations {
                 },
                 _ => return Err(Error::InternalError(anyhow!("Error encountered when extracting arguments from move call, expecting 4 elements, got {}", arguments.len()))),
             };
-            let validator = validator.map_err(|_| {
+            let validator = validator.ok_or_else(|| {
                 Error::InternalError(anyhow!("Error parsing Validator address from call arg."))
             })?;
             Ok(Some((amount, validator)))
@@ -473,7 +469,35 @@ impl TryFrom<SuiTransactionResponse> for Operations {
 impl TryFrom<TransactionData> for Operations {
     type Error = Error;
     fn try_from(data: TransactionData) -> Result<Self, Self::Error> {
-        SuiTransactionData::try_from(data)?.try_into()
+        // Decode the arguments of the system calls the same way the full node does for the
+        // transactions it returns.
+        SuiTransactionData::try_from(data, &FrameworkModules::new())
+            .map_err(|e| Error::InternalError(e.into()))?
+            .try_into()
+    }
+}
+
+/// Resolver for the modules of the Sui framework and the Move stdlib.
+struct FrameworkModules(BTreeMap<ModuleId, CompiledModule>);
+
+impl FrameworkModules {
+    fn new() -> Self {
+        Self(
+            sui_framework::get_move_stdlib()
+                .into_iter()
+                .chain(sui_framework::get_sui_framework())
+                .map(|module| (module.self_id(), module))
+                .collect(),
+        )
+    }
+}
+
+impl GetModule for FrameworkModules {
+    type Error = anyhow::Error;
+    type Item = CompiledModule;
+
+    fn get_module_by_id(&self, id: &ModuleId) -> Result<Option<CompiledModule>, anyhow::Error> {
+        Ok(self.0.get(id).cloned())
     }
 }
 
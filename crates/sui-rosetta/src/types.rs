@@ -409,6 +409,8 @@ pub enum OperationType {
     Genesis,
     ConsensusCommitPrologue,
     ProgrammableTransaction,
+    // Transaction kinds unknown to this version
+    Unknown,
 }
 
 impl From<&SuiTransactionKind> for OperationType {
@@ -429,6 +431,7 @@ impl From<&SuiTransactionKind> for OperationType {
             SuiTransactionKind::ProgrammableTransaction(_) => {
                 OperationType::ProgrammableTransaction
             }
+            SuiTransactionKind::Unknown => OperationType::Unknown,
         }
     }
 }
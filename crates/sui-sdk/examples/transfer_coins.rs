@@ -1,15 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_keys::keystore::{FileBasedKeystore, Keystore};
+use sui_keys::signer::KeystoreSigner;
 use sui_sdk::{
-    types::{
-        base_types::{ObjectID, SuiAddress},
-        messages::Transaction,
-    },
+    types::base_types::{ObjectID, SuiAddress},
     SuiClientBuilder,
 };
-use sui_types::intent::Intent;
 use sui_types::messages::ExecuteTransactionRequestType;
 
 #[tokio::main]
@@ -33,15 +30,17 @@ async fn main() -> Result<(), anyhow::Error> {
         .transfer_sui(my_address, gas_object_id, 1000, recipient, Some(1000))
         .await?;
 
-    // Sign transaction
+    // Sign the transaction with the keystore, any other `Signer` (e.g. backed by a KMS) can be
+    // used instead.
     let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-    let signature = keystore.sign_secure(&my_address, &transfer_tx, Intent::default())?;
+    let signer = KeystoreSigner::new(&keystore, my_address)?;
 
-    // Execute the transaction
+    // Sign and execute the transaction
     let transaction_response = sui
         .quorum_driver()
-        .execute_transaction(
-            Transaction::from_data(transfer_tx, Intent::default(), vec![signature]).verify()?,
+        .sign_and_execute_transaction(
+            transfer_tx,
+            &signer,
             Some(ExecuteTransactionRequestType::WaitForLocalExecution),
         )
         .await?;
@@ -11,29 +11,47 @@ use std::collections::BTreeMap;
 use std::future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use sui_adapter::execution_mode::Normal;
+use sui_json::derive_dynamic_field_id_from_name;
 use sui_json_rpc::api::GovernanceReadApiClient;
 use sui_json_rpc_types::{
-    Balance, Checkpoint, CheckpointId, Coin, CoinPage, DryRunTransactionResponse, DynamicFieldPage,
-    EventPage, SuiCoinMetadata, SuiCommittee, SuiEventEnvelope, SuiEventFilter,
-    SuiMoveNormalizedModule, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
+    Balance, Checkpoint, CheckpointAttestation, CheckpointId, Coin, CoinPage, CommitteePage,
+    DryRunTransactionResponse, DynamicFieldPage, EventPage, SuiCoinMetadata, SuiCommittee,
+    SuiEventEnvelope, SuiEventFilter, SuiExecutionStatus, SuiGasCostTable,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiMoveStructLayout, SuiNodeInfo, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
     SuiPastObjectResponse, SuiTransactionEffectsAPI, SuiTransactionResponse,
-    SuiTransactionResponseOptions, TransactionsPage,
+    SuiTransactionResponseOptions, SuiValidatorReports, TransactionSummariesPage, TransactionsPage,
+    TransactionsPageItem,
 };
+use sui_keys::signer::Signer;
+use sui_protocol_config::{ProtocolConfig, ProtocolVersion};
+use sui_transaction_builder::TransactionBuilder;
 use sui_types::balance::Supply;
 use sui_types::base_types::{
     ObjectID, SequenceNumber, SuiAddress, TransactionDigest, TxSequenceNumber,
 };
 use sui_types::committee::EpochId;
+use sui_types::dynamic_field::{DynamicFieldName, DynamicFieldType};
 use sui_types::error::TRANSACTION_NOT_FOUND_MSG_PREFIX;
 use sui_types::event::EventID;
-use sui_types::messages::{ExecuteTransactionRequestType, TransactionData, VerifiedTransaction};
+use sui_types::gas_coin::GAS;
+use sui_types::intent::{Intent, IntentMessage};
+use sui_types::messages::{
+    Argument, Command, ExecuteTransactionRequestType, ObjectArg, Transaction, TransactionData,
+    VerifiedTransaction,
+};
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::parse_sui_struct_tag;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::query::{EventQuery, TransactionQuery};
 
 use futures::StreamExt;
 use sui_json_rpc::api::{CoinReadApiClient, EventReadApiClient, ReadApiClient, WriteApiClient};
 use sui_types::governance::DelegatedStake;
-use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+use sui_types::sui_system_state::sui_system_state_summary::{
+    SuiSystemStateSummary, SuiValidatorSummary,
+};
 
 #[derive(Debug)]
 pub struct ReadApi {
@@ -65,6 +83,20 @@ impl ReadApi {
             .await?)
     }
 
+    /// Read the `sui::dynamic_field::Field` object holding the dynamic field of `parent_object_id`
+    /// named `name`, addressing it by its derived ID rather than listing the dynamic fields of the
+    /// parent. For dynamic object fields, the field holds the ID of the object.
+    pub async fn get_dynamic_field_by_name(
+        &self,
+        parent_object_id: ObjectID,
+        type_: &DynamicFieldType,
+        name: &DynamicFieldName,
+        options: SuiObjectDataOptions,
+    ) -> SuiRpcResult<SuiObjectResponse> {
+        let field_id = derive_dynamic_field_id_from_name(parent_object_id, type_, name)?;
+        self.get_object_with_options(field_id, options).await
+    }
+
     pub async fn try_get_parsed_past_object(
         &self,
         object_id: ObjectID,
@@ -90,6 +122,18 @@ impl ReadApi {
             .await?)
     }
 
+    pub async fn try_multi_get_object_refs(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: Option<SuiObjectDataOptions>,
+    ) -> SuiRpcResult<Vec<SuiObjectResponse>> {
+        Ok(self
+            .api
+            .http
+            .try_multi_get_object_refs(object_ids, options)
+            .await?)
+    }
+
     pub async fn get_total_transaction_number(&self) -> SuiRpcResult<u64> {
         Ok(self.api.http.get_total_transaction_number().await?)
     }
@@ -140,15 +184,54 @@ impl ReadApi {
         Ok(self
             .api
             .http
-            .get_transactions(query, cursor, limit, Some(descending_order))
+            .get_transactions(query, cursor, limit, Some(descending_order), None)
             .await?)
     }
 
+    /// Same as [Self::get_transactions], with the timestamp, checkpoint, status and sender of each
+    /// transaction instead of only its digest.
+    pub async fn get_transaction_summaries(
+        &self,
+        query: TransactionQuery,
+        cursor: Option<TransactionDigest>,
+        limit: Option<usize>,
+        descending_order: bool,
+    ) -> SuiRpcResult<TransactionSummariesPage> {
+        let page = self
+            .api
+            .http
+            .get_transactions(query, cursor, limit, Some(descending_order), Some(true))
+            .await?;
+        let data = page
+            .data
+            .into_iter()
+            .map(|item| match item {
+                TransactionsPageItem::Summary(summary) => Ok(summary),
+                TransactionsPageItem::Digest(digest) => Err(Error::DataError(format!(
+                    "Missing summary of transaction {digest}"
+                ))),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(TransactionSummariesPage {
+            data,
+            next_cursor: page.next_cursor,
+        })
+    }
+
     /// Return a checkpoint
     pub async fn get_checkpoint(&self, id: CheckpointId) -> SuiRpcResult<Checkpoint> {
         Ok(self.api.http.get_checkpoint(id).await?)
     }
 
+    /// Return a checkpoint along with the signature of the validators certifying it and the
+    /// committee of its epoch, see [CheckpointAttestation::attestation] to verify it.
+    pub async fn get_checkpoint_attestation(
+        &self,
+        id: CheckpointId,
+    ) -> SuiRpcResult<CheckpointAttestation> {
+        Ok(self.api.http.get_checkpoint_attestation(id).await?)
+    }
+
     /// Return the sequence number of the latest checkpoint that has been executed
     pub async fn get_latest_checkpoint_sequence_number(
         &self,
@@ -160,6 +243,12 @@ impl ReadApi {
             .await?)
     }
 
+    /// Return the chain, epoch, protocol version, served checkpoints and software version of the
+    /// node, see [SuiNodeInfo].
+    pub async fn get_node_info(&self) -> SuiRpcResult<SuiNodeInfo> {
+        Ok(self.api.http.get_node_info().await?)
+    }
+
     pub fn get_transactions_stream(
         &self,
         query: TransactionQuery,
@@ -176,7 +265,11 @@ impl ReadApi {
                         .get_transactions(query.clone(), cursor, Some(100), descending_order)
                         .await
                         .ok()?;
-                    let mut data = page.data;
+                    let mut data = page
+                        .data
+                        .iter()
+                        .map(|item| *item.digest())
+                        .collect::<Vec<_>>();
                     data.reverse();
                     data.pop()
                         .map(|item| (item, (data, page.next_cursor, false, query)))
@@ -198,6 +291,54 @@ impl ReadApi {
             .await?)
     }
 
+    pub async fn get_normalized_move_module(
+        &self,
+        package: ObjectID,
+        module_name: String,
+    ) -> SuiRpcResult<SuiMoveNormalizedModule> {
+        Ok(self
+            .api
+            .http
+            .get_normalized_move_module(package, module_name)
+            .await?)
+    }
+
+    pub async fn get_normalized_move_struct(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        struct_name: String,
+    ) -> SuiRpcResult<SuiMoveNormalizedStruct> {
+        Ok(self
+            .api
+            .http
+            .get_normalized_move_struct(package, module_name, struct_name)
+            .await?)
+    }
+
+    /// The signature of a Move function: its visibility, whether it is an entry function, the
+    /// abilities required of its type parameters and its parameter and return types, e.g. to
+    /// check the arguments of a call before building the transaction.
+    pub async fn get_normalized_move_function(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        function_name: String,
+    ) -> SuiRpcResult<SuiMoveNormalizedFunction> {
+        Ok(self
+            .api
+            .http
+            .get_normalized_move_function(package, module_name, function_name)
+            .await?)
+    }
+
+    pub async fn get_move_struct_layout(
+        &self,
+        struct_type: String,
+    ) -> SuiRpcResult<SuiMoveStructLayout> {
+        Ok(self.api.http.get_move_struct_layout(struct_type).await?)
+    }
+
     pub async fn get_reference_gas_price(&self) -> SuiRpcResult<u64> {
         Ok(self.api.http.get_reference_gas_price().await?)
     }
@@ -320,6 +461,13 @@ impl CoinReadApi {
         Ok(self.api.http.get_coin_metadata(coin_type).await?)
     }
 
+    pub async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> SuiRpcResult<Vec<Option<SuiCoinMetadata>>> {
+        Ok(self.api.http.multi_get_coin_metadata(coin_types).await?)
+    }
+
     pub async fn get_total_supply(&self, coin_type: String) -> SuiRpcResult<Supply> {
         Ok(self.api.http.get_total_supply(coin_type).await?)
     }
@@ -365,6 +513,34 @@ impl EventApi {
             .await?)
     }
 
+    /// Return the events of the transactions in `checkpoint`, in execution order.
+    pub async fn get_events_by_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<EventPage> {
+        Ok(self
+            .api
+            .http
+            .get_events_by_checkpoint(checkpoint, cursor, limit)
+            .await?)
+    }
+
+    /// Return the events emitted by the transaction `digest`, in emission order.
+    pub async fn get_transaction_events(
+        &self,
+        digest: TransactionDigest,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<EventPage> {
+        Ok(self
+            .api
+            .http
+            .get_transaction_events(digest, cursor, limit)
+            .await?)
+    }
+
     pub fn get_events_stream(
         &self,
         query: EventQuery,
@@ -448,6 +624,26 @@ impl QuorumDriver {
         })
     }
 
+    /// Sign `tx_data` with `signer` and execute it, see [QuorumDriver::execute_transaction].
+    /// The signature is verified before the transaction is submitted, so that a misbehaving
+    /// external signer is reported as such.
+    pub async fn sign_and_execute_transaction(
+        &self,
+        tx_data: TransactionData,
+        signer: &dyn Signer,
+        request_type: Option<ExecuteTransactionRequestType>,
+    ) -> SuiRpcResult<SuiTransactionResponse> {
+        let intent_message = IntentMessage::new(Intent::default(), tx_data);
+        let signature = signer
+            .sign(&intent_message)
+            .await
+            .map_err(|e| Error::SigningError(e.to_string()))?;
+        let tx = Transaction::from_data(intent_message.value, Intent::default(), vec![signature])
+            .verify()
+            .map_err(|e| Error::SigningError(e.to_string()))?;
+        self.execute_transaction(tx, request_type).await
+    }
+
     async fn wait_until_fullnode_sees_tx(
         c: &RpcClient,
         tx_digest: TransactionDigest,
@@ -504,6 +700,16 @@ impl GovernanceApi {
         Ok(self.api.http.get_committee_info(epoch).await?)
     }
 
+    /// Return the committees of at most `limit` epochs starting at epoch `cursor`, in ascending
+    /// epoch order. If `cursor` is None, start at the genesis epoch
+    pub async fn get_committees(
+        &self,
+        cursor: Option<EpochId>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<CommitteePage> {
+        Ok(self.api.http.get_committees(cursor, limit).await?)
+    }
+
     /// Return the latest SUI system state object on-chain.
     pub async fn get_latest_sui_system_state(&self) -> SuiRpcResult<SuiSystemStateSummary> {
         Ok(self.api.http.get_latest_sui_system_state().await?)
@@ -513,4 +719,307 @@ impl GovernanceApi {
     pub async fn get_reference_gas_price(&self) -> SuiRpcResult<u64> {
         Ok(self.api.http.get_reference_gas_price().await?)
     }
+
+    /// Return the gas costs of the Move bytecode instructions, native functions and transactions
+    /// at `protocol_version`, default to the version of the latest epoch
+    pub async fn get_gas_cost_table(
+        &self,
+        protocol_version: Option<ProtocolVersion>,
+    ) -> SuiRpcResult<SuiGasCostTable> {
+        Ok(self.api.http.get_gas_cost_table(protocol_version).await?)
+    }
+
+    /// Return the reports of the validators against each other in the current epoch, and the
+    /// validators whose rewards the tallying rule slashes at the end of the epoch
+    pub async fn get_validator_reports(&self) -> SuiRpcResult<SuiValidatorReports> {
+        Ok(self.api.http.get_validator_reports().await?)
+    }
+
+    /// Return the estimated APY of every active validator, derived from the latest SUI system
+    /// state. See [ValidatorApy::estimate].
+    pub async fn get_validators_apy(&self) -> SuiRpcResult<Vec<ValidatorApy>> {
+        let system_state = self.get_latest_sui_system_state().await?;
+        Ok(system_state
+            .active_validators
+            .iter()
+            .map(|validator| ValidatorApy::estimate(validator, system_state.epoch))
+            .collect())
+    }
+}
+
+/// Epochs last a day, the APY of a staking pool is compounded over this many epochs.
+const EPOCHS_PER_YEAR: f64 = 365.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatorApy {
+    pub address: SuiAddress,
+    pub apy: f64,
+}
+
+impl ValidatorApy {
+    /// Estimate the APY of `validator` at `epoch` from the exchange rate of its staking pool.
+    /// Pools start at a 1:1 exchange rate between SUI and pool tokens, so the current rate is the
+    /// growth of a stake held since the pool became active, which is annualized here.
+    pub fn estimate(validator: &SuiValidatorSummary, epoch: EpochId) -> Self {
+        let apy = match validator.staking_pool_activation_epoch {
+            Some(activation_epoch)
+                if epoch > activation_epoch && validator.pool_token_balance > 0 =>
+            {
+                let exchange_rate =
+                    validator.staking_pool_sui_balance as f64 / validator.pool_token_balance as f64;
+                let epochs = (epoch - activation_epoch) as f64;
+                exchange_rate.powf(EPOCHS_PER_YEAR / epochs) - 1.0
+            }
+            _ => 0.0,
+        };
+        Self {
+            address: validator.sui_address,
+            apy,
+        }
+    }
+}
+
+/// Builders for staking transactions against the SUI system package, which select the coins to
+/// stake and, if none is given, the validator to stake with.
+#[derive(Clone)]
+pub struct StakingApi {
+    transaction_builder: TransactionBuilder<Normal>,
+    coin_read_api: CoinReadApi,
+    governance_api: GovernanceApi,
+}
+
+impl StakingApi {
+    pub(crate) fn new(
+        transaction_builder: TransactionBuilder<Normal>,
+        coin_read_api: CoinReadApi,
+        governance_api: GovernanceApi,
+    ) -> Self {
+        Self {
+            transaction_builder,
+            coin_read_api,
+            governance_api,
+        }
+    }
+
+    /// Build a transaction staking `amount` of the signer's SUI. SUI coins covering the amount
+    /// are selected from the signer's unlocked coins, excluding `gas`. When `validator` is
+    /// None, the active validator with the highest estimated APY is selected.
+    pub async fn request_add_stake(
+        &self,
+        signer: SuiAddress,
+        amount: u64,
+        validator: Option<SuiAddress>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> SuiRpcResult<TransactionData> {
+        let validator = match validator {
+            Some(validator) => validator,
+            None => self.select_validator().await?,
+        };
+        let coins = self
+            .coin_read_api
+            .select_coins(
+                signer,
+                None,
+                amount as u128,
+                None,
+                gas.into_iter().collect(),
+            )
+            .await?
+            .into_iter()
+            .map(|coin| coin.coin_object_id)
+            .collect();
+        Ok(self
+            .transaction_builder
+            .request_add_delegation(signer, coins, Some(amount), validator, gas, gas_budget)
+            .await?)
+    }
+
+    /// Build a transaction withdrawing the stake held by `staked_sui`, along with its rewards.
+    pub async fn request_withdraw_stake(
+        &self,
+        signer: SuiAddress,
+        staked_sui: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> SuiRpcResult<TransactionData> {
+        Ok(self
+            .transaction_builder
+            .request_withdraw_stake(signer, staked_sui, gas, gas_budget)
+            .await?)
+    }
+
+    /// Return the active validator with the highest estimated APY.
+    pub async fn select_validator(&self) -> SuiRpcResult<SuiAddress> {
+        self.governance_api
+            .get_validators_apy()
+            .await?
+            .into_iter()
+            .max_by(|a, b| a.apy.total_cmp(&b.apy))
+            .map(|validator| validator.address)
+            .ok_or_else(|| Error::DataError("No active validator to stake with.".into()))
+    }
+}
+
+/// Builders for transactions merging the coins of a type owned by an address, so that recipients
+/// of many small payments (e.g. airdrops) can bring down the number of coins they own. Merges are
+/// chunked across transactions to stay within the transaction input limits.
+#[derive(Clone)]
+pub struct CoinMergeApi {
+    read_api: Arc<ReadApi>,
+    coin_read_api: CoinReadApi,
+    quorum_driver: QuorumDriver,
+}
+
+impl CoinMergeApi {
+    pub(crate) fn new(
+        read_api: Arc<ReadApi>,
+        coin_read_api: CoinReadApi,
+        quorum_driver: QuorumDriver,
+    ) -> Self {
+        Self {
+            read_api,
+            coin_read_api,
+            quorum_driver,
+        }
+    }
+
+    /// Build the next transaction bringing the number of unlocked coins of `coin_type` (SUI if
+    /// not specified) owned by `owner` down towards `target_count`. The `target_count - 1`
+    /// largest coins are kept as is, and the smallest coins are merged into the largest of the
+    /// remaining ones. Returns None once `owner` owns at most `target_count` of these coins.
+    ///
+    /// Each transaction changes the versions of the coins it uses, so the next transaction can
+    /// only be built once the previous one has been executed, see [Self::consolidate_coins].
+    pub async fn build_consolidate_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        target_count: usize,
+        gas_budget: u64,
+    ) -> SuiRpcResult<Option<TransactionData>> {
+        if target_count == 0 {
+            return Err(Error::DataError(
+                "The target number of coins must be at least 1.".into(),
+            ));
+        }
+        let mut coins = self
+            .coin_read_api
+            .get_coins_stream(owner, coin_type)
+            .filter(|coin: &Coin| future::ready(coin.locked_until_epoch.is_none()))
+            .collect::<Vec<_>>()
+            .await;
+        if coins.len() <= target_count {
+            return Ok(None);
+        }
+        coins.sort_by(|a, b| b.balance.cmp(&a.balance));
+        let mut to_merge = coins.split_off(target_count - 1);
+        let primary = to_merge.remove(0);
+
+        let config = ProtocolConfig::get_for_min_version();
+        let gas_price = self.read_api.get_reference_gas_price().await?;
+        let required_gas = gas_budget as u128 * gas_price as u128;
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_payment = if GAS::is_gas(&parse_sui_struct_tag(&primary.coin_type)?) {
+            // SUI coins pay for their own merge: the largest ones are added to the gas payment
+            // until it covers the budget, and are merged into the gas coin by gas smashing.
+            let mut gas_payment = vec![primary.object_ref()];
+            let mut gas_balance = primary.balance as u128;
+            while gas_balance < required_gas
+                && gas_payment.len() < config.max_gas_payment_objects() as usize - 1
+                && !to_merge.is_empty()
+            {
+                let coin = to_merge.remove(0);
+                gas_balance += coin.balance as u128;
+                gas_payment.push(coin.object_ref());
+            }
+            if gas_balance < required_gas {
+                return Err(Error::InsufficientFund {
+                    address: owner,
+                    amount: required_gas,
+                });
+            }
+            if let Some(coins) = smallest_coins_args(&mut builder, to_merge, &config) {
+                builder.command(Command::MergeCoins(Argument::GasCoin, coins));
+            }
+            gas_payment
+        } else {
+            let primary = builder.obj(ObjectArg::ImmOrOwnedObject(primary.object_ref()));
+            if let Some(coins) = smallest_coins_args(&mut builder, to_merge, &config) {
+                builder.command(Command::MergeCoins(primary, coins));
+            }
+            self.coin_read_api
+                .select_coins(owner, None, required_gas, None, vec![])
+                .await?
+                .iter()
+                .map(Coin::object_ref)
+                .collect()
+        };
+
+        Ok(Some(TransactionData::new_programmable(
+            owner,
+            gas_payment,
+            builder.finish(),
+            gas_budget,
+            gas_price,
+        )))
+    }
+
+    /// Merge the coins of `coin_type` (SUI if not specified) owned by `owner` until at most
+    /// `target_count` of them are left, see [Self::build_consolidate_coins]. The transactions
+    /// are signed with `signer` and executed one after the other, their responses are returned
+    /// in execution order.
+    pub async fn consolidate_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        target_count: usize,
+        gas_budget: u64,
+        signer: &dyn Signer,
+    ) -> SuiRpcResult<Vec<SuiTransactionResponse>> {
+        let mut responses = vec![];
+        while let Some(tx_data) = self
+            .build_consolidate_coins(owner, coin_type.clone(), target_count, gas_budget)
+            .await?
+        {
+            let response = self
+                .quorum_driver
+                .sign_and_execute_transaction(
+                    tx_data,
+                    signer,
+                    Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+                )
+                .await?;
+            // Stop on failures, as the same transaction would be built again.
+            if let Some(effects) = &response.effects {
+                if let SuiExecutionStatus::Failure { error, .. } = effects.status() {
+                    return Err(Error::DataError(format!(
+                        "Coin merge transaction {} failed: {error}",
+                        effects.transaction_digest()
+                    )));
+                }
+            }
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+}
+
+/// Add the smallest of `coins` to `builder`, as many as fit in a single merge command.
+fn smallest_coins_args(
+    builder: &mut ProgrammableTransactionBuilder,
+    mut coins: Vec<Coin>,
+    config: &ProtocolConfig,
+) -> Option<Vec<Argument>> {
+    if coins.is_empty() {
+        return None;
+    }
+    coins.reverse();
+    coins.truncate(config.max_arguments() as usize - 1);
+    Some(
+        coins
+            .iter()
+            .map(|coin| builder.obj(ObjectArg::ImmOrOwnedObject(coin.object_ref())))
+            .collect(),
+    )
 }
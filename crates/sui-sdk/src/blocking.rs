@@ -0,0 +1,298 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A blocking variant of [crate::SuiClient], for programs that would otherwise not use
+//! async/await, e.g. CLI tools and scripts.
+//!
+//! The requests run on a runtime owned by the client, so the blocking client must not be used
+//! from within an async context, which would panic.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+
+use sui_json_rpc_types::{
+    Balance, Checkpoint, CheckpointId, CoinPage, CommitteePage, DryRunTransactionResponse,
+    DynamicFieldPage, EventPage, SuiCoinMetadata, SuiCommittee, SuiNodeInfo, SuiObjectDataOptions,
+    SuiObjectInfo, SuiObjectResponse, SuiPastObjectResponse, SuiTransactionResponse,
+    SuiTransactionResponseOptions, SuiValidatorReports, TransactionsPage,
+};
+use sui_keys::signer::Signer;
+use sui_types::balance::Supply;
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDigest};
+use sui_types::committee::EpochId;
+use sui_types::event::EventID;
+use sui_types::governance::DelegatedStake;
+use sui_types::messages::{ExecuteTransactionRequestType, TransactionData, VerifiedTransaction};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+
+use crate::apis;
+use crate::error::{Error, SuiRpcResult};
+
+/// Defines blocking methods running the async methods of the same name of `self.api`.
+macro_rules! blocking_methods {
+    ($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> SuiRpcResult<$ret> {
+                self.runtime.block_on(self.api.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+#[derive(Default)]
+pub struct SuiClientBuilder {
+    builder: crate::SuiClientBuilder,
+}
+
+impl SuiClientBuilder {
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.builder = self.builder.request_timeout(request_timeout);
+        self
+    }
+
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.builder = self
+            .builder
+            .max_concurrent_requests(max_concurrent_requests);
+        self
+    }
+
+    /// See [crate::SuiClientBuilder::min_checkpoint].
+    pub fn min_checkpoint(mut self, checkpoint: CheckpointSequenceNumber) -> Self {
+        self.builder = self.builder.min_checkpoint(checkpoint);
+        self
+    }
+
+    pub fn build(self, http: impl AsRef<str>) -> SuiRpcResult<SuiClient> {
+        // The connections of the client are driven by the worker thread between requests.
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("sui-sdk-blocking")
+            .enable_all()
+            .build()
+            .map_err(Error::Runtime)?;
+        let client = runtime.block_on(self.builder.build(http))?;
+        Ok(SuiClient {
+            runtime: Arc::new(runtime),
+            client,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct SuiClient {
+    runtime: Arc<Runtime>,
+    client: crate::SuiClient,
+}
+
+impl SuiClient {
+    /// The async client the requests are sent with, e.g. to [Self::block_on] the APIs without a
+    /// blocking variant such as the transaction builder.
+    pub fn inner(&self) -> &crate::SuiClient {
+        &self.client
+    }
+
+    /// Run `future` to completion on the runtime of the client.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    pub fn api_version(&self) -> &str {
+        self.client.api_version()
+    }
+
+    pub fn check_api_version(&self) -> SuiRpcResult<()> {
+        self.client.check_api_version()
+    }
+
+    pub fn read_api(&self) -> ReadApi<'_> {
+        ReadApi {
+            runtime: &self.runtime,
+            api: self.client.read_api(),
+        }
+    }
+
+    pub fn coin_read_api(&self) -> CoinReadApi<'_> {
+        CoinReadApi {
+            runtime: &self.runtime,
+            api: self.client.coin_read_api(),
+        }
+    }
+
+    pub fn event_api(&self) -> EventApi<'_> {
+        EventApi {
+            runtime: &self.runtime,
+            api: self.client.event_api(),
+        }
+    }
+
+    pub fn quorum_driver(&self) -> QuorumDriver<'_> {
+        QuorumDriver {
+            runtime: &self.runtime,
+            api: self.client.quorum_driver(),
+        }
+    }
+
+    pub fn governance_api(&self) -> GovernanceApi<'_> {
+        GovernanceApi {
+            runtime: &self.runtime,
+            api: self.client.governance_api(),
+        }
+    }
+}
+
+/// Blocking variant of [apis::ReadApi].
+pub struct ReadApi<'a> {
+    runtime: &'a Runtime,
+    api: &'a apis::ReadApi,
+}
+
+impl ReadApi<'_> {
+    blocking_methods! {
+        fn get_objects_owned_by_address(&self, address: SuiAddress) -> Vec<SuiObjectInfo>;
+        fn get_dynamic_fields(
+            &self,
+            object_id: ObjectID,
+            cursor: Option<ObjectID>,
+            limit: Option<usize>
+        ) -> DynamicFieldPage;
+        fn get_object_with_options(
+            &self,
+            object_id: ObjectID,
+            options: SuiObjectDataOptions
+        ) -> SuiObjectResponse;
+        fn try_multi_get_object_refs(
+            &self,
+            object_ids: Vec<ObjectID>,
+            options: Option<SuiObjectDataOptions>
+        ) -> Vec<SuiObjectResponse>;
+        fn try_get_parsed_past_object(
+            &self,
+            object_id: ObjectID,
+            version: SequenceNumber,
+            options: SuiObjectDataOptions
+        ) -> SuiPastObjectResponse;
+        fn get_total_transaction_number(&self) -> u64;
+        fn get_transaction_with_options(
+            &self,
+            digest: TransactionDigest,
+            options: SuiTransactionResponseOptions
+        ) -> SuiTransactionResponse;
+        fn multi_get_transactions_with_options(
+            &self,
+            digests: Vec<TransactionDigest>,
+            options: SuiTransactionResponseOptions
+        ) -> Vec<SuiTransactionResponse>;
+        fn get_transactions(
+            &self,
+            query: TransactionQuery,
+            cursor: Option<TransactionDigest>,
+            limit: Option<usize>,
+            descending_order: bool
+        ) -> TransactionsPage;
+        fn get_checkpoint(&self, id: CheckpointId) -> Checkpoint;
+        fn get_latest_checkpoint_sequence_number(&self) -> CheckpointSequenceNumber;
+        fn get_node_info(&self) -> SuiNodeInfo;
+        fn get_reference_gas_price(&self) -> u64;
+        fn dry_run_transaction(&self, tx: TransactionData) -> DryRunTransactionResponse;
+    }
+}
+
+/// Blocking variant of [apis::CoinReadApi].
+pub struct CoinReadApi<'a> {
+    runtime: &'a Runtime,
+    api: &'a apis::CoinReadApi,
+}
+
+impl CoinReadApi<'_> {
+    blocking_methods! {
+        fn get_coins(
+            &self,
+            owner: SuiAddress,
+            coin_type: Option<String>,
+            cursor: Option<ObjectID>,
+            limit: Option<usize>
+        ) -> CoinPage;
+        fn get_all_coins(
+            &self,
+            owner: SuiAddress,
+            cursor: Option<ObjectID>,
+            limit: Option<usize>
+        ) -> CoinPage;
+        fn get_balance(&self, owner: SuiAddress, coin_type: Option<String>) -> Balance;
+        fn get_all_balances(&self, owner: SuiAddress) -> Vec<Balance>;
+        fn get_coin_metadata(&self, coin_type: String) -> SuiCoinMetadata;
+        fn get_total_supply(&self, coin_type: String) -> Supply;
+    }
+}
+
+/// Blocking variant of [apis::EventApi], without subscriptions.
+pub struct EventApi<'a> {
+    runtime: &'a Runtime,
+    api: &'a apis::EventApi,
+}
+
+impl EventApi<'_> {
+    blocking_methods! {
+        fn get_events(
+            &self,
+            query: EventQuery,
+            cursor: Option<EventID>,
+            limit: Option<usize>,
+            descending_order: bool
+        ) -> EventPage;
+        fn get_transaction_events(
+            &self,
+            digest: TransactionDigest,
+            cursor: Option<EventID>,
+            limit: Option<usize>
+        ) -> EventPage;
+    }
+}
+
+/// Blocking variant of [apis::QuorumDriver].
+pub struct QuorumDriver<'a> {
+    runtime: &'a Runtime,
+    api: &'a apis::QuorumDriver,
+}
+
+impl QuorumDriver<'_> {
+    blocking_methods! {
+        /// See [apis::QuorumDriver::execute_transaction].
+        fn execute_transaction(
+            &self,
+            tx: VerifiedTransaction,
+            request_type: Option<ExecuteTransactionRequestType>
+        ) -> SuiTransactionResponse;
+        /// See [apis::QuorumDriver::sign_and_execute_transaction].
+        fn sign_and_execute_transaction(
+            &self,
+            tx_data: TransactionData,
+            signer: &dyn Signer,
+            request_type: Option<ExecuteTransactionRequestType>
+        ) -> SuiTransactionResponse;
+    }
+}
+
+/// Blocking variant of [apis::GovernanceApi].
+pub struct GovernanceApi<'a> {
+    runtime: &'a Runtime,
+    api: &'a apis::GovernanceApi,
+}
+
+impl GovernanceApi<'_> {
+    blocking_methods! {
+        fn get_delegated_stakes(&self, owner: SuiAddress) -> Vec<DelegatedStake>;
+        fn get_committee_info(&self, epoch: Option<EpochId>) -> SuiCommittee;
+        fn get_committees(&self, cursor: Option<EpochId>, limit: Option<usize>) -> CommitteePage;
+        fn get_latest_sui_system_state(&self) -> SuiSystemStateSummary;
+        fn get_reference_gas_price(&self) -> u64;
+        fn get_validator_reports(&self) -> SuiValidatorReports;
+    }
+}
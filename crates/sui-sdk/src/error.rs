@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use jsonrpsee::types::error::CallError;
+use sui_json_rpc_types::SuiRpcErrorData;
 use sui_types::base_types::{SuiAddress, TransactionDigest};
 use sui_types::error::UserInputError;
 use thiserror::Error;
@@ -15,6 +17,8 @@ pub enum Error {
     PcsSerialisationError(#[from] bcs::Error),
     #[error(transparent)]
     UserInputError(#[from] UserInputError),
+    #[error(transparent)]
+    TransactionBuilderError(#[from] anyhow::Error),
     #[error("Subscription error : {0}")]
     Subscription(String),
     #[error("Encountered error when confirming tx status for {0:?}, err: {1:?}")]
@@ -23,6 +27,10 @@ pub enum Error {
     FailToConfirmTransactionStatus(TransactionDigest, u64),
     #[error("Data error: {0}")]
     DataError(String),
+    #[error("MultiSig error: {0}")]
+    MultiSigError(String),
+    #[error("Signing error: {0}")]
+    SigningError(String),
     #[error("Client/Server api version mismatch, client api version : {client_version}, server api version : {server_version}")]
     ServerVersionMismatch {
         client_version: String,
@@ -30,4 +38,22 @@ pub enum Error {
     },
     #[error("Insufficient fund for address [{address}], requested amount: {amount}")]
     InsufficientFund { address: SuiAddress, amount: u128 },
+    #[error("Failed to start the runtime of the blocking client: {0}")]
+    Runtime(std::io::Error),
+}
+
+impl Error {
+    /// The machine-readable description of a failed RPC call, if the server sent one.
+    pub fn rpc_error_data(&self) -> Option<SuiRpcErrorData> {
+        match self {
+            Error::RpcError(jsonrpsee::core::Error::Call(CallError::Custom(error)))
+            | Error::TransactionConfirmationError(
+                _,
+                jsonrpsee::core::Error::Call(CallError::Custom(error)),
+            ) => error
+                .data()
+                .and_then(|data| serde_json::from_str(data.get()).ok()),
+            _ => None,
+        }
+    }
 }
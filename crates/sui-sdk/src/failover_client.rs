@@ -0,0 +1,307 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A JSON-RPC client spreading its requests over an ordered list of fullnodes, see
+//! [FailoverRpcClient].
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use jsonrpsee::core::client::{BatchResponse, ClientT};
+use jsonrpsee::core::params::BatchRequestBuilder;
+use jsonrpsee::core::traits::ToRpcParams;
+use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::rpc_params;
+use jsonrpsee::types::error::CallError;
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+use sui_json_rpc_types::INTERNAL_ERROR_CODE;
+use tracing::warn;
+
+use crate::error::{Error, SuiRpcResult};
+use crate::sdk_headers;
+
+/// Methods executing transactions. A signed transaction executes at most once, so it can be
+/// submitted again to another fullnode when the outcome of a submission is unknown, but errors
+/// returned by a fullnode that handled the request are final.
+const EXECUTE_METHODS: &[&str] = &[
+    "sui_executeTransaction",
+    "sui_executeTransactionSerializedSig",
+    "sui_submitTransaction",
+];
+
+pub struct FailoverRpcClientBuilder {
+    request_timeout: Duration,
+    max_concurrent_requests: usize,
+    unhealthy_period: Duration,
+}
+
+impl Default for FailoverRpcClientBuilder {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(60),
+            max_concurrent_requests: 256,
+            unhealthy_period: Duration::from_secs(30),
+        }
+    }
+}
+
+impl FailoverRpcClientBuilder {
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
+    /// Time a fullnode is only used as a last resort after it failed a request.
+    pub fn unhealthy_period(mut self, unhealthy_period: Duration) -> Self {
+        self.unhealthy_period = unhealthy_period;
+        self
+    }
+
+    /// A client of the fullnodes at `urls`, in order of preference.
+    pub fn build(
+        self,
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> SuiRpcResult<FailoverRpcClient> {
+        let endpoints = urls
+            .into_iter()
+            .map(|url| {
+                let client = HttpClientBuilder::default()
+                    .max_request_body_size(2 << 30)
+                    .max_concurrent_requests(self.max_concurrent_requests)
+                    .set_headers(sdk_headers())
+                    .request_timeout(self.request_timeout)
+                    .build(url.as_ref())?;
+                Ok(Endpoint {
+                    url: url.as_ref().to_string(),
+                    client,
+                    unhealthy_since: Mutex::new(None),
+                })
+            })
+            .collect::<SuiRpcResult<Vec<_>>>()?;
+        if endpoints.is_empty() {
+            return Err(Error::DataError(
+                "At least one fullnode URL is required".into(),
+            ));
+        }
+        Ok(FailoverRpcClient {
+            endpoints,
+            unhealthy_period: self.unhealthy_period,
+        })
+    }
+}
+
+/// JSON-RPC client of an ordered list of fullnodes. Requests go to the first healthy fullnode,
+/// and fail over to the next ones on connection errors, timeouts, HTTP error statuses and
+/// internal server errors. A fullnode failing a request is marked unhealthy and is only used as
+/// a last resort until its unhealthy period ends or [FailoverRpcClient::check_health] finds it
+/// healthy again.
+///
+/// Reads are retried on every fullnode. Transaction executions are only retried when the
+/// fullnode did not answer, as a transaction executed once is not executed again. Batch requests
+/// are not retried, as they may mix both.
+///
+/// The typed methods of the API are available through [crate::json_rpc_client::SuiJsonRpcClient].
+pub struct FailoverRpcClient {
+    endpoints: Vec<Endpoint>,
+    unhealthy_period: Duration,
+}
+
+struct Endpoint {
+    url: String,
+    client: HttpClient,
+    unhealthy_since: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    fn is_healthy(&self, unhealthy_period: Duration) -> bool {
+        let unhealthy_since = self
+            .unhealthy_since
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        unhealthy_since.map_or(true, |since| since.elapsed() >= unhealthy_period)
+    }
+
+    fn set_healthy(&self, healthy: bool) {
+        let mut unhealthy_since = self
+            .unhealthy_since
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        *unhealthy_since = (!healthy).then(Instant::now);
+    }
+}
+
+impl Debug for FailoverRpcClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FailoverRpcClient")
+            .field(
+                "endpoints",
+                &self.endpoints.iter().map(|e| &e.url).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl FailoverRpcClient {
+    /// URLs of the fullnodes currently considered healthy, in order of preference.
+    pub fn healthy_endpoints(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .filter(|e| e.is_healthy(self.unhealthy_period))
+            .map(|e| e.url.as_str())
+            .collect()
+    }
+
+    /// Queries every fullnode for its latest checkpoint and updates their health accordingly.
+    /// Returns the number of healthy fullnodes.
+    pub async fn check_health(&self) -> usize {
+        let checks = self.endpoints.iter().map(|endpoint| async move {
+            let healthy = endpoint
+                .client
+                .request::<u64, _>("sui_getLatestCheckpointSequenceNumber", rpc_params![])
+                .await
+                .is_ok();
+            endpoint.set_healthy(healthy);
+            healthy
+        });
+        futures::future::join_all(checks)
+            .await
+            .into_iter()
+            .filter(|healthy| *healthy)
+            .count()
+    }
+
+    /// Endpoints in the order they are tried: healthy ones first, then the unhealthy ones as a
+    /// last resort, each group in order of preference.
+    fn ordered_endpoints(&self) -> impl Iterator<Item = &Endpoint> {
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = self
+            .endpoints
+            .iter()
+            .partition(|e| e.is_healthy(self.unhealthy_period));
+        healthy.into_iter().chain(unhealthy)
+    }
+}
+
+#[async_trait]
+impl ClientT for FailoverRpcClient {
+    async fn notification<Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<(), jsonrpsee::core::Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams(params.to_rpc_params()?);
+        let mut last_error = None;
+        for endpoint in self.ordered_endpoints() {
+            match endpoint.client.notification(method, params.clone()).await {
+                Ok(()) => {
+                    endpoint.set_healthy(true);
+                    return Ok(());
+                }
+                Err(e) if should_fail_over(method, &e) => {
+                    endpoint.set_healthy(false);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("There is at least one endpoint"))
+    }
+
+    async fn request<R, Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<R, jsonrpsee::core::Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams(params.to_rpc_params()?);
+        let mut last_error = None;
+        for endpoint in self.ordered_endpoints() {
+            match endpoint.client.request(method, params.clone()).await {
+                Ok(response) => {
+                    endpoint.set_healthy(true);
+                    return Ok(response);
+                }
+                Err(e) if should_fail_over(method, &e) => {
+                    warn!(
+                        "Request {method} to fullnode {} failed, failing over: {e}",
+                        endpoint.url
+                    );
+                    endpoint.set_healthy(false);
+                    last_error = Some(e);
+                }
+                Err(e) => {
+                    endpoint.set_healthy(true);
+                    return Err(e);
+                }
+            }
+        }
+        Err(last_error.expect("There is at least one endpoint"))
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, jsonrpsee::core::Error>
+    where
+        R: DeserializeOwned + Debug + 'a,
+    {
+        let endpoint = self
+            .ordered_endpoints()
+            .next()
+            .expect("There is at least one endpoint");
+        let result = endpoint.client.batch_request(batch).await;
+        if let Err(e) = &result {
+            endpoint.set_healthy(!is_unavailable(e));
+        }
+        result
+    }
+}
+
+/// Whether the request may be sent to the next fullnode after failing with `error`.
+fn should_fail_over(method: &str, error: &jsonrpsee::core::Error) -> bool {
+    if EXECUTE_METHODS.contains(&method) {
+        // The fullnode may have received the transaction, it is submitted again if it did not
+        // answer, and the error it answered with otherwise is final.
+        is_unavailable(error)
+    } else {
+        is_unavailable(error)
+            || matches!(
+                error,
+                jsonrpsee::core::Error::Call(CallError::Custom(e)) if e.code() == INTERNAL_ERROR_CODE
+            )
+    }
+}
+
+/// Whether `error` means the fullnode did not handle the request: connection errors, HTTP error
+/// statuses such as 5xx or 429, and timeouts.
+fn is_unavailable(error: &jsonrpsee::core::Error) -> bool {
+    matches!(
+        error,
+        jsonrpsee::core::Error::Transport(_)
+            | jsonrpsee::core::Error::RequestTimeout
+            | jsonrpsee::core::Error::RestartNeeded(_)
+    )
+}
+
+/// Parameters serialized once, and sent as is to each fullnode tried.
+#[derive(Clone)]
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
@@ -0,0 +1,475 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pool of gas coins owned by a sponsor address, used to pay for the transactions of other
+//! addresses, see [GasStation].
+
+use std::collections::BTreeMap;
+use std::future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use sui_json_rpc_types::{
+    Coin, SuiExecutionStatus, SuiTransactionEffectsAPI, SuiTransactionResponse,
+};
+use sui_keys::signer::Signer;
+use sui_protocol_config::ProtocolConfig;
+use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress};
+use sui_types::committee::EpochId;
+use sui_types::crypto::Signature;
+use sui_types::intent::{Intent, IntentMessage};
+use sui_types::messages::{
+    Argument, Command, ExecuteTransactionRequestType, GasData, SingleTransactionKind, Transaction,
+    TransactionData, TransactionDataAPI, TransactionExpiration, TransactionKind,
+};
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+
+use crate::error::{Error, SuiRpcResult};
+use crate::SuiClient;
+
+#[derive(Debug, Clone)]
+pub struct GasStationConfig {
+    /// Gas budget of the sponsored transactions, and of the rebalancing transactions.
+    pub gas_budget: u64,
+    /// Balance of the coins created when rebalancing the pool.
+    pub target_coin_balance: u64,
+    /// Number of coins the pool is rebalanced to.
+    pub pool_size: usize,
+    /// Time given to the sender to sign and execute a sponsored transaction, after which its
+    /// gas coin is quarantined until the end of the epoch.
+    pub lease_duration: Duration,
+}
+
+impl Default for GasStationConfig {
+    fn default() -> Self {
+        Self {
+            gas_budget: 10_000,
+            target_coin_balance: 100_000_000,
+            pool_size: 10,
+            lease_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+/// A transaction paid for by the sponsor of a [GasStation], waiting for the signature of its
+/// sender.
+#[derive(Debug, Clone)]
+pub struct SponsoredTransaction {
+    pub tx_data: TransactionData,
+    pub sponsor_signature: Signature,
+}
+
+struct Lease {
+    coin: ObjectRef,
+    balance: u64,
+    epoch: EpochId,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct GasPool {
+    available: BTreeMap<ObjectID, (ObjectRef, u64)>,
+    leased: BTreeMap<ObjectID, Lease>,
+    /// Coins whose current version was signed for a transaction which may still be executed,
+    /// with the epoch the transaction expires at. Using them for another transaction in that
+    /// epoch could equivocate and lock them until the end of the epoch.
+    quarantined: BTreeMap<ObjectID, (SequenceNumber, EpochId)>,
+}
+
+impl GasPool {
+    fn expire_leases(&mut self) {
+        let now = Instant::now();
+        let expired = self
+            .leased
+            .iter()
+            .filter(|(_, lease)| lease.expires_at <= now)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        for id in expired {
+            self.quarantine(id);
+        }
+    }
+
+    fn lease(
+        &mut self,
+        required_balance: u64,
+        epoch: EpochId,
+        duration: Duration,
+    ) -> Option<ObjectRef> {
+        self.expire_leases();
+        // The smallest coin covering the budget, to keep the large ones for large budgets.
+        let (id, (coin, balance)) = self
+            .available
+            .iter()
+            .filter(|(_, (_, balance))| *balance >= required_balance)
+            .min_by_key(|(_, (_, balance))| *balance)
+            .map(|(id, coin)| (*id, *coin))?;
+        self.available.remove(&id);
+        self.leased.insert(
+            id,
+            Lease {
+                coin,
+                balance,
+                epoch,
+                expires_at: Instant::now() + duration,
+            },
+        );
+        Some(coin)
+    }
+
+    /// Returns a leased coin which was not signed for a transaction to the pool.
+    fn cancel(&mut self, id: &ObjectID) {
+        if let Some(lease) = self.leased.remove(id) {
+            self.available.insert(*id, (lease.coin, lease.balance));
+        }
+    }
+
+    /// Returns a leased coin to the pool, with its version after the execution of the transaction
+    /// it paid for.
+    fn release(&mut self, coin: ObjectRef, balance: u64) {
+        self.leased.remove(&coin.0);
+        self.quarantined.remove(&coin.0);
+        self.available.insert(coin.0, (coin, balance));
+    }
+
+    fn quarantine(&mut self, id: ObjectID) {
+        if let Some(lease) = self.leased.remove(&id) {
+            self.quarantined.insert(id, (lease.coin.1, lease.epoch));
+        }
+    }
+}
+
+/// Manages a pool of SUI coins owned by a sponsor address, to pay for the gas of transactions of
+/// other addresses.
+///
+/// Each sponsored transaction leases a coin of the pool, which is returned with its new version
+/// once the transaction is executed through [GasStation::execute_sponsored]. A coin is never
+/// signed for two transactions at the same version: the sponsored transactions expire at the end
+/// of the epoch they are signed in, and the coins of the transactions that were not executed
+/// within the lease duration, or whose execution failed, are quarantined until their version
+/// changes or the epoch ends.
+pub struct GasStation {
+    client: SuiClient,
+    sponsor: Arc<dyn Signer>,
+    config: GasStationConfig,
+    pool: Mutex<GasPool>,
+}
+
+impl GasStation {
+    /// Creates a gas station paying with the coins of `sponsor`, the pool is loaded by
+    /// [Self::refresh] or [Self::rebalance].
+    pub fn new(client: SuiClient, sponsor: Arc<dyn Signer>, config: GasStationConfig) -> Self {
+        Self {
+            client,
+            sponsor,
+            config,
+            pool: Mutex::new(GasPool::default()),
+        }
+    }
+
+    pub fn sponsor(&self) -> SuiAddress {
+        self.sponsor.address()
+    }
+
+    /// Number of coins that can be leased.
+    pub fn available_coins(&self) -> usize {
+        self.pool.lock().unwrap().available.len()
+    }
+
+    async fn current_epoch(&self) -> SuiRpcResult<EpochId> {
+        Ok(self
+            .client
+            .governance_api()
+            .get_latest_sui_system_state()
+            .await?
+            .epoch)
+    }
+
+    /// Reloads the pool from the SUI coins owned by the sponsor. Leased coins are left out, and
+    /// quarantined coins are released once their version changed or their epoch ended.
+    pub async fn refresh(&self) -> SuiRpcResult<()> {
+        let epoch = self.current_epoch().await?;
+        let coins = self
+            .client
+            .coin_read_api()
+            .get_coins_stream(self.sponsor(), None)
+            .filter(|coin: &Coin| future::ready(coin.locked_until_epoch.is_none()))
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut pool = self.pool.lock().unwrap();
+        pool.expire_leases();
+        pool.quarantined.retain(|id, (version, expiration)| {
+            *expiration >= epoch
+                && coins
+                    .iter()
+                    .any(|coin| coin.coin_object_id == *id && coin.version == *version)
+        });
+        let available = coins
+            .iter()
+            .filter(|coin| {
+                !pool.leased.contains_key(&coin.coin_object_id)
+                    && !pool.quarantined.contains_key(&coin.coin_object_id)
+            })
+            .map(|coin| (coin.coin_object_id, (coin.object_ref(), coin.balance)))
+            .collect();
+        pool.available = available;
+        Ok(())
+    }
+
+    /// Brings the pool back to `pool_size` coins of at least `target_coin_balance`: the coins
+    /// below the target are merged into the largest coin, which is split into new coins of the
+    /// target balance. Returns the response of the rebalancing transaction, None if the pool was
+    /// already balanced.
+    pub async fn rebalance(&self) -> SuiRpcResult<Option<SuiTransactionResponse>> {
+        self.refresh().await?;
+        let epoch = self.current_epoch().await?;
+        let gas_price = self.client.read_api().get_reference_gas_price().await?;
+        let required_gas = self.config.gas_budget as u128 * gas_price as u128;
+        let target = self.config.target_coin_balance;
+        let max_gas_payment_objects =
+            ProtocolConfig::get_for_min_version().max_gas_payment_objects() as usize;
+
+        let (payment, pt) = {
+            let mut pool = self.pool.lock().unwrap();
+            let mut coins = pool.available.values().copied().collect::<Vec<_>>();
+            coins.sort_by(|(_, a), (_, b)| b.cmp(a));
+            let Some((largest, rest)) = coins.split_first() else {
+                return Err(Error::InsufficientFund {
+                    address: self.sponsor(),
+                    amount: required_gas,
+                });
+            };
+            let ready = rest
+                .iter()
+                .filter(|(_, balance)| *balance >= target)
+                .count();
+            let new_coins = self.config.pool_size.saturating_sub(ready + 1);
+            let dust = rest.iter().filter(|(_, balance)| *balance < target);
+            // The dust coins are merged into the largest one by gas smashing.
+            let payment = std::iter::once(largest)
+                .chain(dust)
+                .take(max_gas_payment_objects)
+                .copied()
+                .collect::<Vec<_>>();
+            if new_coins == 0 && payment.len() == 1 {
+                return Ok(None);
+            }
+            let required = required_gas + new_coins as u128 * target as u128;
+            let balance = payment
+                .iter()
+                .map(|(_, balance)| *balance as u128)
+                .sum::<u128>();
+            if balance < required {
+                return Err(Error::InsufficientFund {
+                    address: self.sponsor(),
+                    amount: required,
+                });
+            }
+            let mut builder = ProgrammableTransactionBuilder::new();
+            builder.pay_sui(vec![self.sponsor(); new_coins], vec![target; new_coins])?;
+            // The coins are leased for the duration of the transaction, so that they are not
+            // handed out to sponsored transactions meanwhile.
+            for (coin, balance) in &payment {
+                pool.available.remove(&coin.0);
+                pool.leased.insert(
+                    coin.0,
+                    Lease {
+                        coin: *coin,
+                        balance: *balance,
+                        epoch,
+                        expires_at: Instant::now() + self.config.lease_duration,
+                    },
+                );
+            }
+            (payment, builder.finish())
+        };
+
+        let mut tx_data = TransactionData::new_programmable(
+            self.sponsor(),
+            payment.iter().map(|(coin, _)| *coin).collect(),
+            pt,
+            self.config.gas_budget,
+            gas_price,
+        );
+        *tx_data.expiration_mut() = TransactionExpiration::Epoch(epoch);
+        let result = self
+            .client
+            .quorum_driver()
+            .sign_and_execute_transaction(
+                tx_data,
+                self.sponsor.as_ref(),
+                Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await;
+        {
+            let mut pool = self.pool.lock().unwrap();
+            for (coin, _) in &payment {
+                if result.is_ok() {
+                    pool.leased.remove(&coin.0);
+                } else {
+                    pool.quarantine(coin.0);
+                }
+            }
+        }
+        let response = result?;
+        self.refresh().await?;
+        if let Some(effects) = &response.effects {
+            if let SuiExecutionStatus::Failure { error, .. } = effects.status() {
+                return Err(Error::DataError(format!(
+                    "Failed to rebalance the gas pool: {error}"
+                )));
+            }
+        }
+        Ok(Some(response))
+    }
+
+    /// Pays the gas of a transaction of kind `tx_kind` sent by `sender` with a coin of the pool.
+    /// The returned transaction is signed by the sponsor, and expires at the end of the current
+    /// epoch. Once signed by `sender`, it should be executed with [Self::execute_sponsored] to
+    /// return the coin to the pool.
+    ///
+    /// Transactions spending the gas coin, e.g. transferring SUI from it, are rejected.
+    pub async fn sponsor_transaction(
+        &self,
+        tx_kind: TransactionKind,
+        sender: SuiAddress,
+    ) -> SuiRpcResult<SponsoredTransaction> {
+        let sponsor = self.sponsor();
+        if sender == sponsor {
+            return Err(Error::DataError(format!(
+                "The sender {sender} is the sponsor of the gas station."
+            )));
+        }
+        if uses_gas_coin(&tx_kind) {
+            return Err(Error::DataError(
+                "Sponsored transactions cannot use the gas coin.".into(),
+            ));
+        }
+        let epoch = self.current_epoch().await?;
+        let gas_price = self.client.read_api().get_reference_gas_price().await?;
+        let required_gas = self.config.gas_budget as u128 * gas_price as u128;
+        let coin = self
+            .pool
+            .lock()
+            .unwrap()
+            .lease(
+                u64::try_from(required_gas).unwrap_or(u64::MAX),
+                epoch,
+                self.config.lease_duration,
+            )
+            .ok_or(Error::InsufficientFund {
+                address: sponsor,
+                amount: required_gas,
+            })?;
+
+        let mut tx_data = TransactionData::new_with_gas_data(
+            tx_kind,
+            sender,
+            GasData {
+                payment: vec![coin],
+                owner: sponsor,
+                price: gas_price,
+                budget: self.config.gas_budget,
+            },
+        );
+        *tx_data.expiration_mut() = TransactionExpiration::Epoch(epoch);
+        let intent_message = IntentMessage::new(Intent::default(), tx_data);
+        match self.sponsor.sign(&intent_message).await {
+            Ok(sponsor_signature) => Ok(SponsoredTransaction {
+                tx_data: intent_message.value,
+                sponsor_signature,
+            }),
+            Err(e) => {
+                self.pool.lock().unwrap().cancel(&coin.0);
+                Err(Error::SigningError(e.to_string()))
+            }
+        }
+    }
+
+    /// Executes a transaction returned by [Self::sponsor_transaction] with the signature of its
+    /// sender, and returns its gas coin to the pool. The coin is quarantined if the transaction
+    /// could not be executed.
+    pub async fn execute_sponsored(
+        &self,
+        sponsored: SponsoredTransaction,
+        sender_signature: Signature,
+    ) -> SuiRpcResult<SuiTransactionResponse> {
+        let coin = sponsored.tx_data.gas()[0];
+        let result = self.execute(sponsored, sender_signature).await;
+        let mut pool = self.pool.lock().unwrap();
+        match result.as_ref().map(|response| &response.effects) {
+            Ok(Some(effects)) => {
+                let balance = pool
+                    .leased
+                    .get(&coin.0)
+                    .map(|lease| lease.balance)
+                    .unwrap_or_default();
+                let gas_used = effects.gas_used().net_gas_usage_improved();
+                let balance = u64::try_from(balance as i64 - gas_used).unwrap_or_default();
+                pool.release(effects.gas_object().reference.to_object_ref(), balance);
+            }
+            // Without effects the new version of the coin is unknown, it is reloaded by the next
+            // refresh once its lease expired.
+            Ok(None) => (),
+            Err(_) => pool.quarantine(coin.0),
+        }
+        result
+    }
+
+    async fn execute(
+        &self,
+        sponsored: SponsoredTransaction,
+        sender_signature: Signature,
+    ) -> SuiRpcResult<SuiTransactionResponse> {
+        let tx = Transaction::new_sponsored(
+            sponsored.tx_data,
+            Intent::default(),
+            sender_signature.into(),
+            sponsored.sponsor_signature.into(),
+        )
+        .and_then(|tx| tx.verify())
+        .map_err(|e| Error::SigningError(e.to_string()))?;
+        self.client
+            .quorum_driver()
+            .execute_transaction(
+                tx,
+                Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await
+    }
+}
+
+/// Whether the transaction could spend the gas coin of its sponsor.
+fn uses_gas_coin(tx_kind: &TransactionKind) -> bool {
+    tx_kind.single_transactions().any(|tx| match tx {
+        SingleTransactionKind::ProgrammableTransaction(pt) => {
+            pt.commands.iter().any(|command| match command {
+                Command::MoveCall(call) => call.arguments.contains(&Argument::GasCoin),
+                Command::TransferObjects(objects, recipient) => {
+                    objects.contains(&Argument::GasCoin) || *recipient == Argument::GasCoin
+                }
+                Command::SplitCoin(coin, amount) => {
+                    *coin == Argument::GasCoin || *amount == Argument::GasCoin
+                }
+                Command::MergeCoins(coin, coins) => {
+                    *coin == Argument::GasCoin || coins.contains(&Argument::GasCoin)
+                }
+                Command::MakeMoveVec(_, elements) => elements.contains(&Argument::GasCoin),
+                Command::Upgrade(_, _, _, ticket) => *ticket == Argument::GasCoin,
+                Command::Publish(_) => false,
+            })
+        }
+        SingleTransactionKind::TransferSui(_)
+        | SingleTransactionKind::PaySui(_)
+        | SingleTransactionKind::PayAllSui(_) => true,
+        SingleTransactionKind::TransferObject(_)
+        | SingleTransactionKind::Publish(_)
+        | SingleTransactionKind::Call(_)
+        | SingleTransactionKind::Pay(_)
+        | SingleTransactionKind::ChangeEpoch(_)
+        | SingleTransactionKind::Genesis(_)
+        | SingleTransactionKind::ConsensusCommitPrologue(_) => false,
+    })
+}
@@ -0,0 +1,841 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed client of the Sui JSON-RPC API, generated from the OpenRPC document with
+//! `cargo -q run --example generate-json-rpc-spec -- record`, do not edit.
+
+use async_trait::async_trait;
+use fastcrypto::encoding::Base64;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::core::Error;
+use jsonrpsee::rpc_params;
+use std::collections::BTreeMap;
+use sui_json::SuiJsonValue;
+use sui_json_rpc_types::Balance;
+use sui_json_rpc_types::BatchTransferParams;
+use sui_json_rpc_types::BigInt;
+use sui_json_rpc_types::Checkpoint;
+use sui_json_rpc_types::CheckpointAttestation;
+use sui_json_rpc_types::CheckpointId;
+use sui_json_rpc_types::CoinPage;
+use sui_json_rpc_types::CommitteePage;
+use sui_json_rpc_types::DevInspectResults;
+use sui_json_rpc_types::DryRunTransactionResponse;
+use sui_json_rpc_types::DynamicFieldPage;
+use sui_json_rpc_types::EventPage;
+use sui_json_rpc_types::MoveFunctionArgType;
+use sui_json_rpc_types::RPCTransactionRequestParams;
+use sui_json_rpc_types::SuiCoinMetadata;
+use sui_json_rpc_types::SuiCommittee;
+use sui_json_rpc_types::SuiGasCostTable;
+use sui_json_rpc_types::SuiMoveNormalizedFunction;
+use sui_json_rpc_types::SuiMoveNormalizedModule;
+use sui_json_rpc_types::SuiMoveNormalizedStruct;
+use sui_json_rpc_types::SuiMoveStructLayout;
+use sui_json_rpc_types::SuiNodeInfo;
+use sui_json_rpc_types::SuiObjectDataOptions;
+use sui_json_rpc_types::SuiObjectInfo;
+use sui_json_rpc_types::SuiObjectResponse;
+use sui_json_rpc_types::SuiPastObjectResponse;
+use sui_json_rpc_types::SuiTBlsSignObjectCommitmentType;
+use sui_json_rpc_types::SuiTBlsSignRandomnessObjectResponse;
+use sui_json_rpc_types::SuiTransactionBuilderMode;
+use sui_json_rpc_types::SuiTransactionResponse;
+use sui_json_rpc_types::SuiTransactionResponseOptions;
+use sui_json_rpc_types::SuiTransactionSubmissionStatus;
+use sui_json_rpc_types::SuiTypeTag;
+use sui_json_rpc_types::SuiValidatorReports;
+use sui_json_rpc_types::TransactionBytes;
+use sui_json_rpc_types::TransactionsPage;
+use sui_protocol_config::ProtocolVersion;
+use sui_types::balance::Supply;
+use sui_types::base_types::ObjectID;
+use sui_types::base_types::SequenceNumber;
+use sui_types::base_types::SuiAddress;
+use sui_types::base_types::TransactionDigest;
+use sui_types::dynamic_field::DynamicFieldName;
+use sui_types::event::EventID;
+use sui_types::governance::DelegatedStake;
+use sui_types::messages::ExecuteTransactionRequestType;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::query::EventQuery;
+use sui_types::query::TransactionQuery;
+use sui_types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+
+/// Methods of the Sui JSON-RPC API, available on any JSON-RPC client.
+#[async_trait]
+pub trait SuiJsonRpcClient: ClientT + Sync {
+    /// Create the unsigned PaySui transactions paying `amounts` to `recipients` with SUI coins of the signer, selected largest first. Locked coins are never selected. The payments are split, in order, across as many transactions as needed to use at most `max_input_coins` coins per transaction, each paying its own gas.
+    async fn auto_pay_sui(
+        &self,
+        signer: SuiAddress,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<BigInt>,
+        max_input_coins: Option<usize>,
+        gas_budget: u64,
+    ) -> Result<Vec<TransactionBytes>, Error> {
+        self.request(
+            "sui_autoPaySui",
+            rpc_params![signer, recipients, amounts, max_input_coins, gas_budget],
+        )
+        .await
+    }
+
+    /// Create an unsigned batched transaction.
+    async fn batch_transaction(
+        &self,
+        signer: SuiAddress,
+        single_transaction_params: Vec<RPCTransactionRequestParams>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+        txn_builder_mode: Option<SuiTransactionBuilderMode>,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_batchTransaction",
+            rpc_params![
+                signer,
+                single_transaction_params,
+                gas,
+                gas_budget,
+                txn_builder_mode
+            ],
+        )
+        .await
+    }
+
+    /// Create an unsigned programmable transaction performing many transfers at once. Each transfer either sends an object of the signer, or an amount of SUI split from the gas coin, to a recipient.
+    async fn batch_transfer(
+        &self,
+        signer: SuiAddress,
+        transfers: Vec<BatchTransferParams>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_batchTransfer",
+            rpc_params![signer, transfers, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Runs the transaction in dev-inspect mode. Which allows for nearly any transaction (or Move call) with any arguments. Detailed results are provided, including both the transaction effects and any return values.
+    async fn dev_inspect_transaction(
+        &self,
+        sender_address: SuiAddress,
+        tx_bytes: Base64,
+        gas_price: Option<u64>,
+        epoch: Option<u64>,
+    ) -> Result<DevInspectResults, Error> {
+        self.request(
+            "sui_devInspectTransaction",
+            rpc_params![sender_address, tx_bytes, gas_price, epoch],
+        )
+        .await
+    }
+
+    /// Return transaction execution effects including the gas cost summary, while the effects are not committed to the chain.
+    async fn dry_run_transaction(
+        &self,
+        tx_bytes: Base64,
+    ) -> Result<DryRunTransactionResponse, Error> {
+        self.request("sui_dryRunTransaction", rpc_params![tx_bytes])
+            .await
+    }
+
+    /// Enqueue the transaction for execution and return its digest without waiting for finality. The node keeps submitting the transaction to validators, also across restarts, and the client polls `sui_getTransactionSubmissionStatus` with the digest for the result.
+    async fn enqueue_transaction(
+        &self,
+        tx_bytes: Base64,
+        signatures: Vec<Base64>,
+    ) -> Result<TransactionDigest, Error> {
+        self.request("sui_enqueueTransaction", rpc_params![tx_bytes, signatures])
+            .await
+    }
+
+    /// Execute the transaction and wait for results if desired. Request types: 1. WaitForEffectsCert: waits for TransactionEffectsCert and then return to client.     This mode is a proxy for transaction finality. 2. WaitForLocalExecution: waits for TransactionEffectsCert and make sure the node     executed the transaction locally before returning the client. The local execution     makes sure this node is aware of this transaction when client fires subsequent queries.     However if the node fails to execute the transaction locally in a timely manner,     a bool type in the response is set to false to indicated the case.
+    #[deprecated]
+    async fn execute_transaction(
+        &self,
+        tx_bytes: Base64,
+        signature: Base64,
+        request_type: ExecuteTransactionRequestType,
+    ) -> Result<SuiTransactionResponse, Error> {
+        self.request(
+            "sui_executeTransaction",
+            rpc_params![tx_bytes, signature, request_type],
+        )
+        .await
+    }
+
+    #[deprecated]
+    async fn execute_transaction_serialized_sig(
+        &self,
+        tx_bytes: Base64,
+        signature: Base64,
+        request_type: ExecuteTransactionRequestType,
+    ) -> Result<SuiTransactionResponse, Error> {
+        self.request(
+            "sui_executeTransactionSerializedSig",
+            rpc_params![tx_bytes, signature, request_type],
+        )
+        .await
+    }
+
+    /// Return the total coin balance for all coin type, owned by the address owner.
+    async fn get_all_balances(&self, owner: SuiAddress) -> Result<Vec<Balance>, Error> {
+        self.request("sui_getAllBalances", rpc_params![owner]).await
+    }
+
+    /// Return all Coin objects owned by an address.
+    async fn get_all_coins(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> Result<CoinPage, Error> {
+        self.request("sui_getAllCoins", rpc_params![owner, cursor, limit])
+            .await
+    }
+
+    /// Return the total coin balance for one coin type, owned by the address owner.
+    async fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+    ) -> Result<Balance, Error> {
+        self.request("sui_getBalance", rpc_params![owner, coin_type])
+            .await
+    }
+
+    /// Return a checkpoint
+    async fn get_checkpoint(&self, id: CheckpointId) -> Result<Checkpoint, Error> {
+        self.request("sui_getCheckpoint", rpc_params![id]).await
+    }
+
+    /// Return a checkpoint along with the aggregated signature of the validators certifying it and the committee of its epoch, which auditors can archive and verify offline
+    async fn get_checkpoint_attestation(
+        &self,
+        id: CheckpointId,
+    ) -> Result<CheckpointAttestation, Error> {
+        self.request("sui_getCheckpointAttestation", rpc_params![id])
+            .await
+    }
+
+    /// Return metadata(e.g., symbol, decimals) for a coin
+    async fn get_coin_metadata(&self, coin_type: String) -> Result<SuiCoinMetadata, Error> {
+        self.request("sui_getCoinMetadata", rpc_params![coin_type])
+            .await
+    }
+
+    /// Return all Coin<`coin_type`> objects owned by an address.
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> Result<CoinPage, Error> {
+        self.request("sui_getCoins", rpc_params![owner, coin_type, cursor, limit])
+            .await
+    }
+
+    /// Return the committee information for the asked `epoch`, with the names, public keys, network addresses and commission of its validators in the latest validator set.
+    async fn get_committee_info(&self, epoch: Option<u64>) -> Result<SuiCommittee, Error> {
+        self.request("sui_getCommitteeInfo", rpc_params![epoch])
+            .await
+    }
+
+    /// Return the committees of a range of epochs, in ascending epoch order, with the validator information of [Self::get_committee_info].
+    async fn get_committees(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<CommitteePage, Error> {
+        self.request("sui_getCommittees", rpc_params![cursor, limit])
+            .await
+    }
+
+    /// Return all [DelegatedStake].
+    async fn get_delegated_stakes(&self, owner: SuiAddress) -> Result<Vec<DelegatedStake>, Error> {
+        self.request("sui_getDelegatedStakes", rpc_params![owner])
+            .await
+    }
+
+    /// Return the dynamic field object information for a specified object
+    async fn get_dynamic_field_object(
+        &self,
+        parent_object_id: ObjectID,
+        name: DynamicFieldName,
+        options: Option<SuiObjectDataOptions>,
+    ) -> Result<SuiObjectResponse, Error> {
+        self.request(
+            "sui_getDynamicFieldObject",
+            rpc_params![parent_object_id, name, options],
+        )
+        .await
+    }
+
+    /// Return the list of dynamic field objects owned by an object.
+    async fn get_dynamic_fields(
+        &self,
+        parent_object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> Result<DynamicFieldPage, Error> {
+        self.request(
+            "sui_getDynamicFields",
+            rpc_params![parent_object_id, cursor, limit],
+        )
+        .await
+    }
+
+    /// Return list of events for a specified query criteria.
+    async fn get_events(
+        &self,
+        query: EventQuery,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<EventPage, Error> {
+        self.request(
+            "sui_getEvents",
+            rpc_params![query, cursor, limit, descending_order],
+        )
+        .await
+    }
+
+    /// Return all events emitted by the transactions of a checkpoint, in execution order.
+    async fn get_events_by_checkpoint(
+        &self,
+        checkpoint: u64,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> Result<EventPage, Error> {
+        self.request(
+            "sui_getEventsByCheckpoint",
+            rpc_params![checkpoint, cursor, limit],
+        )
+        .await
+    }
+
+    /// Return the gas costs of the Move bytecode instructions, native functions and transactions at a protocol version
+    async fn get_gas_cost_table(
+        &self,
+        protocol_version: Option<ProtocolVersion>,
+    ) -> Result<SuiGasCostTable, Error> {
+        self.request("sui_getGasCostTable", rpc_params![protocol_version])
+            .await
+    }
+
+    /// Return the sequence number of the latest checkpoint that has been executed
+    async fn get_latest_checkpoint_sequence_number(
+        &self,
+    ) -> Result<CheckpointSequenceNumber, Error> {
+        self.request("sui_getLatestCheckpointSequenceNumber", rpc_params![])
+            .await
+    }
+
+    /// Return the latest SUI system state object on-chain.
+    async fn get_latest_sui_system_state(&self) -> Result<SuiSystemStateSummary, Error> {
+        self.request("sui_getLatestSuiSystemState", rpc_params![])
+            .await
+    }
+
+    /// Return the argument types of a Move function, based on normalized Type.
+    async fn get_move_function_arg_types(
+        &self,
+        package: ObjectID,
+        module: String,
+        function: String,
+    ) -> Result<Vec<MoveFunctionArgType>, Error> {
+        self.request(
+            "sui_getMoveFunctionArgTypes",
+            rpc_params![package, module, function],
+        )
+        .await
+    }
+
+    /// Return the BCS layout of a Move struct type, to decode the BCS bytes of objects of that type. Layouts only depend on the type, so clients can cache them by type.
+    async fn get_move_struct_layout(
+        &self,
+        struct_type: String,
+    ) -> Result<SuiMoveStructLayout, Error> {
+        self.request("sui_getMoveStructLayout", rpc_params![struct_type])
+            .await
+    }
+
+    /// Return the chain, epoch and protocol version of the node, the range of checkpoints it serves and its software version, for load balancers and clients to route requests away from stale nodes
+    async fn get_node_info(&self) -> Result<SuiNodeInfo, Error> {
+        self.request("sui_getNodeInfo", rpc_params![]).await
+    }
+
+    /// Return a structured representation of Move function
+    async fn get_normalized_move_function(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        function_name: String,
+    ) -> Result<SuiMoveNormalizedFunction, Error> {
+        self.request(
+            "sui_getNormalizedMoveFunction",
+            rpc_params![package, module_name, function_name],
+        )
+        .await
+    }
+
+    /// Return a structured representation of Move module
+    async fn get_normalized_move_module(
+        &self,
+        package: ObjectID,
+        module_name: String,
+    ) -> Result<SuiMoveNormalizedModule, Error> {
+        self.request(
+            "sui_getNormalizedMoveModule",
+            rpc_params![package, module_name],
+        )
+        .await
+    }
+
+    /// Return structured representations of all modules in the given package
+    async fn get_normalized_move_modules_by_package(
+        &self,
+        package: ObjectID,
+    ) -> Result<BTreeMap<String, SuiMoveNormalizedModule>, Error> {
+        self.request(
+            "sui_getNormalizedMoveModulesByPackage",
+            rpc_params![package],
+        )
+        .await
+    }
+
+    /// Return a structured representation of Move struct
+    async fn get_normalized_move_struct(
+        &self,
+        package: ObjectID,
+        module_name: String,
+        struct_name: String,
+    ) -> Result<SuiMoveNormalizedStruct, Error> {
+        self.request(
+            "sui_getNormalizedMoveStruct",
+            rpc_params![package, module_name, struct_name],
+        )
+        .await
+    }
+
+    /// Return the object information for a specified object
+    async fn get_object(
+        &self,
+        object_id: ObjectID,
+        options: Option<SuiObjectDataOptions>,
+    ) -> Result<SuiObjectResponse, Error> {
+        self.request("sui_getObject", rpc_params![object_id, options])
+            .await
+    }
+
+    /// Return the list of objects owned by an address.
+    async fn get_objects_owned_by_address(
+        &self,
+        address: SuiAddress,
+    ) -> Result<Vec<SuiObjectInfo>, Error> {
+        self.request("sui_getObjectsOwnedByAddress", rpc_params![address])
+            .await
+    }
+
+    /// Return the reference gas price for the network
+    async fn get_reference_gas_price(&self) -> Result<u64, Error> {
+        self.request("sui_getReferenceGasPrice", rpc_params![])
+            .await
+    }
+
+    /// Return total supply for a coin
+    async fn get_total_supply(&self, coin_type: String) -> Result<Supply, Error> {
+        self.request("sui_getTotalSupply", rpc_params![coin_type])
+            .await
+    }
+
+    /// Return the total number of transactions known to the server.
+    async fn get_total_transaction_number(&self) -> Result<u64, Error> {
+        self.request("sui_getTotalTransactionNumber", rpc_params![])
+            .await
+    }
+
+    /// Return the transaction response object.
+    async fn get_transaction(
+        &self,
+        digest: TransactionDigest,
+        options: Option<SuiTransactionResponseOptions>,
+    ) -> Result<SuiTransactionResponse, Error> {
+        self.request("sui_getTransaction", rpc_params![digest, options])
+            .await
+    }
+
+    /// Return the events emitted by a transaction, in emission order. Transactions emitting many events can be fetched page by page instead of in a single response.
+    async fn get_transaction_events(
+        &self,
+        digest: TransactionDigest,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+    ) -> Result<EventPage, Error> {
+        self.request(
+            "sui_getTransactionEvents",
+            rpc_params![digest, cursor, limit],
+        )
+        .await
+    }
+
+    /// Return the status of a transaction enqueued with `sui_enqueueTransaction`.
+    async fn get_transaction_submission_status(
+        &self,
+        digest: TransactionDigest,
+    ) -> Result<SuiTransactionSubmissionStatus, Error> {
+        self.request("sui_getTransactionSubmissionStatus", rpc_params![digest])
+            .await
+    }
+
+    /// Return list of transactions for a specified query criteria.
+    async fn get_transactions(
+        &self,
+        query: TransactionQuery,
+        cursor: Option<TransactionDigest>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+        show_summaries: Option<bool>,
+    ) -> Result<TransactionsPage, Error> {
+        self.request(
+            "sui_getTransactions",
+            rpc_params![query, cursor, limit, descending_order, show_summaries],
+        )
+        .await
+    }
+
+    /// Return list of transaction digests within the queried range.
+    async fn get_transactions_in_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<TransactionDigest>, Error> {
+        self.request("sui_getTransactionsInRange", rpc_params![start, end])
+            .await
+    }
+
+    /// Return the reports of the validators against each other in the current epoch, and the validators whose rewards the tallying rule slashes at the end of the epoch
+    async fn get_validator_reports(&self) -> Result<SuiValidatorReports, Error> {
+        self.request("sui_getValidatorReports", rpc_params![]).await
+    }
+
+    /// Create an unsigned transaction to merge multiple coins into one coin.
+    async fn merge_coins(
+        &self,
+        signer: SuiAddress,
+        primary_coin: ObjectID,
+        coin_to_merge: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_mergeCoins",
+            rpc_params![signer, primary_coin, coin_to_merge, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to execute a Move call on the network, by calling the specified function in the module of a given package.
+    async fn move_call(
+        &self,
+        signer: SuiAddress,
+        package_object_id: ObjectID,
+        module: String,
+        function: String,
+        type_arguments: Vec<SuiTypeTag>,
+        arguments: Vec<SuiJsonValue>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+        execution_mode: Option<SuiTransactionBuilderMode>,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_moveCall",
+            rpc_params![
+                signer,
+                package_object_id,
+                module,
+                function,
+                type_arguments,
+                arguments,
+                gas,
+                gas_budget,
+                execution_mode
+            ],
+        )
+        .await
+    }
+
+    /// Return metadata(e.g., symbol, decimals) for a list of coins, in the order of `coin_types`, with `null` for the coins whose metadata cannot be found. The method will throw an error if the input size exceeds QUERY_MAX_RESULT_LIMIT
+    async fn multi_get_coin_metadata(
+        &self,
+        coin_types: Vec<String>,
+    ) -> Result<Vec<Option<SuiCoinMetadata>>, Error> {
+        self.request("sui_multiGetCoinMetadata", rpc_params![coin_types])
+            .await
+    }
+
+    /// Returns an ordered list of transaction responses The method will throw an error if the input contains any duplicate or the input size exceeds QUERY_MAX_RESULT_LIMIT
+    async fn multi_get_transactions(
+        &self,
+        digests: Vec<TransactionDigest>,
+        options: Option<SuiTransactionResponseOptions>,
+    ) -> Result<Vec<SuiTransactionResponse>, Error> {
+        self.request("sui_multiGetTransactions", rpc_params![digests, options])
+            .await
+    }
+
+    /// Send `Coin<T>` to a list of addresses, where `T` can be any coin type, following a list of amounts, The object specified in the `gas` field will be used to pay the gas fee for the transaction. The gas object can not appear in `input_coins`. If the gas object is not specified, the RPC server will auto-select one.
+    async fn pay(
+        &self,
+        signer: SuiAddress,
+        input_coins: Vec<ObjectID>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<BigInt>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_pay",
+            rpc_params![signer, input_coins, recipients, amounts, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Send all SUI coins to one recipient. This is for SUI coin only and does not require a separate gas coin object. Specifically, what pay_all_sui does are: 1. accumulate all SUI from input coins and deposit all SUI to the first input coin 2. transfer the updated first coin to the recipient and also use this first coin as gas coin object. 3. the balance of the first input coin after tx is sum(input_coins) - actual_gas_cost. 4. all other input coins other than the first are deleted.
+    async fn pay_all_sui(
+        &self,
+        signer: SuiAddress,
+        input_coins: Vec<ObjectID>,
+        recipient: SuiAddress,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_payAllSui",
+            rpc_params![signer, input_coins, recipient, gas_budget],
+        )
+        .await
+    }
+
+    /// Send SUI coins to a list of addresses, following a list of amounts. This is for SUI coin only and does not require a separate gas coin object. Specifically, what pay_sui does are: 1. debit each input_coin to create new coin following the order of amounts and assign it to the corresponding recipient. 2. accumulate all residual SUI from input coins left and deposit all SUI to the first input coin, then use the first input coin as the gas coin object. 3. the balance of the first input coin after tx is sum(input_coins) - sum(amounts) - actual_gas_cost 4. all other input coints other than the first one are deleted.
+    async fn pay_sui(
+        &self,
+        signer: SuiAddress,
+        input_coins: Vec<ObjectID>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<BigInt>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_paySui",
+            rpc_params![signer, input_coins, recipients, amounts, gas_budget],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to publish Move module.
+    async fn publish(
+        &self,
+        sender: SuiAddress,
+        compiled_modules: Vec<Base64>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_publish",
+            rpc_params![sender, compiled_modules, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Add delegated stake to a validator's staking pool using multiple coins and amount.
+    async fn request_add_delegation(
+        &self,
+        signer: SuiAddress,
+        coins: Vec<ObjectID>,
+        amount: Option<u64>,
+        validator: SuiAddress,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_requestAddDelegation",
+            rpc_params![signer, coins, amount, validator, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Withdraw a delegation from a validator's staking pool.
+    async fn request_withdraw_delegation(
+        &self,
+        signer: SuiAddress,
+        delegation: ObjectID,
+        staked_sui: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_requestWithdrawDelegation",
+            rpc_params![signer, delegation, staked_sui, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Set the expiration of an unsigned transaction: validators refuse to sign it after epoch `epoch`, so that the transaction can't be executed later if its signing is abandoned.
+    async fn set_transaction_expiration(
+        &self,
+        tx_bytes: Base64,
+        epoch: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request("sui_setTransactionExpiration", rpc_params![tx_bytes, epoch])
+            .await
+    }
+
+    /// Create an unsigned transaction to split a coin object into multiple coins.
+    async fn split_coin(
+        &self,
+        signer: SuiAddress,
+        coin_object_id: ObjectID,
+        split_amounts: Vec<u64>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_splitCoin",
+            rpc_params![signer, coin_object_id, split_amounts, gas, gas_budget],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to split a coin object into multiple equal-size coins.
+    async fn split_coin_equal(
+        &self,
+        signer: SuiAddress,
+        coin_object_id: ObjectID,
+        split_count: u64,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_splitCoinEqual",
+            rpc_params![signer, coin_object_id, split_count, gas, gas_budget],
+        )
+        .await
+    }
+
+    async fn submit_transaction(
+        &self,
+        tx_bytes: Base64,
+        signatures: Vec<Base64>,
+        request_type: ExecuteTransactionRequestType,
+    ) -> Result<SuiTransactionResponse, Error> {
+        self.request(
+            "sui_submitTransaction",
+            rpc_params![tx_bytes, signatures, request_type],
+        )
+        .await
+    }
+
+    /// Sign an a Randomness object with threshold BLS. **Warning**: This API is a work in progress and uses insecure randomness. Please use it for testing purposes only.
+    async fn tbls_sign_randomness_object(
+        &self,
+        object_id: ObjectID,
+        commitment_type: SuiTBlsSignObjectCommitmentType,
+    ) -> Result<SuiTBlsSignRandomnessObjectResponse, Error> {
+        self.request(
+            "sui_tblsSignRandomnessObject",
+            rpc_params![object_id, commitment_type],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to transfer an object from one address to another. The object's type must allow public transfers
+    async fn transfer_object(
+        &self,
+        signer: SuiAddress,
+        object_id: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+        recipient: SuiAddress,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_transferObject",
+            rpc_params![signer, object_id, gas, gas_budget, recipient],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to send SUI coin object to a Sui address. The SUI object is also used as the gas object.
+    async fn transfer_sui(
+        &self,
+        signer: SuiAddress,
+        sui_object_id: ObjectID,
+        gas_budget: u64,
+        recipient: SuiAddress,
+        amount: Option<u64>,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_transferSui",
+            rpc_params![signer, sui_object_id, gas_budget, recipient, amount],
+        )
+        .await
+    }
+
+    /// Note there is no software-level guarantee/SLA that objects with past versions can be retrieved by this API, even if the object and version exists/existed. The result may vary across nodes depending on their pruning policies. Return the object information for a specified version
+    async fn try_get_past_object(
+        &self,
+        object_id: ObjectID,
+        version: SequenceNumber,
+        options: Option<SuiObjectDataOptions>,
+    ) -> Result<SuiPastObjectResponse, Error> {
+        self.request(
+            "sui_tryGetPastObject",
+            rpc_params![object_id, version, options],
+        )
+        .await
+    }
+
+    /// Return the latest reference and owner of each of the specified objects, in the order of the input, e.g. to resolve the input objects of a transaction in one call. Only the references and owners are returned unless requested otherwise with `options`. The method will throw an error if the input contains any duplicate or the input size exceeds QUERY_MAX_RESULT_LIMIT
+    async fn try_multi_get_object_refs(
+        &self,
+        object_ids: Vec<ObjectID>,
+        options: Option<SuiObjectDataOptions>,
+    ) -> Result<Vec<SuiObjectResponse>, Error> {
+        self.request(
+            "sui_tryMultiGetObjectRefs",
+            rpc_params![object_ids, options],
+        )
+        .await
+    }
+
+    /// Create an unsigned transaction to upgrade a Move package, as authorized by its upgrade capability with the upgrade policy of the capability.
+    async fn upgrade_package(
+        &self,
+        sender: SuiAddress,
+        package_id: ObjectID,
+        compiled_modules: Vec<Base64>,
+        dependencies: Vec<ObjectID>,
+        upgrade_capability: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> Result<TransactionBytes, Error> {
+        self.request(
+            "sui_upgradePackage",
+            rpc_params![
+                sender,
+                package_id,
+                compiled_modules,
+                dependencies,
+                upgrade_capability,
+                gas,
+                gas_budget
+            ],
+        )
+        .await
+    }
+}
+
+impl<T: ClientT + Sync> SuiJsonRpcClient for T {}
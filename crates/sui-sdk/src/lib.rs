@@ -17,17 +17,28 @@ use serde_json::Value;
 use sui_adapter::execution_mode::Normal;
 pub use sui_json as json;
 
-use crate::apis::{CoinReadApi, EventApi, GovernanceApi, QuorumDriver, ReadApi};
+use crate::apis::{
+    CoinMergeApi, CoinReadApi, EventApi, GovernanceApi, QuorumDriver, ReadApi, StakingApi,
+};
 use sui_json_rpc::{
     CLIENT_SDK_TYPE_HEADER, CLIENT_SDK_VERSION_HEADER, CLIENT_TARGET_API_VERSION_HEADER,
+    MIN_CHECKPOINT_HEADER,
 };
 pub use sui_json_rpc_types as rpc_types;
 use sui_json_rpc_types::{SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse};
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 pub use sui_types as types;
 use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 pub mod apis;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod error;
+pub mod failover_client;
+pub mod gas_station;
+pub mod json_rpc_client;
+pub mod multisig;
+pub mod submission_manager;
 pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
 const WAIT_FOR_TX_TIMEOUT_SEC: u64 = 60;
 
@@ -35,6 +46,7 @@ pub struct SuiClientBuilder {
     request_timeout: Duration,
     max_concurrent_requests: usize,
     ws_url: Option<String>,
+    min_checkpoint: Option<CheckpointSequenceNumber>,
 }
 
 impl Default for SuiClientBuilder {
@@ -43,6 +55,7 @@ impl Default for SuiClientBuilder {
             request_timeout: Duration::from_secs(60),
             max_concurrent_requests: 256,
             ws_url: None,
+            min_checkpoint: None,
         }
     }
 }
@@ -63,19 +76,19 @@ impl SuiClientBuilder {
         self
     }
 
+    /// Require the reads of the client to reflect at least `checkpoint`, e.g. the checkpoint of a
+    /// transaction executed by another client. The server holds the requests until it reaches the
+    /// checkpoint, and rejects them if it doesn't in time.
+    pub fn min_checkpoint(mut self, checkpoint: CheckpointSequenceNumber) -> Self {
+        self.min_checkpoint = Some(checkpoint);
+        self
+    }
+
     pub async fn build(self, http: impl AsRef<str>) -> SuiRpcResult<SuiClient> {
-        let client_version = env!("CARGO_PKG_VERSION");
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            CLIENT_TARGET_API_VERSION_HEADER,
-            // for rust, the client version is the same as the target api version
-            HeaderValue::from_static(client_version),
-        );
-        headers.insert(
-            CLIENT_SDK_VERSION_HEADER,
-            HeaderValue::from_static(client_version),
-        );
-        headers.insert(CLIENT_SDK_TYPE_HEADER, HeaderValue::from_static("rust"));
+        let mut headers = sdk_headers();
+        if let Some(checkpoint) = self.min_checkpoint {
+            headers.insert(MIN_CHECKPOINT_HEADER, HeaderValue::from(checkpoint));
+        }
 
         let ws = if let Some(url) = self.ws_url {
             Some(
@@ -108,6 +121,16 @@ impl SuiClientBuilder {
         let transaction_builder = TransactionBuilder::new(read_api.clone());
         let coin_read_api = CoinReadApi::new(api.clone());
         let governance_api = GovernanceApi::new(api.clone());
+        let staking_api = StakingApi::new(
+            transaction_builder.clone(),
+            coin_read_api.clone(),
+            governance_api.clone(),
+        );
+        let coin_merge_api = CoinMergeApi::new(
+            read_api.clone(),
+            coin_read_api.clone(),
+            quorum_driver.clone(),
+        );
 
         Ok(SuiClient {
             api,
@@ -117,6 +140,8 @@ impl SuiClientBuilder {
             event_api,
             quorum_driver,
             governance_api,
+            staking_api,
+            coin_merge_api,
         })
     }
 
@@ -164,6 +189,23 @@ impl SuiClientBuilder {
     }
 }
 
+/// Headers identifying the SDK and the API version it targets, sent with every request.
+pub(crate) fn sdk_headers() -> HeaderMap {
+    let client_version = env!("CARGO_PKG_VERSION");
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        CLIENT_TARGET_API_VERSION_HEADER,
+        // for rust, the client version is the same as the target api version
+        HeaderValue::from_static(client_version),
+    );
+    headers.insert(
+        CLIENT_SDK_VERSION_HEADER,
+        HeaderValue::from_static(client_version),
+    );
+    headers.insert(CLIENT_SDK_TYPE_HEADER, HeaderValue::from_static("rust"));
+    headers
+}
+
 #[derive(Clone)]
 pub struct SuiClient {
     api: Arc<RpcClient>,
@@ -173,6 +215,8 @@ pub struct SuiClient {
     event_api: EventApi,
     quorum_driver: QuorumDriver,
     governance_api: GovernanceApi,
+    staking_api: StakingApi,
+    coin_merge_api: CoinMergeApi,
 }
 
 pub(crate) struct RpcClient {
@@ -258,6 +302,12 @@ impl SuiClient {
     pub fn governance_api(&self) -> &GovernanceApi {
         &self.governance_api
     }
+    pub fn staking_api(&self) -> &StakingApi {
+        &self.staking_api
+    }
+    pub fn coin_merge_api(&self) -> &CoinMergeApi {
+        &self.coin_merge_api
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,122 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use fastcrypto::encoding::{Base64, Encoding};
+use serde::{Deserialize, Serialize};
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{PublicKey, Signature};
+use sui_types::multisig::{MultiSig, MultiSigPublicKey, ThresholdUnit, WeightUnit};
+use sui_types::signature::GenericSignature;
+
+use crate::error::{Error, SuiRpcResult};
+
+/// Helper for assembling a MultiSig from partial signatures.
+///
+/// The builder holds the [MultiSigPublicKey] and the partial signatures collected so far.
+/// Its state can be exported with [MultiSigBuilder::to_base64] and restored with
+/// [MultiSigBuilder::from_base64], so that signers on different machines can each add
+/// their signature before the final [GenericSignature] is produced with [MultiSigBuilder::build].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiSigBuilder {
+    multisig_pk: MultiSigPublicKey,
+    /// Partial signatures keyed by the index of the signer's public key in `multisig_pk`.
+    signatures: BTreeMap<u32, Signature>,
+}
+
+impl MultiSigBuilder {
+    /// Create a builder from a list of weighted signers and the threshold required to sign.
+    pub fn new(
+        signers: Vec<(PublicKey, WeightUnit)>,
+        threshold: ThresholdUnit,
+    ) -> SuiRpcResult<Self> {
+        let (pks, weights) = signers.into_iter().unzip();
+        let multisig_pk = MultiSigPublicKey::new(pks, weights, threshold)
+            .map_err(|e| Error::MultiSigError(e.to_string()))?;
+        Ok(Self::from_multisig_pk(multisig_pk))
+    }
+
+    pub fn from_multisig_pk(multisig_pk: MultiSigPublicKey) -> Self {
+        Self {
+            multisig_pk,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    pub fn multisig_pk(&self) -> &MultiSigPublicKey {
+        &self.multisig_pk
+    }
+
+    /// The Sui address derived from the MultiSig public key.
+    pub fn address(&self) -> SuiAddress {
+        SuiAddress::from(self.multisig_pk.clone())
+    }
+
+    /// Add a partial signature. The signature must be produced by one of the signers of
+    /// the MultiSig public key, a second signature from the same signer replaces the first.
+    pub fn add_signature(&mut self, signature: Signature) -> SuiRpcResult<&mut Self> {
+        let pk = signature
+            .to_public_key()
+            .map_err(|e| Error::MultiSigError(e.to_string()))?;
+        let index = self.multisig_pk.get_index(pk).ok_or_else(|| {
+            Error::MultiSigError("Signer is not part of the MultiSig public key".to_string())
+        })?;
+        self.signatures.insert(index, signature);
+        Ok(self)
+    }
+
+    /// Merge the partial signatures collected by another builder for the same MultiSig public key.
+    pub fn merge(&mut self, other: MultiSigBuilder) -> SuiRpcResult<&mut Self> {
+        if self.multisig_pk != other.multisig_pk {
+            return Err(Error::MultiSigError(
+                "Cannot merge builders of different MultiSig public keys".to_string(),
+            ));
+        }
+        self.signatures.extend(other.signatures);
+        Ok(self)
+    }
+
+    /// Total weight of the signers who have signed so far.
+    pub fn signed_weight(&self) -> u16 {
+        let pubkeys = self.multisig_pk.pubkeys();
+        self.signatures
+            .keys()
+            .filter_map(|i| pubkeys.get(*i as usize))
+            .map(|(_, weight)| *weight as u16)
+            .sum()
+    }
+
+    /// Returns true if the collected signatures reach the threshold.
+    pub fn is_complete(&self) -> bool {
+        self.signed_weight() >= *self.multisig_pk.threshold()
+    }
+
+    /// Combine the collected signatures into a MultiSig.
+    pub fn build(&self) -> SuiRpcResult<GenericSignature> {
+        if !self.is_complete() {
+            return Err(Error::MultiSigError(format!(
+                "Insufficient weight {}, threshold is {}",
+                self.signed_weight(),
+                self.multisig_pk.threshold()
+            )));
+        }
+        let multisig = MultiSig::combine(
+            self.signatures.values().cloned().collect(),
+            self.multisig_pk.clone(),
+        )
+        .map_err(|e| Error::MultiSigError(e.to_string()))?;
+        Ok(GenericSignature::MultiSig(multisig))
+    }
+
+    /// Export the builder state as Base64 encoded BCS bytes.
+    pub fn to_base64(&self) -> SuiRpcResult<String> {
+        Ok(Base64::encode(bcs::to_bytes(self)?))
+    }
+
+    /// Restore a builder exported with [MultiSigBuilder::to_base64].
+    pub fn from_base64(value: &str) -> SuiRpcResult<Self> {
+        let bytes = Base64::decode(value).map_err(|e| Error::DataError(e.to_string()))?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
+}
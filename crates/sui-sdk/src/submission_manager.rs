@@ -0,0 +1,259 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Concurrent submission of the transactions of an address, see [SubmissionManager].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use sui_json_rpc_types::{SuiTransactionEffectsAPI, SuiTransactionResponse};
+use sui_keys::signer::Signer;
+use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
+use sui_types::messages::{
+    CallArg, ExecuteTransactionRequestType, InputObjectKind, ObjectArg, SingleTransactionKind,
+    TransactionData, TransactionDataAPI, TransactionKind,
+};
+use tokio::sync::Notify;
+
+use crate::error::{Error, SuiRpcResult};
+use crate::SuiClient;
+
+#[derive(Default)]
+struct SubmissionState {
+    /// Owned objects used by the transactions being executed.
+    in_flight: BTreeSet<ObjectID>,
+    /// References of the owned objects after the last executed transaction using them, None if
+    /// the transaction deleted or wrapped them.
+    latest: BTreeMap<ObjectID, Option<ObjectRef>>,
+}
+
+impl SubmissionState {
+    /// Whether none of `objects` is used by a transaction being executed.
+    fn is_available(&self, objects: &BTreeSet<ObjectID>) -> bool {
+        self.in_flight.is_disjoint(objects)
+    }
+
+    /// Bumps the references of `tx_data` made stale by the transactions executed since it was
+    /// built.
+    fn update_refs(&self, tx_data: &mut TransactionData) -> SuiRpcResult<()> {
+        let update = |obj_ref: &mut ObjectRef| match self.latest.get(&obj_ref.0) {
+            Some(Some(latest)) => {
+                if latest.1 > obj_ref.1 {
+                    *obj_ref = *latest;
+                }
+                Ok(())
+            }
+            Some(None) => Err(Error::DataError(format!(
+                "Object {} was deleted or wrapped by a previously submitted transaction.",
+                obj_ref.0
+            ))),
+            None => Ok(()),
+        };
+        tx_data
+            .gas_data_mut()
+            .payment
+            .iter_mut()
+            .try_for_each(update)?;
+        match tx_data.kind_mut() {
+            TransactionKind::Single(kind) => owned_refs_mut(kind).into_iter().try_for_each(update),
+            TransactionKind::Batch(kinds) => kinds
+                .iter_mut()
+                .flat_map(owned_refs_mut)
+                .try_for_each(update),
+        }
+    }
+}
+
+/// Submits the transactions of an address concurrently, without the callers having to track the
+/// versions of the objects they use.
+///
+/// Transactions using the same owned objects, including their gas coins, are executed one after
+/// the other: a transaction waits for the transactions in flight using its objects, and the
+/// references of its objects are then bumped to their versions after these transactions. The
+/// transactions using distinct objects are executed in parallel. This avoids the errors returned
+/// by the validators when the same version of an object is used by concurrent transactions,
+/// which can lock the object until the end of the epoch.
+///
+/// Immutable objects cannot be told apart from owned objects in a transaction, so transactions
+/// using the same immutable objects are executed one after the other too.
+pub struct SubmissionManager {
+    client: SuiClient,
+    signer: Arc<dyn Signer>,
+    state: Mutex<SubmissionState>,
+    released: Notify,
+}
+
+impl SubmissionManager {
+    pub fn new(client: SuiClient, signer: Arc<dyn Signer>) -> Self {
+        Self {
+            client,
+            signer,
+            state: Mutex::new(SubmissionState::default()),
+            released: Notify::new(),
+        }
+    }
+
+    pub fn sender(&self) -> SuiAddress {
+        self.signer.address()
+    }
+
+    /// Number of owned objects used by the transactions being executed.
+    pub fn in_flight_objects(&self) -> usize {
+        self.state.lock().unwrap().in_flight.len()
+    }
+
+    /// Signs and executes `tx_data` once the transactions in flight using the same owned objects
+    /// are executed, waiting for local execution.
+    ///
+    /// If the execution fails without effects, the versions of the objects of the transaction are
+    /// unknown: the next transactions using them are submitted with the references they were
+    /// built with.
+    pub async fn submit(
+        &self,
+        mut tx_data: TransactionData,
+    ) -> SuiRpcResult<SuiTransactionResponse> {
+        if tx_data.sender() != self.sender() {
+            return Err(Error::DataError(format!(
+                "The sender {} of the transaction is not the address {} of the submission manager.",
+                tx_data.sender(),
+                self.sender()
+            )));
+        }
+        let objects = self.acquire(&mut tx_data).await?;
+        let result = self
+            .client
+            .quorum_driver()
+            .sign_and_execute_transaction(
+                tx_data,
+                self.signer.as_ref(),
+                Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await;
+        objects.release(result.as_ref().ok());
+        result
+    }
+
+    /// Waits until none of the owned objects of `tx_data` is in flight, and marks them in flight.
+    async fn acquire(&self, tx_data: &mut TransactionData) -> SuiRpcResult<InFlightObjects<'_>> {
+        let objects = owned_objects(tx_data)?;
+        loop {
+            // Registered before checking the state, so that a release in between is not missed.
+            let released = self.released.notified();
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.is_available(&objects) {
+                    state.update_refs(tx_data)?;
+                    state.in_flight.extend(&objects);
+                    return Ok(InFlightObjects {
+                        manager: self,
+                        objects,
+                    });
+                }
+            }
+            released.await;
+        }
+    }
+
+    /// Marks `objects` as no longer in flight, recording their references after the execution of
+    /// the transaction which used them.
+    fn release(&self, objects: &BTreeSet<ObjectID>, response: Option<&SuiTransactionResponse>) {
+        let mut state = self.state.lock().unwrap();
+        for id in objects {
+            state.in_flight.remove(id);
+        }
+        match response.and_then(|response| response.effects.as_ref()) {
+            Some(effects) => {
+                let changed = effects
+                    .mutated()
+                    .iter()
+                    .chain([effects.gas_object()])
+                    .map(|o| (o.reference.object_id, Some(o.reference.to_object_ref())));
+                let removed = effects
+                    .deleted()
+                    .iter()
+                    .chain(effects.wrapped())
+                    .map(|o| (o.object_id, None));
+                for (id, latest) in changed.chain(removed) {
+                    if objects.contains(&id) {
+                        state.latest.insert(id, latest);
+                    }
+                }
+            }
+            None => {
+                for id in objects {
+                    state.latest.remove(id);
+                }
+            }
+        }
+        drop(state);
+        self.released.notify_waiters();
+    }
+}
+
+/// Objects marked in flight by a submission, released when dropped if the submission is
+/// cancelled.
+struct InFlightObjects<'a> {
+    manager: &'a SubmissionManager,
+    objects: BTreeSet<ObjectID>,
+}
+
+impl InFlightObjects<'_> {
+    fn release(mut self, response: Option<&SuiTransactionResponse>) {
+        let objects = std::mem::take(&mut self.objects);
+        self.manager.release(&objects, response);
+    }
+}
+
+impl Drop for InFlightObjects<'_> {
+    fn drop(&mut self) {
+        if !self.objects.is_empty() {
+            self.manager.release(&self.objects, None);
+        }
+    }
+}
+
+/// Owned or immutable objects used by the transaction, including its gas coins.
+fn owned_objects(tx_data: &TransactionData) -> SuiRpcResult<BTreeSet<ObjectID>> {
+    Ok(tx_data
+        .input_objects()
+        .map_err(|e| Error::DataError(e.to_string()))?
+        .into_iter()
+        .filter_map(|kind| match kind {
+            InputObjectKind::ImmOrOwnedMoveObject(obj_ref) => Some(obj_ref.0),
+            InputObjectKind::MovePackage(_) | InputObjectKind::SharedMoveObject { .. } => None,
+        })
+        .collect())
+}
+
+fn owned_refs_mut(kind: &mut SingleTransactionKind) -> Vec<&mut ObjectRef> {
+    match kind {
+        SingleTransactionKind::TransferObject(transfer) => vec![&mut transfer.object_ref],
+        SingleTransactionKind::Pay(pay) => pay.coins.iter_mut().collect(),
+        SingleTransactionKind::PaySui(pay) => pay.coins.iter_mut().collect(),
+        SingleTransactionKind::PayAllSui(pay) => pay.coins.iter_mut().collect(),
+        SingleTransactionKind::Call(call) => call_args_refs_mut(&mut call.arguments),
+        SingleTransactionKind::ProgrammableTransaction(pt) => call_args_refs_mut(&mut pt.inputs),
+        SingleTransactionKind::TransferSui(_)
+        | SingleTransactionKind::Publish(_)
+        | SingleTransactionKind::ChangeEpoch(_)
+        | SingleTransactionKind::Genesis(_)
+        | SingleTransactionKind::ConsensusCommitPrologue(_) => vec![],
+    }
+}
+
+fn call_args_refs_mut(args: &mut [CallArg]) -> Vec<&mut ObjectRef> {
+    args.iter_mut()
+        .flat_map(|arg| match arg {
+            CallArg::Pure(_) => vec![],
+            CallArg::Object(object) => object_arg_ref_mut(object).into_iter().collect(),
+            CallArg::ObjVec(objects) => objects.iter_mut().filter_map(object_arg_ref_mut).collect(),
+        })
+        .collect()
+}
+
+fn object_arg_ref_mut(arg: &mut ObjectArg) -> Option<&mut ObjectRef> {
+    match arg {
+        ObjectArg::ImmOrOwnedObject(obj_ref) => Some(obj_ref),
+        ObjectArg::SharedObject { .. } => None,
+    }
+}
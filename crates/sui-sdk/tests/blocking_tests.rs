@@ -0,0 +1,41 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "blocking")]
+
+use sui_json_rpc_types::{SuiObjectDataOptions, SuiTransactionResponseOptions};
+use sui_sdk::blocking::SuiClientBuilder;
+use sui_types::query::TransactionQuery;
+use test_utils::network::TestClusterBuilder;
+
+#[test]
+fn test_blocking_client() -> Result<(), anyhow::Error> {
+    // The blocking client must not be used from an async context, the cluster runs on its own
+    // runtime.
+    let runtime = tokio::runtime::Runtime::new()?;
+    let test_cluster = runtime.block_on(TestClusterBuilder::new().build())?;
+    let address = test_cluster.accounts[0];
+
+    let client = SuiClientBuilder::default().build(test_cluster.rpc_url())?;
+
+    let read_api = client.read_api();
+    let objects = read_api.get_objects_owned_by_address(address)?;
+    assert!(!objects.is_empty());
+    let object =
+        read_api.get_object_with_options(objects[0].object_id, SuiObjectDataOptions::new())?;
+    assert_eq!(objects[0].object_id, object.object()?.object_id);
+
+    let balance = client.coin_read_api().get_balance(address, None)?;
+    assert!(balance.total_balance > 0);
+
+    let transactions = read_api.get_transactions(TransactionQuery::All, None, Some(1), false)?;
+    let response = read_api.get_transaction_with_options(
+        *transactions.data[0].digest(),
+        SuiTransactionResponseOptions::new().with_effects(),
+    )?;
+    assert!(response.effects.is_some());
+
+    let system_state = client.governance_api().get_latest_sui_system_state()?;
+    assert_eq!(0, system_state.epoch);
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use sui_sdk::failover_client::FailoverRpcClientBuilder;
+use sui_sdk::json_rpc_client::SuiJsonRpcClient;
+use test_utils::network::TestClusterBuilder;
+
+// Nothing listens on port 1, connections to it are refused.
+const UNREACHABLE_URL: &str = "http://127.0.0.1:1";
+
+#[tokio::test]
+async fn test_failover_on_unreachable_fullnode() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new().build().await?;
+    let client = FailoverRpcClientBuilder::default()
+        .request_timeout(Duration::from_secs(10))
+        .build([UNREACHABLE_URL, test_cluster.rpc_url()])?;
+    assert_eq!(client.healthy_endpoints().len(), 2);
+
+    let owned = client
+        .get_objects_owned_by_address(test_cluster.get_address_0())
+        .await?;
+    assert!(!owned.is_empty());
+    // The unreachable fullnode is skipped by the next requests.
+    assert_eq!(client.healthy_endpoints(), vec![test_cluster.rpc_url()]);
+    client.get_latest_checkpoint_sequence_number().await?;
+
+    assert_eq!(client.check_health().await, 1);
+    assert_eq!(client.healthy_endpoints(), vec![test_cluster.rpc_url()]);
+
+    // Requests fail with the error of the last fullnode tried when none is available.
+    let client = FailoverRpcClientBuilder::default().build([UNREACHABLE_URL])?;
+    assert!(client.get_total_transaction_number().await.is_err());
+    assert!(client.healthy_endpoints().is_empty());
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sui_json_rpc_types::{SuiExecutionStatus, SuiTransactionEffectsAPI};
+use sui_keys::keystore::AccountKeystore;
+use sui_sdk::gas_station::{GasStation, GasStationConfig};
+use sui_sdk::SuiClientBuilder;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{EncodeDecodeBase64, Signature, SuiKeyPair};
+use sui_types::intent::{Intent, IntentMessage};
+use sui_types::messages::{TransactionDataAPI, TransactionKind};
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use test_utils::network::TestClusterBuilder;
+
+fn keypair(keystore: &impl AccountKeystore, address: &SuiAddress) -> SuiKeyPair {
+    let key = keystore.get_key(address).unwrap();
+    SuiKeyPair::decode_base64(&key.encode_base64()).unwrap()
+}
+
+#[tokio::test]
+async fn test_sponsor_transaction() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new().build().await?;
+    let sponsor = test_cluster.get_address_0();
+    let sender = test_cluster.get_address_1();
+    let recipient = test_cluster.get_address_2();
+    let keystore = &test_cluster.wallet.config.keystore;
+    let sender_key = keypair(keystore, &sender);
+
+    let client = SuiClientBuilder::default()
+        .build(test_cluster.rpc_url())
+        .await?;
+    let station = GasStation::new(
+        client.clone(),
+        Arc::new(keypair(keystore, &sponsor)),
+        GasStationConfig {
+            gas_budget: 20_000,
+            target_coin_balance: 1_000_000,
+            pool_size: 3,
+            lease_duration: Duration::from_secs(60),
+        },
+    );
+    assert!(station.rebalance().await?.is_some());
+    let available = station.available_coins();
+    assert!(available >= 3);
+
+    // The sender transfers one of its coins, the gas is paid by a coin of the pool.
+    let coin = client
+        .coin_read_api()
+        .get_coins(sender, None, None, None)
+        .await?
+        .data
+        .remove(0);
+    let mut builder = ProgrammableTransactionBuilder::new();
+    builder.transfer_object(recipient, coin.object_ref());
+    let sponsored = station
+        .sponsor_transaction(TransactionKind::programmable(builder.finish()), sender)
+        .await?;
+    assert_eq!(sponsored.tx_data.gas_owner(), sponsor);
+    assert_eq!(station.available_coins(), available - 1);
+
+    let sender_signature = Signature::new_secure(
+        &IntentMessage::new(Intent::default(), sponsored.tx_data.clone()),
+        &sender_key,
+    );
+    let gas_coin = sponsored.tx_data.gas()[0];
+    let response = station
+        .execute_sponsored(sponsored, sender_signature)
+        .await?;
+    let effects = response.effects.unwrap();
+    assert_eq!(effects.status(), &SuiExecutionStatus::Success);
+    assert_eq!(effects.gas_object().reference.object_id, gas_coin.0);
+    // The coin is back in the pool with its new version.
+    assert_eq!(station.available_coins(), available);
+
+    // Sponsored transactions cannot spend the gas coin, nor be sent by the sponsor.
+    let mut builder = ProgrammableTransactionBuilder::new();
+    builder.pay_all_sui(recipient);
+    let pay_all = TransactionKind::programmable(builder.finish());
+    assert!(station
+        .sponsor_transaction(pay_all.clone(), sender)
+        .await
+        .is_err());
+    assert!(station.sponsor_transaction(pay_all, sponsor).await.is_err());
+    assert_eq!(station.available_coins(), available);
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use futures::future::join_all;
+use sui_json_rpc_types::{SuiExecutionStatus, SuiTransactionEffectsAPI};
+use sui_keys::keystore::AccountKeystore;
+use sui_sdk::submission_manager::SubmissionManager;
+use sui_sdk::SuiClientBuilder;
+use sui_types::crypto::{EncodeDecodeBase64, SuiKeyPair};
+use sui_types::messages::TransactionData;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use test_utils::network::TestClusterBuilder;
+
+#[tokio::test]
+async fn test_submit_concurrently() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new().build().await?;
+    let sender = test_cluster.get_address_0();
+    let recipient = test_cluster.get_address_1();
+    let keystore = &test_cluster.wallet.config.keystore;
+    let key = SuiKeyPair::decode_base64(&keystore.get_key(&sender)?.encode_base64()).unwrap();
+
+    let client = SuiClientBuilder::default()
+        .build(test_cluster.rpc_url())
+        .await?;
+    let manager = SubmissionManager::new(client.clone(), Arc::new(key));
+    let gas_price = client.read_api().get_reference_gas_price().await?;
+    let coins = client
+        .coin_read_api()
+        .get_coins(sender, None, None, None)
+        .await?
+        .data;
+    assert!(coins.len() >= 2);
+    let pay = |coin| {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.pay_sui(vec![recipient], vec![1000]).unwrap();
+        TransactionData::new_programmable(sender, vec![coin], builder.finish(), 20_000, gas_price)
+    };
+
+    // Transactions paying with the same coin at the same version are executed one after the
+    // other, with the version of the coin bumped, while the other coin is used in parallel.
+    let shared_coin = coins[0].object_ref();
+    let other_coin = coins[1].object_ref();
+    let transactions = (0..3)
+        .map(|_| pay(shared_coin))
+        .chain([pay(other_coin)])
+        .map(|tx_data| manager.submit(tx_data));
+    let responses = join_all(transactions).await;
+    let mut versions = vec![];
+    for response in responses {
+        let effects = response?.effects.unwrap();
+        assert_eq!(effects.status(), &SuiExecutionStatus::Success);
+        if effects.gas_object().reference.object_id == shared_coin.0 {
+            versions.push(effects.gas_object().reference.version);
+        }
+    }
+    versions.sort();
+    versions.dedup();
+    assert_eq!(versions.len(), 3);
+    assert_eq!(manager.in_flight_objects(), 0);
+
+    // A transaction built before the others is still submitted with the latest version.
+    let response = manager.submit(pay(shared_coin)).await?;
+    let effects = response.effects.unwrap();
+    assert_eq!(effects.status(), &SuiExecutionStatus::Success);
+    assert!(effects.gas_object().reference.version > versions[2]);
+
+    // Transactions of another sender are rejected.
+    let mut builder = ProgrammableTransactionBuilder::new();
+    builder.pay_all_sui(sender);
+    let tx_data =
+        TransactionData::new_programmable(recipient, vec![], builder.finish(), 20_000, gas_price);
+    assert!(manager.submit(tx_data).await.is_err());
+    Ok(())
+}
@@ -1,10 +1,18 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
+use rand::{rngs::StdRng, SeedableRng};
 use tempfile::TempDir;
 
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_keys::signer::{KeystoreSigner, Signer};
+use sui_sdk::multisig::MultiSigBuilder;
+use sui_types::base_types::{random_object_ref, SuiAddress};
 use sui_types::crypto::Ed25519SuiSignature;
+use sui_types::crypto::{get_key_pair_from_rng, Signature, SuiKeyPair};
 use sui_types::crypto::{SignatureScheme, SuiSignatureInner};
+use sui_types::intent::{Intent, IntentMessage, PersonalMessage};
+use sui_types::messages::{Transaction, TransactionData};
+use sui_types::signature::{AuthenticatorTrait, GenericSignature};
 #[test]
 fn mnemonic_test() {
     let temp_dir = TempDir::new().unwrap();
@@ -32,3 +40,89 @@ fn keystore_display_test() -> Result<(), anyhow::Error> {
     assert!(!keystore.to_string().contains("keys:"));
     Ok(())
 }
+
+#[test]
+fn multisig_builder_test() {
+    let mut seed = StdRng::from_seed([0; 32]);
+    let kp1 = SuiKeyPair::Ed25519(get_key_pair_from_rng(&mut seed).1);
+    let kp2 = SuiKeyPair::Secp256k1(get_key_pair_from_rng(&mut seed).1);
+    let kp3 = SuiKeyPair::Secp256r1(get_key_pair_from_rng(&mut seed).1);
+    let outsider = SuiKeyPair::Ed25519(get_key_pair_from_rng(&mut seed).1);
+
+    let mut builder = MultiSigBuilder::new(
+        vec![(kp1.public(), 1), (kp2.public(), 1), (kp3.public(), 2)],
+        2,
+    )
+    .unwrap();
+    let addr = builder.address();
+    assert_eq!(addr, SuiAddress::from(builder.multisig_pk().clone()));
+
+    let msg = IntentMessage::new(
+        Intent::default(),
+        PersonalMessage {
+            message: "Hello".as_bytes().to_vec(),
+        },
+    );
+
+    // Signatures from keys outside of the MultiSig public key are rejected.
+    assert!(builder
+        .add_signature(Signature::new_secure(&msg, &outsider))
+        .is_err());
+
+    builder
+        .add_signature(Signature::new_secure(&msg, &kp1))
+        .unwrap();
+    assert_eq!(builder.signed_weight(), 1);
+    assert!(!builder.is_complete());
+    assert!(builder.build().is_err());
+
+    // The partial state can be exported, signed elsewhere and merged back.
+    let mut remote = MultiSigBuilder::from_base64(&builder.to_base64().unwrap()).unwrap();
+    assert_eq!(remote, builder);
+    remote
+        .add_signature(Signature::new_secure(&msg, &kp2))
+        .unwrap();
+    builder.merge(remote).unwrap();
+    assert_eq!(builder.signed_weight(), 2);
+    assert!(builder.is_complete());
+
+    match builder.build().unwrap() {
+        GenericSignature::MultiSig(multisig) => {
+            assert!(multisig.verify_secure_generic(&msg, addr).is_ok())
+        }
+        _ => panic!("Expected a MultiSig"),
+    }
+}
+
+#[tokio::test]
+async fn signer_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("sui.keystore");
+    let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
+    let (address, _, _) = keystore
+        .generate_and_add_new_key(SignatureScheme::ED25519, None)
+        .unwrap();
+    assert!(KeystoreSigner::new(&keystore, SuiAddress::random_for_testing_only()).is_err());
+
+    let keystore_signer = KeystoreSigner::new(&keystore, address).unwrap();
+    let keypair = SuiKeyPair::Secp256k1(get_key_pair_from_rng(&mut StdRng::from_seed([0; 32])).1);
+    let signers: Vec<&dyn Signer> = vec![&keystore_signer, &keypair];
+    for signer in signers {
+        let sender = signer.address();
+        assert_eq!(sender, SuiAddress::from(&signer.public_key()));
+        let data = TransactionData::new_transfer_sui_with_dummy_gas_price(
+            SuiAddress::random_for_testing_only(),
+            sender,
+            None,
+            random_object_ref(),
+            1000,
+        );
+        let intent_message = IntentMessage::new(Intent::default(), data);
+        let signature = signer.sign(&intent_message).await.unwrap();
+        assert!(
+            Transaction::from_data(intent_message.value, Intent::default(), vec![signature])
+                .verify()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Archive of the checkpoints and object versions pruned from the stores of the nodes.
+//!
+//! Pruned nodes only keep recent history, reads of older data are served from an archive of
+//! every checkpoint, with the full contents of its transactions, and of every object version.
+//! The archive is only ever appended to: checkpoints and object versions are immutable once
+//! written, so that they can be written again by retries or by several archivers.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use object_store::aws::AmazonS3Builder;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::local::LocalFileSystem;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sui_types::base_types::{ObjectID, SequenceNumber, TransactionDigest};
+use sui_types::digests::CheckpointDigest;
+use sui_types::error::{SuiError, SuiResult};
+use sui_types::messages::{
+    Transaction, TransactionEffects, TransactionEffectsAPI, TransactionEvents,
+};
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointSequenceNumber, CheckpointTimestamp,
+    FullCheckpointContents,
+};
+use sui_types::object::Object;
+
+const CHECKPOINTS_DIR: &str = "checkpoints";
+const CHECKPOINT_DIGESTS_DIR: &str = "checkpoint_digests";
+const TRANSACTIONS_DIR: &str = "transactions";
+const OBJECTS_DIR: &str = "objects";
+
+/// A checkpoint with the full contents of its transactions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedCheckpoint {
+    pub summary: CertifiedCheckpointSummary,
+    pub contents: FullCheckpointContents,
+    /// Events of the transactions of the checkpoint that emitted events.
+    pub events: Vec<TransactionEvents>,
+}
+
+impl ArchivedCheckpoint {
+    /// The transaction `digest` of the checkpoint, `None` if it is not part of the checkpoint.
+    pub fn transaction(&self, digest: &TransactionDigest) -> Option<ArchivedTransaction> {
+        let data = self
+            .contents
+            .iter()
+            .find(|data| data.transaction.digest() == digest)?;
+        let events = data.effects.events_digest().and_then(|events_digest| {
+            self.events
+                .iter()
+                .find(|events| events.digest() == *events_digest)
+                .cloned()
+        });
+        Some(ArchivedTransaction {
+            checkpoint: self.summary.sequence_number,
+            timestamp_ms: self.summary.timestamp_ms,
+            transaction: data.transaction.clone(),
+            effects: data.effects.clone(),
+            events,
+        })
+    }
+}
+
+/// A transaction read from the archive, along with the checkpoint it was included in.
+#[derive(Clone, Debug)]
+pub struct ArchivedTransaction {
+    pub checkpoint: CheckpointSequenceNumber,
+    pub timestamp_ms: CheckpointTimestamp,
+    pub transaction: Transaction,
+    pub effects: TransactionEffects,
+    pub events: Option<TransactionEvents>,
+}
+
+#[async_trait]
+pub trait ArchivalStore: Send + Sync {
+    /// Archive `checkpoint`. Its transactions can be read as soon as it is archived.
+    async fn put_checkpoint(&self, checkpoint: &ArchivedCheckpoint) -> SuiResult;
+
+    /// Archive `objects`, each version of an object being archived once.
+    async fn put_objects(&self, objects: &[Object]) -> SuiResult;
+
+    async fn get_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> SuiResult<Option<ArchivedCheckpoint>>;
+
+    /// Sequence number of the archived checkpoint `digest`.
+    async fn get_checkpoint_sequence_number(
+        &self,
+        digest: &CheckpointDigest,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>>;
+
+    /// Sequence number of the archived checkpoint including the transaction `digest`.
+    async fn get_transaction_checkpoint(
+        &self,
+        digest: &TransactionDigest,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>>;
+
+    async fn get_object(
+        &self,
+        object_id: &ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult<Option<Object>>;
+
+    async fn get_checkpoint_by_digest(
+        &self,
+        digest: &CheckpointDigest,
+    ) -> SuiResult<Option<ArchivedCheckpoint>> {
+        match self.get_checkpoint_sequence_number(digest).await? {
+            Some(sequence_number) => self.get_checkpoint(sequence_number).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn get_transaction(
+        &self,
+        digest: &TransactionDigest,
+    ) -> SuiResult<Option<ArchivedTransaction>> {
+        let Some(sequence_number) = self.get_transaction_checkpoint(digest).await? else {
+            return Ok(None);
+        };
+        Ok(self
+            .get_checkpoint(sequence_number)
+            .await?
+            .and_then(|checkpoint| checkpoint.transaction(digest)))
+    }
+}
+
+/// [ArchivalStore] writing the archive to an object store, e.g. S3 or GCS, as BCS files:
+/// - `checkpoints/<sequence number>` holds the [ArchivedCheckpoint]s,
+/// - `checkpoint_digests/<digest>` and `transactions/<digest>` the sequence numbers of the
+///   checkpoints, by checkpoint digest and by digest of the transactions they include,
+/// - `objects/<object ID>/<version>` the object versions.
+///
+/// A checkpoint is written before the indexes pointing to it, so that indexed checkpoints can
+/// always be read.
+pub struct ObjectStoreArchive {
+    store: Arc<dyn ObjectStore>,
+    root: Path,
+}
+
+impl ObjectStoreArchive {
+    pub fn new(store: Arc<dyn ObjectStore>, root: Path) -> Self {
+        Self { store, root }
+    }
+
+    /// The archive at `url`, one of `s3://<bucket>/<prefix>`, `gs://<bucket>/<prefix>` or
+    /// `file://<directory>`. Credentials of S3 and GCS are read from the environment.
+    pub fn from_url(url: &str) -> SuiResult<Self> {
+        let invalid_url = |e: String| {
+            SuiError::GenericStorageError(format!("Invalid archival store URL {url}: {e}"))
+        };
+        let (scheme, location) = url
+            .split_once("://")
+            .ok_or_else(|| invalid_url("missing scheme".to_string()))?;
+        if scheme == "file" {
+            let store = LocalFileSystem::new_with_prefix(location)
+                .map_err(|e| invalid_url(e.to_string()))?;
+            return Ok(Self::new(Arc::new(store), Path::default()));
+        }
+        let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+        let store: Arc<dyn ObjectStore> = match scheme {
+            "s3" => Arc::new(
+                AmazonS3Builder::from_env()
+                    .with_bucket_name(bucket)
+                    .build()
+                    .map_err(|e| invalid_url(e.to_string()))?,
+            ),
+            "gs" => Arc::new(
+                GoogleCloudStorageBuilder::from_env()
+                    .with_bucket_name(bucket)
+                    .build()
+                    .map_err(|e| invalid_url(e.to_string()))?,
+            ),
+            _ => return Err(invalid_url(format!("unsupported scheme {scheme}"))),
+        };
+        let root = Path::parse(prefix).map_err(|e| invalid_url(e.to_string()))?;
+        Ok(Self::new(store, root))
+    }
+
+    fn path(&self, dir: &str, name: impl ToString) -> Path {
+        self.root.child(dir).child(name.to_string())
+    }
+
+    fn checkpoint_path(&self, sequence_number: CheckpointSequenceNumber) -> Path {
+        // Zero padded so that checkpoints are listed in order.
+        self.path(CHECKPOINTS_DIR, format!("{sequence_number:020}"))
+    }
+
+    fn object_path(&self, object_id: &ObjectID, version: SequenceNumber) -> Path {
+        self.path(OBJECTS_DIR, object_id)
+            .child(format!("{:020}", version.value()))
+    }
+
+    async fn put<T: Serialize>(&self, path: Path, value: &T) -> SuiResult {
+        let bytes = bcs::to_bytes(value).map_err(|e| {
+            SuiError::GenericStorageError(format!("Failed serializing {path}: {e}"))
+        })?;
+        self.store
+            .put(&path, bytes.into())
+            .await
+            .map_err(|e| SuiError::GenericStorageError(format!("Failed writing {path}: {e}")))
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: Path) -> SuiResult<Option<T>> {
+        let result = match self.store.get(&path).await {
+            Ok(result) => result,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(e) => {
+                return Err(SuiError::GenericStorageError(format!(
+                    "Failed reading {path}: {e}"
+                )))
+            }
+        };
+        let bytes = result
+            .bytes()
+            .await
+            .map_err(|e| SuiError::GenericStorageError(format!("Failed reading {path}: {e}")))?;
+        bcs::from_bytes(&bytes)
+            .map(Some)
+            .map_err(|e| SuiError::GenericStorageError(format!("Failed deserializing {path}: {e}")))
+    }
+}
+
+#[async_trait]
+impl ArchivalStore for ObjectStoreArchive {
+    async fn put_checkpoint(&self, checkpoint: &ArchivedCheckpoint) -> SuiResult {
+        let sequence_number = checkpoint.summary.sequence_number;
+        self.put(self.checkpoint_path(sequence_number), checkpoint)
+            .await?;
+
+        let digest_index = self.put(
+            self.path(CHECKPOINT_DIGESTS_DIR, checkpoint.summary.digest()),
+            &sequence_number,
+        );
+        let transaction_indexes = try_join_all(checkpoint.contents.iter().map(|data| {
+            self.put(
+                self.path(TRANSACTIONS_DIR, data.transaction.digest()),
+                &sequence_number,
+            )
+        }));
+        futures::try_join!(digest_index, transaction_indexes)?;
+        Ok(())
+    }
+
+    async fn put_objects(&self, objects: &[Object]) -> SuiResult {
+        try_join_all(
+            objects
+                .iter()
+                .map(|object| self.put(self.object_path(&object.id(), object.version()), object)),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> SuiResult<Option<ArchivedCheckpoint>> {
+        self.get(self.checkpoint_path(sequence_number)).await
+    }
+
+    async fn get_checkpoint_sequence_number(
+        &self,
+        digest: &CheckpointDigest,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>> {
+        self.get(self.path(CHECKPOINT_DIGESTS_DIR, digest)).await
+    }
+
+    async fn get_transaction_checkpoint(
+        &self,
+        digest: &TransactionDigest,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>> {
+        self.get(self.path(TRANSACTIONS_DIR, digest)).await
+    }
+
+    async fn get_object(
+        &self,
+        object_id: &ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult<Option<Object>> {
+        self.get(self.object_path(object_id, version)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::ExecutionData;
+    use sui_types::utils::{
+        create_fake_transaction, make_committee_key, mock_certified_checkpoint,
+    };
+
+    #[tokio::test]
+    async fn test_archive_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive =
+            ObjectStoreArchive::from_url(&format!("file://{}", dir.path().display())).unwrap();
+
+        let transaction = create_fake_transaction().into_inner();
+        let effects = TransactionEffects::new_with_tx(&transaction);
+        let (keys, committee) = make_committee_key(&mut rand::thread_rng());
+        let checkpoint = ArchivedCheckpoint {
+            summary: mock_certified_checkpoint(keys.iter(), committee, 7),
+            contents: FullCheckpointContents::new_with_causally_ordered_transactions([
+                ExecutionData::new(transaction.clone(), effects.clone()),
+            ]),
+            events: vec![],
+        };
+        let digest = *transaction.digest();
+        assert!(archive.get_transaction(&digest).await.unwrap().is_none());
+
+        archive.put_checkpoint(&checkpoint).await.unwrap();
+        assert_eq!(
+            archive.get_checkpoint(7).await.unwrap(),
+            Some(checkpoint.clone())
+        );
+        assert_eq!(
+            archive
+                .get_checkpoint_by_digest(checkpoint.summary.digest())
+                .await
+                .unwrap(),
+            Some(checkpoint.clone())
+        );
+        let archived = archive.get_transaction(&digest).await.unwrap().unwrap();
+        assert_eq!(archived.checkpoint, 7);
+        assert_eq!(archived.transaction, transaction);
+        assert_eq!(archived.effects, effects);
+        assert!(archived.events.is_none());
+
+        let object = Object::immutable_with_id_for_testing(ObjectID::random());
+        archive.put_objects(&[object.clone()]).await.unwrap();
+        assert_eq!(
+            archive
+                .get_object(&object.id(), object.version())
+                .await
+                .unwrap(),
+            Some(object.clone())
+        );
+        assert!(archive
+            .get_object(
+                &object.id(),
+                SequenceNumber::from_u64(object.version().value() + 1)
+            )
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Binary export of the executed checkpoints, for ETL pipelines and replayers.
+//!
+//! An export file is a header followed by the checkpoints, each checkpoint being a sequence of
+//! frames:
+//! - the header is the magic bytes `SCKX` and the version of the format, one byte,
+//! - a frame is its kind, one byte, the length of its payload, a little endian `u32`, and its
+//!   payload, a BCS value,
+//! - a checkpoint is a [CertifiedCheckpointSummary] frame, a [CheckpointContents] frame and, for
+//!   each transaction of the contents in order, a [TransactionEffects] frame followed by a
+//!   [TransactionEvents] frame if the effects have an events digest.
+//!
+//! Frames are written as they are read from the node, so that files can be read while they are
+//! written and a file cut by a crash can be truncated back to its last complete checkpoint.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sui_types::message_envelope::Message;
+use sui_types::messages::{TransactionEffects, TransactionEffectsAPI, TransactionEvents};
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointSequenceNumber,
+};
+
+pub const MAGIC: [u8; 4] = *b"SCKX";
+pub const FORMAT_VERSION: u8 = 1;
+const HEADER_LEN: u64 = MAGIC.len() as u64 + 1;
+/// Guards against allocating the length of a corrupted frame.
+const MAX_FRAME_LEN: u32 = 256 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum FrameKind {
+    Summary = 0,
+    Contents = 1,
+    Effects = 2,
+    Events = 3,
+}
+
+impl TryFrom<u8> for FrameKind {
+    type Error = io::Error;
+
+    fn try_from(kind: u8) -> io::Result<Self> {
+        Ok(match kind {
+            0 => Self::Summary,
+            1 => Self::Contents,
+            2 => Self::Effects,
+            3 => Self::Events,
+            _ => return Err(invalid_data(format!("Unknown frame kind {kind}"))),
+        })
+    }
+}
+
+/// A checkpoint with the effects and events of its transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportedCheckpoint {
+    pub summary: CertifiedCheckpointSummary,
+    pub contents: CheckpointContents,
+    /// Effects of the transactions, in the order of the contents.
+    pub effects: Vec<TransactionEffects>,
+    /// Events of the transactions of the checkpoint that emitted events.
+    pub events: Vec<TransactionEvents>,
+}
+
+impl ExportedCheckpoint {
+    /// The effects of the transactions of the checkpoint, with their events.
+    pub fn transactions(
+        &self,
+    ) -> impl Iterator<Item = (&TransactionEffects, Option<&TransactionEvents>)> {
+        self.effects.iter().map(|effects| {
+            let events = effects
+                .events_digest()
+                .and_then(|digest| self.events.iter().find(|events| events.digest() == *digest));
+            (effects, events)
+        })
+    }
+}
+
+/// Writes checkpoints in the export format.
+pub struct CheckpointExportWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CheckpointExportWriter<W> {
+    /// A writer of a new export, writing its header to `writer`.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[FORMAT_VERSION])?;
+        Ok(Self { writer })
+    }
+
+    /// A writer appending to an export whose header was already written.
+    pub fn new_appending(writer: W) -> Self {
+        Self { writer }
+    }
+
+    pub fn write_checkpoint(&mut self, checkpoint: &ExportedCheckpoint) -> io::Result<()> {
+        // Checked before writing anything, so that an invalid checkpoint is not partially written.
+        if checkpoint.effects.len() != checkpoint.contents.size() {
+            return Err(invalid_input(format!(
+                "Checkpoint {} has {} transactions but {} effects",
+                checkpoint.summary.sequence_number,
+                checkpoint.contents.size(),
+                checkpoint.effects.len()
+            )));
+        }
+        let transactions = checkpoint.transactions().collect::<Vec<_>>();
+        for ((effects, events), digests) in transactions.iter().zip(checkpoint.contents.iter()) {
+            if effects.digest() != digests.effects {
+                return Err(invalid_input(format!(
+                    "Effects of transaction {} do not match checkpoint {}",
+                    digests.transaction, checkpoint.summary.sequence_number
+                )));
+            }
+            if let (Some(digest), None) = (effects.events_digest(), events) {
+                return Err(invalid_input(format!(
+                    "Missing events {digest} of transaction {}",
+                    digests.transaction
+                )));
+            }
+        }
+
+        self.write_frame(FrameKind::Summary, &checkpoint.summary)?;
+        self.write_frame(FrameKind::Contents, &checkpoint.contents)?;
+        for (effects, events) in transactions {
+            self.write_frame(FrameKind::Effects, effects)?;
+            if let Some(events) = events {
+                self.write_frame(FrameKind::Events, events)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_frame<T: Serialize>(&mut self, kind: FrameKind, value: &T) -> io::Result<()> {
+        let payload = bcs::to_bytes(value).map_err(|e| invalid_input(e.to_string()))?;
+        let len = u32::try_from(payload.len())
+            .ok()
+            .filter(|len| *len <= MAX_FRAME_LEN)
+            .ok_or_else(|| invalid_input(format!("Frame of {} bytes", payload.len())))?;
+        self.writer.write_all(&[kind as u8])?;
+        self.writer.write_all(&len.to_le_bytes())?;
+        self.writer.write_all(&payload)
+    }
+}
+
+impl CheckpointExportWriter<BufWriter<File>> {
+    /// Opens the export file at `path` for appending, creating it if needed. A checkpoint left
+    /// incomplete at the end of the file, e.g. by a crash, is truncated. Returns the writer and
+    /// the sequence number of the last checkpoint of the file, if any.
+    pub fn open(path: &Path) -> io::Result<(Self, Option<CheckpointSequenceNumber>)> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path)?;
+        let (last_checkpoint, len) = if file.metadata()?.len() < HEADER_LEN {
+            (None, 0)
+        } else {
+            let mut reader = CheckpointExportReader::new(BufReader::new(&file))?;
+            let mut last_checkpoint = None;
+            loop {
+                match reader.read_checkpoint() {
+                    Ok(Some(checkpoint)) => {
+                        last_checkpoint = Some(checkpoint.summary.sequence_number)
+                    }
+                    Ok(None) => break,
+                    Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+                    Err(e) => return Err(e),
+                }
+            }
+            (last_checkpoint, reader.checkpoint_end)
+        };
+        file.set_len(len)?;
+        file.seek(SeekFrom::End(0))?;
+        let writer = if len == 0 {
+            CheckpointExportWriter::new(BufWriter::new(file))?
+        } else {
+            CheckpointExportWriter::new_appending(BufWriter::new(file))
+        };
+        Ok((writer, last_checkpoint))
+    }
+}
+
+/// Reads the checkpoints of an export, as written by [CheckpointExportWriter].
+pub struct CheckpointExportReader<R: Read> {
+    reader: R,
+    position: u64,
+    /// Position of the end of the last checkpoint read.
+    checkpoint_end: u64,
+}
+
+impl<R: Read> CheckpointExportReader<R> {
+    /// A reader of the export `reader`, checking its header.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0; HEADER_LEN as usize];
+        reader.read_exact(&mut header)?;
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("Not a checkpoint export".to_string()));
+        }
+        let version = header[MAGIC.len()];
+        if version != FORMAT_VERSION {
+            return Err(invalid_data(format!(
+                "Unsupported checkpoint export version {version}"
+            )));
+        }
+        Ok(Self {
+            reader,
+            position: HEADER_LEN,
+            checkpoint_end: HEADER_LEN,
+        })
+    }
+
+    /// The next checkpoint of the export, `None` at the end of the export. Fails with
+    /// [ErrorKind::UnexpectedEof] if the export ends in the middle of a checkpoint.
+    pub fn read_checkpoint(&mut self) -> io::Result<Option<ExportedCheckpoint>> {
+        let Some(kind) = self.read_kind()? else {
+            return Ok(None);
+        };
+        let summary: CertifiedCheckpointSummary = self.read_payload(kind, FrameKind::Summary)?;
+        let contents: CheckpointContents = self.read_frame(FrameKind::Contents)?;
+        let mut effects = Vec::with_capacity(contents.size());
+        let mut events = vec![];
+        for _ in 0..contents.size() {
+            let tx_effects: TransactionEffects = self.read_frame(FrameKind::Effects)?;
+            if tx_effects.events_digest().is_some() {
+                events.push(self.read_frame(FrameKind::Events)?);
+            }
+            effects.push(tx_effects);
+        }
+        self.checkpoint_end = self.position;
+        Ok(Some(ExportedCheckpoint {
+            summary,
+            contents,
+            effects,
+            events,
+        }))
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Kind of the next frame, `None` at the end of the export.
+    fn read_kind(&mut self) -> io::Result<Option<FrameKind>> {
+        let mut kind = [0];
+        match self.reader.read_exact(&mut kind) {
+            Ok(()) => {
+                self.position += 1;
+                FrameKind::try_from(kind[0]).map(Some)
+            }
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read_frame<T: DeserializeOwned>(&mut self, expected: FrameKind) -> io::Result<T> {
+        let kind = self
+            .read_kind()?
+            .ok_or_else(|| io::Error::from(ErrorKind::UnexpectedEof))?;
+        self.read_payload(kind, expected)
+    }
+
+    fn read_payload<T: DeserializeOwned>(
+        &mut self,
+        kind: FrameKind,
+        expected: FrameKind,
+    ) -> io::Result<T> {
+        if kind != expected {
+            return Err(invalid_data(format!(
+                "Expected a {expected:?} frame, found a {kind:?} frame"
+            )));
+        }
+        let mut len = [0; 4];
+        self.reader.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len);
+        if len > MAX_FRAME_LEN {
+            return Err(invalid_data(format!("Frame of {len} bytes")));
+        }
+        let mut payload = vec![0; len as usize];
+        self.reader.read_exact(&mut payload)?;
+        self.position += 4 + len as u64;
+        bcs::from_bytes(&payload).map_err(|e| invalid_data(format!("Invalid {kind:?} frame: {e}")))
+    }
+}
+
+impl<R: Read> Iterator for CheckpointExportReader<R> {
+    type Item = io::Result<ExportedCheckpoint>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_checkpoint().transpose()
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::ExecutionDigests;
+    use sui_types::messages::TransactionEffectsV1;
+    use sui_types::utils::{
+        create_fake_transaction, make_committee_key, mock_certified_checkpoint,
+    };
+
+    fn checkpoint(sequence_number: CheckpointSequenceNumber) -> ExportedCheckpoint {
+        let transaction = create_fake_transaction().into_inner();
+        let events = TransactionEvents { data: vec![] };
+        let effects = vec![
+            TransactionEffects::new_with_tx(&transaction),
+            TransactionEffects::V1(TransactionEffectsV1 {
+                events_digest: Some(events.digest()),
+                ..Default::default()
+            }),
+        ];
+        let (keys, committee) = make_committee_key(&mut rand::thread_rng());
+        ExportedCheckpoint {
+            summary: mock_certified_checkpoint(keys.iter(), committee, sequence_number),
+            contents: CheckpointContents::new_with_causally_ordered_transactions(
+                effects
+                    .iter()
+                    .map(|e| ExecutionDigests::new(*e.transaction_digest(), e.digest())),
+            ),
+            effects,
+            events: vec![events],
+        }
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let checkpoints = vec![checkpoint(1), checkpoint(2)];
+        let mut writer = CheckpointExportWriter::new(vec![]).unwrap();
+        for checkpoint in &checkpoints {
+            writer.write_checkpoint(checkpoint).unwrap();
+        }
+        let bytes = writer.into_inner();
+
+        let reader = CheckpointExportReader::new(bytes.as_slice()).unwrap();
+        let read = reader.collect::<io::Result<Vec<_>>>().unwrap();
+        assert_eq!(read, checkpoints);
+        let transactions = read[0].transactions().collect::<Vec<_>>();
+        assert!(transactions[0].1.is_none());
+        assert_eq!(transactions[1].1, Some(&checkpoints[0].events[0]));
+
+        // A checkpoint cut in the middle fails to be read.
+        let mut reader = CheckpointExportReader::new(&bytes[..bytes.len() - 10]).unwrap();
+        assert!(reader.read_checkpoint().unwrap().is_some());
+        let err = reader.read_checkpoint().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        assert!(CheckpointExportReader::new(&b"JSON{}"[..]).is_err());
+
+        // Effects not matching the contents are rejected.
+        let mut mismatched = checkpoint(3);
+        mismatched.effects.swap(0, 1);
+        let mut writer = CheckpointExportWriter::new(vec![]).unwrap();
+        assert!(writer.write_checkpoint(&mismatched).is_err());
+    }
+
+    #[test]
+    fn test_open_truncates_incomplete_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export");
+
+        let (mut writer, last) = CheckpointExportWriter::open(&path).unwrap();
+        assert_eq!(last, None);
+        writer.write_checkpoint(&checkpoint(1)).unwrap();
+        writer.write_checkpoint(&checkpoint(2)).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        // Cut the second checkpoint, as a crash while writing it would.
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(file.metadata().unwrap().len() - 10).unwrap();
+        drop(file);
+
+        let (mut writer, last) = CheckpointExportWriter::open(&path).unwrap();
+        assert_eq!(last, Some(1));
+        let second = checkpoint(2);
+        writer.write_checkpoint(&second).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let reader = CheckpointExportReader::new(BufReader::new(File::open(&path).unwrap()))
+            .unwrap()
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(reader.len(), 2);
+        assert_eq!(reader[1], second);
+    }
+}
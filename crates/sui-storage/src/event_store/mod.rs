@@ -48,6 +48,7 @@ pub const OBJECT_VERSION_KEY: &str = "obj_ver";
 pub const AMOUNT_KEY: &str = "amount";
 pub const BALANCE_CHANGE_TYPE_KEY: &str = "change_type";
 pub const OBJECT_DIGEST_KEY: &str = "obj_dig";
+pub const PREVIOUS_OWNER_KEY: &str = "prev_owner";
 
 /// One event pulled out from the EventStore
 #[allow(unused)]
@@ -82,6 +83,7 @@ pub struct StoredEvent {
     /// * `version` - used by TransferObject
     /// * `destination` - address, in hex bytes, used by TransferObject
     /// * `type` - used by TransferObject (TransferType - Coin, ToAddress, ToObject)
+    /// * `prev_owner` - owner of the previous object version, in JSON, used by TransferObject
     fields: BTreeMap<SharedStr, EventValue>, // Change this to something based on CBOR for binary values, or our own value types for efficiency
     /// Contents for MoveEvent
     move_event_contents: Option<Vec<u8>>,
@@ -143,6 +145,7 @@ impl StoredEvent {
         let package_id = self.package_id()?;
         let transaction_module = self.transaction_module()?;
         let sender = self.sender()?;
+        let previous_owner = self.previous_owner()?;
         let recipient = self.recipient()?;
         let object_id = self.object_id()?;
         let object_type = self.object_type()?;
@@ -153,6 +156,7 @@ impl StoredEvent {
             package_id,
             transaction_module,
             sender,
+            previous_owner,
             recipient,
             object_type,
             object_id,
@@ -341,6 +345,12 @@ impl StoredEvent {
         })
     }
 
+    fn previous_owner(&self) -> Result<Option<Owner>, anyhow::Error> {
+        self.extract_string_field(PREVIOUS_OWNER_KEY)?
+            .map(|owner| serde_json::from_str(&owner).map_err(|e| anyhow!(e)))
+            .transpose()
+    }
+
     fn object_digest(&self) -> Result<Option<ObjectDigest>, anyhow::Error> {
         self.extract_string_field(OBJECT_DIGEST_KEY)?
             .map(|opt| {
@@ -390,9 +400,11 @@ impl TryInto<SuiEventEnvelope> for StoredEvent {
             }
             Err(e) => anyhow::bail!("Invalid EventType {event_type_str}: {e:?}"),
         }?;
+        // Events are stored as transactions execute, before they are checkpointed.
         Ok(SuiEventEnvelope {
             timestamp,
             tx_digest,
+            checkpoint: None,
             id: event_id,
             event,
         })
@@ -299,6 +299,11 @@ impl SqlEventStore {
             if let Some(digest) = event.event.digest() {
                 fields.insert(OBJECT_DIGEST_KEY, ObjectDigest::base58_encode(&digest));
             }
+            if let Some(Ok(previous_owner)) =
+                event.event.previous_owner().map(serde_json::to_string)
+            {
+                fields.insert(PREVIOUS_OWNER_KEY, previous_owner);
+            }
             json!(fields).to_string()
         }
     }
@@ -743,6 +748,10 @@ mod tests {
         assert_eq!(queried.object_id, orig.event.object_id());
         assert_eq!(queried.sender, orig.event.sender());
         assert_eq!(queried.recipient.as_ref(), orig.event.recipient());
+        assert_eq!(
+            queried.previous_owner().unwrap().as_ref(),
+            orig.event.previous_owner()
+        );
         assert_eq!(queried.object_type, orig.event.object_type());
         assert_eq!(
             queried.object_version().unwrap().as_ref(),
@@ -966,7 +975,7 @@ mod tests {
 
         test_queried_event_vs_test_envelope(&transfer_event, target_event);
 
-        assert_eq!(transfer_event.fields.len(), 1); // obj ver
+        assert_eq!(transfer_event.fields.len(), 2); // obj ver, previous owner
 
         Ok(())
     }
@@ -1059,7 +1068,7 @@ mod tests {
             .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[2]);
-        assert_eq!(queried_events[0].fields.len(), 1);
+        assert_eq!(queried_events[0].fields.len(), 2);
 
         // Query with wrong time range, return 0 events
         let queried_events = db
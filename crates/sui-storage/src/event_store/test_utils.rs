@@ -161,6 +161,7 @@ pub fn new_test_transfer_event(
     sender: Option<SuiAddress>,
     recipient: Option<Owner>,
 ) -> EventEnvelope {
+    let sender = sender.unwrap_or_else(SuiAddress::random_for_testing_only);
     EventEnvelope::new(
         timestamp,
         digest,
@@ -169,7 +170,8 @@ pub fn new_test_transfer_event(
         Event::TransferObject {
             package_id: ObjectID::random(),
             transaction_module: Identifier::new("module").unwrap(),
-            sender: sender.unwrap_or_else(SuiAddress::random_for_testing_only),
+            sender,
+            previous_owner: Some(Owner::AddressOwner(sender)),
             recipient: recipient
                 .unwrap_or_else(|| Owner::AddressOwner(SuiAddress::random_for_testing_only())),
             object_type: object_type.to_string(),
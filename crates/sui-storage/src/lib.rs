@@ -4,6 +4,8 @@
 pub mod indexes;
 pub use indexes::{IndexStore, IndexStoreTables};
 
+pub mod archival_store;
+pub mod checkpoint_export;
 pub mod event_store;
 pub mod mutex_table;
 pub mod write_ahead_log;
@@ -84,6 +84,10 @@ impl WritePathPendingTransactionLog {
         write_batch.write().map_err(SuiError::from)
     }
 
+    pub fn is_pending(&self, tx: &TransactionDigest) -> SuiResult<bool> {
+        Ok(self.pending_transactions.logs.contains_key(tx)?)
+    }
+
     pub fn load_all_pending_transactions(&self) -> Vec<VerifiedTransaction> {
         self.pending_transactions
             .logs
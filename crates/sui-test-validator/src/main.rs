@@ -34,6 +34,14 @@ struct Args {
     /// Port to start the Sui faucet on
     #[clap(long, default_value = "9123")]
     faucet_port: u16,
+
+    /// Number of validators to start
+    #[clap(long)]
+    num_validators: Option<usize>,
+
+    /// Epoch duration in milliseconds
+    #[clap(long)]
+    epoch_duration_ms: Option<u64>,
 }
 
 #[tokio::main]
@@ -48,6 +56,8 @@ async fn main() -> Result<()> {
         env: Env::NewLocal,
         fullnode_address: Some(format!("127.0.0.1:{}", args.fullnode_rpc_port)),
         faucet_address: None,
+        num_validators: args.num_validators,
+        epoch_duration_ms: args.epoch_duration_ms,
     })
     .await?;
 
@@ -0,0 +1,75 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::anyhow;
+use rocksdb::properties;
+use std::path::Path;
+use std::sync::Arc;
+use sui_storage::default_db_options;
+use typed_store::rocks::{open_cf, MetricConf, RocksDB};
+
+use super::db_dump::list_tables;
+
+/// Size on disk of a table, before and after its compaction.
+pub struct CompactionSummary {
+    pub table_name: String,
+    pub size_before: u64,
+    pub size_after: u64,
+}
+
+/// Opens the DB at `path` for writing, with all of its tables. This works for any store
+/// (validator, index, epoch, committee...), but fails if the DB is opened by a running node.
+fn open_db(path: &Path) -> anyhow::Result<(Arc<RocksDB>, Vec<String>)> {
+    let tables = list_tables(path.to_path_buf())?;
+    let cfs = tables.iter().map(|t| t.as_str()).collect::<Vec<_>>();
+    let db = open_cf(
+        path,
+        Some(default_db_options(None, None).0.options),
+        MetricConf::default(),
+        &cfs,
+    )?;
+    Ok((db, tables))
+}
+
+fn table_size(db: &RocksDB, table_name: &str) -> anyhow::Result<u64> {
+    let cf = db
+        .cf_handle(table_name)
+        .ok_or_else(|| anyhow!("Table {table_name} not found"))?;
+    Ok(db
+        .property_int_value_cf(&cf, properties::TOTAL_SST_FILES_SIZE)?
+        .unwrap_or_default())
+}
+
+/// Runs a manual compaction of the whole key range of `table_name`, or of all the tables of the
+/// DB if None. Blocks until the compaction completes.
+pub fn compact(path: &Path, table_name: Option<&str>) -> anyhow::Result<Vec<CompactionSummary>> {
+    let (db, tables) = open_db(path)?;
+    let tables = match table_name {
+        Some(table_name) => vec![table_name.to_string()],
+        None => tables,
+    };
+    tables
+        .into_iter()
+        .map(|table_name| {
+            let size_before = table_size(&db, &table_name)?;
+            let cf = db
+                .cf_handle(&table_name)
+                .ok_or_else(|| anyhow!("Table {table_name} not found"))?;
+            db.compact_range_cf(&cf, None::<&[u8]>, None::<&[u8]>);
+            let size_after = table_size(&db, &table_name)?;
+            Ok(CompactionSummary {
+                table_name,
+                size_before,
+                size_after,
+            })
+        })
+        .collect()
+}
+
+/// Creates a RocksDB checkpoint of the DB at `output`, which must not exist. The checkpoint
+/// hard links the SST files of the DB when `output` is on the same filesystem.
+pub fn checkpoint(path: &Path, output: &Path) -> anyhow::Result<()> {
+    let (db, _) = open_db(path)?;
+    db.checkpoint(output)?;
+    Ok(())
+}
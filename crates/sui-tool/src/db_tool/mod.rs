@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use self::db_dump::{dump_table, duplicate_objects_summary, list_tables, table_summary, StoreName};
+use self::db_maintenance::{checkpoint, compact};
 use clap::Parser;
 use std::path::PathBuf;
 use sui_types::base_types::EpochId;
 
 pub mod db_dump;
+pub mod db_maintenance;
 
 #[derive(Parser)]
 #[clap(rename_all = "kebab-case")]
@@ -15,6 +17,26 @@ pub enum DbToolCommand {
     Dump(Dump),
     TableSummary(Dump),
     DuplicatesSummary,
+    /// Manually compact the tables of the DB. The node using the DB must be stopped.
+    Compact(Compact),
+    /// Create a RocksDB checkpoint of the DB. The node using the DB must be stopped.
+    Checkpoint(Checkpoint),
+}
+
+#[derive(Parser)]
+#[clap(rename_all = "kebab-case")]
+pub struct Compact {
+    /// The name of the table to compact, all the tables of the DB are compacted if not set
+    #[clap(long = "table-name")]
+    table_name: Option<String>,
+}
+
+#[derive(Parser)]
+#[clap(rename_all = "kebab-case")]
+pub struct Checkpoint {
+    /// The directory to create the checkpoint in, which must not exist
+    #[clap(long = "output")]
+    output: PathBuf,
 }
 
 #[derive(Parser)]
@@ -56,7 +78,23 @@ pub fn execute_db_tool_command(db_path: PathBuf, cmd: DbToolCommand) -> anyhow::
             print_db_table_summary(d.store_name, d.epoch, db_path, &d.table_name)
         }
         DbToolCommand::DuplicatesSummary => print_db_duplicates_summary(db_path),
+        DbToolCommand::Compact(c) => compact_db(db_path, c.table_name.as_deref()),
+        DbToolCommand::Checkpoint(c) => {
+            checkpoint(&db_path, &c.output)?;
+            println!("Created checkpoint of {:?} at {:?}", db_path, c.output);
+            Ok(())
+        }
+    }
+}
+
+pub fn compact_db(db_path: PathBuf, table_name: Option<&str>) -> anyhow::Result<()> {
+    for summary in compact(&db_path, table_name)? {
+        println!(
+            "{}: {} bytes -> {} bytes",
+            summary.table_name, summary.size_before, summary.size_after
+        );
     }
+    Ok(())
 }
 
 pub fn print_db_all_tables(db_path: PathBuf) -> anyhow::Result<()> {
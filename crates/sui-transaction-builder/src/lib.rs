@@ -17,8 +17,8 @@ use sui_adapter::adapter::resolve_and_type_check;
 use sui_adapter::execution_mode::ExecutionMode;
 use sui_json::{resolve_move_function_args, SuiJsonCallArg, SuiJsonValue};
 use sui_json_rpc_types::{
-    RPCTransactionRequestParams, SuiData, SuiObjectDataOptions, SuiObjectInfo, SuiObjectResponse,
-    SuiTypeTag,
+    BatchTransferParams, RPCTransactionRequestParams, SuiData, SuiObjectDataOptions, SuiObjectInfo,
+    SuiObjectResponse, SuiTypeTag,
 };
 use sui_protocol_config::ProtocolConfig;
 use sui_types::base_types::{ObjectID, ObjectRef, ObjectType, SuiAddress};
@@ -26,16 +26,20 @@ use sui_types::coin::{Coin, LockedCoin};
 use sui_types::error::UserInputError;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
-    CallArg, InputObjectKind, MoveCall, ObjectArg, SingleTransactionKind, TransactionData,
-    TransactionKind, TransferObject,
+    CallArg, GasData, InputObjectKind, MoveCall, ObjectArg, SingleTransactionKind, TransactionData,
+    TransactionDataAPI, TransactionKind, TransferObject,
 };
 
 use sui_types::governance::{
     ADD_DELEGATION_LOCKED_COIN_FUN_NAME, ADD_DELEGATION_MUL_COIN_FUN_NAME,
     WITHDRAW_DELEGATION_FUN_NAME,
 };
-use sui_types::move_package::MovePackage;
+use sui_types::move_package::{
+    compute_digest_for_modules_and_deps, MovePackage, UpgradeCap, AUTHORIZE_UPGRADE_FUNCTION_NAME,
+    COMMIT_UPGRADE_FUNCTION_NAME, PACKAGE_MODULE_NAME,
+};
 use sui_types::object::{Object, Owner};
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::sui_system_state::SUI_SYSTEM_MODULE_NAME;
 use sui_types::{
     coin, fp_ensure, SUI_FRAMEWORK_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_ID,
@@ -87,29 +91,34 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
             let required_gas_amount = (budget as u128) * (gas_price as u128);
 
             for obj in gas_objs {
-                let response = self
-                    .0
-                    .get_object_with_options(obj.object_id, SuiObjectDataOptions::new().with_bcs())
-                    .await?;
-                let obj = response.object()?;
-                let gas: GasCoin = bcs::from_bytes(
-                    &obj.bcs
-                        .as_ref()
-                        .ok_or_else(|| anyhow!("bcs field is unexpectedly empty"))?
-                        .try_as_move()
-                        .ok_or_else(|| anyhow!("Cannot parse move object to gas object"))?
-                        .bcs_bytes,
-                )?;
-                if !input_objects.contains(&obj.object_id)
+                let (object_ref, gas) = self.get_gas_coin(obj.object_id).await?;
+                if !input_objects.contains(&object_ref.0)
                     && (gas.value() as u128) >= required_gas_amount
                 {
-                    return Ok(obj.object_ref());
+                    return Ok(object_ref);
                 }
             }
             Err(anyhow!("Cannot find gas coin for signer address [{signer}] with amount sufficient for the required gas amount [{required_gas_amount}]."))
         }
     }
 
+    async fn get_gas_coin(&self, object_id: ObjectID) -> anyhow::Result<(ObjectRef, GasCoin)> {
+        let response = self
+            .0
+            .get_object_with_options(object_id, SuiObjectDataOptions::new().with_bcs())
+            .await?;
+        let obj = response.object()?;
+        let gas: GasCoin = bcs::from_bytes(
+            &obj.bcs
+                .as_ref()
+                .ok_or_else(|| anyhow!("bcs field is unexpectedly empty"))?
+                .try_as_move()
+                .ok_or_else(|| anyhow!("Cannot parse move object to gas object"))?
+                .bcs_bytes,
+        )?;
+        Ok((obj.object_ref(), gas))
+    }
+
     pub async fn transfer_object(
         &self,
         signer: SuiAddress,
@@ -227,6 +236,71 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         )
     }
 
+    /// Build the PaySui transactions paying `amounts` to `recipients` with the SUI coins of
+    /// `signer`, selected largest first. Locked coins are never selected.
+    ///
+    /// The payments are split, in order, across as many transactions as needed for each of them
+    /// to use at most `max_input_coins` coins, default to the maximum number of gas coins of a
+    /// transaction. Every transaction pays its own gas with its coins, so the transactions can be
+    /// executed independently.
+    pub async fn auto_pay_sui(
+        &self,
+        signer: SuiAddress,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+        max_input_coins: Option<usize>,
+        gas_budget: u64,
+    ) -> anyhow::Result<Vec<TransactionData>> {
+        fp_ensure!(
+            !recipients.is_empty() && recipients.len() == amounts.len(),
+            UserInputError::InvalidBatchTransaction {
+                error: "Recipients and amounts must be non-empty and of the same length".to_owned(),
+            }
+            .into()
+        );
+        let max_gas_coins =
+            ProtocolConfig::get_for_min_version().max_gas_payment_objects() as usize;
+        let max_input_coins = max_input_coins.map_or(max_gas_coins, |max| max.min(max_gas_coins));
+        ensure!(max_input_coins > 0, "max_input_coins must be positive");
+
+        let objects = self.0.get_objects_owned_by_address(signer).await?;
+        let handles: Vec<_> = objects
+            .iter()
+            .filter(|obj| obj.type_ == GasCoin::type_().to_string())
+            .map(|obj| self.get_gas_coin(obj.object_id))
+            .collect();
+        let mut coins = join_all(handles)
+            .await
+            .into_iter()
+            .map(|coin| coin.map(|(object_ref, gas)| (object_ref, gas.value())))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        coins.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        let gas_price = self.0.get_reference_gas_price().await?;
+        let payments = recipients.into_iter().zip(amounts).collect::<Vec<_>>();
+        schedule_pay_sui(
+            &coins,
+            &payments,
+            max_input_coins,
+            (gas_budget as u128) * (gas_price as u128),
+        )?
+        .into_iter()
+        .map(|(mut coin_refs, payments)| {
+            let gas_object_ref = coin_refs.remove(0);
+            let (recipients, amounts) = payments.into_iter().unzip();
+            TransactionData::new_pay_sui(
+                signer,
+                coin_refs,
+                recipients,
+                amounts,
+                gas_object_ref,
+                gas_budget,
+                gas_price,
+            )
+        })
+        .collect()
+    }
+
     pub async fn pay_all_sui(
         &self,
         signer: SuiAddress,
@@ -309,10 +383,7 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         let module = Identifier::from_str(module)?;
         let function = Identifier::from_str(function)?;
 
-        let type_args = type_args
-            .into_iter()
-            .map(|ty| ty.try_into())
-            .collect::<Result<Vec<_>, _>>()?;
+        let type_args = type_args.into_iter().map(TypeTag::from).collect::<Vec<_>>();
 
         let call_args = self
             .resolve_and_checks_json_args(package, &module, &function, &type_args, call_args)
@@ -442,6 +513,73 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         ))
     }
 
+    /// Upgrade `package_id` to `compiled_modules`, depending on `dependencies`, with the
+    /// `UpgradeCap` `upgrade_capability` of the package: authorize the upgrade with the policy of
+    /// the cap, upgrade the package, and commit the upgrade to the cap.
+    pub async fn upgrade(
+        &self,
+        sender: SuiAddress,
+        package_id: ObjectID,
+        compiled_modules: Vec<Vec<u8>>,
+        dependencies: Vec<ObjectID>,
+        upgrade_capability: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> anyhow::Result<TransactionData> {
+        let response = self
+            .0
+            .get_object_with_options(upgrade_capability, SuiObjectDataOptions::new().with_bcs())
+            .await?;
+        let cap_object = response.object()?;
+        let cap: UpgradeCap = bcs::from_bytes(
+            &cap_object
+                .bcs
+                .as_ref()
+                .ok_or_else(|| anyhow!("bcs field is unexpectedly empty"))?
+                .try_as_move()
+                .ok_or_else(|| anyhow!("Cannot parse move object to upgrade capability"))?
+                .bcs_bytes,
+        )?;
+        ensure!(
+            cap.package.bytes == package_id,
+            "Upgrade capability {upgrade_capability} is for package {}, not {package_id}",
+            cap.package.bytes
+        );
+        let digest = compute_digest_for_modules_and_deps(&compiled_modules, &dependencies);
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let cap_arg = builder.obj(ObjectArg::ImmOrOwnedObject(cap_object.object_ref()));
+        let policy_arg = builder.pure(cap.policy)?;
+        let digest_arg = builder.pure(digest.to_vec())?;
+        let ticket = builder.programmable_move_call(
+            SUI_FRAMEWORK_OBJECT_ID,
+            PACKAGE_MODULE_NAME.to_owned(),
+            AUTHORIZE_UPGRADE_FUNCTION_NAME.to_owned(),
+            vec![],
+            vec![cap_arg, policy_arg, digest_arg],
+        );
+        let receipt = builder.upgrade(package_id, ticket, dependencies, compiled_modules);
+        builder.programmable_move_call(
+            SUI_FRAMEWORK_OBJECT_ID,
+            PACKAGE_MODULE_NAME.to_owned(),
+            COMMIT_UPGRADE_FUNCTION_NAME.to_owned(),
+            vec![],
+            vec![cap_arg, receipt],
+        );
+
+        let gas_price = self.0.get_reference_gas_price().await?;
+        let gas = self
+            .select_gas(sender, gas, gas_budget, vec![upgrade_capability], gas_price)
+            .await?;
+        Ok(TransactionData::new_programmable(
+            sender,
+            vec![gas],
+            builder.finish(),
+            gas_budget,
+            gas_price,
+        ))
+    }
+
     // TODO: consolidate this with Pay transactions
     pub async fn split_coin(
         &self,
@@ -626,6 +764,98 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         ))
     }
 
+    /// Build a single programmable transaction performing all `transfers`. Objects are
+    /// transferred as they are, SUI amounts are split from the gas coin.
+    pub async fn batch_transfer(
+        &self,
+        signer: SuiAddress,
+        transfers: Vec<BatchTransferParams>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+    ) -> anyhow::Result<TransactionData> {
+        fp_ensure!(
+            !transfers.is_empty(),
+            UserInputError::InvalidBatchTransaction {
+                error: "Batch transfer cannot be empty".to_owned(),
+            }
+            .into()
+        );
+        let mut object_ids = vec![];
+        let mut object_transfers = vec![];
+        let mut recipients = vec![];
+        let mut amounts = vec![];
+        for transfer in transfers {
+            match transfer {
+                BatchTransferParams::Object {
+                    object_id,
+                    recipient,
+                } => {
+                    object_ids.push(object_id);
+                    object_transfers.push((recipient, self.get_object_ref(object_id).await?));
+                }
+                BatchTransferParams::Sui { amount, recipient } => {
+                    recipients.push(recipient);
+                    amounts.push(amount.into());
+                }
+            }
+        }
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.batch_transfer_objects(object_transfers);
+        builder.pay_sui(recipients, amounts)?;
+
+        let gas_price = self.0.get_reference_gas_price().await?;
+        let gas = self
+            .select_gas(signer, gas, gas_budget, object_ids, gas_price)
+            .await?;
+        Ok(TransactionData::new_programmable(
+            signer,
+            vec![gas],
+            builder.finish(),
+            gas_budget,
+            gas_price,
+        ))
+    }
+
+    /// Turn `tx_data` into a transaction sponsored by `sponsor`, which pays its gas with `gas`
+    /// or, if None, with a coin of the sponsor covering the gas budget of `tx_data`. The sender
+    /// and the sponsor both sign the returned transaction data, see
+    /// [sui_types::messages::SenderSignedData::new_sponsored].
+    pub async fn sponsor_transaction(
+        &self,
+        tx_data: TransactionData,
+        sponsor: SuiAddress,
+        gas: Option<ObjectID>,
+    ) -> anyhow::Result<TransactionData> {
+        let inputs = tx_data
+            .kind()
+            .input_objects()?
+            .into_iter()
+            .map(|obj| obj.object_id())
+            .collect();
+        let gas_price = tx_data.gas_price();
+        let gas_budget = tx_data.gas_budget();
+        let gas = self
+            .select_gas(sponsor, gas, gas_budget, inputs, gas_price)
+            .await?;
+        let sender = tx_data.sender();
+        // The sponsored transaction expires like the transaction of the sender.
+        let expiration = *tx_data.expiration();
+        let mut tx_data = TransactionData::new_with_gas_data(
+            tx_data.into_kind(),
+            sender,
+            GasData {
+                payment: vec![gas],
+                owner: sponsor,
+                price: gas_price,
+                budget: gas_budget,
+            },
+        );
+        *tx_data.expiration_mut() = expiration;
+        tx_data.check_sponsorship()?;
+        Ok(tx_data)
+    }
+
     pub async fn request_add_delegation(
         &self,
         signer: SuiAddress,
@@ -700,6 +930,18 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         staked_sui: ObjectID,
         gas: Option<ObjectID>,
         gas_budget: u64,
+    ) -> anyhow::Result<TransactionData> {
+        self.request_withdraw_stake(signer, staked_sui, gas, gas_budget)
+            .await
+    }
+
+    /// Withdraw the stake held by the `staked_sui` object, along with its rewards.
+    pub async fn request_withdraw_stake(
+        &self,
+        signer: SuiAddress,
+        staked_sui: ObjectID,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
     ) -> anyhow::Result<TransactionData> {
         let staked_sui = self.get_object_ref(staked_sui).await?;
         let gas_price = self.0.get_reference_gas_price().await?;
@@ -746,3 +988,63 @@ impl<Mode: ExecutionMode> TransactionBuilder<Mode> {
         Ok((object.object_ref(), object.object_type()?))
     }
 }
+
+/// Split `payments` into transactions using at most `max_input_coins` of `coins` each, taken in
+/// order, with enough balance for their payments and `gas_reserve`.
+fn schedule_pay_sui(
+    coins: &[(ObjectRef, u64)],
+    payments: &[(SuiAddress, u64)],
+    max_input_coins: usize,
+    gas_reserve: u128,
+) -> anyhow::Result<Vec<(Vec<ObjectRef>, Vec<(SuiAddress, u64)>)>> {
+    let mut transactions = vec![];
+    // Coins and payments of the transaction being scheduled.
+    let mut first_coin = 0;
+    let mut last_coin = 0;
+    let mut balance = 0u128;
+    let mut scheduled = vec![];
+    let mut required = gas_reserve;
+    for (recipient, amount) in payments {
+        loop {
+            // Take the fewest coins covering the payment.
+            let mut end = last_coin;
+            let mut end_balance = balance;
+            let needed = required + *amount as u128;
+            while end_balance < needed && end < coins.len() && end - first_coin < max_input_coins {
+                end_balance += coins[end].1 as u128;
+                end += 1;
+            }
+            if end_balance >= needed {
+                last_coin = end;
+                balance = end_balance;
+                required = needed;
+                scheduled.push((*recipient, *amount));
+                break;
+            }
+            ensure!(
+                !scheduled.is_empty(),
+                "Cannot pay {amount} to {recipient} with at most {max_input_coins} coins, the \
+                 remaining SUI coins are not enough for the payment and the gas budget"
+            );
+            // Start a new transaction with the next coins.
+            transactions.push((
+                coins[first_coin..last_coin]
+                    .iter()
+                    .map(|(object_ref, _)| *object_ref)
+                    .collect(),
+                std::mem::take(&mut scheduled),
+            ));
+            first_coin = last_coin;
+            balance = 0;
+            required = gas_reserve;
+        }
+    }
+    transactions.push((
+        coins[first_coin..last_coin]
+            .iter()
+            .map(|(object_ref, _)| *object_ref)
+            .collect(),
+        scheduled,
+    ));
+    Ok(transactions)
+}
@@ -0,0 +1,64 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate criterion;
+
+use std::str::FromStr;
+
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::event::Event;
+use sui_types::messages::{TransactionEvents, TransactionEventsRef};
+
+use criterion::{BenchmarkId, Criterion};
+
+fn move_events(count: usize, contents_len: usize) -> TransactionEvents {
+    let event = Event::MoveEvent {
+        package_id: ObjectID::random(),
+        transaction_module: Identifier::new("bench").unwrap(),
+        sender: SuiAddress::random_for_testing_only(),
+        type_: StructTag::from_str("0x2::bench::BenchEvent").unwrap(),
+        contents: vec![0; contents_len],
+    };
+    TransactionEvents {
+        data: vec![event; count],
+    }
+}
+
+fn events_decode_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_transaction_events");
+    // Roughly the events of a checkpoint, as one blob per transaction.
+    for contents_len in [64, 1024] {
+        let blobs: Vec<_> = (0..100)
+            .map(|_| bcs::to_bytes(&move_events(10, contents_len)).unwrap())
+            .collect();
+        group.bench_with_input(
+            BenchmarkId::new("owned", contents_len),
+            &blobs,
+            |b, blobs| {
+                b.iter(|| {
+                    for blob in blobs {
+                        bcs::from_bytes::<TransactionEvents>(blob).unwrap();
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("borrowed", contents_len),
+            &blobs,
+            |b, blobs| {
+                b.iter(|| {
+                    for blob in blobs {
+                        TransactionEventsRef::from_bcs_bytes(blob).unwrap();
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, events_decode_benchmark);
+criterion_main!(benches);
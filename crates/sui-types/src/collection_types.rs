@@ -1,16 +1,122 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::base_types::{ObjectID, SuiAddress};
 
+#[cfg(test)]
+#[path = "unit_tests/collection_types_tests.rs"]
+mod collection_types_tests;
+
 /// Rust version of the Move sui::vec_map::VecMap type
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct VecMap<K, V> {
     pub contents: Vec<Entry<K, V>>,
 }
 
+#[derive(PartialEq, Eq, Clone, Debug, thiserror::Error)]
+pub enum VecMapError {
+    /// Same failure as the `EKeyAlreadyExists` abort of `sui::vec_map::insert`.
+    #[error("Key of entry {index} is already bound in the VecMap")]
+    KeyAlreadyExists { index: usize },
+
+    #[error("Could not serialize or deserialize the VecMap: {0}")]
+    Bcs(String),
+}
+
+impl<K, V> Default for VecMap<K, V> {
+    fn default() -> Self {
+        Self { contents: vec![] }
+    }
+}
+
+impl<K: PartialEq, V> VecMap<K, V> {
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.contents
+            .iter()
+            .find(|entry| &entry.key == key)
+            .map(|entry| &entry.value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Entries in insertion order, the order of the Move vector.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.contents.iter().map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Appends the entry `key` |-> `value`, failing like `sui::vec_map::insert` aborts if `key`
+    /// is already bound, in which case the map is left unchanged.
+    pub fn insert_unique(&mut self, key: K, value: V) -> Result<(), VecMapError> {
+        if let Some(index) = self.contents.iter().position(|entry| entry.key == key) {
+            return Err(VecMapError::KeyAlreadyExists { index });
+        }
+        self.contents.push(Entry { key, value });
+        Ok(())
+    }
+
+    /// Builds a map from `entries` in order, failing on the first duplicate key.
+    pub fn try_from_entries(
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, VecMapError> {
+        let mut map = Self::default();
+        for (key, value) in entries {
+            map.insert_unique(key, value)?;
+        }
+        Ok(map)
+    }
+
+    /// Checks that no key is bound twice, which Move guarantees for the maps it builds.
+    pub fn check_unique_keys(&self) -> Result<(), VecMapError> {
+        for (index, entry) in self.contents.iter().enumerate() {
+            if self.contents[..index].iter().any(|e| e.key == entry.key) {
+                return Err(VecMapError::KeyAlreadyExists { index });
+            }
+        }
+        Ok(())
+    }
+
+    /// The map with its entries sorted by key. Maps holding the same entries compare equal, and
+    /// serialize to the same bytes, once sorted.
+    pub fn sorted(mut self) -> Self
+    where
+        K: Ord,
+    {
+        self.contents.sort_by(|a, b| a.key.cmp(&b.key));
+        self
+    }
+}
+
+impl<K: PartialEq + Serialize, V: Serialize> VecMap<K, V> {
+    /// BCS bytes of the map with the layout of the Move `VecMap<K, V>`: the entries in order,
+    /// each one a `key` followed by its `value`, prefixed with their count.
+    pub fn to_bcs_bytes(&self) -> Result<Vec<u8>, VecMapError> {
+        self.check_unique_keys()?;
+        bcs::to_bytes(self).map_err(|e| VecMapError::Bcs(e.to_string()))
+    }
+}
+
+impl<K: PartialEq + DeserializeOwned, V: DeserializeOwned> VecMap<K, V> {
+    /// Reads a map from the BCS bytes of a Move `VecMap<K, V>`, rejecting duplicate keys.
+    pub fn from_bcs_bytes(bytes: &[u8]) -> Result<Self, VecMapError> {
+        let map: Self = bcs::from_bytes(bytes).map_err(|e| VecMapError::Bcs(e.to_string()))?;
+        map.check_unique_keys()?;
+        Ok(map)
+    }
+}
+
 /// Rust version of the Move sui::vec_map::Entry type
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct Entry<K, V> {
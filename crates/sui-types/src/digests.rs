@@ -546,6 +546,16 @@ impl ObjectDigest {
     }
 }
 
+impl std::str::FromStr for ObjectDigest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = [0; 32];
+        result.copy_from_slice(&Base58::decode(s).map_err(|e| anyhow::anyhow!(e))?);
+        Ok(ObjectDigest::new(result))
+    }
+}
+
 impl AsRef<[u8]> for ObjectDigest {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -7,6 +7,8 @@ use crate::id::UID;
 use crate::sui_serde::Readable;
 use crate::{ObjectID, SequenceNumber, SUI_FRAMEWORK_ADDRESS};
 use fastcrypto::hash::{HashFunction, Sha3_256};
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use move_core_types::value::{MoveStruct, MoveTypeLayout, MoveValue};
 use schemars::JsonSchema;
@@ -17,6 +19,9 @@ use serde_with::serde_as;
 use serde_with::DisplayFromStr;
 use std::fmt::{Display, Formatter};
 
+pub const DYNAMIC_OBJECT_FIELD_MODULE_NAME: &IdentStr = ident_str!("dynamic_object_field");
+pub const DYNAMIC_OBJECT_FIELD_WRAPPER_STRUCT_NAME: &IdentStr = ident_str!("Wrapper");
+
 /// Rust version of the Move sui::dynamic_field::Field type
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Field<N, V> {
@@ -63,6 +68,24 @@ pub enum DynamicFieldType {
     DynamicObject,
 }
 
+impl DynamicFieldType {
+    /// The type of the key the ID of a field named with a value of `name_type` is derived from:
+    /// the type of the name for dynamic fields, and `sui::dynamic_object_field::Wrapper` of it for
+    /// dynamic object fields. The wrapper has the name as its only field, so the BCS bytes of the
+    /// key are those of the name in both cases.
+    pub fn key_type_tag(&self, name_type: &TypeTag) -> TypeTag {
+        match self {
+            DynamicFieldType::DynamicField => name_type.clone(),
+            DynamicFieldType::DynamicObject => TypeTag::Struct(Box::new(StructTag {
+                address: SUI_FRAMEWORK_ADDRESS,
+                module: DYNAMIC_OBJECT_FIELD_MODULE_NAME.to_owned(),
+                name: DYNAMIC_OBJECT_FIELD_WRAPPER_STRUCT_NAME.to_owned(),
+                type_params: vec![name_type.clone()],
+            })),
+        }
+    }
+}
+
 impl DynamicFieldInfo {
     pub fn is_dynamic_field(tag: &StructTag) -> bool {
         tag.address == SUI_FRAMEWORK_ADDRESS
@@ -70,6 +93,14 @@ impl DynamicFieldInfo {
             && tag.name.as_str() == "Field"
     }
 
+    /// Returns true if the tag is a `Field<Wrapper<N>, ID>` created by `sui::dynamic_object_field`.
+    pub fn is_dynamic_object_field(tag: &StructTag) -> bool {
+        Self::is_dynamic_field(tag)
+            && matches!(tag.type_params.first(), Some(TypeTag::Struct(wrapper)) if wrapper.address == SUI_FRAMEWORK_ADDRESS
+                && wrapper.module.as_ident_str() == DYNAMIC_OBJECT_FIELD_MODULE_NAME
+                && wrapper.name.as_ident_str() == DYNAMIC_OBJECT_FIELD_WRAPPER_STRUCT_NAME)
+    }
+
     pub fn try_extract_field_name(tag: &StructTag, type_: &DynamicFieldType) -> SuiResult<TypeTag> {
         match (type_, tag.type_params.first()) {
             (DynamicFieldType::DynamicField, Some(name_type)) => Ok(name_type.clone()),
@@ -189,11 +220,7 @@ fn extract_id_value(id_value: &MoveValue) -> Option<ObjectID> {
 
 pub fn is_dynamic_object(move_struct: &MoveStruct) -> bool {
     match move_struct {
-        MoveStruct::WithTypes { type_, .. } => {
-            matches!(&type_.type_params[0], TypeTag::Struct(tag) if tag.address == SUI_FRAMEWORK_ADDRESS
-        && tag.module.as_str() == "dynamic_object_field"
-        && tag.name.as_str() == "Wrapper")
-        }
+        MoveStruct::WithTypes { type_, .. } => DynamicFieldInfo::is_dynamic_object_field(type_),
         _ => false,
     }
 }
@@ -207,18 +234,30 @@ pub fn derive_dynamic_field_id<T>(
 where
     T: Into<SuiAddress>,
 {
-    let Ok(k_tag_bytes) = bcs::to_bytes(key_type_tag) else {
+    let Some(k_bytes) = key.simple_serialize(key_type_layout) else {
         return None;
     };
-    let Some(k_bytes) = key.simple_serialize(key_type_layout) else {
+    derive_dynamic_field_id_from_bcs(parent, key_type_tag, &k_bytes)
+}
+
+/// Same as [derive_dynamic_field_id], for a key already serialized with BCS.
+pub fn derive_dynamic_field_id_from_bcs<T>(
+    parent: T,
+    key_type_tag: &TypeTag,
+    key_bytes: &[u8],
+) -> Option<ObjectID>
+where
+    T: Into<SuiAddress>,
+{
+    let Ok(k_tag_bytes) = bcs::to_bytes(key_type_tag) else {
         return None;
     };
 
     // hash(parent || key || key_type_tag)
     let mut hasher = Sha3_256::default();
     hasher.update(parent.into());
-    hasher.update(k_bytes.len().to_le_bytes());
-    hasher.update(k_bytes);
+    hasher.update(key_bytes.len().to_le_bytes());
+    hasher.update(key_bytes);
     hasher.update(k_tag_bytes);
     let hash = hasher.finalize();
 
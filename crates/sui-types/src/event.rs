@@ -193,6 +193,9 @@ pub enum Event {
         package_id: ObjectID,
         transaction_module: Identifier,
         sender: SuiAddress,
+        /// Owner of the previous version of the object, None if that version was not an input of
+        /// the transaction, e.g. for an unwrapped object or a child object of an input
+        previous_owner: Option<Owner>,
         recipient: Owner,
         object_type: String,
         object_id: ObjectID,
@@ -229,6 +232,190 @@ pub enum Event {
     },
 }
 
+/// Borrowed view of the BCS encoding of an [Event], decoding it without copying the contents of
+/// Move events. Variants must be kept in sync with [Event].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum EventRef<'a> {
+    MoveEvent {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        type_: StructTag,
+        contents: &'a [u8],
+    },
+    Publish {
+        sender: SuiAddress,
+        package_id: ObjectID,
+        version: SequenceNumber,
+        digest: ObjectDigest,
+    },
+    CoinBalanceChange {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        change_type: BalanceChangeType,
+        owner: Owner,
+        coin_type: String,
+        coin_object_id: ObjectID,
+        version: SequenceNumber,
+        amount: i128,
+    },
+    EpochChange(EpochId),
+    Checkpoint(CheckpointSequenceNumber),
+    TransferObject {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        previous_owner: Option<Owner>,
+        recipient: Owner,
+        object_type: String,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
+    MutateObject {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        object_type: String,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
+    DeleteObject {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
+    NewObject {
+        package_id: ObjectID,
+        transaction_module: Identifier,
+        sender: SuiAddress,
+        recipient: Owner,
+        object_type: String,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
+}
+
+impl EventRef<'_> {
+    pub fn into_owned(self) -> Event {
+        match self {
+            EventRef::MoveEvent {
+                package_id,
+                transaction_module,
+                sender,
+                type_,
+                contents,
+            } => Event::MoveEvent {
+                package_id,
+                transaction_module,
+                sender,
+                type_,
+                contents: contents.to_vec(),
+            },
+            EventRef::Publish {
+                sender,
+                package_id,
+                version,
+                digest,
+            } => Event::Publish {
+                sender,
+                package_id,
+                version,
+                digest,
+            },
+            EventRef::CoinBalanceChange {
+                package_id,
+                transaction_module,
+                sender,
+                change_type,
+                owner,
+                coin_type,
+                coin_object_id,
+                version,
+                amount,
+            } => Event::CoinBalanceChange {
+                package_id,
+                transaction_module,
+                sender,
+                change_type,
+                owner,
+                coin_type,
+                coin_object_id,
+                version,
+                amount,
+            },
+            EventRef::EpochChange(epoch) => Event::EpochChange(epoch),
+            EventRef::Checkpoint(seq) => Event::Checkpoint(seq),
+            EventRef::TransferObject {
+                package_id,
+                transaction_module,
+                sender,
+                previous_owner,
+                recipient,
+                object_type,
+                object_id,
+                version,
+            } => Event::TransferObject {
+                package_id,
+                transaction_module,
+                sender,
+                previous_owner,
+                recipient,
+                object_type,
+                object_id,
+                version,
+            },
+            EventRef::MutateObject {
+                package_id,
+                transaction_module,
+                sender,
+                object_type,
+                object_id,
+                version,
+            } => Event::MutateObject {
+                package_id,
+                transaction_module,
+                sender,
+                object_type,
+                object_id,
+                version,
+            },
+            EventRef::DeleteObject {
+                package_id,
+                transaction_module,
+                sender,
+                object_id,
+                version,
+            } => Event::DeleteObject {
+                package_id,
+                transaction_module,
+                sender,
+                object_id,
+                version,
+            },
+            EventRef::NewObject {
+                package_id,
+                transaction_module,
+                sender,
+                recipient,
+                object_type,
+                object_id,
+                version,
+            } => Event::NewObject {
+                package_id,
+                transaction_module,
+                sender,
+                recipient,
+                object_type,
+                object_id,
+                version,
+            },
+        }
+    }
+}
+
 #[derive(
     EnumVariantNames,
     Eq,
@@ -294,6 +481,7 @@ impl Event {
 
     pub fn transfer_object(
         ctx: &SingleTxContext,
+        previous_owner: Option<Owner>,
         recipient: Owner,
         object_type: String,
         object_id: ObjectID,
@@ -303,6 +491,7 @@ impl Event {
             package_id: ctx.package_id,
             transaction_module: ctx.transaction_module.clone(),
             sender: ctx.sender,
+            previous_owner,
             recipient,
             object_type,
             object_id,
@@ -420,6 +609,14 @@ impl Event {
         }
     }
 
+    /// Extracts the previous owner of a transferred object from a SuiEvent, if available
+    pub fn previous_owner(&self) -> Option<&Owner> {
+        match self {
+            Event::TransferObject { previous_owner, .. } => previous_owner.as_ref(),
+            _ => None,
+        }
+    }
+
     /// Extracts the serialized recipient from a SuiEvent, if available
     pub fn recipient_serialized(&self) -> Result<Option<String>, SuiError> {
         match self.recipient() {
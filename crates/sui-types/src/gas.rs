@@ -364,6 +364,14 @@ impl<'a> SuiGasStatus<'a> {
         self.deduct_storage_cost(&storage_cost).map(|q| q.into())
     }
 
+    /// Computation gas units consumed so far, the difference between two calls attributes the
+    /// computation cost of the execution in between.
+    pub fn computation_gas_units(&self) -> u64 {
+        u64::from(self.init_budget)
+            .saturating_sub(self.gas_status.remaining_gas().into())
+            .saturating_sub(self.storage_gas_units.into())
+    }
+
     /// This function is only called during testing, where we need to mock
     /// Move VM charging gas.
     pub fn charge_vm_exec_test_only(&mut self, cost: u64) -> Result<(), ExecutionError> {
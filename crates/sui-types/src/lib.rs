@@ -52,7 +52,10 @@ pub mod signature;
 pub mod storage;
 pub mod sui_serde;
 pub mod sui_system_state;
+pub mod table_viewer;
 pub mod temporary_store;
+pub mod transaction_validation;
+pub mod write_set;
 
 pub mod epoch_data;
 #[path = "./unit_tests/utils.rs"]
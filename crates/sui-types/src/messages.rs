@@ -2,7 +2,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{base_types::*, committee::Committee, error::*, event::Event};
+use super::{
+    base_types::*,
+    committee::Committee,
+    error::*,
+    event::{Event, EventRef},
+};
 use crate::certificate_proof::CertificateProof;
 use crate::committee::{EpochId, ProtocolVersion};
 use crate::crypto::{
@@ -438,7 +443,7 @@ impl VersionedProtocolMessage for SingleTransactionKind {
 }
 
 impl CallArg {
-    fn input_objects(&self) -> Vec<InputObjectKind> {
+    pub(crate) fn input_objects(&self) -> Vec<InputObjectKind> {
         match self {
             CallArg::Pure(_) => vec![],
             CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)) => {
@@ -586,6 +591,10 @@ pub enum Command {
     /// Given n-values of the same type, it constructs a vector. For non objects or an empty vector,
     /// the type tag must be specified.
     MakeMoveVec(Option<TypeTag>, Vec<Argument>),
+    /// Upgrades the package `ObjectID` with the Move modules, depending on the packages
+    /// `Vec<ObjectID>`, as authorized by the `UpgradeTicket` argument.
+    /// Package upgrades are not supported yet, these commands are rejected by the input checks.
+    Upgrade(Vec<Vec<u8>>, Vec<ObjectID>, ObjectID, Argument),
 }
 
 /// An argument to a programmable transaction command
@@ -670,6 +679,11 @@ impl Command {
                     .map(InputObjectKind::MovePackage)
                     .collect()
             }
+            Command::Upgrade(_, dependencies, package, _) => dependencies
+                .iter()
+                .chain(std::iter::once(package))
+                .map(|id| InputObjectKind::MovePackage(*id))
+                .collect(),
             Command::MakeMoveVec(None, _)
             | Command::TransferObjects(_, _)
             | Command::SplitCoin(_, _)
@@ -753,6 +767,11 @@ impl Command {
                     }
                 );
             }
+            Command::Upgrade(_, _, _, _) => {
+                return Err(UserInputError::Unsupported(
+                    "Package upgrades are not supported yet".to_string(),
+                ))
+            }
             Command::SplitCoin(_, _) => (),
         };
         Ok(())
@@ -911,6 +930,9 @@ impl Display for Command {
                 write!(f, ")")
             }
             Command::Publish(_bytes) => write!(f, "Publish(_)"),
+            Command::Upgrade(_bytes, _dependencies, package, ticket) => {
+                write!(f, "Upgrade(_,_,{package},{ticket})")
+            }
         }
     }
 }
@@ -1849,6 +1871,9 @@ pub trait TransactionDataAPI {
 
     fn gas_owner(&self) -> SuiAddress;
 
+    /// Whether the gas is paid by a sponsor instead of the sender.
+    fn is_sponsored_tx(&self) -> bool;
+
     fn gas(&self) -> &[ObjectRef];
 
     fn gas_price(&self) -> u64;
@@ -1905,7 +1930,7 @@ impl TransactionDataAPI for TransactionDataV1 {
     /// Transaction signer and Gas owner
     fn signers(&self) -> Vec<SuiAddress> {
         let mut signers = vec![self.sender];
-        if self.gas_owner() != self.sender {
+        if self.is_sponsored_tx() {
             signers.push(self.gas_owner());
         }
         signers
@@ -1919,6 +1944,10 @@ impl TransactionDataAPI for TransactionDataV1 {
         self.gas_data.owner
     }
 
+    fn is_sponsored_tx(&self) -> bool {
+        self.gas_owner() != self.sender
+    }
+
     fn gas(&self) -> &[ObjectRef] {
         &self.gas_data.payment
     }
@@ -1979,7 +2008,7 @@ impl TransactionDataAPI for TransactionDataV1 {
     /// Check if the transaction is compliant with sponsorship.
     fn check_sponsorship(&self) -> UserInputResult {
         // Not a sponsored transaction, nothing to check
-        if self.gas_owner() == self.sender() {
+        if !self.is_sponsored_tx() {
             return Ok(());
         }
         let allow_sponsored_tx = match &self.kind {
@@ -2067,6 +2096,42 @@ impl SenderSignedData {
         }
     }
 
+    /// Assembles a sponsored transaction from the signatures of its sender and of its sponsor,
+    /// the owner of its gas, over the same transaction data. Fails if the transaction is not
+    /// sponsored or if a signature is not from the expected signer, the signatures themselves
+    /// are only verified with the transaction.
+    pub fn new_sponsored(
+        tx_data: TransactionData,
+        intent: Intent,
+        sender_signature: GenericSignature,
+        sponsor_signature: GenericSignature,
+    ) -> SuiResult<Self> {
+        // The sender of a transaction that is not sponsored is its only signer.
+        fp_ensure!(
+            tx_data.is_sponsored_tx(),
+            SuiError::SignerSignatureNumberMismatch {
+                actual: 2,
+                expected: 1,
+            }
+        );
+        for (signer, signature) in [
+            (tx_data.sender(), &sender_signature),
+            (tx_data.gas_owner(), &sponsor_signature),
+        ] {
+            fp_ensure!(
+                SuiAddress::try_from(signature)? == signer,
+                SuiError::SignerSignatureAbsent {
+                    signer: signer.to_string(),
+                }
+            );
+        }
+        Ok(Self::new(
+            tx_data,
+            intent,
+            vec![sender_signature, sponsor_signature],
+        ))
+    }
+
     // This function does not check validity of the signature
     // or perform any de-dup checks.
     pub fn add_signature(&mut self, new_signature: Signature) {
@@ -2579,7 +2644,7 @@ pub enum ExecutionStatus {
 
 pub type CommandIndex = usize;
 
-#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, IntoStaticStr)]
 pub enum ExecutionFailureStatus {
     //
     // General transaction errors
@@ -3545,6 +3610,26 @@ impl TransactionEvents {
     }
 }
 
+/// Borrowed view of the BCS encoding of [TransactionEvents], the contents of Move events point
+/// into the encoded bytes instead of being copied.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TransactionEventsRef<'a> {
+    #[serde(borrow)]
+    pub data: Vec<EventRef<'a>>,
+}
+
+impl<'a> TransactionEventsRef<'a> {
+    pub fn from_bcs_bytes(bytes: &'a [u8]) -> Result<Self, bcs::Error> {
+        bcs::from_bytes(bytes)
+    }
+
+    pub fn into_owned(self) -> TransactionEvents {
+        TransactionEvents {
+            data: self.data.into_iter().map(EventRef::into_owned).collect(),
+        }
+    }
+}
+
 impl Message for TransactionEffects {
     type DigestType = TransactionEffectsDigest;
     const SCOPE: IntentScope = IntentScope::TransactionEffects;
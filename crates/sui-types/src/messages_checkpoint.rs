@@ -242,6 +242,35 @@ impl CertifiedCheckpointSummary {
     }
 }
 
+/// A certified checkpoint summary along with the committee of its epoch, which is everything
+/// needed to verify the checkpoint offline. Auditors can archive its BCS bytes and check them
+/// later with [CheckpointAttestation::verify], without trusting the node they got them from for
+/// anything but the committee.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheckpointAttestation {
+    pub summary: CertifiedCheckpointSummary,
+    pub committee: Committee,
+}
+
+impl CheckpointAttestation {
+    /// Checks that the summary is certified by a quorum of the committee of its epoch.
+    pub fn verify(&self) -> SuiResult {
+        fp_ensure!(
+            self.committee.epoch == self.summary.epoch(),
+            SuiError::WrongEpoch {
+                expected_epoch: self.summary.epoch(),
+                actual_epoch: self.committee.epoch,
+            }
+        );
+        // The public keys of a deserialized committee are not derived from its voting rights.
+        let committee = Committee::new(
+            self.committee.epoch,
+            self.committee.voting_rights.iter().cloned().collect(),
+        )?;
+        self.summary.verify_signature(&committee)
+    }
+}
+
 impl VerifiedCheckpoint {
     pub fn into_summary_and_sequence(self) -> (CheckpointSequenceNumber, CheckpointSummary) {
         let summary = self.into_inner().into_data();
@@ -612,4 +641,59 @@ mod tests {
                 .is_err()
         )
     }
+
+    #[test]
+    fn test_checkpoint_attestation() {
+        let mut rng = StdRng::from_seed(RNG_SEED);
+        let (keys, committee) = make_committee_key(&mut rng);
+        let (_, committee2) = make_committee_key(&mut rng);
+
+        let set = CheckpointContents::new_with_causally_ordered_transactions(
+            [ExecutionDigests::random()].into_iter(),
+        );
+        let summary = CheckpointSummary::new(
+            committee.epoch,
+            1,
+            0,
+            &set,
+            None,
+            GasCostSummary::default(),
+            None,
+            0,
+        );
+        let sign_infos: Vec<_> = keys
+            .iter()
+            .map(|k| SignedCheckpointSummary::sign(committee.epoch, &summary, k, k.public().into()))
+            .collect();
+        let summary =
+            CertifiedCheckpointSummary::new(summary, sign_infos, &committee).expect("Cert is OK");
+
+        // The attestation still verifies once archived.
+        let attestation = CheckpointAttestation {
+            summary: summary.clone(),
+            committee,
+        };
+        let bytes = bcs::to_bytes(&attestation).unwrap();
+        let attestation: CheckpointAttestation = bcs::from_bytes(&bytes).unwrap();
+        assert!(attestation.verify().is_ok());
+
+        // Another committee of the same epoch did not certify the checkpoint.
+        let attestation = CheckpointAttestation {
+            summary: summary.clone(),
+            committee: committee2.clone(),
+        };
+        assert!(attestation.verify().is_err());
+
+        // Nor did a committee of another epoch.
+        let committee3 =
+            Committee::new(1, committee2.voting_rights.iter().cloned().collect()).unwrap();
+        let attestation = CheckpointAttestation {
+            summary,
+            committee: committee3,
+        };
+        assert!(matches!(
+            attestation.verify(),
+            Err(SuiError::WrongEpoch { .. })
+        ));
+    }
 }
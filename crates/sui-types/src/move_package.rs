@@ -7,6 +7,7 @@ use crate::{
     id::{ID, UID},
     SUI_FRAMEWORK_ADDRESS,
 };
+use fastcrypto::hash::{HashFunction, Sha256};
 use move_binary_format::access::ModuleAccess;
 use move_binary_format::binary_views::BinaryIndexedView;
 use move_binary_format::file_format::CompiledModule;
@@ -32,6 +33,8 @@ use std::collections::BTreeMap;
 
 pub const PACKAGE_MODULE_NAME: &IdentStr = ident_str!("package");
 pub const UPGRADECAP_STRUCT_NAME: &IdentStr = ident_str!("UpgradeCap");
+pub const AUTHORIZE_UPGRADE_FUNCTION_NAME: &IdentStr = ident_str!("authorize_upgrade");
+pub const COMMIT_UPGRADE_FUNCTION_NAME: &IdentStr = ident_str!("commit_upgrade");
 
 #[derive(Clone, Debug)]
 /// Additional information about a function
@@ -214,6 +217,31 @@ impl UpgradeCap {
     }
 }
 
+/// Digest of the bytecode and transitive dependencies of an upgrade, that the `UpgradeTicket` of
+/// the upgrade must carry: the SHA256 hash of the digests of the modules and of the IDs of the
+/// dependencies, both sorted so that the digest does not depend on their order.
+pub fn compute_digest_for_modules_and_deps(
+    modules: &[Vec<u8>],
+    dependencies: &[ObjectID],
+) -> [u8; 32] {
+    let mut components: Vec<Vec<u8>> = modules
+        .iter()
+        .map(|module| AsRef::<[u8]>::as_ref(&Sha256::digest(module)).to_vec())
+        .collect();
+    components.sort();
+    let mut dependencies = dependencies.to_vec();
+    dependencies.sort();
+    components.extend(dependencies.iter().map(|id| id.as_ref().to_vec()));
+
+    let mut hasher = Sha256::default();
+    for component in components {
+        hasher.update(component);
+    }
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(AsRef::<[u8]>::as_ref(&hasher.finalize()));
+    digest
+}
+
 pub fn disassemble_modules<'a, I>(modules: I) -> SuiResult<BTreeMap<String, Value>>
 where
     I: Iterator<Item = &'a Vec<u8>>,
@@ -201,6 +201,23 @@ impl ProgrammableTransactionBuilder {
         self.command(Command::Publish(modules))
     }
 
+    /// Upgrade `package` to `modules`, as authorized by `upgrade_ticket`. Returns the
+    /// `UpgradeReceipt` to commit the upgrade with.
+    pub fn upgrade(
+        &mut self,
+        package: ObjectID,
+        upgrade_ticket: Argument,
+        dependencies: Vec<ObjectID>,
+        modules: Vec<Vec<u8>>,
+    ) -> Argument {
+        self.command(Command::Upgrade(
+            modules,
+            dependencies,
+            package,
+            upgrade_ticket,
+        ))
+    }
+
     pub fn publish(&mut self, modules: Vec<Vec<u8>>) {
         let cap = self.publish_upgradeable(modules);
         self.commands
@@ -229,6 +246,24 @@ impl ProgrammableTransactionBuilder {
             .push(Command::TransferObjects(vec![obj_arg], rec_arg));
     }
 
+    /// Transfers each object to its recipient, using a single transfer per distinct recipient
+    pub fn batch_transfer_objects(&mut self, transfers: Vec<(SuiAddress, ObjectRef)>) {
+        let mut recipient_map = IndexMap::new();
+        for (recipient, object_ref) in transfers {
+            recipient_map
+                .entry(recipient)
+                .or_insert_with(Vec::new)
+                .push(object_ref);
+        }
+        for (recipient, object_refs) in recipient_map {
+            let obj_args = object_refs
+                .into_iter()
+                .map(|object_ref| self.obj(ObjectArg::ImmOrOwnedObject(object_ref)))
+                .collect();
+            self.transfer_args(recipient, obj_args);
+        }
+    }
+
     pub fn transfer_sui(&mut self, recipient: SuiAddress, amount: Option<u64>) {
         let rec_arg = self.pure(recipient).unwrap();
         let coin_arg = if let Some(amount) = amount {
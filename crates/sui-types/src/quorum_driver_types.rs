@@ -7,7 +7,7 @@ use std::collections::BTreeMap;
 use crate::base_types::{AuthorityName, ObjectRef, TransactionDigest};
 use crate::committee::StakeUnit;
 use crate::error::SuiError;
-use crate::messages::{QuorumDriverResponse, VerifiedTransaction};
+use crate::messages::{QuorumDriverResponse, TransactionEffects, VerifiedTransaction};
 use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
 use thiserror::Error;
@@ -45,3 +45,18 @@ pub enum QuorumDriverError {
         errors: Vec<(SuiError, Vec<AuthorityName>, StakeUnit)>,
     },
 }
+
+/// Status of a transaction enqueued for execution, looked up by its digest.
+#[derive(Clone, Debug)]
+pub enum TransactionSubmissionStatus {
+    /// The transaction was not submitted to this node, or its result is no longer known.
+    Unknown,
+    /// The transaction is being submitted to validators.
+    Pending,
+    /// The transaction is final, `executed_locally` tells whether the node has executed it.
+    Finalized {
+        effects: TransactionEffects,
+        executed_locally: bool,
+    },
+    Failed(QuorumDriverError),
+}
@@ -0,0 +1,156 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Read the entries of `sui::table::Table` and `sui::table_vec::TableVec` collections, which are
+//! stored as `sui::dynamic_field::Field<K, V>` children of the collection.
+
+use move_core_types::language_storage::TypeTag;
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
+
+use crate::base_types::ObjectID;
+use crate::collection_types::{Table, TableVec};
+use crate::dynamic_field::derive_dynamic_field_id_from_bcs;
+use crate::error::{SuiError, SuiResult, UserInputError};
+use crate::object::Object;
+use crate::storage::ObjectStore;
+
+#[cfg(test)]
+#[path = "unit_tests/table_viewer_tests.rs"]
+mod table_viewer_tests;
+
+/// Key and value of a table entry, deserialized with the layouts of the table types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableEntry {
+    pub key: MoveValue,
+    pub value: MoveValue,
+}
+
+/// Layout of `sui::dynamic_field::Field<K, V>`.
+fn field_layout(key_layout: &MoveTypeLayout, value_layout: &MoveTypeLayout) -> MoveTypeLayout {
+    // UID { id: ID { bytes: address } }
+    let uid_layout =
+        MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![MoveTypeLayout::Struct(
+            MoveStructLayout::Runtime(vec![MoveTypeLayout::Address]),
+        )]));
+    MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+        uid_layout,
+        key_layout.clone(),
+        value_layout.clone(),
+    ]))
+}
+
+/// Deserialize a table entry from its `Field<K, V>` object.
+pub fn deserialize_table_entry(
+    object: &Object,
+    key_layout: &MoveTypeLayout,
+    value_layout: &MoveTypeLayout,
+) -> SuiResult<TableEntry> {
+    let move_object =
+        object
+            .data
+            .try_as_move()
+            .ok_or_else(|| SuiError::ObjectDeserializationError {
+                error: format!("Table entry {} is not a Move object", object.id()),
+            })?;
+    let field = MoveValue::simple_deserialize(
+        move_object.contents(),
+        &field_layout(key_layout, value_layout),
+    )
+    .map_err(|e| SuiError::ObjectDeserializationError {
+        error: format!("Failed to deserialize table entry {}: {e}", object.id()),
+    })?;
+    let MoveValue::Struct(MoveStruct::Runtime(fields)) = field else {
+        return Err(SuiError::ObjectDeserializationError {
+            error: format!("Unexpected table entry {}: {field:?}", object.id()),
+        });
+    };
+    let [_, key, value]: [MoveValue; 3] =
+        fields
+            .try_into()
+            .map_err(|fields| SuiError::ObjectDeserializationError {
+                error: format!("Unexpected table entry {}: {fields:?}", object.id()),
+            })?;
+    Ok(TableEntry { key, value })
+}
+
+/// Look up the entry of `table` with the BCS serialized `key`, returns None if the table has no
+/// such key.
+pub fn get_table_entry<S: ObjectStore>(
+    table: &Table,
+    store: &S,
+    key_type_tag: &TypeTag,
+    key: &[u8],
+    key_layout: &MoveTypeLayout,
+    value_layout: &MoveTypeLayout,
+) -> SuiResult<Option<TableEntry>> {
+    let id = table_entry_id(table.id, key_type_tag, key)?;
+    store
+        .get_object(&id)?
+        .map(|object| deserialize_table_entry(&object, key_layout, value_layout))
+        .transpose()
+}
+
+/// Iterate lazily over the elements of `table_vec` in order, the elements are read from `store`
+/// one at a time.
+pub fn table_vec_elements<'a, S: ObjectStore>(
+    table_vec: &TableVec,
+    store: &'a S,
+    element_layout: MoveTypeLayout,
+) -> TableVecElements<'a, S> {
+    TableVecElements {
+        store,
+        id: table_vec.contents.id,
+        size: table_vec.contents.size,
+        next_index: 0,
+        element_layout,
+    }
+}
+
+pub struct TableVecElements<'a, S> {
+    store: &'a S,
+    id: ObjectID,
+    size: u64,
+    next_index: u64,
+    element_layout: MoveTypeLayout,
+}
+
+impl<'a, S: ObjectStore> TableVecElements<'a, S> {
+    fn element(&self, index: u64) -> SuiResult<MoveValue> {
+        let key = bcs::to_bytes(&index).expect("u64 serialization cannot fail");
+        let id = table_entry_id(self.id, &TypeTag::U64, &key)?;
+        let object = self
+            .store
+            .get_object(&id)?
+            .ok_or(UserInputError::ObjectNotFound {
+                object_id: id,
+                version: None,
+            })?;
+        Ok(deserialize_table_entry(&object, &MoveTypeLayout::U64, &self.element_layout)?.value)
+    }
+}
+
+impl<'a, S: ObjectStore> Iterator for TableVecElements<'a, S> {
+    type Item = SuiResult<MoveValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.size {
+            return None;
+        }
+        let element = self.element(self.next_index);
+        self.next_index += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.size - self.next_index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+fn table_entry_id(table_id: ObjectID, key_type_tag: &TypeTag, key: &[u8]) -> SuiResult<ObjectID> {
+    derive_dynamic_field_id_from_bcs(table_id, key_type_tag, key).ok_or_else(|| {
+        SuiError::ObjectSerializationError {
+            error: format!("Failed to serialize table key type {key_type_tag}"),
+        }
+    })
+}
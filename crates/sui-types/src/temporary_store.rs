@@ -352,6 +352,7 @@ impl<S> TemporaryStore<S> {
                     if old_obj.map(|o| o.owner) != Some(obj.owner) {
                         events.push(Event::transfer_object(
                             &ctx,
+                            old_obj.map(|o| o.owner),
                             obj.owner,
                             // Safe to unwrap, package case handled above
                             obj.data.type_().unwrap().to_string(),
@@ -0,0 +1,252 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Checks of a transaction before it is signed, so that SDKs and wallets can reject the
+//! transactions that validators are bound to reject or that are bound to fail at execution,
+//! charging gas for nothing.
+//!
+//! Unlike [TransactionDataAPI::validity_check], which stops at the first error, all the violations
+//! are reported. The checks only look at the transaction itself: the gas balance, the versions and
+//! ownership of the input objects and the Move calls are only checked with the objects.
+
+use std::collections::BTreeSet;
+
+use sui_protocol_config::ProtocolConfig;
+use thiserror::Error;
+
+use crate::base_types::ObjectID;
+use crate::gas::SuiCostTable;
+use crate::messages::{
+    Argument, CallArg, Command, InputObjectKind, ProgrammableTransaction, SingleTransactionKind,
+    TransactionData, TransactionDataAPI,
+};
+
+#[cfg(test)]
+#[path = "unit_tests/transaction_validation_tests.rs"]
+mod transaction_validation_tests;
+
+/// Size of the intent prefixed to the transaction data, and of the largest single signature
+/// scheme: flag, signature and compressed secp256k1 or secp256r1 public key.
+const INTENT_SIZE: usize = 3;
+const MAX_SIGNATURE_SIZE: usize = 1 + 64 + 33;
+
+/// A reason for the transaction to be rejected by validators or to fail at execution. Commands
+/// and inputs are designated by their index in the programmable transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TransactionViolation {
+    #[error("Transaction size {size} once signed exceeds the maximum of {max_size} bytes")]
+    TransactionTooLarge { size: usize, max_size: usize },
+    #[error("Transaction gas payment missing")]
+    MissingGasPayment,
+    #[error("Transaction pays gas with {count} objects, the limit is {max_count}")]
+    TooManyGasObjects { count: usize, max_count: usize },
+    #[error("Gas object {object_id} is used more than once to pay gas")]
+    DuplicateGasObject { object_id: ObjectID },
+    #[error("Gas object {object_id} is also an input of the transaction")]
+    GasObjectAsInput { object_id: ObjectID },
+    #[error("Gas budget {gas_budget} is lower than the minimum of {min_budget}")]
+    GasBudgetTooLow { gas_budget: u64, min_budget: u64 },
+    #[error("Gas budget {gas_budget} is higher than the maximum of {max_budget}")]
+    GasBudgetTooHigh { gas_budget: u64, max_budget: u64 },
+    #[error("Transaction has {count} commands, the limit is {max_count}")]
+    TooManyCommands { count: usize, max_count: usize },
+    #[error("Command {command} has {count} arguments, the limit is {max_count}")]
+    TooManyArguments {
+        command: usize,
+        count: usize,
+        max_count: usize,
+    },
+    #[error("Object {object_id} is used more than once as input")]
+    DuplicateObjectInput { object_id: ObjectID },
+    #[error("Command {command} uses the gas coin by value, only TransferObjects can")]
+    GasCoinByValue { command: usize },
+    #[error("Command {command} refers to {argument:?}, which does not exist")]
+    ArgumentOutOfBounds { command: usize, argument: Argument },
+}
+
+/// The violations of `tx` under `config`, empty if the transaction can be signed.
+///
+/// The size of the signed transaction is estimated with single signatures, multisig signatures
+/// being larger. Limits are checked like [TransactionDataAPI::validity_check] does, which rejects
+/// counts reaching the limits.
+pub fn validate_for_signing(
+    tx: &TransactionData,
+    config: &ProtocolConfig,
+) -> Vec<TransactionViolation> {
+    let mut violations = vec![];
+
+    // bcs encodes the lengths of the signatures vector and of each signature on a single byte.
+    let size = bcs::serialized_size(tx)
+        .unwrap_or(usize::MAX)
+        .saturating_add(INTENT_SIZE + 1 + tx.signers().len() * (1 + MAX_SIGNATURE_SIZE));
+    if size > config.max_tx_size() {
+        violations.push(TransactionViolation::TransactionTooLarge {
+            size,
+            max_size: config.max_tx_size(),
+        });
+    }
+
+    if !tx.is_system_tx() {
+        check_gas(tx, config, &mut violations);
+    }
+
+    for single in tx.kind().single_transactions() {
+        let inputs = object_inputs(single);
+        let mut seen = BTreeSet::new();
+        let duplicates = inputs
+            .iter()
+            .filter(|id| !seen.insert(**id))
+            .collect::<BTreeSet<_>>();
+        violations.extend(duplicates.into_iter().map(|object_id| {
+            TransactionViolation::DuplicateObjectInput {
+                object_id: *object_id,
+            }
+        }));
+        // The coins of PaySui and PayAllSui include the gas object.
+        if !tx.kind().is_pay_sui_tx() {
+            let gas_objects = tx
+                .gas()
+                .iter()
+                .map(|(id, _, _)| *id)
+                .collect::<BTreeSet<_>>();
+            violations.extend(
+                gas_objects
+                    .into_iter()
+                    .filter(|id| seen.contains(id))
+                    .map(|object_id| TransactionViolation::GasObjectAsInput { object_id }),
+            );
+        }
+        if let SingleTransactionKind::ProgrammableTransaction(pt) = single {
+            check_commands(pt, config, &mut violations);
+        }
+    }
+    violations
+}
+
+fn check_gas(
+    tx: &TransactionData,
+    config: &ProtocolConfig,
+    violations: &mut Vec<TransactionViolation>,
+) {
+    let gas = tx.gas();
+    if gas.is_empty() {
+        violations.push(TransactionViolation::MissingGasPayment);
+    }
+    let max_gas_objects = config.max_gas_payment_objects() as usize;
+    if gas.len() >= max_gas_objects {
+        violations.push(TransactionViolation::TooManyGasObjects {
+            count: gas.len(),
+            max_count: max_gas_objects,
+        });
+    }
+    let mut seen = BTreeSet::new();
+    violations.extend(
+        gas.iter()
+            .filter(|(id, _, _)| !seen.insert(*id))
+            .map(|(id, _, _)| TransactionViolation::DuplicateGasObject { object_id: *id }),
+    );
+
+    // Same bounds as [crate::gas::check_gas_balance].
+    let cost_table = SuiCostTable::new(config);
+    let gas_budget = tx.gas_budget();
+    let gas_price = tx.gas_price() as u128;
+    if gas_budget as u128 > cost_table.max_gas_budget as u128 * gas_price {
+        violations.push(TransactionViolation::GasBudgetTooHigh {
+            gas_budget,
+            max_budget: cost_table.max_gas_budget,
+        });
+    }
+    if (gas_budget as u128) < cost_table.min_gas_budget_external() as u128 * gas_price {
+        violations.push(TransactionViolation::GasBudgetTooLow {
+            gas_budget,
+            min_budget: cost_table.min_gas_budget_external(),
+        });
+    }
+}
+
+/// IDs of the objects passed to `single`, packages excluded, duplicates included.
+fn object_inputs(single: &SingleTransactionKind) -> Vec<ObjectID> {
+    let call_args_objects = |args: &[CallArg]| -> Vec<ObjectID> {
+        args.iter()
+            .flat_map(|arg| arg.input_objects())
+            .filter(|kind| !matches!(kind, InputObjectKind::MovePackage(_)))
+            .map(|kind| kind.object_id())
+            .collect()
+    };
+    match single {
+        SingleTransactionKind::ProgrammableTransaction(pt) => call_args_objects(&pt.inputs),
+        SingleTransactionKind::Call(call) => call_args_objects(&call.arguments),
+        SingleTransactionKind::TransferObject(transfer) => vec![transfer.object_ref.0],
+        SingleTransactionKind::Pay(pay) => pay.coins.iter().map(|coin| coin.0).collect(),
+        SingleTransactionKind::PaySui(pay) => pay.coins.iter().map(|coin| coin.0).collect(),
+        SingleTransactionKind::PayAllSui(pay) => pay.coins.iter().map(|coin| coin.0).collect(),
+        SingleTransactionKind::Publish(_)
+        | SingleTransactionKind::TransferSui(_)
+        | SingleTransactionKind::ChangeEpoch(_)
+        | SingleTransactionKind::Genesis(_)
+        | SingleTransactionKind::ConsensusCommitPrologue(_) => vec![],
+    }
+}
+
+fn check_commands(
+    pt: &ProgrammableTransaction,
+    config: &ProtocolConfig,
+    violations: &mut Vec<TransactionViolation>,
+) {
+    let max_commands = config.max_programmable_tx_commands() as usize;
+    if pt.commands.len() >= max_commands {
+        violations.push(TransactionViolation::TooManyCommands {
+            count: pt.commands.len(),
+            max_count: max_commands,
+        });
+    }
+    let max_arguments = config.max_arguments() as usize;
+    for (command, c) in pt.commands.iter().enumerate() {
+        // The number of arguments that is limited, and the arguments taken by value.
+        let (count, by_value) = match c {
+            Command::MoveCall(call) => (call.arguments.len(), vec![]),
+            Command::TransferObjects(objects, address) => (objects.len(), vec![*address]),
+            Command::MergeCoins(_, coins) => (coins.len(), coins.clone()),
+            Command::MakeMoveVec(_, elements) => (elements.len(), elements.clone()),
+            Command::SplitCoin(_, amount) => (0, vec![*amount]),
+            Command::Upgrade(_, _, _, _) | Command::Publish(_) => (0, vec![]),
+        };
+        if count >= max_arguments {
+            violations.push(TransactionViolation::TooManyArguments {
+                command,
+                count,
+                max_count: max_arguments,
+            });
+        }
+        if by_value.contains(&Argument::GasCoin) {
+            violations.push(TransactionViolation::GasCoinByValue { command });
+        }
+        violations.extend(
+            command_arguments(c)
+                .into_iter()
+                .filter(|argument| match argument {
+                    Argument::GasCoin => false,
+                    Argument::Input(input) => *input as usize >= pt.inputs.len(),
+                    // Results of the previous commands only.
+                    Argument::Result(result) | Argument::NestedResult(result, _) => {
+                        *result as usize >= command
+                    }
+                })
+                .map(|argument| TransactionViolation::ArgumentOutOfBounds { command, argument }),
+        );
+    }
+}
+
+fn command_arguments(command: &Command) -> Vec<Argument> {
+    match command {
+        Command::MoveCall(call) => call.arguments.clone(),
+        Command::TransferObjects(objects, address) => {
+            objects.iter().chain([address]).copied().collect()
+        }
+        Command::SplitCoin(coin, amount) => vec![*coin, *amount],
+        Command::MergeCoins(coin, coins) => [coin].into_iter().chain(coins).copied().collect(),
+        Command::MakeMoveVec(_, elements) => elements.clone(),
+        Command::Upgrade(_, _, _, ticket) => vec![*ticket],
+        Command::Publish(_) => vec![],
+    }
+}
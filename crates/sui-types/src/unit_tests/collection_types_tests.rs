@@ -0,0 +1,130 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use fastcrypto::encoding::{Encoding, Hex};
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
+use proptest::collection;
+use proptest::prelude::*;
+
+// BCS bytes of maps built by `sui::vec_map_tests::bcs_layout`.
+// VecMap<u64, bool> with 5 |-> true inserted before 1 |-> false
+const U64_BOOL_FIXTURE: &str = "02050000000000000001010000000000000000";
+// VecMap<String, String> with "name" |-> "Sui"
+const STRING_STRING_FIXTURE: &str = "01046e616d6503537569";
+
+/// Layout of the Move `VecMap<u64, vector<u8>>`.
+fn u64_bytes_layout() -> MoveTypeLayout {
+    let entry = MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![
+        MoveTypeLayout::U64,
+        MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+    ]));
+    MoveTypeLayout::Struct(MoveStructLayout::Runtime(vec![MoveTypeLayout::Vector(
+        Box::new(entry),
+    )]))
+}
+
+/// Move value of `map`, a `VecMap<u64, vector<u8>>`.
+fn u64_bytes_value(map: &VecMap<u64, Vec<u8>>) -> MoveValue {
+    let entries = map
+        .iter()
+        .map(|(key, value)| {
+            MoveValue::Struct(MoveStruct::Runtime(vec![
+                MoveValue::U64(*key),
+                MoveValue::Vector(value.iter().map(|b| MoveValue::U8(*b)).collect()),
+            ]))
+        })
+        .collect();
+    MoveValue::Struct(MoveStruct::Runtime(vec![MoveValue::Vector(entries)]))
+}
+
+#[test]
+fn test_insert_unique() {
+    let mut map = VecMap::default();
+    map.insert_unique(5u64, true).unwrap();
+    map.insert_unique(1, false).unwrap();
+    assert_eq!(
+        map.insert_unique(5, false),
+        Err(VecMapError::KeyAlreadyExists { index: 0 })
+    );
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&5), Some(&true));
+    assert_eq!(map.get(&2), None);
+    assert_eq!(
+        map.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+        vec![5, 1],
+        "Entries are kept in insertion order"
+    );
+}
+
+#[test]
+fn test_sorted() {
+    let a = VecMap::try_from_entries([(3u64, 'c'), (1, 'a'), (2, 'b')]).unwrap();
+    let b = VecMap::try_from_entries([(2u64, 'b'), (3, 'c'), (1, 'a')]).unwrap();
+    assert_ne!(a, b);
+    let a = a.sorted();
+    assert_eq!(a, b.sorted());
+    assert_eq!(a.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_move_fixtures() {
+    let map = VecMap::try_from_entries([(5u64, true), (1, false)]).unwrap();
+    let bytes = Hex::decode(U64_BOOL_FIXTURE).unwrap();
+    assert_eq!(map.to_bcs_bytes().unwrap(), bytes);
+    assert_eq!(VecMap::<u64, bool>::from_bcs_bytes(&bytes).unwrap(), map);
+
+    let map = VecMap::try_from_entries([("name".to_string(), "Sui".to_string())]).unwrap();
+    let bytes = Hex::decode(STRING_STRING_FIXTURE).unwrap();
+    assert_eq!(map.to_bcs_bytes().unwrap(), bytes);
+    assert_eq!(
+        VecMap::<String, String>::from_bcs_bytes(&bytes).unwrap(),
+        map
+    );
+}
+
+#[test]
+fn test_duplicate_keys_rejected() {
+    let map = VecMap {
+        contents: vec![
+            Entry {
+                key: 1u64,
+                value: 10u64,
+            },
+            Entry { key: 2, value: 20 },
+            Entry { key: 1, value: 30 },
+        ],
+    };
+    let expected = VecMapError::KeyAlreadyExists { index: 2 };
+    assert_eq!(map.to_bcs_bytes(), Err(expected.clone()));
+    let bytes = bcs::to_bytes(&map).unwrap();
+    assert_eq!(VecMap::<u64, u64>::from_bcs_bytes(&bytes), Err(expected));
+}
+
+proptest! {
+    #[test]
+    fn test_bcs_round_trip(
+        entries in collection::vec((any::<u64>(), collection::vec(any::<u8>(), 0..64)), 0..32)
+    ) {
+        let mut map = VecMap::default();
+        for (key, value) in entries {
+            let _ = map.insert_unique(key, value);
+        }
+        let bytes = map.to_bcs_bytes().unwrap();
+
+        // Same bytes as the Move value, which reads back with the Move layout.
+        let value = u64_bytes_value(&map);
+        prop_assert_eq!(value.simple_serialize().unwrap(), bytes.clone());
+        prop_assert_eq!(
+            MoveValue::simple_deserialize(&bytes, &u64_bytes_layout()).unwrap(),
+            value
+        );
+
+        prop_assert_eq!(VecMap::<u64, Vec<u8>>::from_bcs_bytes(&bytes).unwrap(), map.clone());
+        let sorted = map.clone().sorted();
+        prop_assert_eq!(
+            VecMap::<u64, Vec<u8>>::from_bcs_bytes(&sorted.to_bcs_bytes().unwrap()).unwrap(),
+            sorted
+        );
+    }
+}
@@ -79,6 +79,7 @@ fn test_transfer_filter() {
         package_id: ObjectID::from(SUI_FRAMEWORK_ADDRESS),
         transaction_module: Identifier::from(ident_str!("test_module")),
         sender,
+        previous_owner: Some(Owner::AddressOwner(sender)),
         recipient,
         object_type: "0x2::example::Object".into(),
         object_id,
@@ -4,6 +4,7 @@
 
 use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
 use fastcrypto::traits::AggregateAuthenticator;
 use fastcrypto::traits::KeyPair;
@@ -762,6 +763,67 @@ fn test_sponsored_transaction_message() {
     assert_eq!(tx.gas_owner(), sponsor,);
 }
 
+#[test]
+fn test_new_sponsored_sender_signed_data() {
+    let sender_kp = SuiKeyPair::Ed25519(get_key_pair().1);
+    let sender = (&sender_kp.public()).into();
+    let sponsor_kp = SuiKeyPair::Ed25519(get_key_pair().1);
+    let sponsor = (&sponsor_kp.public()).into();
+    let kind = TransactionKind::Single(SingleTransactionKind::TransferObject(TransferObject {
+        recipient: get_new_address::<AccountKeyPair>(),
+        object_ref: random_object_ref(),
+    }));
+    let gas_data = GasData {
+        payment: vec![random_object_ref()],
+        owner: sponsor,
+        price: DUMMY_GAS_PRICE,
+        budget: 10000,
+    };
+    let tx_data = TransactionData::new_with_gas_data(kind.clone(), sender, gas_data.clone());
+    assert!(tx_data.is_sponsored_tx());
+    let intent = Intent::default();
+    let sender_sig: GenericSignature =
+        signature_from_signer(tx_data.clone(), intent.clone(), &sender_kp).into();
+    let sponsor_sig: GenericSignature =
+        signature_from_signer(tx_data.clone(), intent.clone(), &sponsor_kp).into();
+
+    let data = SenderSignedData::new_sponsored(
+        tx_data.clone(),
+        intent.clone(),
+        sender_sig.clone(),
+        sponsor_sig.clone(),
+    )
+    .unwrap();
+    Transaction::new(data).verify().unwrap();
+
+    // Signatures swapped
+    assert!(matches!(
+        SenderSignedData::new_sponsored(
+            tx_data,
+            intent.clone(),
+            sponsor_sig.clone(),
+            sender_sig.clone(),
+        )
+        .unwrap_err(),
+        SuiError::SignerSignatureAbsent { .. }
+    ));
+
+    // Not a sponsored transaction
+    let tx_data = TransactionData::new_with_gas_data(
+        kind,
+        sender,
+        GasData {
+            owner: sender,
+            ..gas_data
+        },
+    );
+    assert!(!tx_data.is_sponsored_tx());
+    assert!(matches!(
+        SenderSignedData::new_sponsored(tx_data, intent, sender_sig, sponsor_sig).unwrap_err(),
+        SuiError::SignerSignatureNumberMismatch { .. }
+    ));
+}
+
 #[test]
 fn test_sponsored_transaction_validity_check() {
     let sender_kp = SuiKeyPair::Ed25519(get_key_pair().1);
@@ -1247,3 +1309,42 @@ fn test_unique_input_objects() {
         input_objects
     );
 }
+
+#[test]
+fn test_transaction_events_ref_matches_owned_decoding() {
+    let sender = SuiAddress::random_for_testing_only();
+    let package_id = ObjectID::random();
+    let events = TransactionEvents {
+        data: vec![
+            Event::MoveEvent {
+                package_id,
+                transaction_module: Identifier::new("test_module").unwrap(),
+                sender,
+                type_: StructTag::from_str("0x2::test_module::TestEvent").unwrap(),
+                contents: vec![7; 64],
+            },
+            Event::EpochChange(1),
+            Event::NewObject {
+                package_id,
+                transaction_module: Identifier::new("test_module").unwrap(),
+                sender,
+                recipient: Owner::AddressOwner(sender),
+                object_type: "0x2::test_module::TestObject".to_string(),
+                object_id: ObjectID::random(),
+                version: SequenceNumber::new(),
+            },
+        ],
+    };
+    let bytes = bcs::to_bytes(&events).unwrap();
+
+    let events_ref = TransactionEventsRef::from_bcs_bytes(&bytes).unwrap();
+    match &events_ref.data[0] {
+        EventRef::MoveEvent { contents, .. } => {
+            // The contents borrow from the encoded bytes rather than being copied.
+            assert!(bytes.as_ptr_range().contains(&contents.as_ptr()));
+            assert_eq!(*contents, &[7; 64][..]);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert_eq!(events_ref.into_owned(), events);
+}
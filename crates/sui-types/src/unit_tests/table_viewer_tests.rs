@@ -0,0 +1,105 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::base_types::{SequenceNumber, TransactionDigest};
+use crate::dynamic_field::Field;
+use crate::id::UID;
+use crate::object::{MoveObject, Owner};
+use crate::parse_sui_struct_tag;
+
+fn field_object(table_id: ObjectID, key: u64, value: u64) -> Object {
+    let key_bytes = bcs::to_bytes(&key).unwrap();
+    let id = table_entry_id(table_id, &TypeTag::U64, &key_bytes).unwrap();
+    let field = Field {
+        id: UID::new(id),
+        name: key,
+        value,
+    };
+    let type_ = parse_sui_struct_tag("0x2::dynamic_field::Field<u64, u64>").unwrap();
+    // Safe because Field has no public transfer.
+    let move_object = unsafe {
+        MoveObject::new_from_execution_with_limit(
+            type_,
+            false,
+            SequenceNumber::new(),
+            bcs::to_bytes(&field).unwrap(),
+            u64::MAX,
+        )
+        .unwrap()
+    };
+    Object::new_move(
+        move_object,
+        Owner::ObjectOwner(table_id.into()),
+        TransactionDigest::genesis(),
+    )
+}
+
+#[test]
+fn test_table_vec_elements() {
+    let table_vec = TableVec {
+        contents: Table {
+            id: ObjectID::random(),
+            size: 3,
+        },
+    };
+    let objects = (0..3)
+        .map(|i| field_object(table_vec.contents.id, i, i * 10))
+        .collect::<Vec<_>>();
+    let store = objects.as_slice();
+
+    let elements = table_vec_elements(&table_vec, &store, MoveTypeLayout::U64)
+        .collect::<SuiResult<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        elements,
+        vec![MoveValue::U64(0), MoveValue::U64(10), MoveValue::U64(20)]
+    );
+
+    // Missing elements are errors.
+    let store = &objects[..2];
+    let mut elements = table_vec_elements(&table_vec, &store, MoveTypeLayout::U64);
+    assert_eq!(elements.size_hint(), (3, Some(3)));
+    assert!(elements.next().unwrap().is_ok());
+    assert!(elements.next().unwrap().is_ok());
+    assert!(elements.next().unwrap().is_err());
+    assert!(elements.next().is_none());
+}
+
+#[test]
+fn test_get_table_entry() {
+    let table = Table {
+        id: ObjectID::random(),
+        size: 1,
+    };
+    let objects = vec![field_object(table.id, 42, 7)];
+    let store = objects.as_slice();
+
+    let entry = get_table_entry(
+        &table,
+        &store,
+        &TypeTag::U64,
+        &bcs::to_bytes(&42u64).unwrap(),
+        &MoveTypeLayout::U64,
+        &MoveTypeLayout::U64,
+    )
+    .unwrap();
+    assert_eq!(
+        entry,
+        Some(TableEntry {
+            key: MoveValue::U64(42),
+            value: MoveValue::U64(7),
+        })
+    );
+
+    let missing = get_table_entry(
+        &table,
+        &store,
+        &TypeTag::U64,
+        &bcs::to_bytes(&43u64).unwrap(),
+        &MoveTypeLayout::U64,
+        &MoveTypeLayout::U64,
+    )
+    .unwrap();
+    assert!(missing.is_none());
+}
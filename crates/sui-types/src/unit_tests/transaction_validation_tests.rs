@@ -0,0 +1,115 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::base_types::{random_object_ref, SuiAddress};
+use crate::messages::ObjectArg;
+
+#[test]
+fn test_valid_transaction() {
+    let config = ProtocolConfig::get_for_max_version();
+    let min_budget = SuiCostTable::new(&config).min_gas_budget_external();
+    let tx = TransactionData::new_transfer(
+        SuiAddress::ZERO,
+        random_object_ref(),
+        SuiAddress::ZERO,
+        random_object_ref(),
+        min_budget,
+        1,
+    );
+    assert_eq!(validate_for_signing(&tx, &config), vec![]);
+}
+
+#[test]
+fn test_transaction_violations() {
+    let config = ProtocolConfig::get_for_max_version();
+    let min_budget = SuiCostTable::new(&config).min_gas_budget_external();
+    let gas = random_object_ref();
+    let coin = random_object_ref();
+    let pt = ProgrammableTransaction {
+        inputs: vec![
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(coin)),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(coin)),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(gas)),
+        ],
+        commands: vec![
+            // The gas coin can't be merged into another coin.
+            Command::MergeCoins(Argument::Input(0), vec![Argument::GasCoin]),
+            Command::TransferObjects(
+                vec![Argument::Result(0), Argument::Result(1)],
+                Argument::Input(3),
+            ),
+        ],
+    };
+    let tx = TransactionData::new_programmable(SuiAddress::ZERO, vec![gas, gas], pt, 0, 1);
+
+    let violations = validate_for_signing(&tx, &config);
+    assert_eq!(
+        violations,
+        vec![
+            TransactionViolation::DuplicateGasObject { object_id: gas.0 },
+            TransactionViolation::GasBudgetTooLow {
+                gas_budget: 0,
+                min_budget,
+            },
+            TransactionViolation::DuplicateObjectInput { object_id: coin.0 },
+            TransactionViolation::GasObjectAsInput { object_id: gas.0 },
+            TransactionViolation::GasCoinByValue { command: 0 },
+            TransactionViolation::ArgumentOutOfBounds {
+                command: 1,
+                argument: Argument::Result(1),
+            },
+            TransactionViolation::ArgumentOutOfBounds {
+                command: 1,
+                argument: Argument::Input(3),
+            },
+        ]
+    );
+    assert_eq!(
+        violations[4].to_string(),
+        "Command 0 uses the gas coin by value, only TransferObjects can"
+    );
+}
+
+#[test]
+fn test_transaction_limits() {
+    let config = ProtocolConfig::get_for_max_version();
+    let max_commands = config.max_programmable_tx_commands() as usize;
+    let pt = ProgrammableTransaction {
+        inputs: vec![],
+        // A single command is enough to exceed the size.
+        commands: std::iter::once(Command::Publish(vec![vec![0; config.max_tx_size()]]))
+            .chain(std::iter::repeat(Command::Publish(vec![])))
+            .take(max_commands)
+            .collect(),
+    };
+    let gas = (0..config.max_gas_payment_objects())
+        .map(|_| random_object_ref())
+        .collect::<Vec<_>>();
+    let max_budget = SuiCostTable::new(&config).max_gas_budget;
+    let tx = TransactionData::new_programmable(SuiAddress::ZERO, gas, pt, max_budget + 1, 1);
+
+    let violations = validate_for_signing(&tx, &config);
+    assert!(matches!(
+        violations[0],
+        TransactionViolation::TransactionTooLarge { size, max_size }
+            if size > max_size && max_size == config.max_tx_size()
+    ));
+    assert_eq!(
+        violations[1..],
+        [
+            TransactionViolation::TooManyGasObjects {
+                count: config.max_gas_payment_objects() as usize,
+                max_count: config.max_gas_payment_objects() as usize,
+            },
+            TransactionViolation::GasBudgetTooHigh {
+                gas_budget: max_budget + 1,
+                max_budget,
+            },
+            TransactionViolation::TooManyCommands {
+                count: max_commands,
+                max_count: max_commands,
+            },
+        ]
+    );
+}
@@ -0,0 +1,77 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::base_types::{random_object_ref, SuiAddress};
+use crate::gas_coin::GasCoin;
+use crate::messages::TransactionEffectsV1;
+use crate::object::Owner;
+
+fn coin_type() -> ObjectType {
+    ObjectType::Struct(GasCoin::type_())
+}
+
+#[test]
+fn test_write_set_violations() {
+    let owner = Owner::AddressOwner(SuiAddress::ZERO);
+    let gas = random_object_ref();
+    let coin = random_object_ref();
+    let created = random_object_ref();
+    let wrapped = random_object_ref();
+    let unwrapped_then_deleted = random_object_ref();
+    let effects = TransactionEffects::V1(TransactionEffectsV1 {
+        mutated: vec![(gas, owner), (coin, owner)],
+        created: vec![(created, owner)],
+        wrapped: vec![wrapped],
+        unwrapped_then_deleted: vec![unwrapped_then_deleted],
+        gas_object: (gas, owner),
+        ..Default::default()
+    });
+    let object_types = BTreeMap::from([(created.0, coin_type())]);
+
+    // Only the gas coin and `coin` may be touched.
+    let constraints =
+        WriteSetConstraints::new().allow_mutated(AllowedObjects::none().with_ids([gas.0, coin.0]));
+    let mut expected = vec![
+        WriteSetViolation {
+            object_id: created.0,
+            change: WriteSetChange::Created,
+            object_type: Some(coin_type()),
+        },
+        WriteSetViolation {
+            object_id: wrapped.0,
+            change: WriteSetChange::Deleted,
+            object_type: None,
+        },
+    ];
+    expected.sort_by_key(|violation| violation.object_id);
+    assert_eq!(
+        constraints.check(&effects, &object_types),
+        Err(expected.clone())
+    );
+    assert!(expected[0]
+        .to_string()
+        .starts_with(&format!("Object {} ", expected[0].object_id)));
+
+    // Created coins are allowed by type, the wrapped object by ID.
+    let constraints = constraints
+        .allow_created(AllowedObjects::none().with_type(coin_type()))
+        .allow_deleted(AllowedObjects::none().with_id(wrapped.0));
+    assert_eq!(constraints.check(&effects, &object_types), Ok(()));
+
+    // Objects without a known type are only allowed by ID.
+    assert_eq!(
+        constraints.violations(&effects, &BTreeMap::new()),
+        vec![WriteSetViolation {
+            object_id: created.0,
+            change: WriteSetChange::Created,
+            object_type: None,
+        }]
+    );
+    assert!(WriteSetConstraints::new()
+        .allow_created(AllowedObjects::any())
+        .allow_mutated(AllowedObjects::any())
+        .allow_deleted(AllowedObjects::any())
+        .violations(&effects, &BTreeMap::new())
+        .is_empty());
+}
@@ -0,0 +1,190 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Check the objects written by a transaction against the objects it is allowed to write, for
+//! signers enforcing policies like "this transaction may only touch these two coins".
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use crate::base_types::{ObjectID, ObjectType};
+use crate::messages::{TransactionEffects, TransactionEffectsAPI};
+use crate::storage::{DeleteKind, WriteKind};
+
+#[cfg(test)]
+#[path = "unit_tests/write_set_tests.rs"]
+mod write_set_tests;
+
+/// How an object was written by a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WriteSetChange {
+    /// Created, or unwrapped from another object.
+    Created,
+    Mutated,
+    /// Deleted, or wrapped into another object.
+    Deleted,
+}
+
+impl Display for WriteSetChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Created => write!(f, "created"),
+            Self::Mutated => write!(f, "mutated"),
+            Self::Deleted => write!(f, "deleted"),
+        }
+    }
+}
+
+/// The objects allowed for one kind of change, by ID or by type. Nothing is allowed by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllowedObjects {
+    any: bool,
+    ids: BTreeSet<ObjectID>,
+    types: BTreeSet<ObjectType>,
+}
+
+impl AllowedObjects {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn any() -> Self {
+        Self {
+            any: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_id(mut self, id: ObjectID) -> Self {
+        self.ids.insert(id);
+        self
+    }
+
+    pub fn with_ids(mut self, ids: impl IntoIterator<Item = ObjectID>) -> Self {
+        self.ids.extend(ids);
+        self
+    }
+
+    /// Allow the objects of `type_`, the type parameters included.
+    pub fn with_type(mut self, type_: ObjectType) -> Self {
+        self.types.insert(type_);
+        self
+    }
+
+    pub fn allows(&self, id: &ObjectID, type_: Option<&ObjectType>) -> bool {
+        self.any || self.ids.contains(id) || type_.map_or(false, |t| self.types.contains(t))
+    }
+}
+
+/// The objects a transaction may create, mutate and delete. Nothing is allowed by default, and
+/// the gas object is checked like any other mutated object.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteSetConstraints {
+    created: AllowedObjects,
+    mutated: AllowedObjects,
+    deleted: AllowedObjects,
+}
+
+/// An object written by a transaction that its constraints do not allow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WriteSetViolation {
+    pub object_id: ObjectID,
+    pub change: WriteSetChange,
+    /// `None` if the type of the object was not provided.
+    pub object_type: Option<ObjectType>,
+}
+
+impl Display for WriteSetViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Object {} ", self.object_id)?;
+        match &self.object_type {
+            Some(ObjectType::Package) => write!(f, "(package) ")?,
+            Some(ObjectType::Struct(tag)) => write!(f, "({tag}) ")?,
+            None => {}
+        }
+        write!(f, "must not be {}", self.change)
+    }
+}
+
+impl WriteSetConstraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow_created(mut self, allowed: AllowedObjects) -> Self {
+        self.created = allowed;
+        self
+    }
+
+    pub fn allow_mutated(mut self, allowed: AllowedObjects) -> Self {
+        self.mutated = allowed;
+        self
+    }
+
+    pub fn allow_deleted(mut self, allowed: AllowedObjects) -> Self {
+        self.deleted = allowed;
+        self
+    }
+
+    /// The objects written by `effects` that are not allowed, ordered by object ID.
+    ///
+    /// `object_types` holds the types of the written objects; objects missing from it are only
+    /// allowed by ID. The types of deleted and wrapped objects are those before the transaction.
+    pub fn violations(
+        &self,
+        effects: &TransactionEffects,
+        object_types: &BTreeMap<ObjectID, ObjectType>,
+    ) -> Vec<WriteSetViolation> {
+        let written = effects
+            .all_mutated()
+            .into_iter()
+            .map(|(object_ref, _, kind)| {
+                let change = match kind {
+                    WriteKind::Create | WriteKind::Unwrap => WriteSetChange::Created,
+                    WriteKind::Mutate => WriteSetChange::Mutated,
+                };
+                (object_ref.0, change)
+            })
+            .chain(
+                effects
+                    .all_deleted()
+                    .into_iter()
+                    // Unwrapped then deleted objects were neither created nor deleted from the
+                    // point of view of the store.
+                    .filter(|(_, kind)| *kind != DeleteKind::UnwrapThenDelete)
+                    .map(|(object_ref, _)| (object_ref.0, WriteSetChange::Deleted)),
+            );
+
+        let mut violations = written
+            .filter_map(|(object_id, change)| {
+                let allowed = match change {
+                    WriteSetChange::Created => &self.created,
+                    WriteSetChange::Mutated => &self.mutated,
+                    WriteSetChange::Deleted => &self.deleted,
+                };
+                let object_type = object_types.get(&object_id);
+                (!allowed.allows(&object_id, object_type)).then(|| WriteSetViolation {
+                    object_id,
+                    change,
+                    object_type: object_type.cloned(),
+                })
+            })
+            .collect::<Vec<_>>();
+        violations.sort_by_key(|violation| (violation.object_id, violation.change));
+        violations
+    }
+
+    /// Check that `effects` only write allowed objects, see [Self::violations].
+    pub fn check(
+        &self,
+        effects: &TransactionEffects,
+        object_types: &BTreeMap<ObjectID, ObjectType>,
+    ) -> Result<(), Vec<WriteSetViolation>> {
+        let violations = self.violations(effects, object_types);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
@@ -11,6 +11,8 @@ use std::{
 };
 
 use crate::config::{Config, PersistedConfig, SuiClientConfig, SuiEnv};
+use crate::inspect_tx::TransactionInspection;
+use crate::watch::AddressWatcher;
 use anyhow::{anyhow, ensure};
 use bip32::DerivationPath;
 use clap::*;
@@ -19,6 +21,8 @@ use fastcrypto::{
     encoding::{Base64, Encoding},
     traits::ToFromBytes,
 };
+use move_binary_format::CompiledModule;
+use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::TypeTag;
 use move_package::BuildConfig as MoveBuildConfig;
 use prettytable::Table;
@@ -31,22 +35,24 @@ use sui_source_validation::{BytecodeSourceVerifier, SourceMode};
 use sui_types::error::SuiError;
 
 use sui_framework_build::compiled_package::BuildConfig;
-use sui_json::SuiJsonValue;
+use sui_json::{coerce_move_function_args, SuiJsonValue};
 use sui_json_rpc_types::{
     DynamicFieldPage, SuiObjectData, SuiObjectInfo, SuiObjectResponse, SuiRawData,
     SuiTransactionEffectsAPI, SuiTransactionResponse,
 };
 use sui_json_rpc_types::{SuiExecutionStatus, SuiObjectDataOptions};
-use sui_keys::keystore::AccountKeystore;
+use sui_keys::keystore::{AccountKeystore, Keystore};
+use sui_keys::signer::{KeystoreSigner, Signer};
+use sui_sdk::submission_manager::SubmissionManager;
 use sui_sdk::SuiClient;
-use sui_types::crypto::SignatureScheme;
+use sui_types::crypto::{EncodeDecodeBase64, SignatureScheme, SuiKeyPair};
 use sui_types::dynamic_field::DynamicFieldType;
-use sui_types::intent::Intent;
+use sui_types::intent::{Intent, IntentMessage};
 use sui_types::signature::GenericSignature;
 use sui_types::{
     base_types::{ObjectID, ObjectRef, SuiAddress},
     gas_coin::GasCoin,
-    messages::{Transaction, VerifiedTransaction},
+    messages::{Transaction, TransactionData, VerifiedTransaction},
     object::Owner,
     parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS,
 };
@@ -192,7 +198,8 @@ pub enum SuiClientCommands {
         )]
         type_args: Vec<TypeTag>,
         /// Simplified ordered args like in the function syntax
-        /// ObjectIDs, Addresses must be hex strings
+        /// ObjectIDs, Addresses must be hex strings, integers can be decimal or hex,
+        /// vector<u8> can be a hex string, a JSON array or a utf8 string
         #[clap(long, multiple_occurrences = false, multiple_values = true)]
         args: Vec<SuiJsonValue>,
         /// ID of the gas object for gas payment, in 20 bytes Hex string
@@ -392,6 +399,20 @@ pub enum SuiClientCommands {
         gas_budget: u64,
     },
 
+    /// Merge the coins of a type owned by the active address until at most `target-count` of
+    /// them are left. The smallest coins are merged first, across as many transactions as needed.
+    ConsolidateCoins {
+        /// Type of the coins to merge, e.g. 0x2::sui::SUI. Defaults to SUI if not provided
+        #[clap(long)]
+        coin_type: Option<String>,
+        /// Number of coins to keep
+        #[clap(long, default_value = "1")]
+        target_count: usize,
+        /// Gas budget for each merge transaction
+        #[clap(long)]
+        gas_budget: u64,
+    },
+
     /// Create an example NFT
     #[clap(name = "create-example-nft")]
     CreateExampleNFT {
@@ -437,7 +458,20 @@ pub enum SuiClientCommands {
         amount: Option<u64>,
     },
 
+    /// Sign a serialized transaction with a key of the local keystore, without connecting to the network. This is useful to sign on an offline machine, the signature can be passed to `execute-signed` along with the transaction bytes.
+    #[clap(name = "sign")]
+    Sign {
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+        #[clap(long)]
+        tx_bytes: String,
+
+        /// Address of the key to sign with, defaults to the sender of the transaction.
+        #[clap(long)]
+        address: Option<SuiAddress>,
+    },
+
     /// Execute a Signed Transaction. This is useful when the user prefers to sign elsewhere and use this command to execute.
+    #[clap(name = "execute-signed", alias = "execute-signed-tx")]
     ExecuteSignedTx {
         /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
         #[clap(long)]
@@ -447,6 +481,27 @@ pub enum SuiClientCommands {
         #[clap(long)]
         signatures: Vec<String>,
     },
+
+    /// Decode a transaction and print its inputs and commands in a human readable form, flagging
+    /// patterns worth double checking before signing it, like transferring the gas coin.
+    #[clap(name = "inspect-tx")]
+    InspectTx {
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+        #[clap(long)]
+        tx_bytes: String,
+    },
+
+    /// Print a live feed of the transactions sent and received by an address, with the changes
+    /// of its balances and the objects it receives, until interrupted with Ctrl-C.
+    #[clap(name = "watch")]
+    Watch {
+        /// Address to watch, defaults to the active address.
+        #[clap(long)]
+        address: Option<SuiAddress>,
+        /// Interval between polls of the network, in milliseconds.
+        #[clap(long, default_value = "1000")]
+        interval_ms: u64,
+    },
 }
 
 impl SuiClientCommands {
@@ -513,16 +568,8 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .publish(sender, compiled_modules, gas, gas_budget)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&sender, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(sender)?)
                     .await?;
 
                 SuiClientCommandResult::Publish(response)
@@ -585,16 +632,8 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .transfer_object(from, object_id, gas, gas_budget, to)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(from)?)
                     .await?;
                 let effects = response.effects.as_ref().ok_or_else(|| {
                     anyhow!("Effects from SuiTransactionResult should not be empty")
@@ -622,16 +661,8 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .transfer_sui(from, object_id, gas_budget, to, amount)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(from)?)
                     .await?;
                 let effects = response.effects.as_ref().ok_or_else(|| {
                     anyhow!("Effects from SuiTransactionResult should not be empty")
@@ -671,16 +702,8 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .pay(from, input_coins, recipients, amounts, gas, gas_budget)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(from)?)
                     .await?;
                 let effects = response.effects.as_ref().ok_or_else(|| {
                     anyhow!("Effects from SuiTransactionResult should not be empty")
@@ -722,16 +745,8 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .pay_sui(signer, input_coins, recipients, amounts, gas_budget)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(signer)?)
                     .await?;
                 let effects = response.effects.as_ref().ok_or_else(|| {
                     anyhow!("Effects from SuiTransactionResult should not be empty")
@@ -761,16 +776,8 @@ impl SuiClientCommands {
                     .pay_all_sui(signer, input_coins, recipient, gas_budget)
                     .await?;
 
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(signer)?)
                     .await?;
                 let effects = response.effects.as_ref().ok_or_else(|| {
                     anyhow!("Effects from SuiTransactionResult should not be empty")
@@ -849,16 +856,8 @@ impl SuiClientCommands {
                         return Err(anyhow!("Exactly one of `count` and `amounts` must be present for split-coin command."));
                     }
                 };
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(signer)?)
                     .await?;
                 SuiClientCommandResult::SplitCoin(response)
             }
@@ -874,20 +873,31 @@ impl SuiClientCommands {
                     .transaction_builder()
                     .merge_coins(signer, primary_coin, coin_to_merge, gas, gas_budget)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
                 let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), vec![signature])
-                            .verify()?,
-                    )
+                    .sign_and_execute_transaction(data, &context.signer(signer)?)
                     .await?;
 
                 SuiClientCommandResult::MergeCoin(response)
             }
+            SuiClientCommands::ConsolidateCoins {
+                coin_type,
+                target_count,
+                gas_budget,
+            } => {
+                let client = context.get_client().await?;
+                let owner = context.active_address()?;
+                let responses = client
+                    .coin_merge_api()
+                    .consolidate_coins(
+                        owner,
+                        coin_type,
+                        target_count,
+                        gas_budget,
+                        &context.signer(owner)?,
+                    )
+                    .await?;
+                SuiClientCommandResult::ConsolidateCoins(responses)
+            }
             SuiClientCommands::Switch { address, env } => {
                 match (address, &env) {
                     (None, Some(env)) => {
@@ -967,6 +977,23 @@ impl SuiClientCommands {
                 ))
             }
 
+            SuiClientCommands::Sign { tx_bytes, address } => {
+                let data: TransactionData = bcs::from_bytes(
+                    &Base64::try_from(tx_bytes)
+                        .map_err(|e| anyhow!(e))?
+                        .to_vec()
+                        .map_err(|e| anyhow!(e))?,
+                )?;
+                let address = address.unwrap_or_else(|| data.sender());
+                let signature = context
+                    .signer(address)?
+                    .sign(&IntentMessage::new(Intent::default(), data))
+                    .await?;
+                SuiClientCommandResult::Sign(SignResponse {
+                    address,
+                    signature: signature.encode_base64(),
+                })
+            }
             SuiClientCommands::ExecuteSignedTx {
                 tx_bytes,
                 signatures,
@@ -996,6 +1023,45 @@ impl SuiClientCommands {
                 let response = context.execute_transaction(verified).await?;
                 SuiClientCommandResult::ExecuteSignedTx(response)
             }
+            SuiClientCommands::InspectTx { tx_bytes } => {
+                let data: TransactionData = bcs::from_bytes(
+                    &Base64::try_from(tx_bytes)
+                        .map_err(|e| anyhow!(e))?
+                        .to_vec()
+                        .map_err(|e| anyhow!(e))?,
+                )?;
+                let client = context.get_client().await?;
+                SuiClientCommandResult::InspectTx(TransactionInspection::new(&data, &client).await)
+            }
+            SuiClientCommands::Watch {
+                address,
+                interval_ms,
+            } => {
+                let address = address.unwrap_or(context.active_address()?);
+                let client = context.get_client().await?;
+                let mut watcher = AddressWatcher::new(client, address).await?;
+                println!("Watching address {address}, press Ctrl-C to stop...");
+                let mut count = 0;
+                loop {
+                    // Failed polls are retried, the watcher resumes from the last transaction seen.
+                    match watcher.poll().await {
+                        Ok(activities) => {
+                            for activity in activities {
+                                println!("{activity}");
+                                count += 1;
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("{}", format!("Failed polling transactions: {e}").yellow())
+                        }
+                    }
+                    tokio::select! {
+                        _ = tokio::signal::ctrl_c() => break,
+                        _ = tokio::time::sleep(std::time::Duration::from_millis(interval_ms)) => {}
+                    }
+                }
+                SuiClientCommandResult::Watch(count)
+            }
             SuiClientCommands::NewEnv { alias, rpc, ws } => {
                 if context.config.envs.iter().any(|env| env.alias == alias) {
                     return Err(anyhow!(
@@ -1235,6 +1301,43 @@ impl WalletContext {
             )
             .await?)
     }
+
+    /// Sign `data` with `signer` and execute it, waiting for local execution.
+    pub async fn sign_and_execute_transaction(
+        &self,
+        data: TransactionData,
+        signer: &dyn Signer,
+    ) -> anyhow::Result<SuiTransactionResponse> {
+        let client = self.get_client().await?;
+        Ok(client
+            .quorum_driver()
+            .sign_and_execute_transaction(
+                data,
+                signer,
+                Some(sui_types::messages::ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await?)
+    }
+
+    /// Signer for one of the addresses managed by the wallet's keystore.
+    pub fn signer(&self, address: SuiAddress) -> anyhow::Result<KeystoreSigner<'_, Keystore>> {
+        KeystoreSigner::new(&self.config.keystore, address)
+    }
+
+    /// Manager submitting the transactions of one of the addresses managed by the wallet's
+    /// keystore concurrently, see [SubmissionManager].
+    pub async fn submission_manager(
+        &self,
+        address: SuiAddress,
+    ) -> anyhow::Result<SubmissionManager> {
+        let key = self.config.keystore.get_key(&address)?;
+        let key = SuiKeyPair::decode_base64(&key.encode_base64())
+            .map_err(|e| anyhow!("Cannot copy the key of {address}: {e}"))?;
+        Ok(SubmissionManager::new(
+            self.get_client().await?,
+            Arc::new(key),
+        ))
+    }
 }
 
 impl Display for SuiClientCommandResult {
@@ -1382,6 +1485,14 @@ impl Display for SuiClientCommandResult {
             SuiClientCommandResult::MergeCoin(response) => {
                 write!(writer, "{}", write_transaction_response(response)?)?;
             }
+            SuiClientCommandResult::ConsolidateCoins(responses) => {
+                if responses.is_empty() {
+                    write!(writer, "No coins to merge.")?;
+                }
+                for response in responses {
+                    writeln!(writer, "{}", write_transaction_response(response)?)?;
+                }
+            }
             SuiClientCommandResult::Switch(response) => {
                 write!(writer, "{}", response)?;
             }
@@ -1403,6 +1514,14 @@ impl Display for SuiClientCommandResult {
             SuiClientCommandResult::SerializeTransferSui(data) => {
                 writeln!(writer, "Raw tx_bytes to execute: {}", data)?;
             }
+            SuiClientCommandResult::Sign(response) => {
+                writeln!(writer, "Signer address: {}", response.address)?;
+                writeln!(
+                    writer,
+                    "Serialized signature (`flag || sig || pk` in Base64): {}",
+                    response.signature
+                )?;
+            }
             SuiClientCommandResult::ActiveEnv(env) => {
                 write!(writer, "{}", env.as_deref().unwrap_or("None"))?;
             }
@@ -1421,6 +1540,12 @@ impl Display for SuiClientCommandResult {
             SuiClientCommandResult::VerifySource => {
                 writeln!(writer, "Source verification succeeded!")?;
             }
+            SuiClientCommandResult::InspectTx(inspection) => {
+                write!(writer, "{}", inspection)?;
+            }
+            SuiClientCommandResult::Watch(count) => {
+                writeln!(writer, "Stopped watching after {count} transactions.")?;
+            }
         }
         write!(f, "{}", writer.trim_end_matches('\n'))
     }
@@ -1436,16 +1561,13 @@ pub async fn call_move(
     args: Vec<SuiJsonValue>,
     context: &mut WalletContext,
 ) -> Result<SuiTransactionResponse, anyhow::Error> {
-    // Convert all numeric input to String, this will allow number input from the CLI without failing SuiJSON's checks.
-    let args = args
-        .into_iter()
-        .map(|value| SuiJsonValue::new(convert_number_to_string(value.to_json_value())))
-        .collect::<Result<_, _>>()?;
-
     let gas_owner = context.try_get_object_owner(&gas).await?;
     let sender = gas_owner.unwrap_or(context.active_address()?);
 
     let client = context.get_client().await?;
+    // Coerce the CLI input following the parameter types of the function, this will allow number
+    // input from the CLI without failing SuiJSON's checks.
+    let args = coerce_call_args(&client, package, module, function, &type_args, args).await?;
     let data = client
         .transaction_builder()
         .move_call(
@@ -1462,13 +1584,9 @@ pub async fn call_move(
             gas_budget,
         )
         .await?;
-    let signature = context
-        .config
-        .keystore
-        .sign_secure(&sender, &data, Intent::default())?;
-    let transaction = Transaction::from_data(data, Intent::default(), vec![signature]).verify()?;
-
-    let response = context.execute_transaction(transaction).await?;
+    let response = context
+        .sign_and_execute_transaction(data, &context.signer(sender)?)
+        .await?;
     let effects = response
         .effects
         .as_ref()
@@ -1479,17 +1597,39 @@ pub async fn call_move(
     Ok(response)
 }
 
-fn convert_number_to_string(value: Value) -> Value {
-    match value {
-        Value::Number(n) => Value::String(n.to_string()),
-        Value::Array(a) => Value::Array(a.into_iter().map(convert_number_to_string).collect()),
-        Value::Object(o) => Value::Object(
-            o.into_iter()
-                .map(|(k, v)| (k, convert_number_to_string(v)))
-                .collect(),
-        ),
-        _ => value,
-    }
+async fn coerce_call_args(
+    client: &SuiClient,
+    package_id: ObjectID,
+    module: &str,
+    function: &str,
+    type_args: &[TypeTag],
+    args: Vec<SuiJsonValue>,
+) -> Result<Vec<SuiJsonValue>, anyhow::Error> {
+    let package = client
+        .read_api()
+        .get_object_with_options(package_id, SuiObjectDataOptions::bcs_lossless())
+        .await?
+        .into_object()?
+        .bcs
+        .ok_or_else(|| anyhow!("Bcs field in object [{}] is missing.", package_id))?;
+    let package = package
+        .try_as_package()
+        .ok_or_else(|| anyhow!("Object [{}] is not a move package.", package_id))?;
+    let module_bytes = package
+        .module_map
+        .get(module)
+        .ok_or_else(|| anyhow!("Module {} not found in package [{}].", module, package_id))?;
+    let module = CompiledModule::deserialize(module_bytes)?;
+
+    // Back to the strings given on the command line
+    let args = args
+        .into_iter()
+        .map(|value| match value.to_json_value() {
+            Value::String(s) => s,
+            value => value.to_string(),
+        })
+        .collect::<Vec<_>>();
+    coerce_move_function_args(&module, &Identifier::new(function)?, type_args, &args)
 }
 
 fn unwrap_or<'a>(val: &'a Option<String>, default: &'a str) -> &'a str {
@@ -1578,16 +1718,28 @@ pub enum SuiClientCommandResult {
     Gas(Vec<GasCoin>),
     SplitCoin(SuiTransactionResponse),
     MergeCoin(SuiTransactionResponse),
+    ConsolidateCoins(Vec<SuiTransactionResponse>),
     Switch(SwitchResponse),
     ActiveAddress(Option<SuiAddress>),
     ActiveEnv(Option<String>),
     Envs(Vec<SuiEnv>, Option<String>),
     CreateExampleNFT(SuiObjectResponse),
     SerializeTransferSui(String),
+    Sign(SignResponse),
     ExecuteSignedTx(SuiTransactionResponse),
+    InspectTx(TransactionInspection),
+    Watch(usize),
     NewEnv(SuiEnv),
 }
 
+#[derive(Serialize, Clone, Debug)]
+pub struct SignResponse {
+    /// Address of the signing key
+    pub address: SuiAddress,
+    /// Base64 encoded signature `flag || signature || pubkey`
+    pub signature: String,
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct SwitchResponse {
     /// Active address
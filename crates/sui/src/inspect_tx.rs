@@ -0,0 +1,421 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter, Write};
+
+use colored::Colorize;
+use fastcrypto::encoding::{Encoding, Hex};
+use futures::future::join_all;
+use serde::Serialize;
+use sui_json_rpc_types::{SuiObjectData, SuiObjectDataOptions};
+use sui_sdk::SuiClient;
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_types::messages::{
+    Argument, CallArg, Command, ObjectArg, ProgrammableTransaction, SingleTransactionKind,
+    TransactionData, TransactionDataAPI, TransactionKind,
+};
+use sui_types::object::Owner;
+
+/// Human readable breakdown of a transaction, produced by `sui client inspect-tx`.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInspection {
+    pub sender: SuiAddress,
+    pub gas_owner: SuiAddress,
+    pub gas_budget: u64,
+    pub gas_price: u64,
+    pub gas_payment: Vec<InspectedObject>,
+    pub transactions: Vec<InspectedTransaction>,
+    /// Patterns in the transaction the signer should double check, e.g. sending away the gas coin.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum InspectedTransaction {
+    Programmable {
+        inputs: Vec<InspectedInput>,
+        commands: Vec<InspectedCommand>,
+    },
+    /// Transaction kinds other than programmable transactions, in their display form.
+    Other(String),
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum InspectedInput {
+    /// Hex encoded BCS bytes of a pure value.
+    Pure(String),
+    Object(InspectedObject),
+    ObjVec(Vec<InspectedObject>),
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectedObject {
+    pub object_id: ObjectID,
+    /// Version of owned and immutable objects, initial shared version of shared objects.
+    pub version: SequenceNumber,
+    /// Whether a shared object is used mutably, None for objects that are not shared.
+    pub shared_mutable: Option<bool>,
+    /// Type and owner of the object as of its latest version, None if it could not be fetched.
+    pub object_type: Option<String>,
+    pub owner: Option<Owner>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectedCommand {
+    pub command: String,
+    /// Arguments of the command as (role, provenance) pairs.
+    pub arguments: Vec<(String, String)>,
+}
+
+impl TransactionInspection {
+    /// Inspect `data`, resolving the types and owners of the objects it uses with `client`.
+    pub async fn new(data: &TransactionData, client: &SuiClient) -> Self {
+        let object_ids = input_object_ids(data);
+        let objects = join_all(object_ids.into_iter().map(|id| async move {
+            client
+                .read_api()
+                .get_object_with_options(id, SuiObjectDataOptions::new().with_type().with_owner())
+                .await
+                .ok()
+                .and_then(|response| response.object().ok().cloned())
+                .map(|object| (id, object))
+        }))
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+        Self::with_objects(data, &objects)
+    }
+
+    /// Inspect `data` with the types and owners of its objects taken from `objects`, objects
+    /// missing from it are left unresolved.
+    pub fn with_objects(
+        data: &TransactionData,
+        objects: &BTreeMap<ObjectID, SuiObjectData>,
+    ) -> Self {
+        let mut warnings = vec![];
+        let transactions = data
+            .kind()
+            .single_transactions()
+            .enumerate()
+            .map(|(i, tx)| match tx {
+                SingleTransactionKind::ProgrammableTransaction(pt) => {
+                    warnings.extend(programmable_transaction_warnings(i, pt));
+                    InspectedTransaction::Programmable {
+                        inputs: pt
+                            .inputs
+                            .iter()
+                            .map(|input| inspect_input(input, objects))
+                            .collect(),
+                        commands: pt
+                            .commands
+                            .iter()
+                            .map(|command| inspect_command(command, pt))
+                            .collect(),
+                    }
+                }
+                tx => {
+                    match tx {
+                        SingleTransactionKind::TransferSui(t) if t.amount.is_none() => warnings
+                            .push(format!(
+                                "Transaction #{i} transfers the gas coin to {}",
+                                t.recipient
+                            )),
+                        SingleTransactionKind::PayAllSui(p) => warnings.push(format!(
+                            "Transaction #{i} transfers all the gas coins to {}",
+                            p.recipient
+                        )),
+                        _ => {}
+                    }
+                    InspectedTransaction::Other(tx.to_string())
+                }
+            })
+            .collect();
+        let gas_payment = data
+            .gas()
+            .iter()
+            .map(|(id, version, _)| inspect_object(*id, *version, None, objects))
+            .collect();
+        Self {
+            sender: data.sender(),
+            gas_owner: data.gas_owner(),
+            gas_budget: data.gas_budget(),
+            gas_price: data.gas_price(),
+            gas_payment,
+            transactions,
+            warnings,
+        }
+    }
+}
+
+fn input_object_ids(data: &TransactionData) -> BTreeSet<ObjectID> {
+    let mut ids: BTreeSet<_> = data.gas().iter().map(|(id, _, _)| *id).collect();
+    for tx in data.kind().single_transactions() {
+        if let SingleTransactionKind::ProgrammableTransaction(pt) = tx {
+            for input in &pt.inputs {
+                match input {
+                    CallArg::Pure(_) => {}
+                    CallArg::Object(arg) => {
+                        ids.insert(object_arg_id(arg));
+                    }
+                    CallArg::ObjVec(args) => ids.extend(args.iter().map(object_arg_id)),
+                }
+            }
+        }
+    }
+    ids
+}
+
+fn object_arg_id(arg: &ObjectArg) -> ObjectID {
+    match arg {
+        ObjectArg::ImmOrOwnedObject((id, _, _)) => *id,
+        ObjectArg::SharedObject { id, .. } => *id,
+    }
+}
+
+fn inspect_input(input: &CallArg, objects: &BTreeMap<ObjectID, SuiObjectData>) -> InspectedInput {
+    match input {
+        CallArg::Pure(bytes) => InspectedInput::Pure(Hex::encode(bytes)),
+        CallArg::Object(arg) => InspectedInput::Object(inspect_object_arg(arg, objects)),
+        CallArg::ObjVec(args) => InspectedInput::ObjVec(
+            args.iter()
+                .map(|arg| inspect_object_arg(arg, objects))
+                .collect(),
+        ),
+    }
+}
+
+fn inspect_object_arg(
+    arg: &ObjectArg,
+    objects: &BTreeMap<ObjectID, SuiObjectData>,
+) -> InspectedObject {
+    match arg {
+        ObjectArg::ImmOrOwnedObject((id, version, _)) => {
+            inspect_object(*id, *version, None, objects)
+        }
+        ObjectArg::SharedObject {
+            id,
+            initial_shared_version,
+            mutable,
+        } => inspect_object(*id, *initial_shared_version, Some(*mutable), objects),
+    }
+}
+
+fn inspect_object(
+    object_id: ObjectID,
+    version: SequenceNumber,
+    shared_mutable: Option<bool>,
+    objects: &BTreeMap<ObjectID, SuiObjectData>,
+) -> InspectedObject {
+    let object = objects.get(&object_id);
+    InspectedObject {
+        object_id,
+        version,
+        shared_mutable,
+        object_type: object.and_then(|o| o.type_.as_ref()).map(|t| t.to_string()),
+        owner: object.and_then(|o| o.owner),
+    }
+}
+
+fn inspect_command(command: &Command, pt: &ProgrammableTransaction) -> InspectedCommand {
+    let describe = |role: &str, arg: &Argument| (role.to_string(), describe_argument(arg, pt));
+    let (command, arguments) = match command {
+        Command::MoveCall(call) => (
+            command_name(command),
+            call.arguments
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| describe(&format!("argument {i}"), arg))
+                .collect(),
+        ),
+        Command::TransferObjects(objs, recipient) => (
+            command_name(command),
+            objs.iter()
+                .map(|obj| describe("object", obj))
+                .chain([describe("recipient", recipient)])
+                .collect(),
+        ),
+        Command::SplitCoin(coin, amount) => (
+            command_name(command),
+            vec![describe("coin", coin), describe("amount", amount)],
+        ),
+        Command::MergeCoins(target, coins) => (
+            command_name(command),
+            [describe("target", target)]
+                .into_iter()
+                .chain(coins.iter().map(|coin| describe("coin", coin)))
+                .collect(),
+        ),
+        Command::Publish(modules) => (
+            format!("{} ({} modules)", command_name(command), modules.len()),
+            vec![],
+        ),
+        Command::MakeMoveVec(_, elems) => (
+            command_name(command),
+            elems.iter().map(|elem| describe("element", elem)).collect(),
+        ),
+        Command::Upgrade(modules, dependencies, package, ticket) => (
+            format!(
+                "{} {package} ({} modules, {} dependencies)",
+                command_name(command),
+                modules.len(),
+                dependencies.len()
+            ),
+            vec![describe("ticket", ticket)],
+        ),
+    };
+    InspectedCommand { command, arguments }
+}
+
+fn command_name(command: &Command) -> String {
+    match command {
+        Command::MoveCall(call) => {
+            let mut name = format!(
+                "MoveCall {}::{}::{}",
+                call.package, call.module, call.function
+            );
+            if !call.type_arguments.is_empty() {
+                let type_arguments: Vec<_> =
+                    call.type_arguments.iter().map(|t| t.to_string()).collect();
+                write!(name, "<{}>", type_arguments.join(", ")).unwrap();
+            }
+            name
+        }
+        Command::TransferObjects(..) => "TransferObjects".to_string(),
+        Command::SplitCoin(..) => "SplitCoin".to_string(),
+        Command::MergeCoins(..) => "MergeCoins".to_string(),
+        Command::Publish(_) => "Publish".to_string(),
+        Command::MakeMoveVec(Some(ty), _) => format!("MakeMoveVec<{ty}>"),
+        Command::MakeMoveVec(None, _) => "MakeMoveVec".to_string(),
+        Command::Upgrade(..) => "Upgrade".to_string(),
+    }
+}
+
+/// Where the value of `arg` comes from: the gas coin, an input, or the result of a prior command.
+fn describe_argument(arg: &Argument, pt: &ProgrammableTransaction) -> String {
+    let command = |i: &u16| {
+        pt.commands
+            .get(*i as usize)
+            .map(command_name)
+            .unwrap_or_else(|| "out of bounds".to_string())
+    };
+    match arg {
+        Argument::GasCoin => "gas coin".to_string(),
+        Argument::Input(i) => match pt.inputs.get(*i as usize) {
+            Some(CallArg::Pure(bytes)) => format!("input #{i} (pure {})", Hex::encode(bytes)),
+            Some(CallArg::Object(arg)) => format!("input #{i} (object {})", object_arg_id(arg)),
+            Some(CallArg::ObjVec(args)) => format!("input #{i} ({} objects)", args.len()),
+            None => format!("input #{i} (out of bounds)"),
+        },
+        Argument::Result(i) => format!("result of command #{i} ({})", command(i)),
+        Argument::NestedResult(i, j) => {
+            format!("result {j} of command #{i} ({})", command(i))
+        }
+    }
+}
+
+fn programmable_transaction_warnings(i: usize, pt: &ProgrammableTransaction) -> Vec<String> {
+    pt.commands
+        .iter()
+        .enumerate()
+        .filter_map(|(j, command)| match command {
+            Command::TransferObjects(objs, recipient) if objs.contains(&Argument::GasCoin) => {
+                Some(format!(
+                    "Command #{j} of transaction #{i} transfers the gas coin to {}",
+                    describe_argument(recipient, pt)
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Batches are flattened into their single transactions.
+trait SingleTransactions {
+    fn single_transactions(&self) -> Box<dyn Iterator<Item = &SingleTransactionKind> + '_>;
+}
+
+impl SingleTransactions for TransactionKind {
+    fn single_transactions(&self) -> Box<dyn Iterator<Item = &SingleTransactionKind> + '_> {
+        match self {
+            TransactionKind::Single(tx) => Box::new(std::iter::once(tx)),
+            TransactionKind::Batch(txs) => Box::new(txs.iter()),
+        }
+    }
+}
+
+impl Display for TransactionInspection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut writer = String::new();
+        writeln!(writer, "{}", "----- Transaction Data ----".bold())?;
+        writeln!(writer, "Sender: {}", self.sender)?;
+        writeln!(writer, "Gas Owner: {}", self.gas_owner)?;
+        writeln!(writer, "Gas Budget: {}", self.gas_budget)?;
+        writeln!(writer, "Gas Price: {}", self.gas_price)?;
+        writeln!(writer, "Gas Payment:")?;
+        for object in &self.gas_payment {
+            writeln!(writer, "  {object}")?;
+        }
+        for (i, tx) in self.transactions.iter().enumerate() {
+            writeln!(writer, "{}", format!("----- Transaction #{i} ----").bold())?;
+            match tx {
+                InspectedTransaction::Programmable { inputs, commands } => {
+                    writeln!(writer, "Inputs:")?;
+                    for (j, input) in inputs.iter().enumerate() {
+                        match input {
+                            InspectedInput::Pure(bytes) => writeln!(writer, "  #{j} Pure {bytes}")?,
+                            InspectedInput::Object(object) => {
+                                writeln!(writer, "  #{j} Object {object}")?
+                            }
+                            InspectedInput::ObjVec(objects) => {
+                                writeln!(writer, "  #{j} Objects")?;
+                                for object in objects {
+                                    writeln!(writer, "     {object}")?;
+                                }
+                            }
+                        }
+                    }
+                    writeln!(writer, "Commands:")?;
+                    for (j, command) in commands.iter().enumerate() {
+                        writeln!(writer, "  #{j} {}", command.command)?;
+                        for (role, provenance) in &command.arguments {
+                            writeln!(writer, "     {role}: {provenance}")?;
+                        }
+                    }
+                }
+                InspectedTransaction::Other(tx) => write!(writer, "{tx}")?,
+            }
+        }
+        if !self.warnings.is_empty() {
+            writeln!(writer, "{}", "----- Warnings ----".bold())?;
+            for warning in &self.warnings {
+                writeln!(writer, "{}", warning.yellow())?;
+            }
+        }
+        write!(f, "{}", writer)
+    }
+}
+
+impl Display for InspectedObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.object_id)?;
+        match self.shared_mutable {
+            Some(true) => write!(f, " [shared mutable, initial version {}]", self.version)?,
+            Some(false) => write!(f, " [shared immutable, initial version {}]", self.version)?,
+            None => write!(f, " [version {}]", self.version)?,
+        }
+        match &self.object_type {
+            Some(object_type) => write!(f, " {object_type}")?,
+            None => write!(f, " {}", "unknown type".red())?,
+        }
+        if let Some(owner) = &self.owner {
+            write!(f, " owned by {owner}")?;
+        }
+        Ok(())
+    }
+}
@@ -19,7 +19,8 @@ use sui_types::multisig::{MultiSig, MultiSigPublicKey, ThresholdUnit, WeightUnit
 use sui_types::signature::GenericSignature;
 use tracing::info;
 
-use sui_keys::keystore::{AccountKeystore, Keystore};
+use sui_keys::encryption::{prompt_new_passphrase, read_new_keystore_passphrase};
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
 use sui_types::base_types::SuiAddress;
 use sui_types::crypto::{get_authority_key_pair, EncodeDecodeBase64, SignatureScheme, SuiKeyPair};
 #[cfg(test)]
@@ -35,7 +36,8 @@ pub enum KeyToolCommand {
     /// m/54'/784'/0'/0/0 for secp256k1 or m/74'/784'/0'/0/0 for secp256r1.
     ///
     /// The keypair file is output to the current directory. The content of the file is
-    /// a Base64 encoded string of 33-byte `flag || privkey`. Note: To generate and add keypair
+    /// a Base64 encoded string of 33-byte `flag || privkey`. The recovery phrase is printed, the
+    /// key can be recovered from it with `keytool import` and the same derivation path. Note: To generate and add keypair
     /// to sui.keystore, use `sui client new-address`), see more at [enum SuiClientCommands].
     Generate {
         key_scheme: SignatureScheme,
@@ -49,7 +51,7 @@ pub enum KeyToolCommand {
     /// outputs the keypair into a file at the current directory, and prints out its Sui
     /// address, Base64 encoded public key, and the key scheme flag.
     Unpack { keypair: SuiKeyPair },
-    /// List all keys by its Sui address, Base64 encoded public key, key scheme name in
+    /// List all keys by its Sui address, Base64 encoded public key, key scheme name and alias in
     /// sui.keystore.
     List,
     /// Create signature using the private key for for the given address in sui keystore.
@@ -74,6 +76,45 @@ pub enum KeyToolCommand {
         key_scheme: SignatureScheme,
         derivation_path: Option<DerivationPath>,
     },
+    /// Add a keypair to sui.keystore, the keypair is [enum SuiKeyPair] (Base64 encoded of 33-byte
+    /// `flag || privkey`) as printed by `keytool export`.
+    ImportKeypair {
+        keypair: SuiKeyPair,
+        #[clap(long)]
+        alias: Option<String>,
+    },
+    /// Print the keypair of the given address in sui.keystore as Base64 encoded of 33-byte
+    /// `flag || privkey`.
+    Export {
+        #[clap(long, parse(try_from_str = decode_bytes_hex))]
+        address: SuiAddress,
+    },
+    /// Set the alias of the given address in sui.keystore, or remove it if no alias is given.
+    SetAlias {
+        #[clap(long, parse(try_from_str = decode_bytes_hex))]
+        address: SuiAddress,
+        #[clap(long)]
+        alias: Option<String>,
+    },
+    /// Generate a new key in sui.keystore to replace the key of the given address, of the same key
+    /// scheme unless `key_scheme` is given. The new key takes over the alias of the old key. The
+    /// old key is kept so that its objects can be transferred to the new address, unless
+    /// `remove_old` is set.
+    Rotate {
+        #[clap(long, parse(try_from_str = decode_bytes_hex))]
+        address: SuiAddress,
+        #[clap(long)]
+        key_scheme: Option<SignatureScheme>,
+        #[clap(long)]
+        derivation_path: Option<DerivationPath>,
+        #[clap(long)]
+        remove_old: bool,
+    },
+    /// Encrypt the plaintext sui.keystore in place with a passphrase, read from the
+    /// SUI_KEYSTORE_PASSPHRASE environment variable or prompted for.
+    Encrypt,
+    /// Change the passphrase of an encrypted sui.keystore, the new passphrase is prompted for.
+    ChangePassphrase,
     /// This reads the content at the provided file path. The accepted format can be
     /// [enum SuiKeyPair] (Base64 encoded of 33-byte `flag || privkey`) or `type AuthorityKeyPair`
     /// (Base64 encoded `privkey`). This prints out the account keypair as Base64 encoded `flag || privkey`,
@@ -120,13 +161,15 @@ impl KeyToolCommand {
                     let file_name = format!("bls-{address}.key");
                     write_authority_keypair_to_file(&keypair, file_name)?;
                 } else {
-                    let (address, kp, scheme, _) = generate_new_key(key_scheme, derivation_path)?;
+                    let (address, kp, scheme, phrase) =
+                        generate_new_key(key_scheme, derivation_path)?;
                     let file = format!("{address}.key");
                     write_keypair_to_file(&kp, &file)?;
                     println!(
                         "Keypair wrote to file path: {:?} with scheme: {:?}",
                         file, scheme
                     );
+                    println!("Secret Recovery Phrase : [{phrase}]");
                 }
             }
             KeyToolCommand::Show { file } => {
@@ -156,16 +199,18 @@ impl KeyToolCommand {
             }
             KeyToolCommand::List => {
                 println!(
-                    " {0: ^42} | {1: ^45} | {2: ^6}",
-                    "Sui Address", "Public Key (Base64)", "Scheme"
+                    " {0: ^42} | {1: ^45} | {2: ^6} | {3: ^10}",
+                    "Sui Address", "Public Key (Base64)", "Scheme", "Alias"
                 );
-                println!("{}", ["-"; 100].join(""));
+                println!("{}", ["-"; 113].join(""));
                 for pub_key in keystore.keys() {
+                    let address = Into::<SuiAddress>::into(&pub_key);
                     println!(
-                        " {0: ^42} | {1: ^45} | {2: ^6}",
-                        Into::<SuiAddress>::into(&pub_key),
+                        " {0: ^42} | {1: ^45} | {2: ^6} | {3: ^10}",
+                        address,
                         pub_key.encode_base64(),
-                        pub_key.scheme().to_string()
+                        pub_key.scheme().to_string(),
+                        keystore.alias(&address).unwrap_or("")
                     );
                 }
             }
@@ -204,6 +249,65 @@ impl KeyToolCommand {
                     keystore.import_from_mnemonic(&mnemonic_phrase, key_scheme, derivation_path)?;
                 info!("Key imported for address [{address}]");
             }
+            KeyToolCommand::ImportKeypair { keypair, alias } => {
+                let address: SuiAddress = (&keypair.public()).into();
+                keystore.add_key(keypair)?;
+                if alias.is_some() {
+                    keystore.set_alias(&address, alias)?;
+                }
+                info!("Key imported for address [{address}]");
+            }
+            KeyToolCommand::Export { address } => {
+                let keypair = keystore.get_key(&address)?;
+                println!(
+                    "Keypair of address [{address}]: {}",
+                    keypair.encode_base64()
+                );
+            }
+            KeyToolCommand::SetAlias { address, alias } => {
+                keystore.set_alias(&address, alias.clone())?;
+                match alias {
+                    Some(alias) => info!("Alias of address [{address}] set to [{alias}]"),
+                    None => info!("Alias of address [{address}] removed"),
+                }
+            }
+            KeyToolCommand::Rotate {
+                address,
+                key_scheme,
+                derivation_path,
+                remove_old,
+            } => {
+                let (new_address, phrase, scheme) =
+                    keystore.rotate_key(&address, key_scheme, derivation_path, remove_old)?;
+                println!(
+                    "Rotated key of address [{address}] to new address [{new_address}] with scheme {scheme}, secret recovery phrase : [{phrase}]"
+                );
+                if !remove_old {
+                    println!("The old key is kept in the keystore, transfer its objects to the new address and remove it with `--remove-old`.");
+                }
+            }
+            KeyToolCommand::Encrypt => {
+                let Keystore::File(file) = keystore else {
+                    return Err(anyhow!("Only a plaintext file keystore can be encrypted"));
+                };
+                let path = file
+                    .path()
+                    .ok_or_else(|| anyhow!("Cannot encrypt a keystore without a path"))?
+                    .to_path_buf();
+                let encrypted =
+                    FileBasedKeystore::new(&path)?.encrypt(read_new_keystore_passphrase()?)?;
+                *keystore = Keystore::from(encrypted);
+                println!("Keystore {:?} encrypted, set `keystore` to `Encrypted: {}` in client.yaml to use it with the client.", path, path.display());
+            }
+            KeyToolCommand::ChangePassphrase => {
+                let Keystore::Encrypted(encrypted) = keystore else {
+                    return Err(anyhow!(
+                        "The keystore is not encrypted, encrypt it with `keytool encrypt`"
+                    ));
+                };
+                encrypted.change_passphrase(prompt_new_passphrase()?)?;
+                info!("Keystore passphrase changed");
+            }
 
             KeyToolCommand::LoadKeypair { file } => {
                 match read_keypair_from_file(&file) {
@@ -5,8 +5,10 @@
 pub mod client_commands;
 pub mod config;
 pub mod console;
+pub mod inspect_tx;
 pub mod keytool;
 pub mod shell;
 pub mod sui_commands;
+pub mod watch;
 
 pub mod genesis_ceremony;
@@ -19,7 +19,10 @@ use sui_config::{
     sui_config_dir, Config, PersistedConfig, FULL_NODE_DB_PATH, SUI_CLIENT_CONFIG,
     SUI_FULLNODE_CONFIG, SUI_NETWORK_CONFIG,
 };
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_keys::encryption::read_keystore_passphrase;
+use sui_keys::keystore::{
+    AccountKeystore, EncryptedFileBasedKeystore, FileBasedKeystore, Keystore,
+};
 use sui_swarm::memory::Swarm;
 use sui_types::crypto::{SignatureScheme, SuiKeyPair};
 
@@ -201,7 +204,14 @@ impl SuiCommand {
             SuiCommand::KeyTool { keystore_path, cmd } => {
                 let keystore_path =
                     keystore_path.unwrap_or(sui_config_dir()?.join(SUI_KEYSTORE_FILENAME));
-                let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+                let mut keystore = if EncryptedFileBasedKeystore::is_encrypted(&keystore_path) {
+                    Keystore::from(EncryptedFileBasedKeystore::new(
+                        &keystore_path,
+                        read_keystore_passphrase()?,
+                    )?)
+                } else {
+                    Keystore::from(FileBasedKeystore::new(&keystore_path)?)
+                };
                 cmd.execute(&mut keystore)
             }
             SuiCommand::Console { config } => {
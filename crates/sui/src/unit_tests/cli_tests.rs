@@ -5,15 +5,17 @@ use std::str::FromStr;
 use std::{fmt::Write, fs::read_dir, path::PathBuf, str, thread, time::Duration};
 
 use anyhow::anyhow;
+use fastcrypto::encoding::{Base64, Encoding};
 use move_package::BuildConfig;
 use serde_json::json;
 use tokio::time::sleep;
 
-use sui::client_commands::SwitchResponse;
+use sui::client_commands::{SignResponse, SwitchResponse};
 use sui::{
     client_commands::{SuiClientCommandResult, SuiClientCommands, WalletContext},
     config::SuiClientConfig,
     sui_commands::SuiCommand,
+    watch::AddressWatcher,
 };
 use sui_config::genesis_config::{AccountConfig, GenesisConfig, ObjectConfig};
 use sui_config::{
@@ -30,6 +32,8 @@ use sui_types::base_types::{ObjectType, SuiAddress};
 use sui_types::crypto::{
     Ed25519SuiSignature, Secp256k1SuiSignature, SignatureScheme, SuiKeyPair, SuiSignatureInner,
 };
+use sui_types::messages::TransactionData;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::{base_types::ObjectID, crypto::get_key_pair, gas_coin::GasCoin};
 use sui_types::{sui_framework_address_concat_string, SUI_FRAMEWORK_ADDRESS};
 use test_utils::messages::make_transactions_with_wallet_context;
@@ -691,6 +695,46 @@ async fn test_native_transfer() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_watch_address() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let address = test_cluster.get_address_0();
+    let recipient = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+
+    let client = context.get_client().await?;
+    let mut watcher = AddressWatcher::new(client.clone(), recipient).await?;
+    assert!(watcher.poll().await?.is_empty());
+
+    let object_refs = client
+        .read_api()
+        .get_objects_owned_by_address(address)
+        .await?;
+    let obj_id = object_refs.get(1).unwrap().object_id;
+    SuiClientCommands::Transfer {
+        gas: None,
+        to: recipient,
+        object_id: obj_id,
+        gas_budget: 50000,
+    }
+    .execute(context)
+    .await?;
+
+    // The transfer is reported once, as incoming.
+    let activity = watcher.poll().await?;
+    assert_eq!(activity.len(), 1);
+    assert_eq!(activity[0].sender, address);
+    assert!(!activity[0].outgoing);
+    assert!(activity[0].success);
+    assert!(activity[0]
+        .received_objects
+        .iter()
+        .any(|(object_id, _)| *object_id == obj_id));
+    assert!(watcher.poll().await?.is_empty());
+
+    Ok(())
+}
+
 #[test]
 // Test for issue https://github.com/MystenLabs/sui/issues/1078
 fn test_bug_1078() {
@@ -990,6 +1034,55 @@ async fn test_merge_coin() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_consolidate_coins() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let address = test_cluster.get_address_0();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let coins = client
+        .coin_read_api()
+        .get_coins(address, None, None, None)
+        .await?
+        .data;
+    assert!(coins.len() > 2);
+
+    let resp = SuiClientCommands::ConsolidateCoins {
+        coin_type: None,
+        target_count: 2,
+        gas_budget: 20_000,
+    }
+    .execute(context)
+    .await?;
+    let SuiClientCommandResult::ConsolidateCoins(responses) = resp else {
+        panic!("Command failed")
+    };
+    assert_eq!(responses.len(), 1);
+
+    // The largest coin is kept as is, all the others are merged into one.
+    let remaining = client
+        .coin_read_api()
+        .get_coins(address, None, None, None)
+        .await?
+        .data;
+    assert_eq!(remaining.len(), 2);
+    let largest = coins.iter().max_by_key(|coin| coin.balance).unwrap();
+    assert!(remaining
+        .iter()
+        .any(|coin| coin.coin_object_id == largest.coin_object_id));
+
+    // Nothing left to merge.
+    let resp = SuiClientCommands::ConsolidateCoins {
+        coin_type: None,
+        target_count: 2,
+        gas_budget: 20_000,
+    }
+    .execute(context)
+    .await?;
+    assert!(matches!(resp, SuiClientCommandResult::ConsolidateCoins(r) if r.is_empty()));
+    Ok(())
+}
+
 #[sim_test]
 async fn test_split_coin() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await?;
@@ -1194,6 +1287,49 @@ async fn test_execute_signed_tx() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_inspect_tx() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let address = test_cluster.get_address_0();
+    let address1 = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let object_refs = client
+        .read_api()
+        .get_objects_owned_by_address(address)
+        .await?;
+    let gas = object_refs.first().unwrap().to_object_ref();
+
+    let mut builder = ProgrammableTransactionBuilder::new();
+    builder.transfer_sui(address1, None);
+    let data = TransactionData::new_programmable_with_dummy_gas_price(
+        address,
+        vec![gas],
+        builder.finish(),
+        1000,
+    );
+
+    let resp = SuiClientCommands::InspectTx {
+        tx_bytes: Base64::encode(bcs::to_bytes(&data)?),
+    }
+    .execute(context)
+    .await?;
+    let SuiClientCommandResult::InspectTx(inspection) = resp else {
+        panic!("Command failed")
+    };
+    assert_eq!(inspection.sender, address);
+    assert_eq!(inspection.gas_payment.len(), 1);
+    assert_eq!(inspection.gas_payment[0].object_id, gas.0);
+    assert!(inspection.gas_payment[0]
+        .object_type
+        .as_ref()
+        .unwrap()
+        .contains("Coin"));
+    // Transferring the whole gas coin is flagged.
+    assert_eq!(inspection.warnings.len(), 1);
+    Ok(())
+}
+
 #[sim_test]
 async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await?;
@@ -1218,6 +1354,69 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_sign_and_execute_signed_tx() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let address = test_cluster.get_address_0();
+    let address1 = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let object_refs = client
+        .read_api()
+        .get_objects_owned_by_address(address)
+        .await?;
+    let coin = object_refs.get(1).unwrap().object_id;
+
+    let SuiClientCommandResult::SerializeTransferSui(tx_bytes) =
+        SuiClientCommands::SerializeTransferSui {
+            to: address1,
+            sui_coin_object_id: coin,
+            gas_budget: 1000,
+            amount: Some(1),
+        }
+        .execute(context)
+        .await?
+    else {
+        panic!("Expected SerializeTransferSui result");
+    };
+
+    // The transaction is signed by its sender by default
+    let SuiClientCommandResult::Sign(SignResponse {
+        address: signer,
+        signature,
+    }) = SuiClientCommands::Sign {
+        tx_bytes: tx_bytes.clone(),
+        address: None,
+    }
+    .execute(context)
+    .await?
+    else {
+        panic!("Expected Sign result");
+    };
+    assert_eq!(signer, address);
+
+    // Signing with a key that is not in the keystore fails
+    assert!(SuiClientCommands::Sign {
+        tx_bytes: tx_bytes.clone(),
+        address: Some(SuiAddress::random_for_testing_only()),
+    }
+    .execute(context)
+    .await
+    .is_err());
+
+    let SuiClientCommandResult::ExecuteSignedTx(response) = SuiClientCommands::ExecuteSignedTx {
+        tx_bytes,
+        signatures: vec![signature],
+    }
+    .execute(context)
+    .await?
+    else {
+        panic!("Expected ExecuteSignedTx result");
+    };
+    assert!(response.effects.unwrap().status().is_ok());
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_delegation_with_none_amount() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await?;
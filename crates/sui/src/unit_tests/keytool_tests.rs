@@ -12,7 +12,9 @@ use fastcrypto::encoding::Base64;
 use fastcrypto::encoding::Encoding;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore};
+use sui_keys::keystore::{
+    AccountKeystore, EncryptedFileBasedKeystore, FileBasedKeystore, InMemKeystore, Keystore,
+};
 use sui_types::base_types::ObjectDigest;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::SequenceNumber;
@@ -238,6 +240,75 @@ fn test_mnemonics_secp256k1() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test]
+fn test_mnemonics_secp256r1() -> Result<(), anyhow::Error> {
+    // Secp256r1 keys are derived along the BIP-32 path of secp256k1, the derived secret key is
+    // then used as a secp256r1 secret key.
+    const TEST_CASES: [[&str; 3]; 3] = [["film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm", "AkNuHmlb0HjmMEJozneYqv2WPHnZMwqstOCIdGgWZ0XD", "dbf6043550855156891890525eaf8b1db9fb74d0d507ea85b598ff817d1c784f"],
+    ["require decline left thought grid priority false tiny gasp angle royal system attack beef setup reward aunt skill wasp tray vital bounce inflict level", "AtHhqBC0+ytxUOZ/nA8YqONfpUJzg+DGR00pUBEOS8KK", "d5abde7775533a04b355399bd3242c93cc9e15b1ebd758cdf717a703dda19d55"],
+    ["organ crash swim stick traffic remember army arctic mesh slice swear summer police vast chaos cradle squirrel hood useless evidence pet hub soap lake", "AkokF5LU1GrQ+IwJBDP04+Bwi+Hkfz0PY/vdjf1cbLMr", "418c4c26842e1122e8e834b3d37cf3f16b6ab33391ba72a5671d96580f77c78c"]];
+
+    for t in TEST_CASES {
+        let mut keystore = Keystore::from(InMemKeystore::new(0));
+        KeyToolCommand::Import {
+            mnemonic_phrase: t[0].to_string(),
+            key_scheme: SignatureScheme::Secp256r1,
+            derivation_path: None,
+        }
+        .execute(&mut keystore)?;
+        let kp = SuiKeyPair::decode_base64(t[1]).unwrap();
+        let addr = SuiAddress::from_str(t[2]).unwrap();
+        assert_eq!(SuiAddress::from(&kp.public()), addr);
+        assert!(keystore.addresses().contains(&addr));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mnemonics_custom_derivation_path() -> Result<(), anyhow::Error> {
+    // Test cases match with /mysten/sui/sdk/typescript/test/unit/cryptography/ed25519-keypair.test.ts
+    // and secp256k1-keypair.test.ts
+    const MNEMONIC: &str = "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm";
+    let test_cases = [
+        (
+            SignatureScheme::ED25519,
+            "m/44'/784'/1'/0'/1'",
+            "APbWthtODvvrWf2F2iJ2L2cyoxgwR1ZtTISEGe9VcR8G",
+            "93414e25fe6797b98fabcb65543b74c8c585d1013a9e0180cce7811698b7d7fd",
+        ),
+        (
+            SignatureScheme::Secp256k1,
+            "m/54'/784'/1'/0/1",
+            "ARQnD4vlhMrmW8nGx/qUezYitasyl9dVf/VZ2bhjxxwq",
+            "458aabaab704cbd90e2f0238e6abd630d42a074a9d0199ec1b48f979031fb3b8",
+        ),
+        (
+            SignatureScheme::Secp256r1,
+            "m/74'/784'/1'/0/1",
+            "Ar5BBNZlrNnEn8c1ntXZaR5bnwVYRiAUxxW91ykRETTq",
+            "7d4f2f7e34df2f04ff5db6c68fd196f81012873ab6167fc4639fdc0d8c20dd3f",
+        ),
+    ];
+
+    for (key_scheme, path, keypair, address) in test_cases {
+        let mut keystore = Keystore::from(InMemKeystore::new(0));
+        KeyToolCommand::Import {
+            mnemonic_phrase: MNEMONIC.to_string(),
+            key_scheme,
+            derivation_path: Some(path.parse().unwrap()),
+        }
+        .execute(&mut keystore)?;
+        let addr = SuiAddress::from_str(address).unwrap();
+        assert_eq!(keystore.addresses(), vec![addr]);
+        assert_eq!(
+            keystore.get_key(&addr)?.encode_base64(),
+            keypair,
+            "key derived at {path}"
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn test_invalid_derivation_path() -> Result<(), anyhow::Error> {
     let mut keystore = Keystore::from(InMemKeystore::new(0));
@@ -281,6 +352,14 @@ fn test_invalid_derivation_path() -> Result<(), anyhow::Error> {
     .execute(&mut keystore)
     .is_err());
 
+    assert!(KeyToolCommand::Import {
+        mnemonic_phrase: TEST_MNEMONIC.to_string(),
+        key_scheme: SignatureScheme::Secp256r1,
+        derivation_path: Some("m/54'/784'/0'/0/0".parse().unwrap()),
+    }
+    .execute(&mut keystore)
+    .is_err());
+
     Ok(())
 }
 
@@ -326,6 +405,14 @@ fn test_valid_derivation_path() -> Result<(), anyhow::Error> {
     }
     .execute(&mut keystore)
     .is_ok());
+
+    assert!(KeyToolCommand::Import {
+        mnemonic_phrase: TEST_MNEMONIC.to_string(),
+        key_scheme: SignatureScheme::Secp256r1,
+        derivation_path: Some("m/74'/784'/1'/0/1".parse().unwrap()),
+    }
+    .execute(&mut keystore)
+    .is_ok());
     Ok(())
 }
 
@@ -380,3 +467,98 @@ fn test_sign_command() -> Result<(), anyhow::Error> {
     .execute(&mut keystore)?;
     Ok(())
 }
+
+#[test]
+fn test_encrypted_keystore() -> Result<(), anyhow::Error> {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("sui.keystore");
+    let mut keystore = FileBasedKeystore::new(&path)?;
+    keystore.add_key(SuiKeyPair::Ed25519(get_key_pair().1))?;
+    keystore.add_key(SuiKeyPair::Secp256k1(get_key_pair().1))?;
+    let addresses = keystore.addresses();
+    keystore.set_alias(&addresses[0], Some("alice".to_string()))?;
+
+    let mut keystore = FileBasedKeystore::new(&path)?.encrypt("passphrase".to_string())?;
+    assert!(EncryptedFileBasedKeystore::is_encrypted(&path));
+    assert!(FileBasedKeystore::new(&path).is_err());
+    assert!(!path.with_extension("aliases").exists());
+    assert!(EncryptedFileBasedKeystore::new(&path, "wrong".to_string()).is_err());
+
+    let reopened = EncryptedFileBasedKeystore::new(&path, "passphrase".to_string())?;
+    assert_eq!(reopened.addresses(), addresses);
+    assert_eq!(reopened.alias(&addresses[0]), Some("alice"));
+
+    keystore.change_passphrase("new passphrase".to_string())?;
+    assert!(EncryptedFileBasedKeystore::new(&path, "passphrase".to_string()).is_err());
+    let reopened = EncryptedFileBasedKeystore::new(&path, "new passphrase".to_string())?;
+    assert_eq!(reopened.addresses(), addresses);
+    Ok(())
+}
+
+#[test]
+fn test_aliases() -> Result<(), anyhow::Error> {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("sui.keystore");
+    let mut keystore = Keystore::from(FileBasedKeystore::new(&path)?);
+    keystore.add_key(SuiKeyPair::Ed25519(get_key_pair().1))?;
+    keystore.add_key(SuiKeyPair::Ed25519(get_key_pair().1))?;
+    let addresses = keystore.addresses();
+
+    KeyToolCommand::SetAlias {
+        address: addresses[0],
+        alias: Some("alice".to_string()),
+    }
+    .execute(&mut keystore)?;
+    assert_eq!(keystore.address_by_alias("alice")?, addresses[0]);
+    // An alias names one address only.
+    assert!(keystore
+        .set_alias(&addresses[1], Some("alice".to_string()))
+        .is_err());
+    // Aliases of unknown addresses cannot be set.
+    assert!(keystore
+        .set_alias(
+            &SuiAddress::random_for_testing_only(),
+            Some("bob".to_string())
+        )
+        .is_err());
+
+    let reopened = FileBasedKeystore::new(&path)?;
+    assert_eq!(reopened.alias(&addresses[0]), Some("alice"));
+
+    KeyToolCommand::SetAlias {
+        address: addresses[0],
+        alias: None,
+    }
+    .execute(&mut keystore)?;
+    assert!(keystore.address_by_alias("alice").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rotate_key() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new(0));
+    keystore.add_key(SuiKeyPair::Ed25519(get_key_pair().1))?;
+    let old_address = keystore.addresses()[0];
+    keystore.set_alias(&old_address, Some("alice".to_string()))?;
+
+    let (new_address, _, scheme) = keystore.rotate_key(&old_address, None, None, false)?;
+    assert!(matches!(scheme, SignatureScheme::ED25519));
+    assert_eq!(keystore.address_by_alias("alice")?, new_address);
+    assert!(keystore.alias(&old_address).is_none());
+    assert!(keystore.get_key(&old_address).is_ok());
+
+    KeyToolCommand::Rotate {
+        address: new_address,
+        key_scheme: Some(SignatureScheme::Secp256k1),
+        derivation_path: None,
+        remove_old: true,
+    }
+    .execute(&mut keystore)?;
+    assert!(keystore.get_key(&new_address).is_err());
+    let rotated_address = keystore.address_by_alias("alice")?;
+    assert!(matches!(
+        keystore.get_key(&rotated_address)?.public().scheme(),
+        SignatureScheme::Secp256k1
+    ));
+    Ok(())
+}
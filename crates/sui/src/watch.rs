@@ -0,0 +1,255 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Write};
+
+use colored::Colorize;
+use serde::Serialize;
+use sui_json_rpc_types::{
+    SuiEvent, SuiTransactionDataAPI, SuiTransactionEffectsAPI, SuiTransactionResponse,
+    SuiTransactionResponseOptions,
+};
+use sui_sdk::SuiClient;
+use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
+use sui_types::object::Owner;
+use sui_types::query::TransactionQuery;
+
+const PAGE_SIZE: usize = 50;
+
+/// Follows the transactions sent or received by an address, for `sui client watch`.
+///
+/// The transactions are polled from the read API, so that the watcher works against full nodes as
+/// well as indexers. Only the transactions executed after the watcher is created are reported.
+pub struct AddressWatcher {
+    client: SuiClient,
+    address: SuiAddress,
+    /// Last transaction seen from and to the address, the cursors of the next polls.
+    sent_cursor: Option<TransactionDigest>,
+    received_cursor: Option<TransactionDigest>,
+}
+
+/// A transaction sent or received by the watched address.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressActivity {
+    pub digest: TransactionDigest,
+    pub checkpoint: Option<u64>,
+    pub timestamp_ms: Option<u64>,
+    pub sender: SuiAddress,
+    /// Whether the transaction was sent by the watched address.
+    pub outgoing: bool,
+    pub success: bool,
+    /// Changes of the coin balances of the address, as (coin type, amount) pairs.
+    pub balance_changes: Vec<(String, i128)>,
+    /// Objects transferred to the address, as (object ID, object type) pairs.
+    pub received_objects: Vec<(ObjectID, String)>,
+}
+
+impl AddressWatcher {
+    pub async fn new(client: SuiClient, address: SuiAddress) -> anyhow::Result<Self> {
+        let latest = |query| {
+            let client = client.clone();
+            async move {
+                let page = client
+                    .read_api()
+                    .get_transactions(query, None, Some(1), true)
+                    .await?;
+                Ok::<_, anyhow::Error>(page.data.first().map(|item| *item.digest()))
+            }
+        };
+        Ok(Self {
+            sent_cursor: latest(TransactionQuery::FromAddress(address)).await?,
+            received_cursor: latest(TransactionQuery::ToAddress(address)).await?,
+            client,
+            address,
+        })
+    }
+
+    pub fn address(&self) -> SuiAddress {
+        self.address
+    }
+
+    /// The transactions of the address executed since the last poll, oldest first.
+    pub async fn poll(&mut self) -> anyhow::Result<Vec<AddressActivity>> {
+        let mut digests = self
+            .poll_query(
+                TransactionQuery::FromAddress(self.address),
+                self.sent_cursor,
+            )
+            .await?;
+        if let Some(last) = digests.last() {
+            self.sent_cursor = Some(*last);
+        }
+        let received = self
+            .poll_query(
+                TransactionQuery::ToAddress(self.address),
+                self.received_cursor,
+            )
+            .await?;
+        if let Some(last) = received.last() {
+            self.received_cursor = Some(*last);
+        }
+        // Transactions sending coins to their sender show up in both queries.
+        let sent = digests.iter().copied().collect::<BTreeSet<_>>();
+        digests.extend(received.into_iter().filter(|digest| !sent.contains(digest)));
+
+        let mut activity = vec![];
+        for chunk in digests.chunks(PAGE_SIZE) {
+            let responses = self
+                .client
+                .read_api()
+                .multi_get_transactions_with_options(
+                    chunk.to_vec(),
+                    SuiTransactionResponseOptions::new()
+                        .with_input()
+                        .with_effects()
+                        .with_events(),
+                )
+                .await?;
+            activity.extend(
+                responses
+                    .into_iter()
+                    .map(|response| AddressActivity::new(self.address, response)),
+            );
+        }
+        // Transactions not in a checkpoint yet are the latest.
+        activity.sort_by_key(|activity| {
+            (
+                activity.checkpoint.unwrap_or(u64::MAX),
+                activity.timestamp_ms,
+            )
+        });
+        Ok(activity)
+    }
+
+    /// Digests of the transactions of `query` after `cursor`, oldest first.
+    async fn poll_query(
+        &self,
+        query: TransactionQuery,
+        mut cursor: Option<TransactionDigest>,
+    ) -> anyhow::Result<Vec<TransactionDigest>> {
+        let mut digests = vec![];
+        loop {
+            let page = self
+                .client
+                .read_api()
+                .get_transactions(query.clone(), cursor, Some(PAGE_SIZE), false)
+                .await?;
+            // Pages start at their cursor, which was already seen.
+            digests.extend(
+                page.data
+                    .iter()
+                    .map(|item| *item.digest())
+                    .filter(|digest| Some(*digest) != cursor),
+            );
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+        Ok(digests)
+    }
+}
+
+impl AddressActivity {
+    fn new(address: SuiAddress, response: SuiTransactionResponse) -> Self {
+        let sender = response
+            .transaction
+            .as_ref()
+            .map_or(SuiAddress::ZERO, |tx| *tx.data.sender());
+        let success = response
+            .effects
+            .as_ref()
+            .map_or(false, |effects| effects.status().is_ok());
+        let owned_by_address = |owner: &Owner| *owner == Owner::AddressOwner(address);
+        let mut balance_changes = vec![];
+        let mut received_objects = vec![];
+        for event in response.events.iter().flat_map(|events| &events.data) {
+            match event {
+                SuiEvent::CoinBalanceChange {
+                    owner,
+                    coin_type,
+                    amount,
+                    ..
+                } if owned_by_address(owner) => {
+                    match balance_changes
+                        .iter_mut()
+                        .find(|(change_type, _)| change_type == coin_type)
+                    {
+                        Some((_, total)) => *total += amount,
+                        None => balance_changes.push((coin_type.clone(), *amount)),
+                    }
+                }
+                SuiEvent::TransferObject {
+                    recipient,
+                    previous_owner,
+                    object_type,
+                    object_id,
+                    ..
+                } if owned_by_address(recipient)
+                    && !previous_owner.as_ref().map_or(false, owned_by_address) =>
+                {
+                    received_objects.push((*object_id, object_type.clone()))
+                }
+                SuiEvent::NewObject {
+                    recipient,
+                    object_type,
+                    object_id,
+                    sender,
+                    ..
+                } if owned_by_address(recipient) && *sender != address => {
+                    received_objects.push((*object_id, object_type.clone()))
+                }
+                _ => {}
+            }
+        }
+        Self {
+            digest: response.digest,
+            checkpoint: response.checkpoint,
+            timestamp_ms: response.timestamp_ms,
+            sender,
+            outgoing: sender == address,
+            success,
+            balance_changes,
+            received_objects,
+        }
+    }
+}
+
+impl Display for AddressActivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut writer = String::new();
+        let direction = if self.outgoing {
+            "Outgoing".yellow()
+        } else {
+            "Incoming".green()
+        };
+        write!(writer, "{direction} {}", self.digest)?;
+        if let Some(checkpoint) = self.checkpoint {
+            write!(writer, " checkpoint {checkpoint}")?;
+        }
+        if let Some(timestamp_ms) = self.timestamp_ms {
+            write!(writer, " at {timestamp_ms} ms")?;
+        }
+        if !self.success {
+            write!(writer, " {}", "failed".red())?;
+        }
+        writeln!(writer)?;
+        if !self.outgoing {
+            writeln!(writer, "  From: {}", self.sender)?;
+        }
+        for (coin_type, amount) in &self.balance_changes {
+            let amount = if *amount < 0 {
+                amount.to_string().red()
+            } else {
+                format!("+{amount}").green()
+            };
+            writeln!(writer, "  Balance: {amount} {coin_type}")?;
+        }
+        for (object_id, object_type) in &self.received_objects {
+            writeln!(writer, "  Received: {object_id} ({object_type})")?;
+        }
+        write!(f, "{}", writer.trim_end_matches('\n'))
+    }
+}
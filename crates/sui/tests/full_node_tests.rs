@@ -12,10 +12,11 @@ use move_core_types::value::MoveStructLayout;
 use mysten_metrics::RegistryService;
 use prometheus::Registry;
 use sui::client_commands::{SuiClientCommandResult, SuiClientCommands, WalletContext};
+use sui_config::genesis_config::{AccountConfig, ObjectConfig};
 use sui_json_rpc_types::{
     type_and_fields_from_move_struct, EventPage, SuiEvent, SuiEventEnvelope, SuiEventFilter,
-    SuiExecutionStatus, SuiMoveStruct, SuiMoveValue, SuiTransactionEffectsAPI,
-    SuiTransactionResponse,
+    SuiExecutionStatus, SuiMoveStruct, SuiMoveValue, SuiPackagePublish, SuiPackagePublishFilter,
+    SuiTransactionEffectsAPI, SuiTransactionResponse,
 };
 use sui_keys::keystore::AccountKeystore;
 use sui_macros::*;
@@ -45,7 +46,7 @@ use test_utils::messages::{
 };
 use test_utils::network::{start_fullnode_from_config, TestClusterBuilder};
 use test_utils::transaction::{
-    create_devnet_nft, delete_devnet_nft, increment_counter,
+    create_devnet_nft, delete_devnet_nft, increment_counter, publish_basics_package,
     publish_basics_package_and_make_counter, transfer_coin,
 };
 use test_utils::transaction::{wait_for_all_txes, wait_for_tx};
@@ -85,6 +86,51 @@ async fn test_full_node_follows_txes() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_cluster_with_funded_accounts() -> Result<(), anyhow::Error> {
+    let gas_objects = |count: usize| {
+        (0..count)
+            .map(|_| ObjectConfig {
+                object_id: ObjectID::random(),
+                gas_value: 1_000_000_000,
+            })
+            .collect::<Vec<_>>()
+    };
+    let external_address = SuiAddress::random_for_testing_only();
+    let test_cluster = TestClusterBuilder::new()
+        .with_num_validators(5)
+        .with_accounts(vec![
+            AccountConfig {
+                address: None,
+                gas_objects: gas_objects(3),
+                gas_object_ranges: None,
+            },
+            AccountConfig {
+                address: Some(external_address),
+                gas_objects: gas_objects(1),
+                gas_object_ranges: None,
+            },
+        ])
+        .build()
+        .await?;
+
+    assert_eq!(test_cluster.validator_network_addresses().len(), 5);
+
+    // Only the account without an address has its key in the cluster keystore
+    let addresses = test_cluster.keystore().addresses();
+    assert_eq!(addresses.len(), 1);
+    for (address, gas_object_count) in [(addresses[0], 3), (external_address, 1)] {
+        let coins = test_cluster
+            .sui_client()
+            .coin_read_api()
+            .get_coins(address, None, None, None)
+            .await?;
+        assert_eq!(coins.data.len(), gas_object_count);
+    }
+
+    Ok(())
+}
+
 #[sim_test]
 async fn test_full_node_shared_objects() -> Result<(), anyhow::Error> {
     let mut test_cluster = TestClusterBuilder::new().build().await?;
@@ -676,6 +722,56 @@ async fn test_full_node_sub_and_query_move_event_ok() -> Result<(), anyhow::Erro
     Ok(())
 }
 
+#[sim_test]
+async fn test_full_node_sub_package_publishes_ok() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await?;
+
+    let fullnode = start_fullnode_from_config(
+        test_cluster
+            .fullnode_config_builder()
+            .with_event_store()
+            .build()
+            .unwrap(),
+    )
+    .await
+    .unwrap();
+    let ws_client = fullnode.ws_client;
+
+    let sender = test_cluster.get_address_0();
+    let context = &test_cluster.wallet;
+
+    let mut sub: Subscription<SuiPackagePublish> = ws_client
+        .subscribe(
+            "sui_subscribePackagePublishes",
+            rpc_params![SuiPackagePublishFilter {
+                publisher: Some(sender),
+                dependency: Some(SUI_FRAMEWORK_OBJECT_ID),
+            }],
+            "sui_unsubscribePackagePublishes",
+        )
+        .await
+        .unwrap();
+
+    let (package_id, version, _) = publish_basics_package(context, sender).await;
+
+    match timeout(Duration::from_secs(5), sub.next()).await {
+        Ok(Some(Ok(publish))) => {
+            assert_eq!(publish.package_id, package_id);
+            assert_eq!(publish.version, version);
+            assert_eq!(publish.publisher, sender);
+            assert!(publish.modules.contains(&"counter".to_string()));
+            assert!(publish.dependencies.contains(&SUI_FRAMEWORK_OBJECT_ID));
+            assert!(!publish.dependencies.contains(&package_id));
+        }
+        other => panic!("Failed to get SuiPackagePublish, but {:?}", other),
+    }
+
+    Ok(())
+}
+
 // Test fullnode has event read jsonrpc endpoints working
 #[sim_test]
 async fn test_full_node_event_read_api_ok() {
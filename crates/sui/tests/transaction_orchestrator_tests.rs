@@ -8,10 +8,10 @@ use sui_macros::sim_test;
 use sui_types::crypto::{get_key_pair, AccountKeyPair};
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse,
-    FinalizedEffects, TransactionData, VerifiedTransaction,
+    FinalizedEffects, TransactionData, TransactionEffectsAPI, VerifiedTransaction,
 };
 use sui_types::object::generate_test_gas_objects_with_owner;
-use sui_types::quorum_driver_types::QuorumDriverError;
+use sui_types::quorum_driver_types::{QuorumDriverError, TransactionSubmissionStatus};
 use sui_types::utils::to_sender_signed_transaction;
 use test_utils::authority::{
     spawn_fullnode, spawn_test_authorities, test_authority_configs,
@@ -163,6 +163,57 @@ async fn test_fullnode_wal_log() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_enqueue_transaction() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let node = &test_cluster.fullnode_handle.sui_node;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let reconfig_channel = node.subscribe_to_epoch_change();
+    let orchestrator = TransactiondOrchestrator::new_with_network_clients(
+        node.state(),
+        reconfig_channel,
+        temp_dir.path(),
+        &Registry::new(),
+    )
+    .await
+    .unwrap();
+
+    let context = &mut test_cluster.wallet;
+    let mut txns = make_transactions_with_wallet_context(context, 1).await;
+    let txn = txns.swap_remove(0);
+    let digest = *txn.digest();
+
+    assert!(matches!(
+        orchestrator.submission_status(&digest)?,
+        TransactionSubmissionStatus::Unknown
+    ));
+
+    let ticket = orchestrator.enqueue_transaction(txn.into()).await?;
+    assert_eq!(ticket, digest);
+
+    // Poll the ticket until the transaction is final.
+    let effects = tokio::time::timeout(tokio::time::Duration::from_secs(30), async {
+        loop {
+            match orchestrator.submission_status(&ticket).unwrap() {
+                TransactionSubmissionStatus::Finalized { effects, .. } => break effects,
+                TransactionSubmissionStatus::Failed(err) => panic!("Transaction failed: {err}"),
+                TransactionSubmissionStatus::Pending | TransactionSubmissionStatus::Unknown => {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+                }
+            }
+        }
+    })
+    .await?;
+    assert_eq!(effects.transaction_digest(), &digest);
+
+    // The transaction leaves the pending transaction log once final.
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    assert!(orchestrator.load_all_pending_transactions().is_empty());
+
+    Ok(())
+}
+
 #[sim_test]
 async fn test_transaction_orchestrator_reconfig() {
     telemetry_subscribers::init_for_testing();
@@ -3,12 +3,14 @@
 
 use futures::future::join_all;
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
 use jsonrpsee::ws_client::WsClient;
 use jsonrpsee::ws_client::WsClientBuilder;
+use multiaddr::Multiaddr;
 use prometheus::Registry;
 use rand::{distributions::*, rngs::OsRng, seq::SliceRandom};
 use tokio::{task::JoinHandle, time::sleep};
@@ -18,7 +20,7 @@ use mysten_metrics::RegistryService;
 use sui::config::SuiEnv;
 use sui::{client_commands::WalletContext, config::SuiClientConfig};
 use sui_config::builder::{ProtocolVersionsConfig, SupportedProtocolVersionsCallback};
-use sui_config::genesis_config::GenesisConfig;
+use sui_config::genesis_config::{AccountConfig, GenesisConfig};
 use sui_config::node::DBCheckpointConfig;
 use sui_config::{Config, SUI_CLIENT_CONFIG, SUI_NETWORK_CONFIG};
 use sui_config::{FullnodeConfigBuilder, NodeConfig, PersistedConfig, SUI_KEYSTORE_FILENAME};
@@ -67,6 +69,31 @@ impl TestCluster {
         &self.fullnode_handle.rpc_url
     }
 
+    pub fn ws_url(&self) -> &str {
+        &self.fullnode_handle.ws_url
+    }
+
+    /// Keystore holding the keys of the accounts funded at genesis.
+    pub fn keystore(&self) -> &Keystore {
+        &self.wallet.config.keystore
+    }
+
+    pub fn keystore_path(&self) -> PathBuf {
+        self.swarm.dir().join(SUI_KEYSTORE_FILENAME)
+    }
+
+    /// Path of the client config, to point the Sui CLI or a `WalletContext` at the cluster.
+    pub fn wallet_config_path(&self) -> PathBuf {
+        self.swarm.dir().join(SUI_CLIENT_CONFIG)
+    }
+
+    pub fn validator_network_addresses(&self) -> Vec<Multiaddr> {
+        self.swarm
+            .validators()
+            .map(|v| v.config.network_address().clone())
+            .collect()
+    }
+
     pub fn wallet_mut(&mut self) -> &mut WalletContext {
         &mut self.wallet
     }
@@ -203,6 +230,7 @@ impl RandomNodeRestarter {
 
 pub struct TestClusterBuilder {
     genesis_config: Option<GenesisConfig>,
+    accounts: Option<Vec<AccountConfig>>,
     additional_objects: Vec<Object>,
     num_validators: Option<usize>,
     fullnode_rpc_port: Option<u16>,
@@ -218,6 +246,7 @@ impl TestClusterBuilder {
     pub fn new() -> Self {
         TestClusterBuilder {
             genesis_config: None,
+            accounts: None,
             additional_objects: vec![],
             fullnode_rpc_port: None,
             num_validators: None,
@@ -240,6 +269,13 @@ impl TestClusterBuilder {
         self
     }
 
+    /// Accounts funded at genesis, replacing the accounts of the genesis config. Keys are
+    /// generated and added to the cluster keystore for accounts without an address.
+    pub fn with_accounts(mut self, accounts: Vec<AccountConfig>) -> Self {
+        self.accounts = Some(accounts);
+        self
+    }
+
     pub fn with_objects<I: IntoIterator<Item = Object>>(mut self, objects: I) -> Self {
         self.additional_objects.extend(objects);
         self
@@ -359,7 +395,14 @@ impl TestClusterBuilder {
                 self.supported_protocol_versions_config.clone(),
             );
 
-        if let Some(genesis_config) = self.genesis_config.take() {
+        let genesis_config = match (self.genesis_config.take(), self.accounts.take()) {
+            (genesis_config, Some(accounts)) => Some(GenesisConfig {
+                accounts,
+                ..genesis_config.unwrap_or_else(GenesisConfig::for_local_testing)
+            }),
+            (genesis_config, None) => genesis_config,
+        };
+        if let Some(genesis_config) = genesis_config {
             builder = builder.initial_accounts_config(genesis_config);
         }
 
@@ -70,6 +70,9 @@ impl SamplingInterval {
 #[derive(Debug)]
 pub struct ColumnFamilyMetrics {
     pub rocksdb_total_sst_files_size: IntGaugeVec,
+    pub rocksdb_live_sst_files_size: IntGaugeVec,
+    pub rocksdb_estimate_live_data_size: IntGaugeVec,
+    pub rocksdb_estimate_pending_compaction_bytes: IntGaugeVec,
     pub rocksdb_size_all_mem_tables: IntGaugeVec,
     pub rocksdb_num_snapshots: IntGaugeVec,
     pub rocksdb_oldest_snapshot_time: IntGaugeVec,
@@ -98,6 +101,29 @@ impl ColumnFamilyMetrics {
                 registry,
             )
             .unwrap(),
+            rocksdb_live_sst_files_size: register_int_gauge_vec_with_registry!(
+                "rocksdb_live_sst_files_size",
+                "The storage size occupied by the SST files of the current version of the column family",
+                &["cf_name"],
+                registry,
+            )
+            .unwrap(),
+            rocksdb_estimate_live_data_size: register_int_gauge_vec_with_registry!(
+                "rocksdb_estimate_live_data_size",
+                "The estimated size of the live data of the column family. Compared to
+                rocksdb_total_sst_files_size, it shows the space that compactions can reclaim.",
+                &["cf_name"],
+                registry,
+            )
+            .unwrap(),
+            rocksdb_estimate_pending_compaction_bytes: register_int_gauge_vec_with_registry!(
+                "rocksdb_estimate_pending_compaction_bytes",
+                "The estimated number of bytes compactions need to rewrite to bring all the
+                levels of the column family below their target size.",
+                &["cf_name"],
+                registry,
+            )
+            .unwrap(),
             rocksdb_size_all_mem_tables: register_int_gauge_vec_with_registry!(
                 "rocksdb_size_all_mem_tables",
                 "The memory size occupied by the column family's in-memory buffer",
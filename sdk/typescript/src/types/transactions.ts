@@ -157,6 +157,8 @@ export const SuiTransactionData = object({
   transactions: array(SuiTransactionKind),
   sender: SuiAddress,
   gasData: SuiGasData,
+  // The last epoch validators sign the transaction in, null if it never expires.
+  expiration: optional(nullable(number())),
 });
 export type SuiTransactionData = Infer<typeof SuiTransactionData>;
 
@@ -177,6 +179,7 @@ export const GasCostSummary = object({
   computationCost: number(),
   storageCost: number(),
   storageRebate: number(),
+  nonRefundableStorageFee: optional(number()),
 });
 export type GasCostSummary = Infer<typeof GasCostSummary>;
 
@@ -488,7 +491,8 @@ export function getTotalGasUsed(
   return gasSummary
     ? gasSummary.computationCost +
         gasSummary.storageCost -
-        gasSummary.storageRebate
+        gasSummary.storageRebate +
+        (gasSummary.nonRefundableStorageFee ?? 0)
     : undefined;
 }
 
@@ -21,6 +21,7 @@ import { AuthorityName } from './transactions';
 
 export type DelegatedStake = Infer<typeof DelegatedStake>;
 export type CommitteeInfo = Infer<typeof CommitteeInfo>;
+export type CommitteeValidator = Infer<typeof CommitteeValidator>;
 
 // Staking
 
@@ -121,10 +122,27 @@ export const DelegationStakingPool = object({
   fields: DelegationStakingPoolFields,
 });
 
+export const CommitteeValidator = object({
+  authorityName: AuthorityName,
+  stake: number(),
+  suiAddress: SuiAddress,
+  name: string(),
+  networkPubkey: string(),
+  workerPubkey: string(),
+  netAddress: string(),
+  p2pAddress: string(),
+  primaryAddress: string(),
+  workerAddress: string(),
+  gasPrice: number(),
+  commissionRate: number(),
+});
+
 export const CommitteeInfo = object({
   epoch: number(),
   /** Array of (validator public key, stake unit) tuple */
   validators: optional(array(tuple([AuthorityName, number()]))),
+  /** Names, keys and network metadata of the validators */
+  validatorInfo: optional(array(CommitteeValidator)),
 });
 
 export const SuiValidatorSummary = object({
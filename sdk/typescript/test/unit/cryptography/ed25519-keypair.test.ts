@@ -65,6 +65,20 @@ describe('ed25519-keypair', () => {
     }
   });
 
+  it('derive keypair with custom derivation path matches keytool', () => {
+    // Test case matches with test_mnemonics_custom_derivation_path in crates/sui/src/unit_tests/keytool_tests.rs
+    const keypair = Ed25519Keypair.deriveKeypair(
+      TEST_CASES[0][0],
+      `m/44'/784'/1'/0'/1'`,
+    );
+    expect(keypair.getPublicKey().toSuiAddress()).toEqual(
+      '93414e25fe6797b98fabcb65543b74c8c585d1013a9e0180cce7811698b7d7fd',
+    );
+    expect(keypair.export().privateKey).toEqual(
+      toB64(fromB64('APbWthtODvvrWf2F2iJ2L2cyoxgwR1ZtTISEGe9VcR8G').slice(1)),
+    );
+  });
+
   it('generate keypair from random seed', () => {
     const keypair = Ed25519Keypair.fromSecretKey(
       Uint8Array.from(Array(PRIVATE_KEY_SIZE).fill(8)),
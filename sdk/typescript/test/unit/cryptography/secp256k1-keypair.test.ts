@@ -152,6 +152,20 @@ describe('secp256k1-keypair', () => {
     }
   });
 
+  it('derive keypair with custom derivation path matches keytool', () => {
+    // Test case matches with test_mnemonics_custom_derivation_path in crates/sui/src/unit_tests/keytool_tests.rs
+    const keypair = Secp256k1Keypair.deriveKeypair(
+      TEST_CASES[0][0],
+      `m/54'/784'/1'/0/1`,
+    );
+    expect(keypair.getPublicKey().toSuiAddress()).toEqual(
+      '458aabaab704cbd90e2f0238e6abd630d42a074a9d0199ec1b48f979031fb3b8',
+    );
+    expect(keypair.export().privateKey).toEqual(
+      toB64(fromB64('ARQnD4vlhMrmW8nGx/qUezYitasyl9dVf/VZ2bhjxxwq').slice(1)),
+    );
+  });
+
   it('incorrect purpose node for secp256k1 derivation path', () => {
     expect(() => {
       Secp256k1Keypair.deriveKeypair(TEST_MNEMONIC, `m/44'/784'/0'/0'/0'`);